@@ -34,7 +34,9 @@ use collab_plugins::local_storage::kv::doc::CollabKVAction;
 use collab_user::core::{UserAwareness, UserAwarenessNotifier};
 
 use crate::instant_indexed_data_provider::InstantIndexedDataWriter;
+use crate::sync_status::SyncStatusRegistry;
 use flowy_error::FlowyError;
+use futures::StreamExt;
 use lib_infra::{if_native, if_wasm};
 use tracing::{error, instrument, trace, warn};
 use uuid::Uuid;
@@ -81,6 +83,7 @@ pub struct AppFlowyCollabBuilder {
   rocksdb_backup: ArcSwapOption<Arc<dyn RocksdbBackup>>,
   workspace_integrate: Arc<dyn WorkspaceCollabIntegrate>,
   embeddings_writer: Option<Weak<InstantIndexedDataWriter>>,
+  sync_status_registry: Arc<SyncStatusRegistry>,
 }
 
 impl AppFlowyCollabBuilder {
@@ -97,9 +100,16 @@ impl AppFlowyCollabBuilder {
       #[cfg(not(target_arch = "wasm32"))]
       rocksdb_backup: Default::default(),
       workspace_integrate: Arc::new(workspace_integrate),
+      sync_status_registry: Default::default(),
     }
   }
 
+  /// The registry tracking the sync status of every collab built by this
+  /// builder.
+  pub fn sync_status_registry(&self) -> &Arc<SyncStatusRegistry> {
+    &self.sync_status_registry
+  }
+
   pub fn set_snapshot_persistence(&self, snapshot_persistence: Arc<dyn SnapshotPersistence>) {
     self
       .snapshot_persistence
@@ -330,6 +340,7 @@ impl AppFlowyCollabBuilder {
 
     if build_config.sync_enable {
       trace!("🚀finalize collab:{}", object);
+      let object_id = object.object_id.to_string();
       let plugin_provider = self.plugin_provider.load_full();
       let provider_type = plugin_provider.provider_type();
       let span =
@@ -351,6 +362,35 @@ impl AppFlowyCollabBuilder {
         },
         CollabPluginProviderType::Local => {},
       }
+
+      // Count every update applied to the doc towards the pending sync
+      // queue. Updates pulled from the server are cleared again by the next
+      // sync-finished transition; while offline every update is local.
+      let weak_registry = Arc::downgrade(&self.sync_status_registry);
+      let observed_object_id = object_id.clone();
+      match write_collab.borrow().doc().observe_update_v1(move |_txn, event| {
+        if let Some(registry) = weak_registry.upgrade() {
+          registry.record_local_change(&observed_object_id, event.update.len());
+        }
+      }) {
+        Ok(subscription) => {
+          self
+            .sync_status_registry
+            .retain_update_subscription(&object_id, subscription);
+        },
+        Err(err) => warn!("Observe updates of {} failed: {}", object_id, err),
+      }
+
+      // Fold the collab's sync state transitions into the sync status
+      // registry. The task ends when the collab is dropped.
+      let registry = self.sync_status_registry.clone();
+      let mut sync_state_stream = write_collab.borrow().subscribe_sync_state();
+      tokio::spawn(async move {
+        while let Some(sync_state) = sync_state_stream.next().await {
+          registry.apply_sync_state(&object_id, sync_state);
+        }
+        registry.remove(&object_id);
+      });
     }
 
     (*write_collab).borrow_mut().initialize();
@@ -170,7 +170,7 @@ impl InstantIndexedDataWriter {
   }
 
   pub fn support_collab_type(&self, t: &CollabType) -> bool {
-    matches!(t, CollabType::Document)
+    matches!(t, CollabType::Document | CollabType::DatabaseRow)
   }
 
   pub async fn index_encoded_collab(
@@ -254,10 +254,48 @@ pub fn unindexed_data_form_collab(
       let paras = doc.paragraphs(txn);
       Some(UnindexedData::Paragraphs(paras))
     },
+    CollabType::DatabaseRow => {
+      let mut texts = Vec::new();
+      database_row_text(&collab.to_json_value(), &mut texts);
+      if texts.is_empty() {
+        None
+      } else {
+        Some(UnindexedData::Paragraphs(texts))
+      }
+    },
     _ => None,
   }
 }
 
+/// Pulls the searchable text out of a database row collab: the string
+/// payloads under the row's `cells` map. Select option ids, timestamps and
+/// other non-text payloads are skipped.
+fn database_row_text(value: &serde_json::Value, texts: &mut Vec<String>) {
+  match value {
+    serde_json::Value::Object(map) => {
+      if let Some(serde_json::Value::Object(cells)) = map.get("cells") {
+        for cell in cells.values() {
+          if let Some(serde_json::Value::String(data)) = cell.get("data") {
+            let data = data.trim();
+            if !data.is_empty() && data.chars().any(|c| c.is_alphabetic()) {
+              texts.push(data.to_string());
+            }
+          }
+        }
+      }
+      for nested in map.values() {
+        database_row_text(nested, texts);
+      }
+    },
+    serde_json::Value::Array(items) => {
+      for item in items {
+        database_row_text(item, texts);
+      }
+    },
+    _ => {},
+  }
+}
+
 pub fn unindexed_collab_from_encoded_collab(
   workspace_id: Uuid,
   object_id: Uuid,
@@ -265,7 +303,7 @@ pub fn unindexed_collab_from_encoded_collab(
   collab_type: CollabType,
 ) -> Option<UnindexedCollab> {
   match collab_type {
-    CollabType::Document => {
+    CollabType::Document | CollabType::DatabaseRow => {
       let collab = Collab::new_with_source(
         CollabOrigin::Empty,
         &object_id.to_string(),
@@ -6,6 +6,7 @@ pub mod collab_builder;
 pub mod config;
 pub mod instant_indexed_data_provider;
 mod plugin_provider;
+pub mod sync_status;
 
 pub use collab_plugins::local_storage::kv::doc::CollabKVAction;
 pub use collab_plugins::local_storage::kv::error::PersistenceError;
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+
+use collab::core::collab_state::SyncState;
+use collab::preclude::Subscription;
+use tokio::sync::broadcast;
+use tracing::trace;
+
+/// The sync status of one collab object, used to drive a
+/// "saved / syncing / offline" indicator in the UI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectSyncStatus {
+  /// All local changes reached the server (or the object is local only).
+  Synced,
+  /// Local changes are waiting to be synced, e.g. while offline.
+  Pending { local_changes: u32 },
+  /// Changes are being exchanged with the server right now.
+  Syncing,
+  /// The last sync attempt failed.
+  Error { reason: String },
+}
+
+/// A status transition of one collab object.
+#[derive(Debug, Clone)]
+pub struct SyncStatusUpdate {
+  pub object_id: String,
+  pub status: ObjectSyncStatus,
+}
+
+/// The pending local updates of one collab object that have not reached the
+/// server yet.
+#[derive(Debug, Clone)]
+pub struct PendingSyncInfo {
+  pub object_id: String,
+  pub pending_updates: u32,
+  pub pending_bytes: u64,
+  /// When the oldest pending update was made, in milliseconds since the
+  /// epoch.
+  pub oldest_timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct PendingCounters {
+  updates: u32,
+  bytes: u64,
+  oldest_at: i64,
+}
+
+/// Tracks the sync status of every collab object built by the
+/// [crate::collab_builder::AppFlowyCollabBuilder]. The status is derived from
+/// the collab's [SyncState] stream plus explicitly reported local changes and
+/// sync errors. Transitions are broadcast to subscribers.
+pub struct SyncStatusRegistry {
+  statuses: RwLock<HashMap<String, ObjectSyncStatus>>,
+  /// Local changes per object since the last finished sync.
+  pending_changes: RwLock<HashMap<String, PendingCounters>>,
+  notifier: broadcast::Sender<SyncStatusUpdate>,
+  /// Fires when the last pending object finished syncing, e.g. after
+  /// reconnecting.
+  drain_notifier: broadcast::Sender<()>,
+  /// Keeps the per-collab update observers alive.
+  update_subscriptions: Mutex<HashMap<String, Subscription>>,
+  /// When the object last saw an update or sync transition, in milliseconds
+  /// since the epoch. Used to find quiescent objects, e.g. for compaction.
+  last_activity: RwLock<HashMap<String, i64>>,
+}
+
+impl Default for SyncStatusRegistry {
+  fn default() -> Self {
+    let (notifier, _) = broadcast::channel(1000);
+    let (drain_notifier, _) = broadcast::channel(16);
+    Self {
+      statuses: Default::default(),
+      pending_changes: Default::default(),
+      notifier,
+      drain_notifier,
+      update_subscriptions: Default::default(),
+      last_activity: Default::default(),
+    }
+  }
+}
+
+impl SyncStatusRegistry {
+  /// Returns the current status of the object. Objects without any recorded
+  /// transition are considered synced.
+  pub fn get_status(&self, object_id: &str) -> ObjectSyncStatus {
+    self
+      .statuses
+      .read()
+      .unwrap()
+      .get(object_id)
+      .cloned()
+      .unwrap_or(ObjectSyncStatus::Synced)
+  }
+
+  /// Subscribes to status transitions of all objects.
+  pub fn subscribe(&self) -> broadcast::Receiver<SyncStatusUpdate> {
+    self.notifier.subscribe()
+  }
+
+  /// Records a local change of the object with the byte size of its update.
+  /// While the change has not been synced the object reports
+  /// [ObjectSyncStatus::Pending] with the number of accumulated changes.
+  pub fn record_local_change(&self, object_id: &str, update_size: usize) {
+    self.touch(object_id);
+    let local_changes = {
+      let mut pending_changes = self.pending_changes.write().unwrap();
+      let counters = pending_changes.entry(object_id.to_string()).or_default();
+      counters.updates += 1;
+      counters.bytes += update_size as u64;
+      if counters.oldest_at == 0 {
+        counters.oldest_at = timestamp_ms();
+      }
+      counters.updates
+    };
+    match self.get_status(object_id) {
+      // Keep the syncing/error state, the pending count is reported once the
+      // sync cycle settles.
+      ObjectSyncStatus::Syncing | ObjectSyncStatus::Error { .. } => {},
+      _ => self.set_status(object_id, ObjectSyncStatus::Pending { local_changes }),
+    }
+  }
+
+  /// A snapshot of all objects with pending local updates, oldest first.
+  pub fn pending_queue(&self) -> Vec<PendingSyncInfo> {
+    let mut queue = self
+      .pending_changes
+      .read()
+      .unwrap()
+      .iter()
+      .map(|(object_id, counters)| PendingSyncInfo {
+        object_id: object_id.clone(),
+        pending_updates: counters.updates,
+        pending_bytes: counters.bytes,
+        oldest_timestamp: counters.oldest_at,
+      })
+      .collect::<Vec<_>>();
+    queue.sort_by_key(|info| info.oldest_timestamp);
+    queue
+  }
+
+  /// Subscribes to the signal that fires when the pending queue drained.
+  pub fn subscribe_drain(&self) -> broadcast::Receiver<()> {
+    self.drain_notifier.subscribe()
+  }
+
+  /// Drops the pending bookkeeping of the object without waiting for a sync,
+  /// used when the user discards poisoned updates.
+  pub fn clear_pending(&self, object_id: &str) {
+    self.pending_changes.write().unwrap().remove(object_id);
+    self.set_status(object_id, ObjectSyncStatus::Synced);
+  }
+
+  /// Records a failed sync attempt of the object.
+  pub fn report_sync_error(&self, object_id: &str, reason: String) {
+    self.set_status(object_id, ObjectSyncStatus::Error { reason });
+  }
+
+  /// Folds a [SyncState] transition of the collab into the status.
+  pub fn apply_sync_state(&self, object_id: &str, sync_state: SyncState) {
+    self.touch(object_id);
+    match sync_state {
+      SyncState::InitSyncBegin | SyncState::Syncing => {
+        self.set_status(object_id, ObjectSyncStatus::Syncing);
+      },
+      SyncState::InitSyncEnd | SyncState::SyncFinished => {
+        let drained = {
+          let mut pending_changes = self.pending_changes.write().unwrap();
+          pending_changes.remove(object_id).is_some() && pending_changes.is_empty()
+        };
+        self.set_status(object_id, ObjectSyncStatus::Synced);
+        if drained {
+          trace!("pending sync queue drained");
+          let _ = self.drain_notifier.send(());
+        }
+      },
+    }
+  }
+
+  /// Keeps the doc update observer of the object alive until the object is
+  /// removed from the registry.
+  pub fn retain_update_subscription(&self, object_id: &str, subscription: Subscription) {
+    self
+      .update_subscriptions
+      .lock()
+      .unwrap()
+      .insert(object_id.to_string(), subscription);
+  }
+
+  /// When the object last saw an update or sync transition, `None` when it
+  /// was quiet for the whole session.
+  pub fn last_activity(&self, object_id: &str) -> Option<i64> {
+    self.last_activity.read().unwrap().get(object_id).copied()
+  }
+
+  /// Removes the object from the registry, e.g. when its collab is closed.
+  pub fn remove(&self, object_id: &str) {
+    self.statuses.write().unwrap().remove(object_id);
+    self.pending_changes.write().unwrap().remove(object_id);
+    self.update_subscriptions.lock().unwrap().remove(object_id);
+    self.last_activity.write().unwrap().remove(object_id);
+  }
+
+  fn touch(&self, object_id: &str) {
+    self
+      .last_activity
+      .write()
+      .unwrap()
+      .insert(object_id.to_string(), timestamp_ms());
+  }
+
+  fn set_status(&self, object_id: &str, status: ObjectSyncStatus) {
+    let changed = {
+      let mut statuses = self.statuses.write().unwrap();
+      match statuses.get(object_id) {
+        Some(current) if *current == status => false,
+        _ => {
+          statuses.insert(object_id.to_string(), status.clone());
+          true
+        },
+      }
+    };
+    if changed {
+      trace!("sync status of {}: {:?}", object_id, status);
+      let _ = self.notifier.send(SyncStatusUpdate {
+        object_id: object_id.to_string(),
+        status,
+      });
+    }
+  }
+}
+
+fn timestamp_ms() -> i64 {
+  std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as i64)
+    .unwrap_or(0)
+}
@@ -16,6 +16,10 @@ pub struct AppFlowyDartConfiguration {
   pub device_id: String,
   pub platform: String,
   pub authenticator_type: AuthenticatorType,
+  /// Opt-in key for encrypting the local sqlite storage. Fetched from the OS
+  /// keychain by the host application before the core boots.
+  #[serde(default)]
+  pub local_encryption_key: Option<String>,
   pub(crate) appflowy_cloud_config: AFCloudConfiguration,
   #[serde(default)]
   pub(crate) envs: HashMap<String, String>,
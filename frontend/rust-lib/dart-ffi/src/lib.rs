@@ -143,7 +143,8 @@ pub extern "C" fn init_sdk(_port: i64, data: *mut c_char) -> i64 {
     configuration.device_id,
     configuration.platform,
     DEFAULT_NAME.to_string(),
-  );
+  )
+  .with_local_encryption_key(configuration.local_encryption_key);
 
   if let Some(core) = &*DART_APPFLOWY_CORE.core.write().unwrap() {
     core.close_db();
@@ -78,7 +78,7 @@ async fn perform_search_with_workspace(
     .unwrap();
 
   let stream = search_handler
-    .perform_search(query.to_string(), workspace_id)
+    .perform_search(query.to_string(), None, workspace_id)
     .await;
 
   stream.collect().await
@@ -93,7 +93,7 @@ async fn perform_search(
 
   test
     .search_manager
-    .perform_search_with_sink(query.to_string(), sink.clone(), search_id)
+    .perform_search_with_sink(query.to_string(), None, sink.clone(), search_id)
     .await;
 
   // Parse the collected results
@@ -5,7 +5,7 @@ use flowy_error::{FlowyError, FlowyResult};
 use flowy_sqlite::upsert::excluded;
 use flowy_sqlite::{
   DBConnection, ExpressionMethods, Identifiable, Insertable, OptionalExtension, QueryResult,
-  Queryable, diesel, insert_into,
+  Queryable, TextExpressionMethods, diesel, insert_into,
   query_dsl::*,
   schema::{chat_message_table, chat_message_table::dsl},
 };
@@ -194,6 +194,29 @@ pub fn select_chat_messages(
   })
 }
 
+/// Case-insensitive substring search over message content across every chat,
+/// newest first.
+pub fn search_chat_messages(
+  mut conn: DBConnection,
+  query: &str,
+  limit_val: u64,
+) -> QueryResult<Vec<ChatMessageTable>> {
+  let pattern = format!("%{}%", query);
+  dsl::chat_message_table
+    .filter(chat_message_table::content.like(pattern))
+    .order((
+      chat_message_table::created_at.desc(),
+      chat_message_table::message_id.desc(),
+    ))
+    .limit(limit_val as i64)
+    .load::<ChatMessageTable>(&mut *conn)
+}
+
+pub fn delete_chat_messages(mut conn: DBConnection, chat_id_val: &str) -> QueryResult<usize> {
+  diesel::delete(dsl::chat_message_table.filter(chat_message_table::chat_id.eq(chat_id_val)))
+    .execute(&mut *conn)
+}
+
 pub fn total_message_count(mut conn: DBConnection, chat_id_val: &str) -> QueryResult<i64> {
   dsl::chat_message_table
     .filter(chat_message_table::chat_id.eq(chat_id_val))
@@ -22,6 +22,7 @@ pub struct ChatTable {
   pub rag_ids: Option<String>,
   pub is_sync: bool,
   pub summary: String,
+  pub name: String,
 }
 
 impl ChatTable {
@@ -36,6 +37,7 @@ impl ChatTable {
       rag_ids,
       is_sync,
       summary: "".to_string(),
+      name: "".to_string(),
     }
   }
 }
@@ -70,6 +72,7 @@ pub struct ChatTableChangeset {
   pub rag_ids: Option<String>,
   pub is_sync: Option<bool>,
   pub summary: Option<String>,
+  pub name: Option<String>,
 }
 
 impl ChatTableChangeset {
@@ -80,6 +83,7 @@ impl ChatTableChangeset {
       rag_ids: None,
       is_sync: None,
       summary: Some(summary),
+      name: None,
     }
   }
 
@@ -91,6 +95,18 @@ impl ChatTableChangeset {
       rag_ids,
       is_sync: None,
       summary: None,
+      name: None,
+    }
+  }
+
+  pub fn name(chat_id: String, name: String) -> Self {
+    Self {
+      chat_id,
+      metadata: None,
+      rag_ids: None,
+      is_sync: None,
+      summary: None,
+      name: Some(name),
     }
   }
 }
@@ -154,6 +170,7 @@ pub fn update_chat(mut conn: DBConnection, changeset: ChatTableChangeset) -> Que
       rag_ids: changeset.rag_ids,
       is_sync: changeset.is_sync.unwrap_or(false),
       summary: changeset.summary.unwrap_or_default(),
+      name: changeset.name.unwrap_or_default(),
     };
 
     // Insert the new row
@@ -173,6 +190,13 @@ pub fn update_chat_is_sync(
     .execute(&mut *conn)
 }
 
+/// All chat sessions, newest first.
+pub fn select_all_chats(mut conn: DBConnection) -> QueryResult<Vec<ChatTable>> {
+  dsl::chat_table
+    .order(chat_table::created_at.desc())
+    .load::<ChatTable>(&mut *conn)
+}
+
 pub fn select_chat(mut conn: DBConnection, chat_id_val: &str) -> QueryResult<ChatTable> {
   let row = dsl::chat_table
     .filter(chat_table::chat_id.eq(chat_id_val))
@@ -210,7 +234,6 @@ pub fn select_chat_metadata(
   Ok(deserialize_chat_metadata(&metadata_str))
 }
 
-#[allow(dead_code)]
 pub fn delete_chat(mut conn: DBConnection, chat_id_val: &str) -> QueryResult<usize> {
   diesel::delete(dsl::chat_table.filter(chat_table::chat_id.eq(chat_id_val))).execute(&mut *conn)
 }
@@ -3,9 +3,11 @@ mod chat_sql;
 mod collab_metadata_sql;
 mod collab_sql;
 mod local_model_sql;
+mod prompt_template_sql;
 
 pub use chat_message_sql::*;
 pub use chat_sql::*;
 pub use collab_metadata_sql::*;
 pub use collab_sql::*;
 pub use local_model_sql::*;
+pub use prompt_template_sql::*;
@@ -0,0 +1,63 @@
+use flowy_sqlite::upsert::excluded;
+use flowy_sqlite::{
+  DBConnection, ExpressionMethods, Identifiable, Insertable, OptionalExtension, QueryResult,
+  Queryable, diesel, insert_into,
+  query_dsl::*,
+  schema::{ai_prompt_template_table, ai_prompt_template_table::dsl},
+};
+
+/// A reusable prompt template. `template` may reference `{{selection}}` and
+/// `{{page}}`, substituted with the caller's context when the template runs.
+#[derive(Queryable, Insertable, Identifiable, Debug, Clone)]
+#[diesel(table_name = ai_prompt_template_table)]
+pub struct PromptTemplateTable {
+  pub id: String,
+  pub name: String,
+  pub description: String,
+  pub template: String,
+  pub preferred_model: String,
+  pub temperature: Option<f64>,
+  pub created_at: i64,
+  pub updated_at: i64,
+}
+
+pub fn upsert_prompt_template(
+  mut conn: DBConnection,
+  row: &PromptTemplateTable,
+) -> QueryResult<usize> {
+  insert_into(ai_prompt_template_table::table)
+    .values(row)
+    .on_conflict(ai_prompt_template_table::id)
+    .do_update()
+    .set((
+      ai_prompt_template_table::name.eq(excluded(ai_prompt_template_table::name)),
+      ai_prompt_template_table::description.eq(excluded(ai_prompt_template_table::description)),
+      ai_prompt_template_table::template.eq(excluded(ai_prompt_template_table::template)),
+      ai_prompt_template_table::preferred_model
+        .eq(excluded(ai_prompt_template_table::preferred_model)),
+      ai_prompt_template_table::temperature.eq(excluded(ai_prompt_template_table::temperature)),
+      ai_prompt_template_table::updated_at.eq(excluded(ai_prompt_template_table::updated_at)),
+    ))
+    .execute(&mut *conn)
+}
+
+pub fn select_prompt_templates(mut conn: DBConnection) -> QueryResult<Vec<PromptTemplateTable>> {
+  dsl::ai_prompt_template_table
+    .order(ai_prompt_template_table::name.asc())
+    .load::<PromptTemplateTable>(&mut *conn)
+}
+
+pub fn select_prompt_template(
+  mut conn: DBConnection,
+  template_id: &str,
+) -> QueryResult<Option<PromptTemplateTable>> {
+  dsl::ai_prompt_template_table
+    .filter(ai_prompt_template_table::id.eq(template_id))
+    .first::<PromptTemplateTable>(&mut *conn)
+    .optional()
+}
+
+pub fn delete_prompt_template(mut conn: DBConnection, template_id: &str) -> QueryResult<usize> {
+  diesel::delete(dsl::ai_prompt_template_table.filter(ai_prompt_template_table::id.eq(template_id)))
+    .execute(&mut *conn)
+}
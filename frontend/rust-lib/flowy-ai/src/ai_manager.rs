@@ -1,26 +1,36 @@
 use crate::chat::Chat;
 use crate::entities::{
-  AIModelPB, ChatInfoPB, ChatMessageListPB, ChatMessagePB, ChatSettingsPB,
-  CustomPromptDatabaseConfigurationPB, FilePB, ModelSelectionPB, PredefinedFormatPB,
-  RepeatedRelatedQuestionPB, StreamMessageParams,
+  AIModelPB, ChatInfoPB, ChatMarkdownExportPB, ChatMessageListPB, ChatMessagePB,
+  ChatSearchResultPB, ChatSessionPB, ChatSettingsPB, CustomPromptDatabaseConfigurationPB, FilePB,
+  ModelSelectionPB, PredefinedFormatPB, PromptTemplatePB, RepeatedRelatedQuestionPB,
+  StreamMessageParams,
 };
+use crate::anthropic::AnthropicController;
 use crate::local_ai::controller::{LocalAIController, LocalAISetting};
 use crate::middleware::chat_service_mw::ChatServiceMiddleware;
 use flowy_ai_pub::persistence::{
-  ChatTableChangeset, select_chat_metadata, select_chat_rag_ids, select_chat_summary, update_chat,
+  ChatTableChangeset, PromptTemplateTable, delete_chat as delete_chat_row, delete_chat_messages,
+  delete_prompt_template, deserialize_rag_ids, search_chat_messages, select_all_chats, select_chat,
+  select_chat_messages, select_chat_metadata, select_chat_rag_ids, select_chat_summary,
+  select_prompt_template, select_prompt_templates, update_chat, upsert_prompt_template,
 };
 use std::collections::HashMap;
 
 use dashmap::DashMap;
-use flowy_ai_pub::cloud::{AIModel, ChatCloudService, ChatSettings, UpdateChatParams};
+use flowy_ai_pub::cloud::{
+  AIModel, ChatCloudService, ChatSettings, MessageCursor, UpdateChatParams,
+};
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use flowy_sqlite::kv::KVStorePreferences;
 
 use crate::model_select::{
-  GLOBAL_ACTIVE_MODEL_KEY, LocalAiSource, LocalModelStorageImpl, ModelSelectionControl,
-  ServerAiSource, ServerModelStorageImpl, SourceKey,
+  AnthropicModelSource, GLOBAL_ACTIVE_MODEL_KEY, LocalAiSource, LocalModelStorageImpl,
+  ModelSelectionControl, ServerAiSource, ServerModelStorageImpl, SourceKey,
 };
 use crate::notification::{ChatNotification, chat_notification_builder};
+use crate::proofread::ProofreadController;
+use crate::transcription::TranscriptionController;
+use crate::tools::AIToolController;
 use flowy_ai_pub::persistence::{
   AFCollabMetadata, batch_insert_collab_metadata, batch_select_collab_metadata,
 };
@@ -28,6 +38,7 @@ use flowy_ai_pub::user_service::AIUserService;
 use flowy_sqlite::DBConnection;
 use flowy_storage_pub::storage::StorageService;
 use lib_infra::async_trait::async_trait;
+use lib_infra::util::timestamp;
 use serde_json::json;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -61,6 +72,10 @@ pub struct AIManager {
   pub external_service: Arc<dyn AIExternalService>,
   chats: Arc<DashMap<Uuid, Arc<Chat>>>,
   pub local_ai: Arc<LocalAIController>,
+  pub anthropic: Arc<AnthropicController>,
+  pub tools: Arc<AIToolController>,
+  pub proofread: Arc<ProofreadController>,
+  pub transcription: Arc<TranscriptionController>,
   pub store_preferences: Arc<KVStorePreferences>,
   model_control: Mutex<ModelSelectionControl>,
 }
@@ -81,22 +96,31 @@ impl AIManager {
   ) -> AIManager {
     let user_service = Arc::new(user_service);
     let external_service = Arc::new(query_service);
+    let anthropic = Arc::new(AnthropicController::new(Arc::downgrade(&store_preferences)));
     let cloud_service_wm = Arc::new(ChatServiceMiddleware::new(
       user_service.clone(),
       chat_cloud_service,
       local_ai.clone(),
+      anthropic.clone(),
       storage_service,
     ));
     let mut model_control = ModelSelectionControl::new();
     model_control.set_local_storage(LocalModelStorageImpl(store_preferences.clone()));
     model_control.set_server_storage(ServerModelStorageImpl(cloud_service_wm.clone()));
     model_control.add_source(Box::new(ServerAiSource::new(cloud_service_wm.clone())));
+    model_control.add_source(Box::new(AnthropicModelSource::new(anthropic.clone())));
 
     Self {
       cloud_service_wm,
       user_service,
       chats: Arc::new(DashMap::new()),
+      transcription: Arc::new(TranscriptionController::new(local_ai.clone())),
       local_ai,
+      anthropic,
+      tools: Arc::new(AIToolController::new()),
+      proofread: Arc::new(ProofreadController::new(Arc::downgrade(
+        &store_preferences,
+      ))),
       external_service,
       store_preferences,
       model_control: Mutex::new(model_control),
@@ -268,9 +292,191 @@ impl AIManager {
       chat.close();
       self.local_ai.close_chat(chat_id);
     }
+
+    // Purge the locally persisted session and its messages.
+    let uid = self.user_service.user_id()?;
+    let chat_id = chat_id.to_string();
+    delete_chat_messages(self.user_service.sqlite_connection(uid)?, &chat_id)?;
+    delete_chat_row(self.user_service.sqlite_connection(uid)?, &chat_id)?;
     Ok(())
   }
 
+  /// Locally persisted chat sessions, newest first.
+  pub async fn get_chat_sessions(&self) -> FlowyResult<Vec<ChatSessionPB>> {
+    let uid = self.user_service.user_id()?;
+    let chats = select_all_chats(self.user_service.sqlite_connection(uid)?)?;
+    let mut sessions = Vec::with_capacity(chats.len());
+    for chat in chats {
+      let model = self.get_active_model(&chat.chat_id).await;
+      sessions.push(ChatSessionPB {
+        rag_ids: deserialize_rag_ids(&chat.rag_ids),
+        chat_id: chat.chat_id,
+        name: chat.name,
+        summary: chat.summary,
+        created_at: chat.created_at,
+        model: model.name,
+      });
+    }
+    Ok(sessions)
+  }
+
+  pub async fn search_chat_messages(
+    &self,
+    query: &str,
+    limit: u64,
+  ) -> FlowyResult<Vec<ChatSearchResultPB>> {
+    let uid = self.user_service.user_id()?;
+    let rows = search_chat_messages(self.user_service.sqlite_connection(uid)?, query, limit)?;
+    Ok(
+      rows
+        .into_iter()
+        .map(|row| ChatSearchResultPB {
+          chat_id: row.chat_id,
+          message_id: row.message_id,
+          content: row.content,
+          created_at: row.created_at,
+          author_type: row.author_type,
+        })
+        .collect(),
+    )
+  }
+
+  pub async fn rename_chat(&self, chat_id: &Uuid, name: &str) -> FlowyResult<()> {
+    let uid = self.user_service.user_id()?;
+    update_chat(
+      self.user_service.sqlite_connection(uid)?,
+      ChatTableChangeset::name(chat_id.to_string(), name.to_string()),
+    )?;
+    Ok(())
+  }
+
+  /// Render the whole conversation as markdown. The caller turns the result
+  /// into a new document view; this keeps flowy-ai free of folder/document
+  /// dependencies.
+  pub async fn export_chat_to_markdown(&self, chat_id: &Uuid) -> FlowyResult<ChatMarkdownExportPB> {
+    let uid = self.user_service.user_id()?;
+    let chat = select_chat(
+      self.user_service.sqlite_connection(uid)?,
+      &chat_id.to_string(),
+    )?;
+    // A limit of zero loads the whole conversation.
+    let mut messages = select_chat_messages(
+      self.user_service.sqlite_connection(uid)?,
+      &chat_id.to_string(),
+      0,
+      MessageCursor::NextBack,
+    )?
+    .messages;
+    messages.reverse();
+
+    let title = if !chat.name.is_empty() {
+      chat.name
+    } else if !chat.summary.is_empty() {
+      chat.summary
+    } else {
+      format!("Chat {}", chat_id)
+    };
+
+    let mut markdown = format!("# {}\n", title);
+    for message in messages {
+      let author = match message.author_type {
+        1 => "User",
+        2 => "System",
+        3 => "AI",
+        _ => "Unknown",
+      };
+      markdown.push_str(&format!("\n## {}\n\n{}\n", author, message.content));
+    }
+
+    Ok(ChatMarkdownExportPB {
+      file_name: format!("{}.md", title),
+      markdown,
+    })
+  }
+
+  pub fn get_prompt_templates(&self) -> FlowyResult<Vec<PromptTemplatePB>> {
+    let uid = self.user_service.user_id()?;
+    let rows = select_prompt_templates(self.user_service.sqlite_connection(uid)?)?;
+    Ok(rows.into_iter().map(PromptTemplatePB::from).collect())
+  }
+
+  /// Create or update a template. An empty id creates a new template; the
+  /// stored row is returned so the caller sees the generated id.
+  pub fn upsert_prompt_template(&self, data: PromptTemplatePB) -> FlowyResult<PromptTemplatePB> {
+    let uid = self.user_service.user_id()?;
+    let now = timestamp();
+    let id = if data.id.is_empty() {
+      Uuid::new_v4().to_string()
+    } else {
+      data.id.clone()
+    };
+    let row = PromptTemplateTable {
+      id,
+      name: data.name,
+      description: data.description,
+      template: data.template,
+      preferred_model: data.preferred_model,
+      temperature: (data.temperature > 0.0).then_some(data.temperature),
+      created_at: now,
+      updated_at: now,
+    };
+    upsert_prompt_template(self.user_service.sqlite_connection(uid)?, &row)?;
+    Ok(PromptTemplatePB::from(row))
+  }
+
+  pub fn delete_prompt_template(&self, template_id: &str) -> FlowyResult<()> {
+    let uid = self.user_service.user_id()?;
+    delete_prompt_template(self.user_service.sqlite_connection(uid)?, template_id)?;
+    Ok(())
+  }
+
+  /// Load a template, substitute the `{{selection}}` and `{{page}}` variables
+  /// and return the rendered prompt together with the model it should run on.
+  /// The template's preferred model is used when it is still available,
+  /// otherwise the active model for `object_id` applies.
+  pub async fn render_prompt_template(
+    &self,
+    template_id: &str,
+    object_id: &str,
+    selection: &str,
+    page: &str,
+  ) -> FlowyResult<(String, AIModel)> {
+    let uid = self.user_service.user_id()?;
+    let template = select_prompt_template(self.user_service.sqlite_connection(uid)?, template_id)?
+      .ok_or_else(|| {
+        FlowyError::record_not_found()
+          .with_context(format!("Prompt template not found: {}", template_id))
+      })?;
+
+    let rendered = template
+      .template
+      .replace("{{selection}}", selection)
+      .replace("{{page}}", page);
+
+    let mut model = self.get_active_model(object_id).await;
+    if !template.preferred_model.is_empty() && template.preferred_model != model.name {
+      let workspace_id = self.user_service.workspace_id()?;
+      let preferred = self
+        .model_control
+        .lock()
+        .await
+        .get_models(&workspace_id)
+        .await
+        .into_iter()
+        .find(|m| m.name == template.preferred_model);
+      if let Some(preferred) = preferred {
+        model = preferred;
+      } else {
+        warn!(
+          "[AI Prompt Template] preferred model {} is unavailable, falling back to {}",
+          template.preferred_model, model.name
+        );
+      }
+    }
+
+    Ok((rendered, model))
+  }
+
   pub async fn get_chat_info(&self, chat_id: &str) -> FlowyResult<ChatInfoPB> {
     let uid = self.user_service.user_id()?;
     let mut conn = self.user_service.sqlite_connection(uid)?;
@@ -0,0 +1,232 @@
+use async_stream::try_stream;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use futures::stream::BoxStream;
+use serde::Deserialize;
+use serde_json::json;
+use tracing::trace;
+
+pub const ANTHROPIC_API_URL: &str = "https://api.anthropic.com";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+const MAX_TOKENS: u32 = 4096;
+
+/// A single turn sent to the Messages API. The API only accepts `user` and
+/// `assistant` roles; system instructions travel in a separate field.
+#[derive(Debug, Clone)]
+pub struct AnthropicMessage {
+  pub role: &'static str,
+  pub content: String,
+}
+
+impl AnthropicMessage {
+  pub fn user(content: impl Into<String>) -> Self {
+    Self {
+      role: "user",
+      content: content.into(),
+    }
+  }
+
+  pub fn assistant(content: impl Into<String>) -> Self {
+    Self {
+      role: "assistant",
+      content: content.into(),
+    }
+  }
+}
+
+/// Token usage accumulated over one streamed response.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenUsage {
+  pub input_tokens: u64,
+  pub output_tokens: u64,
+}
+
+pub enum AnthropicStreamEvent {
+  /// A chunk of answer text.
+  TextDelta(String),
+  /// Emitted once, after the final text delta.
+  Usage(TokenUsage),
+}
+
+pub struct AnthropicClient {
+  http: reqwest::Client,
+  base_url: String,
+  api_key: String,
+}
+
+impl AnthropicClient {
+  pub fn new(base_url: String, api_key: String) -> Self {
+    Self {
+      http: reqwest::Client::new(),
+      base_url,
+      api_key,
+    }
+  }
+
+  /// Stream a response from the Messages API as SSE events. Text deltas are
+  /// yielded as they arrive, followed by a single [AnthropicStreamEvent::Usage]
+  /// with the token counts the server reported.
+  pub async fn stream_messages(
+    &self,
+    model: &str,
+    system: String,
+    messages: Vec<AnthropicMessage>,
+  ) -> FlowyResult<BoxStream<'static, FlowyResult<AnthropicStreamEvent>>> {
+    let body = json!({
+      "model": model,
+      "max_tokens": MAX_TOKENS,
+      "stream": true,
+      "system": system,
+      "messages": messages
+        .iter()
+        .map(|message| json!({"role": message.role, "content": message.content}))
+        .collect::<Vec<_>>(),
+    });
+
+    let url = format!("{}/v1/messages", self.base_url.trim_end_matches('/'));
+    let mut response = self
+      .http
+      .post(&url)
+      .header("x-api-key", &self.api_key)
+      .header("anthropic-version", ANTHROPIC_VERSION)
+      .json(&body)
+      .send()
+      .await
+      .map_err(|err| {
+        FlowyError::http().with_context(format!("Failed to connect to Anthropic: {}", err))
+      })?;
+
+    let status = response.status();
+    if !status.is_success() {
+      let body = response.text().await.unwrap_or_default();
+      return Err(map_api_error(status, &body));
+    }
+
+    let stream = try_stream! {
+      let mut buffer = String::new();
+      let mut usage = TokenUsage::default();
+      let mut stopped = false;
+      while !stopped {
+        let Some(chunk) = response.chunk().await.map_err(|err| {
+          FlowyError::http().with_context(format!("Anthropic stream interrupted: {}", err))
+        })? else {
+          break;
+        };
+        buffer.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(newline) = buffer.find('\n') {
+          let line = buffer
+            .drain(..=newline)
+            .collect::<String>()
+            .trim_end()
+            .to_string();
+          let Some(data) = line.strip_prefix("data:") else {
+            // Ignore `event:` lines and keep-alive comments; the payload type
+            // is repeated inside the data object.
+            continue;
+          };
+          let event = match serde_json::from_str::<SseData>(data.trim()) {
+            Ok(event) => event,
+            Err(err) => {
+              trace!("[Anthropic] skip unparseable event: {}: {}", data, err);
+              continue;
+            },
+          };
+          match event.ty.as_str() {
+            "message_start" => {
+              if let Some(message) = event.message {
+                usage.input_tokens = message.usage.input_tokens;
+              }
+            },
+            "content_block_delta" => {
+              if let Some(text) = event.delta.and_then(|delta| delta.text) {
+                yield AnthropicStreamEvent::TextDelta(text);
+              }
+            },
+            "message_delta" => {
+              if let Some(delta_usage) = event.usage {
+                usage.output_tokens = delta_usage.output_tokens;
+              }
+            },
+            "message_stop" => {
+              yield AnthropicStreamEvent::Usage(usage);
+              stopped = true;
+              break;
+            },
+            "error" => {
+              let error = event.error.unwrap_or_default();
+              Err(
+                FlowyError::http()
+                  .with_context(format!("Anthropic error: {}: {}", error.ty, error.message)),
+              )?;
+            },
+            // ping, content_block_start, content_block_stop
+            _ => {},
+          }
+        }
+      }
+    };
+    Ok(Box::pin(stream))
+  }
+}
+
+/// Map a non-success Messages API response to a [FlowyError], preserving the
+/// server's error message when the body parses.
+fn map_api_error(status: reqwest::StatusCode, body: &str) -> FlowyError {
+  let message = serde_json::from_str::<ApiErrorResponse>(body)
+    .map(|response| response.error.message)
+    .unwrap_or_else(|_| body.to_string());
+  match status.as_u16() {
+    401 | 403 => FlowyError::unauthorized()
+      .with_context(format!("Anthropic rejected the API key: {}", message)),
+    429 => FlowyError::new(ErrorCode::AIResponseLimitExceeded, message),
+    500..=599 => FlowyError::server_error()
+      .with_context(format!("Anthropic is unavailable: {}", message)),
+    _ => FlowyError::http().with_context(format!("Anthropic error ({}): {}", status, message)),
+  }
+}
+
+#[derive(Deserialize)]
+struct SseData {
+  #[serde(rename = "type")]
+  ty: String,
+  #[serde(default)]
+  message: Option<SseMessage>,
+  #[serde(default)]
+  delta: Option<SseDelta>,
+  #[serde(default)]
+  usage: Option<SseUsage>,
+  #[serde(default)]
+  error: Option<ApiError>,
+}
+
+#[derive(Deserialize)]
+struct SseMessage {
+  #[serde(default)]
+  usage: SseUsage,
+}
+
+#[derive(Deserialize, Default)]
+struct SseDelta {
+  #[serde(default)]
+  text: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct SseUsage {
+  #[serde(default)]
+  input_tokens: u64,
+  #[serde(default)]
+  output_tokens: u64,
+}
+
+#[derive(Deserialize)]
+struct ApiErrorResponse {
+  error: ApiError,
+}
+
+#[derive(Deserialize, Default)]
+struct ApiError {
+  #[serde(rename = "type", default)]
+  ty: String,
+  #[serde(default)]
+  message: String,
+}
@@ -0,0 +1,225 @@
+pub mod client;
+
+use crate::anthropic::client::{
+  ANTHROPIC_API_URL, AnthropicClient, AnthropicMessage, AnthropicStreamEvent,
+};
+use crate::local_ai::prompt::format_instruction;
+use flowy_ai_pub::cloud::{
+  AIModel, CompleteTextParams, CompletionStreamValue, CompletionType, QuestionStreamValue,
+  ResponseFormat, StreamAnswer, StreamComplete,
+};
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::kv::KVStorePreferences;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::{Arc, Weak};
+use tracing::info;
+
+const ANTHROPIC_SETTING_KEY: &str = "appflowy_anthropic_setting:v1";
+
+fn anthropic_setting_key(workspace_id: &str) -> String {
+  format!("{}:{}", ANTHROPIC_SETTING_KEY, workspace_id)
+}
+
+/// Per-workspace configuration for the Anthropic (Claude) provider. The
+/// provider is considered enabled for a workspace once an API key is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnthropicSetting {
+  pub api_base_url: String,
+  pub api_key: String,
+  pub default_model: String,
+  /// Claude models offered in the model selection UI.
+  pub model_names: Vec<String>,
+}
+
+impl Default for AnthropicSetting {
+  fn default() -> Self {
+    Self {
+      api_base_url: ANTHROPIC_API_URL.to_string(),
+      api_key: String::new(),
+      default_model: "claude-sonnet-4-0".to_string(),
+      model_names: vec![
+        "claude-opus-4-1".to_string(),
+        "claude-sonnet-4-0".to_string(),
+        "claude-3-5-haiku-latest".to_string(),
+      ],
+    }
+  }
+}
+
+pub struct AnthropicController {
+  store_preferences: Weak<KVStorePreferences>,
+}
+
+impl AnthropicController {
+  pub fn new(store_preferences: Weak<KVStorePreferences>) -> Self {
+    Self { store_preferences }
+  }
+
+  fn upgrade_store_preferences(&self) -> FlowyResult<Arc<KVStorePreferences>> {
+    self
+      .store_preferences
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Store preferences is dropped"))
+  }
+
+  pub fn get_setting(&self, workspace_id: &str) -> AnthropicSetting {
+    self
+      .upgrade_store_preferences()
+      .ok()
+      .and_then(|store| store.get_object::<AnthropicSetting>(&anthropic_setting_key(workspace_id)))
+      .unwrap_or_default()
+  }
+
+  pub fn update_setting(&self, workspace_id: &str, setting: AnthropicSetting) -> FlowyResult<()> {
+    info!(
+      "[Anthropic] update setting for workspace {}, default model: {}",
+      workspace_id, setting.default_model
+    );
+    let store_preferences = self.upgrade_store_preferences()?;
+    store_preferences.set_object(&anthropic_setting_key(workspace_id), &setting)?;
+    Ok(())
+  }
+
+  pub fn is_enabled_on_workspace(&self, workspace_id: &str) -> bool {
+    !self.get_setting(workspace_id).api_key.is_empty()
+  }
+
+  /// Whether the given model should be served by this provider. Claude models
+  /// are namespaced by their `claude` prefix, so no extra bookkeeping is
+  /// needed to tell them apart from server models.
+  pub fn owns_model(&self, workspace_id: &str, model_name: &str) -> bool {
+    model_name.starts_with("claude") && self.is_enabled_on_workspace(workspace_id)
+  }
+
+  pub fn available_models(&self, workspace_id: &str) -> Vec<AIModel> {
+    let setting = self.get_setting(workspace_id);
+    if setting.api_key.is_empty() {
+      return vec![];
+    }
+    setting
+      .model_names
+      .into_iter()
+      .map(|name| AIModel::server(name, "Anthropic".to_string()))
+      .collect()
+  }
+
+  fn client(&self, workspace_id: &str) -> FlowyResult<AnthropicClient> {
+    let setting = self.get_setting(workspace_id);
+    if setting.api_key.is_empty() {
+      return Err(FlowyError::unauthorized().with_context("Anthropic API key is not configured"));
+    }
+    Ok(AnthropicClient::new(setting.api_base_url, setting.api_key))
+  }
+
+  /// Stream an answer for a chat question. `messages` is the conversation so
+  /// far in chronological order, ending with the question. Token usage is
+  /// reported as a final metadata value once the answer completes.
+  pub async fn stream_answer(
+    &self,
+    workspace_id: &str,
+    model: &str,
+    format: &ResponseFormat,
+    messages: Vec<AnthropicMessage>,
+  ) -> Result<StreamAnswer, FlowyError> {
+    let client = self.client(workspace_id)?;
+    let system = format_instruction(format).to_string();
+    let stream = client.stream_messages(model, system, messages).await?;
+    let stream = stream.map(|event| {
+      event.map(|event| match event {
+        AnthropicStreamEvent::TextDelta(value) => QuestionStreamValue::Answer { value },
+        AnthropicStreamEvent::Usage(usage) => QuestionStreamValue::Metadata {
+          value: json!({
+            "token_usage": {
+              "input_tokens": usage.input_tokens,
+              "output_tokens": usage.output_tokens,
+            }
+          }),
+        },
+      })
+    });
+    Ok(stream.boxed())
+  }
+
+  /// Stream a writing-tool completion. The completion type is translated into
+  /// a system instruction; the selected text travels as the user message.
+  pub async fn stream_complete(
+    &self,
+    workspace_id: &str,
+    model: &str,
+    params: CompleteTextParams,
+  ) -> Result<StreamComplete, FlowyError> {
+    let client = self.client(workspace_id)?;
+    let completion_type = params.completion_type.unwrap_or(CompletionType::AskAI);
+    let custom_prompt = params
+      .metadata
+      .as_ref()
+      .and_then(|metadata| metadata.custom_prompt.as_ref())
+      .map(|prompt| prompt.system.clone());
+    let system = completion_instruction(&completion_type, custom_prompt, &params.format);
+
+    let mut messages = vec![];
+    if let Some(history) = params
+      .metadata
+      .as_ref()
+      .and_then(|metadata| metadata.completion_history.as_ref())
+    {
+      for message in history {
+        match message.role.as_str() {
+          "human" | "user" => messages.push(AnthropicMessage::user(message.content.clone())),
+          "ai" | "assistant" => messages.push(AnthropicMessage::assistant(message.content.clone())),
+          _ => {},
+        }
+      }
+    }
+    messages.push(AnthropicMessage::user(params.text));
+
+    let stream = client.stream_messages(model, system, messages).await?;
+    let stream = stream.filter_map(|event| async move {
+      match event {
+        Ok(AnthropicStreamEvent::TextDelta(value)) => {
+          Some(Ok(CompletionStreamValue::Answer { value }))
+        },
+        // Completion streams have no metadata channel to carry token usage.
+        Ok(AnthropicStreamEvent::Usage(_)) => None,
+        Err(err) => Some(Err(err)),
+      }
+    });
+    Ok(stream.boxed())
+  }
+}
+
+fn completion_instruction(
+  completion_type: &CompletionType,
+  custom_prompt: Option<String>,
+  format: &ResponseFormat,
+) -> String {
+  let task = match completion_type {
+    CompletionType::CustomPrompt => custom_prompt.unwrap_or_default(),
+    CompletionType::ImproveWriting => {
+      "Improve the writing of the provided text while preserving its meaning. Return only the rewritten text.".to_string()
+    },
+    CompletionType::SpellingAndGrammar => {
+      "Fix the spelling and grammar of the provided text without changing its meaning. Return only the corrected text.".to_string()
+    },
+    CompletionType::MakeShorter => {
+      "Rewrite the provided text to be more concise while keeping the key information. Return only the rewritten text.".to_string()
+    },
+    CompletionType::MakeLonger => {
+      "Expand the provided text with more detail while keeping its tone. Return only the rewritten text.".to_string()
+    },
+    CompletionType::ContinueWriting => {
+      "Continue writing from the provided text, matching its tone and style. Return only the continuation.".to_string()
+    },
+    CompletionType::Explain => {
+      "Explain the provided text in plain language.".to_string()
+    },
+    CompletionType::AskAI => String::new(),
+  };
+  if task.is_empty() {
+    format_instruction(format).to_string()
+  } else {
+    format!("{}\n\n{}", task, format_instruction(format))
+  }
+}
@@ -4,8 +4,10 @@ use crate::entities::{
 };
 use crate::middleware::chat_service_mw::ChatServiceMiddleware;
 use crate::notification::{ChatNotification, chat_notification_builder};
-use crate::stream_message::{AIFollowUpData, StreamMessage};
+use crate::stream_message::{AIFollowUpData, AITokenUsageData, StreamMessage};
+use crate::token_usage::{extract_token_usage, token_usage_for_model};
 use allo_isolate::Isolate;
+use arc_swap::ArcSwap;
 use flowy_ai_pub::cloud::{
   AIModel, ChatCloudService, ChatMessage, MessageCursor, QuestionStreamValue, ResponseFormat,
 };
@@ -18,10 +20,13 @@ use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use flowy_sqlite::DBConnection;
 use futures::{SinkExt, StreamExt};
 use lib_infra::isolate_stream::IsolateSink;
+use serde_json::{Value, json};
 use std::path::PathBuf;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicI64};
+use std::sync::atomic::AtomicI64;
+use tokio::select;
 use tokio::sync::{Mutex, RwLock};
+use tokio_util::sync::CancellationToken;
 use tracing::{error, instrument, trace};
 use uuid::Uuid;
 
@@ -38,7 +43,9 @@ pub struct Chat {
   chat_service: Arc<ChatServiceMiddleware>,
   prev_message_state: Arc<RwLock<PrevMessageState>>,
   latest_message_id: Arc<AtomicI64>,
-  stop_stream: Arc<AtomicBool>,
+  /// Cancels the in-flight answer stream. Replaced with a fresh token every
+  /// time a new stream starts, so cancelling never affects a later request.
+  cancel_token: ArcSwap<CancellationToken>,
   stream_buffer: Arc<Mutex<StringBuffer>>,
 }
 
@@ -56,7 +63,7 @@ impl Chat {
       user_service,
       prev_message_state: Arc::new(RwLock::new(PrevMessageState::HasMore)),
       latest_message_id: Default::default(),
-      stop_stream: Arc::new(AtomicBool::new(false)),
+      cancel_token: ArcSwap::from_pointee(CancellationToken::new()),
       stream_buffer: Arc::new(Mutex::new(StringBuffer::default())),
     }
   }
@@ -64,9 +71,7 @@ impl Chat {
   pub fn close(&self) {}
 
   pub async fn stop_stream_message(&self) {
-    self
-      .stop_stream
-      .store(true, std::sync::atomic::Ordering::SeqCst);
+    self.cancel_token.load().cancel();
   }
 
   #[instrument(level = "info", skip_all, err)]
@@ -82,8 +87,8 @@ impl Chat {
 
     // clear
     self
-      .stop_stream
-      .store(false, std::sync::atomic::Ordering::SeqCst);
+      .cancel_token
+      .store(Arc::new(CancellationToken::new()));
     self.stream_buffer.lock().await.clear();
 
     let mut question_sink = IsolateSink::new(Isolate::new(params.question_stream_port));
@@ -142,8 +147,8 @@ impl Chat {
 
     // clear
     self
-      .stop_stream
-      .store(false, std::sync::atomic::Ordering::SeqCst);
+      .cancel_token
+      .store(Arc::new(CancellationToken::new()));
     self.stream_buffer.lock().await.clear();
 
     let format = format.map(Into::into).unwrap_or_default();
@@ -175,78 +180,94 @@ impl Chat {
     format: ResponseFormat,
     ai_model: AIModel,
   ) {
-    let stop_stream = self.stop_stream.clone();
+    let cancel_token = self.cancel_token.load_full();
     let chat_id = self.chat_id;
     let cloud_service = self.chat_service.clone();
+    let model_name = ai_model.name.clone();
+    let is_local_model = ai_model.is_local;
     tokio::spawn(async move {
       let mut answer_sink = IsolateSink::new(Isolate::new(answer_stream_port));
       match cloud_service
         .stream_answer(&workspace_id, &chat_id, question_id, format, ai_model)
         .await
       {
-        Ok(mut stream) => {
-          while let Some(message) = stream.next().await {
-            match message {
-              Ok(message) => {
-                if stop_stream.load(std::sync::atomic::Ordering::Relaxed) {
-                  trace!("[Chat] client stop streaming message");
-                  break;
-                }
-                match message {
-                  QuestionStreamValue::Answer { value } => {
-                    answer_stream_buffer.lock().await.push_str(&value);
-                    if let Err(err) = answer_sink
-                      .send(StreamMessage::OnData(value).to_string())
-                      .await
-                    {
-                      error!("Failed to stream answer via IsolateSink: {}", err);
-                    }
-                  },
-                  QuestionStreamValue::Metadata { value } => {
-                    if let Ok(s) = serde_json::to_string(&value) {
-                      answer_stream_buffer.lock().await.set_metadata(value);
-                      let _ = answer_sink
-                        .send(StreamMessage::Metadata(s).to_string())
-                        .await;
-                    }
-                  },
-                  QuestionStreamValue::SuggestedQuestion {
-                    context_suggested_questions: _,
-                  } => {},
-                  QuestionStreamValue::FollowUp {
-                    should_generate_related_question,
-                  } => {
-                    let _ = answer_sink
-                      .send(
-                        StreamMessage::OnFollowUp(AIFollowUpData {
-                          should_generate_related_question,
-                        })
-                        .to_string(),
-                      )
-                      .await;
-                  },
+        Ok(mut stream) => loop {
+          let message = select! {
+            _ = cancel_token.cancelled() => {
+              trace!("[Chat] client canceled streaming message");
+              break;
+            },
+            message = stream.next() => match message {
+              Some(message) => message,
+              None => break,
+            },
+          };
+          match message {
+            Ok(message) => match message {
+              QuestionStreamValue::Answer { value } => {
+                answer_stream_buffer.lock().await.push_str(&value);
+                if let Err(err) = answer_sink
+                  .send(StreamMessage::OnData(value).to_string())
+                  .await
+                {
+                  error!("Failed to stream answer via IsolateSink: {}", err);
                 }
               },
-              Err(err) => {
-                if err.code == ErrorCode::RequestTimeout || err.code == ErrorCode::Internal {
-                  error!("[Chat] unexpected stream error: {}", err);
-                  let _ = answer_sink.send(StreamMessage::Done.to_string()).await;
-                } else {
-                  error!("[Chat] failed to stream answer: {}", err);
+              QuestionStreamValue::Metadata { value } => {
+                if let Some((prompt_tokens, completion_tokens)) = extract_token_usage(&value) {
+                  let usage = token_usage_for_model(
+                    &model_name,
+                    is_local_model,
+                    prompt_tokens,
+                    completion_tokens,
+                  );
+                  answer_stream_buffer.lock().await.set_token_usage(usage);
                   let _ = answer_sink
-                    .send(StreamMessage::OnError(err.msg.clone()).to_string())
+                    .send(StreamMessage::TokenUsage(usage).to_string())
+                    .await;
+                }
+                if let Ok(s) = serde_json::to_string(&value) {
+                  answer_stream_buffer.lock().await.set_metadata(value);
+                  let _ = answer_sink
+                    .send(StreamMessage::Metadata(s).to_string())
                     .await;
-                  let pb = ChatMessageErrorPB {
-                    chat_id: chat_id.to_string(),
-                    error_message: err.to_string(),
-                  };
-                  chat_notification_builder(chat_id, ChatNotification::StreamChatMessageError)
-                    .payload(pb)
-                    .send();
-                  return Err(err);
                 }
               },
-            }
+              QuestionStreamValue::SuggestedQuestion {
+                context_suggested_questions: _,
+              } => {},
+              QuestionStreamValue::FollowUp {
+                should_generate_related_question,
+              } => {
+                let _ = answer_sink
+                  .send(
+                    StreamMessage::OnFollowUp(AIFollowUpData {
+                      should_generate_related_question,
+                    })
+                    .to_string(),
+                  )
+                  .await;
+              },
+            },
+            Err(err) => {
+              if err.code == ErrorCode::RequestTimeout || err.code == ErrorCode::Internal {
+                error!("[Chat] unexpected stream error: {}", err);
+                let _ = answer_sink.send(StreamMessage::Done.to_string()).await;
+              } else {
+                error!("[Chat] failed to stream answer: {}", err);
+                let _ = answer_sink
+                  .send(StreamMessage::OnError(err.msg.clone()).to_string())
+                  .await;
+                let pb = ChatMessageErrorPB {
+                  chat_id: chat_id.to_string(),
+                  error_message: err.to_string(),
+                };
+                chat_notification_builder(chat_id, ChatNotification::StreamChatMessageError)
+                  .payload(pb)
+                  .send();
+                return Err(err);
+              }
+            },
           }
         },
         Err(err) => {
@@ -295,7 +316,17 @@ impl Chat {
         return Ok(());
       }
       let content = answer_stream_buffer.lock().await.take_content();
-      let metadata = answer_stream_buffer.lock().await.take_metadata();
+      let mut metadata = answer_stream_buffer.lock().await.take_metadata();
+      // Persist token accounting with the answer so it survives reloads.
+      if let Some(usage) = answer_stream_buffer.lock().await.take_token_usage() {
+        let usage = serde_json::to_value(usage).unwrap_or_default();
+        match metadata.as_mut() {
+          Some(Value::Object(map)) => {
+            map.insert("token_usage".to_string(), usage);
+          },
+          _ => metadata = Some(json!({ "token_usage": usage })),
+        }
+      }
       let answer = cloud_service
         .create_answer(
           &workspace_id,
@@ -627,12 +658,14 @@ fn save_chat_message_disk(
 struct StringBuffer {
   content: String,
   metadata: Option<serde_json::Value>,
+  token_usage: Option<AITokenUsageData>,
 }
 
 impl StringBuffer {
   fn clear(&mut self) {
     self.content.clear();
     self.metadata = None;
+    self.token_usage = None;
   }
 
   fn push_str(&mut self, value: &str) {
@@ -643,6 +676,14 @@ impl StringBuffer {
     self.metadata = Some(value);
   }
 
+  fn set_token_usage(&mut self, usage: AITokenUsageData) {
+    self.token_usage = Some(usage);
+  }
+
+  fn take_token_usage(&mut self) -> Option<AITokenUsageData> {
+    self.token_usage.take()
+  }
+
   fn is_empty(&self) -> bool {
     self.content.is_empty()
   }
@@ -1,11 +1,15 @@
-use crate::entities::{CompleteTextPB, CompleteTextTaskPB, CompletionTypePB};
+use crate::entities::{
+  CompleteTextPB, CompleteTextTaskPB, CompletionTypePB, GhostTextCompletionPB, GhostTextPB,
+};
 use allo_isolate::Isolate;
 use std::str::FromStr;
+use std::time::Duration;
 
+use arc_swap::ArcSwap;
 use dashmap::DashMap;
 use flowy_ai_pub::cloud::{
   AIModel, ChatCloudService, CompleteTextParams, CompletionMetadata, CompletionStreamValue,
-  CompletionType, CustomPrompt,
+  CompletionType, CustomPrompt, ResponseFormat,
 };
 use flowy_error::{FlowyError, FlowyResult};
 
@@ -16,13 +20,26 @@ use crate::stream_message::StreamMessage;
 use flowy_ai_pub::user_service::AIUserService;
 use std::sync::{Arc, Weak};
 use tokio::select;
-use tracing::{error, info};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, trace};
 use uuid::Uuid;
 
+/// Upper bound on how long a ghost text request may run. Inline suggestions
+/// are only useful while the user pauses, so a slow provider is cut off and
+/// whatever streamed so far is returned.
+const GHOST_TEXT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Rough character budget per token, used to cap the suggestion length
+/// without running a tokenizer.
+const GHOST_TEXT_CHARS_PER_TOKEN: usize = 4;
+
+const GHOST_TEXT_DEFAULT_MAX_TOKENS: u64 = 64;
+
 pub struct AICompletion {
   tasks: Arc<DashMap<String, tokio::sync::mpsc::Sender<()>>>,
   cloud_service: Weak<dyn ChatCloudService>,
   user_service: Weak<dyn AIUserService>,
+  ghost_text_token: ArcSwap<CancellationToken>,
 }
 
 impl AICompletion {
@@ -34,7 +51,106 @@ impl AICompletion {
       tasks: Arc::new(DashMap::new()),
       cloud_service,
       user_service,
+      ghost_text_token: ArcSwap::from_pointee(CancellationToken::new()),
+    }
+  }
+
+  /// Produce a single inline suggestion for the text surrounding the caret.
+  /// Each call supersedes the previous one: the in-flight request is
+  /// cancelled so fast typists don't pile up stale completions. A superseded
+  /// or timed out request resolves with whatever text streamed so far.
+  pub async fn complete_ghost_text(
+    &self,
+    data: GhostTextCompletionPB,
+    preferred_model: AIModel,
+  ) -> FlowyResult<GhostTextPB> {
+    let workspace_id = self
+      .user_service
+      .upgrade()
+      .ok_or_else(FlowyError::internal)?
+      .workspace_id()?;
+    let cloud_service = self
+      .cloud_service
+      .upgrade()
+      .ok_or_else(FlowyError::internal)?;
+    let object_id = Uuid::from_str(&data.object_id)?;
+
+    let cancel_token = Arc::new(CancellationToken::new());
+    let previous = self.ghost_text_token.swap(cancel_token.clone());
+    previous.cancel();
+
+    let max_tokens = if data.max_tokens == 0 {
+      GHOST_TEXT_DEFAULT_MAX_TOKENS
+    } else {
+      data.max_tokens
+    };
+    let max_chars = max_tokens as usize * GHOST_TEXT_CHARS_PER_TOKEN;
+
+    let mut system = "You are an inline writing assistant. Continue the text naturally. \
+Respond with the continuation only, without repeating the given text or adding commentary."
+      .to_string();
+    if !data.context_after.is_empty() {
+      system.push_str(&format!(
+        "\nThe continuation must splice cleanly before the following text:\n{}",
+        data.context_after
+      ));
+    }
+
+    let params = CompleteTextParams {
+      text: data.context_before,
+      completion_type: Some(CompletionType::ContinueWriting),
+      metadata: Some(CompletionMetadata {
+        object_id,
+        workspace_id: Some(workspace_id),
+        rag_ids: None,
+        completion_history: None,
+        custom_prompt: Some(CustomPrompt { system }),
+        prompt_id: None,
+      }),
+      format: ResponseFormat::default(),
+    };
+
+    let mut stream = cloud_service
+      .stream_complete(&workspace_id, params, preferred_model)
+      .await?;
+
+    let mut suggestion = String::new();
+    let collect = async {
+      while let Some(result) = stream.next().await {
+        match result? {
+          CompletionStreamValue::Answer { value } => {
+            suggestion.push_str(&value);
+            if suggestion.chars().count() >= max_chars {
+              break;
+            }
+          },
+          CompletionStreamValue::Comment { .. } => {},
+        }
+      }
+      Ok::<(), FlowyError>(())
+    };
+
+    let outcome = select! {
+      _ = cancel_token.cancelled() => None,
+      result = tokio::time::timeout(GHOST_TEXT_TIMEOUT, collect) => Some(result),
+    };
+
+    match outcome {
+      None => {
+        trace!("[AI Ghost Text] request superseded");
+        return Ok(GhostTextPB::default());
+      },
+      Some(Ok(Ok(()))) => {},
+      Some(Ok(Err(err))) => {
+        if suggestion.is_empty() {
+          return Err(err);
+        }
+        trace!("[AI Ghost Text] stream error after partial suggestion: {}", err);
+      },
+      Some(Err(_)) => trace!("[AI Ghost Text] timed out, returning partial suggestion"),
     }
+
+    Ok(GhostTextPB { text: suggestion })
   }
 
   pub async fn create_complete_task(
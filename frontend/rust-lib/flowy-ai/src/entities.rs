@@ -1,9 +1,12 @@
+use crate::anthropic::AnthropicSetting;
 use crate::local_ai::controller::LocalAISetting;
+use crate::local_ai::model_registry::InstalledModel;
 use crate::local_ai::resource::PendingResource;
 use flowy_ai_pub::cloud::{
   AIModel, ChatMessage, ChatMessageType, CompletionMessage, LLMModel, OutputContent, OutputLayout,
   RelatedQuestion, RepeatedChatMessage, RepeatedRelatedQuestion, ResponseFormat,
 };
+use flowy_ai_pub::persistence::PromptTemplateTable;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use lib_infra::validator_fn::required_not_empty_str;
 use serde::{Deserialize, Serialize};
@@ -765,3 +768,589 @@ pub struct CustomPromptDatabaseConfigurationPB {
   #[pb(index = 5, one_of)]
   pub category_field_id: Option<String>,
 }
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct InstalledModelPB {
+  #[pb(index = 1)]
+  pub file_name: String,
+
+  #[pb(index = 2)]
+  pub size_in_bytes: i64,
+
+  /// Hex encoded SHA256 of the file content, computed at install time.
+  #[pb(index = 3)]
+  pub sha256: String,
+
+  /// Quantization tag parsed from the file name, e.g. `Q4_K_M`. Empty when
+  /// the file name carries no recognizable tag.
+  #[pb(index = 4)]
+  pub quantization: String,
+
+  /// Timestamp of the last time the model was loaded, in seconds. Zero when
+  /// the model was never loaded.
+  #[pb(index = 5)]
+  pub last_used_at: i64,
+}
+
+impl From<InstalledModel> for InstalledModelPB {
+  fn from(value: InstalledModel) -> Self {
+    Self {
+      file_name: value.file_name,
+      size_in_bytes: value.size_in_bytes as i64,
+      sha256: value.sha256,
+      quantization: value.quantization,
+      last_used_at: value.last_used_at,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct LocalModelRegistryPB {
+  #[pb(index = 1)]
+  pub models: Vec<InstalledModelPB>,
+
+  /// Combined size of every installed model, in bytes.
+  #[pb(index = 2)]
+  pub total_size_in_bytes: i64,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct ModelFileNamePB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub file_name: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ModelVerificationPB {
+  /// Whether the file still matches the hash recorded at install time.
+  #[pb(index = 1)]
+  pub is_valid: bool,
+
+  #[pb(index = 2)]
+  pub sha256: String,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct OllamaModelNamePB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub name: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct OllamaPullProgressPB {
+  #[pb(index = 1)]
+  pub model_name: String,
+
+  /// Status line reported by the Ollama server, e.g. `pulling manifest`.
+  #[pb(index = 2)]
+  pub status: String,
+
+  #[pb(index = 3)]
+  pub total_bytes: i64,
+
+  #[pb(index = 4)]
+  pub downloaded_bytes: i64,
+
+  /// Set on the final notification of a pull.
+  #[pb(index = 5)]
+  pub done: bool,
+
+  #[pb(index = 6, one_of)]
+  pub error: Option<String>,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct AnthropicSettingPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub api_base_url: String,
+
+  #[pb(index = 2)]
+  pub api_key: String,
+
+  #[pb(index = 3)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub default_model: String,
+
+  /// Claude models offered in the model selection UI.
+  #[pb(index = 4)]
+  pub model_names: Vec<String>,
+}
+
+impl From<AnthropicSetting> for AnthropicSettingPB {
+  fn from(value: AnthropicSetting) -> Self {
+    AnthropicSettingPB {
+      api_base_url: value.api_base_url,
+      api_key: value.api_key,
+      default_model: value.default_model,
+      model_names: value.model_names,
+    }
+  }
+}
+
+impl From<AnthropicSettingPB> for AnthropicSetting {
+  fn from(value: AnthropicSettingPB) -> Self {
+    AnthropicSetting {
+      api_base_url: value.api_base_url,
+      api_key: value.api_key,
+      default_model: value.default_model,
+      model_names: value.model_names,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ChatSessionPB {
+  #[pb(index = 1)]
+  pub chat_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub summary: String,
+
+  #[pb(index = 4)]
+  pub created_at: i64,
+
+  /// The model currently selected for this chat.
+  #[pb(index = 5)]
+  pub model: String,
+
+  /// Ids of the views this chat uses as RAG sources.
+  #[pb(index = 6)]
+  pub rag_ids: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct RepeatedChatSessionPB {
+  #[pb(index = 1)]
+  pub items: Vec<ChatSessionPB>,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct RenameChatPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub chat_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub name: String,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct ChatMessageSearchPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub query: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ChatSearchResultPB {
+  #[pb(index = 1)]
+  pub chat_id: String,
+
+  #[pb(index = 2)]
+  pub message_id: i64,
+
+  #[pb(index = 3)]
+  pub content: String,
+
+  #[pb(index = 4)]
+  pub created_at: i64,
+
+  #[pb(index = 5)]
+  pub author_type: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct RepeatedChatSearchResultPB {
+  #[pb(index = 1)]
+  pub items: Vec<ChatSearchResultPB>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ChatMarkdownExportPB {
+  /// Suggested file or view name, derived from the chat name.
+  #[pb(index = 1)]
+  pub file_name: String,
+
+  #[pb(index = 2)]
+  pub markdown: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct AIToolPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  #[pb(index = 2)]
+  pub description: String,
+
+  /// JSON schema describing the tool arguments.
+  #[pb(index = 3)]
+  pub parameters: String,
+
+  #[pb(index = 4)]
+  pub requires_confirmation: bool,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct AIToolListPB {
+  #[pb(index = 1)]
+  pub items: Vec<AIToolPB>,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct PerformToolCallPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub chat_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub tool_name: String,
+
+  /// JSON encoded tool arguments.
+  #[pb(index = 3)]
+  pub arguments: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf_Enum, PartialEq, Eq, Copy)]
+pub enum ToolCallStatusPB {
+  #[default]
+  PendingConfirmation = 0,
+  Completed = 1,
+  Rejected = 2,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ToolCallPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub chat_id: String,
+
+  #[pb(index = 3)]
+  pub tool_name: String,
+
+  /// JSON encoded tool arguments.
+  #[pb(index = 4)]
+  pub arguments: String,
+
+  #[pb(index = 5)]
+  pub status: ToolCallStatusPB,
+
+  /// JSON encoded tool result. Empty until the call completes.
+  #[pb(index = 6)]
+  pub output: String,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct ToolCallDecisionPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub call_id: String,
+
+  #[pb(index = 2)]
+  pub approved: bool,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct GhostTextCompletionPB {
+  /// Id of the document being edited, used for model selection.
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub object_id: String,
+
+  /// Text before the caret.
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub context_before: String,
+
+  /// Text after the caret. The suggestion must splice cleanly before it.
+  #[pb(index = 3)]
+  pub context_after: String,
+
+  /// Approximate length cap for the suggestion. Zero uses the default.
+  #[pb(index = 4)]
+  pub max_tokens: u64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct GhostTextPB {
+  /// The suggested continuation. Empty when the request was superseded.
+  #[pb(index = 1)]
+  pub text: String,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct ProofreadTextPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub text: String,
+
+  /// BCP 47 language tag, e.g. "en" or "en-US". Empty defaults to English.
+  #[pb(index = 2)]
+  pub language: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf_Enum, PartialEq, Eq, Copy)]
+pub enum TextIssueKindPB {
+  #[default]
+  Spelling = 0,
+  Grammar = 1,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct TextIssuePB {
+  /// Character offset of the first affected character.
+  #[pb(index = 1)]
+  pub start: u64,
+
+  /// Character offset one past the last affected character.
+  #[pb(index = 2)]
+  pub end: u64,
+
+  #[pb(index = 3)]
+  pub kind: TextIssueKindPB,
+
+  #[pb(index = 4)]
+  pub message: String,
+
+  #[pb(index = 5)]
+  pub suggestions: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ProofreadResultPB {
+  #[pb(index = 1)]
+  pub items: Vec<TextIssuePB>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ProofreadDictionaryPB {
+  #[pb(index = 1)]
+  pub words: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct ProofreadDictionaryWordPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub word: String,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct PromptTemplatePB {
+  /// Empty when creating a new template; assigned by the backend.
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub description: String,
+
+  /// Prompt text. `{{selection}}` and `{{page}}` are substituted with the
+  /// caller's context when the template runs.
+  #[pb(index = 4)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub template: String,
+
+  /// Model the template prefers. Empty uses the active model.
+  #[pb(index = 5)]
+  pub preferred_model: String,
+
+  /// Sampling temperature. Zero or negative uses the provider default.
+  #[pb(index = 6)]
+  pub temperature: f64,
+
+  #[pb(index = 7)]
+  pub updated_at: i64,
+}
+
+impl From<PromptTemplateTable> for PromptTemplatePB {
+  fn from(row: PromptTemplateTable) -> Self {
+    Self {
+      id: row.id,
+      name: row.name,
+      description: row.description,
+      template: row.template,
+      preferred_model: row.preferred_model,
+      temperature: row.temperature.unwrap_or_default(),
+      updated_at: row.updated_at,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct PromptTemplateListPB {
+  #[pb(index = 1)]
+  pub items: Vec<PromptTemplatePB>,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct PromptTemplateIdPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub template_id: String,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct RunPromptTemplatePB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub template_id: String,
+
+  /// Id of the document or view the prompt runs against.
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub object_id: String,
+
+  /// Substituted for `{{selection}}` in the template.
+  #[pb(index = 3)]
+  pub selection: String,
+
+  /// Substituted for `{{page}}` in the template.
+  #[pb(index = 4)]
+  pub page: String,
+
+  /// Isolate port receiving the streamed response.
+  #[pb(index = 5)]
+  pub stream_port: i64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct AIRequestQueuePB {
+  #[pb(index = 1)]
+  pub provider: String,
+
+  /// 1-based position among callers waiting for the same provider.
+  #[pb(index = 2)]
+  pub position: u64,
+
+  #[pb(index = 3)]
+  pub eta_seconds: u64,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct TranscribeAudioPB {
+  /// Absolute path of the audio file to transcribe.
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub file_path: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct TranscriptionSegmentPB {
+  #[pb(index = 1)]
+  pub start_ms: u64,
+
+  #[pb(index = 2)]
+  pub end_ms: u64,
+
+  #[pb(index = 3)]
+  pub text: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct TranscriptionPB {
+  /// The full transcript without timestamps.
+  #[pb(index = 1)]
+  pub text: String,
+
+  #[pb(index = 2)]
+  pub segments: Vec<TranscriptionSegmentPB>,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct AppendTranscriptionPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub file_path: String,
+
+  /// The document the transcript is appended to.
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub document_id: String,
+}
+
+#[derive(Default, ProtoBuf, Validate, Clone, Debug)]
+pub struct CountTokensPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub text: String,
+
+  /// Empty means the active model.
+  #[pb(index = 2)]
+  pub model_name: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct TokenCountPB {
+  /// Estimated token count of the text; exact counts come from the provider.
+  #[pb(index = 1)]
+  pub tokens: u64,
+
+  /// The model's context window, for showing how much of it the text uses.
+  #[pb(index = 2)]
+  pub context_window: u64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf_Enum, PartialEq, Eq, Copy)]
+pub enum LocalAIRuntimeStatusPB {
+  #[default]
+  Starting = 0,
+  Ready = 1,
+  Degraded = 2,
+  Crashed = 3,
+  Stopped = 4,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct LocalAIRuntimeStatePB {
+  #[pb(index = 1)]
+  pub state: LocalAIRuntimeStatusPB,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct LoadedModelPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  #[pb(index = 2)]
+  pub size_in_bytes: u64,
+
+  #[pb(index = 3)]
+  pub vram_in_bytes: u64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct LocalAIDiagnosticsPB {
+  #[pb(index = 1)]
+  pub state: LocalAIRuntimeStatusPB,
+
+  #[pb(index = 2)]
+  pub consecutive_failures: u32,
+
+  #[pb(index = 3)]
+  pub restart_attempts: u32,
+
+  #[pb(index = 4)]
+  pub last_error: String,
+
+  /// Timestamp of the last health probe, in seconds.
+  #[pb(index = 5)]
+  pub last_check_at: i64,
+
+  /// The models the runtime currently holds in memory.
+  #[pb(index = 6)]
+  pub loaded_models: Vec<LoadedModelPB>,
+}
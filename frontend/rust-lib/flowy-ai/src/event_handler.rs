@@ -1,6 +1,8 @@
 use crate::ai_manager::AIManager;
 use crate::completion::AICompletion;
 use crate::entities::*;
+use crate::token_usage::{context_window, count_tokens};
+use crate::transcription::transcript_markdown;
 use flowy_ai_pub::cloud::{AIModel, ChatMessageType};
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use lib_dispatch::prelude::{AFPluginData, AFPluginState, DataResult, data_result_ok};
@@ -397,3 +399,399 @@ pub(crate) async fn set_custom_prompt_database_configuration_handler(
 
   Ok(())
 }
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_local_model_registry_handler(
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<LocalModelRegistryPB, FlowyError> {
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let models = ai_manager.local_ai.get_installed_models().await?;
+  let total_size_in_bytes = models.iter().map(|model| model.size_in_bytes as i64).sum();
+  data_result_ok(LocalModelRegistryPB {
+    models: models.into_iter().map(Into::into).collect(),
+    total_size_in_bytes,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn delete_local_model_handler(
+  data: AFPluginData<ModelFileNamePB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  ai_manager
+    .local_ai
+    .delete_installed_model(&data.file_name)
+    .await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn verify_local_model_handler(
+  data: AFPluginData<ModelFileNamePB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<ModelVerificationPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let (is_valid, sha256) = ai_manager
+    .local_ai
+    .verify_installed_model(&data.file_name)
+    .await?;
+  data_result_ok(ModelVerificationPB { is_valid, sha256 })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn pull_ollama_model_handler(
+  data: AFPluginData<OllamaModelNamePB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  ai_manager.local_ai.start_ollama_model_pull(&data.name)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn cancel_ollama_model_pull_handler(
+  data: AFPluginData<OllamaModelNamePB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  ai_manager.local_ai.cancel_ollama_model_pull(&data.name);
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_anthropic_setting_handler(
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<AnthropicSettingPB, FlowyError> {
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let workspace_id = ai_manager.user_service.workspace_id()?;
+  let setting = ai_manager.anthropic.get_setting(&workspace_id.to_string());
+  data_result_ok(setting.into())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn update_anthropic_setting_handler(
+  data: AFPluginData<AnthropicSettingPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let workspace_id = ai_manager.user_service.workspace_id()?;
+  ai_manager
+    .anthropic
+    .update_setting(&workspace_id.to_string(), data.into())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn list_chat_sessions_handler(
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<RepeatedChatSessionPB, FlowyError> {
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let items = ai_manager.get_chat_sessions().await?;
+  data_result_ok(RepeatedChatSessionPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn search_chat_messages_handler(
+  data: AFPluginData<ChatMessageSearchPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<RepeatedChatSearchResultPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let items = ai_manager.search_chat_messages(&data.query, 100).await?;
+  data_result_ok(RepeatedChatSearchResultPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn rename_chat_session_handler(
+  data: AFPluginData<RenameChatPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let chat_id = Uuid::from_str(&data.chat_id)?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  ai_manager.rename_chat(&chat_id, &data.name).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn delete_chat_session_handler(
+  data: AFPluginData<ChatId>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let chat_id = Uuid::from_str(&data.value)?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  ai_manager.delete_chat(&chat_id).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_chat_markdown_handler(
+  data: AFPluginData<ChatId>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<ChatMarkdownExportPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let chat_id = Uuid::from_str(&data.value)?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let export = ai_manager.export_chat_to_markdown(&chat_id).await?;
+  data_result_ok(export)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_available_tools_handler(
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<AIToolListPB, FlowyError> {
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let items = ai_manager.tools.available_tools();
+  data_result_ok(AIToolListPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn perform_tool_call_handler(
+  data: AFPluginData<PerformToolCallPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<ToolCallPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let chat_id = Uuid::from_str(&data.chat_id)?;
+  let arguments = if data.arguments.is_empty() {
+    serde_json::json!({})
+  } else {
+    serde_json::from_str(&data.arguments)
+      .map_err(|err| FlowyError::invalid_data().with_context(err))?
+  };
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let call = ai_manager
+    .tools
+    .perform_tool_call(&chat_id, &data.tool_name, arguments)
+    .await?;
+  data_result_ok(call)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn confirm_tool_call_handler(
+  data: AFPluginData<ToolCallDecisionPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<ToolCallPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let call = ai_manager
+    .tools
+    .confirm_tool_call(&data.call_id, data.approved)
+    .await?;
+  data_result_ok(call)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn complete_ghost_text_handler(
+  data: AFPluginData<GhostTextCompletionPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+  tools: AFPluginState<Arc<AICompletion>>,
+) -> DataResult<GhostTextPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let ai_model = ai_manager.get_active_model(&data.object_id).await;
+  let suggestion = tools.complete_ghost_text(data, ai_model).await?;
+  data_result_ok(suggestion)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn proofread_text_handler(
+  data: AFPluginData<ProofreadTextPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<ProofreadResultPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let workspace_id = ai_manager.user_service.workspace_id()?;
+  let items = ai_manager
+    .proofread
+    .check_text(&workspace_id.to_string(), &data.text, &data.language);
+  data_result_ok(ProofreadResultPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_proofread_dictionary_handler(
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<ProofreadDictionaryPB, FlowyError> {
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let workspace_id = ai_manager.user_service.workspace_id()?;
+  let words = ai_manager
+    .proofread
+    .get_dictionary(&workspace_id.to_string());
+  data_result_ok(ProofreadDictionaryPB { words })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn add_proofread_dictionary_word_handler(
+  data: AFPluginData<ProofreadDictionaryWordPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let workspace_id = ai_manager.user_service.workspace_id()?;
+  ai_manager
+    .proofread
+    .add_word(&workspace_id.to_string(), &data.word)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn remove_proofread_dictionary_word_handler(
+  data: AFPluginData<ProofreadDictionaryWordPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let workspace_id = ai_manager.user_service.workspace_id()?;
+  ai_manager
+    .proofread
+    .remove_word(&workspace_id.to_string(), &data.word)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_prompt_templates_handler(
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<PromptTemplateListPB, FlowyError> {
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let items = ai_manager.get_prompt_templates()?;
+  data_result_ok(PromptTemplateListPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn upsert_prompt_template_handler(
+  data: AFPluginData<PromptTemplatePB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<PromptTemplatePB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let template = ai_manager.upsert_prompt_template(data)?;
+  data_result_ok(template)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn delete_prompt_template_handler(
+  data: AFPluginData<PromptTemplateIdPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  ai_manager.delete_prompt_template(&data.template_id)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn run_prompt_template_handler(
+  data: AFPluginData<RunPromptTemplatePB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+  tools: AFPluginState<Arc<AICompletion>>,
+) -> DataResult<CompleteTextTaskPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let (prompt, model) = ai_manager
+    .render_prompt_template(&data.template_id, &data.object_id, &data.selection, &data.page)
+    .await?;
+
+  let complete = CompleteTextPB {
+    text: data.selection,
+    completion_type: CompletionTypePB::CustomPrompt,
+    format: None,
+    stream_port: data.stream_port,
+    object_id: data.object_id,
+    rag_ids: vec![],
+    history: vec![],
+    custom_prompt: Some(prompt),
+    prompt_id: Some(data.template_id),
+  };
+  let task = tools.create_complete_task(complete, model).await?;
+  data_result_ok(task)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn transcribe_audio_handler(
+  data: AFPluginData<TranscribeAudioPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<TranscriptionPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let segments = ai_manager
+    .transcription
+    .transcribe_audio(&data.file_path)
+    .await?;
+  data_result_ok(TranscriptionPB {
+    text: segments
+      .iter()
+      .map(|segment| segment.text.as_str())
+      .collect::<Vec<_>>()
+      .join(" "),
+    segments: segments
+      .into_iter()
+      .map(|segment| TranscriptionSegmentPB {
+        start_ms: segment.start_ms,
+        end_ms: segment.end_ms,
+        text: segment.text,
+      })
+      .collect(),
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn append_transcription_handler(
+  data: AFPluginData<AppendTranscriptionPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let document_id = Uuid::from_str(&data.document_id)?;
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  let segments = ai_manager
+    .transcription
+    .transcribe_audio(&data.file_path)
+    .await?;
+  let markdown = transcript_markdown(&segments);
+  let service = ai_manager.tools.service()?;
+  service.append_to_document(&document_id, &markdown).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn count_tokens_handler(
+  data: AFPluginData<CountTokensPB>,
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<TokenCountPB, FlowyError> {
+  let data = data.into_inner();
+  data.validate()?;
+  let model_name = if data.model_name.is_empty() {
+    let ai_manager = upgrade_ai_manager(ai_manager)?;
+    ai_manager.get_active_model("").await.name
+  } else {
+    data.model_name
+  };
+  data_result_ok(TokenCountPB {
+    tokens: count_tokens(&data.text, &model_name),
+    context_window: context_window(&model_name),
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_local_ai_diagnostics_handler(
+  ai_manager: AFPluginState<Weak<AIManager>>,
+) -> DataResult<LocalAIDiagnosticsPB, FlowyError> {
+  let ai_manager = upgrade_ai_manager(ai_manager)?;
+  data_result_ok(ai_manager.local_ai.watchdog().diagnostics())
+}
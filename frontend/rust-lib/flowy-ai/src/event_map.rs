@@ -58,6 +58,55 @@ pub fn init(ai_manager: Weak<AIManager>) -> AFPlugin {
       AIEvent::SetCustomPromptDatabaseConfiguration,
       set_custom_prompt_database_configuration_handler,
     )
+    .event(
+      AIEvent::GetLocalModelRegistry,
+      get_local_model_registry_handler,
+    )
+    .event(AIEvent::DeleteLocalModel, delete_local_model_handler)
+    .event(AIEvent::VerifyLocalModel, verify_local_model_handler)
+    .event(AIEvent::PullOllamaModel, pull_ollama_model_handler)
+    .event(
+      AIEvent::CancelOllamaModelPull,
+      cancel_ollama_model_pull_handler,
+    )
+    .event(AIEvent::GetAnthropicSetting, get_anthropic_setting_handler)
+    .event(
+      AIEvent::UpdateAnthropicSetting,
+      update_anthropic_setting_handler,
+    )
+    .event(AIEvent::ListChatSessions, list_chat_sessions_handler)
+    .event(AIEvent::SearchChatMessages, search_chat_messages_handler)
+    .event(AIEvent::RenameChatSession, rename_chat_session_handler)
+    .event(AIEvent::DeleteChatSession, delete_chat_session_handler)
+    .event(AIEvent::ExportChatMarkdown, export_chat_markdown_handler)
+    .event(AIEvent::GetAvailableTools, get_available_tools_handler)
+    .event(AIEvent::PerformToolCall, perform_tool_call_handler)
+    .event(AIEvent::ConfirmToolCall, confirm_tool_call_handler)
+    .event(AIEvent::CompleteGhostText, complete_ghost_text_handler)
+    .event(AIEvent::ProofreadText, proofread_text_handler)
+    .event(
+      AIEvent::GetProofreadDictionary,
+      get_proofread_dictionary_handler,
+    )
+    .event(
+      AIEvent::AddProofreadDictionaryWord,
+      add_proofread_dictionary_word_handler,
+    )
+    .event(
+      AIEvent::RemoveProofreadDictionaryWord,
+      remove_proofread_dictionary_word_handler,
+    )
+    .event(AIEvent::GetPromptTemplates, get_prompt_templates_handler)
+    .event(AIEvent::UpsertPromptTemplate, upsert_prompt_template_handler)
+    .event(AIEvent::DeletePromptTemplate, delete_prompt_template_handler)
+    .event(AIEvent::RunPromptTemplate, run_prompt_template_handler)
+    .event(AIEvent::TranscribeAudio, transcribe_audio_handler)
+    .event(AIEvent::AppendTranscription, append_transcription_handler)
+    .event(AIEvent::CountTokens, count_tokens_handler)
+    .event(
+      AIEvent::GetLocalAIDiagnostics,
+      get_local_ai_diagnostics_handler,
+    )
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
@@ -142,4 +191,127 @@ pub enum AIEvent {
 
   #[event(input = "CustomPromptDatabaseConfigurationPB")]
   SetCustomPromptDatabaseConfiguration = 36,
+
+  /// List installed local models with size, hash, quantization, last used
+  /// and the total disk usage.
+  #[event(output = "LocalModelRegistryPB")]
+  GetLocalModelRegistry = 37,
+
+  /// Delete an installed model file. Models referenced by the current local
+  /// AI setting are refused.
+  #[event(input = "ModelFileNamePB")]
+  DeleteLocalModel = 38,
+
+  /// Re-hash an installed model file and compare it against the hash
+  /// recorded at install time.
+  #[event(input = "ModelFileNamePB", output = "ModelVerificationPB")]
+  VerifyLocalModel = 39,
+
+  /// Pull a model from the configured Ollama server, streaming progress via
+  /// notification, so users who already run Ollama don't need the built-in
+  /// model download path.
+  #[event(input = "OllamaModelNamePB")]
+  PullOllamaModel = 40,
+
+  /// Cancel a running Ollama model pull.
+  #[event(input = "OllamaModelNamePB")]
+  CancelOllamaModelPull = 41,
+
+  /// Return the Anthropic provider configuration for the current workspace.
+  #[event(output = "AnthropicSettingPB")]
+  GetAnthropicSetting = 42,
+
+  /// Update the Anthropic provider configuration for the current workspace.
+  /// Setting an API key makes the configured Claude models selectable
+  /// alongside the server and local models.
+  #[event(input = "AnthropicSettingPB")]
+  UpdateAnthropicSetting = 43,
+
+  /// List locally persisted chat sessions, newest first.
+  #[event(output = "RepeatedChatSessionPB")]
+  ListChatSessions = 44,
+
+  /// Search past messages across every chat by content substring.
+  #[event(input = "ChatMessageSearchPB", output = "RepeatedChatSearchResultPB")]
+  SearchChatMessages = 45,
+
+  #[event(input = "RenameChatPB")]
+  RenameChatSession = 46,
+
+  /// Delete a chat session together with its locally stored messages.
+  #[event(input = "ChatId")]
+  DeleteChatSession = 47,
+
+  /// Render a conversation as markdown. The returned content is used by the
+  /// client to create a new document view.
+  #[event(input = "ChatId", output = "ChatMarkdownExportPB")]
+  ExportChatMarkdown = 48,
+
+  /// List the workspace tools that can be exposed to capable models.
+  #[event(output = "AIToolListPB")]
+  GetAvailableTools = 49,
+
+  /// Run a tool call emitted by a model. Mutating tools return a pending
+  /// call that must be confirmed before anything executes.
+  #[event(input = "PerformToolCallPB", output = "ToolCallPB")]
+  PerformToolCall = 50,
+
+  /// Approve or reject a pending mutating tool call.
+  #[event(input = "ToolCallDecisionPB", output = "ToolCallPB")]
+  ConfirmToolCall = 51,
+
+  /// Low-latency inline completion for the editor. A new request cancels the
+  /// in-flight one, and slow providers are cut off after a short timeout.
+  #[event(input = "GhostTextCompletionPB", output = "GhostTextPB")]
+  CompleteGhostText = 52,
+
+  /// Offline grammar and spelling check. Nothing leaves the device; custom
+  /// dictionary words never get reported.
+  #[event(input = "ProofreadTextPB", output = "ProofreadResultPB")]
+  ProofreadText = 53,
+
+  #[event(output = "ProofreadDictionaryPB")]
+  GetProofreadDictionary = 54,
+
+  #[event(input = "ProofreadDictionaryWordPB")]
+  AddProofreadDictionaryWord = 55,
+
+  #[event(input = "ProofreadDictionaryWordPB")]
+  RemoveProofreadDictionaryWord = 56,
+
+  /// List the locally stored prompt templates.
+  #[event(output = "PromptTemplateListPB")]
+  GetPromptTemplates = 57,
+
+  /// Create or update a prompt template. An empty id creates a new one.
+  #[event(input = "PromptTemplatePB", output = "PromptTemplatePB")]
+  UpsertPromptTemplate = 58,
+
+  #[event(input = "PromptTemplateIdPB")]
+  DeletePromptTemplate = 59,
+
+  /// Render a template with the given context and stream the completion,
+  /// like CompleteText but driven by a stored template.
+  #[event(input = "RunPromptTemplatePB", output = "CompleteTextTaskPB")]
+  RunPromptTemplate = 60,
+
+  /// Transcribe an audio file with a locally installed whisper model,
+  /// returning timestamped segments. Nothing leaves the device.
+  #[event(input = "TranscribeAudioPB", output = "TranscriptionPB")]
+  TranscribeAudio = 61,
+
+  /// Transcribe an audio file and append the transcript as paragraph blocks
+  /// to the target document, for voice notes.
+  #[event(input = "AppendTranscriptionPB")]
+  AppendTranscription = 62,
+
+  /// Estimate how many tokens a text takes for a model, along with the
+  /// model's context window, so the UI can warn before a prompt overflows.
+  #[event(input = "CountTokensPB", output = "TokenCountPB")]
+  CountTokens = 63,
+
+  /// Health snapshot of the local AI runtime for the settings screen:
+  /// liveness, loaded models, failure and restart counters.
+  #[event(output = "LocalAIDiagnosticsPB")]
+  GetLocalAIDiagnostics = 64,
 }
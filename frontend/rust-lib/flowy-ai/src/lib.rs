@@ -2,6 +2,7 @@ mod event_handler;
 pub mod event_map;
 
 pub mod ai_manager;
+pub mod anthropic;
 mod chat;
 mod completion;
 pub mod entities;
@@ -21,6 +22,10 @@ mod model_select;
 mod model_select_test;
 pub mod notification;
 pub mod offline;
+pub mod proofread;
 mod protobuf;
 mod search;
 mod stream_message;
+pub mod token_usage;
+pub mod tools;
+pub mod transcription;
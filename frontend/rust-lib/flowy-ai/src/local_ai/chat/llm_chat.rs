@@ -54,6 +54,7 @@ impl LLMChat {
     let retriever = create_retriever(
       &info.workspace_id,
       info.rag_ids.clone(),
+      &info.model,
       store.clone(),
       retriever_sources,
     );
@@ -194,6 +195,7 @@ fn create_formatter_prompt_with_format(
 fn create_retriever(
   workspace_id: &Uuid,
   rag_ids: Vec<String>,
+  model_name: &str,
   store: Option<SqliteVectorStore>,
   retrievers_sources: Vec<Weak<dyn MultipleSourceRetrieverStore>>,
 ) -> Box<dyn AFRetriever> {
@@ -227,6 +229,7 @@ fn create_retriever(
     rag_ids.clone(),
     5,
     0.2,
+    model_name,
   ))
 }
 
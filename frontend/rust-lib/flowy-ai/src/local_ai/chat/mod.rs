@@ -20,7 +20,7 @@ use flowy_ai_pub::cloud::{
 };
 use flowy_ai_pub::persistence::select_latest_user_message;
 use flowy_ai_pub::user_service::AIUserService;
-use flowy_database_pub::cloud::{SummaryRowContent, TranslateRowContent};
+use flowy_database_pub::cloud::{SummaryRowContent, SummaryStream, TranslateRowContent};
 use flowy_error::{FlowyError, FlowyResult};
 use futures_util::StreamExt;
 use ollama_rs::Ollama;
@@ -151,6 +151,26 @@ impl LLMChatController {
     Ok(response)
   }
 
+  /// Same as [Self::summarize_database_row], but yields the summary as
+  /// partial text chunks while the local model generates it.
+  pub async fn summarize_database_row_stream(
+    &self,
+    model_name: &str,
+    data: SummaryRowContent,
+  ) -> FlowyResult<SummaryStream> {
+    let client = self
+      .client
+      .read()
+      .await
+      .clone()
+      .ok_or(FlowyError::local_ai())?
+      .upgrade()
+      .ok_or(FlowyError::local_ai())?;
+
+    let chain = DatabaseSummaryChain::new(LLMOllama::new(model_name, client, None, None));
+    chain.summarize_stream(data).await
+  }
+
   pub async fn translate_database_row(
     &self,
     model_name: &str,
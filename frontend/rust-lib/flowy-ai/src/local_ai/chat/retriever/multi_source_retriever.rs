@@ -1,10 +1,12 @@
 use crate::local_ai::chat::retriever::{AFRetriever, MultipleSourceRetrieverStore};
+use crate::token_usage::{context_window, count_tokens};
 use async_trait::async_trait;
 use futures::future::join_all;
 use langchain_rust::schemas::Document;
+use std::cmp::Ordering;
 use std::error::Error;
 use std::sync::Arc;
-use tracing::{error, trace};
+use tracing::{error, trace, warn};
 use uuid::Uuid;
 
 pub struct MultipleSourceRetriever {
@@ -14,6 +16,8 @@ pub struct MultipleSourceRetriever {
   rag_ids: Vec<String>,
   full_search: bool,
   score_threshold: f32,
+  /// The model the chunks are composed for, used to size the token budget.
+  model_name: String,
 }
 
 impl MultipleSourceRetriever {
@@ -23,6 +27,7 @@ impl MultipleSourceRetriever {
     rag_ids: Vec<String>,
     num_docs: usize,
     score_threshold: f32,
+    model_name: &str,
   ) -> Self {
     MultipleSourceRetriever {
       workspace_id,
@@ -31,6 +36,7 @@ impl MultipleSourceRetriever {
       rag_ids,
       full_search: false,
       score_threshold,
+      model_name: model_name.to_string(),
     }
   }
   pub fn set_rag_ids(&mut self, new_rag_ids: Vec<String>) {
@@ -114,6 +120,45 @@ impl AFRetriever for MultipleSourceRetriever {
       }
     }
 
-    Ok(results)
+    Ok(truncate_to_token_budget(
+      results,
+      &self.model_name,
+      count_tokens(query, &self.model_name),
+    ))
   }
 }
+
+/// Drops the least relevant chunks when the composed prompt would overflow
+/// the model's context window. Chunks may take at most half the window; the
+/// other half is left for the question, conversation history and the answer.
+fn truncate_to_token_budget(
+  mut docs: Vec<Document>,
+  model_name: &str,
+  query_tokens: u64,
+) -> Vec<Document> {
+  let budget = context_window(model_name) / 2;
+  docs.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+
+  let mut used = query_tokens;
+  let total = docs.len();
+  let mut kept = Vec::with_capacity(total);
+  for doc in docs {
+    let tokens = count_tokens(&doc.page_content, model_name);
+    if used + tokens > budget && !kept.is_empty() {
+      continue;
+    }
+    used += tokens;
+    kept.push(doc);
+  }
+
+  if kept.len() < total {
+    warn!(
+      "[VectorStore] prompt exceeds the {} context window, kept the {} most relevant of {} chunks ({} tokens)",
+      model_name,
+      kept.len(),
+      total,
+      used
+    );
+  }
+  kept
+}
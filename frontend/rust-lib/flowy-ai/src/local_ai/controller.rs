@@ -1,4 +1,4 @@
-use crate::entities::LocalAIPB;
+use crate::entities::{LocalAIPB, OllamaPullProgressPB};
 use crate::local_ai::resource::{LLMResourceService, LocalAIResourceController};
 use crate::notification::{
   APPFLOWY_AI_NOTIFICATION_KEY, ChatNotification, chat_notification_builder,
@@ -11,21 +11,26 @@ use lib_infra::async_trait::async_trait;
 use std::collections::HashMap;
 
 use crate::local_ai::chat::{LLMChatController, LLMChatInfo};
+use crate::local_ai::model_registry::{InstalledModel, LocalModelRegistry};
+use crate::local_ai::ollama_pull::{PullProgress, pull_ollama_model};
+use crate::local_ai::watchdog::LocalAIWatchdog;
 use crate::stream_message::StreamMessage;
 use arc_swap::ArcSwapOption;
+use dashmap::DashMap;
 use flowy_ai_pub::cloud::AIModel;
 use flowy_ai_pub::persistence::{
   LocalAIModelTable, ModelType, select_local_ai_model, upsert_local_ai_model,
 };
 use flowy_ai_pub::user_service::AIUserService;
 use futures_util::SinkExt;
-use lib_infra::util::get_operating_system;
+use lib_infra::util::{get_operating_system, timestamp};
 use ollama_rs::Ollama;
 use ollama_rs::generation::embeddings::request::{EmbeddingsInput, GenerateEmbeddingsRequest};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 use std::path::PathBuf;
 use std::sync::{Arc, Weak};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, trace, warn};
 use uuid::Uuid;
 
@@ -55,6 +60,10 @@ pub struct LocalAIController {
   store_preferences: Weak<KVStorePreferences>,
   user_service: Arc<dyn AIUserService>,
   pub(crate) ollama: ArcSwapOption<Ollama>,
+  /// Cancellation tokens of the Ollama model pulls currently running.
+  pulling_models: Arc<DashMap<String, CancellationToken>>,
+  /// Health supervisor of the Ollama runtime; runs while local AI is enabled.
+  watchdog: LocalAIWatchdog,
 }
 
 impl Deref for LocalAIController {
@@ -93,9 +102,15 @@ impl LocalAIController {
       store_preferences,
       user_service,
       ollama,
+      pulling_models: Arc::new(DashMap::new()),
+      watchdog: LocalAIWatchdog::new(),
     }
   }
 
+  pub fn watchdog(&self) -> &LocalAIWatchdog {
+    &self.watchdog
+  }
+
   pub async fn reload_ollama_client(&self, workspace_id: &str) {
     if !self.is_enabled_on_workspace(workspace_id) {
       #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
@@ -105,6 +120,7 @@ impl LocalAIController {
         shared.set_ollama(None);
         self.ollama.store(None);
       }
+      self.watchdog.stop();
       return;
     }
 
@@ -134,6 +150,7 @@ impl LocalAIController {
           }
         }
         self.ollama.store(Some(new_ollama.clone()));
+        self.watchdog.start(setting.ollama_server_url.clone());
       },
       Err(err) => error!(
         "[Local AI] failed to create ollama client: {:?}, thread: {:?}",
@@ -223,6 +240,9 @@ impl LocalAIController {
     };
     self.current_chat_id.store(Some(Arc::new(*chat_id)));
     trace!("[Chat] open chat: {}", chat_id);
+    if let Ok(registry) = self.model_registry() {
+      let _ = registry.touch(model, timestamp());
+    }
     self.llm_controller.open_chat(info).await?;
     Ok(())
   }
@@ -350,6 +370,104 @@ impl LocalAIController {
       .map(|path| path.to_string_lossy().to_string())
   }
 
+  /// The registry of model files installed in the local model folder.
+  fn model_registry(&self) -> FlowyResult<LocalModelRegistry> {
+    Ok(LocalModelRegistry::new(self.resource.user_model_folder()?))
+  }
+
+  /// Returns the metadata of every installed model file. Scanning hashes any
+  /// newly discovered file, so it runs on a blocking thread.
+  pub async fn get_installed_models(&self) -> FlowyResult<Vec<InstalledModel>> {
+    let registry = self.model_registry()?;
+    tokio::task::spawn_blocking(move || registry.scan()).await?
+  }
+
+  /// Deletes an installed model file. Models referenced by the current local
+  /// AI setting are refused, since the runtime may have them loaded.
+  pub async fn delete_installed_model(&self, file_name: &str) -> FlowyResult<()> {
+    if self.is_model_in_use(file_name) {
+      return Err(FlowyError::local_ai().with_context(format!(
+        "Model {} is in use by the local AI runtime and can't be deleted",
+        file_name
+      )));
+    }
+    let registry = self.model_registry()?;
+    let file_name = file_name.to_string();
+    tokio::task::spawn_blocking(move || registry.delete(&file_name)).await?
+  }
+
+  /// Re-hashes an installed model file and compares it against the hash
+  /// recorded at install time. Returns whether they match, along with the
+  /// freshly computed hash.
+  pub async fn verify_installed_model(&self, file_name: &str) -> FlowyResult<(bool, String)> {
+    let registry = self.model_registry()?;
+    let file_name = file_name.to_string();
+    tokio::task::spawn_blocking(move || registry.verify(&file_name)).await?
+  }
+
+  /// Starts pulling a model from the configured Ollama server. Progress is
+  /// streamed via [ChatNotification::DidUpdateOllamaModelPull]; a final
+  /// notification with `done` set (or `error` on failure) closes the pull.
+  pub fn start_ollama_model_pull(&self, model_name: &str) -> FlowyResult<()> {
+    if self.pulling_models.contains_key(model_name) {
+      return Err(FlowyError::local_ai().with_context(format!(
+        "Model {} is already being pulled",
+        model_name
+      )));
+    }
+    let cancel_token = CancellationToken::new();
+    self
+      .pulling_models
+      .insert(model_name.to_string(), cancel_token.clone());
+
+    let server_url = self.resource.get_llm_setting().ollama_server_url;
+    let model_name = model_name.to_string();
+    let pulling_models = self.pulling_models.clone();
+    tokio::spawn(async move {
+      let result = pull_ollama_model(&server_url, &model_name, cancel_token, |progress| {
+        send_pull_progress(&model_name, progress, false, None);
+      })
+      .await;
+      pulling_models.remove(&model_name);
+      match result {
+        Ok(()) => {
+          info!("[Ollama] finished pulling model {}", model_name);
+          send_pull_progress(&model_name, PullProgress::default(), true, None);
+        },
+        Err(err) => {
+          error!("[Ollama] failed to pull model {}: {}", model_name, err);
+          send_pull_progress(
+            &model_name,
+            PullProgress::default(),
+            true,
+            Some(err.to_string()),
+          );
+        },
+      }
+    });
+    Ok(())
+  }
+
+  /// Cancels a running Ollama model pull, if any.
+  pub fn cancel_ollama_model_pull(&self, model_name: &str) {
+    if let Some((_, cancel_token)) = self.pulling_models.remove(model_name) {
+      cancel_token.cancel();
+    }
+  }
+
+  /// True when the file belongs to a model the current local AI setting
+  /// points at, i.e. one the runtime may have loaded.
+  fn is_model_in_use(&self, file_name: &str) -> bool {
+    let setting = self.resource.get_llm_setting();
+    let stem = file_name.rsplit_once('.').map_or(file_name, |(stem, _)| stem);
+    [setting.chat_model_name, setting.embedding_model_name]
+      .iter()
+      .any(|model| {
+        let model_name = model.rsplit_once(':').map_or(model.as_str(), |(name, _)| name);
+        file_name == *model || stem == *model || stem == model_name
+      })
+  }
+
   pub async fn toggle_local_ai(&self) -> FlowyResult<bool> {
     let workspace_id = self.user_service.workspace_id()?;
     let key = local_ai_enabled_key(&workspace_id.to_string());
@@ -485,6 +603,27 @@ impl LocalAIController {
 }
 
 #[instrument(level = "debug", skip_all, err)]
+fn send_pull_progress(
+  model_name: &str,
+  progress: PullProgress,
+  done: bool,
+  error: Option<String>,
+) {
+  chat_notification_builder(
+    APPFLOWY_AI_NOTIFICATION_KEY,
+    ChatNotification::DidUpdateOllamaModelPull,
+  )
+  .payload(OllamaPullProgressPB {
+    model_name: model_name.to_string(),
+    status: progress.status,
+    total_bytes: progress.total as i64,
+    downloaded_bytes: progress.completed as i64,
+    done,
+    error,
+  })
+  .send();
+}
+
 async fn check_local_ai_resources(
   llm_resource: &Arc<LocalAIResourceController>,
   llm_controller: &LLMChatController,
@@ -1,6 +1,7 @@
 use crate::local_ai::chat::llm::LLMOllama;
-use flowy_database_pub::cloud::SummaryRowContent;
+use flowy_database_pub::cloud::{SummaryRowContent, SummaryStream};
 use flowy_error::{FlowyError, FlowyResult};
+use futures_util::StreamExt;
 use langchain_rust::language_models::llm::LLM;
 use langchain_rust::schemas::Message;
 
@@ -38,8 +39,31 @@ impl DatabaseSummaryChain {
   }
 
   pub async fn summarize(&self, data: SummaryRowContent) -> FlowyResult<String> {
-    let input_text = self.format_summary_data(&data);
-    self.summarize_text(input_text).await
+    let messages = self.build_messages(&data);
+    match self.llm.generate(&messages).await {
+      Ok(response) => Ok(response.generation),
+      Err(err) => {
+        Err(FlowyError::internal().with_context(format!("Error generating summary: {}", err)))
+      },
+    }
+  }
+
+  /// Same as [Self::summarize], but yields the summary as partial text
+  /// chunks while the model generates it.
+  pub async fn summarize_stream(&self, data: SummaryRowContent) -> FlowyResult<SummaryStream> {
+    let messages = self.build_messages(&data);
+    let stream = self
+      .llm
+      .stream(&messages)
+      .await
+      .map_err(|err| FlowyError::local_ai().with_context(err))?
+      .map(|result| {
+        result
+          .map(|data| data.content)
+          .map_err(|err| FlowyError::local_ai().with_context(err))
+      })
+      .boxed();
+    Ok(stream)
   }
 
   fn format_summary_data(&self, data: &SummaryRowContent) -> String {
@@ -54,20 +78,15 @@ impl DatabaseSummaryChain {
     formatted_items.join("\n")
   }
 
-  async fn summarize_text(&self, text: String) -> FlowyResult<String> {
+  fn build_messages(&self, data: &SummaryRowContent) -> Vec<Message> {
+    let input_text = self.format_summary_data(data);
     let system_prompt =
       SUMMARIZE_SYSTEM_PROMPT.replace("{good_format_example}", SUMMARY_GOOD_FORMAT_EXAMPLE);
-    let user_prompt = SUMMARIZE_USER_PROMPT.replace("{input}", &text);
+    let user_prompt = SUMMARIZE_USER_PROMPT.replace("{input}", &input_text);
 
-    let system_prompt = Message::new_system_message(system_prompt);
-    let user_prompt = Message::new_human_message(user_prompt);
-    let messages = vec![system_prompt, user_prompt];
-
-    match self.llm.generate(&messages).await {
-      Ok(response) => Ok(response.generation),
-      Err(err) => {
-        Err(FlowyError::internal().with_context(format!("Error generating summary: {}", err)))
-      },
-    }
+    vec![
+      Message::new_system_message(system_prompt),
+      Message::new_human_message(user_prompt),
+    ]
   }
 }
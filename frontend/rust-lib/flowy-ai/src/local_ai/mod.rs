@@ -1,6 +1,9 @@
 pub mod controller;
+pub mod model_registry;
+pub mod ollama_pull;
 mod request;
 pub mod resource;
+pub mod watchdog;
 
 pub mod chat;
 pub mod completion;
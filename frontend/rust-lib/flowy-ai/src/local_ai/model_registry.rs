@@ -0,0 +1,185 @@
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tracing::trace;
+
+/// The index file inside the model folder that holds the metadata of every
+/// installed model.
+const REGISTRY_FILE_NAME: &str = "registry.json";
+
+/// Metadata of one installed local model file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstalledModel {
+  pub file_name: String,
+  pub size_in_bytes: u64,
+  /// Hex encoded SHA256 of the file content, computed when the model is
+  /// first seen and used by [LocalModelRegistry::verify].
+  pub sha256: String,
+  /// Quantization tag parsed from the file name, e.g. `Q4_K_M`. Empty when
+  /// the file name carries no recognizable tag.
+  pub quantization: String,
+  /// Timestamp of the last time the model was loaded, in seconds.
+  pub last_used_at: i64,
+}
+
+/// Tracks the model files installed in the local model folder. The metadata
+/// lives in a JSON index next to the models and is reconciled with the files
+/// on disk on every scan, so models added or removed outside the app are
+/// picked up as well.
+pub struct LocalModelRegistry {
+  model_dir: PathBuf,
+}
+
+impl LocalModelRegistry {
+  pub fn new(model_dir: PathBuf) -> Self {
+    Self { model_dir }
+  }
+
+  /// Returns the metadata of every installed model, reconciling the index
+  /// with the files on disk: new files are hashed and added, entries whose
+  /// file disappeared are dropped.
+  pub fn scan(&self) -> FlowyResult<Vec<InstalledModel>> {
+    let mut models = self.load_index();
+    let mut changed = false;
+
+    models.retain(|model| {
+      let exists = self.model_dir.join(&model.file_name).is_file();
+      if !exists {
+        trace!("[Model Registry] dropping missing model {}", model.file_name);
+        changed = true;
+      }
+      exists
+    });
+
+    if self.model_dir.is_dir() {
+      for entry in fs::read_dir(&self.model_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+          continue;
+        };
+        if !path.is_file()
+          || file_name == REGISTRY_FILE_NAME
+          || file_name.ends_with(".part")
+          || models.iter().any(|model| model.file_name == file_name)
+        {
+          continue;
+        }
+        trace!("[Model Registry] indexing new model {}", file_name);
+        let metadata = entry.metadata()?;
+        models.push(InstalledModel {
+          file_name: file_name.to_string(),
+          size_in_bytes: metadata.len(),
+          sha256: file_sha256(&path)?,
+          quantization: parse_quantization(file_name),
+          last_used_at: 0,
+        });
+        changed = true;
+      }
+    }
+
+    if changed {
+      self.save_index(&models)?;
+    }
+    Ok(models)
+  }
+
+  /// The combined size of every installed model, in bytes.
+  pub fn total_disk_usage(&self) -> FlowyResult<u64> {
+    Ok(self.scan()?.iter().map(|model| model.size_in_bytes).sum())
+  }
+
+  /// Re-hashes the model file and compares it against the hash recorded when
+  /// the model was installed. Returns the freshly computed hash along with
+  /// the comparison result.
+  pub fn verify(&self, file_name: &str) -> FlowyResult<(bool, String)> {
+    let model = self
+      .scan()?
+      .into_iter()
+      .find(|model| model.file_name == file_name)
+      .ok_or_else(|| {
+        FlowyError::record_not_found().with_context(format!("Model {} is not installed", file_name))
+      })?;
+    let sha256 = file_sha256(&self.model_dir.join(file_name))?;
+    Ok((sha256 == model.sha256, sha256))
+  }
+
+  /// Removes the model file and its index entry.
+  pub fn delete(&self, file_name: &str) -> FlowyResult<()> {
+    let path = self.model_dir.join(file_name);
+    if !path.is_file() {
+      return Err(
+        FlowyError::record_not_found()
+          .with_context(format!("Model {} is not installed", file_name)),
+      );
+    }
+    fs::remove_file(&path)?;
+    let mut models = self.load_index();
+    models.retain(|model| model.file_name != file_name);
+    self.save_index(&models)
+  }
+
+  /// Records that the model was loaded, for the "last used" column. The name
+  /// may be the file name or the model name without the file extension.
+  pub fn touch(&self, name: &str, timestamp: i64) -> FlowyResult<()> {
+    let mut models = self.load_index();
+    if let Some(model) = models.iter_mut().find(|model| {
+      let stem = model
+        .file_name
+        .rsplit_once('.')
+        .map_or(model.file_name.as_str(), |(stem, _)| stem);
+      model.file_name == name || stem == name
+    }) {
+      model.last_used_at = timestamp;
+      self.save_index(&models)?;
+    }
+    Ok(())
+  }
+
+  fn load_index(&self) -> Vec<InstalledModel> {
+    fs::read_to_string(self.model_dir.join(REGISTRY_FILE_NAME))
+      .ok()
+      .and_then(|content| serde_json::from_str(&content).ok())
+      .unwrap_or_default()
+  }
+
+  fn save_index(&self, models: &[InstalledModel]) -> FlowyResult<()> {
+    fs::create_dir_all(&self.model_dir)?;
+    let content = serde_json::to_string_pretty(models)
+      .map_err(|err| FlowyError::internal().with_context(err))?;
+    fs::write(self.model_dir.join(REGISTRY_FILE_NAME), content)?;
+    Ok(())
+  }
+}
+
+/// Parses the quantization tag out of a model file name, e.g. `Q4_K_M` from
+/// `llama-2-7b.Q4_K_M.gguf` or `F16` from `mistral-7b-f16.gguf`.
+fn parse_quantization(file_name: &str) -> String {
+  file_name
+    .split(['.', '-'])
+    .find(|token| {
+      let mut chars = token.chars();
+      matches!(chars.next(), Some('Q' | 'q' | 'F' | 'f'))
+        && chars.next().is_some_and(|ch| ch.is_ascii_digit())
+    })
+    .map(|token| token.to_ascii_uppercase())
+    .unwrap_or_default()
+}
+
+/// Hex encoded SHA256 of the file content, read in 1 MB blocks.
+fn file_sha256(path: &Path) -> FlowyResult<String> {
+  let mut file = File::open(path)?;
+  let mut hasher = Sha256::new();
+  let mut buffer = vec![0; 2_usize.pow(20)];
+  loop {
+    let bytes_read = file.read(&mut buffer)?;
+    if bytes_read == 0 {
+      break;
+    }
+    hasher.update(&buffer[..bytes_read]);
+  }
+  Ok(format!("{:x}", hasher.finalize()))
+}
@@ -0,0 +1,76 @@
+use flowy_error::{FlowyError, FlowyResult};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+use tracing::trace;
+
+/// One progress line reported by the Ollama server while pulling a model.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PullProgress {
+  #[serde(default)]
+  pub status: String,
+  #[serde(default)]
+  pub total: u64,
+  #[serde(default)]
+  pub completed: u64,
+  #[serde(default)]
+  pub error: Option<String>,
+}
+
+/// Pulls a model from the Ollama server's `/api/pull` endpoint, invoking
+/// `on_progress` for every status line the server streams back. Returns once
+/// the pull finished, was canceled or the server reported an error.
+pub async fn pull_ollama_model(
+  server_url: &str,
+  model_name: &str,
+  cancel_token: CancellationToken,
+  on_progress: impl Fn(PullProgress),
+) -> FlowyResult<()> {
+  let client = Client::new();
+  let mut response = client
+    .post(format!("{}/api/pull", server_url))
+    .json(&json!({ "model": model_name }))
+    .send()
+    .await
+    .map_err(|err| FlowyError::http().with_context(err))?;
+  if !response.status().is_success() {
+    return Err(
+      FlowyError::http().with_context(format!("Ollama pull failed: {}", response.status())),
+    );
+  }
+
+  // The server streams newline-delimited JSON; chunks may split a line, so
+  // buffer until a full line arrives.
+  let mut buffer = String::new();
+  while let Some(chunk) = response
+    .chunk()
+    .await
+    .map_err(|err| FlowyError::http().with_context(err))?
+  {
+    if cancel_token.is_cancelled() {
+      trace!("[Ollama] pull of {} canceled by client", model_name);
+      return Err(FlowyError::local_ai().with_context("Model pull canceled"));
+    }
+    buffer.push_str(&String::from_utf8_lossy(&chunk));
+    while let Some(newline) = buffer.find('\n') {
+      let line = buffer[..newline].trim().to_string();
+      buffer.drain(..=newline);
+      if line.is_empty() {
+        continue;
+      }
+      let progress: PullProgress = match serde_json::from_str(&line) {
+        Ok(progress) => progress,
+        Err(err) => {
+          trace!("[Ollama] unexpected pull progress line {}: {}", line, err);
+          continue;
+        },
+      };
+      if let Some(error) = progress.error {
+        return Err(FlowyError::local_ai().with_context(error));
+      }
+      on_progress(progress);
+    }
+  }
+  Ok(())
+}
@@ -39,16 +39,20 @@ Singapore’s landmarks blend modern architecture with natural beauty. Here’s
 
 These attractions highlight Singapore’s blend of modernity and greenery, offering visitors diverse experiences."#;
 
-pub fn format_prompt(format: &ResponseFormat) -> Message {
+pub fn format_instruction(format: &ResponseFormat) -> &'static str {
   match format.output_layout {
-    OutputLayout::Paragraph => Message::new_system_message(OPEN_AI_QA_PARAGRAPH_FORMAT),
-    OutputLayout::BulletList => Message::new_system_message(OPEN_AI_QA_BULLET_LIST_FORMAT),
-    OutputLayout::NumberedList => Message::new_system_message(OPEN_AI_QA_NUMBER_LIST_FORMAT),
-    OutputLayout::SimpleTable => Message::new_system_message(OPEN_AI_QA_SIMPLE_TABLE_FORMAT),
-    OutputLayout::Flex => Message::new_system_message(OPEN_AI_QA_FLEX_FORMAT),
+    OutputLayout::Paragraph => OPEN_AI_QA_PARAGRAPH_FORMAT,
+    OutputLayout::BulletList => OPEN_AI_QA_BULLET_LIST_FORMAT,
+    OutputLayout::NumberedList => OPEN_AI_QA_NUMBER_LIST_FORMAT,
+    OutputLayout::SimpleTable => OPEN_AI_QA_SIMPLE_TABLE_FORMAT,
+    OutputLayout::Flex => OPEN_AI_QA_FLEX_FORMAT,
   }
 }
 
+pub fn format_prompt(format: &ResponseFormat) -> Message {
+  Message::new_system_message(format_instruction(format))
+}
+
 pub fn history_prompt(history: Option<Vec<CompletionMessage>>) -> Vec<Message> {
   let mut messages = vec![];
   if let Some(history) = history {
@@ -5,8 +5,9 @@ use reqwest::{Client, Response, StatusCode};
 use sha2::{Digest, Sha256};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
-use tokio::fs::{self, File};
+use tokio::fs::{self, OpenOptions};
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
 use tokio_util::sync::CancellationToken;
@@ -15,6 +16,8 @@ use tracing::{instrument, trace};
 #[allow(dead_code)]
 type ProgressCallback = Arc<dyn Fn(u64, u64) + Send + Sync>;
 
+/// Downloads a model file, using several ranged connections when the server
+/// supports them and a single resumable stream otherwise.
 #[instrument(level = "trace", skip_all, err)]
 pub async fn download_model(
   url: &str,
@@ -22,14 +25,60 @@ pub async fn download_model(
   model_filename: &str,
   progress_callback: Option<ProgressCallback>,
   cancel_token: Option<CancellationToken>,
+) -> Result<PathBuf, anyhow::Error> {
+  download_model_segmented(
+    url,
+    model_path,
+    model_filename,
+    SegmentedDownloadOptions::default(),
+    progress_callback,
+    cancel_token,
+  )
+  .await
+}
+
+/// The single-stream download path: one connection, resuming from an
+/// existing `.part` file when the server honors the Range request.
+#[instrument(level = "trace", skip_all, err)]
+async fn download_model_single_stream(
+  url: &str,
+  model_path: &Path,
+  model_filename: &str,
+  progress_callback: Option<ProgressCallback>,
+  cancel_token: Option<CancellationToken>,
 ) -> Result<PathBuf, anyhow::Error> {
   let client = Client::new();
-  let mut response = make_request(&client, url, None).await?;
-  let total_size_in_bytes = response.content_length().unwrap_or(0);
   let partial_path = model_path.join(format!("{}.part", model_filename));
   let download_path = model_path.join(model_filename);
-  let mut part_file = File::create(&partial_path).await?;
-  let mut downloaded: u64 = 0;
+
+  // The length of an existing `.part` file is the number of bytes that were
+  // already persisted by an interrupted download; ask the server to resume
+  // from there.
+  let existing_len = match fs::metadata(&partial_path).await {
+    Ok(metadata) => metadata.len(),
+    Err(_) => 0,
+  };
+  let offset = (existing_len > 0).then_some(existing_len);
+  let mut response = make_request(&client, url, offset).await?;
+  let resumed = offset.is_some() && response.status() == StatusCode::PARTIAL_CONTENT;
+
+  let mut part_file = OpenOptions::new()
+    .read(true)
+    .write(true)
+    .create(true)
+    .open(&partial_path)
+    .await?;
+  let mut downloaded: u64 = if resumed {
+    part_file.seek(tokio::io::SeekFrom::End(0)).await?;
+    existing_len
+  } else {
+    // The server ignored the Range request or there was nothing to resume;
+    // start over from zero.
+    part_file.set_len(0).await?;
+    0
+  };
+  // On a resumed download the content length only covers the remaining bytes.
+  let total_size_in_bytes = response.content_length().unwrap_or(0) + downloaded;
 
   let debounce_duration = Duration::from_millis(100);
   let mut last_update = Instant::now()
@@ -58,44 +107,280 @@ pub async fn download_model(
   }
 
   // Verify file integrity
-  let header_sha256 = response
+  let header_sha256 = sha256_header(&response);
+  verify_sha256(&partial_path, header_sha256).await?;
+
+  fs::rename(&partial_path, &download_path).await?;
+  Ok(download_path)
+}
+
+/// Configuration for [download_model_segmented].
+pub struct SegmentedDownloadOptions {
+  /// Number of parallel ranged connections.
+  pub connections: usize,
+  /// Aggregate download rate cap in bytes per second, across all
+  /// connections. `None` means unthrottled.
+  pub max_bytes_per_sec: Option<u64>,
+}
+
+impl Default for SegmentedDownloadOptions {
+  fn default() -> Self {
+    Self {
+      connections: 4,
+      max_bytes_per_sec: None,
+    }
+  }
+}
+
+/// Downloads a model over several ranged connections writing into a
+/// preallocated file, with an optional aggregate bandwidth cap and the same
+/// SHA256 validation as the single-stream path. Falls back to that path when
+/// the server lacks range support or does not report a total size.
+#[instrument(level = "trace", skip_all, err)]
+pub async fn download_model_segmented(
+  url: &str,
+  model_path: &Path,
+  model_filename: &str,
+  options: SegmentedDownloadOptions,
+  progress_callback: Option<ProgressCallback>,
+  cancel_token: Option<CancellationToken>,
+) -> Result<PathBuf, anyhow::Error> {
+  let client = Client::new();
+  // Probe range support with a one-byte request.
+  let probe = client
+    .get(url)
+    .header("Range", "bytes=0-0")
+    .send()
+    .await?;
+  let total_size = content_range_total(&probe).unwrap_or(0);
+  if probe.status() != StatusCode::PARTIAL_CONTENT || total_size == 0 || options.connections <= 1 {
+    trace!("Server lacks range support; falling back to single-stream download");
+    return download_model_single_stream(
+      url,
+      model_path,
+      model_filename,
+      progress_callback,
+      cancel_token,
+    )
+    .await;
+  }
+  let header_sha256 = sha256_header(&probe);
+  drop(probe);
+
+  let partial_path = model_path.join(format!("{}.part", model_filename));
+  let download_path = model_path.join(model_filename);
+
+  // Preallocate the full file so every connection can write its segment at
+  // its own offset.
+  let file = OpenOptions::new()
+    .write(true)
+    .create(true)
+    .open(&partial_path)
+    .await?;
+  file.set_len(total_size).await?;
+  drop(file);
+
+  let downloaded = Arc::new(AtomicU64::new(0));
+  let throttle = options
+    .max_bytes_per_sec
+    .map(|max| Arc::new(BandwidthThrottle::new(max)));
+
+  let mut tasks = vec![];
+  for (index, (start, end)) in plan_segments(total_size, options.connections)
+    .into_iter()
+    .enumerate()
+  {
+    let client = client.clone();
+    let url = url.to_string();
+    let partial_path = partial_path.clone();
+    let downloaded = downloaded.clone();
+    let throttle = throttle.clone();
+    let cancel_token = cancel_token.clone();
+    tasks.push(tokio::spawn(async move {
+      let mut response = client
+        .get(&url)
+        .header("Range", format!("bytes={}-{}", start, end))
+        .send()
+        .await?;
+      if response.status() != StatusCode::PARTIAL_CONTENT {
+        return Err(anyhow!(
+          "Server returned {} for segment {}",
+          response.status(),
+          index
+        ));
+      }
+      let mut file = OpenOptions::new().write(true).open(&partial_path).await?;
+      file.seek(tokio::io::SeekFrom::Start(start)).await?;
+      while let Some(chunk) = response.chunk().await? {
+        if let Some(cancel_token) = &cancel_token {
+          if cancel_token.is_cancelled() {
+            trace!("Download canceled by client");
+            return Err(anyhow!("Download canceled"));
+          }
+        }
+        file.write_all(&chunk).await?;
+        downloaded.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+        if let Some(throttle) = &throttle {
+          throttle.consume(chunk.len() as u64).await;
+        }
+      }
+      Ok::<(), anyhow::Error>(())
+    }));
+  }
+
+  // Report aggregate progress across all connections until they finish.
+  let progress_task = progress_callback.clone().map(|progress_callback| {
+    let downloaded = downloaded.clone();
+    let stop = CancellationToken::new();
+    let reporter = stop.clone();
+    let handle = tokio::spawn(async move {
+      while !reporter.is_cancelled() {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        progress_callback(downloaded.load(Ordering::Relaxed), total_size);
+      }
+    });
+    (stop, handle)
+  });
+  let results = futures::future::join_all(tasks).await;
+  if let Some((stop, handle)) = progress_task {
+    stop.cancel();
+    let _ = handle.await;
+  }
+  for result in results {
+    if let Err(err) = result? {
+      if cancel_token
+        .as_ref()
+        .map(|token| token.is_cancelled())
+        .unwrap_or(false)
+      {
+        let _ = fs::remove_file(&partial_path).await;
+      }
+      return Err(err);
+    }
+  }
+  if let Some(progress_callback) = &progress_callback {
+    progress_callback(total_size, total_size);
+  }
+
+  verify_sha256(&partial_path, header_sha256).await?;
+
+  fs::rename(&partial_path, &download_path).await?;
+  Ok(download_path)
+}
+
+/// Splits `total_size` bytes into up to `connections` contiguous inclusive
+/// byte ranges that tile the file exactly once, one range per connection.
+fn plan_segments(total_size: u64, connections: usize) -> Vec<(u64, u64)> {
+  if total_size == 0 || connections == 0 {
+    return vec![];
+  }
+  let connections = connections as u64;
+  let segment_len = total_size.div_ceil(connections);
+  let mut segments = vec![];
+  for index in 0..connections {
+    let start = index * segment_len;
+    if start >= total_size {
+      break;
+    }
+    let end = ((index + 1) * segment_len).min(total_size) - 1;
+    segments.push((start, end));
+  }
+  segments
+}
+
+/// A token bucket shared by every connection: accounts for each chunk and
+/// pauses all downloads when the aggregate rate exceeds the cap.
+struct BandwidthThrottle {
+  max_bytes_per_sec: u64,
+  window: tokio::sync::Mutex<(Instant, u64)>,
+}
+
+impl BandwidthThrottle {
+  fn new(max_bytes_per_sec: u64) -> Self {
+    Self {
+      max_bytes_per_sec,
+      window: tokio::sync::Mutex::new((Instant::now(), 0)),
+    }
+  }
+
+  async fn consume(&self, bytes: u64) {
+    let mut window = self.window.lock().await;
+    let (start, used) = *window;
+    let elapsed = start.elapsed();
+    if elapsed >= Duration::from_secs(1) {
+      *window = (Instant::now(), bytes);
+      return;
+    }
+    let used = used + bytes;
+    if used >= self.max_bytes_per_sec {
+      // Sleeping while holding the lock pauses every connection.
+      tokio::time::sleep(Duration::from_secs(1).saturating_sub(elapsed)).await;
+      *window = (Instant::now(), 0);
+    } else {
+      *window = (start, used);
+    }
+  }
+}
+
+/// The total size reported in a `Content-Range: bytes 0-0/<total>` header.
+fn content_range_total(response: &Response) -> Option<u64> {
+  response
+    .headers()
+    .get(reqwest::header::CONTENT_RANGE)?
+    .to_str()
+    .ok()?
+    .rsplit('/')
+    .next()?
+    .parse()
+    .ok()
+}
+
+/// The expected file hash from the `SHA256` response header, if any.
+fn sha256_header(response: &Response) -> Option<Vec<u8>> {
+  response
     .headers()
     .get("SHA256")
     .and_then(|value| value.to_str().ok())
-    .and_then(|value| STANDARD.decode(value).ok());
+    .and_then(|value| STANDARD.decode(value).ok())
+}
 
-  part_file.seek(tokio::io::SeekFrom::Start(0)).await?;
+/// Hashes the downloaded file and compares it against the expected hash,
+/// removing the file on a mismatch. Without an expected hash the file is
+/// accepted as-is.
+async fn verify_sha256(
+  partial_path: &Path,
+  header_sha256: Option<Vec<u8>>,
+) -> Result<(), anyhow::Error> {
+  let Some(header_sha256) = header_sha256 else {
+    return Ok(());
+  };
+  let mut file = OpenOptions::new().read(true).open(partial_path).await?;
   let mut hasher = Sha256::new();
   let block_size = 2_usize.pow(20); // 1 MB
   let mut buffer = vec![0; block_size];
-  while let Ok(bytes_read) = part_file.read(&mut buffer).await {
+  while let Ok(bytes_read) = file.read(&mut buffer).await {
     if bytes_read == 0 {
       break;
     }
     hasher.update(&buffer[..bytes_read]);
   }
   let calculated_sha256 = hasher.finalize();
-  if let Some(header_sha256) = header_sha256 {
-    if calculated_sha256.as_slice() != header_sha256.as_slice() {
-      trace!(
-        "Header Sha256: {:?}, calculated Sha256:{:?}",
-        header_sha256, calculated_sha256
-      );
-
-      fs::remove_file(&partial_path).await?;
-      return Err(anyhow!(
-        "Sha256 mismatch: expected {:?}, got {:?}",
-        header_sha256,
-        calculated_sha256
-      ));
-    }
-  }
+  if calculated_sha256.as_slice() != header_sha256.as_slice() {
+    trace!(
+      "Header Sha256: {:?}, calculated Sha256:{:?}",
+      header_sha256, calculated_sha256
+    );
 
-  fs::rename(&partial_path, &download_path).await?;
-  Ok(download_path)
+    fs::remove_file(partial_path).await?;
+    return Err(anyhow!(
+      "Sha256 mismatch: expected {:?}, got {:?}",
+      header_sha256,
+      calculated_sha256
+    ));
+  }
+  Ok(())
 }
 
-#[allow(dead_code)]
 async fn make_request(
   client: &Client,
   url: &str,
@@ -115,3 +400,68 @@ async fn make_request(
   }
   Ok(response)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn segments_tile_the_file() {
+    for (total_size, connections) in [(1, 4), (7, 4), (8, 4), (100, 3), (4096, 1), (5, 16)] {
+      let segments = plan_segments(total_size, connections);
+      assert!(segments.len() <= connections);
+      // The ranges are contiguous, in order, and cover [0, total_size - 1].
+      assert_eq!(segments.first().unwrap().0, 0);
+      assert_eq!(segments.last().unwrap().1, total_size - 1);
+      for window in segments.windows(2) {
+        assert_eq!(window[0].1 + 1, window[1].0);
+      }
+      for (start, end) in segments {
+        assert!(start <= end);
+      }
+    }
+    assert!(plan_segments(0, 4).is_empty());
+    assert!(plan_segments(10, 0).is_empty());
+  }
+
+  #[tokio::test]
+  async fn segments_merge_into_the_original_bytes() {
+    let source: Vec<u8> = (0..1000u32).map(|byte| (byte % 251) as u8).collect();
+    let temp_dir = tempfile::tempdir().unwrap();
+    let partial_path = temp_dir.path().join("model.part");
+
+    // Preallocate the file, then write every planned segment at its own
+    // offset, mirroring what the ranged connections do.
+    let file = OpenOptions::new()
+      .write(true)
+      .create(true)
+      .open(&partial_path)
+      .await
+      .unwrap();
+    file.set_len(source.len() as u64).await.unwrap();
+    drop(file);
+    for (start, end) in plan_segments(source.len() as u64, 4) {
+      let mut file = OpenOptions::new().write(true).open(&partial_path).await.unwrap();
+      file.seek(tokio::io::SeekFrom::Start(start)).await.unwrap();
+      file
+        .write_all(&source[start as usize..=end as usize])
+        .await
+        .unwrap();
+    }
+
+    let merged = fs::read(&partial_path).await.unwrap();
+    assert_eq!(merged, source);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn throttle_pauses_when_the_cap_is_exceeded() {
+    let throttle = BandwidthThrottle::new(100);
+    let before = tokio::time::Instant::now();
+    // Under the cap: no pause.
+    throttle.consume(60).await;
+    assert_eq!(tokio::time::Instant::now(), before);
+    // Crossing the cap pauses until the one second window is over.
+    throttle.consume(60).await;
+    assert!(tokio::time::Instant::now() - before >= Duration::from_millis(900));
+  }
+}
@@ -0,0 +1,295 @@
+use crate::entities::{
+  LoadedModelPB, LocalAIDiagnosticsPB, LocalAIRuntimeStatePB, LocalAIRuntimeStatusPB,
+};
+use crate::notification::{
+  APPFLOWY_AI_NOTIFICATION_KEY, ChatNotification, chat_notification_builder,
+};
+use lib_infra::util::timestamp;
+use reqwest::Client;
+use serde::Deserialize;
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, warn};
+
+/// How often the runtime is probed while healthy.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+/// A probe slower than this marks the runtime degraded even when it answers.
+const DEGRADED_LATENCY: Duration = Duration::from_secs(2);
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// Failures in a row before the runtime counts as crashed rather than flaky.
+const CRASH_THRESHOLD: u32 = 2;
+const RESTART_BACKOFF_BASE: Duration = Duration::from_secs(2);
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(60);
+
+/// The executable spawned to bring a crashed local server back, when the
+/// configured server runs on this machine.
+const OLLAMA_EXECUTABLE: &str = "ollama";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeStatus {
+  /// Monitoring started but the first probe hasn't answered yet.
+  Starting,
+  Ready,
+  /// The runtime answers, but slowly or only partially.
+  Degraded,
+  Crashed,
+  /// Local AI is disabled; the watchdog is idle.
+  Stopped,
+}
+
+/// One model the runtime currently holds in memory, from Ollama's `/api/ps`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LoadedModel {
+  pub name: String,
+  #[serde(default)]
+  pub size: u64,
+  #[serde(default)]
+  pub size_vram: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct PsResponse {
+  #[serde(default)]
+  models: Vec<LoadedModel>,
+}
+
+#[derive(Debug, Clone)]
+struct Diagnostics {
+  status: RuntimeStatus,
+  consecutive_failures: u32,
+  restart_attempts: u32,
+  last_error: Option<String>,
+  last_check_at: i64,
+  loaded_models: Vec<LoadedModel>,
+}
+
+impl Default for Diagnostics {
+  fn default() -> Self {
+    Self {
+      status: RuntimeStatus::Stopped,
+      consecutive_failures: 0,
+      restart_attempts: 0,
+      last_error: None,
+      last_check_at: 0,
+      loaded_models: vec![],
+    }
+  }
+}
+
+/// Supervises the local AI runtime: probes liveness and load state on an
+/// interval, emits [ChatNotification::DidUpdateLocalAIRuntimeState] on every
+/// status change, and tries to bring a crashed local server back with
+/// exponential backoff.
+pub struct LocalAIWatchdog {
+  diagnostics: Arc<Mutex<Diagnostics>>,
+  task_token: Mutex<Option<CancellationToken>>,
+}
+
+impl LocalAIWatchdog {
+  pub fn new() -> Self {
+    Self {
+      diagnostics: Arc::new(Mutex::new(Diagnostics::default())),
+      task_token: Mutex::new(None),
+    }
+  }
+
+  /// Starts (or restarts) monitoring the runtime at `server_url`. A previous
+  /// monitoring task is canceled first.
+  pub fn start(&self, server_url: String) {
+    let token = CancellationToken::new();
+    if let Some(previous) = self.task_token.lock().unwrap().replace(token.clone()) {
+      previous.cancel();
+    }
+    info!("[AI Watchdog] start monitoring {}", server_url);
+    set_status(&self.diagnostics, RuntimeStatus::Starting);
+
+    let diagnostics = self.diagnostics.clone();
+    tokio::spawn(async move {
+      let client = Client::new();
+      loop {
+        let delay = check_once(&diagnostics, &client, &server_url).await;
+        tokio::select! {
+          _ = token.cancelled() => {
+            debug!("[AI Watchdog] monitoring of {} stopped", server_url);
+            return;
+          },
+          _ = tokio::time::sleep(delay) => {},
+        }
+      }
+    });
+  }
+
+  /// Stops monitoring, e.g. when local AI gets disabled.
+  pub fn stop(&self) {
+    if let Some(token) = self.task_token.lock().unwrap().take() {
+      token.cancel();
+    }
+    set_status(&self.diagnostics, RuntimeStatus::Stopped);
+  }
+
+  /// Snapshot of the runtime health for the settings screen.
+  pub fn diagnostics(&self) -> LocalAIDiagnosticsPB {
+    let diagnostics = self.diagnostics.lock().unwrap().clone();
+    LocalAIDiagnosticsPB {
+      state: runtime_state_pb(diagnostics.status),
+      consecutive_failures: diagnostics.consecutive_failures,
+      restart_attempts: diagnostics.restart_attempts,
+      last_error: diagnostics.last_error.unwrap_or_default(),
+      last_check_at: diagnostics.last_check_at,
+      loaded_models: diagnostics
+        .loaded_models
+        .into_iter()
+        .map(|model| LoadedModelPB {
+          name: model.name,
+          size_in_bytes: model.size,
+          vram_in_bytes: model.size_vram,
+        })
+        .collect(),
+    }
+  }
+}
+
+/// Runs one health check, updating the shared diagnostics, and returns how
+/// long to wait before the next one.
+async fn check_once(
+  diagnostics: &Arc<Mutex<Diagnostics>>,
+  client: &Client,
+  server_url: &str,
+) -> Duration {
+  let started = Instant::now();
+  let probe = client
+    .get(format!("{}/api/version", server_url))
+    .timeout(PROBE_TIMEOUT)
+    .send()
+    .await;
+
+  match probe {
+    Ok(response) if response.status().is_success() => {
+      let status = if started.elapsed() > DEGRADED_LATENCY {
+        RuntimeStatus::Degraded
+      } else {
+        RuntimeStatus::Ready
+      };
+      let loaded_models = fetch_loaded_models(client, server_url).await;
+      {
+        let mut guard = diagnostics.lock().unwrap();
+        guard.consecutive_failures = 0;
+        guard.restart_attempts = 0;
+        guard.last_error = None;
+        guard.last_check_at = timestamp();
+        guard.loaded_models = loaded_models;
+      }
+      set_status(diagnostics, status);
+      HEALTH_CHECK_INTERVAL
+    },
+    Ok(response) => {
+      record_failure(diagnostics, format!("Probe returned {}", response.status()));
+      handle_failure(diagnostics, server_url)
+    },
+    Err(err) => {
+      record_failure(diagnostics, err.to_string());
+      handle_failure(diagnostics, server_url)
+    },
+  }
+}
+
+fn record_failure(diagnostics: &Arc<Mutex<Diagnostics>>, error: String) {
+  let mut guard = diagnostics.lock().unwrap();
+  guard.consecutive_failures += 1;
+  guard.last_error = Some(error);
+  guard.last_check_at = timestamp();
+  guard.loaded_models.clear();
+}
+
+/// Decides what to do after a failed probe: a single failure only marks the
+/// runtime degraded, repeated ones mark it crashed and trigger a restart
+/// attempt with exponential backoff.
+fn handle_failure(diagnostics: &Arc<Mutex<Diagnostics>>, server_url: &str) -> Duration {
+  let (failures, restart_attempts) = {
+    let guard = diagnostics.lock().unwrap();
+    (guard.consecutive_failures, guard.restart_attempts)
+  };
+
+  if failures < CRASH_THRESHOLD {
+    set_status(diagnostics, RuntimeStatus::Degraded);
+    return HEALTH_CHECK_INTERVAL;
+  }
+
+  set_status(diagnostics, RuntimeStatus::Crashed);
+  if is_local_server(server_url) {
+    try_restart_server(diagnostics);
+  }
+  let backoff = RESTART_BACKOFF_BASE * 2u32.saturating_pow(restart_attempts);
+  backoff.min(RESTART_BACKOFF_MAX)
+}
+
+/// Spawns `ollama serve` detached. Restarting only makes sense for a server
+/// on this machine; remote servers just keep being probed.
+fn try_restart_server(diagnostics: &Arc<Mutex<Diagnostics>>) {
+  let attempt = {
+    let mut guard = diagnostics.lock().unwrap();
+    guard.restart_attempts += 1;
+    guard.restart_attempts
+  };
+  warn!("[AI Watchdog] runtime crashed, restart attempt {}", attempt);
+  match Command::new(OLLAMA_EXECUTABLE).arg("serve").spawn() {
+    Ok(_) => info!("[AI Watchdog] spawned {} serve", OLLAMA_EXECUTABLE),
+    Err(err) => error!(
+      "[AI Watchdog] failed to spawn {} serve: {}",
+      OLLAMA_EXECUTABLE, err
+    ),
+  }
+}
+
+fn is_local_server(server_url: &str) -> bool {
+  server_url.contains("localhost") || server_url.contains("127.0.0.1")
+}
+
+async fn fetch_loaded_models(client: &Client, server_url: &str) -> Vec<LoadedModel> {
+  let response = client
+    .get(format!("{}/api/ps", server_url))
+    .timeout(PROBE_TIMEOUT)
+    .send()
+    .await;
+  match response {
+    Ok(response) => response
+      .json::<PsResponse>()
+      .await
+      .map(|body| body.models)
+      .unwrap_or_default(),
+    Err(_) => vec![],
+  }
+}
+
+/// Updates the status and notifies the UI when it changed.
+fn set_status(diagnostics: &Arc<Mutex<Diagnostics>>, status: RuntimeStatus) {
+  let changed = {
+    let mut guard = diagnostics.lock().unwrap();
+    let changed = guard.status != status;
+    guard.status = status;
+    changed
+  };
+  if changed {
+    info!("[AI Watchdog] runtime status changed to {:?}", status);
+    chat_notification_builder(
+      APPFLOWY_AI_NOTIFICATION_KEY,
+      ChatNotification::DidUpdateLocalAIRuntimeState,
+    )
+    .payload(LocalAIRuntimeStatePB {
+      state: runtime_state_pb(status),
+    })
+    .send();
+  }
+}
+
+fn runtime_state_pb(status: RuntimeStatus) -> LocalAIRuntimeStatusPB {
+  match status {
+    RuntimeStatus::Starting => LocalAIRuntimeStatusPB::Starting,
+    RuntimeStatus::Ready => LocalAIRuntimeStatusPB::Ready,
+    RuntimeStatus::Degraded => LocalAIRuntimeStatusPB::Degraded,
+    RuntimeStatus::Crashed => LocalAIRuntimeStatusPB::Crashed,
+    RuntimeStatus::Stopped => LocalAIRuntimeStatusPB::Stopped,
+  }
+}
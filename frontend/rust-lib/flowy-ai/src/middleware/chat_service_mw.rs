@@ -1,5 +1,9 @@
+use crate::anthropic::AnthropicController;
+use crate::anthropic::client::AnthropicMessage;
 use crate::local_ai::controller::LocalAIController;
-use flowy_ai_pub::persistence::select_message_content;
+use crate::middleware::rate_limit::{AIRequestLimiter, ANTHROPIC_PROVIDER, SERVER_PROVIDER};
+use crate::token_usage::{context_window, count_tokens};
+use flowy_ai_pub::persistence::{select_chat_messages, select_message_content};
 use std::collections::HashMap;
 
 use flowy_ai_pub::cloud::{
@@ -15,15 +19,17 @@ use flowy_storage_pub::storage::StorageService;
 use serde_json::Value;
 use std::path::Path;
 use std::sync::{Arc, Weak};
-use tracing::{info, trace};
+use tracing::{info, trace, warn};
 use uuid::Uuid;
 
 pub struct ChatServiceMiddleware {
   cloud_service: Arc<dyn ChatCloudService>,
   user_service: Arc<dyn AIUserService>,
   local_ai: Arc<LocalAIController>,
+  anthropic: Arc<AnthropicController>,
   #[allow(dead_code)]
   storage_service: Weak<dyn StorageService>,
+  limiter: AIRequestLimiter,
 }
 
 impl ChatServiceMiddleware {
@@ -31,13 +37,16 @@ impl ChatServiceMiddleware {
     user_service: Arc<dyn AIUserService>,
     cloud_service: Arc<dyn ChatCloudService>,
     local_ai: Arc<LocalAIController>,
+    anthropic: Arc<AnthropicController>,
     storage_service: Weak<dyn StorageService>,
   ) -> Self {
     Self {
       user_service,
       cloud_service,
       local_ai,
+      anthropic,
       storage_service,
+      limiter: AIRequestLimiter::new(),
     }
   }
 
@@ -49,6 +58,53 @@ impl ChatServiceMiddleware {
     })?;
     Ok(content)
   }
+
+  /// Rebuild the conversation up to and including the question, in the
+  /// chronological user/assistant form provider APIs expect.
+  fn chat_history_for_question(
+    &self,
+    chat_id: &Uuid,
+    question_id: i64,
+  ) -> FlowyResult<Vec<AnthropicMessage>> {
+    const HISTORY_LIMIT: u64 = 20;
+    let uid = self.user_service.user_id()?;
+    let conn = self.user_service.sqlite_connection(uid)?;
+    let result = select_chat_messages(
+      conn,
+      &chat_id.to_string(),
+      HISTORY_LIMIT,
+      MessageCursor::NextBack,
+    )?;
+    // Messages come back newest first; drop anything written after the
+    // question (e.g. the placeholder answer) and restore chronological order.
+    let mut messages: Vec<AnthropicMessage> = result
+      .messages
+      .into_iter()
+      .filter(|row| row.message_id <= question_id)
+      .filter_map(|row| match row.author_type {
+        1 => Some(AnthropicMessage::user(row.content)),
+        3 => Some(AnthropicMessage::assistant(row.content)),
+        _ => None,
+      })
+      .collect();
+    messages.reverse();
+    if !matches!(messages.last(), Some(message) if message.role == "user") {
+      messages.push(AnthropicMessage::user(
+        self.get_message_content(question_id)?,
+      ));
+    }
+    Ok(messages)
+  }
+}
+
+/// Queue notifications for completions key off the object being generated so
+/// the observing UI (e.g. an AI cell) can show the wait.
+fn completion_progress_id(workspace_id: &Uuid, params: &CompleteTextParams) -> String {
+  params
+    .metadata
+    .as_ref()
+    .map(|metadata| metadata.object_id.to_string())
+    .unwrap_or_else(|| workspace_id.to_string())
 }
 
 #[async_trait]
@@ -115,10 +171,44 @@ impl ChatCloudService for ChatServiceMiddleware {
       } else {
         Err(FlowyError::local_ai_not_ready())
       }
+    } else if self
+      .anthropic
+      .owns_model(&workspace_id.to_string(), &ai_model.name)
+    {
+      let messages = self.chat_history_for_question(chat_id, question_id)?;
+      let prompt_tokens: u64 = messages
+        .iter()
+        .map(|message| count_tokens(&message.content, &ai_model.name))
+        .sum();
+      if prompt_tokens > context_window(&ai_model.name) {
+        warn!(
+          "[AI Chat] prompt is ~{} tokens, over the {} context window of {}; the provider may reject or truncate it",
+          prompt_tokens,
+          context_window(&ai_model.name),
+          ai_model.name
+        );
+      }
+      let workspace_id = workspace_id.to_string();
+      self
+        .limiter
+        .execute(ANTHROPIC_PROVIDER, &chat_id.to_string(), || {
+          self
+            .anthropic
+            .stream_answer(&workspace_id, &ai_model.name, &format, messages.clone())
+        })
+        .await
     } else {
       self
-        .cloud_service
-        .stream_answer(workspace_id, chat_id, question_id, format, ai_model)
+        .limiter
+        .execute(SERVER_PROVIDER, &chat_id.to_string(), || {
+          self.cloud_service.stream_answer(
+            workspace_id,
+            chat_id,
+            question_id,
+            format.clone(),
+            ai_model.clone(),
+          )
+        })
         .await
     }
   }
@@ -200,6 +290,16 @@ impl ChatCloudService for ChatServiceMiddleware {
           items: vec![],
         })
       }
+    } else if self
+      .anthropic
+      .owns_model(&workspace_id.to_string(), &ai_model.name)
+    {
+      // Related questions are generated server side; skip them for models the
+      // AppFlowy cloud doesn't know about.
+      Ok(RepeatedRelatedQuestion {
+        message_id,
+        items: vec![],
+      })
     } else {
       self
         .cloud_service
@@ -221,10 +321,29 @@ impl ChatCloudService for ChatServiceMiddleware {
       } else {
         Err(FlowyError::local_ai_not_ready())
       }
+    } else if self
+      .anthropic
+      .owns_model(&workspace_id.to_string(), &ai_model.name)
+    {
+      let progress_id = completion_progress_id(workspace_id, &params);
+      let workspace_id = workspace_id.to_string();
+      self
+        .limiter
+        .execute(ANTHROPIC_PROVIDER, &progress_id, || {
+          self
+            .anthropic
+            .stream_complete(&workspace_id, &ai_model.name, params.clone())
+        })
+        .await
     } else {
+      let progress_id = completion_progress_id(workspace_id, &params);
       self
-        .cloud_service
-        .stream_complete(workspace_id, params, ai_model)
+        .limiter
+        .execute(SERVER_PROVIDER, &progress_id, || {
+          self
+            .cloud_service
+            .stream_complete(workspace_id, params.clone(), ai_model.clone())
+        })
         .await
     }
   }
@@ -1 +1,2 @@
 pub(crate) mod chat_service_mw;
+pub(crate) mod rate_limit;
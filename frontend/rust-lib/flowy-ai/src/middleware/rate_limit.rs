@@ -0,0 +1,186 @@
+use crate::entities::AIRequestQueuePB;
+use crate::notification::{ChatNotification, chat_notification_builder};
+use dashmap::DashMap;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+use tracing::{debug, trace};
+
+pub const SERVER_PROVIDER: &str = "server";
+pub const ANTHROPIC_PROVIDER: &str = "anthropic";
+
+/// Sliding window over which requests are counted.
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+/// How often a queued caller re-reports its position while waiting.
+const QUEUE_REPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy)]
+pub struct ProviderLimit {
+  pub requests_per_minute: usize,
+  pub max_retries: u32,
+}
+
+fn default_limit(provider: &str) -> ProviderLimit {
+  match provider {
+    ANTHROPIC_PROVIDER => ProviderLimit {
+      requests_per_minute: 50,
+      max_retries: 3,
+    },
+    _ => ProviderLimit {
+      requests_per_minute: 60,
+      max_retries: 3,
+    },
+  }
+}
+
+struct ProviderState {
+  limit: ProviderLimit,
+  /// Start times of the requests admitted within the current window.
+  recent: Mutex<VecDeque<Instant>>,
+  /// Callers currently waiting for a slot, used for queue position reports.
+  waiting: AtomicU64,
+}
+
+/// Client-side rate limiter and retry policy for cloud AI providers. Bursts
+/// beyond a provider's limit queue instead of failing, with the position and
+/// estimated wait surfaced through `ChatNotification::DidUpdateAIRequestQueue`
+/// so e.g. AI cell generation over many rows degrades gracefully. Requests
+/// that still come back with 429 or a server error are retried with jittered
+/// exponential backoff.
+pub struct AIRequestLimiter {
+  providers: DashMap<String, Arc<ProviderState>>,
+}
+
+impl AIRequestLimiter {
+  pub fn new() -> Self {
+    Self {
+      providers: DashMap::new(),
+    }
+  }
+
+  fn provider_state(&self, provider: &str) -> Arc<ProviderState> {
+    self
+      .providers
+      .entry(provider.to_string())
+      .or_insert_with(|| {
+        Arc::new(ProviderState {
+          limit: default_limit(provider),
+          recent: Mutex::new(VecDeque::new()),
+          waiting: AtomicU64::new(0),
+        })
+      })
+      .clone()
+  }
+
+  /// Wait until the provider has a free slot. `progress_id` is the
+  /// notification key the caller's UI observes, typically the chat or object
+  /// id.
+  async fn acquire(&self, provider: &str, progress_id: &str) {
+    let state = self.provider_state(provider);
+    let mut queued = false;
+    loop {
+      let wait = {
+        let mut recent = state.recent.lock().await;
+        let now = Instant::now();
+        while recent
+          .front()
+          .is_some_and(|start| now.duration_since(*start) >= RATE_WINDOW)
+        {
+          recent.pop_front();
+        }
+        if recent.len() < state.limit.requests_per_minute {
+          recent.push_back(now);
+          None
+        } else {
+          recent
+            .front()
+            .map(|oldest| RATE_WINDOW - now.duration_since(*oldest))
+        }
+      };
+
+      match wait {
+        None => {
+          if queued {
+            state.waiting.fetch_sub(1, Ordering::Relaxed);
+          }
+          return;
+        },
+        Some(wait) => {
+          if !queued {
+            queued = true;
+            state.waiting.fetch_add(1, Ordering::Relaxed);
+          }
+          let position = state.waiting.load(Ordering::Relaxed);
+          debug!(
+            "[AI Rate Limit] {} queued at position {}, eta {:?}",
+            provider, position, wait
+          );
+          chat_notification_builder(progress_id, ChatNotification::DidUpdateAIRequestQueue)
+            .payload(AIRequestQueuePB {
+              provider: provider.to_string(),
+              position,
+              eta_seconds: wait.as_secs().max(1),
+            })
+            .send();
+          tokio::time::sleep(wait.min(QUEUE_REPORT_INTERVAL)).await;
+        },
+      }
+    }
+  }
+
+  /// Run `request` under the provider's rate limit, retrying rate limited and
+  /// server errors with jittered exponential backoff.
+  pub async fn execute<T, F, Fut>(
+    &self,
+    provider: &str,
+    progress_id: &str,
+    mut request: F,
+  ) -> FlowyResult<T>
+  where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = FlowyResult<T>>,
+  {
+    let max_retries = self.provider_state(provider).limit.max_retries;
+    let mut attempt = 0;
+    loop {
+      self.acquire(provider, progress_id).await;
+      match request().await {
+        Ok(value) => return Ok(value),
+        Err(err) if is_retryable(&err) && attempt < max_retries => {
+          attempt += 1;
+          let backoff = backoff_with_jitter(attempt);
+          trace!(
+            "[AI Rate Limit] {} request failed with {:?}, retry {}/{} in {:?}",
+            provider, err.code, attempt, max_retries, backoff
+          );
+          tokio::time::sleep(backoff).await;
+        },
+        Err(err) => return Err(err),
+      }
+    }
+  }
+}
+
+fn is_retryable(err: &FlowyError) -> bool {
+  matches!(
+    err.code,
+    ErrorCode::AIResponseLimitExceeded | ErrorCode::InternalServerError
+  )
+}
+
+/// Exponential backoff capped at [MAX_BACKOFF], with up to 250ms of jitter so
+/// retries from a burst don't land on the provider at the same instant.
+fn backoff_with_jitter(attempt: u32) -> Duration {
+  let exponential = BASE_BACKOFF * 2u32.saturating_pow(attempt.saturating_sub(1));
+  let jitter_ms = SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|d| u64::from(d.subsec_nanos()) % 250)
+    .unwrap_or(0);
+  exponential.min(MAX_BACKOFF) + Duration::from_millis(jitter_ms)
+}
@@ -1,3 +1,4 @@
+use crate::anthropic::AnthropicController;
 use crate::local_ai::controller::LocalAIController;
 use arc_swap::ArcSwapOption;
 use flowy_ai_pub::cloud::{AIModel, ChatCloudService};
@@ -81,7 +82,7 @@ impl ModelSelectionControl {
 
   /// Fetches all server‐side models and, if specified, a single local model by name.
   ///
-  /// First collects models from any source named `"server"`. Then it fetches all local models
+  /// First collects models from every non-local source. Then it fetches all local models
   /// (from the `"local"` source) and:
   /// - If `local_model_name` is `Some(name)`, it will append exactly that local model
   ///   if it exists.
@@ -93,9 +94,9 @@ impl ModelSelectionControl {
     local_model_name: Option<String>,
   ) -> Vec<Model> {
     let mut models = Vec::new();
-    // add server models
+    // add models from every non-local source (server, anthropic, ...)
     for source in &self.sources {
-      if source.source_name() == "server" {
+      if source.source_name() != "local" {
         let mut list = source.list_chat_models(workspace_id).await;
         models.append(&mut list);
       }
@@ -320,6 +321,29 @@ impl ModelSource for LocalAiSource {
   }
 }
 
+/// Models served by the Anthropic Messages API. The list is empty until the
+/// workspace has an API key configured.
+pub struct AnthropicModelSource {
+  controller: Arc<AnthropicController>,
+}
+
+impl AnthropicModelSource {
+  pub fn new(controller: Arc<AnthropicController>) -> Self {
+    Self { controller }
+  }
+}
+
+#[async_trait]
+impl ModelSource for AnthropicModelSource {
+  fn source_name(&self) -> &'static str {
+    "anthropic"
+  }
+
+  async fn list_chat_models(&self, workspace_id: &Uuid) -> Vec<Model> {
+    self.controller.available_models(&workspace_id.to_string())
+  }
+}
+
 /// A server-side AI source (e.g., cloud API)
 #[derive(Debug, Default)]
 struct ServerModelsCache {
@@ -17,6 +17,10 @@ pub enum ChatNotification {
   DidUpdateChatSettings = 7,
   LocalAIResourceUpdated = 8,
   DidUpdateSelectedModel = 9,
+  DidUpdateOllamaModelPull = 10,
+  DidRequestToolCall = 11,
+  DidUpdateAIRequestQueue = 12,
+  DidUpdateLocalAIRuntimeState = 13,
 }
 
 impl std::convert::From<ChatNotification> for i32 {
@@ -35,6 +39,11 @@ impl std::convert::From<i32> for ChatNotification {
       6 => ChatNotification::UpdateLocalAIState,
       7 => ChatNotification::DidUpdateChatSettings,
       8 => ChatNotification::LocalAIResourceUpdated,
+      9 => ChatNotification::DidUpdateSelectedModel,
+      10 => ChatNotification::DidUpdateOllamaModelPull,
+      11 => ChatNotification::DidRequestToolCall,
+      12 => ChatNotification::DidUpdateAIRequestQueue,
+      13 => ChatNotification::DidUpdateLocalAIRuntimeState,
       _ => ChatNotification::Unknown,
     }
   }
@@ -0,0 +1,341 @@
+use crate::entities::{TextIssueKindPB, TextIssuePB};
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::kv::KVStorePreferences;
+use std::collections::HashSet;
+use std::sync::{Arc, Weak};
+
+const PROOFREAD_DICTIONARY_KEY: &str = "appflowy_proofread_dictionary:v1";
+
+fn dictionary_key(workspace_id: &str) -> String {
+  format!("{}:{}", PROOFREAD_DICTIONARY_KEY, workspace_id)
+}
+
+/// Frequent misspellings with their corrections. This is deliberately a
+/// small, high-confidence list: the checker runs on every pause, so a false
+/// positive is worse than a miss.
+const COMMON_MISSPELLINGS: &[(&str, &str)] = &[
+  ("teh", "the"),
+  ("adn", "and"),
+  ("nad", "and"),
+  ("taht", "that"),
+  ("thier", "their"),
+  ("recieve", "receive"),
+  ("recieved", "received"),
+  ("seperate", "separate"),
+  ("definately", "definitely"),
+  ("occured", "occurred"),
+  ("occurence", "occurrence"),
+  ("untill", "until"),
+  ("wich", "which"),
+  ("becuase", "because"),
+  ("beleive", "believe"),
+  ("acheive", "achieve"),
+  ("accomodate", "accommodate"),
+  ("alot", "a lot"),
+  ("wierd", "weird"),
+  ("tommorow", "tomorrow"),
+  ("enviroment", "environment"),
+  ("goverment", "government"),
+  ("immediatly", "immediately"),
+  ("neccessary", "necessary"),
+  ("publically", "publicly"),
+];
+
+/// Rule-based offline proofreader. Everything runs locally: spelling issues
+/// come from a built-in misspelling list filtered by the per-workspace custom
+/// dictionary, grammar issues from a handful of high-confidence rules. All
+/// offsets are character offsets into the checked text, end exclusive.
+pub struct ProofreadController {
+  store_preferences: Weak<KVStorePreferences>,
+}
+
+impl ProofreadController {
+  pub fn new(store_preferences: Weak<KVStorePreferences>) -> Self {
+    Self { store_preferences }
+  }
+
+  fn upgrade_store_preferences(&self) -> FlowyResult<Arc<KVStorePreferences>> {
+    self
+      .store_preferences
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Unexpected error: store preferences is None"))
+  }
+
+  /// Words the user added for this workspace. They are never reported as
+  /// spelling issues.
+  pub fn get_dictionary(&self, workspace_id: &str) -> Vec<String> {
+    self
+      .upgrade_store_preferences()
+      .ok()
+      .and_then(|store| store.get_object::<Vec<String>>(&dictionary_key(workspace_id)))
+      .unwrap_or_default()
+  }
+
+  pub fn add_word(&self, workspace_id: &str, word: &str) -> FlowyResult<()> {
+    let word = word.trim().to_lowercase();
+    if word.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("Dictionary word is empty"));
+    }
+    let mut words = self.get_dictionary(workspace_id);
+    if !words.contains(&word) {
+      words.push(word);
+      words.sort();
+      let store = self.upgrade_store_preferences()?;
+      store.set_object(&dictionary_key(workspace_id), &words)?;
+    }
+    Ok(())
+  }
+
+  pub fn remove_word(&self, workspace_id: &str, word: &str) -> FlowyResult<()> {
+    let word = word.trim().to_lowercase();
+    let mut words = self.get_dictionary(workspace_id);
+    let len = words.len();
+    words.retain(|w| w != &word);
+    if words.len() != len {
+      let store = self.upgrade_store_preferences()?;
+      store.set_object(&dictionary_key(workspace_id), &words)?;
+    }
+    Ok(())
+  }
+
+  /// Check `text` and return the found issues. Only English is supported by
+  /// the built-in rules; other languages return no issues rather than noise.
+  pub fn check_text(&self, workspace_id: &str, text: &str, language: &str) -> Vec<TextIssuePB> {
+    if !language.is_empty() && !language.to_lowercase().starts_with("en") {
+      return vec![];
+    }
+
+    let custom_words: HashSet<String> = self
+      .get_dictionary(workspace_id)
+      .into_iter()
+      .collect();
+
+    let tokens = tokenize(text);
+    let mut issues = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+      let lowercase = token.text.to_lowercase();
+      if custom_words.contains(&lowercase) {
+        continue;
+      }
+
+      // Built-in misspelling list.
+      if let Some((_, correction)) = COMMON_MISSPELLINGS
+        .iter()
+        .find(|(wrong, _)| *wrong == lowercase)
+      {
+        issues.push(TextIssuePB {
+          start: token.start as u64,
+          end: token.end as u64,
+          kind: TextIssueKindPB::Spelling,
+          message: format!("Possible misspelling of \"{}\"", correction),
+          suggestions: vec![match_case(correction, &token.text)],
+        });
+        continue;
+      }
+
+      if let Some(previous) = index.checked_sub(1).map(|i| &tokens[i]) {
+        // Repeated word, e.g. "the the".
+        if previous.text.to_lowercase() == lowercase && previous.end + 1 == token.start {
+          issues.push(TextIssuePB {
+            start: previous.start as u64,
+            end: token.end as u64,
+            kind: TextIssueKindPB::Grammar,
+            message: "Repeated word".to_string(),
+            suggestions: vec![previous.text.clone()],
+          });
+          continue;
+        }
+
+        // "a" before a vowel sound, "an" before a consonant sound. The
+        // letter-based heuristic is kept conservative: words starting with
+        // "u" or "h" are skipped because their sound is ambiguous.
+        let first = lowercase.chars().next().unwrap_or_default();
+        let prev_lower = previous.text.to_lowercase();
+        if prev_lower == "a" && matches!(first, 'a' | 'e' | 'i' | 'o') {
+          issues.push(TextIssuePB {
+            start: previous.start as u64,
+            end: previous.end as u64,
+            kind: TextIssueKindPB::Grammar,
+            message: format!("Use \"an\" before \"{}\"", token.text),
+            suggestions: vec![match_case("an", &previous.text)],
+          });
+        } else if prev_lower == "an" && !matches!(first, 'a' | 'e' | 'i' | 'o' | 'u' | 'h') {
+          issues.push(TextIssuePB {
+            start: previous.start as u64,
+            end: previous.end as u64,
+            kind: TextIssueKindPB::Grammar,
+            message: format!("Use \"a\" before \"{}\"", token.text),
+            suggestions: vec![match_case("a", &previous.text)],
+          });
+        }
+      }
+
+      // Lowercase sentence start.
+      if token.starts_sentence && token.text.chars().next().is_some_and(|c| c.is_lowercase()) {
+        issues.push(TextIssuePB {
+          start: token.start as u64,
+          end: token.end as u64,
+          kind: TextIssueKindPB::Grammar,
+          message: "Sentence should start with a capital letter".to_string(),
+          suggestions: vec![capitalize(&token.text)],
+        });
+      }
+    }
+
+    issues.extend(whitespace_issues(text));
+    issues.sort_by_key(|issue| issue.start);
+    issues
+  }
+}
+
+struct Token {
+  /// Character offset of the first character.
+  start: usize,
+  /// Character offset one past the last character.
+  end: usize,
+  text: String,
+  starts_sentence: bool,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+  let mut tokens = Vec::new();
+  let mut current: Option<Token> = None;
+  let mut starts_sentence = true;
+
+  for (offset, ch) in text.chars().enumerate() {
+    if ch.is_alphabetic() || (ch == '\'' && current.is_some()) {
+      match current.as_mut() {
+        Some(token) => {
+          token.end = offset + 1;
+          token.text.push(ch);
+        },
+        None => {
+          current = Some(Token {
+            start: offset,
+            end: offset + 1,
+            text: ch.to_string(),
+            starts_sentence,
+          });
+          starts_sentence = false;
+        },
+      }
+    } else {
+      if let Some(token) = current.take() {
+        tokens.push(token);
+      }
+      if matches!(ch, '.' | '!' | '?' | '\n') {
+        starts_sentence = true;
+      } else if !ch.is_whitespace() {
+        starts_sentence = false;
+      }
+    }
+  }
+  if let Some(token) = current.take() {
+    tokens.push(token);
+  }
+  tokens
+}
+
+fn whitespace_issues(text: &str) -> Vec<TextIssuePB> {
+  let mut issues = Vec::new();
+  let chars: Vec<char> = text.chars().collect();
+  for (offset, window) in chars.windows(2).enumerate() {
+    // Space before punctuation, e.g. "word ,".
+    if window[0] == ' ' && matches!(window[1], ',' | '.' | '!' | '?' | ';' | ':') {
+      issues.push(TextIssuePB {
+        start: offset as u64,
+        end: (offset + 2) as u64,
+        kind: TextIssueKindPB::Grammar,
+        message: "Remove the space before the punctuation".to_string(),
+        suggestions: vec![window[1].to_string()],
+      });
+    }
+    // Double space, reported once per run.
+    if window[0] == ' ' && window[1] == ' ' && (offset == 0 || chars[offset - 1] != ' ') {
+      let end = offset + chars[offset..].iter().take_while(|c| **c == ' ').count();
+      issues.push(TextIssuePB {
+        start: offset as u64,
+        end: end as u64,
+        kind: TextIssueKindPB::Grammar,
+        message: "Double space".to_string(),
+        suggestions: vec![" ".to_string()],
+      });
+    }
+  }
+  issues
+}
+
+/// Apply the capitalization of `original` to `replacement` so corrections
+/// keep the author's casing.
+fn match_case(replacement: &str, original: &str) -> String {
+  if original.chars().next().is_some_and(|c| c.is_uppercase()) {
+    capitalize(replacement)
+  } else {
+    replacement.to_string()
+  }
+}
+
+fn capitalize(word: &str) -> String {
+  let mut chars = word.chars();
+  match chars.next() {
+    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+    None => String::new(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::Weak;
+
+  fn controller() -> ProofreadController {
+    ProofreadController::new(Weak::new())
+  }
+
+  #[test]
+  fn detects_common_misspelling() {
+    let issues = controller().check_text("w", "I recieve mail", "en");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, TextIssueKindPB::Spelling);
+    assert_eq!(issues[0].suggestions, vec!["receive".to_string()]);
+    assert_eq!(issues[0].start, 2);
+    assert_eq!(issues[0].end, 9);
+  }
+
+  #[test]
+  fn detects_repeated_word() {
+    let issues = controller().check_text("w", "It was the the end", "en");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].message, "Repeated word");
+    assert_eq!(issues[0].suggestions, vec!["the".to_string()]);
+  }
+
+  #[test]
+  fn detects_wrong_article() {
+    let issues = controller().check_text("w", "This is a apple", "en");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].suggestions, vec!["an".to_string()]);
+  }
+
+  #[test]
+  fn detects_lowercase_sentence_start() {
+    let issues = controller().check_text("w", "Stop. it was late", "en");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].suggestions, vec!["It".to_string()]);
+  }
+
+  #[test]
+  fn reports_space_run_once() {
+    let issues = controller().check_text("w", "Hello   world", "en");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].start, 5);
+    assert_eq!(issues[0].end, 8);
+  }
+
+  #[test]
+  fn unsupported_language_returns_no_issues() {
+    let issues = controller().check_text("w", "teh teh teh", "fr");
+    assert!(issues.is_empty());
+  }
+}
@@ -10,6 +10,7 @@ pub enum StreamMessage {
   OnFollowUp(AIFollowUpData),
   OnError(String),
   Metadata(String),
+  TokenUsage(AITokenUsageData),
   Done,
   StartIndexFile { file_name: String },
   EndIndexFile { file_name: String },
@@ -26,6 +27,17 @@ pub struct AIFollowUpData {
   pub should_generate_related_question: bool,
 }
 
+/// Per-message token accounting, sent once the provider reports usage for a
+/// streamed response. The cost estimate is omitted for models without a known
+/// price.
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+pub struct AITokenUsageData {
+  pub prompt_tokens: u64,
+  pub completion_tokens: u64,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cost_estimate_usd: Option<f64>,
+}
+
 impl Display for StreamMessage {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
@@ -36,6 +48,13 @@ impl Display for StreamMessage {
       StreamMessage::OnError(message) => write!(f, "error:{message}"),
       StreamMessage::Done => write!(f, "done:"),
       StreamMessage::Metadata(s) => write!(f, "metadata:{s}"),
+      StreamMessage::TokenUsage(data) => {
+        if let Ok(s) = serde_json::to_string(&data) {
+          write!(f, "token_usage:{}", s)
+        } else {
+          write!(f, "token_usage:")
+        }
+      },
       StreamMessage::StartIndexFile { file_name } => {
         write!(f, "start_index_file:{}", file_name)
       },
@@ -0,0 +1,136 @@
+use crate::stream_message::AITokenUsageData;
+use serde_json::Value;
+
+/// USD prices per million tokens as `(model name prefix, prompt, completion)`.
+/// First matching prefix wins, so more specific prefixes come first. The table
+/// only feeds the client-side cost estimate; billing stays with the provider.
+const MODEL_PRICES: &[(&str, f64, f64)] = &[
+  ("claude-opus", 15.0, 75.0),
+  ("claude-sonnet", 3.0, 15.0),
+  ("claude-3-5-haiku", 0.8, 4.0),
+  ("claude", 3.0, 15.0),
+];
+
+/// Pull prompt/completion token counts out of the metadata a provider attached
+/// to a streamed answer. Both the Anthropic `input/output_tokens` and the
+/// OpenAI-style `prompt/completion_tokens` spellings are accepted.
+pub fn extract_token_usage(metadata: &Value) -> Option<(u64, u64)> {
+  let usage = metadata.get("token_usage").unwrap_or(metadata);
+  let prompt_tokens = usage
+    .get("input_tokens")
+    .or_else(|| usage.get("prompt_tokens"))?
+    .as_u64()?;
+  let completion_tokens = usage
+    .get("output_tokens")
+    .or_else(|| usage.get("completion_tokens"))?
+    .as_u64()?;
+  Some((prompt_tokens, completion_tokens))
+}
+
+/// Build the usage record for one answered message. Local models run for
+/// free; remote models without a price entry report counts but no estimate.
+pub fn token_usage_for_model(
+  model_name: &str,
+  is_local: bool,
+  prompt_tokens: u64,
+  completion_tokens: u64,
+) -> AITokenUsageData {
+  let cost_estimate_usd = if is_local {
+    Some(0.0)
+  } else {
+    estimate_cost_usd(model_name, prompt_tokens, completion_tokens)
+  };
+  AITokenUsageData {
+    prompt_tokens,
+    completion_tokens,
+    cost_estimate_usd,
+  }
+}
+
+fn estimate_cost_usd(model_name: &str, prompt_tokens: u64, completion_tokens: u64) -> Option<f64> {
+  let (_, prompt_price, completion_price) = MODEL_PRICES
+    .iter()
+    .find(|(prefix, _, _)| model_name.starts_with(prefix))?;
+  Some(
+    (prompt_tokens as f64 * prompt_price + completion_tokens as f64 * completion_price)
+      / 1_000_000.0,
+  )
+}
+
+/// Context window sizes in tokens as `(model name prefix, window)`. First
+/// matching prefix wins. Models missing from the table get a conservative
+/// default so the guard never over-promises.
+const MODEL_CONTEXT_WINDOWS: &[(&str, u64)] = &[
+  ("claude", 200_000),
+  ("gpt-4o", 128_000),
+  ("gpt-4", 128_000),
+  ("llama", 8_192),
+  ("mistral", 32_768),
+  ("qwen", 32_768),
+  ("deepseek", 64_000),
+];
+
+const DEFAULT_CONTEXT_WINDOW: u64 = 8_192;
+
+/// The context window of the model, in tokens.
+pub fn context_window(model_name: &str) -> u64 {
+  let model_name = model_name.to_ascii_lowercase();
+  MODEL_CONTEXT_WINDOWS
+    .iter()
+    .find(|(prefix, _)| model_name.starts_with(prefix))
+    .map(|(_, window)| *window)
+    .unwrap_or(DEFAULT_CONTEXT_WINDOW)
+}
+
+/// Estimates the number of tokens `text` takes for the model. The estimate is
+/// tokenizer-free — roughly one token per four latin characters and one per
+/// CJK character — which is accurate enough for sizing prompts against a
+/// context window; exact counts still come from the provider's usage report.
+pub fn count_tokens(text: &str, _model_name: &str) -> u64 {
+  let mut tokens: u64 = 0;
+  for word in text.split_whitespace() {
+    let mut latin_chars: u64 = 0;
+    for ch in word.chars() {
+      if is_cjk(ch) {
+        tokens += 1;
+      } else {
+        latin_chars += 1;
+      }
+    }
+    if latin_chars > 0 {
+      tokens += latin_chars.div_ceil(4);
+    }
+  }
+  tokens
+}
+
+fn is_cjk(ch: char) -> bool {
+  matches!(ch,
+    '\u{4E00}'..='\u{9FFF}'   // CJK unified ideographs
+    | '\u{3040}'..='\u{30FF}' // hiragana and katakana
+    | '\u{AC00}'..='\u{D7AF}' // hangul syllables
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn count_tokens_latin_and_cjk() {
+    assert_eq!(count_tokens("", "claude-sonnet"), 0);
+    // Two short words, one token each.
+    assert_eq!(count_tokens("the cat", "claude-sonnet"), 2);
+    // 12 latin characters round up to 3 tokens.
+    assert_eq!(count_tokens("internationa", "claude-sonnet"), 3);
+    // CJK characters count one token apiece.
+    assert_eq!(count_tokens("你好世界", "qwen2.5"), 4);
+  }
+
+  #[test]
+  fn context_window_by_prefix() {
+    assert_eq!(context_window("claude-sonnet-4"), 200_000);
+    assert_eq!(context_window("LLAMA3.1"), 8_192);
+    assert_eq!(context_window("some-unknown-model"), 8_192);
+  }
+}
@@ -0,0 +1,321 @@
+use crate::entities::{AIToolPB, ToolCallPB, ToolCallStatusPB};
+use crate::notification::{ChatNotification, chat_notification_builder};
+use arc_swap::ArcSwapOption;
+use dashmap::DashMap;
+use flowy_error::{FlowyError, FlowyResult};
+use lib_infra::async_trait::async_trait;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{info, trace};
+use uuid::Uuid;
+
+pub const CREATE_PAGE_TOOL: &str = "create_page";
+pub const APPEND_TO_DOCUMENT_TOOL: &str = "append_to_document";
+pub const CREATE_DATABASE_ROW_TOOL: &str = "create_database_row";
+pub const QUERY_DATABASE_ROWS_TOOL: &str = "query_database_rows";
+
+/// Operations the AI tool layer is allowed to perform on the workspace. The
+/// implementation lives outside this crate because creating pages and rows
+/// requires the folder, document and database managers.
+#[async_trait]
+pub trait WorkspaceToolService: Send + Sync + 'static {
+  /// Create an empty document page and return its view id. When no parent is
+  /// given the page is created under the current workspace.
+  async fn create_page(&self, parent_view_id: Option<Uuid>, name: &str) -> FlowyResult<Uuid>;
+
+  /// Append the given markdown to the end of an existing document.
+  async fn append_to_document(&self, document_id: &Uuid, markdown: &str) -> FlowyResult<()>;
+
+  /// Create a row in the database backing `view_id`. Cells are keyed by field
+  /// name or field id and return the created row id.
+  async fn create_database_row(
+    &self,
+    view_id: &Uuid,
+    cells: HashMap<String, String>,
+  ) -> FlowyResult<String>;
+
+  /// Read rows from the database backing `view_id` as a JSON value with the
+  /// cells stringified per field.
+  async fn query_database_rows(&self, view_id: &Uuid, limit: usize) -> FlowyResult<Value>;
+}
+
+/// Description of a single tool exposed to capable models. The parameter
+/// schema follows the JSON schema subset used by function-calling APIs.
+pub struct ToolDefinition {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub parameters: Value,
+  /// Mutating tools require an explicit user confirmation before they run.
+  pub mutating: bool,
+}
+
+pub fn built_in_tools() -> Vec<ToolDefinition> {
+  vec![
+    ToolDefinition {
+      name: CREATE_PAGE_TOOL,
+      description: "Create a new empty document page in the workspace",
+      parameters: json!({
+        "type": "object",
+        "properties": {
+          "name": { "type": "string", "description": "Title of the new page" },
+          "parent_view_id": {
+            "type": "string",
+            "description": "View id of the parent page. Defaults to the workspace root"
+          }
+        },
+        "required": ["name"]
+      }),
+      mutating: true,
+    },
+    ToolDefinition {
+      name: APPEND_TO_DOCUMENT_TOOL,
+      description: "Append markdown content to the end of an existing document",
+      parameters: json!({
+        "type": "object",
+        "properties": {
+          "document_id": { "type": "string", "description": "View id of the document" },
+          "markdown": { "type": "string", "description": "Markdown content to append" }
+        },
+        "required": ["document_id", "markdown"]
+      }),
+      mutating: true,
+    },
+    ToolDefinition {
+      name: CREATE_DATABASE_ROW_TOOL,
+      description: "Create a row in a grid, board or calendar",
+      parameters: json!({
+        "type": "object",
+        "properties": {
+          "view_id": { "type": "string", "description": "View id of the database" },
+          "cells": {
+            "type": "object",
+            "description": "Cell content keyed by field name",
+            "additionalProperties": { "type": "string" }
+          }
+        },
+        "required": ["view_id"]
+      }),
+      mutating: true,
+    },
+    ToolDefinition {
+      name: QUERY_DATABASE_ROWS_TOOL,
+      description: "Read rows from a grid, board or calendar",
+      parameters: json!({
+        "type": "object",
+        "properties": {
+          "view_id": { "type": "string", "description": "View id of the database" },
+          "limit": { "type": "integer", "description": "Maximum number of rows to return" }
+        },
+        "required": ["view_id"]
+      }),
+      mutating: false,
+    },
+  ]
+}
+
+struct PendingToolCall {
+  chat_id: Uuid,
+  tool_name: String,
+  arguments: Value,
+}
+
+/// Routes tool calls requested during a chat. Read-only tools execute
+/// immediately; mutating tools are parked until the user confirms them via
+/// `AIEvent::ConfirmToolCall`, so the model can never change the workspace
+/// without the user seeing what is about to happen.
+pub struct AIToolController {
+  service: ArcSwapOption<dyn WorkspaceToolService>,
+  pending: DashMap<String, PendingToolCall>,
+}
+
+impl AIToolController {
+  pub fn new() -> Self {
+    Self {
+      service: ArcSwapOption::from(None),
+      pending: DashMap::new(),
+    }
+  }
+
+  /// Called once during startup, after the folder, document and database
+  /// managers exist.
+  pub fn set_service(&self, service: Arc<dyn WorkspaceToolService>) {
+    self.service.store(Some(service));
+  }
+
+  pub(crate) fn service(&self) -> FlowyResult<Arc<dyn WorkspaceToolService>> {
+    self
+      .service
+      .load_full()
+      .ok_or_else(|| FlowyError::internal().with_context("Workspace tool service is not set"))
+  }
+
+  pub fn available_tools(&self) -> Vec<AIToolPB> {
+    built_in_tools()
+      .into_iter()
+      .map(|tool| AIToolPB {
+        name: tool.name.to_string(),
+        description: tool.description.to_string(),
+        parameters: tool.parameters.to_string(),
+        requires_confirmation: tool.mutating,
+      })
+      .collect()
+  }
+
+  /// Entry point for a tool call emitted by a model. Mutating calls are
+  /// recorded as pending and surfaced to the user through
+  /// [ChatNotification::DidRequestToolCall]; the returned payload carries the
+  /// call id the confirmation must reference.
+  pub async fn perform_tool_call(
+    &self,
+    chat_id: &Uuid,
+    tool_name: &str,
+    arguments: Value,
+  ) -> FlowyResult<ToolCallPB> {
+    let definition = built_in_tools()
+      .into_iter()
+      .find(|tool| tool.name == tool_name)
+      .ok_or_else(|| {
+        FlowyError::invalid_data().with_context(format!("Unknown tool: {}", tool_name))
+      })?;
+
+    if !definition.mutating {
+      let output = self.execute(tool_name, &arguments).await?;
+      return Ok(ToolCallPB {
+        id: Uuid::new_v4().to_string(),
+        chat_id: chat_id.to_string(),
+        tool_name: tool_name.to_string(),
+        arguments: arguments.to_string(),
+        status: ToolCallStatusPB::Completed,
+        output: output.to_string(),
+      });
+    }
+
+    let call_id = Uuid::new_v4().to_string();
+    info!(
+      "[AI Tool] pending confirmation for {}: {}, call id: {}",
+      tool_name, arguments, call_id
+    );
+    let call = ToolCallPB {
+      id: call_id.clone(),
+      chat_id: chat_id.to_string(),
+      tool_name: tool_name.to_string(),
+      arguments: arguments.to_string(),
+      status: ToolCallStatusPB::PendingConfirmation,
+      output: "".to_string(),
+    };
+    self.pending.insert(
+      call_id,
+      PendingToolCall {
+        chat_id: *chat_id,
+        tool_name: tool_name.to_string(),
+        arguments,
+      },
+    );
+    chat_notification_builder(chat_id, ChatNotification::DidRequestToolCall)
+      .payload(call.clone())
+      .send();
+    Ok(call)
+  }
+
+  /// Resolve a pending mutating call. A rejected call is dropped without
+  /// touching the workspace.
+  pub async fn confirm_tool_call(&self, call_id: &str, approved: bool) -> FlowyResult<ToolCallPB> {
+    let (_, call) = self.pending.remove(call_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Tool call not found: {}", call_id))
+    })?;
+
+    if !approved {
+      trace!("[AI Tool] rejected call {}: {}", call_id, call.tool_name);
+      return Ok(ToolCallPB {
+        id: call_id.to_string(),
+        chat_id: call.chat_id.to_string(),
+        tool_name: call.tool_name,
+        arguments: call.arguments.to_string(),
+        status: ToolCallStatusPB::Rejected,
+        output: "".to_string(),
+      });
+    }
+
+    let output = self.execute(&call.tool_name, &call.arguments).await?;
+    Ok(ToolCallPB {
+      id: call_id.to_string(),
+      chat_id: call.chat_id.to_string(),
+      tool_name: call.tool_name,
+      arguments: call.arguments.to_string(),
+      status: ToolCallStatusPB::Completed,
+      output: output.to_string(),
+    })
+  }
+
+  async fn execute(&self, tool_name: &str, arguments: &Value) -> FlowyResult<Value> {
+    let service = self.service()?;
+    match tool_name {
+      CREATE_PAGE_TOOL => {
+        let name = required_str_arg(arguments, "name")?;
+        let parent_view_id = optional_uuid_arg(arguments, "parent_view_id")?;
+        let view_id = service.create_page(parent_view_id, name).await?;
+        Ok(json!({ "view_id": view_id.to_string() }))
+      },
+      APPEND_TO_DOCUMENT_TOOL => {
+        let document_id = required_uuid_arg(arguments, "document_id")?;
+        let markdown = required_str_arg(arguments, "markdown")?;
+        service.append_to_document(&document_id, markdown).await?;
+        Ok(json!({ "document_id": document_id.to_string() }))
+      },
+      CREATE_DATABASE_ROW_TOOL => {
+        let view_id = required_uuid_arg(arguments, "view_id")?;
+        let cells = arguments
+          .get("cells")
+          .and_then(|v| v.as_object())
+          .map(|map| {
+            map
+              .iter()
+              .map(|(k, v)| {
+                let value = match v {
+                  Value::String(s) => s.clone(),
+                  other => other.to_string(),
+                };
+                (k.clone(), value)
+              })
+              .collect::<HashMap<String, String>>()
+          })
+          .unwrap_or_default();
+        let row_id = service.create_database_row(&view_id, cells).await?;
+        Ok(json!({ "row_id": row_id }))
+      },
+      QUERY_DATABASE_ROWS_TOOL => {
+        let view_id = required_uuid_arg(arguments, "view_id")?;
+        let limit = arguments
+          .get("limit")
+          .and_then(|v| v.as_u64())
+          .unwrap_or(100) as usize;
+        service.query_database_rows(&view_id, limit).await
+      },
+      _ => Err(FlowyError::invalid_data().with_context(format!("Unknown tool: {}", tool_name))),
+    }
+  }
+}
+
+fn required_str_arg<'a>(arguments: &'a Value, key: &str) -> FlowyResult<&'a str> {
+  arguments.get(key).and_then(|v| v.as_str()).ok_or_else(|| {
+    FlowyError::invalid_data().with_context(format!("Missing tool argument: {}", key))
+  })
+}
+
+fn required_uuid_arg(arguments: &Value, key: &str) -> FlowyResult<Uuid> {
+  let value = required_str_arg(arguments, key)?;
+  Uuid::from_str(value)
+    .map_err(|_| FlowyError::invalid_data().with_context(format!("Invalid uuid: {}", value)))
+}
+
+fn optional_uuid_arg(arguments: &Value, key: &str) -> FlowyResult<Option<Uuid>> {
+  match arguments.get(key).and_then(|v| v.as_str()) {
+    None => Ok(None),
+    Some(value) if value.is_empty() => Ok(None),
+    Some(value) => Uuid::from_str(value)
+      .map(Some)
+      .map_err(|_| FlowyError::invalid_data().with_context(format!("Invalid uuid: {}", value))),
+  }
+}
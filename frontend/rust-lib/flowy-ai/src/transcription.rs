@@ -0,0 +1,192 @@
+use crate::local_ai::controller::LocalAIController;
+use flowy_error::{FlowyError, FlowyResult};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Arc;
+use tracing::debug;
+
+/// The whisper.cpp command line frontend. It must be on the PATH; the model
+/// file itself is installed through the regular model download machinery.
+const WHISPER_EXECUTABLE: &str = "whisper-cli";
+
+/// One timestamped span of the transcript, offsets in milliseconds from the
+/// start of the recording.
+#[derive(Debug, Clone)]
+pub struct TranscriptionSegment {
+  pub start_ms: u64,
+  pub end_ms: u64,
+  pub text: String,
+}
+
+/// Transcribes audio files with a locally installed whisper.cpp model, so
+/// voice notes never leave the device. The model file is any `whisper` model
+/// in the local model folder — users install one the same way as chat models,
+/// e.g. by dropping `ggml-base.bin` into the folder or pulling it.
+pub struct TranscriptionController {
+  local_ai: Arc<LocalAIController>,
+}
+
+impl TranscriptionController {
+  pub fn new(local_ai: Arc<LocalAIController>) -> Self {
+    Self { local_ai }
+  }
+
+  /// Picks the whisper model to use: the largest installed model file whose
+  /// name contains `whisper` or uses the ggml naming scheme.
+  async fn whisper_model_path(&self) -> FlowyResult<PathBuf> {
+    let model_dir = PathBuf::from(self.local_ai.get_model_storage_directory()?);
+    self
+      .local_ai
+      .get_installed_models()
+      .await?
+      .into_iter()
+      .filter(|model| {
+        let name = model.file_name.to_ascii_lowercase();
+        name.contains("whisper") || name.starts_with("ggml-")
+      })
+      .max_by_key(|model| model.size_in_bytes)
+      .map(|model| model_dir.join(model.file_name))
+      .ok_or_else(|| {
+        FlowyError::local_ai_not_ready().with_context(
+          "No whisper model installed. Download one (e.g. ggml-base.bin) into the model folder",
+        )
+      })
+  }
+
+  /// Transcribes the audio file at `audio_path` and returns the timestamped
+  /// segments in order. Runs whisper.cpp on a blocking thread; expect this to
+  /// take roughly real time on small models.
+  pub async fn transcribe_audio(&self, audio_path: &str) -> FlowyResult<Vec<TranscriptionSegment>> {
+    if !Path::new(audio_path).is_file() {
+      return Err(
+        FlowyError::record_not_found()
+          .with_context(format!("Audio file not found: {}", audio_path)),
+      );
+    }
+    let model_path = self.whisper_model_path().await?;
+    debug!(
+      "[Transcription] transcribing {} with model {:?}",
+      audio_path, model_path
+    );
+
+    let audio_path = audio_path.to_string();
+    let output = tokio::task::spawn_blocking(move || {
+      Command::new(WHISPER_EXECUTABLE)
+        .arg("--model")
+        .arg(&model_path)
+        .arg("--file")
+        .arg(&audio_path)
+        .arg("--no-prints")
+        .output()
+    })
+    .await?
+    .map_err(|err| {
+      FlowyError::local_ai_not_ready().with_context(format!(
+        "Failed to run {}: {}. Is whisper.cpp installed?",
+        WHISPER_EXECUTABLE, err
+      ))
+    })?;
+
+    if !output.status.success() {
+      let stderr = String::from_utf8_lossy(&output.stderr);
+      return Err(
+        FlowyError::internal().with_context(format!("Transcription failed: {}", stderr.trim())),
+      );
+    }
+
+    let segments = parse_whisper_output(&String::from_utf8_lossy(&output.stdout));
+    if segments.is_empty() {
+      return Err(FlowyError::internal().with_context("Transcription produced no text"));
+    }
+    Ok(segments)
+  }
+}
+
+/// Renders segments as the markdown appended to a document: one paragraph per
+/// segment, prefixed with its start timestamp.
+pub fn transcript_markdown(segments: &[TranscriptionSegment]) -> String {
+  segments
+    .iter()
+    .map(|segment| format!("[{}] {}", format_timestamp(segment.start_ms), segment.text))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn format_timestamp(ms: u64) -> String {
+  let seconds = ms / 1000;
+  format!("{:02}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Parses whisper.cpp's stdout, which prints one line per segment in the form
+/// `[00:00:00.000 --> 00:00:02.500]   text`.
+fn parse_whisper_output(stdout: &str) -> Vec<TranscriptionSegment> {
+  stdout
+    .lines()
+    .filter_map(|line| {
+      let line = line.trim();
+      let rest = line.strip_prefix('[')?;
+      let (range, text) = rest.split_once(']')?;
+      let (start, end) = range.split_once("-->")?;
+      let text = text.trim();
+      if text.is_empty() {
+        return None;
+      }
+      Some(TranscriptionSegment {
+        start_ms: parse_timestamp_ms(start.trim())?,
+        end_ms: parse_timestamp_ms(end.trim())?,
+        text: text.to_string(),
+      })
+    })
+    .collect()
+}
+
+/// Parses a `hh:mm:ss.mmm` timestamp into milliseconds.
+fn parse_timestamp_ms(value: &str) -> Option<u64> {
+  let (clock, millis) = value.split_once('.')?;
+  let mut parts = clock.split(':');
+  let hours: u64 = parts.next()?.parse().ok()?;
+  let minutes: u64 = parts.next()?.parse().ok()?;
+  let seconds: u64 = parts.next()?.parse().ok()?;
+  let millis: u64 = millis.parse().ok()?;
+  Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_whisper_segments() {
+    let stdout = "\
+[00:00:00.000 --> 00:00:02.540]   Hello there.
+[00:00:02.540 --> 00:01:05.000]   This is a voice note.
+some banner line without timestamps
+";
+    let segments = parse_whisper_output(stdout);
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].start_ms, 0);
+    assert_eq!(segments[0].end_ms, 2540);
+    assert_eq!(segments[0].text, "Hello there.");
+    assert_eq!(segments[1].end_ms, 65000);
+  }
+
+  #[test]
+  fn transcript_markdown_prefixes_timestamps() {
+    let segments = vec![
+      TranscriptionSegment {
+        start_ms: 0,
+        end_ms: 2000,
+        text: "Hello".to_string(),
+      },
+      TranscriptionSegment {
+        start_ms: 65_000,
+        end_ms: 70_000,
+        text: "World".to_string(),
+      },
+    ];
+    assert_eq!(
+      transcript_markdown(&segments),
+      "[00:00] Hello\n[01:05] World"
+    );
+  }
+}
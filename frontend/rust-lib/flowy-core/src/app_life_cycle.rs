@@ -1,7 +1,7 @@
 use anyhow::Context;
 use client_api::entity::billing_dto::SubscriptionPlan;
 use std::sync::{Arc, Weak};
-use tracing::{error, event, info, instrument};
+use tracing::{error, event, info, instrument, trace};
 
 use crate::full_indexed_data_provider::FullIndexedDataWriter;
 use crate::server_layer::ServerProvider;
@@ -16,11 +16,13 @@ use flowy_document::manager::DocumentManager;
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_folder::manager::{FolderInitDataSource, FolderManager};
 use flowy_search::services::manager::SearchManager;
+use flowy_search_pub::index_coordinator::close_index_coordinator;
 use flowy_search_pub::tantivy_state_init::close_document_tantivy_state;
 use flowy_server::af_cloud::define::LoggedUser;
 use flowy_storage::manager::StorageManager;
 use flowy_user::event_map::AppLifeCycle;
 use flowy_user::services::entities::{UserConfig, UserPaths};
+use flowy_user::services::notification_inbox::{InboxNotificationKind, NotificationInboxTable};
 use flowy_user::user_manager::UserManager;
 use flowy_user_pub::cloud::{UserCloudConfig, UserCloudServiceProvider};
 use flowy_user_pub::entities::{UserProfile, UserWorkspace, WorkspaceType};
@@ -389,6 +391,7 @@ impl AppLifeCycle for AppLifeCycleImpl {
   }
 
   async fn on_workspace_closed(&self, workspace_id: &Uuid) -> FlowyResult<()> {
+    close_index_coordinator(workspace_id);
     close_document_tantivy_state(workspace_id);
     Ok(())
   }
@@ -474,6 +477,28 @@ impl AppLifeCycle for AppLifeCycleImpl {
     Ok(())
   }
 
+  async fn on_full_sync_reconcile(&self, _user_id: i64, workspace_id: &Uuid) -> FlowyResult<()> {
+    trace!("full sync reconcile of workspace: {}", workspace_id);
+    // Refreshing the shared views pulls the latest share state from the
+    // cloud and persists it locally as a side effect.
+    let shared = self.folder_manager()?.get_shared_pages().await?;
+    // Surface newly shared pages in the notification inbox. The record id
+    // derives from the view id, so pages that were already reported are
+    // ignored by the insert.
+    let user_manager = self.user_manager()?;
+    for shared_view in shared.shared_views {
+      let mut record = NotificationInboxTable::new(
+        format!("shared:{}", shared_view.view.id),
+        InboxNotificationKind::SharedWithYou,
+        shared_view.view.name.clone(),
+      );
+      record.object_id = shared_view.view.id.clone();
+      record.workspace_id = workspace_id.to_string();
+      user_manager.add_inbox_notification(record).await?;
+    }
+    Ok(())
+  }
+
   fn on_network_status_changed(&self, reachable: bool) {
     info!("Notify did update network: reachable: {}", reachable);
     if let Some(collab_builder) = self.collab_builder.upgrade() {
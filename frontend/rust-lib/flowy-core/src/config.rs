@@ -28,6 +28,10 @@ pub struct AppFlowyCoreConfig {
   pub application_path: String,
   pub(crate) log_filter: String,
   pub cloud_config: Option<AFCloudConfiguration>,
+  /// Opt-in key for encrypting the local sqlite storage. The host application
+  /// fetches it from the OS keychain and passes it in over FFI; it is never
+  /// persisted by the Rust side.
+  pub local_encryption_key: Option<String>,
 }
 impl AppFlowyCoreConfig {
   pub fn new(
@@ -61,9 +65,15 @@ impl AppFlowyCoreConfig {
       platform,
       log_filter,
       cloud_config,
+      local_encryption_key: None,
     }
   }
 
+  pub fn with_local_encryption_key(mut self, local_encryption_key: Option<String>) -> Self {
+    self.local_encryption_key = local_encryption_key;
+    self
+  }
+
   pub fn log_filter(mut self, level: &str, with_crates: Vec<String>) -> Self {
     self.log_filter = create_log_filter(
       level.to_owned(),
@@ -96,6 +106,7 @@ impl fmt::Debug for AppFlowyCoreConfig {
     debug.field("app_version", &self.app_version);
     debug.field("storage_path", &self.storage_path);
     debug.field("application_path", &self.application_path);
+    debug.field("local_encryption", &self.local_encryption_key.is_some());
     if let Some(config) = &self.cloud_config {
       debug.field("base_url", &config.base_url);
       debug.field("ws_url", &config.ws_base_url);
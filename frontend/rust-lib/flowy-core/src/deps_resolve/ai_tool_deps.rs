@@ -0,0 +1,227 @@
+use collab_document::blocks::{Block, BlockAction, BlockActionPayload, BlockActionType};
+use flowy_ai::ai_manager::AIManager;
+use flowy_ai::tools::WorkspaceToolService;
+use flowy_database2::DatabaseManager;
+use flowy_database2::entities::{CreateRowPayloadPB, OrderObjectPositionPB};
+use flowy_database2::services::cell::stringify_cell;
+use flowy_document::manager::DocumentManager;
+use flowy_document::parser::constant::PARAGRAPH;
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_folder::entities::{CreateViewParams, ViewLayoutPB};
+use flowy_folder::manager::FolderManager;
+use flowy_folder::view_operation::ViewData;
+use flowy_folder_pub::cloud::gen_view_id;
+use lib_infra::async_trait::async_trait;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+use uuid::Uuid;
+
+pub struct AIToolDepsResolver;
+
+impl AIToolDepsResolver {
+  pub fn resolve(
+    ai_manager: &Arc<AIManager>,
+    folder_manager: Weak<FolderManager>,
+    document_manager: Weak<DocumentManager>,
+    database_manager: Weak<DatabaseManager>,
+  ) {
+    ai_manager
+      .tools
+      .set_service(Arc::new(WorkspaceToolServiceImpl {
+        folder_manager,
+        document_manager,
+        database_manager,
+      }));
+  }
+}
+
+struct WorkspaceToolServiceImpl {
+  folder_manager: Weak<FolderManager>,
+  document_manager: Weak<DocumentManager>,
+  database_manager: Weak<DatabaseManager>,
+}
+
+impl WorkspaceToolServiceImpl {
+  fn folder_manager(&self) -> FlowyResult<Arc<FolderManager>> {
+    self
+      .folder_manager
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Unexpected error: FolderManager is None"))
+  }
+
+  fn document_manager(&self) -> FlowyResult<Arc<DocumentManager>> {
+    self.document_manager.upgrade().ok_or_else(|| {
+      FlowyError::internal().with_context("Unexpected error: DocumentManager is None")
+    })
+  }
+
+  fn database_manager(&self) -> FlowyResult<Arc<DatabaseManager>> {
+    self.database_manager.upgrade().ok_or_else(|| {
+      FlowyError::internal().with_context("Unexpected error: DatabaseManager is None")
+    })
+  }
+}
+
+#[async_trait]
+impl WorkspaceToolService for WorkspaceToolServiceImpl {
+  async fn create_page(&self, parent_view_id: Option<Uuid>, name: &str) -> FlowyResult<Uuid> {
+    let folder_manager = self.folder_manager()?;
+    let parent_view_id = match parent_view_id {
+      Some(id) => id,
+      None => {
+        let workspace = folder_manager.get_current_workspace().await?;
+        Uuid::from_str(&workspace.id)?
+      },
+    };
+
+    let params = CreateViewParams {
+      parent_view_id,
+      name: name.to_string(),
+      layout: ViewLayoutPB::Document,
+      view_id: gen_view_id(),
+      initial_data: ViewData::Empty,
+      meta: HashMap::new(),
+      set_as_current: false,
+      index: None,
+      section: None,
+      icon: None,
+      extra: None,
+    };
+    let (view, _) = folder_manager.create_view_with_params(params, true).await?;
+    Ok(Uuid::from_str(&view.id)?)
+  }
+
+  async fn append_to_document(&self, document_id: &Uuid, markdown: &str) -> FlowyResult<()> {
+    let manager = self.document_manager()?;
+    let document = manager.editable_document(document_id).await?;
+    let mut guard = document.write().await;
+    let data = guard
+      .get_document_data()
+      .map_err(|err| FlowyError::internal().with_context(err))?;
+    let page_block = data.blocks.get(&data.page_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context("Document has no root page block")
+    })?;
+    let mut prev_id = data
+      .meta
+      .children_map
+      .get(&page_block.children)
+      .and_then(|children| children.last().cloned());
+
+    // Append each non-empty line as a paragraph block. The block text lives
+    // in the external text map, so every insert is paired with an InsertText
+    // action carrying the delta.
+    let mut actions = Vec::new();
+    for line in markdown.lines().map(str::trim).filter(|s| !s.is_empty()) {
+      let block_id = Uuid::new_v4().to_string();
+      let text_id = Uuid::new_v4().to_string();
+      actions.push(BlockAction {
+        action: BlockActionType::Insert,
+        payload: BlockActionPayload {
+          block: Some(Block {
+            id: block_id.clone(),
+            ty: PARAGRAPH.to_string(),
+            parent: data.page_id.clone(),
+            children: Uuid::new_v4().to_string(),
+            data: HashMap::new(),
+            external_id: Some(text_id.clone()),
+            external_type: Some("text".to_string()),
+          }),
+          parent_id: Some(data.page_id.clone()),
+          prev_id: prev_id.clone(),
+          text_id: None,
+          delta: None,
+        },
+      });
+      actions.push(BlockAction {
+        action: BlockActionType::InsertText,
+        payload: BlockActionPayload {
+          block: None,
+          parent_id: None,
+          prev_id: None,
+          text_id: Some(text_id),
+          delta: Some(json!([{ "insert": line }]).to_string()),
+        },
+      });
+      prev_id = Some(block_id);
+    }
+
+    if actions.is_empty() {
+      return Ok(());
+    }
+    guard.apply_action(actions)?;
+    Ok(())
+  }
+
+  async fn create_database_row(
+    &self,
+    view_id: &Uuid,
+    cells: HashMap<String, String>,
+  ) -> FlowyResult<String> {
+    let database_manager = self.database_manager()?;
+    let view_id = view_id.to_string();
+    let editor = database_manager
+      .get_database_editor_with_view_id(&view_id)
+      .await?;
+
+    // The model addresses columns by name, while the row payload expects
+    // field ids. Accept a field id as well so callers holding one don't need
+    // the name.
+    let fields = editor.get_fields(&view_id, None).await;
+    let mut data = HashMap::new();
+    for (key, value) in cells {
+      let field_id = fields
+        .iter()
+        .find(|field| field.id == key || field.name.eq_ignore_ascii_case(&key))
+        .map(|field| field.id.clone())
+        .ok_or_else(|| {
+          FlowyError::record_not_found().with_context(format!("No field named: {}", key))
+        })?;
+      data.insert(field_id, value);
+    }
+
+    let params = CreateRowPayloadPB {
+      view_id,
+      row_position: OrderObjectPositionPB::end(),
+      group_id: None,
+      data,
+    };
+    let row_detail = editor
+      .create_row(params)
+      .await?
+      .ok_or_else(|| FlowyError::internal().with_context("Create row returned no row"))?;
+    Ok(row_detail.row.id.to_string())
+  }
+
+  async fn query_database_rows(&self, view_id: &Uuid, limit: usize) -> FlowyResult<Value> {
+    let database_manager = self.database_manager()?;
+    let view_id = view_id.to_string();
+    let editor = database_manager
+      .get_database_editor_with_view_id(&view_id)
+      .await?;
+    let fields = editor.get_fields(&view_id, None).await;
+    let rows = editor.get_all_rows(&view_id).await?;
+
+    let total = rows.len();
+    let rows = rows
+      .iter()
+      .take(limit)
+      .map(|row| {
+        let mut cells = serde_json::Map::new();
+        for field in &fields {
+          let content = row
+            .cells
+            .get(&field.id)
+            .map(|cell| stringify_cell(cell, field))
+            .unwrap_or_default();
+          cells.insert(field.name.clone(), Value::String(content));
+        }
+        cells.insert("row_id".to_string(), Value::String(row.id.to_string()));
+        Value::Object(cells)
+      })
+      .collect::<Vec<_>>();
+
+    Ok(json!({ "total": total, "rows": rows }))
+  }
+}
@@ -72,6 +72,11 @@ impl CollabSnapshotRow {
       data,
     }
   }
+
+  pub fn with_desc(mut self, desc: String) -> Self {
+    self.desc = desc;
+    self
+  }
 }
 
 impl From<CollabSnapshotRow> for CollabSnapshot {
@@ -166,6 +171,24 @@ impl CollabSnapshotSql {
     Ok(snapshots)
   }
 
+  /// Returns (id, timestamp, desc) for every snapshot of the object, newest
+  /// first.
+  pub(crate) fn get_snapshot_versions(
+    object_id: &str,
+    conn: &mut SqliteConnection,
+  ) -> Result<Vec<(String, i64, String)>, FlowyError> {
+    let results = collab_snapshot::table
+      .filter(collab_snapshot::object_id.eq(object_id))
+      .order(collab_snapshot::timestamp.desc())
+      .select((
+        collab_snapshot::id,
+        collab_snapshot::timestamp,
+        collab_snapshot::desc,
+      ))
+      .load::<(String, i64, String)>(conn)?;
+    Ok(results)
+  }
+
   pub(crate) fn get_snapshot(
     object_id: &str,
     conn: &mut SqliteConnection,
@@ -3,10 +3,11 @@ use collab_integrate::CollabKVDB;
 use flowy_ai::ai_manager::AIManager;
 use flowy_database2::{DatabaseManager, DatabaseUser};
 use flowy_database_pub::cloud::{
-  DatabaseAIService, DatabaseCloudService, SummaryRowContent, TranslateRowContent,
+  DatabaseAIService, DatabaseCloudService, SummaryRowContent, SummaryStream, TranslateRowContent,
   TranslateRowResponse,
 };
 use flowy_error::FlowyError;
+use flowy_sqlite::DBConnection;
 use flowy_user::services::authenticate_user::AuthenticateUser;
 use lib_infra::async_trait::async_trait;
 use lib_infra::priority_task::TaskDispatcher;
@@ -26,7 +27,7 @@ impl DatabaseDepsResolver {
     ai_manager: Arc<AIManager>,
   ) -> Arc<DatabaseManager> {
     let user = Arc::new(DatabaseUserImpl(authenticate_user));
-    Arc::new(DatabaseManager::new(
+    let manager = Arc::new(DatabaseManager::new(
       user,
       task_scheduler,
       collab_builder,
@@ -35,7 +36,10 @@ impl DatabaseDepsResolver {
         ai_manager,
         ai_service,
       }),
-    ))
+    ));
+    manager.init_automation_runner(Arc::downgrade(&manager));
+    manager.init_relation_resolver(Arc::downgrade(&manager));
+    manager
   }
 }
 
@@ -73,6 +77,34 @@ impl DatabaseAIService for DatabaseAIServiceMiddleware {
     }
   }
 
+  async fn summary_database_row_stream(
+    &self,
+    workspace_id: &Uuid,
+    object_id: &Uuid,
+    summary_row: SummaryRowContent,
+  ) -> Result<SummaryStream, FlowyError> {
+    if self
+      .ai_manager
+      .local_ai
+      .is_enabled_on_workspace(&workspace_id.to_string())
+    {
+      let model = self
+        .ai_manager
+        .get_active_model(&object_id.to_string())
+        .await;
+      self
+        .ai_manager
+        .local_ai
+        .summarize_database_row_stream(&model.name, summary_row)
+        .await
+    } else {
+      self
+        .ai_service
+        .summary_database_row_stream(workspace_id, object_id, summary_row)
+        .await
+    }
+  }
+
   async fn translate_database_row(
     &self,
     workspace_id: &Uuid,
@@ -129,4 +161,8 @@ impl DatabaseUser for DatabaseUserImpl {
   fn workspace_database_object_id(&self) -> Result<Uuid, FlowyError> {
     self.upgrade_user()?.workspace_database_object_id()
   }
+
+  fn sqlite_connection(&self, uid: i64) -> Result<DBConnection, FlowyError> {
+    self.upgrade_user()?.get_sqlite_connection(uid)
+  }
 }
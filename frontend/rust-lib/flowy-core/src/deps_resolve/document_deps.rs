@@ -1,12 +1,20 @@
-use crate::deps_resolve::CollabSnapshotSql;
+use crate::deps_resolve::{CollabSnapshotRow, CollabSnapshotSql};
+use collab_entity::CollabType;
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use collab_integrate::CollabKVDB;
-use flowy_document::entities::{DocumentSnapshotData, DocumentSnapshotMeta};
+use flowy_document::entities::{DocumentSnapshotData, DocumentSnapshotMeta, DocumentVersionMeta};
 use flowy_document::manager::{DocumentManager, DocumentSnapshotService, DocumentUserService};
+use flowy_document::comment::{CommentThread, DocumentCommentStore};
+use flowy_document::mention::{MentionType, Mentionable, MentionableProvider};
+use flowy_document::template::{DocumentTemplate, DocumentTemplateStore};
 use flowy_document_pub::cloud::DocumentCloudService;
 use flowy_error::{FlowyError, FlowyResult};
+use flowy_folder::manager::FolderManager;
+use flowy_sqlite::kv::KVStorePreferences;
 use flowy_storage_pub::storage::StorageService;
 use flowy_user::services::authenticate_user::AuthenticateUser;
+use flowy_user::user_manager::UserManager;
+use lib_infra::async_trait::async_trait;
 use std::sync::{Arc, Weak};
 use uuid::Uuid;
 
@@ -75,6 +83,42 @@ impl DocumentSnapshotService for DocumentSnapshotImpl {
         FlowyError::record_not_found().with_context(format!("Snapshot {} not found", snapshot_id)),
       )
   }
+
+  fn create_document_snapshot(
+    &self,
+    document_id: &str,
+    data: Vec<u8>,
+    summary: String,
+  ) -> FlowyResult<String> {
+    let authenticate_user = self.get_authenticate_user()?;
+    let uid = authenticate_user.user_id()?;
+    let mut db = authenticate_user.get_sqlite_connection(uid)?;
+    let row = CollabSnapshotRow::new(
+      document_id.to_string(),
+      CollabType::Document.to_string(),
+      data,
+    )
+    .with_desc(summary);
+    let version_id = row.id.clone();
+    CollabSnapshotSql::create(row, &mut db)?;
+    Ok(version_id)
+  }
+
+  fn get_document_versions(&self, document_id: &str) -> FlowyResult<Vec<DocumentVersionMeta>> {
+    let authenticate_user = self.get_authenticate_user()?;
+    let uid = authenticate_user.user_id()?;
+    let mut db = authenticate_user.get_sqlite_connection(uid)?;
+    CollabSnapshotSql::get_snapshot_versions(document_id, &mut db).map(|rows| {
+      rows
+        .into_iter()
+        .map(|(version_id, created_at, summary)| DocumentVersionMeta {
+          version_id,
+          created_at,
+          summary,
+        })
+        .collect()
+    })
+  }
 }
 
 struct DocumentUserImpl(Weak<AuthenticateUser>);
@@ -111,3 +155,96 @@ impl DocumentUserService for DocumentUserImpl {
       .get_collab_db(uid)
   }
 }
+
+/// Supplies pages of the current workspace as mention autocomplete candidates.
+pub struct PageMentionableProvider(pub Weak<FolderManager>);
+
+#[async_trait]
+impl MentionableProvider for PageMentionableProvider {
+  async fn search_mentionable(&self, query: &str) -> FlowyResult<Vec<Mentionable>> {
+    let folder_manager = self.0.upgrade().ok_or_else(FlowyError::ref_drop)?;
+    let query = query.to_lowercase();
+    let views = folder_manager.get_all_views().await?;
+    Ok(
+      views
+        .into_iter()
+        .filter(|view| view.name.to_lowercase().contains(&query))
+        .map(|view| Mentionable {
+          id: view.id.clone(),
+          name: view.name.clone(),
+          ty: MentionType::Page,
+          icon: view.icon.clone().map(|icon| icon.value),
+        })
+        .collect(),
+    )
+  }
+}
+
+/// Supplies workspace members as mention autocomplete candidates, matched by
+/// name or email.
+pub struct MemberMentionableProvider(pub Weak<UserManager>);
+
+#[async_trait]
+impl MentionableProvider for MemberMentionableProvider {
+  async fn search_mentionable(&self, query: &str) -> FlowyResult<Vec<Mentionable>> {
+    let user_manager = self.0.upgrade().ok_or_else(FlowyError::ref_drop)?;
+    let workspace_id = user_manager.workspace_id()?;
+    let query = query.to_lowercase();
+    let members = user_manager.get_workspace_members(workspace_id).await?;
+    Ok(
+      members
+        .into_iter()
+        .filter(|member| {
+          member.name.to_lowercase().contains(&query)
+            || member.email.to_lowercase().contains(&query)
+        })
+        .map(|member| Mentionable {
+          id: member.email,
+          name: member.name,
+          ty: MentionType::User,
+          icon: member.avatar_url,
+        })
+        .collect(),
+    )
+  }
+}
+
+/// Persists comment threads per document in the local key-value store.
+pub struct KVDocumentCommentStore(pub Arc<KVStorePreferences>);
+
+impl KVDocumentCommentStore {
+  fn key(document_id: &str) -> String {
+    format!("document_comments:{}", document_id)
+  }
+}
+
+impl DocumentCommentStore for KVDocumentCommentStore {
+  fn load_threads(&self, document_id: &str) -> FlowyResult<Vec<CommentThread>> {
+    Ok(self.0.get_object(&Self::key(document_id)).unwrap_or_default())
+  }
+
+  fn save_threads(&self, document_id: &str, threads: &[CommentThread]) -> FlowyResult<()> {
+    self
+      .0
+      .set_object(&Self::key(document_id), &threads.to_vec())
+      .map_err(|err| FlowyError::internal().with_context(err))
+  }
+}
+
+/// Persists document templates in the local key-value store.
+pub struct KVDocumentTemplateStore(pub Arc<KVStorePreferences>);
+
+const DOCUMENT_TEMPLATES_KEY: &str = "document_templates";
+
+impl DocumentTemplateStore for KVDocumentTemplateStore {
+  fn load_templates(&self) -> FlowyResult<Vec<DocumentTemplate>> {
+    Ok(self.0.get_object(DOCUMENT_TEMPLATES_KEY).unwrap_or_default())
+  }
+
+  fn save_templates(&self, templates: &[DocumentTemplate]) -> FlowyResult<()> {
+    self
+      .0
+      .set_object(DOCUMENT_TEMPLATES_KEY, &templates.to_vec())
+      .map_err(|err| FlowyError::internal().with_context(err))
+  }
+}
@@ -178,6 +178,20 @@ impl FolderOperationHandler for DatabaseFolderOperation {
     _user_id: i64,
     params: CreateViewParams,
   ) -> Result<Option<EncodedCollab>, FlowyError> {
+    // A view created with {"database_template_id": "xx"} in the meta is
+    // instantiated from one of the built-in database templates.
+    if let Some(template_params) = CreateDatabaseTemplateExtParams::from_map(params.meta.clone()) {
+      let encoded_collab = self
+        .database_manager()?
+        .create_database_from_template(
+          &template_params.database_template_id,
+          &params.view_id.to_string(),
+          params.name.as_str(),
+        )
+        .await?;
+      return Ok(Some(encoded_collab));
+    }
+
     match CreateDatabaseExtParams::from_map(params.meta.clone()) {
       None => match params.initial_data {
         ViewData::DuplicateData(data) => {
@@ -203,6 +217,7 @@ impl FolderOperationHandler for DatabaseFolderOperation {
           ViewLayoutPB::Board => DatabaseLayoutPB::Board,
           ViewLayoutPB::Calendar => DatabaseLayoutPB::Calendar,
           ViewLayoutPB::Grid => DatabaseLayoutPB::Grid,
+          ViewLayoutPB::Timeline => DatabaseLayoutPB::Timeline,
           ViewLayoutPB::Document | ViewLayoutPB::Chat => {
             return Err(
               FlowyError::invalid_data().with_context("Can't handle document layout type"),
@@ -272,19 +287,39 @@ impl FolderOperationHandler for DatabaseFolderOperation {
     import_type: ImportType,
     bytes: Vec<u8>,
   ) -> Result<Vec<ImportedData>, FlowyError> {
-    let format = match import_type {
-      ImportType::CSV => CSVFormat::Original,
-      ImportType::AFDatabase => CSVFormat::META,
-      _ => CSVFormat::Original,
+    let result = match import_type {
+      ImportType::XLSX => {
+        self
+          .database_manager()?
+          .import_xlsx(view_id.to_string(), bytes)
+          .await?
+      },
+      ImportType::DatabaseJSON => {
+        let content = tokio::task::spawn_blocking(move || {
+          String::from_utf8(bytes).map_err(|err| FlowyError::internal().with_context(err))
+        })
+        .await??;
+        self
+          .database_manager()?
+          .import_database_json(view_id.to_string(), content)
+          .await?
+      },
+      _ => {
+        let format = match import_type {
+          ImportType::CSV => CSVFormat::Original,
+          ImportType::AFDatabase => CSVFormat::META,
+          _ => CSVFormat::Original,
+        };
+        let content = tokio::task::spawn_blocking(move || {
+          String::from_utf8(bytes).map_err(|err| FlowyError::internal().with_context(err))
+        })
+        .await??;
+        self
+          .database_manager()?
+          .import_csv(view_id.to_string(), content, format)
+          .await?
+      },
     };
-    let content = tokio::task::spawn_blocking(move || {
-      String::from_utf8(bytes).map_err(|err| FlowyError::internal().with_context(err))
-    })
-    .await??;
-    let result = self
-      .database_manager()?
-      .import_csv(view_id.to_string(), content, format)
-      .await?;
     Ok(
       result
         .encoded_collabs
@@ -358,3 +393,15 @@ impl CreateDatabaseExtParams {
     serde_json::from_value::<Self>(value).ok()
   }
 }
+
+#[derive(Debug, serde::Deserialize)]
+struct CreateDatabaseTemplateExtParams {
+  database_template_id: String,
+}
+
+impl CreateDatabaseTemplateExtParams {
+  pub fn from_map(map: HashMap<String, String>) -> Option<Self> {
+    let value = serde_json::to_value(map).ok()?;
+    serde_json::from_value::<Self>(value).ok()
+  }
+}
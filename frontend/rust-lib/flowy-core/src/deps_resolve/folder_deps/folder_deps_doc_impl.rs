@@ -1,23 +1,29 @@
 use crate::deps_resolve::folder_deps::get_encoded_collab_v1_from_disk;
 use bytes::Bytes;
 use collab::entity::EncodedCollab;
+use collab_document::blocks::DocumentData;
+use collab_document::importer::md_importer::MDImporter;
 use collab_entity::CollabType;
 use collab_folder::hierarchy_builder::NestedViewBuilder;
 use collab_folder::ViewLayout;
 use flowy_document::entities::DocumentDataPB;
+use flowy_document::html_export::HtmlChildLink;
 use flowy_document::manager::DocumentManager;
 use flowy_document::parser::json::parser::JsonToDocumentParser;
+use flowy_document::template::{DOCUMENT_TEMPLATE_AUTHOR_META_KEY, DOCUMENT_TEMPLATE_ID_META_KEY};
 use flowy_error::FlowyError;
 use flowy_folder::entities::{CreateViewParams, ViewLayoutPB};
 use flowy_folder::manager::FolderUser;
 use flowy_folder::share::ImportType;
 use flowy_folder::view_operation::{
-  FolderOperationHandler, GatherEncodedCollab, ImportedData, ViewData,
+  ExportedHtmlPage, FolderOperationHandler, GatherEncodedCollab, ImportedChildView, ImportedData,
+  ViewData,
 };
 use flowy_search_pub::tantivy_state_init::get_document_tantivy_state;
 use lib_dispatch::prelude::ToBytes;
 use lib_infra::async_trait::async_trait;
 use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Weak};
 use tokio::sync::RwLock;
@@ -118,6 +124,22 @@ impl FolderOperationHandler for DocumentFolderOperation {
     params: CreateViewParams,
   ) -> Result<Option<EncodedCollab>, FlowyError> {
     debug_assert_eq!(params.layout, ViewLayoutPB::Document);
+    // Views created from a document template carry the template id in the
+    // meta; the placeholder variables are substituted on instantiation.
+    if let (ViewData::Empty, Some(template_id)) =
+      (&params.initial_data, params.meta.get(DOCUMENT_TEMPLATE_ID_META_KEY))
+    {
+      let (encoded_collab, _) = self
+        .document_manager()?
+        .create_document_from_template(
+          user_id,
+          &params.view_id,
+          template_id,
+          params.meta.get(DOCUMENT_TEMPLATE_AUTHOR_META_KEY).map(String::as_str),
+        )
+        .await?;
+      return Ok(Some(encoded_collab));
+    }
     let data = match params.initial_data {
       ViewData::DuplicateData(data) => Some(DocumentDataPB::try_from(data)?),
       ViewData::Data(data) => Some(DocumentDataPB::try_from(data)?),
@@ -185,4 +207,285 @@ impl FolderOperationHandler for DocumentFolderOperation {
     // TODO(lucas): import file from local markdown file
     Ok(())
   }
+
+  async fn import_from_bytes_with_children(
+    &self,
+    uid: i64,
+    view_id: &Uuid,
+    name: &str,
+    import_type: ImportType,
+    bytes: Vec<u8>,
+    split_heading_level: Option<u8>,
+  ) -> Result<(Vec<ImportedData>, Vec<ImportedChildView>), FlowyError> {
+    match import_type {
+      ImportType::Markdown => {
+        let md = String::from_utf8(bytes).map_err(|_| {
+          FlowyError::invalid_data().with_context("The markdown file is not valid UTF-8")
+        })?;
+        self
+          .import_markdown(uid, view_id, md, None, split_heading_level)
+          .await
+      },
+      other => {
+        let data = self
+          .import_from_bytes(uid, view_id, name, other, bytes)
+          .await?;
+        Ok((data, vec![]))
+      },
+    }
+  }
+
+  async fn import_from_file_path_with_children(
+    &self,
+    uid: i64,
+    view_id: &Uuid,
+    name: &str,
+    import_type: ImportType,
+    path: String,
+    split_heading_level: Option<u8>,
+  ) -> Result<(Vec<ImportedData>, Vec<ImportedChildView>), FlowyError> {
+    match import_type {
+      ImportType::Markdown => {
+        let md = tokio::fs::read_to_string(&path).await?;
+        // Relative image references are resolved against the markdown file's
+        // directory.
+        let base_dir = Path::new(&path).parent().map(PathBuf::from);
+        self
+          .import_markdown(uid, view_id, md, base_dir.as_deref(), split_heading_level)
+          .await
+      },
+      _ => {
+        self
+          .import_from_file_path(&view_id.to_string(), name, path)
+          .await?;
+        Ok((vec![], vec![]))
+      },
+    }
+  }
+
+  async fn export_html(
+    &self,
+    view_id: &Uuid,
+    name: &str,
+    child_pages: Vec<ExportedHtmlPage>,
+    output_dir: &Path,
+  ) -> Result<PathBuf, FlowyError> {
+    let child_links = child_pages
+      .into_iter()
+      .map(|page| HtmlChildLink {
+        file_name: page.file_name,
+        name: page.name,
+      })
+      .collect();
+    self
+      .document_manager()?
+      .export_document_as_html(view_id, name, child_links, output_dir)
+      .await
+  }
+}
+
+impl DocumentFolderOperation {
+  /// Imports a markdown string as a document. Images referenced by a local
+  /// path are uploaded to AppFlowy's file storage and the links are rewritten
+  /// to the uploaded urls. When `split_heading_level` is set, the sections
+  /// under headings of that level become child documents and are reported as
+  /// child views.
+  async fn import_markdown(
+    &self,
+    uid: i64,
+    view_id: &Uuid,
+    md: String,
+    base_dir: Option<&Path>,
+    split_heading_level: Option<u8>,
+  ) -> Result<(Vec<ImportedData>, Vec<ImportedChildView>), FlowyError> {
+    let manager = self.document_manager()?;
+    let md = upload_local_images(&manager, view_id, md, base_dir).await;
+    let (parent_md, sections) = match split_heading_level {
+      Some(level) => split_markdown_sections(&md, level),
+      None => (md, vec![]),
+    };
+
+    let importer = MDImporter::new(None);
+    let mut imported_data = vec![];
+    let mut child_views = vec![];
+
+    let data = markdown_to_document_data(&importer, &view_id.to_string(), parent_md)?;
+    let encoded_collab = manager.create_document(uid, view_id, Some(data)).await?;
+    imported_data.push((view_id.to_string(), CollabType::Document, encoded_collab));
+
+    for (title, section_md) in sections {
+      let child_view_id = Uuid::new_v4();
+      let data = markdown_to_document_data(&importer, &child_view_id.to_string(), section_md)?;
+      let encoded_collab = manager
+        .create_document(uid, &child_view_id, Some(data))
+        .await?;
+      imported_data.push((child_view_id.to_string(), CollabType::Document, encoded_collab));
+      let name = if title.is_empty() {
+        "Untitled".to_string()
+      } else {
+        title
+      };
+      child_views.push(ImportedChildView {
+        view_id: child_view_id,
+        name,
+      });
+    }
+    Ok((imported_data, child_views))
+  }
+}
+
+fn markdown_to_document_data(
+  importer: &MDImporter,
+  document_id: &str,
+  md: String,
+) -> Result<DocumentData, FlowyError> {
+  importer
+    .import(document_id, md)
+    .map_err(|err| FlowyError::invalid_data().with_context(format!("Import markdown failed: {}", err)))
+}
+
+/// Uploads every image referenced by a local path to the file storage and
+/// rewrites the markdown links to the uploaded urls. Remote urls, missing
+/// files and references inside code fences are left untouched.
+async fn upload_local_images(
+  manager: &Arc<DocumentManager>,
+  view_id: &Uuid,
+  md: String,
+  base_dir: Option<&Path>,
+) -> String {
+  let workspace_id = match manager.user_service.workspace_id() {
+    Ok(workspace_id) => workspace_id.to_string(),
+    Err(_) => return md,
+  };
+
+  let mut lines = vec![];
+  let mut in_code_fence = false;
+  for line in md.split('\n') {
+    if line.trim_start().starts_with("```") {
+      in_code_fence = !in_code_fence;
+    }
+    if in_code_fence || !line.contains("![") {
+      lines.push(line.to_string());
+    } else {
+      lines.push(upload_images_in_line(manager, view_id, &workspace_id, line, base_dir).await);
+    }
+  }
+  lines.join("\n")
+}
+
+/// Rewrites every `![alt](target)` in the line whose target is a local file.
+async fn upload_images_in_line(
+  manager: &Arc<DocumentManager>,
+  view_id: &Uuid,
+  workspace_id: &str,
+  line: &str,
+  base_dir: Option<&Path>,
+) -> String {
+  let mut out = String::with_capacity(line.len());
+  let mut remaining = line;
+  loop {
+    let (prefix, target, rest) = match next_image_target(remaining) {
+      Some(parts) => parts,
+      None => {
+        out.push_str(remaining);
+        return out;
+      },
+    };
+    out.push_str(prefix);
+    match upload_image_target(manager, view_id, workspace_id, target, base_dir).await {
+      Some(url) => out.push_str(&url),
+      None => out.push_str(target),
+    }
+    out.push(')');
+    remaining = rest;
+  }
+}
+
+/// Finds the next `![alt](target)` in the line and returns the text up to and
+/// including the opening of the link, the target and the remainder after the
+/// closing parenthesis.
+fn next_image_target(line: &str) -> Option<(&str, &str, &str)> {
+  let bang = line.find("![")?;
+  let open = bang + line[bang..].find("](")? + 2;
+  let close = open + line[open..].find(')')?;
+  Some((&line[..open], &line[open..close], &line[close + 1..]))
+}
+
+/// Uploads the image the target points at, if it references an existing local
+/// file, and returns the rewritten target.
+async fn upload_image_target(
+  manager: &Arc<DocumentManager>,
+  view_id: &Uuid,
+  workspace_id: &str,
+  target: &str,
+  base_dir: Option<&Path>,
+) -> Option<String> {
+  // The target can carry an optional title: ![alt](image.png "title").
+  let (path_part, title) = match target.find(' ') {
+    Some(index) => (&target[..index], &target[index..]),
+    None => (target, ""),
+  };
+  let candidate = Path::new(path_part.strip_prefix("file://").unwrap_or(path_part));
+  let path = if candidate.is_absolute() {
+    candidate.to_path_buf()
+  } else {
+    base_dir?.join(candidate)
+  };
+  if !path.is_file() {
+    return None;
+  }
+  match manager
+    .upload_file(workspace_id.to_string(), &view_id.to_string(), path.to_str()?)
+    .await
+  {
+    Ok(upload) => Some(format!("{}{}", upload.url, title)),
+    Err(err) => {
+      tracing::warn!("upload image {} from markdown failed: {}", path.display(), err);
+      None
+    },
+  }
+}
+
+/// Splits the markdown on headings of `level`. The content before the first
+/// matching heading stays in the parent document and every section becomes a
+/// `(title, content)` pair for a child page. Headings inside code fences are
+/// ignored.
+fn split_markdown_sections(md: &str, level: u8) -> (String, Vec<(String, String)>) {
+  let marker = "#".repeat(level as usize);
+  let mut parent = String::new();
+  let mut sections: Vec<(String, String)> = vec![];
+  let mut in_code_fence = false;
+  for line in md.split('\n') {
+    if line.trim_start().starts_with("```") {
+      in_code_fence = !in_code_fence;
+    }
+    let title = if in_code_fence {
+      None
+    } else {
+      heading_title(line, &marker)
+    };
+    match title {
+      Some(title) => sections.push((title, String::new())),
+      None => {
+        let content = match sections.last_mut() {
+          Some((_, content)) => content,
+          None => &mut parent,
+        };
+        content.push_str(line);
+        content.push('\n');
+      },
+    }
+  }
+  (parent, sections)
+}
+
+/// Returns the heading text when the line is a heading of exactly the level
+/// the marker stands for.
+fn heading_title(line: &str, marker: &str) -> Option<String> {
+  let rest = line.strip_prefix(marker)?;
+  if rest.starts_with('#') {
+    return None;
+  }
+  let rest = rest.strip_prefix(' ')?;
+  Some(rest.trim().to_string())
 }
@@ -1,3 +1,4 @@
+pub use ai_tool_deps::*;
 pub use chat_deps::*;
 pub use collab_deps::*;
 pub use database_deps::*;
@@ -9,6 +10,7 @@ pub use user_deps::*;
 mod collab_deps;
 mod document_deps;
 
+mod ai_tool_deps;
 mod chat_deps;
 mod cloud_service_impl;
 mod database_deps;
@@ -53,7 +53,9 @@ impl FolderViewObserver for FolderViewObserverImpl {
             Err(err) => error!("FolderIndexManager error deserialize (update): {:?}", err),
           },
           IndexContent::Delete(ids) => {
-            let _ = state.write().await.delete_documents(&ids);
+            // Deleting a view moves it to the trash; keep it indexed but
+            // flagged so it only shows up when trash results are requested.
+            let _ = state.write().await.set_documents_trashed(&ids, true);
           },
         }
       }
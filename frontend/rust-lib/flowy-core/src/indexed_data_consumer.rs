@@ -8,7 +8,11 @@ use dashmap::DashMap;
 use flowy_ai_pub::entities::{UnindexedCollab, UnindexedCollabMetadata, UnindexedData};
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_folder::manager::FolderManager;
-use flowy_search_pub::entities::FolderViewObserver;
+use flowy_search_pub::entities::{FolderViewObserver, TanvityDocumentAttributes};
+use flowy_search_pub::index_coordinator::{
+  DocumentIndexCoordinator, IndexCommand, get_or_init_index_coordinator,
+};
+use flowy_search_pub::schema::LocalSearchTantivySchema;
 use flowy_search_pub::tantivy_state::DocumentTantivyState;
 use flowy_search_pub::tantivy_state_init::get_or_init_document_tantivy_state;
 use flowy_server::af_cloud::define::LoggedUser;
@@ -177,23 +181,33 @@ impl FullIndexedDataConsumer for SearchFullIndexConsumer {
 
     strong.write().await.add_document(
       &object_id,
+      object_type_for(&data.collab_type),
       content,
       data.metadata.name.clone(),
       data.metadata.icon.clone().map(|v| ViewIcon {
         ty: IconType::from(v.ty as u8),
         value: v.value,
       }),
+      TanvityDocumentAttributes::default(),
     )?;
     Ok(())
   }
 }
 
+fn object_type_for(collab_type: &CollabType) -> &'static str {
+  match collab_type {
+    CollabType::DatabaseRow => LocalSearchTantivySchema::TYPE_DATABASE_ROW,
+    _ => LocalSearchTantivySchema::TYPE_DOCUMENT,
+  }
+}
+
 /// -----------------------------------------------------
 /// Instant‐index consumer also holds a Weak:
 /// -----------------------------------------------------
 pub struct SearchInstantIndexImpl {
   workspace_id: Uuid,
   state: Weak<RwLock<DocumentTantivyState>>,
+  coordinator: Arc<DocumentIndexCoordinator>,
   consume_history: DashMap<Uuid, String>,
   folder_manager: Weak<FolderManager>,
   #[allow(dead_code)]
@@ -219,9 +233,11 @@ impl SearchInstantIndexImpl {
       }
     }
 
+    let coordinator = get_or_init_index_coordinator(*workspace_id, Arc::downgrade(&state));
     Ok(Self {
       workspace_id: *workspace_id,
       state: Arc::downgrade(&state),
+      coordinator,
       consume_history: Default::default(),
       folder_manager,
       folder_observer,
@@ -268,11 +284,14 @@ impl SearchInstantIndexImpl {
                 }
               },
               FolderViewChange::Deleted { view_ids } => {
-                let _ = state.write().await.delete_documents(
+                // Keep trashed views in the index, flagged so search can
+                // still surface them when the user opts into trash results.
+                let _ = state.write().await.set_documents_trashed(
                   &view_ids
                     .iter()
                     .map(|id| id.to_string())
                     .collect::<Vec<String>>(),
+                  true,
                 );
               },
             }
@@ -296,16 +315,37 @@ impl InstantIndexedDataConsumer for SearchInstantIndexImpl {
     workspace_id: &Uuid,
     data: Option<UnindexedData>,
     object_id: &Uuid,
-    _collab_type: CollabType,
+    collab_type: CollabType,
   ) -> Result<bool, FlowyError> {
     if self.workspace_id != *workspace_id {
       return Ok(false);
     }
 
-    let state = self
-      .state
-      .upgrade()
-      .ok_or_else(|| FlowyError::internal().with_context("Tantivy state dropped"))?;
+    // Database rows are not folder views, so they carry no name or icon.
+    if matches!(collab_type, CollabType::DatabaseRow) {
+      let content_hash = match &data {
+        None => return Ok(false),
+        Some(data) => data.content_hash(),
+      };
+      if let Some(entry) = self.consume_history.get(object_id) {
+        if entry.value() == &content_hash {
+          return Ok(false);
+        }
+      }
+      self.consume_history.insert(*object_id, content_hash);
+      self
+        .coordinator
+        .enqueue(IndexCommand::AddDocument {
+          id: object_id.to_string(),
+          object_type: LocalSearchTantivySchema::TYPE_DATABASE_ROW.to_string(),
+          content: data.map(|v| v.into_string()),
+          name: None,
+          icon: None,
+          attrs: TanvityDocumentAttributes::default(),
+        })
+        .await?;
+      return Ok(true);
+    }
 
     let folder_manager = self
       .folder_manager
@@ -342,15 +382,26 @@ impl InstantIndexedDataConsumer for SearchInstantIndexImpl {
     }
 
     self.consume_history.insert(*object_id, combined_hash);
-    state.write().await.add_document(
-      &object_id.to_string(),
-      data.map(|v| v.into_string()),
-      Some(view.name.clone()),
-      view.icon.clone().map(|v| ViewIcon {
-        ty: IconType::from(v.ty as u8),
-        value: v.value,
-      }),
-    )?;
+    self
+      .coordinator
+      .enqueue(IndexCommand::AddDocument {
+        id: object_id.to_string(),
+        object_type: LocalSearchTantivySchema::TYPE_DOCUMENT.to_string(),
+        content: data.map(|v| v.into_string()),
+        name: Some(view.name.clone()),
+        icon: view.icon.clone().map(|v| ViewIcon {
+          ty: IconType::from(v.ty as u8),
+          value: v.value,
+        }),
+        attrs: TanvityDocumentAttributes {
+          view_layout: Some(view.layout.clone().into()),
+          parent_view_id: Some(view.parent_view_id.clone()),
+          created_by: view.created_by,
+          last_edited_at: Some(view.last_edited_time),
+          trashed: Some(false),
+        },
+      })
+      .await?;
     Ok(true)
   }
 
@@ -359,15 +410,10 @@ impl InstantIndexedDataConsumer for SearchInstantIndexImpl {
     _workspace_id: &Uuid,
     object_id: &Uuid,
   ) -> Result<(), FlowyError> {
-    let strong = self
-      .state
-      .upgrade()
-      .ok_or_else(|| FlowyError::internal().with_context("Tantivy state dropped"))?;
-    strong
-      .write()
+    self
+      .coordinator
+      .enqueue(IndexCommand::DeleteDocuments(vec![object_id.to_string()]))
       .await
-      .delete_document(&object_id.to_string())?;
-    Ok(())
   }
 }
 
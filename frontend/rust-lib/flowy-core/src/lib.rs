@@ -49,7 +49,9 @@ mod folder_view_observer;
 mod full_indexed_data_provider;
 mod indexed_data_consumer;
 mod indexing_data_runner;
+mod local_api;
 mod log_filter;
+mod mention_notifier;
 pub mod module;
 pub(crate) mod server_layer;
 
@@ -125,7 +127,13 @@ impl AppFlowyCore {
     config.ensure_path();
 
     // Init the key value database
-    let store_preference = Arc::new(KVStorePreferences::new(&config.storage_path).unwrap());
+    let store_preference = Arc::new(
+      KVStorePreferences::new_with_key(
+        &config.storage_path,
+        config.local_encryption_key.as_deref(),
+      )
+      .unwrap(),
+    );
     info!("🔥{:?}", &config);
 
     #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
@@ -142,7 +150,8 @@ impl AppFlowyCore {
       &config.application_path,
       &config.device_id,
       config.app_version.clone(),
-    );
+    )
+    .with_local_encryption_key(config.local_encryption_key.clone());
 
     let authenticate_user = Arc::new(AuthenticateUser::new(
       user_config.clone(),
@@ -233,6 +242,15 @@ impl AppFlowyCore {
         Arc::downgrade(&storage_manager.storage_service),
       );
 
+      // The AI manager is created before the folder, document and database
+      // managers, so the workspace tools are wired up afterwards.
+      AIToolDepsResolver::resolve(
+        &ai_manager,
+        Arc::downgrade(&folder_manager),
+        Arc::downgrade(&document_manager),
+        Arc::downgrade(&database_manager),
+      );
+
       let user_manager = UserDepsResolver::resolve(
         authenticate_user.clone(),
         Arc::downgrade(&collab_builder),
@@ -254,6 +272,37 @@ impl AppFlowyCore {
         Arc::downgrade(&ai_manager),
       );
 
+      // Register the mention autocomplete providers
+      document_manager.register_mention_provider(Arc::new(PageMentionableProvider(
+        Arc::downgrade(&folder_manager),
+      )));
+      document_manager.register_mention_provider(Arc::new(MemberMentionableProvider(
+        Arc::downgrade(&user_manager),
+      )));
+      document_manager.set_comment_store(Arc::new(KVDocumentCommentStore(
+        store_preference.clone(),
+      )));
+      document_manager.set_template_store(Arc::new(KVDocumentTemplateStore(
+        store_preference.clone(),
+      )));
+
+      // Turn recorded mentions and person-cell assignments into
+      // notifications
+      crate::mention_notifier::spawn_mention_notifier(
+        Arc::downgrade(&user_manager),
+        Arc::downgrade(&document_manager),
+        Arc::downgrade(&database_manager),
+        Arc::downgrade(&folder_manager),
+      );
+
+      // Optional localhost automation API, off unless configured via the
+      // environment
+      crate::local_api::spawn_local_api_server(
+        Arc::downgrade(&folder_manager),
+        Arc::downgrade(&document_manager),
+        Arc::downgrade(&database_manager),
+      );
+
       (
         user_manager,
         folder_manager,
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+
+use flowy_database2::DatabaseManager;
+use flowy_database2::entities::CreateRowPayloadPB;
+use flowy_database2::services::share::csv::CSVFormat;
+use flowy_document::manager::DocumentManager;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use flowy_folder::entities::{CreateViewParams, ViewLayoutPB};
+use flowy_folder::manager::FolderManager;
+use flowy_folder::view_operation::ViewData;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::net::tcp::OwnedWriteHalf;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Port the automation server listens on. The server stays off unless this
+/// is set.
+const LOCAL_API_PORT_ENV: &str = "APPFLOWY_LOCAL_API_PORT";
+/// Bearer token every request has to carry. The server stays off unless this
+/// is set, so it can never run unauthenticated.
+const LOCAL_API_TOKEN_ENV: &str = "APPFLOWY_LOCAL_API_TOKEN";
+
+const MAX_BODY_LEN: usize = 1024 * 1024;
+
+/// Starts the opt-in localhost REST server power users can script against,
+/// e.g. from shell or cron. It only binds to 127.0.0.1 and requires the port
+/// and token environment variables; without them this is a no-op. Routes:
+///
+/// - `GET  /views`                      list the views of the workspace
+/// - `POST /views`                      create a view: `{parent_view_id, name, layout}`
+/// - `GET  /documents/{view_id}`        the document content as plain text
+/// - `GET  /databases/{view_id}/rows`   the database rows as CSV
+/// - `POST /databases/{view_id}/rows`   create a row: `{cells: {field_id: value}}`
+///
+/// The task ends once the managers are dropped.
+pub(crate) fn spawn_local_api_server(
+  folder_manager: Weak<FolderManager>,
+  document_manager: Weak<DocumentManager>,
+  database_manager: Weak<DatabaseManager>,
+) {
+  let port = match std::env::var(LOCAL_API_PORT_ENV)
+    .ok()
+    .and_then(|port| port.parse::<u16>().ok())
+  {
+    Some(port) => port,
+    None => return,
+  };
+  let token = match std::env::var(LOCAL_API_TOKEN_ENV) {
+    Ok(token) if !token.is_empty() => token,
+    _ => {
+      warn!(
+        "[LocalApi] {} is set but {} is not, the automation server stays off",
+        LOCAL_API_PORT_ENV, LOCAL_API_TOKEN_ENV
+      );
+      return;
+    },
+  };
+
+  tokio::spawn(async move {
+    let listener = match TcpListener::bind(("127.0.0.1", port)).await {
+      Ok(listener) => listener,
+      Err(err) => {
+        error!("[LocalApi] bind 127.0.0.1:{} failed: {}", port, err);
+        return;
+      },
+    };
+    info!("[LocalApi] automation server listening on 127.0.0.1:{}", port);
+
+    loop {
+      let (stream, _) = match listener.accept().await {
+        Ok(accepted) => accepted,
+        Err(err) => {
+          error!("[LocalApi] accept failed: {}", err);
+          continue;
+        },
+      };
+      let managers = (
+        folder_manager.upgrade(),
+        document_manager.upgrade(),
+        database_manager.upgrade(),
+      );
+      let (folder, document, database) = match managers {
+        (Some(folder), Some(document), Some(database)) => (folder, document, database),
+        _ => break,
+      };
+      let token = token.clone();
+      tokio::spawn(async move {
+        let (reader, writer) = stream.into_split();
+        if let Err(err) = handle_request(reader, writer, &token, folder, document, database).await {
+          warn!("[LocalApi] request failed: {}", err);
+        }
+      });
+    }
+    info!("[LocalApi] automation server stopped");
+  });
+}
+
+async fn handle_request(
+  reader: tokio::net::tcp::OwnedReadHalf,
+  mut writer: OwnedWriteHalf,
+  token: &str,
+  folder: Arc<FolderManager>,
+  document: Arc<DocumentManager>,
+  database: Arc<DatabaseManager>,
+) -> FlowyResult<()> {
+  let mut reader = BufReader::new(reader);
+  let mut request_line = String::new();
+  reader.read_line(&mut request_line).await?;
+  let mut parts = request_line.split_whitespace();
+  let method = parts.next().unwrap_or_default().to_string();
+  let path = parts.next().unwrap_or_default().to_string();
+
+  let mut content_length = 0usize;
+  let mut authorized = false;
+  loop {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+      break;
+    }
+    let line = line.trim_end();
+    if line.is_empty() {
+      break;
+    }
+    if let Some((name, value)) = line.split_once(':') {
+      let value = value.trim();
+      if name.eq_ignore_ascii_case("content-length") {
+        content_length = value.parse().unwrap_or(0);
+      } else if name.eq_ignore_ascii_case("authorization") {
+        authorized = value
+          .strip_prefix("Bearer ")
+          .map(|bearer| token_matches(bearer, token))
+          .unwrap_or(false);
+      }
+    }
+  }
+
+  if !authorized {
+    return write_response(&mut writer, 401, "application/json", &error_body("invalid token")).await;
+  }
+  if content_length > MAX_BODY_LEN {
+    return write_response(
+      &mut writer,
+      413,
+      "application/json",
+      &error_body("request body too large"),
+    )
+    .await;
+  }
+  let mut body = vec![0u8; content_length];
+  reader.read_exact(&mut body).await?;
+
+  match route(&method, &path, &body, folder, document, database).await {
+    Ok((content_type, response_body)) => {
+      write_response(&mut writer, 200, content_type, &response_body).await
+    },
+    Err(err) => {
+      let status = match err.code {
+        ErrorCode::RecordNotFound => 404,
+        ErrorCode::InvalidParams | ErrorCode::InvalidData => 400,
+        _ => 500,
+      };
+      write_response(&mut writer, status, "application/json", &error_body(&err.msg)).await
+    },
+  }
+}
+
+/// Compares the presented token against the configured one by digest, so the
+/// comparison time doesn't leak how long a matching prefix was.
+fn token_matches(bearer: &str, token: &str) -> bool {
+  Sha256::digest(bearer.as_bytes()) == Sha256::digest(token.as_bytes())
+}
+
+async fn route(
+  method: &str,
+  path: &str,
+  body: &[u8],
+  folder: Arc<FolderManager>,
+  document: Arc<DocumentManager>,
+  database: Arc<DatabaseManager>,
+) -> FlowyResult<(&'static str, String)> {
+  let segments = path
+    .trim_matches('/')
+    .split('/')
+    .collect::<Vec<&str>>();
+  match (method, segments.as_slice()) {
+    ("GET", ["views"]) => {
+      let views = folder.get_all_views_pb().await?;
+      let items = views
+        .into_iter()
+        .map(|view| {
+          serde_json::json!({
+            "id": view.id,
+            "parent_view_id": view.parent_view_id,
+            "name": view.name,
+            "layout": format!("{:?}", view.layout),
+          })
+        })
+        .collect::<Vec<_>>();
+      Ok((
+        "application/json",
+        serde_json::json!({ "views": items }).to_string(),
+      ))
+    },
+    ("POST", ["views"]) => {
+      let payload = parse_json_body(body)?;
+      let parent_view_id = payload
+        .get("parent_view_id")
+        .and_then(|value| value.as_str())
+        .ok_or_else(|| FlowyError::invalid_data().with_context("parent_view_id is required"))?;
+      let name = payload
+        .get("name")
+        .and_then(|value| value.as_str())
+        .unwrap_or("Untitled");
+      let layout = match payload
+        .get("layout")
+        .and_then(|value| value.as_str())
+        .unwrap_or("document")
+      {
+        "document" => ViewLayoutPB::Document,
+        "grid" => ViewLayoutPB::Grid,
+        "board" => ViewLayoutPB::Board,
+        "calendar" => ViewLayoutPB::Calendar,
+        other => {
+          return Err(
+            FlowyError::invalid_data().with_context(format!("unsupported layout: {}", other)),
+          );
+        },
+      };
+      let params = CreateViewParams {
+        parent_view_id: Uuid::parse_str(parent_view_id)
+          .map_err(|_| FlowyError::invalid_data().with_context("parent_view_id is not a uuid"))?,
+        name: name.to_string(),
+        layout,
+        view_id: Uuid::new_v4(),
+        initial_data: ViewData::Empty,
+        meta: HashMap::new(),
+        set_as_current: false,
+        index: None,
+        section: None,
+        icon: None,
+        extra: None,
+      };
+      let (view, _) = folder.create_view_with_params(params, true).await?;
+      Ok((
+        "application/json",
+        serde_json::json!({ "id": view.id, "name": view.name }).to_string(),
+      ))
+    },
+    ("GET", ["documents", view_id]) => {
+      let doc_id = Uuid::parse_str(view_id)
+        .map_err(|_| FlowyError::invalid_data().with_context("view id is not a uuid"))?;
+      let text = document.get_document_text(&doc_id).await?;
+      Ok((
+        "application/json",
+        serde_json::json!({ "view_id": view_id, "text": text }).to_string(),
+      ))
+    },
+    ("GET", ["databases", view_id, "rows"]) => {
+      let csv = database.export_csv(view_id, CSVFormat::Original).await?;
+      Ok(("text/csv", csv))
+    },
+    ("POST", ["databases", view_id, "rows"]) => {
+      let payload = parse_json_body(body)?;
+      let mut data = HashMap::new();
+      if let Some(cells) = payload.get("cells").and_then(|value| value.as_object()) {
+        for (field_id, value) in cells {
+          if let Some(value) = value.as_str() {
+            data.insert(field_id.clone(), value.to_string());
+          }
+        }
+      }
+      let editor = database.get_database_editor_with_view_id(view_id).await?;
+      let row_detail = editor
+        .create_row(CreateRowPayloadPB {
+          view_id: view_id.to_string(),
+          data,
+          ..Default::default()
+        })
+        .await?
+        .ok_or_else(|| FlowyError::internal().with_context("create row failed"))?;
+      Ok((
+        "application/json",
+        serde_json::json!({ "row_id": row_detail.row.id.to_string() }).to_string(),
+      ))
+    },
+    _ => Err(
+      FlowyError::record_not_found().with_context(format!("no route for {} {}", method, path)),
+    ),
+  }
+}
+
+fn parse_json_body(body: &[u8]) -> FlowyResult<serde_json::Value> {
+  serde_json::from_slice(body)
+    .map_err(|err| FlowyError::invalid_data().with_context(format!("invalid json body: {}", err)))
+}
+
+fn error_body(message: &str) -> String {
+  serde_json::json!({ "error": message }).to_string()
+}
+
+async fn write_response(
+  writer: &mut OwnedWriteHalf,
+  status: u16,
+  content_type: &str,
+  body: &str,
+) -> FlowyResult<()> {
+  let reason = match status {
+    200 => "OK",
+    400 => "Bad Request",
+    401 => "Unauthorized",
+    404 => "Not Found",
+    413 => "Payload Too Large",
+    _ => "Internal Server Error",
+  };
+  let response = format!(
+    "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+    status,
+    reason,
+    content_type,
+    body.len(),
+    body
+  );
+  writer.write_all(response.as_bytes()).await?;
+  writer.shutdown().await?;
+  Ok(())
+}
@@ -0,0 +1,217 @@
+use std::sync::Weak;
+use std::time::Duration;
+
+use flowy_database2::{DatabaseManager, RecordedAssignment};
+use flowy_document::manager::DocumentManager;
+use flowy_document::mention::RecordedUserMention;
+use flowy_error::FlowyError;
+use flowy_folder::manager::FolderManager;
+use flowy_user::services::notification_inbox::{InboxNotificationKind, NotificationInboxTable};
+use flowy_user::user_manager::UserManager;
+use flowy_user_pub::cloud::MentionNotificationParams;
+use tracing::{trace, warn};
+use uuid::Uuid;
+
+/// How often the recorded mentions and person-cell assignments are drained.
+const MENTION_DRAIN_INTERVAL: Duration = Duration::from_secs(10);
+/// Upper bound for the content excerpt attached to a mention notification.
+const MENTION_SNIPPET_MAX_LEN: usize = 120;
+
+/// Turns the user mentions recorded by the document manager and the
+/// assignments recorded by the database manager into notifications: an inbox
+/// record when the current user is the target, a cloud delivery otherwise.
+/// The task ends once the managers are dropped.
+pub(crate) fn spawn_mention_notifier(
+  user_manager: Weak<UserManager>,
+  document_manager: Weak<DocumentManager>,
+  database_manager: Weak<DatabaseManager>,
+  folder_manager: Weak<FolderManager>,
+) {
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(MENTION_DRAIN_INTERVAL);
+    loop {
+      interval.tick().await;
+      let (user_manager, document_manager, database_manager, folder_manager) = match (
+        user_manager.upgrade(),
+        document_manager.upgrade(),
+        database_manager.upgrade(),
+        folder_manager.upgrade(),
+      ) {
+        (Some(a), Some(b), Some(c), Some(d)) => (a, b, c, d),
+        _ => break,
+      };
+
+      let mentions = document_manager.take_recorded_user_mentions();
+      let assignments = database_manager.take_recorded_assignments();
+      if mentions.is_empty() && assignments.is_empty() {
+        continue;
+      }
+
+      // Skipped while signed out; the recorded events are dropped since
+      // they can't be attributed to a user anymore.
+      let context = match NotifierContext::load(&user_manager).await {
+        Some(context) => context,
+        None => continue,
+      };
+
+      for mention in mentions {
+        if let Err(err) = handle_mention(
+          &context,
+          &user_manager,
+          &document_manager,
+          &folder_manager,
+          mention,
+        )
+        .await
+        {
+          warn!("Failed to deliver a document mention notification: {}", err);
+        }
+      }
+      for assignment in assignments {
+        if let Err(err) =
+          handle_assignment(&context, &user_manager, &folder_manager, assignment).await
+        {
+          warn!("Failed to deliver an assignment notification: {}", err);
+        }
+      }
+    }
+  });
+}
+
+/// The identity the drained events are resolved against.
+struct NotifierContext {
+  workspace_id: Uuid,
+  current_user_email: String,
+  current_user_name: String,
+}
+
+impl NotifierContext {
+  async fn load(user_manager: &UserManager) -> Option<Self> {
+    let uid = user_manager.user_id().ok()?;
+    let workspace_id = user_manager.workspace_id().ok()?;
+    let profile = user_manager
+      .get_user_profile_from_disk(uid, &workspace_id.to_string())
+      .await
+      .ok()?;
+    Some(Self {
+      workspace_id,
+      current_user_email: profile.email,
+      current_user_name: profile.name,
+    })
+  }
+}
+
+async fn handle_mention(
+  context: &NotifierContext,
+  user_manager: &UserManager,
+  document_manager: &DocumentManager,
+  folder_manager: &FolderManager,
+  mention: RecordedUserMention,
+) -> Result<(), FlowyError> {
+  let view_id = mention.document_id.to_string();
+  let snippet = document_manager
+    .get_block_snippet(&mention.document_id, &mention.block_id, MENTION_SNIPPET_MAX_LEN)
+    .await
+    .unwrap_or_default();
+
+  if mention
+    .mentioned_user_id
+    .eq_ignore_ascii_case(&context.current_user_email)
+  {
+    let view_name = folder_manager
+      .get_view_pb(&view_id)
+      .await
+      .map(|view| view.name)
+      .unwrap_or_default();
+    // The block id is embedded in the record id so the client can scroll to
+    // the mention on tap; the object id holds the view to open.
+    let mut record = NotificationInboxTable::new(
+      format!("mention:{}:{}", view_id, mention.block_id),
+      InboxNotificationKind::Mention,
+      format!("{} mentioned you in {}", context.current_user_name, view_name),
+    );
+    record.body = snippet;
+    record.object_id = view_id;
+    record.sender = context.current_user_name.clone();
+    record.workspace_id = context.workspace_id.to_string();
+    user_manager.add_inbox_notification(record).await
+  } else {
+    trace!(
+      "Sending mention notification to {} through the cloud",
+      mention.mentioned_user_id
+    );
+    user_manager
+      .send_mention_notification(MentionNotificationParams {
+        mentioned_user_email: mention.mentioned_user_id,
+        workspace_id: context.workspace_id,
+        view_id,
+        block_id: Some(mention.block_id),
+        row_id: None,
+        snippet,
+        sender_name: context.current_user_name.clone(),
+      })
+      .await
+  }
+}
+
+async fn handle_assignment(
+  context: &NotifierContext,
+  user_manager: &UserManager,
+  folder_manager: &FolderManager,
+  assignment: RecordedAssignment,
+) -> Result<(), FlowyError> {
+  // The person cell holds free text; only assignments that resolve to a
+  // workspace member by name or email produce a notification.
+  let members = user_manager
+    .get_workspace_members(context.workspace_id)
+    .await?;
+  let assignee = members.into_iter().find(|member| {
+    member.email.eq_ignore_ascii_case(&assignment.assignee)
+      || member.name.eq_ignore_ascii_case(&assignment.assignee)
+  });
+  let assignee = match assignee {
+    Some(assignee) => assignee,
+    None => return Ok(()),
+  };
+
+  if assignee
+    .email
+    .eq_ignore_ascii_case(&context.current_user_email)
+  {
+    let view_name = folder_manager
+      .get_view_pb(&assignment.view_id)
+      .await
+      .map(|view| view.name)
+      .unwrap_or_default();
+    // The row id is embedded in the record id so the client can open the row
+    // on tap; the object id holds the view to open.
+    let mut record = NotificationInboxTable::new(
+      format!("assign:{}:{}", assignment.view_id, assignment.row_id),
+      InboxNotificationKind::Mention,
+      format!(
+        "{} assigned you a row in {}",
+        context.current_user_name, view_name
+      ),
+    );
+    record.object_id = assignment.view_id;
+    record.sender = context.current_user_name.clone();
+    record.workspace_id = context.workspace_id.to_string();
+    user_manager.add_inbox_notification(record).await
+  } else {
+    trace!(
+      "Sending assignment notification to {} through the cloud",
+      assignee.email
+    );
+    user_manager
+      .send_mention_notification(MentionNotificationParams {
+        mentioned_user_email: assignee.email,
+        workspace_id: context.workspace_id,
+        view_id: assignment.view_id,
+        block_id: None,
+        row_id: Some(assignment.row_id),
+        snippet: String::new(),
+        sender_name: context.current_user_name.clone(),
+      })
+      .await
+  }
+}
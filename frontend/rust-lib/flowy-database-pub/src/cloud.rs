@@ -2,6 +2,8 @@ pub use client_api::entity::ai_dto::{TranslateItem, TranslateRowResponse};
 use collab::entity::EncodedCollab;
 use collab_entity::CollabType;
 use flowy_error::FlowyError;
+use futures::StreamExt;
+use futures::stream::{self, BoxStream};
 use lib_infra::async_trait::async_trait;
 use std::collections::HashMap;
 use uuid::Uuid;
@@ -9,6 +11,10 @@ use uuid::Uuid;
 pub type EncodeCollabByOid = HashMap<Uuid, EncodedCollab>;
 pub type SummaryRowContent = HashMap<String, String>;
 pub type TranslateRowContent = Vec<TranslateItem>;
+/// Partial summary text chunks, in order.
+pub type SummaryStream = BoxStream<'static, Result<String, FlowyError>>;
+/// The raw cell contents of a form submission, keyed by field id.
+pub type FormSubmissionContent = HashMap<String, String>;
 
 #[async_trait]
 pub trait DatabaseAIService: Send + Sync {
@@ -21,6 +27,20 @@ pub trait DatabaseAIService: Send + Sync {
     Ok("".to_string())
   }
 
+  /// Streams the summary as partial text chunks. Services without streaming
+  /// support fall back to a single chunk from [Self::summary_database_row].
+  async fn summary_database_row_stream(
+    &self,
+    workspace_id: &Uuid,
+    object_id: &Uuid,
+    summary_row: SummaryRowContent,
+  ) -> Result<SummaryStream, FlowyError> {
+    let response = self
+      .summary_database_row(workspace_id, object_id, summary_row)
+      .await?;
+    Ok(stream::once(async move { Ok(response) }).boxed())
+  }
+
   async fn translate_database_row(
     &self,
     _workspace_id: &Uuid,
@@ -67,6 +87,18 @@ pub trait DatabaseCloudService: Send + Sync {
     object_id: &Uuid,
     limit: usize,
   ) -> Result<Vec<DatabaseSnapshot>, FlowyError>;
+
+  /// Submits a form to a published database view, so an external user can
+  /// create a row without being a member of the workspace. Servers that don't
+  /// support publishing can rely on the default implementation.
+  async fn submit_database_form(
+    &self,
+    _workspace_id: &Uuid,
+    _view_id: &Uuid,
+    _cells: FormSubmissionContent,
+  ) -> Result<(), FlowyError> {
+    Err(FlowyError::not_support().with_context("Form submission requires a cloud server"))
+  }
 }
 
 pub struct DatabaseSnapshot {
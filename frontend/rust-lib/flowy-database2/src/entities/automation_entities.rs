@@ -0,0 +1,213 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::NotEmptyStr;
+use crate::services::automation::AutomationTable;
+
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, ProtoBuf_Enum)]
+pub enum AutomationTriggerTypePB {
+  /// Fires when a row is created in the database.
+  #[default]
+  RowCreated = 0,
+  /// Fires when a cell of the configured field changes.
+  CellChanged = 1,
+  /// Fires when a row is moved into the configured group.
+  RowMovedToGroup = 2,
+}
+
+impl From<i32> for AutomationTriggerTypePB {
+  fn from(value: i32) -> Self {
+    match value {
+      1 => AutomationTriggerTypePB::CellChanged,
+      2 => AutomationTriggerTypePB::RowMovedToGroup,
+      _ => AutomationTriggerTypePB::RowCreated,
+    }
+  }
+}
+
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, ProtoBuf_Enum)]
+pub enum AutomationActionTypePB {
+  /// Sets a cell of the triggering row.
+  #[default]
+  SetCell = 0,
+  /// Creates a row in the configured view, possibly of another database.
+  CreateRow = 1,
+  /// POSTs the triggering event to the configured url as JSON.
+  Webhook = 2,
+}
+
+impl From<i32> for AutomationActionTypePB {
+  fn from(value: i32) -> Self {
+    match value {
+      1 => AutomationActionTypePB::CreateRow,
+      2 => AutomationActionTypePB::Webhook,
+      _ => AutomationActionTypePB::SetCell,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationPB {
+  #[pb(index = 1)]
+  pub trigger_id: String,
+
+  #[pb(index = 2)]
+  pub database_id: String,
+
+  #[pb(index = 3)]
+  pub trigger_type: AutomationTriggerTypePB,
+
+  /// JSON-serialized trigger config, see
+  /// [crate::services::automation] for the config structs.
+  #[pb(index = 4)]
+  pub trigger_config: String,
+
+  #[pb(index = 5)]
+  pub action_type: AutomationActionTypePB,
+
+  /// JSON-serialized action config.
+  #[pb(index = 6)]
+  pub action_config: String,
+
+  #[pb(index = 7)]
+  pub enabled: bool,
+
+  #[pb(index = 8)]
+  pub created_at: i64,
+}
+
+impl From<AutomationTable> for AutomationPB {
+  fn from(table: AutomationTable) -> Self {
+    Self {
+      trigger_id: table.trigger_id,
+      database_id: table.database_id,
+      trigger_type: table.trigger_type.into(),
+      trigger_config: table.trigger_config,
+      action_type: table.action_type.into(),
+      action_config: table.action_config,
+      enabled: table.enabled,
+      created_at: table.created_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedAutomationPB {
+  #[pb(index = 1)]
+  pub items: Vec<AutomationPB>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AddAutomationPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub trigger_type: AutomationTriggerTypePB,
+
+  #[pb(index = 3)]
+  pub trigger_config: String,
+
+  #[pb(index = 4)]
+  pub action_type: AutomationActionTypePB,
+
+  #[pb(index = 5)]
+  pub action_config: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AddAutomationParams {
+  pub view_id: String,
+  pub trigger_type: AutomationTriggerTypePB,
+  pub trigger_config: String,
+  pub action_type: AutomationActionTypePB,
+  pub action_config: String,
+}
+
+impl TryInto<AddAutomationParams> for AddAutomationPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AddAutomationParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    Ok(AddAutomationParams {
+      view_id,
+      trigger_type: self.trigger_type,
+      trigger_config: self.trigger_config,
+      action_type: self.action_type,
+      action_config: self.action_config,
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationIdPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub trigger_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct AutomationIdParams {
+  pub view_id: String,
+  pub trigger_id: String,
+}
+
+impl TryInto<AutomationIdParams> for AutomationIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AutomationIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let trigger_id = NotEmptyStr::parse(self.trigger_id)
+      .map_err(|_| ErrorCode::InvalidParams)?
+      .0;
+    Ok(AutomationIdParams {
+      view_id,
+      trigger_id,
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SetAutomationEnabledPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub trigger_id: String,
+
+  #[pb(index = 3)]
+  pub enabled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetAutomationEnabledParams {
+  pub view_id: String,
+  pub trigger_id: String,
+  pub enabled: bool,
+}
+
+impl TryInto<SetAutomationEnabledParams> for SetAutomationEnabledPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SetAutomationEnabledParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let trigger_id = NotEmptyStr::parse(self.trigger_id)
+      .map_err(|_| ErrorCode::InvalidParams)?
+      .0;
+    Ok(SetAutomationEnabledParams {
+      view_id,
+      trigger_id,
+      enabled: self.enabled,
+    })
+  }
+}
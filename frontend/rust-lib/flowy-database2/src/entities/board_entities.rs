@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use flowy_derive::ProtoBuf;
 
 use crate::services::setting::BoardLayoutSetting;
@@ -9,6 +11,9 @@ pub struct BoardLayoutSettingPB {
 
   #[pb(index = 2)]
   pub collapse_hidden_groups: bool,
+
+  #[pb(index = 3)]
+  pub wip_limits: HashMap<String, i64>,
 }
 
 impl From<BoardLayoutSetting> for BoardLayoutSettingPB {
@@ -16,6 +21,7 @@ impl From<BoardLayoutSetting> for BoardLayoutSettingPB {
     Self {
       hide_ungrouped_column: setting.hide_ungrouped_column,
       collapse_hidden_groups: setting.collapse_hidden_groups,
+      wip_limits: setting.wip_limits,
     }
   }
 }
@@ -25,6 +31,7 @@ impl From<BoardLayoutSettingPB> for BoardLayoutSetting {
     Self {
       hide_ungrouped_column: setting.hide_ungrouped_column,
       collapse_hidden_groups: setting.collapse_hidden_groups,
+      wip_limits: setting.wip_limits,
     }
   }
 }
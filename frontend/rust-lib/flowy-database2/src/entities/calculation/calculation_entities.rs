@@ -73,9 +73,14 @@ pub enum CalculationType {
   Median = 2,        // Number
   Min = 3,           // Number
   Sum = 4,           // Number
-  Count = 5,         // All
-  CountEmpty = 6,    // All
-  CountNonEmpty = 7, // All
+  Count = 5,            // All
+  CountEmpty = 6,       // All
+  CountNonEmpty = 7,    // All
+  Range = 8,            // Number
+  PercentChecked = 9,   // Checkbox
+  PercentUnchecked = 10, // Checkbox
+  EarliestDate = 11,    // Date
+  LatestDate = 12,      // Date
 }
 
 impl Display for CalculationType {
@@ -126,9 +131,18 @@ impl CalculationType {
       | CalculationType::Min
       | CalculationType::Average
       | CalculationType::Median
+      | CalculationType::Range
       | CalculationType::Sum => {
         matches!(field_type, FieldType::Number)
       },
+      // Checkbox fields only
+      CalculationType::PercentChecked | CalculationType::PercentUnchecked => {
+        matches!(field_type, FieldType::Checkbox)
+      },
+      // Date fields only
+      CalculationType::EarliestDate | CalculationType::LatestDate => {
+        matches!(field_type, FieldType::DateTime)
+      },
       // Exclude some fields from CountNotEmpty & CountEmpty
       CalculationType::CountEmpty | CalculationType::CountNonEmpty => !matches!(
         field_type,
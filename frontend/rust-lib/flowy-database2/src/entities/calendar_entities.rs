@@ -23,6 +23,11 @@ pub struct CalendarLayoutSettingPB {
 
   #[pb(index = 5)]
   pub show_week_numbers: bool,
+
+  /// An optional second date field marking the end of each event, so rows
+  /// can span multiple days.
+  #[pb(index = 6, one_of)]
+  pub end_field_id: Option<String>,
 }
 
 impl std::convert::From<CalendarLayoutSettingPB> for CalendarLayoutSetting {
@@ -33,6 +38,7 @@ impl std::convert::From<CalendarLayoutSettingPB> for CalendarLayoutSetting {
       show_weekends: pb.show_weekends,
       show_week_numbers: pb.show_week_numbers,
       field_id: pb.field_id,
+      end_field_id: pb.end_field_id.unwrap_or_default(),
     }
   }
 }
@@ -45,6 +51,11 @@ impl std::convert::From<CalendarLayoutSetting> for CalendarLayoutSettingPB {
       first_day_of_week: params.first_day_of_week,
       show_weekends: params.show_weekends,
       show_week_numbers: params.show_week_numbers,
+      end_field_id: if params.end_field_id.is_empty() {
+        None
+      } else {
+        Some(params.end_field_id)
+      },
     }
   }
 }
@@ -81,11 +92,21 @@ impl std::convert::From<CalendarLayout> for CalendarLayoutPB {
 pub struct CalendarEventRequestPB {
   #[pb(index = 1)]
   pub view_id: String,
+
+  /// The visible date range of the calendar. When provided, recurring date
+  /// cells are expanded into one event per occurrence inside the range.
+  #[pb(index = 2, one_of)]
+  pub range_start: Option<i64>,
+
+  #[pb(index = 3, one_of)]
+  pub range_end: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct CalendarEventRequestParams {
   pub view_id: String,
+  pub range_start: Option<i64>,
+  pub range_end: Option<i64>,
 }
 
 impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
@@ -93,7 +114,11 @@ impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
 
   fn try_into(self) -> Result<CalendarEventRequestParams, Self::Error> {
     let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
-    Ok(CalendarEventRequestParams { view_id: view_id.0 })
+    Ok(CalendarEventRequestParams {
+      view_id: view_id.0,
+      range_start: self.range_start,
+      range_end: self.range_end,
+    })
   }
 }
 
@@ -110,6 +135,16 @@ pub struct CalendarEventPB {
 
   #[pb(index = 4, one_of)]
   pub timestamp: Option<i64>,
+
+  /// When the date cell has an end date, the event spans from [timestamp] to
+  /// [end_timestamp] and the UI renders it across multiple days.
+  #[pb(index = 5, one_of)]
+  pub end_timestamp: Option<i64>,
+
+  /// True when this event is an occurrence expanded from a recurring date
+  /// cell. All occurrences share the same [row_meta].
+  #[pb(index = 6)]
+  pub is_recurring: bool,
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -125,6 +160,29 @@ pub struct MoveCalendarEventPB {
 
   #[pb(index = 2)]
   pub timestamp: i64,
+
+  /// Only meaningful when the date cell of the event is recurring. Ignored
+  /// otherwise.
+  #[pb(index = 3)]
+  pub edit_mode: RecurringEditModePB,
+
+  /// The start timestamp of the occurrence being moved. Required when
+  /// [edit_mode] is [RecurringEditModePB::ThisOccurrence], so the occurrence
+  /// can be excluded from the original rule.
+  #[pb(index = 4, one_of)]
+  pub occurrence_timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum RecurringEditModePB {
+  /// Shifts the base timestamp of the recurring cell, moving every
+  /// occurrence.
+  #[default]
+  AllOccurrences = 0,
+  /// Detaches the occurrence into a standalone row and excludes it from the
+  /// recurring rule of the original row.
+  ThisOccurrence = 1,
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -141,3 +199,42 @@ pub struct RepeatedNoDateCalendarEventPB {
   #[pb(index = 1)]
   pub items: Vec<NoDateCalendarEventPB>,
 }
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ExportCalendarICSPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// When set, the .ics content is also written to this path. Writing the
+  /// feed to the same path on every export gives external calendar apps a
+  /// stable file to subscribe to.
+  #[pb(index = 2, one_of)]
+  pub file_path: Option<String>,
+}
+
+pub struct ExportCalendarICSParams {
+  pub view_id: String,
+  pub file_path: Option<String>,
+}
+
+impl TryInto<ExportCalendarICSParams> for ExportCalendarICSPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ExportCalendarICSParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    Ok(ExportCalendarICSParams {
+      view_id: view_id.0,
+      file_path: self.file_path,
+    })
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ExportCalendarICSResultPB {
+  #[pb(index = 1)]
+  pub content: String,
+
+  /// The path the feed was written to, when one was requested.
+  #[pb(index = 2, one_of)]
+  pub file_path: Option<String>,
+}
@@ -242,6 +242,20 @@ impl TryInto<EditFieldParams> for UpdateFieldTypePayloadPB {
   }
 }
 
+/// Dry-run result of switching a field to another type: how many cells the
+/// switch would rewrite and whether it can drop information the new type
+/// cannot represent.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldTypeTransformImpactPB {
+  /// Number of non-empty cells the switch would rewrite. Zero when the
+  /// cells are kept and reinterpreted at read time.
+  #[pb(index = 1)]
+  pub altered_cell_count: i64,
+
+  #[pb(index = 2)]
+  pub lossy: bool,
+}
+
 /// Collection of the [FieldPB]
 #[derive(Debug, Default, ProtoBuf)]
 pub struct RepeatedFieldPB {
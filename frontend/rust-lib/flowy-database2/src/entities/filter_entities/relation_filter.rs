@@ -1,4 +1,8 @@
+use std::str::FromStr;
+
 use collab_database::{fields::Field, rows::Cell};
+use serde::{Deserialize, Serialize};
+
 use flowy_derive::ProtoBuf;
 
 use crate::services::filter::{ParseFilterData, PreFillCellsWithFilter};
@@ -7,11 +11,52 @@ use crate::services::filter::{ParseFilterData, PreFillCellsWithFilter};
 pub struct RelationFilterPB {
   #[pb(index = 1)]
   pub condition: i64,
+
+  /// The field in the related database whose cells the condition is applied
+  /// to. When empty the filter has no effect and every row stays visible.
+  #[pb(index = 2)]
+  pub remote_field_id: String,
+
+  /// The text the remote cell must contain (case-insensitive). A row is
+  /// visible when the remote cell of at least one of its related rows
+  /// matches.
+  #[pb(index = 3)]
+  pub remote_content: String,
+}
+
+/// The remote condition of a relation filter, serialized as JSON into the
+/// filter's content so that older filters (with an empty content) keep their
+/// behavior.
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct RelationFilterContent {
+  #[serde(default)]
+  pub remote_field_id: String,
+  #[serde(default)]
+  pub remote_content: String,
+}
+
+impl RelationFilterContent {
+  pub fn to_json_string(&self) -> String {
+    serde_json::to_string(self).unwrap()
+  }
+}
+
+impl FromStr for RelationFilterContent {
+  type Err = serde_json::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    serde_json::from_str(s)
+  }
 }
 
 impl ParseFilterData for RelationFilterPB {
-  fn parse(_condition: u8, _content: String) -> Self {
-    RelationFilterPB { condition: 0 }
+  fn parse(condition: u8, content: String) -> Self {
+    let content = RelationFilterContent::from_str(&content).unwrap_or_default();
+    RelationFilterPB {
+      condition: condition as i64,
+      remote_field_id: content.remote_field_id,
+      remote_content: content.remote_content,
+    }
   }
 }
 
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+use crate::entities::parser::NotEmptyStr;
+use crate::services::setting::{FormFieldSetting, FormLayoutSetting};
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf)]
+pub struct FormLayoutSettingPB {
+  /// The fields shown on the form, in display order.
+  #[pb(index = 1)]
+  pub fields: Vec<FormFieldSettingPB>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf)]
+pub struct FormFieldSettingPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub visible: bool,
+
+  #[pb(index = 3)]
+  pub required: bool,
+}
+
+impl std::convert::From<FormLayoutSettingPB> for FormLayoutSetting {
+  fn from(pb: FormLayoutSettingPB) -> Self {
+    FormLayoutSetting {
+      fields: pb.fields.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+impl std::convert::From<FormLayoutSetting> for FormLayoutSettingPB {
+  fn from(params: FormLayoutSetting) -> Self {
+    FormLayoutSettingPB {
+      fields: params.fields.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+impl std::convert::From<FormFieldSettingPB> for FormFieldSetting {
+  fn from(pb: FormFieldSettingPB) -> Self {
+    FormFieldSetting {
+      field_id: pb.field_id,
+      visible: pb.visible,
+      required: pb.required,
+    }
+  }
+}
+
+impl std::convert::From<FormFieldSetting> for FormFieldSettingPB {
+  fn from(params: FormFieldSetting) -> Self {
+    FormFieldSettingPB {
+      field_id: params.field_id,
+      visible: params.visible,
+      required: params.required,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf, Validate)]
+pub struct SubmitFormPayloadPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  /// The submitted cell contents, keyed by field id.
+  #[pb(index = 2)]
+  pub cells: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SubmitFormParams {
+  pub view_id: String,
+  pub cells: HashMap<String, String>,
+}
+
+impl TryInto<SubmitFormParams> for SubmitFormPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SubmitFormParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    Ok(SubmitFormParams {
+      view_id,
+      cells: self.cells,
+    })
+  }
+}
@@ -6,7 +6,7 @@ use lib_infra::validator_fn::required_not_empty_str;
 use validator::Validate;
 
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::{FieldType, RowMetaPB};
+use crate::entities::{CalculationPB, FieldType, RowMetaPB};
 use crate::services::group::{GroupChangeset, GroupData, GroupSetting};
 
 use super::group_config_json_to_pb;
@@ -92,6 +92,21 @@ pub struct GroupPB {
 
   #[pb(index = 6)]
   pub is_visible: bool,
+
+  /// The values of the view's calculations computed over the rows of this
+  /// group only. Empty when the view has no calculations.
+  #[pb(index = 7)]
+  pub calculations: Vec<CalculationPB>,
+
+  /// The board's WIP limit for this group, if one is configured. The current
+  /// card count is the length of [rows].
+  #[pb(index = 8, one_of)]
+  pub wip_limit: Option<i64>,
+
+  /// The custom hex color of the select option backing this group, when the
+  /// option has one. Unset for the fixed palette and for non-select groups.
+  #[pb(index = 9, one_of)]
+  pub hex_color: Option<String>,
 }
 
 impl std::convert::From<GroupData> for GroupPB {
@@ -102,6 +117,9 @@ impl std::convert::From<GroupData> for GroupPB {
       rows: group_data.rows.into_iter().map(RowMetaPB::from).collect(),
       is_default: group_data.is_default,
       is_visible: group_data.is_visible,
+      calculations: vec![],
+      wip_limit: None,
+      hex_color: None,
     }
   }
 }
@@ -62,6 +62,11 @@ macro_rules! impl_into_calculation_type {
           5 => CalculationType::Count,
           6 => CalculationType::CountEmpty,
           7 => CalculationType::CountNonEmpty,
+          8 => CalculationType::Range,
+          9 => CalculationType::PercentChecked,
+          10 => CalculationType::PercentUnchecked,
+          11 => CalculationType::EarliestDate,
+          12 => CalculationType::LatestDate,
           _ => {
             tracing::error!("🔴 Can't parse CalculationType from value: {}", ty);
             CalculationType::Average
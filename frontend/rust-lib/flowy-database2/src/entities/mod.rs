@@ -1,3 +1,4 @@
+mod automation_entities;
 mod board_entities;
 pub mod calculation;
 mod calendar_entities;
@@ -7,19 +8,29 @@ mod field_entities;
 mod field_settings_entities;
 pub mod file_entities;
 pub mod filter_entities;
+mod form_entities;
 mod group_entities;
+mod my_tasks_entities;
 pub mod parser;
+mod personal_view_entities;
 mod position_entities;
+mod query_entities;
+mod row_comment_entities;
 mod row_entities;
+mod row_history_entities;
+mod row_template_entities;
 pub mod setting_entities;
 mod share_entities;
 mod sort_entities;
+mod template_entities;
+mod timeline_entities;
 mod type_option_entities;
 mod view_entities;
 
 #[macro_use]
 mod macros;
 
+pub use automation_entities::*;
 pub use board_entities::*;
 pub use calculation::*;
 pub use calendar_entities::*;
@@ -29,12 +40,21 @@ pub use field_entities::*;
 pub use field_settings_entities::*;
 pub use file_entities::*;
 pub use filter_entities::*;
+pub use form_entities::*;
 pub use group_entities::*;
+pub use my_tasks_entities::*;
+pub use personal_view_entities::*;
 pub use position_entities::*;
+pub use query_entities::*;
+pub use row_comment_entities::*;
 pub use row_entities::*;
+pub use row_history_entities::*;
+pub use row_template_entities::*;
 pub use setting_entities::*;
 pub use share_entities::*;
 pub use sort_entities::*;
+pub use template_entities::*;
+pub use timeline_entities::*;
 pub use type_option_entities::*;
 pub use view_entities::*;
 
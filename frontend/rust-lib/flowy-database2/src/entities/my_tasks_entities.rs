@@ -0,0 +1,70 @@
+use flowy_derive::ProtoBuf;
+
+use crate::services::my_tasks::{MyTask, MyTasksFilter};
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct MyTasksPayloadPB {
+  /// Matches person fields against this name, case-insensitively. When empty,
+  /// only unchecked rows with an upcoming due date are returned.
+  #[pb(index = 1, one_of)]
+  pub person_name: Option<String>,
+
+  /// Only return tasks whose due date falls before this timestamp.
+  #[pb(index = 2, one_of)]
+  pub due_before: Option<i64>,
+
+  #[pb(index = 3)]
+  pub include_done: bool,
+}
+
+impl From<MyTasksPayloadPB> for MyTasksFilter {
+  fn from(payload: MyTasksPayloadPB) -> Self {
+    Self {
+      person_name: payload.person_name,
+      due_before: payload.due_before,
+      include_done: payload.include_done,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct MyTaskPB {
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  /// The view the task's row belongs to, so the home screen can open it.
+  #[pb(index = 2)]
+  pub view_id: String,
+
+  #[pb(index = 3)]
+  pub row_id: String,
+
+  /// The row's primary cell content.
+  #[pb(index = 4)]
+  pub title: String,
+
+  #[pb(index = 5, one_of)]
+  pub due_timestamp: Option<i64>,
+
+  #[pb(index = 6)]
+  pub is_done: bool,
+}
+
+impl From<MyTask> for MyTaskPB {
+  fn from(task: MyTask) -> Self {
+    Self {
+      database_id: task.database_id,
+      view_id: task.view_id,
+      row_id: task.row_id,
+      title: task.title,
+      due_timestamp: task.due_timestamp,
+      is_done: task.is_done,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedMyTaskPB {
+  #[pb(index = 1)]
+  pub items: Vec<MyTaskPB>,
+}
@@ -0,0 +1,39 @@
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::NotEmptyStr;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SetViewPersonalPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub is_personal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SetViewPersonalParams {
+  pub view_id: String,
+  pub is_personal: bool,
+}
+
+impl TryInto<SetViewPersonalParams> for SetViewPersonalPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SetViewPersonalParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    Ok(SetViewPersonalParams {
+      view_id,
+      is_personal: self.is_personal,
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct PersonalViewStatusPB {
+  #[pb(index = 1)]
+  pub is_personal: bool,
+}
@@ -0,0 +1,85 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::entities::RowMetaPB;
+use crate::entities::parser::NotEmptyStr;
+
+/// A simple cell predicate evaluated against the stringified cell value of a
+/// field, without touching the filters of the view.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowPredicatePB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub condition: RowPredicateConditionPB,
+
+  #[pb(index = 3)]
+  pub value: String,
+}
+
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, ProtoBuf_Enum)]
+pub enum RowPredicateConditionPB {
+  #[default]
+  Equals = 0,
+  Contains = 1,
+  /// The cell and the value are compared as numbers when both parse,
+  /// lexicographically otherwise. This also covers date cells, which
+  /// stringify to timestamps before formatting.
+  GreaterThanOrEquals = 2,
+  LessThanOrEquals = 3,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct QueryRowsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// All predicates must match for a row to be returned.
+  #[pb(index = 2)]
+  pub predicates: Vec<RowPredicatePB>,
+
+  /// The maximum number of rows to return. Zero means no limit.
+  #[pb(index = 3)]
+  pub limit: i64,
+
+  #[pb(index = 4)]
+  pub offset: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryRowsParams {
+  pub view_id: String,
+  pub predicates: Vec<RowPredicatePB>,
+  pub limit: i64,
+  pub offset: i64,
+}
+
+impl TryInto<QueryRowsParams> for QueryRowsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<QueryRowsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    for predicate in self.predicates.iter() {
+      NotEmptyStr::parse(predicate.field_id.clone()).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    }
+    Ok(QueryRowsParams {
+      view_id,
+      predicates: self.predicates,
+      limit: self.limit,
+      offset: self.offset,
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct QueryRowsResultPB {
+  #[pb(index = 1)]
+  pub rows: Vec<RowMetaPB>,
+
+  #[pb(index = 2)]
+  pub has_more: bool,
+}
@@ -0,0 +1,265 @@
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::NotEmptyStr;
+use crate::services::row_comment::RowCommentTable;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowCommentPB {
+  #[pb(index = 1)]
+  pub comment_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  /// The id of the comment this one replies to, if any.
+  #[pb(index = 3, one_of)]
+  pub reply_comment_id: Option<String>,
+
+  #[pb(index = 4)]
+  pub uid: i64,
+
+  #[pb(index = 5)]
+  pub content: String,
+
+  #[pb(index = 6)]
+  pub reactions: Vec<RowCommentReactionPB>,
+
+  #[pb(index = 7)]
+  pub created_at: i64,
+
+  #[pb(index = 8)]
+  pub updated_at: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowCommentReactionPB {
+  #[pb(index = 1)]
+  pub emoji: String,
+
+  /// The ids of the users that reacted with this emoji.
+  #[pb(index = 2)]
+  pub uids: Vec<i64>,
+}
+
+impl From<RowCommentTable> for RowCommentPB {
+  fn from(comment: RowCommentTable) -> Self {
+    let mut reactions = comment
+      .parse_reactions()
+      .into_iter()
+      .map(|(emoji, uids)| RowCommentReactionPB { emoji, uids })
+      .collect::<Vec<_>>();
+    reactions.sort_by(|a, b| a.emoji.cmp(&b.emoji));
+    Self {
+      comment_id: comment.comment_id,
+      row_id: comment.row_id,
+      reply_comment_id: comment.reply_comment_id,
+      uid: comment.uid,
+      content: comment.content,
+      reactions,
+      created_at: comment.created_at,
+      updated_at: comment.updated_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedRowCommentPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowCommentPB>,
+
+  /// True if older comments exist beyond this page.
+  #[pb(index = 2)]
+  pub has_more: bool,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct AddRowCommentPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub content: String,
+
+  #[pb(index = 4, one_of)]
+  pub reply_comment_id: Option<String>,
+}
+
+pub struct AddRowCommentParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub content: String,
+  pub reply_comment_id: Option<String>,
+}
+
+impl TryInto<AddRowCommentParams> for AddRowCommentPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AddRowCommentParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(AddRowCommentParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      content: content.0,
+      reply_comment_id: self.reply_comment_id,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct UpdateRowCommentPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub comment_id: String,
+
+  #[pb(index = 3)]
+  pub content: String,
+}
+
+pub struct UpdateRowCommentParams {
+  pub view_id: String,
+  pub comment_id: String,
+  pub content: String,
+}
+
+impl TryInto<UpdateRowCommentParams> for UpdateRowCommentPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateRowCommentParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let comment_id = NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(UpdateRowCommentParams {
+      view_id: view_id.0,
+      comment_id: comment_id.0,
+      content: content.0,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DeleteRowCommentPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub comment_id: String,
+}
+
+pub struct DeleteRowCommentParams {
+  pub view_id: String,
+  pub comment_id: String,
+}
+
+impl TryInto<DeleteRowCommentParams> for DeleteRowCommentPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DeleteRowCommentParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let comment_id = NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(DeleteRowCommentParams {
+      view_id: view_id.0,
+      comment_id: comment_id.0,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct ToggleRowCommentReactionPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub comment_id: String,
+
+  #[pb(index = 3)]
+  pub emoji: String,
+}
+
+pub struct ToggleRowCommentReactionParams {
+  pub view_id: String,
+  pub comment_id: String,
+  pub emoji: String,
+}
+
+impl TryInto<ToggleRowCommentReactionParams> for ToggleRowCommentReactionPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ToggleRowCommentReactionParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let comment_id = NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let emoji = NotEmptyStr::parse(self.emoji).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(ToggleRowCommentReactionParams {
+      view_id: view_id.0,
+      comment_id: comment_id.0,
+      emoji: emoji.0,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct GetRowCommentsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  /// The maximum number of comments to return.
+  #[pb(index = 3)]
+  pub limit: i64,
+
+  /// When set, only the comments created before this timestamp are returned.
+  /// Used to fetch the next page.
+  #[pb(index = 4, one_of)]
+  pub before: Option<i64>,
+}
+
+pub struct GetRowCommentsParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub limit: i64,
+  pub before: Option<i64>,
+}
+
+impl TryInto<GetRowCommentsParams> for GetRowCommentsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<GetRowCommentsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    if self.limit <= 0 {
+      return Err(ErrorCode::InvalidParams);
+    }
+    Ok(GetRowCommentsParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      limit: self.limit,
+      before: self.before,
+    })
+  }
+}
+
+/// The payload of the [DatabaseNotification::DidUpdateRowComments]
+/// notification, keyed by the row id.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RowCommentChangesetPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub inserted_comments: Vec<RowCommentPB>,
+
+  #[pb(index = 3)]
+  pub updated_comments: Vec<RowCommentPB>,
+
+  #[pb(index = 4)]
+  pub deleted_comment_ids: Vec<String>,
+}
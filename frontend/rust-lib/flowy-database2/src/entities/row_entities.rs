@@ -72,6 +72,10 @@ pub struct RowMetaPB {
 
   #[pb(index = 6, one_of)]
   pub cover: Option<RowCoverPB>,
+
+  /// The number of comments on the row, used for card badges.
+  #[pb(index = 7, one_of)]
+  pub comment_count: Option<i64>,
 }
 
 #[derive(Debug, Default, Clone, ProtoBuf, Serialize, Deserialize)]
@@ -153,6 +157,7 @@ impl From<RowOrder> for RowMetaPB {
       is_document_empty: None,
       attachment_count: None,
       cover: None,
+      comment_count: None,
     }
   }
 }
@@ -166,6 +171,7 @@ impl From<&Row> for RowMetaPB {
       cover: None,
       is_document_empty: None,
       attachment_count: None,
+      comment_count: None,
     }
   }
 }
@@ -179,6 +185,7 @@ impl From<Row> for RowMetaPB {
       is_document_empty: None,
       attachment_count: None,
       cover: None,
+      comment_count: None,
     }
   }
 }
@@ -192,6 +199,7 @@ impl From<RowDetail> for RowMetaPB {
       is_document_empty: Some(row_detail.meta.is_document_empty),
       attachment_count: Some(row_detail.meta.attachment_count),
       cover: row_detail.meta.cover.map(|cover| cover.into()),
+      comment_count: None,
     }
   }
 }
@@ -205,6 +213,7 @@ impl From<&RowDetail> for RowMetaPB {
       is_document_empty: Some(row_detail.meta.is_document_empty),
       attachment_count: Some(row_detail.meta.attachment_count),
       cover: row_detail.meta.clone().cover.map(|cover| cover.into()),
+      comment_count: None,
     }
   }
 }
@@ -463,6 +472,42 @@ pub struct RepeatedRowIdPB {
   pub row_ids: Vec<String>,
 }
 
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DuplicateRowsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_ids: Vec<String>,
+
+  /// When true, relation cells that point at other rows of the selection are
+  /// rewritten to point at the new copies.
+  #[pb(index = 3)]
+  pub remap_relations: bool,
+}
+
+pub struct DuplicateRowsParams {
+  pub view_id: String,
+  pub row_ids: Vec<RowId>,
+  pub remap_relations: bool,
+}
+
+impl TryInto<DuplicateRowsParams> for DuplicateRowsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DuplicateRowsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    if self.row_ids.is_empty() {
+      return Err(ErrorCode::RowIdIsEmpty);
+    }
+    Ok(DuplicateRowsParams {
+      view_id: view_id.0,
+      row_ids: self.row_ids.into_iter().map(RowId::from).collect(),
+      remap_relations: self.remap_relations,
+    })
+  }
+}
+
 #[derive(ProtoBuf, Default, Validate)]
 pub struct CreateRowPayloadPB {
   #[pb(index = 1)]
@@ -492,6 +537,17 @@ pub struct SummaryRowPB {
   pub field_id: String,
 }
 
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct GenerateSummaryColumnPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub field_id: String,
+}
+
 #[derive(Debug, Default, Clone, ProtoBuf, Validate)]
 pub struct TranslateRowPB {
   #[pb(index = 1)]
@@ -506,3 +562,91 @@ pub struct TranslateRowPB {
   #[validate(custom(function = "required_not_empty_str"))]
   pub field_id: String,
 }
+
+#[derive(Debug, Default, Clone, ProtoBuf, Validate)]
+pub struct TranslateColumnPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub field_id: String,
+}
+
+/// Sent with [DatabaseNotification::DidUpdateTranslateColumnProgress] after
+/// each row finishes while a whole column is being translated.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct TranslateColumnProgressPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  /// The number of rows translated so far, including failed ones.
+  #[pb(index = 3)]
+  pub completed: i64,
+
+  #[pb(index = 4)]
+  pub total: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct GetRowsPagePayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub offset: i64,
+
+  /// The maximum number of rows to return. Zero means no limit.
+  #[pb(index = 3)]
+  pub limit: i64,
+}
+
+pub struct GetRowsPageParams {
+  pub view_id: String,
+  pub offset: i64,
+  pub limit: i64,
+}
+
+impl TryInto<GetRowsPageParams> for GetRowsPagePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<GetRowsPageParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    Ok(GetRowsPageParams {
+      view_id: view_id.0,
+      offset: self.offset,
+      limit: self.limit,
+    })
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowsPagePB {
+  #[pb(index = 1)]
+  pub rows: Vec<RowMetaPB>,
+
+  /// The total number of visible rows in the view, after filtering.
+  #[pb(index = 2)]
+  pub total: i64,
+
+  #[pb(index = 3)]
+  pub has_more: bool,
+}
+
+/// Sent with [DatabaseNotification::DidUpdateRowWindow] when the rows inside
+/// the subscribed window may have changed and the page should be refetched.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowsWindowChangedPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub offset: i64,
+
+  #[pb(index = 3)]
+  pub limit: i64,
+}
@@ -0,0 +1,128 @@
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::NotEmptyStr;
+use crate::services::row_history::RowHistoryTable;
+
+/// A single recorded cell-level change of a row.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowHistoryPB {
+  /// The version of the row history. Pass it to `RevertRow` to restore the
+  /// row to the state it had at this point in time.
+  #[pb(index = 1)]
+  pub version: i64,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub field_id: String,
+
+  /// The serialized cell before the change. Empty if the cell didn't exist.
+  #[pb(index = 4)]
+  pub old_value: String,
+
+  /// The serialized cell after the change.
+  #[pb(index = 5)]
+  pub new_value: String,
+
+  /// The id of the user that made the change.
+  #[pb(index = 6)]
+  pub uid: i64,
+
+  #[pb(index = 7)]
+  pub created_at: i64,
+}
+
+impl From<RowHistoryTable> for RowHistoryPB {
+  fn from(entry: RowHistoryTable) -> Self {
+    Self {
+      version: entry.id as i64,
+      row_id: entry.row_id,
+      field_id: entry.field_id,
+      old_value: entry.old_value,
+      new_value: entry.new_value,
+      uid: entry.uid,
+      created_at: entry.created_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedRowHistoryPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowHistoryPB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct GetRowHistoryPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  /// The maximum number of changes to return.
+  #[pb(index = 3)]
+  pub limit: i64,
+}
+
+pub struct GetRowHistoryParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub limit: i64,
+}
+
+impl TryInto<GetRowHistoryParams> for GetRowHistoryPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<GetRowHistoryParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    if self.limit <= 0 {
+      return Err(ErrorCode::InvalidParams);
+    }
+    Ok(GetRowHistoryParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      limit: self.limit,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RevertRowPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  /// The version to revert the row to. All the changes recorded after this
+  /// version are undone.
+  #[pb(index = 3)]
+  pub version: i64,
+}
+
+pub struct RevertRowParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub version: i64,
+}
+
+impl TryInto<RevertRowParams> for RevertRowPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RevertRowParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    if self.version < 0 {
+      return Err(ErrorCode::InvalidParams);
+    }
+    Ok(RevertRowParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      version: self.version,
+    })
+  }
+}
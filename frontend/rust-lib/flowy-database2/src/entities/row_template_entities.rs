@@ -0,0 +1,105 @@
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::NotEmptyStr;
+use crate::services::row_template::RowTemplateTable;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowTemplatePB {
+  #[pb(index = 1)]
+  pub template_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  /// True if this template is applied to newly created rows.
+  #[pb(index = 3)]
+  pub is_default: bool,
+
+  /// The document id of the row the template was saved from. The document
+  /// content is copied from it when a row is created from the template.
+  #[pb(index = 4, one_of)]
+  pub source_document_id: Option<String>,
+
+  #[pb(index = 5)]
+  pub created_at: i64,
+}
+
+impl From<RowTemplateTable> for RowTemplatePB {
+  fn from(template: RowTemplateTable) -> Self {
+    Self {
+      template_id: template.template_id,
+      name: template.name,
+      is_default: template.is_default,
+      source_document_id: template.source_document_id,
+      created_at: template.created_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedRowTemplatePB {
+  #[pb(index = 1)]
+  pub items: Vec<RowTemplatePB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct SaveRowAsTemplatePayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  /// The name of the template. Defaults to the row's primary cell content.
+  #[pb(index = 3, one_of)]
+  pub name: Option<String>,
+}
+
+pub struct SaveRowAsTemplateParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub name: Option<String>,
+}
+
+impl TryInto<SaveRowAsTemplateParams> for SaveRowAsTemplatePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SaveRowAsTemplateParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    Ok(SaveRowAsTemplateParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      name: self.name,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RowTemplateIdPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub template_id: String,
+}
+
+pub struct RowTemplateIdParams {
+  pub view_id: String,
+  pub template_id: String,
+}
+
+impl TryInto<RowTemplateIdParams> for RowTemplateIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RowTemplateIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let template_id =
+      NotEmptyStr::parse(self.template_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(RowTemplateIdParams {
+      view_id: view_id.0,
+      template_id: template_id.0,
+    })
+  }
+}
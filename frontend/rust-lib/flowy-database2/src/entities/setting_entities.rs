@@ -13,9 +13,13 @@ use crate::entities::{
   RepeatedFieldSettingsPB, RepeatedFilterPB, RepeatedGroupSettingPB, RepeatedSortPB,
   UpdateFilterDataPB, UpdateFilterTypePB, UpdateGroupPB, UpdateSortPayloadPB,
 };
-use crate::services::setting::{BoardLayoutSetting, CalendarLayoutSetting};
+use crate::services::setting::{
+  BoardLayoutSetting, CalendarLayoutSetting, FormLayoutSetting, TimelineLayoutSetting,
+};
 
-use super::{BoardLayoutSettingPB, ReorderSortPayloadPB};
+use super::{
+  BoardLayoutSettingPB, FormLayoutSettingPB, ReorderSortPayloadPB, TimelineLayoutSettingPB,
+};
 
 /// [DatabaseViewSettingPB] defines the setting options for the grid. Such as the filter, group, and sort.
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
@@ -46,6 +50,7 @@ pub enum DatabaseLayoutPB {
   Grid = 0,
   Board = 1,
   Calendar = 2,
+  Timeline = 3,
 }
 
 impl std::convert::From<DatabaseLayout> for DatabaseLayoutPB {
@@ -64,6 +69,10 @@ impl std::convert::From<DatabaseLayoutPB> for DatabaseLayout {
       DatabaseLayoutPB::Grid => DatabaseLayout::Grid,
       DatabaseLayoutPB::Board => DatabaseLayout::Board,
       DatabaseLayoutPB::Calendar => DatabaseLayout::Calendar,
+      // collab-database doesn't have a dedicated timeline layout yet, so
+      // timeline views are persisted as grids. Their layout settings live
+      // under the grid key as well, see [TimelineLayoutSetting].
+      DatabaseLayoutPB::Timeline => DatabaseLayout::Grid,
     }
   }
 }
@@ -120,6 +129,12 @@ pub struct DatabaseLayoutSettingPB {
 
   #[pb(index = 3, one_of)]
   pub calendar: Option<CalendarLayoutSettingPB>,
+
+  #[pb(index = 4, one_of)]
+  pub timeline: Option<TimelineLayoutSettingPB>,
+
+  #[pb(index = 5, one_of)]
+  pub form: Option<FormLayoutSettingPB>,
 }
 
 impl DatabaseLayoutSettingPB {
@@ -128,6 +143,8 @@ impl DatabaseLayoutSettingPB {
       layout_type: DatabaseLayoutPB::Board,
       board: Some(layout_setting.into()),
       calendar: None,
+      timeline: None,
+      form: None,
     }
   }
 
@@ -136,6 +153,28 @@ impl DatabaseLayoutSettingPB {
       layout_type: DatabaseLayoutPB::Calendar,
       calendar: Some(layout_setting.into()),
       board: None,
+      timeline: None,
+      form: None,
+    }
+  }
+
+  pub fn from_timeline(layout_setting: TimelineLayoutSetting) -> Self {
+    Self {
+      layout_type: DatabaseLayoutPB::Timeline,
+      timeline: Some(layout_setting.into()),
+      board: None,
+      calendar: None,
+      form: None,
+    }
+  }
+
+  pub fn from_form(layout_setting: FormLayoutSetting) -> Self {
+    Self {
+      layout_type: DatabaseLayoutPB::Grid,
+      form: Some(layout_setting.into()),
+      board: None,
+      calendar: None,
+      timeline: None,
     }
   }
 }
@@ -145,6 +184,8 @@ pub struct LayoutSettingParams {
   pub layout_type: DatabaseLayout,
   pub board: Option<BoardLayoutSetting>,
   pub calendar: Option<CalendarLayoutSetting>,
+  pub timeline: Option<TimelineLayoutSetting>,
+  pub form: Option<FormLayoutSetting>,
 }
 
 impl LayoutSettingParams {
@@ -162,6 +203,8 @@ impl From<LayoutSettingParams> for DatabaseLayoutSettingPB {
       layout_type: data.layout_type.into(),
       board: data.board.map(|board| board.into()),
       calendar: data.calendar.map(|calendar| calendar.into()),
+      timeline: data.timeline.map(|timeline| timeline.into()),
+      form: data.form.map(|form| form.into()),
     }
   }
 }
@@ -179,6 +222,12 @@ pub struct LayoutSettingChangesetPB {
 
   #[pb(index = 4, one_of)]
   pub calendar: Option<CalendarLayoutSettingPB>,
+
+  #[pb(index = 5, one_of)]
+  pub timeline: Option<TimelineLayoutSettingPB>,
+
+  #[pb(index = 6, one_of)]
+  pub form: Option<FormLayoutSettingPB>,
 }
 
 #[derive(Debug)]
@@ -187,12 +236,18 @@ pub struct LayoutSettingChangeset {
   pub layout_type: DatabaseLayout,
   pub board: Option<BoardLayoutSetting>,
   pub calendar: Option<CalendarLayoutSetting>,
+  pub timeline: Option<TimelineLayoutSetting>,
+  pub form: Option<FormLayoutSetting>,
 }
 
 impl LayoutSettingChangeset {
   pub fn is_valid(&self) -> bool {
     self.board.is_some() && self.layout_type == DatabaseLayout::Board
       || self.calendar.is_some() && self.layout_type == DatabaseLayout::Calendar
+      // Timeline views are persisted with the grid layout, see
+      // [DatabaseLayoutPB::Timeline].
+      || (self.timeline.is_some() || self.form.is_some())
+        && self.layout_type == DatabaseLayout::Grid
   }
 }
 
@@ -209,6 +264,8 @@ impl TryInto<LayoutSettingChangeset> for LayoutSettingChangesetPB {
       layout_type: self.layout_type.into(),
       board: self.board.map(Into::into),
       calendar: self.calendar.map(Into::into),
+      timeline: self.timeline.map(Into::into),
+      form: self.form.map(Into::into),
     })
   }
 }
@@ -1,4 +1,8 @@
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::NotEmptyStr;
+use crate::services::share::csv::CSVRowImportError;
 
 #[derive(Debug, ProtoBuf_Enum, Clone, Default)]
 pub enum DatabaseExportDataType {
@@ -7,6 +11,12 @@ pub enum DatabaseExportDataType {
 
   // DatabaseData
   RawDatabaseData = 1,
+
+  // JSON schema (fields + type options) plus rows
+  JSON = 2,
+
+  // GitHub-flavored markdown table
+  Markdown = 3,
 }
 
 #[derive(Debug, ProtoBuf, Default, Clone)]
@@ -17,3 +27,125 @@ pub struct DatabaseExportDataPB {
   #[pb(index = 2)]
   pub data: String,
 }
+
+/// Binary export data, used by export formats that are not valid UTF-8 such
+/// as .xlsx.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct DatabaseExportBytesPB {
+  #[pb(index = 1)]
+  pub data: Vec<u8>,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct ExportCSVPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// When true the active filters and sorts of the view are applied and only
+  /// visible fields are exported, in their display order. When false the raw
+  /// table is exported.
+  #[pb(index = 2)]
+  pub apply_view_settings: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportCSVParams {
+  pub view_id: String,
+  pub apply_view_settings: bool,
+}
+
+impl TryInto<ExportCSVParams> for ExportCSVPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ExportCSVParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    Ok(ExportCSVParams {
+      view_id,
+      apply_view_settings: self.apply_view_settings,
+    })
+  }
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct ImportCSVRowsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub content: String,
+
+  /// Explicit column header → field id mapping. Columns without a mapping
+  /// fall back to the field with the same name; columns that match no field
+  /// are skipped.
+  #[pb(index = 3)]
+  pub column_mappings: Vec<CSVColumnMappingPB>,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CSVColumnMappingPB {
+  #[pb(index = 1)]
+  pub column: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportCSVRowsParams {
+  pub view_id: String,
+  pub content: String,
+  pub column_mappings: Vec<(String, String)>,
+}
+
+impl TryInto<ImportCSVRowsParams> for ImportCSVRowsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ImportCSVRowsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    Ok(ImportCSVRowsParams {
+      view_id,
+      content: self.content,
+      column_mappings: self
+        .column_mappings
+        .into_iter()
+        .map(|mapping| (mapping.column, mapping.field_id))
+        .collect(),
+    })
+  }
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct ImportCSVRowsResultPB {
+  #[pb(index = 1)]
+  pub imported_rows: i64,
+
+  #[pb(index = 2)]
+  pub row_errors: Vec<CSVRowErrorPB>,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CSVRowErrorPB {
+  /// Zero-based index of the row in the file, header excluded.
+  #[pb(index = 1)]
+  pub row: i64,
+
+  #[pb(index = 2)]
+  pub column: String,
+
+  #[pb(index = 3)]
+  pub error: String,
+}
+
+impl From<CSVRowImportError> for CSVRowErrorPB {
+  fn from(error: CSVRowImportError) -> Self {
+    Self {
+      row: error.row as i64,
+      column: error.column,
+      error: error.error,
+    }
+  }
+}
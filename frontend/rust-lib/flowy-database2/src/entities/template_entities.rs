@@ -0,0 +1,69 @@
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+use crate::entities::DatabaseLayoutPB;
+use crate::entities::parser::NotEmptyStr;
+use crate::template::DatabaseTemplate;
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DatabaseTemplatePB {
+  #[pb(index = 1)]
+  pub template_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub description: String,
+
+  #[pb(index = 4)]
+  pub layout: DatabaseLayoutPB,
+}
+
+impl From<DatabaseTemplate> for DatabaseTemplatePB {
+  fn from(template: DatabaseTemplate) -> Self {
+    Self {
+      template_id: template.id.to_string(),
+      name: template.name.to_string(),
+      description: template.description.to_string(),
+      layout: template.layout.into(),
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedDatabaseTemplatePB {
+  #[pb(index = 1)]
+  pub items: Vec<DatabaseTemplatePB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CreateDatabaseFromTemplatePayloadPB {
+  #[pb(index = 1)]
+  pub template_id: String,
+
+  /// The folder view the new database should be attached under. The caller
+  /// creates the folder view with the returned view id.
+  #[pb(index = 2)]
+  pub parent_view_id: String,
+}
+
+pub struct CreateDatabaseFromTemplateParams {
+  pub template_id: String,
+  pub parent_view_id: String,
+}
+
+impl TryInto<CreateDatabaseFromTemplateParams> for CreateDatabaseFromTemplatePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<CreateDatabaseFromTemplateParams, Self::Error> {
+    let template_id =
+      NotEmptyStr::parse(self.template_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let parent_view_id =
+      NotEmptyStr::parse(self.parent_view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    Ok(CreateDatabaseFromTemplateParams {
+      template_id: template_id.0,
+      parent_view_id: parent_view_id.0,
+    })
+  }
+}
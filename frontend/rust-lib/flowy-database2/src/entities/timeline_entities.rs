@@ -0,0 +1,136 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::entities::RowMetaPB;
+use crate::entities::parser::NotEmptyStr;
+use crate::services::setting::{TimelineLayoutSetting, TimelineZoomLevel};
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf)]
+pub struct TimelineLayoutSettingPB {
+  #[pb(index = 1)]
+  pub start_field_id: String,
+
+  #[pb(index = 2)]
+  pub end_field_id: String,
+
+  #[pb(index = 3)]
+  pub zoom_level: TimelineZoomLevelPB,
+}
+
+impl std::convert::From<TimelineLayoutSettingPB> for TimelineLayoutSetting {
+  fn from(pb: TimelineLayoutSettingPB) -> Self {
+    TimelineLayoutSetting {
+      start_field_id: pb.start_field_id,
+      end_field_id: pb.end_field_id,
+      zoom_level: pb.zoom_level.into(),
+    }
+  }
+}
+
+impl std::convert::From<TimelineLayoutSetting> for TimelineLayoutSettingPB {
+  fn from(params: TimelineLayoutSetting) -> Self {
+    TimelineLayoutSettingPB {
+      start_field_id: params.start_field_id,
+      end_field_id: params.end_field_id,
+      zoom_level: params.zoom_level.into(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum TimelineZoomLevelPB {
+  DayZoom = 0,
+  #[default]
+  WeekZoom = 1,
+  MonthZoom = 2,
+  YearZoom = 3,
+}
+
+impl std::convert::From<TimelineZoomLevelPB> for TimelineZoomLevel {
+  fn from(pb: TimelineZoomLevelPB) -> Self {
+    match pb {
+      TimelineZoomLevelPB::DayZoom => TimelineZoomLevel::Day,
+      TimelineZoomLevelPB::WeekZoom => TimelineZoomLevel::Week,
+      TimelineZoomLevelPB::MonthZoom => TimelineZoomLevel::Month,
+      TimelineZoomLevelPB::YearZoom => TimelineZoomLevel::Year,
+    }
+  }
+}
+
+impl std::convert::From<TimelineZoomLevel> for TimelineZoomLevelPB {
+  fn from(zoom_level: TimelineZoomLevel) -> Self {
+    match zoom_level {
+      TimelineZoomLevel::Day => TimelineZoomLevelPB::DayZoom,
+      TimelineZoomLevel::Week => TimelineZoomLevelPB::WeekZoom,
+      TimelineZoomLevel::Month => TimelineZoomLevelPB::MonthZoom,
+      TimelineZoomLevel::Year => TimelineZoomLevelPB::YearZoom,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct TimelineEventRequestPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TimelineEventRequestParams {
+  pub view_id: String,
+}
+
+impl TryInto<TimelineEventRequestParams> for TimelineEventRequestPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<TimelineEventRequestParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    Ok(TimelineEventRequestParams { view_id: view_id.0 })
+  }
+}
+
+/// One bar on the timeline: the row it represents and the timestamps of the
+/// date cells providing its start and end.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct TimelineEventPB {
+  #[pb(index = 1)]
+  pub row_meta: RowMetaPB,
+
+  #[pb(index = 2)]
+  pub start_field_id: String,
+
+  #[pb(index = 3)]
+  pub end_field_id: String,
+
+  #[pb(index = 4)]
+  pub title: String,
+
+  #[pb(index = 5, one_of)]
+  pub start_timestamp: Option<i64>,
+
+  #[pb(index = 6, one_of)]
+  pub end_timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedTimelineEventPB {
+  #[pb(index = 1)]
+  pub items: Vec<TimelineEventPB>,
+}
+
+/// Moves or resizes a bar by writing the dragged timestamps back into the
+/// underlying date cells.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct MoveTimelineEventPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3, one_of)]
+  pub start_timestamp: Option<i64>,
+
+  #[pb(index = 4, one_of)]
+  pub end_timestamp: Option<i64>,
+}
@@ -61,6 +61,16 @@ pub struct DateCellChangesetPB {
 
   #[pb(index = 7, one_of)]
   pub reminder_id: Option<String>,
+
+  /// Serialized [RecurringRule] json. An empty string removes the rule from
+  /// the cell.
+  #[pb(index = 8, one_of)]
+  pub recurrence: Option<String>,
+
+  /// Seconds before the date at which the reminder fires. A negative value
+  /// removes the reminder offset from the cell.
+  #[pb(index = 9, one_of)]
+  pub reminder_offset: Option<i64>,
 }
 
 // Date
@@ -4,6 +4,7 @@ use collab_database::fields::media_type_option::{
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 
 use crate::entities::{CellIdPB, FileUploadTypePB};
+use crate::services::media_meta::MediaFileMetaTable;
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct MediaCellDataPB {
@@ -65,6 +66,57 @@ pub struct MediaFilePB {
 
   #[pb(index = 5)]
   pub file_type: MediaFileTypePB,
+
+  /// The locally extracted metadata of the file. Unset when the metadata
+  /// hasn't been extracted yet.
+  #[pb(index = 6, one_of)]
+  pub metadata: Option<MediaFileMetadataPB>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct MediaFileMetadataPB {
+  /// The width of the image in pixels, zero when unknown.
+  #[pb(index = 1)]
+  pub width: i32,
+
+  /// The height of the image in pixels, zero when unknown.
+  #[pb(index = 2)]
+  pub height: i32,
+
+  /// The duration of the audio/video file in milliseconds, zero when
+  /// unknown.
+  #[pb(index = 3)]
+  pub duration: i64,
+
+  #[pb(index = 4)]
+  pub mime: String,
+
+  /// The path of the generated thumbnail, empty when no thumbnail was
+  /// generated.
+  #[pb(index = 5)]
+  pub thumbnail_path: String,
+}
+
+impl From<MediaFileMetaTable> for MediaFileMetadataPB {
+  fn from(meta: MediaFileMetaTable) -> Self {
+    Self {
+      width: meta.width,
+      height: meta.height,
+      duration: meta.duration,
+      mime: meta.mime,
+      thumbnail_path: meta.thumbnail_path,
+    }
+  }
+}
+
+/// Fills in the metadata of the files from the locally stored media file
+/// meta rows, matching by file id.
+pub fn apply_media_file_metadata(files: &mut [MediaFilePB], metas: Vec<MediaFileMetaTable>) {
+  for meta in metas {
+    if let Some(file) = files.iter_mut().find(|file| file.id == meta.file_id) {
+      file.metadata = Some(MediaFileMetadataPB::from(meta));
+    }
+  }
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, ProtoBuf_Enum)]
@@ -126,6 +178,7 @@ impl From<MediaFile> for MediaFilePB {
       url: data.url,
       upload_type: data.upload_type.into(),
       file_type: data.file_type.into(),
+      metadata: None,
     }
   }
 }
@@ -15,6 +15,27 @@ pub struct NumberTypeOptionPB {
 
   #[pb(index = 5)]
   pub name: String,
+
+  /// Unit shown before the number, e.g. "~" or "≈". Empty when unset.
+  #[pb(index = 6)]
+  pub prefix: String,
+
+  /// Unit shown after the number, e.g. " kg". Empty when unset.
+  #[pb(index = 7)]
+  pub suffix: String,
+
+  /// Custom thousands separator; empty means the format's default.
+  #[pb(index = 8)]
+  pub thousands_separator: String,
+
+  /// Custom decimal separator; empty means the format's default.
+  #[pb(index = 9)]
+  pub decimal_separator: String,
+
+  /// Rounds the displayed number to this many significant digits. Zero
+  /// disables the rounding.
+  #[pb(index = 10)]
+  pub significant_digits: u32,
 }
 
 impl From<NumberTypeOption> for NumberTypeOptionPB {
@@ -24,6 +45,9 @@ impl From<NumberTypeOption> for NumberTypeOptionPB {
       scale: data.scale,
       symbol: data.symbol,
       name: data.name,
+      // The formatting extras live next to [NumberTypeOption] in the type
+      // option data; they are filled in by `type_option_to_pb`.
+      ..Default::default()
     }
   }
 }
@@ -1,8 +1,10 @@
 use collab_database::fields::relation_type_option::RelationTypeOption;
 use collab_database::template::relation_parse::RelationCellData;
 use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
 
 use crate::entities::CellIdPB;
+use crate::entities::parser::NotEmptyStr;
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct RelationCellDataPB {
@@ -86,3 +88,44 @@ pub struct GetRelatedRowDataPB {
   #[pb(index = 2)]
   pub row_ids: Vec<String>,
 }
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SearchRelatedRowsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The relation field whose target database is searched.
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  /// The search text. An empty query returns the most recently modified
+  /// rows.
+  #[pb(index = 3)]
+  pub query: String,
+
+  /// The maximum number of rows to return. Zero means no limit.
+  #[pb(index = 4)]
+  pub limit: i64,
+}
+
+pub struct SearchRelatedRowsParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub query: String,
+  pub limit: i64,
+}
+
+impl TryInto<SearchRelatedRowsParams> for SearchRelatedRowsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SearchRelatedRowsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(SearchRelatedRowsParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      query: self.query,
+      limit: self.limit,
+    })
+  }
+}
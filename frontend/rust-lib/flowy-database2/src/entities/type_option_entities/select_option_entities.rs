@@ -1,5 +1,5 @@
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::{CellIdPB, CellIdParams};
+use crate::entities::{CellIdPB, CellIdParams, FieldIdParams};
 use collab_database::fields::checklist_type_option::ChecklistTypeOption;
 use collab_database::fields::select_type_option::{
   MultiSelectTypeOption, SelectOption, SelectOptionColor, SelectTypeOption, SingleSelectTypeOption,
@@ -18,6 +18,15 @@ pub struct SelectOptionPB {
 
   #[pb(index = 3)]
   pub color: SelectOptionColorPB,
+
+  /// Arbitrary hex color like `#AABBCC` overriding the fixed palette when
+  /// set. The palette [color] is kept in sync for older clients.
+  #[pb(index = 4, one_of)]
+  pub hex_color: Option<String>,
+
+  /// Optional label used to visually cluster related options.
+  #[pb(index = 5, one_of)]
+  pub group: Option<String>,
 }
 
 impl From<SelectOption> for SelectOptionPB {
@@ -26,6 +35,8 @@ impl From<SelectOption> for SelectOptionPB {
       id: data.id,
       name: data.name,
       color: data.color.into(),
+      hex_color: None,
+      group: None,
     }
   }
 }
@@ -55,6 +66,96 @@ pub struct RepeatedSelectOptionPayload {
   pub items: Vec<SelectOptionPB>,
 }
 
+/// Identifies a select/multiselect field, used by the option management
+/// events that operate on the whole field instead of a single cell.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectOptionFieldPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+}
+
+impl TryInto<FieldIdParams> for SelectOptionFieldPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FieldIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(FieldIdParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+    })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedSelectOptionPB {
+  #[pb(index = 1)]
+  pub items: Vec<SelectOptionPB>,
+}
+
+/// One option of a select/multiselect field together with the number of rows
+/// of the database that currently use it.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectOptionUsagePB {
+  #[pb(index = 1)]
+  pub option: SelectOptionPB,
+
+  #[pb(index = 2)]
+  pub count: i64,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedSelectOptionUsagePB {
+  #[pb(index = 1)]
+  pub items: Vec<SelectOptionUsagePB>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct MergeSelectOptionsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  /// The option that gets absorbed. All cells referencing it are rewritten
+  /// to reference the target option, then the option is deleted.
+  #[pb(index = 3)]
+  pub from_option_id: String,
+
+  #[pb(index = 4)]
+  pub into_option_id: String,
+}
+
+pub struct MergeSelectOptionsParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub from_option_id: String,
+  pub into_option_id: String,
+}
+
+impl TryInto<MergeSelectOptionsParams> for MergeSelectOptionsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MergeSelectOptionsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    let from_option_id =
+      NotEmptyStr::parse(self.from_option_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let into_option_id =
+      NotEmptyStr::parse(self.into_option_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(MergeSelectOptionsParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      from_option_id: from_option_id.0,
+      into_option_id: into_option_id.0,
+    })
+  }
+}
+
 #[derive(ProtoBuf_Enum, PartialEq, Eq, Debug, Clone, Default)]
 #[repr(u8)]
 pub enum SelectOptionColorPB {
@@ -25,4 +25,13 @@ impl From<TimeTypeOptionPB> for TimeTypeOption {
 pub struct TimeCellDataPB {
   #[pb(index = 2)]
   pub time: i64,
+
+  /// True while a tracking timer is running on the cell.
+  #[pb(index = 3)]
+  pub is_tracking: bool,
+
+  /// When the running timer was started, so the frontend can tick the elapsed
+  /// time locally.
+  #[pb(index = 4, one_of)]
+  pub tracking_started_at: Option<i64>,
 }
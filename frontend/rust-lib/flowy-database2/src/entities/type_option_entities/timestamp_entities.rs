@@ -25,6 +25,15 @@ pub struct TimestampTypeOptionPB {
 
   #[pb(index = 4)]
   pub field_type: FieldType,
+
+  /// A strftime format string overriding the fixed date/time formats.
+  /// Empty when unset.
+  #[pb(index = 5)]
+  pub custom_format: String,
+
+  /// Renders the timestamp relative to now, e.g. "3 hours ago".
+  #[pb(index = 6)]
+  pub show_relative: bool,
 }
 
 impl From<TimestampTypeOption> for TimestampTypeOptionPB {
@@ -34,6 +43,9 @@ impl From<TimestampTypeOption> for TimestampTypeOptionPB {
       time_format: data.time_format.into(),
       include_time: data.include_time,
       field_type: data.field_type.into(),
+      // The display extras live next to [TimestampTypeOption] in the type
+      // option data; they are filled in by `type_option_to_pb`.
+      ..Default::default()
     }
   }
 }
@@ -1,10 +1,41 @@
 use collab_database::fields::url_type_option::URLTypeOption;
 use flowy_derive::ProtoBuf;
 
+use crate::services::url_preview::UrlPreviewTable;
+
 #[derive(Clone, Debug, Default, ProtoBuf)]
 pub struct URLCellDataPB {
   #[pb(index = 1)]
   pub content: String,
+
+  /// The cached link preview of the URL. Unset until the preview has been
+  /// fetched.
+  #[pb(index = 2, one_of)]
+  pub preview: Option<URLPreviewPB>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct URLPreviewPB {
+  /// The title of the page, empty when the page doesn't declare one.
+  #[pb(index = 1)]
+  pub title: String,
+
+  #[pb(index = 2)]
+  pub favicon_url: String,
+
+  /// The og:image of the page, empty when the page doesn't declare one.
+  #[pb(index = 3)]
+  pub image_url: String,
+}
+
+impl From<UrlPreviewTable> for URLPreviewPB {
+  fn from(preview: UrlPreviewTable) -> Self {
+    Self {
+      title: preview.title,
+      favicon_url: preview.favicon_url,
+      image_url: preview.image_url,
+    }
+  }
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
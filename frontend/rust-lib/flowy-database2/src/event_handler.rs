@@ -1,3 +1,4 @@
+use collab_database::database::{gen_database_view_id, timestamp};
 use collab_database::fields::media_type_option::MediaCellData;
 use collab_database::rows::{Cell, RowCover, RowId};
 use lib_infra::box_any::BoxAny;
@@ -9,14 +10,17 @@ use flowy_error::{FlowyError, FlowyResult};
 use lib_dispatch::prelude::{AFPluginData, AFPluginState, DataResult, data_result_ok};
 
 use crate::entities::*;
-use crate::manager::DatabaseManager;
+use crate::manager::{DatabaseManager, RecordedAssignment};
 use crate::services::field::checklist_filter::ChecklistCellChangeset;
 use crate::services::field::date_filter::DateCellChangeset;
+use crate::services::field::recurrence::get_recurring_rule;
 use crate::services::field::{
   RelationCellChangeset, SelectOptionCellChangeset, TypeOptionCellExt, type_option_data_from_pb,
 };
 use crate::services::group::GroupChangeset;
+use crate::services::my_tasks::is_person_field;
 use crate::services::share::csv::CSVFormat;
+use crate::template::list_database_templates;
 
 fn upgrade_manager(
   database_manager: AFPluginState<Weak<DatabaseManager>>,
@@ -347,6 +351,22 @@ pub(crate) async fn switch_to_field_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_field_type_transform_impact_handler(
+  data: AFPluginData<UpdateFieldTypePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<FieldTypeTransformImpactPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: EditFieldParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let impact = database_editor
+    .get_field_type_transform_impact(&params.view_id, &params.field_id, params.field_type)
+    .await?;
+  data_result_ok(impact)
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn duplicate_field_handler(
   data: AFPluginData<DuplicateFieldPayloadPB>,
@@ -446,7 +466,7 @@ pub(crate) async fn get_row_meta_handler(
 pub(crate) async fn update_row_meta_handler(
   data: AFPluginData<UpdateRowMetaChangesetPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: UpdateRowMetaParams = data.into_inner().try_into()?;
   let database_editor = manager
@@ -569,6 +589,36 @@ pub(crate) async fn get_cell_handler(
   data_result_ok(cell)
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn start_time_tracking_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .start_time_tracking(&params.view_id, &params.row_id, &params.field_id)
+    .await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn stop_time_tracking_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .stop_time_tracking(&params.view_id, &params.row_id, &params.field_id)
+    .await
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn update_cell_handler(
   data: AFPluginData<CellChangesetPB>,
@@ -582,11 +632,56 @@ pub(crate) async fn update_cell_handler(
   database_editor
     .update_cell_with_changeset(
       &params.view_id,
-      &RowId::from(params.row_id),
+      &RowId::from(params.row_id.clone()),
       &params.field_id,
-      BoxAny::new(params.cell_changeset),
+      BoxAny::new(params.cell_changeset.clone()),
     )
     .await?;
+
+  // Assignments through a person cell feed the notification pipeline, which
+  // turns them into mention notifications for the assigned user.
+  let assignee = params.cell_changeset.trim().to_string();
+  if !assignee.is_empty() {
+    if let Some(field) = database_editor.get_field(&params.field_id).await {
+      if is_person_field(&field) {
+        manager.record_assignment(RecordedAssignment {
+          view_id: params.view_id,
+          row_id: params.row_id,
+          field_id: params.field_id,
+          assignee,
+          created_at: timestamp(),
+        });
+      }
+    }
+  }
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn undo_cell_edit_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(view_id.as_ref())
+    .await?;
+  database_editor.undo_cell_edit(view_id.as_ref()).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn redo_cell_edit_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(view_id.as_ref())
+    .await?;
+  database_editor.redo_cell_edit(view_id.as_ref()).await?;
   Ok(())
 }
 
@@ -679,6 +774,59 @@ pub(crate) async fn update_select_option_cell_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_select_option_usage_handler(
+  data: AFPluginData<SelectOptionFieldPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedSelectOptionUsagePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let usage = database_editor
+    .get_select_option_usage(&params.field_id)
+    .await?;
+  data_result_ok(usage)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn merge_select_options_handler(
+  data: AFPluginData<MergeSelectOptionsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: MergeSelectOptionsParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .merge_select_options(
+      &params.view_id,
+      &params.field_id,
+      &params.from_option_id,
+      &params.into_option_id,
+    )
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn delete_unused_select_options_handler(
+  data: AFPluginData<SelectOptionFieldPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedSelectOptionPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let items = database_editor
+    .delete_unused_select_options(&params.field_id)
+    .await?;
+  data_result_ok(RepeatedSelectOptionPB { items })
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn update_checklist_cell_handler(
   data: AFPluginData<ChecklistCellDataChangesetPB>,
@@ -718,6 +866,8 @@ pub(crate) async fn update_date_cell_handler(
     is_range: data.is_range,
     clear_flag: data.clear_flag,
     reminder_id: data.reminder_id,
+    recurrence: data.recurrence,
+    reminder_offset: data.reminder_offset,
   };
 
   let database_editor = manager
@@ -768,7 +918,7 @@ pub(crate) async fn get_group_handler(
 pub(crate) async fn set_group_by_field_handler(
   data: AFPluginData<GroupByFieldPayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: GroupByFieldParams = data.into_inner().try_into()?;
   let database_editor = manager
@@ -784,7 +934,7 @@ pub(crate) async fn set_group_by_field_handler(
 pub(crate) async fn update_group_handler(
   data: AFPluginData<UpdateGroupPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: UpdateGroupParams = data.into_inner().try_into()?;
   let view_id = params.view_id.clone();
@@ -800,7 +950,7 @@ pub(crate) async fn update_group_handler(
 pub(crate) async fn move_group_handler(
   data: AFPluginData<MoveGroupPayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: MoveGroupParams = data.into_inner().try_into()?;
   let database_editor = manager
@@ -816,7 +966,7 @@ pub(crate) async fn move_group_handler(
 pub(crate) async fn move_group_row_handler(
   data: AFPluginData<MoveGroupRowPayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: MoveGroupRowParams = data.into_inner().try_into()?;
   let database_editor = manager
@@ -838,7 +988,7 @@ pub(crate) async fn move_group_row_handler(
 pub(crate) async fn create_group_handler(
   data: AFPluginData<CreateGroupPayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: CreateGroupParams = data.into_inner().try_into()?;
   let database_editor = manager
@@ -854,7 +1004,7 @@ pub(crate) async fn create_group_handler(
 pub(crate) async fn delete_group_handler(
   data: AFPluginData<DeleteGroupPayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: DeleteGroupParams = data.into_inner().try_into()?;
   let database_editor = manager
@@ -913,7 +1063,7 @@ pub(crate) async fn get_databases_handler(
 pub(crate) async fn set_layout_setting_handler(
   data: AFPluginData<LayoutSettingChangesetPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let changeset = data.into_inner();
   let view_id = changeset.view_id.clone();
@@ -949,8 +1099,9 @@ pub(crate) async fn get_calendar_events_handler(
   let database_editor = manager
     .get_database_editor_with_view_id(&params.view_id)
     .await?;
+  let range = params.range_start.zip(params.range_end);
   let events = database_editor
-    .get_all_calendar_events(&params.view_id)
+    .get_all_calendar_events(&params.view_id, range)
     .await;
   data_result_ok(RepeatedCalendarEventPB { items: events })
 }
@@ -994,46 +1145,171 @@ pub(crate) async fn get_calendar_event_handler(
 pub(crate) async fn move_calendar_event_handler(
   data: AFPluginData<MoveCalendarEventPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let data = data.into_inner();
   let cell_id: CellIdParams = data.cell_path.try_into()?;
-  let cell_changeset = DateCellChangeset {
-    timestamp: Some(data.timestamp),
-    ..Default::default()
-  };
   let database_editor = manager
     .get_database_editor_with_view_id(&cell_id.view_id)
     .await?;
-  database_editor
-    .update_cell_with_changeset(
-      &cell_id.view_id,
-      &cell_id.row_id,
-      &cell_id.field_id,
-      BoxAny::new(cell_changeset),
-    )
+
+  let recurring_rule = database_editor
+    .get_cell(&cell_id.field_id, &cell_id.row_id)
+    .await
+    .as_ref()
+    .and_then(get_recurring_rule);
+
+  match (recurring_rule, data.edit_mode) {
+    (Some(mut rule), RecurringEditModePB::ThisOccurrence) => {
+      let occurrence = data.occurrence_timestamp.ok_or_else(|| {
+        FlowyError::invalid_data()
+          .with_context("occurrence_timestamp is required when moving a single occurrence")
+      })?;
+      // Exclude the occurrence from the original rule, then detach it into a
+      // standalone row at the new timestamp.
+      rule.exdates.push(occurrence);
+      database_editor
+        .update_cell_with_changeset(
+          &cell_id.view_id,
+          &cell_id.row_id,
+          &cell_id.field_id,
+          BoxAny::new(DateCellChangeset {
+            recurrence: Some(rule.to_json()),
+            ..Default::default()
+          }),
+        )
+        .await?;
+
+      let new_row_id = database_editor
+        .duplicate_row(&cell_id.view_id, &cell_id.row_id)
+        .await?;
+      database_editor
+        .update_cell_with_changeset(
+          &cell_id.view_id,
+          &new_row_id,
+          &cell_id.field_id,
+          BoxAny::new(DateCellChangeset {
+            timestamp: Some(data.timestamp),
+            recurrence: Some(String::new()),
+            ..Default::default()
+          }),
+        )
+        .await?;
+    },
+    // A plain event, or every occurrence of a recurring one: shift the
+    // (base) timestamp of the cell.
+    _ => {
+      database_editor
+        .update_cell_with_changeset(
+          &cell_id.view_id,
+          &cell_id.row_id,
+          &cell_id.field_id,
+          BoxAny::new(DateCellChangeset {
+            timestamp: Some(data.timestamp),
+            ..Default::default()
+          }),
+        )
+        .await?;
+    },
+  }
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_timeline_events_handler(
+  data: AFPluginData<TimelineEventRequestPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedTimelineEventPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: TimelineEventRequestParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
     .await?;
+  let events = database_editor
+    .get_all_timeline_events(&params.view_id)
+    .await;
+  data_result_ok(RepeatedTimelineEventPB { items: events })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn move_timeline_event_handler(
+  data: AFPluginData<MoveTimelineEventPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let data = data.into_inner();
+  let view_id = parser::NotEmptyStr::parse(data.view_id)
+    .map_err(|_| flowy_error::ErrorCode::ViewIdIsInvalid)?
+    .0;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let timeline_setting = database_editor
+    .get_layout_setting(&view_id, collab_database::views::DatabaseLayout::Grid)
+    .await
+    .and_then(|setting| setting.timeline)
+    .ok_or_else(|| {
+      FlowyError::record_not_found().with_context("Timeline layout setting not found")
+    })?;
+
+  let row_id = RowId::from(data.row_id);
+  for (field_id, timestamp) in [
+    (&timeline_setting.start_field_id, data.start_timestamp),
+    (&timeline_setting.end_field_id, data.end_timestamp),
+  ] {
+    if let Some(timestamp) = timestamp {
+      let cell_changeset = DateCellChangeset {
+        timestamp: Some(timestamp),
+        ..Default::default()
+      };
+      database_editor
+        .update_cell_with_changeset(&view_id, &row_id, field_id, BoxAny::new(cell_changeset))
+        .await?;
+    }
+  }
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn submit_form_handler(
+  data: AFPluginData<SubmitFormPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowMetaPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: SubmitFormParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  match database_editor.submit_form(params).await? {
+    Some(row) => data_result_ok(RowMetaPB::from(row)),
+    None => Err(FlowyError::internal().with_context("Error creating row from form submission")),
+  }
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn create_database_view(
   _data: AFPluginData<CreateDatabaseViewPayloadPB>,
   _manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   // let data: CreateDatabaseViewParams = data.into_inner().try_into()?;
   Ok(())
 }
 
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn export_csv_handler(
-  data: AFPluginData<DatabaseViewIdPB>,
+  data: AFPluginData<ExportCSVPayloadPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
 ) -> DataResult<DatabaseExportDataPB, FlowyError> {
   let manager = upgrade_manager(manager)?;
-  let view_id = data.into_inner().value;
-  let database = manager.get_database_editor_with_view_id(&view_id).await?;
-  let data = database.export_csv(CSVFormat::Original).await?;
+  let params: ExportCSVParams = data.into_inner().try_into()?;
+  let database = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let data = if params.apply_view_settings {
+    database
+      .export_view_csv(&params.view_id, CSVFormat::Original)
+      .await?
+  } else {
+    database.export_csv(CSVFormat::Original).await?
+  };
   data_result_ok(DatabaseExportDataPB {
     export_type: DatabaseExportDataType::CSV,
     data,
@@ -1054,6 +1330,41 @@ pub(crate) async fn export_raw_database_data_handler(
   })
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_markdown_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database = manager.get_database_editor_with_view_id(&view_id).await?;
+  let data = database.export_view_markdown(&view_id).await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::Markdown,
+    data,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_calendar_ics_handler(
+  data: AFPluginData<ExportCalendarICSPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<ExportCalendarICSResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: ExportCalendarICSParams = data.into_inner().try_into()?;
+  let database = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let content = database.export_calendar_ics(&params.view_id).await?;
+  if let Some(file_path) = &params.file_path {
+    tokio::fs::write(file_path, &content).await?;
+  }
+  data_result_ok(ExportCalendarICSResultPB {
+    content,
+    file_path: params.file_path,
+  })
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn get_snapshots_handler(
   data: AFPluginData<DatabaseViewIdPB>,
@@ -1113,7 +1424,7 @@ pub(crate) async fn get_all_field_settings_handler(
 pub(crate) async fn update_field_settings_handler(
   data: AFPluginData<FieldSettingsChangesetPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params = data.try_into_inner()?;
   let database_editor = manager
@@ -1175,7 +1486,7 @@ pub(crate) async fn remove_calculation_handler(
 pub(crate) async fn get_related_database_ids_handler(
   _data: AFPluginData<DatabaseViewIdPB>,
   _manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   Ok(())
 }
 
@@ -1183,7 +1494,7 @@ pub(crate) async fn get_related_database_ids_handler(
 pub(crate) async fn update_relation_cell_handler(
   data: AFPluginData<RelationCellChangesetPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: RelationCellChangesetPB = data.into_inner();
   let view_id = parser::NotEmptyStr::parse(params.view_id)
@@ -1259,6 +1570,28 @@ pub(crate) async fn get_related_database_rows_handler(
   data_result_ok(RepeatedRelatedRowDataPB { rows })
 }
 
+#[instrument(level = "debug", skip_all, err)]
+pub(crate) async fn search_related_rows_handler(
+  data: AFPluginData<SearchRelatedRowsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRelatedRowDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: SearchRelatedRowsParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let related_database_id = database_editor
+    .get_related_database_id(&params.field_id)
+    .await?;
+  let related_database_editor = manager
+    .get_or_init_database_editor(&related_database_id)
+    .await?;
+  let rows = related_database_editor
+    .search_related_rows(&params.query, params.limit)
+    .await?;
+  data_result_ok(RepeatedRelatedRowDataPB { rows })
+}
+
 pub(crate) async fn summarize_row_handler(
   data: AFPluginData<SummaryRowPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
@@ -1278,6 +1611,43 @@ pub(crate) async fn summarize_row_handler(
   Ok(())
 }
 
+pub(crate) async fn generate_summary_cell_handler(
+  data: AFPluginData<SummaryRowPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let data = data.into_inner();
+  let row_id = RowId::from(data.row_id);
+  let (tx, rx) = oneshot::channel();
+  tokio::spawn(async move {
+    let result = manager
+      .generate_summary_cell(&data.view_id, row_id, data.field_id)
+      .await;
+    let _ = tx.send(result);
+  });
+
+  rx.await??;
+  Ok(())
+}
+
+pub(crate) async fn generate_summary_column_handler(
+  data: AFPluginData<GenerateSummaryColumnPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let data = data.try_into_inner()?;
+  let (tx, rx) = oneshot::channel();
+  tokio::spawn(async move {
+    let result = manager
+      .generate_summary_column(&data.view_id, data.field_id)
+      .await;
+    let _ = tx.send(result);
+  });
+
+  rx.await??;
+  Ok(())
+}
+
 pub(crate) async fn translate_row_handler(
   data: AFPluginData<TranslateRowPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
@@ -1297,11 +1667,40 @@ pub(crate) async fn translate_row_handler(
   Ok(())
 }
 
+pub(crate) async fn get_my_tasks_handler(
+  data: AFPluginData<MyTasksPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedMyTaskPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let tasks = manager.get_my_tasks(data.into_inner().into()).await?;
+  data_result_ok(RepeatedMyTaskPB {
+    items: tasks.into_iter().map(Into::into).collect(),
+  })
+}
+
+pub(crate) async fn translate_column_handler(
+  data: AFPluginData<TranslateColumnPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let data = data.try_into_inner()?;
+  let (tx, rx) = oneshot::channel();
+  tokio::spawn(async move {
+    let result = manager
+      .translate_column(&data.view_id, data.field_id)
+      .await;
+    let _ = tx.send(result);
+  });
+
+  rx.await??;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn update_media_cell_handler(
   data: AFPluginData<MediaCellChangesetPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: MediaCellChangesetPB = data.into_inner();
   let cell_id: CellIdParams = params.cell_id.try_into()?;
@@ -1341,7 +1740,7 @@ pub(crate) async fn update_media_cell_handler(
 pub(crate) async fn rename_media_cell_file_handler(
   data: AFPluginData<RenameMediaChangesetPB>,
   manager: AFPluginState<Weak<DatabaseManager>>,
-) -> FlowyResult<()> {
+) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: RenameMediaChangesetPB = data.into_inner();
   let cell_id: CellIdParams = params.cell_id.try_into()?;
@@ -1448,3 +1847,405 @@ pub(crate) async fn test_custom_prompt_database_configuration_handler(
 
   data_result_ok(configuration)
 }
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn add_row_comment_handler(
+  data: AFPluginData<AddRowCommentPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowCommentPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: AddRowCommentParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let comment = database_editor
+    .add_row_comment(&params.row_id, params.content, params.reply_comment_id)
+    .await?;
+  data_result_ok(comment)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_row_comment_handler(
+  data: AFPluginData<UpdateRowCommentPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: UpdateRowCommentParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .update_row_comment(&params.comment_id, params.content)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn delete_row_comment_handler(
+  data: AFPluginData<DeleteRowCommentPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: DeleteRowCommentParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor.delete_row_comment(&params.comment_id).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn toggle_row_comment_reaction_handler(
+  data: AFPluginData<ToggleRowCommentReactionPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: ToggleRowCommentReactionParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .toggle_row_comment_reaction(&params.comment_id, &params.emoji)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_row_comments_handler(
+  data: AFPluginData<GetRowCommentsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowCommentPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: GetRowCommentsParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let comments = database_editor
+    .get_row_comments(&params.row_id, params.limit, params.before)
+    .await?;
+  data_result_ok(comments)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_row_history_handler(
+  data: AFPluginData<GetRowHistoryPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowHistoryPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: GetRowHistoryParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let history = database_editor
+    .get_row_history(&params.row_id, params.limit)
+    .await?;
+  data_result_ok(history)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn revert_row_handler(
+  data: AFPluginData<RevertRowPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RevertRowParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .revert_row_to(&params.view_id, &RowId::from(params.row_id), params.version)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn save_row_as_template_handler(
+  data: AFPluginData<SaveRowAsTemplatePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowTemplatePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: SaveRowAsTemplateParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let template = database_editor
+    .save_row_as_template(&params.view_id, &RowId::from(params.row_id), params.name)
+    .await?;
+  data_result_ok(template)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn create_row_from_template_handler(
+  data: AFPluginData<RowTemplateIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowMetaPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowTemplateIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let row_meta = database_editor
+    .create_row_from_template(&params.view_id, &params.template_id)
+    .await?;
+  data_result_ok(row_meta)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_row_templates_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowTemplatePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager
+    .get_database_editor_with_view_id(view_id.as_ref())
+    .await?;
+  let templates = database_editor.get_row_templates().await?;
+  data_result_ok(templates)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn delete_row_template_handler(
+  data: AFPluginData<RowTemplateIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowTemplateIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .delete_row_template(&params.template_id)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn set_default_row_template_handler(
+  data: AFPluginData<RowTemplateIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowTemplateIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .set_default_row_template(&params.template_id)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn list_database_templates_handler() -> DataResult<RepeatedDatabaseTemplatePB, FlowyError>
+{
+  let items = list_database_templates()
+    .into_iter()
+    .map(DatabaseTemplatePB::from)
+    .collect();
+  data_result_ok(RepeatedDatabaseTemplatePB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn create_database_from_template_handler(
+  data: AFPluginData<CreateDatabaseFromTemplatePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseViewIdPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CreateDatabaseFromTemplateParams = data.into_inner().try_into()?;
+  let name = list_database_templates()
+    .into_iter()
+    .find(|template| template.id == params.template_id)
+    .map(|template| template.name.to_string())
+    .unwrap_or_default();
+  let view_id = gen_database_view_id();
+  manager
+    .create_database_from_template(&params.template_id, &view_id, &name)
+    .await?;
+  data_result_ok(DatabaseViewIdPB { value: view_id })
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn duplicate_rows_handler(
+  data: AFPluginData<DuplicateRowsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRowIdPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: DuplicateRowsParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let new_row_ids = database_editor
+    .duplicate_rows(&params.view_id, &params.row_ids, params.remap_relations)
+    .await?;
+  data_result_ok(RepeatedRowIdPB {
+    view_id: params.view_id,
+    row_ids: new_row_ids.into_iter().map(|id| id.into_inner()).collect(),
+  })
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn import_csv_rows_handler(
+  data: AFPluginData<ImportCSVRowsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<ImportCSVRowsResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: ImportCSVRowsParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let column_mappings = params.column_mappings.into_iter().collect();
+  let (imported_rows, row_errors) = database_editor
+    .import_csv_rows(&params.view_id, params.content, column_mappings)
+    .await?;
+  data_result_ok(ImportCSVRowsResultPB {
+    imported_rows: imported_rows as i64,
+    row_errors: row_errors.into_iter().map(Into::into).collect(),
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_xlsx_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportBytesPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database = manager.get_database_editor_with_view_id(&view_id).await?;
+  let data = database.export_xlsx(&view_id).await?;
+  data_result_ok(DatabaseExportBytesPB { data })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_database_json_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database = manager.get_database_editor_with_view_id(&view_id).await?;
+  let data = database.export_database_json().await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::JSON,
+    data,
+  })
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn query_rows_handler(
+  data: AFPluginData<QueryRowsPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<QueryRowsResultPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: QueryRowsParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let result = database_editor
+    .query_rows(
+      &params.view_id,
+      &params.predicates,
+      params.limit,
+      params.offset,
+    )
+    .await?;
+  data_result_ok(result)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_rows_page_handler(
+  data: AFPluginData<GetRowsPagePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RowsPagePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: GetRowsPageParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let page = database_editor
+    .get_rows_page(&params.view_id, params.offset, params.limit)
+    .await?;
+  data_result_ok(page)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn add_automation_handler(
+  data: AFPluginData<AddAutomationPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<AutomationPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: AddAutomationParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  let automation = database_editor.add_automation(params).await?;
+  data_result_ok(automation)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn delete_automation_handler(
+  data: AFPluginData<AutomationIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: AutomationIdParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor.delete_automation(&params.trigger_id).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_automations_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedAutomationPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  let automations = database_editor.get_automations().await?;
+  data_result_ok(automations)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn set_automation_enabled_handler(
+  data: AFPluginData<SetAutomationEnabledPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: SetAutomationEnabledParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .set_automation_enabled(&params.trigger_id, params.enabled)
+    .await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn set_view_personal_handler(
+  data: AFPluginData<SetViewPersonalPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: SetViewPersonalParams = data.into_inner().try_into()?;
+  let database_editor = manager
+    .get_database_editor_with_view_id(&params.view_id)
+    .await?;
+  database_editor
+    .set_view_personal(&params.view_id, params.is_personal)
+    .await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_view_personal_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<PersonalViewStatusPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let database_editor = manager.get_database_editor_with_view_id(&view_id).await?;
+  data_result_ok(PersonalViewStatusPB {
+    is_personal: database_editor.is_view_personal(&view_id),
+  })
+}
@@ -29,6 +29,7 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
          .event(DatabaseEvent::DeleteField, delete_field_handler)
          .event(DatabaseEvent::ClearField, clear_field_handler)
          .event(DatabaseEvent::UpdateFieldType, switch_to_field_handler)
+         .event(DatabaseEvent::GetFieldTypeTransformImpact, get_field_type_transform_impact_handler)
          .event(DatabaseEvent::DuplicateField, duplicate_field_handler)
          .event(DatabaseEvent::MoveField, move_field_handler)
          .event(DatabaseEvent::CreateField, create_field_handler)
@@ -40,16 +41,35 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
          .event(DatabaseEvent::UpdateRowMeta, update_row_meta_handler)
          .event(DatabaseEvent::DeleteRows, delete_rows_handler)
          .event(DatabaseEvent::DuplicateRow, duplicate_row_handler)
+         .event(DatabaseEvent::DuplicateRows, duplicate_rows_handler)
+         .event(DatabaseEvent::ImportCSVRows, import_csv_rows_handler)
+         .event(DatabaseEvent::ExportXLSX, export_xlsx_handler)
+         .event(DatabaseEvent::ExportDatabaseJSON, export_database_json_handler)
+         .event(DatabaseEvent::QueryRows, query_rows_handler)
+         .event(DatabaseEvent::GetRowsPage, get_rows_page_handler)
          .event(DatabaseEvent::MoveRow, move_row_handler)
+         // Automation
+         .event(DatabaseEvent::AddAutomation, add_automation_handler)
+         .event(DatabaseEvent::DeleteAutomation, delete_automation_handler)
+         .event(DatabaseEvent::GetAutomations, get_automations_handler)
+         .event(DatabaseEvent::SetAutomationEnabled, set_automation_enabled_handler)
+         // Personal view
+         .event(DatabaseEvent::SetViewPersonal, set_view_personal_handler)
+         .event(DatabaseEvent::GetViewPersonal, get_view_personal_handler)
          .event(DatabaseEvent::RemoveCover, remove_cover_handler)
          // Cell
          .event(DatabaseEvent::GetCell, get_cell_handler)
          .event(DatabaseEvent::UpdateCell, update_cell_handler)
+         .event(DatabaseEvent::UndoCellEdit, undo_cell_edit_handler)
+         .event(DatabaseEvent::RedoCellEdit, redo_cell_edit_handler)
          // SelectOption
          .event(DatabaseEvent::CreateSelectOption, new_select_option_handler)
          .event(DatabaseEvent::InsertOrUpdateSelectOption, insert_or_update_select_option_handler)
          .event(DatabaseEvent::DeleteSelectOption, delete_select_option_handler)
          .event(DatabaseEvent::UpdateSelectOptionCell, update_select_option_cell_handler)
+         .event(DatabaseEvent::GetSelectOptionUsage, get_select_option_usage_handler)
+         .event(DatabaseEvent::MergeSelectOptions, merge_select_options_handler)
+         .event(DatabaseEvent::DeleteUnusedSelectOptions, delete_unused_select_options_handler)
          // Checklist
          .event(DatabaseEvent::UpdateChecklistCell, update_checklist_cell_handler)
          // Date
@@ -71,6 +91,11 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
          .event(DatabaseEvent::GetNoDateCalendarEvents, get_no_date_calendar_events_handler)
          .event(DatabaseEvent::GetCalendarEvent, get_calendar_event_handler)
          .event(DatabaseEvent::MoveCalendarEvent, move_calendar_event_handler)
+        // Timeline
+        .event(DatabaseEvent::GetAllTimelineEvents, get_timeline_events_handler)
+        .event(DatabaseEvent::MoveTimelineEvent, move_timeline_event_handler)
+        // Form
+        .event(DatabaseEvent::SubmitForm, submit_form_handler)
          // Layout setting
          .event(DatabaseEvent::SetLayoutSetting, set_layout_setting_handler)
          .event(DatabaseEvent::GetLayoutSetting, get_layout_setting_handler)
@@ -78,6 +103,8 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
          // Export
          .event(DatabaseEvent::ExportCSV, export_csv_handler)
          .event(DatabaseEvent::ExportRawDatabaseData, export_raw_database_data_handler)
+         .event(DatabaseEvent::ExportMarkdown, export_markdown_handler)
+         .event(DatabaseEvent::ExportCalendarICS, export_calendar_ics_handler)
          .event(DatabaseEvent::GetDatabaseSnapshots, get_snapshots_handler)
          // Field settings
          .event(DatabaseEvent::GetFieldSettings, get_field_settings_handler)
@@ -92,12 +119,39 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
          .event(DatabaseEvent::UpdateRelationCell, update_relation_cell_handler)
          .event(DatabaseEvent::GetRelatedRowDatas, get_related_row_datas_handler)
          .event(DatabaseEvent::GetRelatedDatabaseRows, get_related_database_rows_handler)
+         .event(DatabaseEvent::SearchRelatedRows, search_related_rows_handler)
          // AI
          .event(DatabaseEvent::SummarizeRow, summarize_row_handler)
+         .event(DatabaseEvent::GenerateSummaryCell, generate_summary_cell_handler)
+         .event(DatabaseEvent::GenerateSummaryColumn, generate_summary_column_handler)
          .event(DatabaseEvent::TranslateRow, translate_row_handler)
+         .event(DatabaseEvent::TranslateColumn, translate_column_handler)
          // Media
          .event(DatabaseEvent::UpdateMediaCell, update_media_cell_handler)
          .event(DatabaseEvent::RenameMediaFile, rename_media_cell_file_handler)
+         // Row comment
+         .event(DatabaseEvent::AddRowComment, add_row_comment_handler)
+         .event(DatabaseEvent::UpdateRowComment, update_row_comment_handler)
+         .event(DatabaseEvent::DeleteRowComment, delete_row_comment_handler)
+         .event(DatabaseEvent::ToggleRowCommentReaction, toggle_row_comment_reaction_handler)
+         .event(DatabaseEvent::GetRowComments, get_row_comments_handler)
+         // Row history
+         .event(DatabaseEvent::GetRowHistory, get_row_history_handler)
+         .event(DatabaseEvent::RevertRow, revert_row_handler)
+         // Row template
+         .event(DatabaseEvent::SaveRowAsTemplate, save_row_as_template_handler)
+         .event(DatabaseEvent::CreateRowFromTemplate, create_row_from_template_handler)
+         .event(DatabaseEvent::GetRowTemplates, get_row_templates_handler)
+         .event(DatabaseEvent::DeleteRowTemplate, delete_row_template_handler)
+         .event(DatabaseEvent::SetDefaultRowTemplate, set_default_row_template_handler)
+         // Database template
+         .event(DatabaseEvent::ListDatabaseTemplates, list_database_templates_handler)
+         .event(DatabaseEvent::CreateDatabaseFromTemplate, create_database_from_template_handler)
+         // My tasks
+         .event(DatabaseEvent::GetMyTasks, get_my_tasks_handler)
+         // Time tracking
+         .event(DatabaseEvent::StartTimeTracking, start_time_tracking_handler)
+         .event(DatabaseEvent::StopTimeTracking, stop_time_tracking_handler)
          .event(DatabaseEvent::GetDatabaseCustomPrompts, get_database_custom_prompts_handler)
          .event(DatabaseEvent::TestCustomPromptDatabaseConfiguration, test_custom_prompt_database_configuration_handler)
 }
@@ -335,10 +389,21 @@ pub enum DatabaseEvent {
   #[event(input = "MoveCalendarEventPB")]
   MoveCalendarEvent = 126,
 
+  #[event(input = "TimelineEventRequestPB", output = "RepeatedTimelineEventPB")]
+  GetAllTimelineEvents = 127,
+
+  #[event(input = "MoveTimelineEventPB")]
+  MoveTimelineEvent = 128,
+
+  /// Creates a row from a form submission after validating it against the
+  /// view's form settings.
+  #[event(input = "SubmitFormPayloadPB", output = "RowMetaPB")]
+  SubmitForm = 129,
+
   #[event(input = "CreateDatabaseViewPayloadPB")]
   CreateDatabaseView = 130,
 
-  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  #[event(input = "ExportCSVPayloadPB", output = "DatabaseExportDataPB")]
   ExportCSV = 141,
 
   /// Returns all the snapshots of the database view.
@@ -404,6 +469,201 @@ pub enum DatabaseEvent {
   #[event(input = "RenameMediaChangesetPB")]
   RenameMediaFile = 201,
 
+  /// Adds a comment to a row. When `reply_comment_id` is set, the comment is
+  /// added as a reply in that comment's thread.
+  #[event(input = "AddRowCommentPayloadPB", output = "RowCommentPB")]
+  AddRowComment = 210,
+
+  /// Edits the content of a comment. Only the author can edit a comment.
+  #[event(input = "UpdateRowCommentPayloadPB")]
+  UpdateRowComment = 211,
+
+  /// Deletes a comment together with its replies.
+  #[event(input = "DeleteRowCommentPayloadPB")]
+  DeleteRowComment = 212,
+
+  /// Toggles the current user's emoji reaction on a comment.
+  #[event(input = "ToggleRowCommentReactionPayloadPB")]
+  ToggleRowCommentReaction = 213,
+
+  /// Returns a page of comments of a row, newest first.
+  #[event(input = "GetRowCommentsPayloadPB", output = "RepeatedRowCommentPB")]
+  GetRowComments = 214,
+
+  /// Returns the most recent cell-level changes of a row, newest first.
+  #[event(input = "GetRowHistoryPayloadPB", output = "RepeatedRowHistoryPB")]
+  GetRowHistory = 215,
+
+  /// Reverts the cells of a row to the values they had at the given history
+  /// version.
+  #[event(input = "RevertRowPayloadPB")]
+  RevertRow = 216,
+
+  /// Saves the cells and the meta of a row as a reusable template for its
+  /// database.
+  #[event(input = "SaveRowAsTemplatePayloadPB", output = "RowTemplatePB")]
+  SaveRowAsTemplate = 217,
+
+  /// Creates a row prefilled with the cells and the meta captured in a
+  /// template.
+  #[event(input = "RowTemplateIdPB", output = "RowMetaPB")]
+  CreateRowFromTemplate = 218,
+
+  /// Returns all the row templates of the database.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedRowTemplatePB")]
+  GetRowTemplates = 219,
+
+  #[event(input = "RowTemplateIdPB")]
+  DeleteRowTemplate = 220,
+
+  /// Marks a template as the default one. Its cells are used to prefill newly
+  /// created empty rows.
+  #[event(input = "RowTemplateIdPB")]
+  SetDefaultRowTemplate = 221,
+
+  /// Duplicates a set of rows, optionally rewriting relation cells that point
+  /// at other rows of the selection to point at the new copies.
+  #[event(input = "DuplicateRowsPayloadPB", output = "RepeatedRowIdPB")]
+  DuplicateRows = 222,
+
+  /// Appends the rows of a CSV file to an existing database, mapping columns
+  /// to fields and reporting per-row parse errors.
+  #[event(input = "ImportCSVRowsPayloadPB", output = "ImportCSVRowsResultPB")]
+  ImportCSVRows = 223,
+
+  /// Exports the database view to an .xlsx workbook with typed number, date
+  /// and checkbox cells.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportBytesPB")]
+  ExportXLSX = 224,
+
+  /// Exports the database as a JSON schema plus rows that can be re-imported
+  /// to recreate an identical database.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  ExportDatabaseJSON = 225,
+
+  /// Returns the rows of the view matching simple cell predicates
+  /// (equals/contains/range), paginated, without creating a filter on the
+  /// view.
+  #[event(input = "QueryRowsPayloadPB", output = "QueryRowsResultPB")]
+  QueryRows = 226,
+
+  /// Registers an automation on the database: a trigger (row created, cell
+  /// changed, row moved to group) paired with an action (set cell, create
+  /// row, call webhook).
+  #[event(input = "AddAutomationPayloadPB", output = "AutomationPB")]
+  AddAutomation = 227,
+
+  #[event(input = "AutomationIdPB")]
+  DeleteAutomation = 228,
+
+  /// Returns all the automations registered on the database.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedAutomationPB")]
+  GetAutomations = 229,
+
+  /// Enables or disables an automation without deleting it.
+  #[event(input = "SetAutomationEnabledPayloadPB")]
+  SetAutomationEnabled = 230,
+
+  /// Marks a view as personal for the current user so its filter, sort and
+  /// group changes are stored per-user instead of in the shared collab.
+  #[event(input = "SetViewPersonalPayloadPB")]
+  SetViewPersonal = 231,
+
+  /// Returns whether the view is personal for the current user.
+  #[event(input = "DatabaseViewIdPB", output = "PersonalViewStatusPB")]
+  GetViewPersonal = 232,
+
+  /// Returns one page of the visible rows of the view for grid
+  /// virtualization. The requested window is subscribed: later row changes
+  /// trigger a DidUpdateRowWindow notification.
+  #[event(input = "GetRowsPagePayloadPB", output = "RowsPagePB")]
+  GetRowsPage = 233,
+
+  /// Returns the options of the select field together with the number of
+  /// rows that currently use each of them.
+  #[event(input = "SelectOptionFieldPayloadPB", output = "RepeatedSelectOptionUsagePB")]
+  GetSelectOptionUsage = 234,
+
+  /// Merges one select option into another, rewriting every cell that
+  /// references the absorbed option.
+  #[event(input = "MergeSelectOptionsPayloadPB")]
+  MergeSelectOptions = 235,
+
+  /// Deletes every option of the select field that no row references.
+  #[event(input = "SelectOptionFieldPayloadPB", output = "RepeatedSelectOptionPB")]
+  DeleteUnusedSelectOptions = 236,
+
+  /// Searches the primary field of the database targeted by a relation
+  /// field, with fuzzy matching and recency ranking, so the relation picker
+  /// does not need to load every related row.
+  #[event(input = "SearchRelatedRowsPayloadPB", output = "RepeatedRelatedRowDataPB")]
+  SearchRelatedRows = 237,
+
+  /// Dry run of [DatabaseEvent::UpdateFieldType]: reports how many cells
+  /// the switch would rewrite and whether it can drop information.
+  #[event(input = "UpdateFieldTypePayloadPB", output = "FieldTypeTransformImpactPB")]
+  GetFieldTypeTransformImpact = 238,
+
+  /// Reverts the most recent cell edit made on this device. Edits that
+  /// another collaborator has changed in the meantime are never reverted.
+  #[event(input = "DatabaseViewIdPB")]
+  UndoCellEdit = 239,
+
+  /// Re-applies the most recently undone cell edit.
+  #[event(input = "DatabaseViewIdPB")]
+  RedoCellEdit = 240,
+
+  /// Returns the built-in database templates that ship with the app.
+  #[event(output = "RepeatedDatabaseTemplatePB")]
+  ListDatabaseTemplates = 241,
+
+  /// Instantiates a built-in template as a new database and returns the view
+  /// id of its primary view. The caller attaches a folder view with that id
+  /// under the payload's parent view.
+  #[event(input = "CreateDatabaseFromTemplatePayloadPB", output = "DatabaseViewIdPB")]
+  CreateDatabaseFromTemplate = 242,
+
+  /// Exports the view as a GitHub-flavored markdown table. The active
+  /// filters and sorts are applied and only visible fields are included.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  ExportMarkdown = 243,
+
+  /// Exports a calendar view as an iCalendar (.ics) document, optionally
+  /// writing it to a stable path external calendar apps can subscribe to.
+  #[event(input = "ExportCalendarICSPayloadPB", output = "ExportCalendarICSResultPB")]
+  ExportCalendarICS = 244,
+
+  /// Same as [Self::SummarizeRow], but streams the model output into the cell
+  /// as it is generated.
+  #[event(input = "SummaryRowPB")]
+  GenerateSummaryCell = 245,
+
+  /// Fills the summary cells of every row in the view that doesn't have one
+  /// yet, a few rows at a time.
+  #[event(input = "GenerateSummaryColumnPB")]
+  GenerateSummaryColumn = 246,
+
+  /// Translates every row in the view that has source content but no
+  /// translation yet, one row at a time. Progress is reported with
+  /// [DatabaseNotification::DidUpdateTranslateColumnProgress].
+  #[event(input = "TranslateColumnPB")]
+  TranslateColumn = 247,
+
+  /// Scans every database in the workspace for rows that look like the
+  /// current user's tasks, so the home screen can show a unified task list.
+  #[event(input = "MyTasksPayloadPB", output = "RepeatedMyTaskPB")]
+  GetMyTasks = 248,
+
+  /// Starts a time-tracking timer on a time cell. Fails when a timer is
+  /// already running on it.
+  #[event(input = "CellIdPB")]
+  StartTimeTracking = 249,
+
+  /// Stops the running timer on a time cell, appending the finished session
+  /// and adding its duration to the cell's total time.
+  #[event(input = "CellIdPB")]
+  StopTimeTracking = 250,
+
   #[event(
     input = "CustomPromptDatabaseConfigPB",
     output = "RepeatedCustomPromptPB"
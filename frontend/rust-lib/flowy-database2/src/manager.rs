@@ -5,12 +5,11 @@ use collab::core::collab::DataSource;
 use collab::core::origin::CollabOrigin;
 use collab::lock::RwLock;
 use collab::preclude::Collab;
-use collab_database::database::{Database, DatabaseData};
+use collab_database::database::{Database, DatabaseData, timestamp};
 use collab_database::entity::{CreateDatabaseParams, CreateViewParams, EncodedDatabase};
 use collab_database::error::DatabaseError;
 use collab_database::fields::translate_type_option::TranslateTypeOption;
 use collab_database::rows::RowId;
-use collab_database::template::csv::CSVTemplate;
 use collab_database::views::DatabaseLayout;
 use collab_database::workspace_database::{
   CollabPersistenceImpl, DatabaseCollabPersistenceService, DatabaseCollabService, DatabaseMeta,
@@ -24,7 +23,8 @@ use std::str::FromStr;
 use std::sync::{Arc, Weak};
 use std::time::Duration;
 use tokio::sync::Mutex;
-use tracing::{error, info, instrument, trace};
+use tokio::sync::mpsc::UnboundedSender;
+use tracing::{error, info, instrument, trace, warn};
 
 use collab_integrate::collab_builder::{AppFlowyCollabBuilder, CollabBuilderConfig};
 use collab_integrate::{CollabKVAction, CollabKVDB};
@@ -32,16 +32,27 @@ use flowy_database_pub::cloud::{
   DatabaseAIService, DatabaseCloudService, SummaryRowContent, TranslateItem, TranslateRowContent,
 };
 use flowy_error::{FlowyError, FlowyResult, internal_error};
+use flowy_sqlite::DBConnection;
+use futures::StreamExt;
 
 use lib_infra::box_any::BoxAny;
 use lib_infra::priority_task::TaskDispatcher;
 
-use crate::entities::{DatabaseLayoutPB, DatabaseSnapshotPB, FieldType, RowMetaPB};
+use crate::entities::{
+  DatabaseLayoutPB, DatabaseSnapshotPB, FieldType, RowMetaPB, TranslateColumnProgressPB,
+};
+use crate::notification::{DatabaseNotification, database_notification_builder};
+use crate::services::automation::{AutomationEvent, AutomationRunner};
 use crate::services::cell::stringify_cell;
 use crate::services::database::DatabaseEditor;
 use crate::services::database_view::DatabaseLayoutDepsResolver;
+use crate::services::field::RelationCellResolver;
 use crate::services::field_settings::default_field_settings_by_layout_map;
-use crate::services::share::csv::{CSVFormat, CSVImporter, ImportResult};
+use crate::services::my_tasks::{MyTask, MyTasksFilter, collect_tasks};
+use crate::services::share::csv::{CSVFormat, CSVImportOptions, CSVImporter, ImportResult};
+use crate::services::share::json::DatabaseJsonImporter;
+use crate::services::share::xlsx::XLSXImporter;
+use crate::template::make_database_from_template;
 use tokio::sync::RwLock as TokioRwLock;
 use uuid::Uuid;
 
@@ -50,6 +61,19 @@ pub trait DatabaseUser: Send + Sync {
   fn collab_db(&self, uid: i64) -> Result<Weak<CollabKVDB>, FlowyError>;
   fn workspace_id(&self) -> Result<Uuid, FlowyError>;
   fn workspace_database_object_id(&self) -> Result<Uuid, FlowyError>;
+  fn sqlite_connection(&self, uid: i64) -> Result<DBConnection, FlowyError>;
+}
+
+/// An assignment made through a person cell, kept so a notification for the
+/// assigned user can be generated later.
+#[derive(Debug, Clone)]
+pub struct RecordedAssignment {
+  pub view_id: String,
+  pub row_id: String,
+  pub field_id: String,
+  /// The text typed into the person cell: a member name or email.
+  pub assignee: String,
+  pub created_at: i64,
 }
 
 pub(crate) type DatabaseEditorMap = HashMap<String, Arc<DatabaseEditor>>;
@@ -62,6 +86,11 @@ pub struct DatabaseManager {
   collab_builder: Weak<AppFlowyCollabBuilder>,
   cloud_service: Arc<dyn DatabaseCloudService>,
   ai_service: Arc<dyn DatabaseAIService>,
+  automation_tx: ArcSwapOption<UnboundedSender<AutomationEvent>>,
+  relation_resolver: ArcSwapOption<RelationCellResolver>,
+  /// Assignments made through person cells, drained by the notification
+  /// pipeline.
+  recorded_assignments: std::sync::Mutex<Vec<RecordedAssignment>>,
 }
 
 impl Drop for DatabaseManager {
@@ -87,9 +116,46 @@ impl DatabaseManager {
       collab_builder,
       cloud_service,
       ai_service,
+      automation_tx: Default::default(),
+      relation_resolver: Default::default(),
+      recorded_assignments: std::sync::Mutex::new(vec![]),
+    }
+  }
+
+  /// Records an assignment made through a person cell so the notification
+  /// pipeline can generate a notification for the assigned user.
+  pub fn record_assignment(&self, assignment: RecordedAssignment) {
+    if let Ok(mut assignments) = self.recorded_assignments.lock() {
+      assignments.push(assignment);
     }
   }
 
+  /// Drains the assignments recorded since the last call.
+  pub fn take_recorded_assignments(&self) -> Vec<RecordedAssignment> {
+    self
+      .recorded_assignments
+      .lock()
+      .map(|mut assignments| std::mem::take(&mut *assignments))
+      .unwrap_or_default()
+  }
+
+  /// Spawns the automation runner and installs its sender into every editor
+  /// opened from now on. Called once the manager is wrapped in an `Arc`.
+  pub fn init_automation_runner(&self, manager: Weak<DatabaseManager>) {
+    let tx = AutomationRunner::spawn(self.user.clone(), manager);
+    self.automation_tx.store(Some(Arc::new(tx)));
+  }
+
+  /// Creates the resolver that reads cells of related databases, e.g. when a
+  /// filter targets a field of a related row, and installs it into every
+  /// editor opened from now on. Called once the manager is wrapped in an
+  /// `Arc`.
+  pub fn init_relation_resolver(&self, manager: Weak<DatabaseManager>) {
+    self
+      .relation_resolver
+      .store(Some(Arc::new(RelationCellResolver::new(manager))));
+  }
+
   fn collab_builder(&self) -> FlowyResult<Arc<AppFlowyCollabBuilder>> {
     self.collab_builder.upgrade().ok_or(FlowyError::ref_drop())
   }
@@ -299,12 +365,26 @@ impl DatabaseManager {
       collab_builder,
     )
     .await?;
+    if let Some(tx) = self.automation_tx.load_full() {
+      editor.set_automation_tx((*tx).clone());
+    }
+    if let Some(resolver) = self.relation_resolver.load_full() {
+      editor.set_relation_resolver(resolver);
+    }
 
     self
       .editors
       .lock()
       .await
       .insert(database_id.to_string(), editor.clone());
+
+    // Backfill the metadata of media attachments that predate the extraction,
+    // so existing gallery and card covers render instantly.
+    let backfill_editor = editor.clone();
+    tokio::spawn(async move {
+      backfill_editor.backfill_media_file_meta().await;
+    });
+
     Ok(editor)
   }
 
@@ -475,6 +555,29 @@ impl DatabaseManager {
     Ok(database)
   }
 
+  /// Instantiates one of the built-in database templates. The template's
+  /// schema and sample rows are embedded in the binary, so this also works
+  /// offline.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn create_database_from_template(
+    &self,
+    template_id: &str,
+    view_id: &str,
+    name: &str,
+  ) -> FlowyResult<EncodedCollab> {
+    let params = make_database_from_template(template_id, view_id, name).ok_or_else(|| {
+      FlowyError::record_not_found()
+        .with_context(format!("database template:{} is not found", template_id))
+    })?;
+    let database = self.import_database(params).await?;
+    let encoded_collab = database
+      .read()
+      .await
+      .encode_collab_v1(|collab| CollabType::Database.validate_require_data(collab))
+      .map_err(|err| FlowyError::internal().with_context(err))?;
+    Ok(encoded_collab)
+  }
+
   /// A linked view is a view that is linked to existing database.
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn create_linked_view(
@@ -515,11 +618,26 @@ impl DatabaseManager {
   ) -> FlowyResult<ImportResult> {
     let params = match format {
       CSVFormat::Original => {
-        let mut csv_template = CSVTemplate::try_from_reader(content.as_bytes(), true, None)?;
-        csv_template.reset_view_id(view_id.clone());
-
-        let database_template = csv_template.try_into_database_template(None).await?;
-        database_template.into_params()
+        // Infer a field type per column instead of importing everything as
+        // text. Rows that fail to parse are imported with the offending cells
+        // left empty.
+        let cloned_view_id = view_id.clone();
+        let (params, row_errors) = tokio::task::spawn_blocking(move || {
+          CSVImporter.import_csv_from_string_with_options(
+            &cloned_view_id,
+            content,
+            CSVImportOptions {
+              infer_field_types: true,
+              ..Default::default()
+            },
+          )
+        })
+        .await
+        .map_err(internal_error)??;
+        if !row_errors.is_empty() {
+          warn!("import csv: {} cells failed to parse", row_errors.len());
+        }
+        params
       },
 
       CSVFormat::META => {
@@ -548,11 +666,68 @@ impl DatabaseManager {
     Ok(result)
   }
 
+  pub async fn import_xlsx(&self, view_id: String, bytes: Vec<u8>) -> FlowyResult<ImportResult> {
+    let cloned_view_id = view_id.clone();
+    let params = tokio::task::spawn_blocking(move || {
+      XLSXImporter.import_xlsx_from_bytes(&cloned_view_id, bytes)
+    })
+    .await
+    .map_err(internal_error)??;
+
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import xlsx result: {}", result);
+    Ok(result)
+  }
+
+  pub async fn import_database_json(
+    &self,
+    view_id: String,
+    content: String,
+  ) -> FlowyResult<ImportResult> {
+    let cloned_view_id = view_id.clone();
+    let params = tokio::task::spawn_blocking(move || {
+      DatabaseJsonImporter.import_json_from_string(&cloned_view_id, content)
+    })
+    .await
+    .map_err(internal_error)??;
+
+    let database_id = params.database_id.clone();
+    let database = self.import_database(params).await?;
+    let encoded_database = database.read().await.encode_database_collabs().await?;
+    let encoded_collabs = std::iter::once(encoded_database.encoded_database_collab)
+      .chain(encoded_database.encoded_row_collabs.into_iter())
+      .collect::<Vec<_>>();
+
+    let result = ImportResult {
+      database_id,
+      view_id,
+      encoded_collabs,
+    };
+    info!("import database json result: {}", result);
+    Ok(result)
+  }
+
   pub async fn export_csv(&self, view_id: &str, style: CSVFormat) -> FlowyResult<String> {
     let database = self.get_database_editor_with_view_id(view_id).await?;
     database.export_csv(style).await
   }
 
+  pub async fn export_database_json(&self, view_id: &str) -> FlowyResult<String> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    database.export_database_json().await
+  }
+
   pub async fn update_database_layout(
     &self,
     view_id: &str,
@@ -593,20 +768,20 @@ impl DatabaseManager {
       .ok_or_else(|| FlowyError::internal().with_context("Workspace database not initialized"))
   }
 
-  #[instrument(level = "debug", skip_all)]
-  pub async fn summarize_row(
+  /// Collects the text content of the row's other cells as the input of the
+  /// summarization prompt. AI cells are skipped; they don't need to be
+  /// summarized.
+  async fn summary_row_content(
     &self,
+    database: &DatabaseEditor,
     view_id: &str,
-    row_id: RowId,
-    field_id: String,
-  ) -> FlowyResult<()> {
-    let database = self.get_database_editor_with_view_id(view_id).await?;
+    row_id: &RowId,
+    field_id: &str,
+  ) -> SummaryRowContent {
     let mut summary_row_content = SummaryRowContent::new();
-    if let Some(row) = database.get_row(view_id, &row_id).await {
+    if let Some(row) = database.get_row(view_id, row_id).await {
       let fields = database.get_fields(view_id, None).await;
       for field in fields {
-        // When summarizing a row, skip the content in the "AI summary" cell; it does not need to
-        // be summarized.
         if field.id != field_id {
           if FieldType::from(field.field_type).is_ai_field() {
             continue;
@@ -617,6 +792,20 @@ impl DatabaseManager {
         }
       }
     }
+    summary_row_content
+  }
+
+  #[instrument(level = "debug", skip_all)]
+  pub async fn summarize_row(
+    &self,
+    view_id: &str,
+    row_id: RowId,
+    field_id: String,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    let summary_row_content = self
+      .summary_row_content(&database, view_id, &row_id, &field_id)
+      .await;
 
     // Call the cloud service to summarize the row.
     trace!(
@@ -640,6 +829,99 @@ impl DatabaseManager {
     Ok(())
   }
 
+  /// Same as [Self::summarize_row], but streams the model output into the cell
+  /// as it arrives, so the user watches the summary grow instead of waiting for
+  /// the full response.
+  #[instrument(level = "debug", skip_all)]
+  pub async fn generate_summary_cell(
+    &self,
+    view_id: &str,
+    row_id: RowId,
+    field_id: String,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    let summary_row_content = self
+      .summary_row_content(&database, view_id, &row_id, &field_id)
+      .await;
+
+    trace!(
+      "[AI]:generate summary cell for row:{}, content:{:?}",
+      row_id, summary_row_content
+    );
+    let mut stream = self
+      .ai_service
+      .summary_database_row_stream(
+        &self.user.workspace_id()?,
+        &Uuid::from_str(&row_id)?,
+        summary_row_content,
+      )
+      .await?;
+
+    // Intermediate chunks are written without emitting automation events, so a
+    // streaming summary can't re-trigger automations on every partial write.
+    let mut summary = String::new();
+    while let Some(chunk) = stream.next().await {
+      summary.push_str(&chunk?);
+      database
+        .update_cell_with_changeset_from_automation(
+          view_id,
+          &row_id,
+          &field_id,
+          BoxAny::new(summary.clone()),
+        )
+        .await?;
+    }
+    trace!("[AI]:generate summary cell response: {}", summary);
+
+    // The final write is a regular cell update.
+    database
+      .update_cell_with_changeset(view_id, &row_id, &field_id, BoxAny::new(summary))
+      .await?;
+    Ok(())
+  }
+
+  /// Fills the summary cells of every row in the view that doesn't have one
+  /// yet, generating a few rows at a time.
+  #[instrument(level = "debug", skip_all)]
+  pub async fn generate_summary_column(&self, view_id: &str, field_id: String) -> FlowyResult<()> {
+    const BATCH_SIZE: usize = 5;
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    let field = database.get_field(&field_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", field_id))
+    })?;
+
+    let row_ids = database
+      .get_all_rows(view_id)
+      .await?
+      .into_iter()
+      .filter(|row| {
+        row
+          .cells
+          .get(&field_id)
+          .map_or(true, |cell| stringify_cell(cell, &field).is_empty())
+      })
+      .map(|row| row.id.clone())
+      .collect::<Vec<_>>();
+    trace!(
+      "[AI]:generate summary column:{}, {} rows to fill",
+      field_id,
+      row_ids.len()
+    );
+
+    for batch in row_ids.chunks(BATCH_SIZE) {
+      let results = futures::future::join_all(batch.iter().map(|row_id| {
+        self.generate_summary_cell(view_id, row_id.clone(), field_id.clone())
+      }))
+      .await;
+      for (row_id, result) in batch.iter().zip(results) {
+        if let Err(err) = result {
+          warn!("[AI]:generate summary for row:{} failed: {}", row_id, err);
+        }
+      }
+    }
+    Ok(())
+  }
+
   #[instrument(level = "debug", skip_all)]
   pub async fn translate_row(
     &self,
@@ -715,6 +997,120 @@ impl DatabaseManager {
     Ok(())
   }
 
+  /// Scans every database in the workspace for rows that look like the
+  /// current user's tasks and returns them with their source view ids, so the
+  /// home screen can show a unified task list. Tasks are ordered by due date,
+  /// with undated ones last.
+  #[instrument(level = "debug", skip_all)]
+  pub async fn get_my_tasks(&self, filter: MyTasksFilter) -> FlowyResult<Vec<MyTask>> {
+    let now = timestamp();
+    let mut tasks = vec![];
+    for database_meta in self.get_all_databases_meta().await {
+      let view_id = match database_meta.linked_views.first() {
+        Some(view_id) => view_id.clone(),
+        None => continue,
+      };
+      let database = match self
+        .get_or_init_database_editor(&database_meta.database_id)
+        .await
+      {
+        Ok(database) => database,
+        Err(err) => {
+          warn!(
+            "[Database]: skip database:{} when collecting tasks: {}",
+            database_meta.database_id, err
+          );
+          continue;
+        },
+      };
+      let fields = database.get_fields(&view_id, None).await;
+      match database.get_all_rows(&view_id).await {
+        Ok(rows) => tasks.extend(collect_tasks(
+          &database_meta.database_id,
+          &view_id,
+          &fields,
+          &rows,
+          &filter,
+          now,
+        )),
+        Err(err) => warn!(
+          "[Database]: skip view:{} when collecting tasks: {}",
+          view_id, err
+        ),
+      }
+    }
+    tasks.sort_by_key(|task| (task.due_timestamp.is_none(), task.due_timestamp));
+    Ok(tasks)
+  }
+
+  /// Translates every row in the view that has source content but no
+  /// translation yet. Rows are translated one at a time with a short pause
+  /// between requests so the AI provider isn't flooded, and each result is
+  /// written to its cell as soon as it arrives.
+  #[instrument(level = "debug", skip_all)]
+  pub async fn translate_column(&self, view_id: &str, field_id: String) -> FlowyResult<()> {
+    const TRANSLATE_REQUEST_INTERVAL: Duration = Duration::from_millis(500);
+    let database = self.get_database_editor_with_view_id(view_id).await?;
+    let field = database.get_field(&field_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", field_id))
+    })?;
+    let fields = database.get_fields(view_id, None).await;
+
+    let row_ids = database
+      .get_all_rows(view_id)
+      .await?
+      .into_iter()
+      .filter(|row| {
+        // Skip rows that are already translated.
+        if row
+          .cells
+          .get(&field_id)
+          .is_some_and(|cell| !stringify_cell(cell, &field).is_empty())
+        {
+          return false;
+        }
+        // Queue only rows with at least one non-empty source cell.
+        fields.iter().any(|source_field| {
+          if source_field.id == field_id || FieldType::from(source_field.field_type).is_ai_field()
+          {
+            return false;
+          }
+          row
+            .cells
+            .get(&source_field.id)
+            .is_some_and(|cell| !stringify_cell(cell, source_field).is_empty())
+        })
+      })
+      .map(|row| row.id.clone())
+      .collect::<Vec<_>>();
+
+    let total = row_ids.len();
+    trace!(
+      "[AI]:translate column:{}, {} rows to translate",
+      field_id, total
+    );
+    for (index, row_id) in row_ids.into_iter().enumerate() {
+      if index != 0 {
+        tokio::time::sleep(TRANSLATE_REQUEST_INTERVAL).await;
+      }
+      if let Err(err) = self
+        .translate_row(view_id, row_id.clone(), field_id.clone())
+        .await
+      {
+        warn!("[AI]:translate row:{} failed: {}", row_id, err);
+      }
+      database_notification_builder(view_id, DatabaseNotification::DidUpdateTranslateColumnProgress)
+        .payload(TranslateColumnProgressPB {
+          view_id: view_id.to_string(),
+          field_id: field_id.clone(),
+          completed: (index + 1) as i64,
+          total: total as i64,
+        })
+        .send();
+    }
+    Ok(())
+  }
+
   /// Only expose this method for testing
   #[cfg(debug_assertions)]
   pub fn get_cloud_service(&self) -> &Arc<dyn DatabaseCloudService> {
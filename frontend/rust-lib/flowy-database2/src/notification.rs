@@ -52,6 +52,15 @@ pub enum DatabaseNotification {
   DidUpdateFieldSettings = 86,
   // Trigger when Calculation changed
   DidUpdateCalculation = 87,
+  /// Trigger after adding/editing/deleting a row comment. The notification is
+  /// keyed by the row id.
+  DidUpdateRowComments = 88,
+  /// Trigger when a row changes inside the row window that the frontend
+  /// subscribed to with GetRowsPage, so the current page can be refetched.
+  DidUpdateRowWindow = 89,
+  /// Trigger after each row finishes while a whole column is being translated,
+  /// so the frontend can show the progress.
+  DidUpdateTranslateColumnProgress = 90,
 }
 
 impl std::convert::From<DatabaseNotification> for i32 {
@@ -84,6 +93,9 @@ impl std::convert::From<i32> for DatabaseNotification {
       84 => DatabaseNotification::DidMoveDatabaseViewToTrash,
       86 => DatabaseNotification::DidUpdateFieldSettings,
       87 => DatabaseNotification::DidUpdateCalculation,
+      88 => DatabaseNotification::DidUpdateRowComments,
+      89 => DatabaseNotification::DidUpdateRowWindow,
+      90 => DatabaseNotification::DidUpdateTranslateColumnProgress,
       _ => DatabaseNotification::Unknown,
     }
   }
@@ -0,0 +1,93 @@
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::DBConnection;
+use flowy_sqlite::schema::database_automation_table;
+use flowy_sqlite::schema::database_automation_table::dsl;
+use flowy_sqlite::{ExpressionMethods, prelude::*};
+use lib_infra::util::timestamp;
+use uuid::Uuid;
+
+/// An automation registered on a database: a trigger (row created, cell of a
+/// field changed, row moved to a group) paired with an action (set a cell,
+/// create a row in another database, call a webhook). The trigger and action
+/// configs are JSON-serialized config structs, see
+/// [crate::services::automation].
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = database_automation_table)]
+#[diesel(primary_key(trigger_id))]
+pub struct AutomationTable {
+  pub trigger_id: String,
+  pub database_id: String,
+  pub trigger_type: i32,
+  pub trigger_config: String,
+  pub action_type: i32,
+  pub action_config: String,
+  pub enabled: bool,
+  pub created_at: i64,
+}
+
+impl AutomationTable {
+  pub fn new(
+    database_id: String,
+    trigger_type: i32,
+    trigger_config: String,
+    action_type: i32,
+    action_config: String,
+  ) -> Self {
+    Self {
+      trigger_id: Uuid::new_v4().to_string(),
+      database_id,
+      trigger_type,
+      trigger_config,
+      action_type,
+      action_config,
+      enabled: true,
+      created_at: timestamp(),
+    }
+  }
+}
+
+pub fn insert_automation(conn: &mut DBConnection, automation: &AutomationTable) -> FlowyResult<()> {
+  diesel::insert_into(dsl::database_automation_table)
+    .values(automation.clone())
+    .execute(conn)?;
+  Ok(())
+}
+
+/// Selects all the automations of a database, oldest first.
+pub fn select_automations(
+  conn: &mut DBConnection,
+  database_id: &str,
+) -> FlowyResult<Vec<AutomationTable>> {
+  let automations = dsl::database_automation_table
+    .filter(database_automation_table::database_id.eq(database_id))
+    .order(database_automation_table::created_at.asc())
+    .load::<AutomationTable>(conn)?;
+  Ok(automations)
+}
+
+pub fn delete_automation(conn: &mut DBConnection, trigger_id: &str) -> FlowyResult<()> {
+  diesel::delete(
+    dsl::database_automation_table.filter(database_automation_table::trigger_id.eq(trigger_id)),
+  )
+  .execute(conn)?;
+  Ok(())
+}
+
+pub fn set_automation_enabled(
+  conn: &mut DBConnection,
+  trigger_id: &str,
+  enabled: bool,
+) -> FlowyResult<()> {
+  let affected = diesel::update(
+    dsl::database_automation_table.filter(database_automation_table::trigger_id.eq(trigger_id)),
+  )
+  .set(database_automation_table::enabled.eq(enabled))
+  .execute(conn)?;
+  if affected == 0 {
+    return Err(
+      FlowyError::record_not_found()
+        .with_context(format!("automation:{} is not found", trigger_id)),
+    );
+  }
+  Ok(())
+}
@@ -0,0 +1,5 @@
+mod automation_sql;
+mod runner;
+
+pub use automation_sql::*;
+pub use runner::*;
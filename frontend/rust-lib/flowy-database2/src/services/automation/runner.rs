@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+
+use collab_database::rows::RowId;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+use tracing::{trace, warn};
+
+use flowy_error::{FlowyError, FlowyResult};
+use lib_infra::box_any::BoxAny;
+
+use crate::entities::{AutomationActionTypePB, AutomationTriggerTypePB, CreateRowPayloadPB};
+use crate::manager::{DatabaseManager, DatabaseUser};
+use crate::services::automation::{AutomationTable, select_automations};
+
+/// A change emitted by a database editor that may fire automations.
+#[derive(Debug, Clone)]
+pub enum AutomationTrigger {
+  RowCreated { row_id: RowId },
+  CellChanged { row_id: RowId, field_id: String },
+  RowMovedToGroup { row_id: RowId, group_id: String },
+}
+
+impl AutomationTrigger {
+  pub fn row_id(&self) -> &RowId {
+    match self {
+      AutomationTrigger::RowCreated { row_id } => row_id,
+      AutomationTrigger::CellChanged { row_id, .. } => row_id,
+      AutomationTrigger::RowMovedToGroup { row_id, .. } => row_id,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct AutomationEvent {
+  pub database_id: String,
+  pub view_id: String,
+  pub trigger: AutomationTrigger,
+}
+
+/// Config of a [AutomationTriggerTypePB::CellChanged] trigger.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CellChangedTriggerConfig {
+  pub field_id: String,
+}
+
+/// Config of a [AutomationTriggerTypePB::RowMovedToGroup] trigger.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RowMovedToGroupTriggerConfig {
+  pub group_id: String,
+}
+
+/// Config of a [AutomationActionTypePB::SetCell] action. The value is applied
+/// to the field of the triggering row as a cell changeset string.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetCellActionConfig {
+  pub field_id: String,
+  pub value: String,
+}
+
+/// Config of a [AutomationActionTypePB::CreateRow] action, targeting a view
+/// of any database. The data maps field ids to cell changeset strings.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateRowActionConfig {
+  pub view_id: String,
+  #[serde(default)]
+  pub data: HashMap<String, String>,
+}
+
+/// Config of a [AutomationActionTypePB::Webhook] action. The event is POSTed
+/// to the url as JSON.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookActionConfig {
+  pub url: String,
+}
+
+pub struct AutomationRunner;
+
+impl AutomationRunner {
+  /// Spawns the worker that executes automations asynchronously from the
+  /// row/cell change observers. Returns the sender the editors emit their
+  /// events into.
+  pub fn spawn(
+    user: Arc<dyn DatabaseUser>,
+    manager: Weak<DatabaseManager>,
+  ) -> UnboundedSender<AutomationEvent> {
+    let (tx, mut rx) = unbounded_channel::<AutomationEvent>();
+    tokio::spawn(async move {
+      while let Some(event) = rx.recv().await {
+        if let Err(err) = handle_event(&user, &manager, &event).await {
+          warn!("[Automation]: failed to handle event: {}", err);
+        }
+      }
+    });
+    tx
+  }
+}
+
+async fn handle_event(
+  user: &Arc<dyn DatabaseUser>,
+  manager: &Weak<DatabaseManager>,
+  event: &AutomationEvent,
+) -> FlowyResult<()> {
+  let automations = {
+    let uid = user.user_id()?;
+    let mut conn = user.sqlite_connection(uid)?;
+    select_automations(&mut conn, &event.database_id)?
+  };
+
+  for automation in automations.into_iter().filter(|a| a.enabled) {
+    if !trigger_matches(&automation, &event.trigger) {
+      continue;
+    }
+    trace!("[Automation]: running automation: {}", automation.trigger_id);
+    if let Err(err) = execute_action(manager, event, &automation).await {
+      warn!(
+        "[Automation]: automation {} failed: {}",
+        automation.trigger_id, err
+      );
+    }
+  }
+  Ok(())
+}
+
+fn trigger_matches(automation: &AutomationTable, trigger: &AutomationTrigger) -> bool {
+  match (
+    AutomationTriggerTypePB::from(automation.trigger_type),
+    trigger,
+  ) {
+    (AutomationTriggerTypePB::RowCreated, AutomationTrigger::RowCreated { .. }) => true,
+    (AutomationTriggerTypePB::CellChanged, AutomationTrigger::CellChanged { field_id, .. }) => {
+      serde_json::from_str::<CellChangedTriggerConfig>(&automation.trigger_config)
+        .map(|config| &config.field_id == field_id)
+        .unwrap_or(false)
+    },
+    (
+      AutomationTriggerTypePB::RowMovedToGroup,
+      AutomationTrigger::RowMovedToGroup { group_id, .. },
+    ) => serde_json::from_str::<RowMovedToGroupTriggerConfig>(&automation.trigger_config)
+      .map(|config| &config.group_id == group_id)
+      .unwrap_or(false),
+    _ => false,
+  }
+}
+
+async fn execute_action(
+  manager: &Weak<DatabaseManager>,
+  event: &AutomationEvent,
+  automation: &AutomationTable,
+) -> FlowyResult<()> {
+  let manager = manager.upgrade().ok_or_else(FlowyError::ref_drop)?;
+  match AutomationActionTypePB::from(automation.action_type) {
+    AutomationActionTypePB::SetCell => {
+      let config = serde_json::from_str::<SetCellActionConfig>(&automation.action_config)
+        .map_err(|err| FlowyError::serde().with_context(err))?;
+      let row_id = event.trigger.row_id().clone();
+      let editor = manager
+        .get_database_editor_with_view_id(&event.view_id)
+        .await?;
+      // Automation edits don't re-trigger automations, preventing
+      // self-referential loops.
+      editor
+        .update_cell_with_changeset_from_automation(
+          &event.view_id,
+          &row_id,
+          &config.field_id,
+          BoxAny::new(config.value),
+        )
+        .await
+    },
+    AutomationActionTypePB::CreateRow => {
+      let config = serde_json::from_str::<CreateRowActionConfig>(&automation.action_config)
+        .map_err(|err| FlowyError::serde().with_context(err))?;
+      let editor = manager
+        .get_database_editor_with_view_id(&config.view_id)
+        .await?;
+      editor
+        .create_row_from_automation(CreateRowPayloadPB {
+          view_id: config.view_id.clone(),
+          data: config.data,
+          ..Default::default()
+        })
+        .await?;
+      Ok(())
+    },
+    AutomationActionTypePB::Webhook => {
+      let config = serde_json::from_str::<WebhookActionConfig>(&automation.action_config)
+        .map_err(|err| FlowyError::serde().with_context(err))?;
+      let payload = webhook_payload(event);
+      let response = reqwest::Client::new()
+        .post(&config.url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|err| FlowyError::http().with_context(err))?;
+      if !response.status().is_success() {
+        return Err(
+          FlowyError::http().with_context(format!("webhook returned {}", response.status())),
+        );
+      }
+      Ok(())
+    },
+  }
+}
+
+fn webhook_payload(event: &AutomationEvent) -> serde_json::Value {
+  let trigger = match &event.trigger {
+    AutomationTrigger::RowCreated { row_id } => json!({
+      "type": "row_created",
+      "row_id": row_id.to_string(),
+    }),
+    AutomationTrigger::CellChanged { row_id, field_id } => json!({
+      "type": "cell_changed",
+      "row_id": row_id.to_string(),
+      "field_id": field_id,
+    }),
+    AutomationTrigger::RowMovedToGroup { row_id, group_id } => json!({
+      "type": "row_moved_to_group",
+      "row_id": row_id.to_string(),
+      "group_id": group_id,
+    }),
+  };
+  json!({
+    "database_id": event.database_id,
+    "view_id": event.view_id,
+    "trigger": trigger,
+  })
+}
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use collab_database::fields::Field;
-use collab_database::rows::{Cell, Row};
+use collab_database::rows::{Cell, Row, RowId};
 use dashmap::DashMap;
 use flowy_error::FlowyResult;
 use lib_infra::priority_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
@@ -23,6 +23,12 @@ use super::{Calculation, CalculationChangeset, CalculationsService};
 #[async_trait]
 pub trait CalculationsDelegate: Send + Sync + 'static {
   async fn get_cells_for_field(&self, view_id: &str, field_id: &str) -> Vec<Arc<Cell>>;
+  async fn get_cells_for_field_in_rows(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    row_ids: &[RowId],
+  ) -> Vec<Arc<Cell>>;
   async fn get_field(&self, field_id: &str) -> Option<Field>;
   async fn get_calculation(&self, view_id: &str, field_id: &str) -> Option<Arc<Calculation>>;
   async fn get_all_calculations(&self, view_id: &str) -> Vec<Arc<Calculation>>;
@@ -241,6 +247,32 @@ impl CalculationsController {
     }
   }
 
+  /// Computes the values of all the view's calculations over the given rows
+  /// only. Used to surface per-group aggregates when grouping is active.
+  pub async fn calculate_for_rows(&self, row_ids: &[RowId]) -> Vec<CalculationPB> {
+    let mut results = vec![];
+    let calculations = self.delegate.get_all_calculations(&self.view_id).await;
+    for calculation in calculations.into_iter() {
+      if let Some(field) = self.delegate.get_field(&calculation.field_id).await {
+        let cells = self
+          .delegate
+          .get_cells_for_field_in_rows(&self.view_id, &calculation.field_id, row_ids)
+          .await;
+        let value =
+          self
+            .calculations_service
+            .calculate(&field, calculation.calculation_type, cells);
+        results.push(CalculationPB {
+          id: calculation.id.clone(),
+          field_id: calculation.field_id.clone(),
+          calculation_type: calculation.calculation_type.into(),
+          value,
+        });
+      }
+    }
+    results
+  }
+
   pub async fn did_receive_row_changed(&self, row: Row) {
     self
       .gen_task(
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use collab_database::fields::Field;
 use collab_database::rows::Cell;
 
-use crate::entities::CalculationType;
+use collab_database::fields::date_type_option::DateCellData;
+
+use crate::entities::{CalculationType, CheckboxCellDataPB};
 use crate::services::field::TypeOptionCellExt;
 use rayon::prelude::*;
 
@@ -25,6 +27,11 @@ impl CalculationsService {
       CalculationType::Count => self.calculate_count(cells),
       CalculationType::CountEmpty => self.calculate_count_empty(field, cells),
       CalculationType::CountNonEmpty => self.calculate_count_non_empty(field, cells),
+      CalculationType::Range => self.calculate_range(field, cells),
+      CalculationType::PercentChecked => self.calculate_percent_checked(cells, true),
+      CalculationType::PercentUnchecked => self.calculate_percent_checked(cells, false),
+      CalculationType::EarliestDate => self.calculate_date_boundary(field, cells, true),
+      CalculationType::LatestDate => self.calculate_date_boundary(field, cells, false),
     }
   }
 
@@ -87,6 +94,72 @@ impl CalculationsService {
     }
   }
 
+  fn calculate_range(&self, field: &Field, cells: Vec<Arc<Cell>>) -> String {
+    let values = self.reduce_values_f64(field, cells);
+    let min = values.par_iter().min_by(|a, b| a.total_cmp(b));
+    let max = values.par_iter().max_by(|a, b| a.total_cmp(b));
+    if let (Some(min), Some(max)) = (min, max) {
+      format!("{:.2}", max - min)
+    } else {
+      String::new()
+    }
+  }
+
+  fn calculate_percent_checked(&self, cells: Vec<Arc<Cell>>, checked: bool) -> String {
+    if cells.is_empty() {
+      return String::new();
+    }
+    let checked_count = cells
+      .par_iter()
+      .filter(|cell| CheckboxCellDataPB::from(cell.as_ref()).is_checked)
+      .count();
+    let count = if checked {
+      checked_count
+    } else {
+      cells.len() - checked_count
+    };
+    format!("{:.1}%", count as f64 / cells.len() as f64 * 100.0)
+  }
+
+  fn calculate_date_boundary(
+    &self,
+    field: &Field,
+    cells: Vec<Arc<Cell>>,
+    earliest: bool,
+  ) -> String {
+    let boundary = cells
+      .iter()
+      .filter_map(|cell| {
+        let cell_data = DateCellData::from(cell.as_ref());
+        // A range cell ends at its end timestamp, so that's the value to
+        // compare when looking for the latest date.
+        let timestamp = if earliest {
+          cell_data.timestamp?
+        } else {
+          cell_data.end_timestamp.or(cell_data.timestamp)?
+        };
+        Some((timestamp, cell))
+      })
+      .reduce(|acc, item| {
+        let is_better = if earliest {
+          item.0 < acc.0
+        } else {
+          item.0 > acc.0
+        };
+        if is_better { item } else { acc }
+      });
+
+    match boundary {
+      Some((_, cell)) => {
+        match TypeOptionCellExt::new(field, None).get_type_option_cell_data_handler() {
+          Some(handler) => handler.handle_stringify_cell(cell, field),
+          None => String::new(),
+        }
+      },
+      None => String::new(),
+    }
+  }
+
   fn calculate_count(&self, cells: Vec<Arc<Cell>>) -> String {
     format!("{}", cells.len())
   }
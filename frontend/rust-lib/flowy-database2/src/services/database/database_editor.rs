@@ -2,34 +2,74 @@ use crate::DatabaseUser;
 use crate::entities::*;
 use crate::notification::{DatabaseNotification, database_notification_builder};
 use crate::services::calculations::Calculation;
-use crate::services::cell::{CellCache, apply_cell_changeset, get_cell_protobuf, stringify_cell};
+use crate::services::cell::{
+  CellCache, CellProtobufBlob, apply_cell_changeset, get_cell_protobuf, stringify_cell,
+};
 use crate::services::database::database_observe::*;
 use crate::services::database::util::database_view_setting_pb_from_view;
 use crate::services::database_view::{
   DatabaseViewChanged, DatabaseViewEditor, DatabaseViewOperation, DatabaseViews, EditorByViewId,
 };
 use crate::services::field::checklist_filter::ChecklistCellChangeset;
-use crate::services::field::type_option_transform::transform_type_option;
+use crate::services::field::date_type_option::date_type_option::migrate_date_cells_to_utc;
+use crate::services::field::type_option_transform::{
+  transform_is_lossy, transform_rewrites_cells, transform_type_option,
+};
 use crate::services::field::{
-  SelectOptionCellChangeset, StringCellData, TypeOptionCellDataHandler, TypeOptionCellExt,
-  default_type_option_data_from_type, select_type_option_from_field, type_option_data_from_pb,
+  RelationCellResolver, SelectOptionCellChangeset, StringCellData, TypeOptionCellDataHandler,
+  TypeOptionCellExt, default_type_option_data_from_type, get_time_tracking_started_at,
+  select_option_meta_from_field, select_option_meta_from_pb_options,
+  select_type_option_data_with_meta, select_type_option_from_field, start_time_session,
+  stop_time_session, type_option_data_from_pb,
 };
 use crate::services::field_settings::{FieldSettings, default_field_settings_by_layout_map};
 use crate::services::filter::{Filter, FilterChangeset};
 use crate::services::group::{GroupChangeset, GroupSetting, default_group_setting};
-use crate::services::share::csv::{CSVExport, CSVFormat};
+use crate::services::row_comment::{
+  RowCommentTable, count_row_comments, delete_row_comment, insert_row_comment, select_row_comment,
+  select_row_comments, toggle_row_comment_reaction, update_row_comment_content,
+};
+use crate::services::row_history::{
+  RowHistoryChange, insert_row_history, select_row_history, select_row_history_after,
+};
+use crate::services::row_template::{
+  RowTemplateTable, delete_row_template, insert_row_template, select_default_row_template,
+  select_row_template, select_row_templates, set_default_row_template,
+};
+use crate::services::automation::{
+  AutomationEvent, AutomationTable, AutomationTrigger, delete_automation, insert_automation,
+  select_automations, set_automation_enabled,
+};
+use crate::services::media_meta::{
+  extract_media_file_meta, select_media_file_metas, upsert_media_file_meta,
+};
+use crate::services::undo::{CellEditRecord, CellUndoStack};
+use crate::services::personal_view::PersonalViewStore;
+use crate::services::url_preview::{fetch_url_preview, select_url_preview, upsert_url_preview};
+use crate::services::share::csv::{CSVExport, CSVFormat, CSVRowImportError, typed_cell_for_field};
+use crate::services::share::ics::ICSExport;
+use crate::services::share::json::DatabaseJsonExport;
+use crate::services::share::markdown::MarkdownExport;
+use crate::services::share::xlsx::XLSXExport;
 use crate::services::sort::Sort;
 use crate::utils::cache::AnyTypeCache;
 use arc_swap::ArcSwapOption;
 use async_trait::async_trait;
 use collab::core::collab_plugin::CollabPluginType;
 use collab::lock::RwLock;
-use collab_database::database::Database;
+use collab_database::database::{Database, gen_row_id};
 use collab_database::entity::DatabaseView;
-use collab_database::fields::media_type_option::MediaCellData;
+use collab_database::fields::media_type_option::{MediaCellData, MediaFile};
 use collab_database::fields::relation_type_option::RelationTypeOption;
+use collab_database::fields::select_type_option::SelectOptionIds;
+use collab_database::fields::url_type_option::URLCellData;
 use collab_database::fields::{Field, TypeOptionData};
-use collab_database::rows::{Cell, Cells, DatabaseRow, Row, RowCell, RowDetail, RowId, RowUpdate};
+use collab_database::rows::{
+  Cell, Cells, CreateRowParams, DatabaseRow, Row, RowCell, RowCover, RowDetail, RowId, RowUpdate,
+  get_field_type_from_cell,
+};
+use collab_database::template::relation_parse::RelationCellData;
+use collab_database::template::time_parse::TimeCellData;
 use collab_database::template::timestamp_parse::TimestampCellData;
 use collab_database::views::{
   DatabaseLayout, FilterMap, LayoutSetting, OrderObjectPosition, RowOrder,
@@ -38,17 +78,20 @@ use collab_entity::CollabType;
 use collab_integrate::collab_builder::{AppFlowyCollabBuilder, CollabBuilderConfig};
 use flowy_error::{ErrorCode, FlowyError, FlowyResult, internal_error};
 use flowy_notification::DebounceNotificationSender;
+use flowy_sqlite::DBConnection;
 use futures::future::join_all;
 use futures::{StreamExt, pin_mut};
 use lib_infra::box_any::BoxAny;
 use lib_infra::priority_task::TaskDispatcher;
 use lib_infra::util::timestamp;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::{Arc, Weak};
 use std::time::Duration;
 use tokio::select;
 use tokio::sync::RwLock as TokioRwLock;
+use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::oneshot::Sender;
 use tokio::sync::{broadcast, oneshot};
 use tokio::task::yield_now;
@@ -63,7 +106,6 @@ pub struct DatabaseEditor {
   pub(crate) database: Arc<RwLock<Database>>,
   pub cell_cache: CellCache,
   pub(crate) database_views: Arc<DatabaseViews>,
-  #[allow(dead_code)]
   user: Arc<dyn DatabaseUser>,
   collab_builder: Weak<AppFlowyCollabBuilder>,
   is_loading_rows: ArcSwapOption<broadcast::Sender<()>>,
@@ -72,6 +114,10 @@ pub struct DatabaseEditor {
   database_cancellation: Arc<RwLock<Option<CancellationToken>>>,
   un_finalized_rows_cancellation: Arc<ArcSwapOption<CancellationToken>>,
   finalized_rows: Arc<moka::future::Cache<String, Weak<RwLock<DatabaseRow>>>>,
+  automation_tx: ArcSwapOption<UnboundedSender<AutomationEvent>>,
+  relation_resolver: Arc<ArcSwapOption<RelationCellResolver>>,
+  personal_views: Arc<PersonalViewStore>,
+  cell_undo: CellUndoStack,
 }
 
 impl DatabaseEditor {
@@ -101,12 +147,18 @@ impl DatabaseEditor {
 
     // Used to cache the view of the database for fast access.
     let editor_by_view_id = Arc::new(RwLock::new(EditorByViewId::default()));
+    let personal_views = Arc::new(PersonalViewStore::new(user.clone()));
+    // Installed by the manager after the editor is created, shared with the
+    // view operation so that filters can read cells of related databases.
+    let relation_resolver: Arc<ArcSwapOption<RelationCellResolver>> = Arc::new(Default::default());
     let view_operation = Arc::new(DatabaseViewOperationImpl {
       database: database.clone(),
       task_scheduler: task_scheduler.clone(),
       cell_cache: cell_cache.clone(),
       editor_by_view_id: editor_by_view_id.clone(),
       database_cancellation: database_cancellation.clone(),
+      relation_resolver: relation_resolver.clone(),
+      personal_views: personal_views.clone(),
     });
 
     let database_views = Arc::new(
@@ -144,6 +196,10 @@ impl DatabaseEditor {
       database_cancellation,
       un_finalized_rows_cancellation: Arc::new(Default::default()),
       finalized_rows: Arc::new(finalized_rows),
+      automation_tx: Default::default(),
+      relation_resolver,
+      personal_views,
+      cell_undo: CellUndoStack::default(),
     });
     observe_block_event(&database_id, &this).await;
     observe_view_change(&database_id, &this).await;
@@ -382,6 +438,32 @@ impl DatabaseEditor {
     }
   }
 
+  /// Marks the view as personal for the current user, or reverts it to a
+  /// shared view. When a view becomes personal, its filter, sort and group
+  /// settings are copied into the user's sqlite database and subsequent
+  /// changes are stored there instead of in the shared collab. The change
+  /// takes effect the next time the view is opened.
+  pub async fn set_view_personal(&self, view_id: &str, is_personal: bool) -> FlowyResult<()> {
+    if !is_personal {
+      return self.personal_views.remove_personal(view_id);
+    }
+    let (filters, sorts, group_settings) = {
+      let database = self.database.read().await;
+      (
+        database.get_all_filters::<Filter>(view_id),
+        database.get_all_sorts::<Sort>(view_id),
+        database.get_all_group_setting::<GroupSetting>(view_id),
+      )
+    };
+    self
+      .personal_views
+      .set_personal(view_id, &filters, sorts, group_settings)
+  }
+
+  pub fn is_view_personal(&self, view_id: &str) -> bool {
+    self.personal_views.is_personal(view_id)
+  }
+
   /// Returns a list of fields of the view.
   /// If `field_ids` is not provided, all the fields will be returned in the order of the field that
   /// defined in the view. Otherwise, the fields will be returned in the order of the `field_ids`.
@@ -476,6 +558,7 @@ impl DatabaseEditor {
     let view_editors = self.database_views.editors().await;
     {
       let mut database = self.database.write().await;
+      migrate_date_cells_to_utc(&mut database, &type_option_data, &old_field).await;
       update_field_type_option_fn(&mut database, type_option_data, &old_field).await?;
       drop(database);
     }
@@ -542,6 +625,40 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Dry run of [Self::switch_to_field_type]: reports how many cells the
+  /// switch would rewrite and whether it can drop information, without
+  /// touching the field.
+  pub async fn get_field_type_transform_impact(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    new_field_type: FieldType,
+  ) -> FlowyResult<FieldTypeTransformImpactPB> {
+    let old_field_type = {
+      let database = self.database.read().await;
+      let field = database.get_field(field_id).ok_or_else(|| {
+        FlowyError::record_not_found().with_context(format!("field:{} is not found", field_id))
+      })?;
+      FieldType::from(field.field_type)
+    };
+
+    let altered_cell_count = if transform_rewrites_cells(old_field_type, new_field_type) {
+      self
+        .get_cells_for_field(view_id, field_id)
+        .await
+        .into_iter()
+        .filter(|row_cell| row_cell.cell.is_some())
+        .count() as i64
+    } else {
+      0
+    };
+
+    Ok(FieldTypeTransformImpactPB {
+      altered_cell_count,
+      lossy: transform_is_lossy(old_field_type, new_field_type),
+    })
+  }
+
   pub async fn duplicate_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
     let mut database = self.database.write().await;
     let is_primary = database
@@ -578,7 +695,8 @@ impl DatabaseEditor {
     Ok(())
   }
 
-  pub async fn duplicate_row(&self, view_id: &str, row_id: &RowId) -> FlowyResult<()> {
+  /// Duplicates the row and returns the id of the new row.
+  pub async fn duplicate_row(&self, view_id: &str, row_id: &RowId) -> FlowyResult<RowId> {
     let mut database = self.database.write().await;
     let params = database
       .duplicate_row(row_id)
@@ -606,7 +724,142 @@ impl DatabaseEditor {
       row_id, index, row_order
     );
 
-    Ok(())
+    Ok(row_order.id)
+  }
+
+  /// Duplicates the rows and returns the ids of the new rows, in the same
+  /// order as the input. When `remap_relations` is true, relation cells that
+  /// point at other rows of the selection are rewritten to point at the new
+  /// copies instead of the originals.
+  pub async fn duplicate_rows(
+    &self,
+    view_id: &str,
+    row_ids: &[RowId],
+    remap_relations: bool,
+  ) -> FlowyResult<Vec<RowId>> {
+    let mut new_row_ids = Vec::with_capacity(row_ids.len());
+    for row_id in row_ids {
+      new_row_ids.push(self.duplicate_row(view_id, row_id).await?);
+    }
+
+    if remap_relations {
+      let id_map: HashMap<RowId, RowId> = row_ids
+        .iter()
+        .cloned()
+        .zip(new_row_ids.iter().cloned())
+        .collect();
+      let relation_fields = self
+        .get_fields(view_id, None)
+        .await
+        .into_iter()
+        .filter(|field| FieldType::from(field.field_type) == FieldType::Relation)
+        .collect::<Vec<_>>();
+
+      for new_row_id in &new_row_ids {
+        for field in &relation_fields {
+          let cell = match self.get_cell(&field.id, new_row_id).await {
+            Some(cell) => cell,
+            None => continue,
+          };
+          let cell_data = RelationCellData::from(&cell);
+          let mut changed = false;
+          let row_ids = cell_data
+            .row_ids
+            .iter()
+            .map(|row_id| match id_map.get(row_id) {
+              Some(new_id) => {
+                changed = true;
+                new_id.clone()
+              },
+              None => row_id.clone(),
+            })
+            .collect();
+          if changed {
+            let new_cell = Cell::from(RelationCellData { row_ids });
+            self
+              .update_cell(view_id, new_row_id, &field.id, new_cell)
+              .await?;
+          }
+        }
+      }
+    }
+
+    Ok(new_row_ids)
+  }
+
+  /// Appends the rows of a CSV file to the database. Every column is resolved
+  /// to an existing field through `column_mappings` (column header → field id),
+  /// falling back to the field with the same name; columns that match no field
+  /// are skipped. Cells that don't parse as the field type are reported in the
+  /// returned error list and left empty instead of failing the whole file.
+  pub async fn import_csv_rows(
+    &self,
+    view_id: &str,
+    content: String,
+    column_mappings: HashMap<String, String>,
+  ) -> FlowyResult<(usize, Vec<CSVRowImportError>)> {
+    if content.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("Import content is empty"));
+    }
+    let fields = self.get_fields(view_id, None).await;
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+    let headers = reader
+      .headers()
+      .map_err(|_| FlowyError::invalid_data().with_context("Header not found"))?
+      .clone();
+
+    // Resolve a target field per column.
+    let columns = headers
+      .iter()
+      .map(|header| match column_mappings.get(header) {
+        Some(field_id) => fields.iter().find(|field| &field.id == field_id).cloned(),
+        None => fields.iter().find(|field| field.name == header).cloned(),
+      })
+      .collect::<Vec<Option<Field>>>();
+
+    let records = reader
+      .records()
+      .flat_map(|record| record.ok())
+      .collect::<Vec<_>>();
+
+    let mut row_errors = vec![];
+    let mut imported_rows = 0;
+    for (row_index, record) in records.into_iter().enumerate() {
+      let mut cells = Cells::new();
+      for (index, cell_content) in record.iter().enumerate() {
+        let field = match columns.get(index) {
+          Some(Some(field)) => field,
+          _ => continue,
+        };
+        if cell_content.trim().is_empty() {
+          continue;
+        }
+        match typed_cell_for_field(cell_content, field) {
+          Ok(cell) => {
+            cells.insert(field.id.clone(), cell);
+          },
+          Err(error) => row_errors.push(CSVRowImportError {
+            row: row_index,
+            column: field.name.clone(),
+            error,
+          }),
+        }
+      }
+
+      let mut database = self.database.write().await;
+      let mut params = CreateRowParams::new(gen_row_id(), self.database_id.to_string());
+      params.cells = cells;
+      database.create_row_in_view(view_id, params).await?;
+      imported_rows += 1;
+    }
+    trace!(
+      "[Database]: imported {} csv rows into view: {}, {} cells failed to parse",
+      imported_rows,
+      view_id,
+      row_errors.len()
+    );
+
+    Ok((imported_rows, row_errors))
   }
 
   #[tracing::instrument(level = "trace", skip_all, err)]
@@ -642,12 +895,14 @@ impl DatabaseEditor {
       from_row, from_group, to_group
     );
 
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    if from_group != to_group {
+      view_editor.v_check_group_wip_limit(to_group).await?;
+    }
+
     // when moving row between groups, the cells of the row should be updated
     // if the updated cells is not empty, we need to update cells for given row
-    let updated_cells = self
-      .database_views
-      .get_or_init_view_editor(view_id)
-      .await?
+    let updated_cells = view_editor
       .v_move_group_row(&row, to_group, to_row.clone())
       .await;
     if !updated_cells.is_empty() {
@@ -676,16 +931,100 @@ impl DatabaseEditor {
       self.move_row(view_id, from_row.clone(), to_row_id).await?;
     }
 
+    self.emit_automation_event(
+      view_id,
+      AutomationTrigger::RowMovedToGroup {
+        row_id: from_row,
+        group_id: to_group.to_string(),
+      },
+    );
+
     Ok(())
   }
 
+  /// Validates a form submission against the view's form settings and
+  /// creates a row from it. The cell contents are parsed by the same
+  /// [CellBuilder] used for regular row creation, so each value is checked
+  /// against its field type.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn submit_form(&self, params: SubmitFormParams) -> FlowyResult<Option<RowDetail>> {
+    let view_editor = self
+      .database_views
+      .get_or_init_view_editor(&params.view_id)
+      .await?;
+    let form_setting = view_editor
+      .v_get_layout_settings(&DatabaseLayout::Grid)
+      .await
+      .form
+      .ok_or_else(|| {
+        FlowyError::record_not_found().with_context("Form layout setting not found")
+      })?;
+
+    for field_setting in form_setting.fields.iter() {
+      let is_missing = params
+        .cells
+        .get(&field_setting.field_id)
+        .map(|content| content.is_empty())
+        .unwrap_or(true);
+      if field_setting.required && field_setting.visible && is_missing {
+        return Err(FlowyError::invalid_data().with_context(format!(
+          "Missing required form field: {}",
+          field_setting.field_id
+        )));
+      }
+    }
+
+    // Only accept values for fields that are actually on the form.
+    let mut cells = params.cells;
+    cells.retain(|field_id, _| {
+      form_setting
+        .fields
+        .iter()
+        .any(|field| field.visible && field.field_id == *field_id)
+    });
+
+    self
+      .create_row(CreateRowPayloadPB {
+        view_id: params.view_id,
+        row_position: OrderObjectPositionPB::default(),
+        group_id: None,
+        data: cells,
+      })
+      .await
+  }
+
   pub async fn create_row(&self, params: CreateRowPayloadPB) -> FlowyResult<Option<RowDetail>> {
+    self.create_row_inner(params, true).await
+  }
+
+  /// Same as [Self::create_row], but doesn't emit an automation event so a
+  /// create-row action can't re-trigger automations.
+  pub(crate) async fn create_row_from_automation(
+    &self,
+    params: CreateRowPayloadPB,
+  ) -> FlowyResult<Option<RowDetail>> {
+    self.create_row_inner(params, false).await
+  }
+
+  async fn create_row_inner(
+    &self,
+    params: CreateRowPayloadPB,
+    emit_automation: bool,
+  ) -> FlowyResult<Option<RowDetail>> {
+    let view_id = params.view_id.clone();
     let view_editor = self
       .database_views
       .get_or_init_view_editor(&params.view_id)
       .await?;
 
-    let params = view_editor.v_will_create_row(params).await?;
+    let mut params = view_editor.v_will_create_row(params).await?;
+    // Prefill the new row from the database's default template when no cell
+    // data was provided.
+    if params.cells.is_empty() {
+      if let Some(cells) = self.default_template_cells() {
+        params.cells = cells;
+      }
+    }
 
     let mut database = self.database.write().await;
     let (index, row_order) = database
@@ -695,6 +1034,14 @@ impl DatabaseEditor {
     drop(database);
 
     trace!("[Database]: did create row: {} at {}", row_order.id, index);
+    if emit_automation {
+      self.emit_automation_event(
+        &view_id,
+        AutomationTrigger::RowCreated {
+          row_id: row_order.id.clone(),
+        },
+      );
+    }
     if let Some(row_detail) = row_detail {
       trace!("created row: {:?} at {}", row_detail, index);
       return Ok(Some(row_detail));
@@ -781,6 +1128,89 @@ impl DatabaseEditor {
     Ok(view_editor.v_get_all_rows().await)
   }
 
+  /// Returns the rows of the view whose cells match all the given predicates,
+  /// paginated. Predicates are evaluated against the stringified cell values
+  /// so they work for any field type, without touching the filters of the
+  /// view.
+  pub async fn query_rows(
+    &self,
+    view_id: &str,
+    predicates: &[RowPredicatePB],
+    limit: i64,
+    offset: i64,
+  ) -> FlowyResult<QueryRowsResultPB> {
+    let fields = self
+      .get_fields(view_id, None)
+      .await
+      .into_iter()
+      .map(|field| (field.id.clone(), field))
+      .collect::<HashMap<String, Field>>();
+    for predicate in predicates {
+      if !fields.contains_key(&predicate.field_id) {
+        return Err(
+          FlowyError::record_not_found()
+            .with_context(format!("Field {} not found", predicate.field_id)),
+        );
+      }
+    }
+
+    let matched = self
+      .get_all_rows(view_id)
+      .await?
+      .into_iter()
+      .filter(|row| {
+        predicates
+          .iter()
+          .all(|predicate| row_matches_predicate(row, &fields[&predicate.field_id], predicate))
+      })
+      .collect::<Vec<_>>();
+
+    let offset = offset.max(0) as usize;
+    let has_more = limit > 0 && offset + (limit as usize) < matched.len();
+    let page = matched
+      .into_iter()
+      .skip(offset)
+      .take(if limit > 0 { limit as usize } else { usize::MAX });
+
+    let mut rows = vec![];
+    for row in page {
+      if let Some(row_meta) = self.get_row_meta(view_id, &row.id).await {
+        rows.push(row_meta);
+      }
+    }
+    Ok(QueryRowsResultPB { rows, has_more })
+  }
+
+  /// Returns one page of the visible rows of the view so the frontend can
+  /// virtualize huge grids. The requested window is remembered by the view:
+  /// subsequent row changes trigger a
+  /// [DatabaseNotification::DidUpdateRowWindow] notification so the frontend
+  /// can refetch the page.
+  pub async fn get_rows_page(
+    &self,
+    view_id: &str,
+    offset: i64,
+    limit: i64,
+  ) -> FlowyResult<RowsPagePB> {
+    let view_editor = self.database_views.get_or_init_view_editor(view_id).await?;
+    let offset = offset.max(0) as usize;
+    let limit = if limit > 0 { limit as usize } else { usize::MAX };
+    let (page, total) = view_editor.v_get_rows_page(offset, limit).await;
+    let has_more = offset + page.len() < total;
+
+    let mut rows = vec![];
+    for row in page {
+      if let Some(row_meta) = self.get_row_meta(view_id, &row.id).await {
+        rows.push(row_meta);
+      }
+    }
+    Ok(RowsPagePB {
+      rows,
+      total: total as i64,
+      has_more,
+    })
+  }
+
   pub async fn get_row(&self, view_id: &str, row_id: &RowId) -> Option<Row> {
     let database = self.database.read().await;
     if database.contains_row(view_id, row_id) {
@@ -842,6 +1272,10 @@ impl DatabaseEditor {
     if database.contains_row(view_id, row_id) {
       let row_meta = database.get_row_meta(row_id).await?;
       let row_document_id = database.get_row_document_id(row_id)?;
+      let comment_count = self
+        .user_db_connection()
+        .ok()
+        .and_then(|mut conn| count_row_comments(&mut conn, row_id.as_str()).ok());
       Some(RowMetaPB {
         id: row_id.clone().into_inner(),
         document_id: Some(row_document_id),
@@ -849,6 +1283,7 @@ impl DatabaseEditor {
         is_document_empty: Some(row_meta.is_document_empty),
         attachment_count: Some(row_meta.attachment_count),
         cover: row_meta.cover.map(|cover| cover.into()),
+        comment_count,
       })
     } else {
       warn!(
@@ -893,6 +1328,166 @@ impl DatabaseEditor {
     }
   }
 
+  /// Returns a sqlite connection scoped to the current user. The row comments,
+  /// the row history and the automations are stored locally instead of inside
+  /// the database collab.
+  fn user_db_connection(&self) -> FlowyResult<DBConnection> {
+    let uid = self.user.user_id()?;
+    self.user.sqlite_connection(uid)
+  }
+
+  /// Installs the sender the automation runner listens on. Called by the
+  /// manager when the editor is opened.
+  pub(crate) fn set_automation_tx(&self, tx: UnboundedSender<AutomationEvent>) {
+    self.automation_tx.store(Some(Arc::new(tx)));
+  }
+
+  /// Installs the resolver used to read cells of related databases. Called by
+  /// the manager when the editor is opened.
+  pub(crate) fn set_relation_resolver(&self, resolver: Arc<RelationCellResolver>) {
+    self.relation_resolver.store(Some(resolver));
+  }
+
+  /// Forwards a change to the automation runner. Events are dropped silently
+  /// when no runner is installed.
+  fn emit_automation_event(&self, view_id: &str, trigger: AutomationTrigger) {
+    if let Some(tx) = self.automation_tx.load_full() {
+      let _ = tx.send(AutomationEvent {
+        database_id: self.database_id.to_string(),
+        view_id: view_id.to_string(),
+        trigger,
+      });
+    }
+  }
+
+  pub async fn add_automation(&self, params: AddAutomationParams) -> FlowyResult<AutomationPB> {
+    let automation = AutomationTable::new(
+      self.database_id.to_string(),
+      params.trigger_type as i32,
+      params.trigger_config,
+      params.action_type as i32,
+      params.action_config,
+    );
+    let mut conn = self.user_db_connection()?;
+    insert_automation(&mut conn, &automation)?;
+    Ok(AutomationPB::from(automation))
+  }
+
+  pub async fn get_automations(&self) -> FlowyResult<RepeatedAutomationPB> {
+    let mut conn = self.user_db_connection()?;
+    let items = select_automations(&mut conn, &self.database_id.to_string())?
+      .into_iter()
+      .map(AutomationPB::from)
+      .collect();
+    Ok(RepeatedAutomationPB { items })
+  }
+
+  pub async fn delete_automation(&self, trigger_id: &str) -> FlowyResult<()> {
+    let mut conn = self.user_db_connection()?;
+    delete_automation(&mut conn, trigger_id)
+  }
+
+  pub async fn set_automation_enabled(&self, trigger_id: &str, enabled: bool) -> FlowyResult<()> {
+    let mut conn = self.user_db_connection()?;
+    set_automation_enabled(&mut conn, trigger_id, enabled)
+  }
+
+  fn notify_did_update_row_comments(&self, changeset: RowCommentChangesetPB) {
+    let row_id = changeset.row_id.clone();
+    database_notification_builder(&row_id, DatabaseNotification::DidUpdateRowComments)
+      .payload(changeset)
+      .send();
+  }
+
+  pub async fn add_row_comment(
+    &self,
+    row_id: &str,
+    content: String,
+    reply_comment_id: Option<String>,
+  ) -> FlowyResult<RowCommentPB> {
+    let uid = self.user.user_id()?;
+    let mut conn = self.user_db_connection()?;
+    if let Some(reply_comment_id) = reply_comment_id.as_deref() {
+      // Replying to a reply is not supported; the thread is one level deep.
+      let parent = select_row_comment(&mut conn, reply_comment_id)?;
+      if parent.reply_comment_id.is_some() {
+        return Err(
+          FlowyError::invalid_data()
+            .with_context("can't reply to a comment that is itself a reply"),
+        );
+      }
+    }
+    let comment = RowCommentTable::new(row_id.to_string(), uid, content, reply_comment_id);
+    insert_row_comment(&mut conn, &comment)?;
+
+    let comment_pb = RowCommentPB::from(comment);
+    self.notify_did_update_row_comments(RowCommentChangesetPB {
+      row_id: row_id.to_string(),
+      inserted_comments: vec![comment_pb.clone()],
+      ..Default::default()
+    });
+    Ok(comment_pb)
+  }
+
+  pub async fn update_row_comment(&self, comment_id: &str, content: String) -> FlowyResult<()> {
+    let uid = self.user.user_id()?;
+    let mut conn = self.user_db_connection()?;
+    let comment = update_row_comment_content(&mut conn, comment_id, uid, &content)?;
+
+    let row_id = comment.row_id.clone();
+    self.notify_did_update_row_comments(RowCommentChangesetPB {
+      row_id,
+      updated_comments: vec![RowCommentPB::from(comment)],
+      ..Default::default()
+    });
+    Ok(())
+  }
+
+  pub async fn delete_row_comment(&self, comment_id: &str) -> FlowyResult<()> {
+    let mut conn = self.user_db_connection()?;
+    let comment = select_row_comment(&mut conn, comment_id)?;
+    let deleted_comment_ids = delete_row_comment(&mut conn, comment_id)?;
+
+    self.notify_did_update_row_comments(RowCommentChangesetPB {
+      row_id: comment.row_id,
+      deleted_comment_ids,
+      ..Default::default()
+    });
+    Ok(())
+  }
+
+  pub async fn toggle_row_comment_reaction(
+    &self,
+    comment_id: &str,
+    emoji: &str,
+  ) -> FlowyResult<()> {
+    let uid = self.user.user_id()?;
+    let mut conn = self.user_db_connection()?;
+    let comment = toggle_row_comment_reaction(&mut conn, comment_id, uid, emoji)?;
+
+    let row_id = comment.row_id.clone();
+    self.notify_did_update_row_comments(RowCommentChangesetPB {
+      row_id,
+      updated_comments: vec![RowCommentPB::from(comment)],
+      ..Default::default()
+    });
+    Ok(())
+  }
+
+  pub async fn get_row_comments(
+    &self,
+    row_id: &str,
+    limit: i64,
+    before: Option<i64>,
+  ) -> FlowyResult<RepeatedRowCommentPB> {
+    let mut conn = self.user_db_connection()?;
+    let (comments, has_more) = select_row_comments(&mut conn, row_id, limit, before)?;
+    Ok(RepeatedRowCommentPB {
+      items: comments.into_iter().map(RowCommentPB::from).collect(),
+      has_more,
+    })
+  }
+
   pub async fn get_cell(&self, field_id: &str, row_id: &RowId) -> Option<Cell> {
     let database = self.database.read().await;
     let field = database.get_field(field_id)?;
@@ -920,7 +1515,17 @@ impl DatabaseEditor {
     };
 
     let field_type = FieldType::from(field.field_type);
-    let cell_bytes = get_cell_protobuf(&cell, &field, Some(self.cell_cache.clone()));
+    // Media cells are enriched with the locally extracted file metadata so
+    // gallery and card covers can render without probing the files.
+    let cell_bytes = match field_type {
+      FieldType::Media => self.media_cell_protobuf(&cell, &field),
+      // URL cells are enriched with the cached link preview so grids can show
+      // rich previews instead of raw URLs.
+      FieldType::URL => self.url_cell_protobuf(&cell, &field),
+      // Time cells expose whether a tracking timer is currently running.
+      FieldType::Time => self.time_cell_protobuf(&cell, &field),
+      _ => get_cell_protobuf(&cell, &field, Some(self.cell_cache.clone())),
+    };
     Some(CellPB {
       field_id: field_id.to_string(),
       row_id: row_id.clone().into(),
@@ -929,6 +1534,164 @@ impl DatabaseEditor {
     })
   }
 
+  /// Builds the protobuf of a media cell, filling in the stored metadata of
+  /// each file. Files whose metadata hasn't been extracted yet are returned
+  /// without it.
+  fn media_cell_protobuf(&self, cell: &Cell, field: &Field) -> CellProtobufBlob {
+    let mut data = MediaCellDataPB::from(MediaCellData::from(cell));
+    let file_ids: Vec<String> = data.files.iter().map(|file| file.id.clone()).collect();
+    if !file_ids.is_empty() {
+      if let Ok(mut conn) = self.user_db_connection() {
+        if let Ok(metas) = select_media_file_metas(&mut conn, &file_ids) {
+          apply_media_file_metadata(&mut data.files, metas);
+        }
+      }
+    }
+    CellProtobufBlob::from(data)
+      .unwrap_or_else(|_| get_cell_protobuf(cell, field, Some(self.cell_cache.clone())))
+  }
+
+  /// Builds the protobuf of a time cell, exposing whether a tracking timer is
+  /// currently running on it and since when.
+  fn time_cell_protobuf(&self, cell: &Cell, field: &Field) -> CellProtobufBlob {
+    let started_at = get_time_tracking_started_at(cell);
+    let data = TimeCellDataPB {
+      time: TimeCellData::from(cell).0.unwrap_or_default(),
+      is_tracking: started_at.is_some(),
+      tracking_started_at: started_at,
+    };
+    CellProtobufBlob::from(data)
+      .unwrap_or_else(|_| get_cell_protobuf(cell, field, Some(self.cell_cache.clone())))
+  }
+
+  /// Builds the protobuf of a URL cell, attaching the cached link preview.
+  /// When no preview has been fetched yet, a background fetch is kicked off
+  /// so the preview is available the next time the cell is read.
+  fn url_cell_protobuf(&self, cell: &Cell, field: &Field) -> CellProtobufBlob {
+    let mut data = URLCellDataPB::from(URLCellData::from(cell));
+    let url = data.content.trim().to_string();
+    if !url.is_empty() {
+      match self
+        .user_db_connection()
+        .and_then(|mut conn| select_url_preview(&mut conn, &url))
+      {
+        Ok(Some(preview)) => data.preview = Some(URLPreviewPB::from(preview)),
+        Ok(None) => self.fetch_url_preview_in_background(url),
+        Err(_) => {},
+      }
+    }
+    CellProtobufBlob::from(data)
+      .unwrap_or_else(|_| get_cell_protobuf(cell, field, Some(self.cell_cache.clone())))
+  }
+
+  /// Fetches and caches the link preview of a URL in the background. Fetches
+  /// that can't reach the page (offline, timeout) cache nothing, so the URL
+  /// is retried on a later read.
+  fn fetch_url_preview_in_background(&self, url: String) {
+    let user = self.user.clone();
+    tokio::spawn(async move {
+      if let Some(preview) = fetch_url_preview(&url).await {
+        match user.user_id().and_then(|uid| user.sqlite_connection(uid)) {
+          Ok(mut conn) => {
+            if let Err(err) = upsert_url_preview(&mut conn, &preview) {
+              warn!("[UrlPreview]: failed to store preview of {}: {}", url, err);
+            }
+          },
+          Err(err) => warn!("[UrlPreview]: failed to open db connection: {}", err),
+        }
+      }
+    });
+  }
+
+  /// Extracts and stores the metadata of the given media files in the
+  /// background, skipping files whose metadata is already stored.
+  fn extract_media_file_meta_in_background(&self, files: Vec<MediaFile>) {
+    if files.is_empty() {
+      return;
+    }
+    let user = self.user.clone();
+    let database_id = self.database_id.to_string();
+    tokio::spawn(async move {
+      let file_ids: Vec<String> = files.iter().map(|file| file.id.clone()).collect();
+      let known_ids: HashSet<String> = user
+        .user_id()
+        .and_then(|uid| user.sqlite_connection(uid))
+        .and_then(|mut conn| select_media_file_metas(&mut conn, &file_ids))
+        .map(|metas| metas.into_iter().map(|meta| meta.file_id).collect())
+        .unwrap_or_default();
+      let files: Vec<MediaFile> = files
+        .into_iter()
+        .filter(|file| !known_ids.contains(&file.id))
+        .collect();
+      if files.is_empty() {
+        return;
+      }
+
+      // Probing and thumbnailing the files touches the filesystem, so keep it
+      // off the async runtime.
+      let metas = tokio::task::spawn_blocking(move || {
+        files
+          .iter()
+          .map(|file| extract_media_file_meta(&database_id, file))
+          .collect::<Vec<_>>()
+      })
+      .await
+      .unwrap_or_default();
+
+      match user.user_id().and_then(|uid| user.sqlite_connection(uid)) {
+        Ok(mut conn) => {
+          for meta in metas {
+            if let Err(err) = upsert_media_file_meta(&mut conn, &meta) {
+              warn!(
+                "[MediaMeta]: failed to store meta of file {}: {}",
+                meta.file_id, err
+              );
+            }
+          }
+        },
+        Err(err) => warn!("[MediaMeta]: failed to open db connection: {}", err),
+      }
+    });
+  }
+
+  /// Backfills the metadata of every media attachment of this database that
+  /// doesn't have one stored yet, so existing gallery and card covers render
+  /// instantly. Spawned by the manager when the database is opened.
+  pub(crate) async fn backfill_media_file_meta(&self) {
+    let media_field_ids: Vec<String> = {
+      let database = self.database.read().await;
+      database
+        .get_fields(None)
+        .into_iter()
+        .filter(|field| FieldType::from(field.field_type) == FieldType::Media)
+        .map(|field| field.id)
+        .collect()
+    };
+    if media_field_ids.is_empty() {
+      return;
+    }
+
+    let mut files: Vec<MediaFile> = vec![];
+    {
+      let database = self.database.read().await;
+      let rows_stream = database.get_all_rows(10, None).await;
+      pin_mut!(rows_stream);
+      while let Some(result) = rows_stream.next().await {
+        if let Ok(row) = result {
+          for field_id in &media_field_ids {
+            if let Some(cell) = row.cells.get(field_id) {
+              files.extend(MediaCellData::from(cell).files);
+            }
+          }
+        }
+      }
+    }
+
+    let mut seen = HashSet::new();
+    files.retain(|file| seen.insert(file.id.clone()));
+    self.extract_media_file_meta_in_background(files);
+  }
+
   pub async fn get_cells_for_field(&self, view_id: &str, field_id: &str) -> Vec<RowCell> {
     let database = self.database.read().await;
     if let Some(field) = database.get_field(field_id) {
@@ -971,6 +1734,33 @@ impl DatabaseEditor {
     row_id: &RowId,
     field_id: &str,
     cell_changeset: BoxAny,
+  ) -> FlowyResult<()> {
+    self
+      .update_cell_with_changeset_inner(view_id, row_id, field_id, cell_changeset, true)
+      .await
+  }
+
+  /// Same as [Self::update_cell_with_changeset], but doesn't emit an
+  /// automation event so a set-cell action can't re-trigger automations.
+  pub(crate) async fn update_cell_with_changeset_from_automation(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    cell_changeset: BoxAny,
+  ) -> FlowyResult<()> {
+    self
+      .update_cell_with_changeset_inner(view_id, row_id, field_id, cell_changeset, false)
+      .await
+  }
+
+  async fn update_cell_with_changeset_inner(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    cell_changeset: BoxAny,
+    emit_automation: bool,
   ) -> FlowyResult<()> {
     let (field, cell) = {
       let database = self.database.read().await;
@@ -986,7 +1776,9 @@ impl DatabaseEditor {
 
     let new_cell =
       apply_cell_changeset(cell_changeset, cell, &field, Some(self.cell_cache.clone()))?;
-    self.update_cell(view_id, row_id, field_id, new_cell).await
+    self
+      .update_cell_inner(view_id, row_id, field_id, new_cell, emit_automation)
+      .await
   }
 
   /// Update a cell in the database.
@@ -998,24 +1790,282 @@ impl DatabaseEditor {
     row_id: &RowId,
     field_id: &str,
     new_cell: Cell,
+  ) -> FlowyResult<()> {
+    self
+      .update_cell_inner(view_id, row_id, field_id, new_cell, true)
+      .await
+  }
+
+  async fn update_cell_inner(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    new_cell: Cell,
+    emit_automation: bool,
   ) -> FlowyResult<()> {
     // Get the old row before updating the cell. It would be better to get the old cell
     let old_row = self.get_row(view_id, row_id).await;
     trace!("[Database Row]: update cell: {:?}", new_cell);
+    let old_cell = old_row
+      .as_ref()
+      .and_then(|row| row.cells.get(field_id).cloned());
     self
       .update_row(row_id.clone(), |row_update| {
         row_update
           .set_last_modified(timestamp())
           .update_cells(|cell_update| {
-            cell_update.insert(field_id, new_cell);
+            cell_update.insert(field_id, new_cell.clone());
           });
       })
       .await?;
 
-    self
-      .did_update_row(view_id, row_id, field_id, old_row)
-      .await;
+    // Newly attached media files get their metadata extracted right away so
+    // covers render instantly the next time the cell is read.
+    if get_field_type_from_cell::<FieldType>(&new_cell) == Some(FieldType::Media) {
+      self.extract_media_file_meta_in_background(MediaCellData::from(&new_cell).files);
+    }
+
+    // Only the user's own edits are undoable; automation writes are system
+    // changes and stay off the undo stack.
+    if emit_automation {
+      self.cell_undo.push(CellEditRecord {
+        row_id: row_id.clone(),
+        field_id: field_id.to_string(),
+        before: old_cell.clone(),
+        after: Some(new_cell.clone()),
+      });
+    }
+
+    self.record_cell_change(row_id, field_id, old_cell, Some(new_cell));
+    self
+      .did_update_row(view_id, row_id, field_id, old_row)
+      .await;
+
+    if emit_automation {
+      self.emit_automation_event(
+        view_id,
+        AutomationTrigger::CellChanged {
+          row_id: row_id.clone(),
+          field_id: field_id.to_string(),
+        },
+      );
+    }
+
+    Ok(())
+  }
+
+  /// Saves the cells and the meta of the row as a reusable template for this
+  /// database.
+  pub async fn save_row_as_template(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    name: Option<String>,
+  ) -> FlowyResult<RowTemplatePB> {
+    let row = self.get_row(view_id, row_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("row:{} is not found", row_id))
+    })?;
+
+    let (row_meta, document_id, primary_field) = {
+      let database = self.database.read().await;
+      (
+        database.get_row_meta(row_id).await,
+        database.get_row_document_id(row_id),
+        database.get_primary_field(),
+      )
+    };
+
+    let name = name.unwrap_or_else(|| {
+      primary_field
+        .and_then(|field| {
+          row
+            .cells
+            .get(&field.id)
+            .map(|cell| stringify_cell(cell, &field))
+        })
+        .unwrap_or_default()
+    });
+    let cells =
+      serde_json::to_string(&row.cells).map_err(|err| FlowyError::serde().with_context(err))?;
+    let (icon, cover) = match row_meta {
+      Some(meta) => (
+        meta.icon_url,
+        meta
+          .cover
+          .and_then(|cover| serde_json::to_string(&RowCoverPB::from(cover)).ok()),
+      ),
+      None => (None, None),
+    };
+
+    let template = RowTemplateTable::new(
+      self.database_id.to_string(),
+      name,
+      cells,
+      icon,
+      cover,
+      document_id,
+    );
+    let mut conn = self.user_db_connection()?;
+    insert_row_template(&mut conn, &template)?;
+    Ok(RowTemplatePB::from(template))
+  }
+
+  /// Creates a row prefilled with the cells and the meta captured in the
+  /// template. The document content of the template's source row is copied by
+  /// the caller using the document ids exposed in [RowTemplatePB] and the
+  /// returned meta.
+  pub async fn create_row_from_template(
+    &self,
+    view_id: &str,
+    template_id: &str,
+  ) -> FlowyResult<RowMetaPB> {
+    let template = {
+      let mut conn = self.user_db_connection()?;
+      select_row_template(&mut conn, template_id)?
+    };
+    let cells = serde_json::from_str::<Cells>(&template.cells)
+      .map_err(|err| FlowyError::serde().with_context(err))?;
+
+    let mut database = self.database.write().await;
+    let mut params = CreateRowParams::new(gen_row_id(), self.database_id.to_string());
+    params.cells = cells;
+    let (index, row_order) = database.create_row_in_view(view_id, params).await?;
+    trace!(
+      "[Database]: created row: {} from template: {} at {}",
+      row_order.id, template_id, index
+    );
+
+    let icon = template.icon;
+    let cover = template
+      .cover
+      .and_then(|cover| serde_json::from_str::<RowCoverPB>(&cover).ok())
+      .map(RowCover::from);
+    if icon.is_some() || cover.is_some() {
+      database
+        .update_row_meta(&row_order.id, |meta_update| {
+          meta_update
+            .insert_cover_if_not_none(cover)
+            .insert_icon_if_not_none(icon);
+        })
+        .await;
+    }
+    drop(database);
+
+    self
+      .get_row_meta(view_id, &row_order.id)
+      .await
+      .ok_or_else(|| FlowyError::internal().with_context("error while creating row from template"))
+  }
+
+  pub async fn get_row_templates(&self) -> FlowyResult<RepeatedRowTemplatePB> {
+    let mut conn = self.user_db_connection()?;
+    let templates = select_row_templates(&mut conn, &self.database_id.to_string())?;
+    Ok(RepeatedRowTemplatePB {
+      items: templates.into_iter().map(RowTemplatePB::from).collect(),
+    })
+  }
+
+  pub async fn delete_row_template(&self, template_id: &str) -> FlowyResult<()> {
+    let mut conn = self.user_db_connection()?;
+    delete_row_template(&mut conn, template_id)
+  }
+
+  /// Marks the template as the default one: its cells are used to prefill
+  /// newly created empty rows.
+  pub async fn set_default_row_template(&self, template_id: &str) -> FlowyResult<()> {
+    let mut conn = self.user_db_connection()?;
+    set_default_row_template(&mut conn, &self.database_id.to_string(), template_id)
+  }
+
+  /// Returns the cells of the database's default row template, if one is set.
+  fn default_template_cells(&self) -> Option<Cells> {
+    let mut conn = self.user_db_connection().ok()?;
+    let template = select_default_row_template(&mut conn, &self.database_id.to_string()).ok()??;
+    serde_json::from_str::<Cells>(&template.cells).ok()
+  }
+
+  /// Records a cell-level change into the row history so the row can be
+  /// inspected and reverted later. Failing to record the change must not fail
+  /// the edit, so the error is only logged.
+  fn record_cell_change(
+    &self,
+    row_id: &RowId,
+    field_id: &str,
+    old_cell: Option<Cell>,
+    new_cell: Option<Cell>,
+  ) {
+    let serialize = |cell: Option<Cell>| {
+      cell
+        .and_then(|cell| serde_json::to_string(&cell).ok())
+        .unwrap_or_default()
+    };
+    let old_value = serialize(old_cell);
+    let new_value = serialize(new_cell);
+    if old_value == new_value {
+      return;
+    }
+
+    let result = self.user.user_id().and_then(|uid| {
+      let mut conn = self.user.sqlite_connection(uid)?;
+      let change = RowHistoryChange::new(
+        row_id.clone().into_inner(),
+        field_id.to_string(),
+        uid,
+        old_value,
+        new_value,
+      );
+      insert_row_history(&mut conn, &change)
+    });
+    if let Err(err) = result {
+      warn!("[Database Row]: failed to record row history: {}", err);
+    }
+  }
+
+  /// Returns the most recent cell-level changes of the row, newest first.
+  pub async fn get_row_history(
+    &self,
+    row_id: &str,
+    limit: i64,
+  ) -> FlowyResult<RepeatedRowHistoryPB> {
+    let mut conn = self.user_db_connection()?;
+    let entries = select_row_history(&mut conn, row_id, limit)?;
+    Ok(RepeatedRowHistoryPB {
+      items: entries.into_iter().map(RowHistoryPB::from).collect(),
+    })
+  }
+
+  /// Reverts the cells of the row to the values they had at the given history
+  /// version by undoing every change recorded after it. The revert goes
+  /// through the regular cell write path, so it is recorded in the history and
+  /// can be undone as well.
+  pub async fn revert_row_to(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    version: i64,
+  ) -> FlowyResult<()> {
+    let entries = {
+      let mut conn = self.user_db_connection()?;
+      select_row_history_after(&mut conn, row_id.as_str(), version)?
+    };
+
+    // For each field, the earliest change after the version holds the value
+    // the cell had at that point in time.
+    let mut cells: HashMap<String, String> = HashMap::new();
+    for entry in entries {
+      cells.entry(entry.field_id).or_insert(entry.old_value);
+    }
 
+    for (field_id, old_value) in cells {
+      let cell = if old_value.is_empty() {
+        Cell::default()
+      } else {
+        serde_json::from_str::<Cell>(&old_value)
+          .map_err(|err| FlowyError::serde().with_context(err))?
+      };
+      self.update_cell(view_id, row_id, &field_id, cell).await?;
+    }
     Ok(())
   }
 
@@ -1037,6 +2087,9 @@ impl DatabaseEditor {
   pub async fn clear_cell(&self, view_id: &str, row_id: RowId, field_id: &str) -> FlowyResult<()> {
     // Get the old row before updating the cell. It would be better to get the old cell
     let old_row = self.get_row(view_id, &row_id).await;
+    let old_cell = old_row
+      .as_ref()
+      .and_then(|row| row.cells.get(field_id).cloned());
     self
       .update_row(row_id.clone(), |row_update| {
         row_update
@@ -1047,6 +2100,15 @@ impl DatabaseEditor {
       })
       .await?;
 
+    if old_cell.is_some() {
+      self.cell_undo.push(CellEditRecord {
+        row_id: row_id.clone(),
+        field_id: field_id.to_string(),
+        before: old_cell,
+        after: None,
+      });
+    }
+
     self
       .did_update_row(view_id, &row_id, field_id, old_row)
       .await;
@@ -1054,6 +2116,124 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Starts a time-tracking timer on the cell. Fails when a timer is already
+  /// running on it.
+  pub async fn start_time_tracking(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+  ) -> FlowyResult<()> {
+    self.require_time_field(field_id).await?;
+    let cell = self.get_cell(field_id, row_id).await;
+    let new_cell = start_time_session(cell.as_ref(), timestamp())?;
+    self.update_cell(view_id, row_id, field_id, new_cell).await
+  }
+
+  /// Stops the running time-tracking timer on the cell, appending the finished
+  /// session and adding its duration to the cell's total time.
+  pub async fn stop_time_tracking(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+  ) -> FlowyResult<()> {
+    self.require_time_field(field_id).await?;
+    let cell = self.get_cell(field_id, row_id).await;
+    let new_cell = stop_time_session(cell.as_ref(), timestamp())?;
+    self.update_cell(view_id, row_id, field_id, new_cell).await
+  }
+
+  async fn require_time_field(&self, field_id: &str) -> FlowyResult<()> {
+    let field = self.get_field(field_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", field_id))
+    })?;
+    if FieldType::from(field.field_type) != FieldType::Time {
+      return Err(
+        FlowyError::invalid_data().with_context("Time tracking is only supported on time fields"),
+      );
+    }
+    Ok(())
+  }
+
+  /// Reverts the most recent cell edit made on this device. Edits whose cell
+  /// has since been changed by another collaborator are skipped and dropped,
+  /// so undo never reverts someone else's change.
+  pub async fn undo_cell_edit(&self, view_id: &str) -> FlowyResult<()> {
+    while let Some(record) = self.cell_undo.pop_undo() {
+      let current = self.get_cell(&record.field_id, &record.row_id).await;
+      if current != record.after {
+        trace!(
+          "[Database Row]: skip undo for field:{}, the cell was changed by another collaborator",
+          record.field_id
+        );
+        continue;
+      }
+      self
+        .apply_cell_for_undo(view_id, &record.row_id, &record.field_id, record.before.clone())
+        .await?;
+      self.cell_undo.push_redo(record);
+      return Ok(());
+    }
+    Err(FlowyError::record_not_found().with_context("There is no cell edit to undo"))
+  }
+
+  /// Re-applies the most recently undone cell edit, subject to the same
+  /// collaboration check as [Self::undo_cell_edit].
+  pub async fn redo_cell_edit(&self, view_id: &str) -> FlowyResult<()> {
+    while let Some(record) = self.cell_undo.pop_redo() {
+      let current = self.get_cell(&record.field_id, &record.row_id).await;
+      if current != record.before {
+        trace!(
+          "[Database Row]: skip redo for field:{}, the cell was changed by another collaborator",
+          record.field_id
+        );
+        continue;
+      }
+      self
+        .apply_cell_for_undo(view_id, &record.row_id, &record.field_id, record.after.clone())
+        .await?;
+      self.cell_undo.push_undo(record);
+      return Ok(());
+    }
+    Err(FlowyError::record_not_found().with_context("There is no cell edit to redo"))
+  }
+
+  /// Writes a cell on behalf of undo/redo. The change is recorded in the row
+  /// history but not pushed on the undo stack again, and no automation event
+  /// is emitted.
+  async fn apply_cell_for_undo(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    cell: Option<Cell>,
+  ) -> FlowyResult<()> {
+    let old_row = self.get_row(view_id, row_id).await;
+    let old_cell = old_row
+      .as_ref()
+      .and_then(|row| row.cells.get(field_id).cloned());
+    let new_cell = cell.clone();
+    self
+      .update_row(row_id.clone(), |row_update| {
+        row_update
+          .set_last_modified(timestamp())
+          .update_cells(|cell_update| {
+            match cell {
+              Some(cell) => cell_update.insert(field_id, cell),
+              None => cell_update.clear(field_id),
+            };
+          });
+      })
+      .await?;
+
+    self.record_cell_change(row_id, field_id, old_cell, new_cell);
+    self
+      .did_update_row(view_id, row_id, field_id, old_row)
+      .await;
+    Ok(())
+  }
+
   async fn did_update_row(
     &self,
     view_id: &str,
@@ -1217,13 +2397,20 @@ impl DatabaseEditor {
       insert_option_ids: options.iter().map(|option| option.id.clone()).collect(),
       ..Default::default()
     };
+    let mut option_meta = select_option_meta_from_field(&field);
+    option_meta.extend(select_option_meta_from_pb_options(&options));
     options
       .into_iter()
       .for_each(|option| type_option.insert_option(option.into()));
 
     // Update the field's type option
     let view_editors = self.database_views.editors().await;
-    update_field_type_option_fn(&mut database, type_option.to_type_option_data(), &field).await?;
+    update_field_type_option_fn(
+      &mut database,
+      select_type_option_data_with_meta(type_option.as_ref(), option_meta),
+      &field,
+    )
+    .await?;
     drop(database);
 
     for view_editor in view_editors {
@@ -1263,7 +2450,15 @@ impl DatabaseEditor {
     }
 
     let view_editors = self.database_views.editors().await;
-    update_field_type_option_fn(&mut database, type_option.to_type_option_data(), &field).await?;
+    update_field_type_option_fn(
+      &mut database,
+      select_type_option_data_with_meta(
+        type_option.as_ref(),
+        select_option_meta_from_field(&field),
+      ),
+      &field,
+    )
+    .await?;
 
     // Drop the database write lock ASAP
     drop(database);
@@ -1278,6 +2473,187 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Returns the options of the select field together with the number of
+  /// rows of the database that currently use each of them.
+  pub async fn get_select_option_usage(
+    &self,
+    field_id: &str,
+  ) -> FlowyResult<RepeatedSelectOptionUsagePB> {
+    let database = self.database.read().await;
+    let field = database.get_field(field_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", &field_id))
+    })?;
+    let type_option = select_type_option_from_field(&field)?;
+    let options = type_option.options().clone();
+
+    let mut count_by_option_id = options
+      .iter()
+      .map(|option| (option.id.clone(), 0i64))
+      .collect::<HashMap<String, i64>>();
+    let rows_stream = database.get_all_rows(10, None).await;
+    pin_mut!(rows_stream);
+    while let Some(result) = rows_stream.next().await {
+      if let Ok(row) = result {
+        if let Some(cell) = row.cells.get(field_id) {
+          for option_id in SelectOptionIds::from(cell).into_inner() {
+            if let Some(count) = count_by_option_id.get_mut(&option_id) {
+              *count += 1;
+            }
+          }
+        }
+      }
+    }
+
+    let items = options
+      .into_iter()
+      .map(|option| {
+        let count = count_by_option_id.remove(&option.id).unwrap_or_default();
+        SelectOptionUsagePB {
+          option: SelectOptionPB::from(option),
+          count,
+        }
+      })
+      .collect();
+    Ok(RepeatedSelectOptionUsagePB { items })
+  }
+
+  /// Merges one select option into another: every cell referencing the
+  /// absorbed option is rewritten to reference the target option, then the
+  /// absorbed option is removed from the field.
+  pub async fn merge_select_options(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    from_option_id: &str,
+    into_option_id: &str,
+  ) -> FlowyResult<()> {
+    if from_option_id == into_option_id {
+      return Err(
+        FlowyError::invalid_data().with_context("Cannot merge a select option into itself"),
+      );
+    }
+
+    let row_ids = {
+      let database = self.database.read().await;
+      let field = database.get_field(field_id).ok_or_else(|| {
+        FlowyError::record_not_found()
+          .with_context(format!("Field with id:{} not found", &field_id))
+      })?;
+      let type_option = select_type_option_from_field(&field)?;
+      for option_id in [from_option_id, into_option_id] {
+        if !type_option
+          .options()
+          .iter()
+          .any(|option| option.id == option_id)
+        {
+          return Err(
+            FlowyError::record_not_found()
+              .with_context(format!("Select option with id:{} not found", option_id)),
+          );
+        }
+      }
+
+      let mut row_ids = vec![];
+      let rows_stream = database.get_all_rows(10, None).await;
+      pin_mut!(rows_stream);
+      while let Some(result) = rows_stream.next().await {
+        if let Ok(row) = result {
+          if let Some(cell) = row.cells.get(field_id) {
+            if SelectOptionIds::from(cell)
+              .into_inner()
+              .iter()
+              .any(|id| id == from_option_id)
+            {
+              row_ids.push(row.id.clone());
+            }
+          }
+        }
+      }
+      row_ids
+    };
+
+    // Rewrite the cells before touching the type option so the absorbed
+    // option is still valid while the changesets are applied.
+    for row_id in row_ids {
+      let cell_changeset = SelectOptionCellChangeset {
+        insert_option_ids: vec![into_option_id.to_string()],
+        delete_option_ids: vec![from_option_id.to_string()],
+      };
+      self
+        .update_cell_with_changeset(view_id, &row_id, field_id, BoxAny::new(cell_changeset))
+        .await?;
+    }
+
+    let mut database = self.database.write().await;
+    let field = database.get_field(field_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", &field_id))
+    })?;
+    let mut type_option = select_type_option_from_field(&field)?;
+    type_option.delete_option(from_option_id);
+
+    let view_editors = self.database_views.editors().await;
+    update_field_type_option_fn(
+      &mut database,
+      select_type_option_data_with_meta(
+        type_option.as_ref(),
+        select_option_meta_from_field(&field),
+      ),
+      &field,
+    )
+    .await?;
+    drop(database);
+
+    for view_editor in view_editors {
+      view_editor.v_did_update_field_type_option(&field).await?;
+    }
+    Ok(())
+  }
+
+  /// Deletes every option of the select field that is not referenced by any
+  /// row of the database. Returns the deleted options.
+  pub async fn delete_unused_select_options(
+    &self,
+    field_id: &str,
+  ) -> FlowyResult<Vec<SelectOptionPB>> {
+    let unused = self
+      .get_select_option_usage(field_id)
+      .await?
+      .items
+      .into_iter()
+      .filter(|usage| usage.count == 0)
+      .map(|usage| usage.option)
+      .collect::<Vec<_>>();
+    if unused.is_empty() {
+      return Ok(unused);
+    }
+
+    let mut database = self.database.write().await;
+    let field = database.get_field(field_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", &field_id))
+    })?;
+    let mut type_option = select_type_option_from_field(&field)?;
+    for option in unused.iter() {
+      type_option.delete_option(&option.id);
+    }
+
+    let view_editors = self.database_views.editors().await;
+    update_field_type_option_fn(
+      &mut database,
+      select_type_option_data_with_meta(
+        type_option.as_ref(),
+        select_option_meta_from_field(&field),
+      ),
+      &field,
+    )
+    .await?;
+    drop(database);
+
+    for view_editor in view_editors {
+      view_editor.v_did_update_field_type_option(&field).await?;
+    }
+    Ok(unused)
+  }
+
   pub async fn set_checklist_options(
     &self,
     view_id: &str,
@@ -1371,9 +2747,24 @@ impl DatabaseEditor {
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
-  pub async fn get_all_calendar_events(&self, view_id: &str) -> Vec<CalendarEventPB> {
+  pub async fn get_all_calendar_events(
+    &self,
+    view_id: &str,
+    range: Option<(i64, i64)>,
+  ) -> Vec<CalendarEventPB> {
+    match self.database_views.get_or_init_view_editor(view_id).await {
+      Ok(view) => view.v_get_all_calendar_events(range).await.unwrap_or_default(),
+      Err(_) => {
+        warn!("Can not find the view: {}", view_id);
+        vec![]
+      },
+    }
+  }
+
+  #[tracing::instrument(level = "trace", skip_all)]
+  pub async fn get_all_timeline_events(&self, view_id: &str) -> Vec<TimelineEventPB> {
     match self.database_views.get_or_init_view_editor(view_id).await {
-      Ok(view) => view.v_get_all_calendar_events().await.unwrap_or_default(),
+      Ok(view) => view.v_get_all_timeline_events().await.unwrap_or_default(),
       Err(_) => {
         warn!("Can not find the view: {}", view_id);
         vec![]
@@ -1709,6 +3100,103 @@ impl DatabaseEditor {
     Ok(csv)
   }
 
+  /// Exports the database as seen through the given view: the active filters
+  /// and sorts are applied and only visible fields are included, in their
+  /// display order.
+  pub async fn export_view_csv(&self, view_id: &str, style: CSVFormat) -> FlowyResult<String> {
+    let rows = self.get_all_rows(view_id).await?;
+    let fields = self.get_fields(view_id, None).await;
+    let field_ids = fields.iter().map(|field| field.id.clone()).collect();
+    let visibility = self
+      .get_field_settings(view_id, field_ids)
+      .await?
+      .into_iter()
+      .map(|settings| (settings.field_id, settings.visibility))
+      .collect::<HashMap<_, _>>();
+    let visible_fields = fields
+      .into_iter()
+      .filter(|field| {
+        visibility
+          .get(&field.id)
+          .map(|visibility| *visibility != FieldVisibility::AlwaysHidden)
+          .unwrap_or(true)
+      })
+      .collect::<Vec<_>>();
+
+    CSVExport.export_rows(visible_fields, rows, style)
+  }
+
+  /// Exports the database as seen through the given view as a GitHub-flavored
+  /// markdown table: the active filters and sorts are applied and only
+  /// visible fields are included, in their display order.
+  pub async fn export_view_markdown(&self, view_id: &str) -> FlowyResult<String> {
+    let rows = self.get_all_rows(view_id).await?;
+    let fields = self.get_fields(view_id, None).await;
+    let field_ids = fields.iter().map(|field| field.id.clone()).collect();
+    let visibility = self
+      .get_field_settings(view_id, field_ids)
+      .await?
+      .into_iter()
+      .map(|settings| (settings.field_id, settings.visibility))
+      .collect::<HashMap<_, _>>();
+    let visible_fields = fields
+      .into_iter()
+      .filter(|field| {
+        visibility
+          .get(&field.id)
+          .map(|visibility| *visibility != FieldVisibility::AlwaysHidden)
+          .unwrap_or(true)
+      })
+      .collect::<Vec<_>>();
+
+    Ok(MarkdownExport.export_rows(visible_fields, rows))
+  }
+
+  /// Exports the rows of a calendar view as an iCalendar (.ics) document,
+  /// using the date field configured in the calendar layout setting. The
+  /// active filters of the view are applied.
+  pub async fn export_calendar_ics(&self, view_id: &str) -> FlowyResult<String> {
+    let calendar_setting = self
+      .get_layout_setting(view_id, DatabaseLayout::Calendar)
+      .await
+      .and_then(|setting| setting.calendar)
+      .ok_or_else(|| {
+        FlowyError::record_not_found()
+          .with_context(format!("No calendar layout setting in view:{}", view_id))
+      })?;
+    let (primary_field, date_field) = {
+      let database = self.database.read().await;
+      let primary_field = database
+        .get_primary_field()
+        .ok_or_else(|| FlowyError::record_not_found().with_context("No primary field"))?;
+      let date_field = database.get_field(&calendar_setting.field_id).ok_or_else(|| {
+        FlowyError::record_not_found().with_context(format!(
+          "Date field:{} of the calendar is not found",
+          calendar_setting.field_id
+        ))
+      })?;
+      (primary_field, date_field)
+    };
+    let rows = self.get_all_rows(view_id).await?;
+    Ok(ICSExport.export_rows(&primary_field, &date_field, rows))
+  }
+
+  /// Exports the database as a JSON schema (fields with their type options)
+  /// plus rows, suitable for re-importing an identical database.
+  pub async fn export_database_json(&self) -> FlowyResult<String> {
+    let database = self.database.clone();
+    let database_guard = database.read().await;
+    DatabaseJsonExport.export_database(&database_guard).await
+  }
+
+  /// Exports the database as seen through the given view to an .xlsx
+  /// workbook, writing number, checkbox and date cells as typed excel cells.
+  pub async fn export_xlsx(&self, view_id: &str) -> FlowyResult<Vec<u8>> {
+    let rows = self.get_all_rows(view_id).await?;
+    let fields = self.get_fields(view_id, None).await;
+    XLSXExport.export_rows(fields, rows)
+  }
+
   pub async fn get_field_settings(
     &self,
     view_id: &str,
@@ -1852,6 +3340,65 @@ impl DatabaseEditor {
     }
   }
 
+  /// Searches the rows of this database by their primary field value so the
+  /// relation picker can offer suggestions without loading the whole related
+  /// database. Matching is fuzzy; results are ranked by match quality first
+  /// and by recency of modification second.
+  pub async fn search_related_rows(
+    &self,
+    query: &str,
+    limit: i64,
+  ) -> FlowyResult<Vec<RelatedRowDataPB>> {
+    let database = self.database.read().await;
+    let primary_field = Arc::new(
+      database
+        .get_primary_field()
+        .ok_or_else(|| FlowyError::internal().with_context("Primary field is not exist"))?,
+    );
+    let handler = Arc::new(
+      TypeOptionCellExt::new(&primary_field, Some(self.cell_cache.clone()))
+        .get_type_option_cell_data_handler_with_field_type(FieldType::RichText)
+        .ok_or(FlowyError::internal())?,
+    );
+
+    let query = query.trim().to_lowercase();
+    let mut matches = vec![];
+    let rows_stream = database.get_all_rows(10, None).await;
+    pin_mut!(rows_stream);
+    while let Some(result) = rows_stream.next().await {
+      if let Ok(row) = result {
+        let title = database
+          .get_cell(&primary_field.id, &row.id)
+          .await
+          .cell
+          .and_then(|cell| handler.handle_get_boxed_cell_data(&cell, &primary_field))
+          .and_then(|cell_data| cell_data.unbox_or_none())
+          .unwrap_or_else(|| StringCellData("".to_string()))
+          .0;
+        if let Some(score) = fuzzy_match_score(&title.to_lowercase(), &query) {
+          matches.push((
+            score,
+            row.modified_at,
+            RelatedRowDataPB {
+              row_id: row.id.to_string(),
+              name: title,
+            },
+          ));
+        }
+      }
+    }
+
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+    let limit = if limit > 0 { limit as usize } else { usize::MAX };
+    Ok(
+      matches
+        .into_iter()
+        .take(limit)
+        .map(|(_, _, row_data)| row_data)
+        .collect(),
+    )
+  }
+
   pub async fn get_prompts_from_database(
     &self,
     config: &CustomPromptDatabaseConfigPB,
@@ -2008,6 +3555,8 @@ struct DatabaseViewOperationImpl {
   editor_by_view_id: Arc<RwLock<EditorByViewId>>,
   #[allow(dead_code)]
   database_cancellation: Arc<RwLock<Option<CancellationToken>>>,
+  relation_resolver: Arc<ArcSwapOption<RelationCellResolver>>,
+  personal_views: Arc<PersonalViewStore>,
 }
 
 #[async_trait]
@@ -2149,10 +3698,19 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn get_group_setting(&self, view_id: &str) -> Vec<GroupSetting> {
+    if let Some(settings) = self.personal_views.group_settings(view_id) {
+      return settings;
+    }
     self.database.read().await.get_all_group_setting(view_id)
   }
 
   async fn insert_group_setting(&self, view_id: &str, setting: GroupSetting) {
+    if let Some(mut settings) = self.personal_views.group_settings(view_id) {
+      settings.retain(|s| s.id != setting.id);
+      settings.push(setting);
+      self.personal_views.save_group_settings(view_id, settings);
+      return;
+    }
     self
       .database
       .write()
@@ -2161,6 +3719,9 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn get_sort(&self, view_id: &str, sort_id: &str) -> Option<Sort> {
+    if let Some(sorts) = self.personal_views.sorts(view_id) {
+      return sorts.into_iter().find(|sort| sort.id == sort_id);
+    }
     self
       .database
       .read()
@@ -2169,10 +3730,28 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn insert_sort(&self, view_id: &str, sort: Sort) {
+    if let Some(mut sorts) = self.personal_views.sorts(view_id) {
+      match sorts.iter_mut().find(|s| s.id == sort.id) {
+        Some(existing) => *existing = sort,
+        None => sorts.push(sort),
+      }
+      self.personal_views.save_sorts(view_id, sorts);
+      return;
+    }
     self.database.write().await.insert_sort(view_id, sort);
   }
 
   async fn move_sort(&self, view_id: &str, from_sort_id: &str, to_sort_id: &str) {
+    if let Some(mut sorts) = self.personal_views.sorts(view_id) {
+      let from = sorts.iter().position(|sort| sort.id == from_sort_id);
+      let to = sorts.iter().position(|sort| sort.id == to_sort_id);
+      if let (Some(from), Some(to)) = (from, to) {
+        let sort = sorts.remove(from);
+        sorts.insert(to, sort);
+        self.personal_views.save_sorts(view_id, sorts);
+      }
+      return;
+    }
     self
       .database
       .write()
@@ -2181,14 +3760,26 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn remove_sort(&self, view_id: &str, sort_id: &str) {
+    if let Some(mut sorts) = self.personal_views.sorts(view_id) {
+      sorts.retain(|sort| sort.id != sort_id);
+      self.personal_views.save_sorts(view_id, sorts);
+      return;
+    }
     self.database.write().await.remove_sort(view_id, sort_id);
   }
 
   async fn get_all_sorts(&self, view_id: &str) -> Vec<Sort> {
+    if let Some(sorts) = self.personal_views.sorts(view_id) {
+      return sorts;
+    }
     self.database.read().await.get_all_sorts::<Sort>(view_id)
   }
 
   async fn remove_all_sorts(&self, view_id: &str) {
+    if self.personal_views.is_personal(view_id) {
+      self.personal_views.save_sorts(view_id, vec![]);
+      return;
+    }
     self.database.write().await.remove_all_sorts(view_id);
   }
 
@@ -2212,6 +3803,9 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn get_all_filters(&self, view_id: &str) -> Vec<Filter> {
+    if let Some(filters) = self.personal_views.filters(view_id) {
+      return filters;
+    }
     self
       .database
       .read()
@@ -2222,6 +3816,11 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn delete_filter(&self, view_id: &str, filter_id: &str) {
+    if let Some(mut filters) = self.personal_views.filters(view_id) {
+      filters.retain(|filter| filter.id != filter_id);
+      self.personal_views.save_filters(view_id, &filters);
+      return;
+    }
     self
       .database
       .write()
@@ -2230,10 +3829,20 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn insert_filter(&self, view_id: &str, filter: Filter) {
+    if let Some(mut filters) = self.personal_views.filters(view_id) {
+      filters.retain(|f| f.id != filter.id);
+      filters.push(filter);
+      self.personal_views.save_filters(view_id, &filters);
+      return;
+    }
     self.database.write().await.insert_filter(view_id, &filter);
   }
 
   async fn save_filters(&self, view_id: &str, filters: &[Filter]) {
+    if self.personal_views.is_personal(view_id) {
+      self.personal_views.save_filters(view_id, filters);
+      return;
+    }
     self
       .database
       .write()
@@ -2242,6 +3851,9 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
   }
 
   async fn get_filter(&self, view_id: &str, filter_id: &str) -> Option<Filter> {
+    if let Some(filters) = self.personal_views.filters(view_id) {
+      return filters.into_iter().find(|filter| filter.id == filter_id);
+    }
     self
       .database
       .read()
@@ -2293,6 +3905,10 @@ impl DatabaseViewOperation for DatabaseViewOperationImpl {
     TypeOptionCellExt::new(field, Some(self.cell_cache.clone())).get_type_option_cell_data_handler()
   }
 
+  fn get_relation_cell_resolver(&self) -> Option<Arc<RelationCellResolver>> {
+    self.relation_resolver.load_full()
+  }
+
   async fn get_field_settings(
     &self,
     view_id: &str,
@@ -2446,6 +4062,74 @@ fn notify_did_update_database_field(database: &Database, field_id: &str) -> Flow
   Ok(())
 }
 
+/// Evaluates a query predicate against the stringified value of a row cell.
+/// Comparisons are numeric when both sides parse as numbers, lexicographic
+/// otherwise.
+fn row_matches_predicate(row: &Row, field: &Field, predicate: &RowPredicatePB) -> bool {
+  let content = row
+    .cells
+    .get(&field.id)
+    .map(|cell| stringify_cell(cell, field))
+    .unwrap_or_default();
+  let content = content.trim();
+  let value = predicate.value.trim();
+  match predicate.condition {
+    RowPredicateConditionPB::Equals => content.eq_ignore_ascii_case(value),
+    RowPredicateConditionPB::Contains => content.to_lowercase().contains(&value.to_lowercase()),
+    RowPredicateConditionPB::GreaterThanOrEquals | RowPredicateConditionPB::LessThanOrEquals => {
+      let ordering = match (content.parse::<f64>(), value.parse::<f64>()) {
+        (Ok(lhs), Ok(rhs)) => lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal),
+        _ => content.cmp(value),
+      };
+      if predicate.condition == RowPredicateConditionPB::GreaterThanOrEquals {
+        ordering != Ordering::Less
+      } else {
+        ordering != Ordering::Greater
+      }
+    },
+  }
+}
+
+/// Scores how well a lowercased title matches a lowercased query: exact
+/// match beats prefix, prefix beats substring, substring beats an in-order
+/// subsequence with tighter subsequences ranked higher. Returns `None` when
+/// the query characters do not all appear in order.
+fn fuzzy_match_score(title: &str, query: &str) -> Option<i64> {
+  if query.is_empty() {
+    return Some(0);
+  }
+  if title == query {
+    return Some(1000);
+  }
+  if title.starts_with(query) {
+    return Some(800);
+  }
+  if title.contains(query) {
+    return Some(600);
+  }
+
+  let mut remaining = query.chars().peekable();
+  let mut first_match = None;
+  let mut last_match = 0;
+  for (index, c) in title.chars().enumerate() {
+    match remaining.peek() {
+      Some(&next) if next == c => {
+        remaining.next();
+        first_match.get_or_insert(index);
+        last_match = index;
+      },
+      Some(_) => {},
+      None => break,
+    }
+  }
+  if remaining.peek().is_some() {
+    return None;
+  }
+  let span = (last_match - first_match.unwrap_or(0) + 1) as i64;
+  let query_len = query.chars().count() as i64;
+  Some((400 - (span - query_len)).max(1))
+}
+
 async fn database_row_evict_listener(key: Arc<String>, row: Weak<RwLock<DatabaseRow>>) {
   remove_row_sync_plugin(key.as_str(), row).await
 }
@@ -1,6 +1,7 @@
 pub use layout_deps::*;
 pub use notifier::*;
 pub use view_editor::*;
+pub use view_index::*;
 pub use view_operation::*;
 pub use views::*;
 
@@ -10,6 +11,7 @@ mod view_calculations;
 mod view_editor;
 mod view_filter;
 mod view_group;
+mod view_index;
 mod view_operation;
 mod view_sort;
 mod views;
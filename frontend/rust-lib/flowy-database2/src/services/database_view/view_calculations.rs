@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use collab_database::fields::Field;
 use std::sync::Arc;
 
-use collab_database::rows::Cell;
+use collab_database::rows::{Cell, RowId};
 
 use crate::services::calculations::{
   Calculation, CalculationsController, CalculationsDelegate, CalculationsTaskHandler,
@@ -56,6 +56,22 @@ impl CalculationsDelegate for DatabaseViewCalculationsDelegateImpl {
       .collect()
   }
 
+  async fn get_cells_for_field_in_rows(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    row_ids: &[RowId],
+  ) -> Vec<Arc<Cell>> {
+    self
+      .0
+      .get_cells_for_field(view_id, field_id)
+      .await
+      .into_iter()
+      .filter(|row_cell| row_ids.contains(&row_cell.row_id))
+      .filter_map(|row_cell| row_cell.cell.map(Arc::new))
+      .collect()
+  }
+
   async fn get_field(&self, field_id: &str) -> Option<Field> {
     self.0.get_field(field_id).await
   }
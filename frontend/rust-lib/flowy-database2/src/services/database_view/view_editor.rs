@@ -8,7 +8,8 @@ use crate::entities::{
   DatabaseLayoutSettingPB, DeleteSortPayloadPB, FieldSettingsChangesetPB, FieldType,
   GroupChangesPB, GroupPB, InsertedRowPB, LayoutSettingChangeset, LayoutSettingParams,
   RemoveCalculationChangesetPB, ReorderSortPayloadPB, RowMetaPB, RowsChangePB,
-  SortChangesetNotificationPB, SortPB, UpdateCalculationChangesetPB, UpdateSortPayloadPB,
+  RowsWindowChangedPB, SortChangesetNotificationPB, SortPB, TimelineEventPB,
+  UpdateCalculationChangesetPB, UpdateSortPayloadPB,
 };
 use crate::notification::{DatabaseNotification, database_notification_builder};
 use crate::services::calculations::{Calculation, CalculationChangeset, CalculationsController};
@@ -21,19 +22,22 @@ use crate::services::database_view::view_operation::DatabaseViewOperation;
 use crate::services::database_view::view_sort::make_sort_controller;
 use crate::services::database_view::{
   DatabaseLayoutDepsResolver, DatabaseViewChangedNotifier, DatabaseViewChangedReceiverRunner,
-  notify_did_update_filter, notify_did_update_group_rows, notify_did_update_num_of_groups,
-  notify_did_update_setting, notify_did_update_sort,
+  FieldRowIndex, notify_did_update_filter, notify_did_update_group_rows,
+  notify_did_update_num_of_groups, notify_did_update_setting, notify_did_update_sort,
 };
+use crate::services::field::date_type_option::recurrence::get_recurring_rule;
+use crate::services::field::select_option_meta_from_field;
 use crate::services::field_settings::FieldSettings;
 use crate::services::filter::{Filter, FilterChangeset, FilterController};
 use crate::services::group::{
   DidMoveGroupRowResult, GroupChangeset, GroupController, MoveGroupRowContext, UpdatedCells,
 };
-use crate::services::setting::CalendarLayoutSetting;
+use crate::services::setting::{CalendarLayoutSetting, FormLayoutSetting, TimelineLayoutSetting};
 use crate::services::sort::{Sort, SortChangeset, SortController};
 use collab_database::database::{gen_database_calculation_id, gen_database_sort_id, gen_row_id};
 use collab_database::entity::DatabaseView;
 use collab_database::fields::Field;
+use collab_database::fields::date_type_option::DateCellData;
 use collab_database::rows::{Cell, Cells, CreateRowParams, Row, RowCell, RowDetail, RowId};
 use collab_database::views::{DatabaseLayout, RowOrder};
 use dashmap::DashMap;
@@ -58,6 +62,12 @@ pub struct DatabaseViewEditor {
   /// Check out this link (https://github.com/y-crdt/y-crdt/issues/341) for more information.
   pub(crate) row_orders: RwLock<Vec<RowOrder>>,
   pub(crate) row_by_row_id: DashMap<String, Arc<Row>>,
+  /// Per-field indexes consulted by the filter and sort controllers, kept up
+  /// to date on row changes.
+  row_index: Arc<FieldRowIndex>,
+  /// The last row window requested with [DatabaseViewEditor::v_get_rows_page],
+  /// as (offset, limit). Row changes notify the frontend to refetch the page.
+  row_window: RwLock<Option<(usize, usize)>>,
   pub notifier: DatabaseViewChangedNotifier,
 }
 
@@ -85,12 +95,15 @@ impl DatabaseViewEditor {
     let (notifier, _) = broadcast::channel(100);
     tokio::spawn(DatabaseViewChangedReceiverRunner(Some(notifier.subscribe())).run());
 
+    let row_index = Arc::new(FieldRowIndex::new());
+
     // Filter
     let filter_controller = make_filter_controller(
       &view_id,
       delegate.clone(),
       notifier.clone(),
       cell_cache.clone(),
+      row_index.clone(),
     )
     .await;
 
@@ -101,6 +114,7 @@ impl DatabaseViewEditor {
       notifier.clone(),
       filter_controller.clone(),
       cell_cache,
+      row_index.clone(),
     )
     .await;
 
@@ -129,6 +143,8 @@ impl DatabaseViewEditor {
       calculations_controller,
       row_orders: Default::default(),
       row_by_row_id: Default::default(),
+      row_index,
+      row_window: Default::default(),
       notifier,
     })
   }
@@ -209,6 +225,7 @@ impl DatabaseViewEditor {
 
     // fill in cells according to group_id if supplied
     if let Some(group_id) = params.group_id {
+      self.v_check_group_wip_limit(&group_id).await?;
       if let Some(controller) = self.group_controller.read().await.as_ref() {
         let field = self
           .delegate
@@ -247,6 +264,9 @@ impl DatabaseViewEditor {
     is_local_change: bool,
     row_changes: &DashMap<String, RowsChangePB>,
   ) {
+    let fields = self.delegate.get_fields(&self.view_id, None).await;
+    self.row_index.did_update_row(&fields, None, &row_detail.row);
+
     // Send the group notification if the current view has groups
     if let Some(controller) = self.group_controller.write().await.as_mut() {
       let rows = vec![Arc::new(row_detail.row.clone())];
@@ -284,11 +304,13 @@ impl DatabaseViewEditor {
     self
       .gen_did_create_row_view_tasks(row_detail.row.clone())
       .await;
+    self.notify_row_window_changed().await;
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
   pub async fn v_did_delete_row(&self, row: &Row, is_move_row: bool, is_local_change: bool) {
     let deleted_row = row.clone();
+    self.row_index.remove_row(&row.id);
 
     // Only update group rows
     // 1. when the row is deleted locally. If the row is moved, we don't need to send the group
@@ -312,6 +334,8 @@ impl DatabaseViewEditor {
           .await;
       }
     });
+
+    self.notify_row_window_changed().await;
   }
 
   /// Notify the view that the row has been updated. If the view has groups,
@@ -319,6 +343,9 @@ impl DatabaseViewEditor {
   /// send the view notification with [RowsChangePB]
   #[instrument(level = "trace", skip_all)]
   pub async fn v_did_update_row(&self, old_row: &Option<Row>, row: &Row, field_id: Option<String>) {
+    let fields = self.delegate.get_fields(&self.view_id, None).await;
+    self.row_index.did_update_row(&fields, old_row.as_ref(), row);
+
     if let Some(controller) = self.group_controller.write().await.as_mut() {
       let field = self
         .delegate
@@ -377,6 +404,7 @@ impl DatabaseViewEditor {
     self
       .gen_did_update_row_view_tasks(row.id.clone(), field_id)
       .await;
+    self.notify_row_window_changed().await;
   }
 
   pub async fn v_filter_rows(&self, rows: Vec<Arc<Row>>) -> Vec<Arc<Row>> {
@@ -409,6 +437,46 @@ impl DatabaseViewEditor {
     rows
   }
 
+  /// Returns one window of the visible rows of the view along with the total
+  /// number of visible rows. The window is remembered so that subsequent row
+  /// changes notify the frontend to refetch the page.
+  pub async fn v_get_rows_page(&self, offset: usize, limit: usize) -> (Vec<Arc<Row>>, usize) {
+    *self.row_window.write().await = Some((offset, limit));
+
+    // Without filters or sorts the row orders already describe the visible
+    // rows, so only the cells of the requested window need to be materialized.
+    if !self.has_filters().await && !self.has_sorts().await {
+      let row_orders = self.delegate.get_all_row_orders(&self.view_id).await;
+      let total = row_orders.len();
+      let window = row_orders
+        .into_iter()
+        .skip(offset)
+        .take(limit)
+        .collect::<Vec<_>>();
+      let rows = self.delegate.get_all_rows(&self.view_id, window).await;
+      return (rows, total);
+    }
+
+    let rows = self.v_get_all_rows().await;
+    let total = rows.len();
+    let rows = rows.into_iter().skip(offset).take(limit).collect();
+    (rows, total)
+  }
+
+  /// Notify the frontend that the rows inside the subscribed window may have
+  /// changed, so the current page can be refetched.
+  async fn notify_row_window_changed(&self) {
+    if let Some((offset, limit)) = *self.row_window.read().await {
+      database_notification_builder(&self.view_id, DatabaseNotification::DidUpdateRowWindow)
+        .payload(RowsWindowChangedPB {
+          view_id: self.view_id.clone(),
+          offset: offset as i64,
+          limit: limit as i64,
+        })
+        .send();
+    }
+  }
+
   pub async fn v_get_cells_for_field(&self, field_id: &str) -> Vec<RowCell> {
     let row_orders = self.delegate.get_all_row_orders(&self.view_id).await;
     let rows = self.delegate.get_all_rows(&self.view_id, row_orders).await;
@@ -461,15 +529,46 @@ impl DatabaseViewEditor {
   /// Only call once after database view editor initialized
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_load_groups(&self) -> Option<Vec<GroupPB>> {
-    let groups = self
-      .group_controller
-      .read()
+    let (group_data, grouping_field_id) = {
+      let controller = self.group_controller.read().await;
+      let controller = controller.as_ref()?;
+      (
+        controller
+          .get_all_groups()
+          .into_iter()
+          .cloned()
+          .collect::<Vec<_>>(),
+        controller.get_grouping_field_id().to_string(),
+      )
+    };
+    let option_meta = match self.delegate.get_field(&grouping_field_id).await {
+      Some(field) => select_option_meta_from_field(&field),
+      None => Default::default(),
+    };
+    let wip_limits = self
+      .v_get_layout_settings(&DatabaseLayout::Board)
       .await
-      .as_ref()?
-      .get_all_groups()
-      .into_iter()
-      .map(|group_data| GroupPB::from(group_data.clone()))
-      .collect::<Vec<_>>();
+      .board
+      .map(|setting| setting.wip_limits)
+      .unwrap_or_default();
+    let mut groups = Vec::with_capacity(group_data.len());
+    for group_data in group_data {
+      let row_ids = group_data
+        .rows
+        .iter()
+        .map(|row| row.id.clone())
+        .collect::<Vec<_>>();
+      let mut group = GroupPB::from(group_data);
+      group.calculations = self
+        .calculations_controller
+        .calculate_for_rows(&row_ids)
+        .await;
+      group.wip_limit = wip_limits.get(&group.group_id).copied();
+      group.hex_color = option_meta
+        .get(&group.group_id)
+        .and_then(|meta| meta.hex_color.clone());
+      groups.push(group);
+    }
     tracing::trace!("Number of groups: {}", groups.len());
     Some(groups)
   }
@@ -484,10 +583,61 @@ impl DatabaseViewEditor {
       .and_then(|group| group.get_group(group_id))
     {
       None => Err(FlowyError::record_not_found().with_context("Can't find the group")),
-      Some((_, group)) => Ok(GroupPB::from(group)),
+      Some((_, group)) => {
+        let row_ids = group
+          .rows
+          .iter()
+          .map(|row| row.id.clone())
+          .collect::<Vec<_>>();
+        let mut group = GroupPB::from(group);
+        group.calculations = self
+          .calculations_controller
+          .calculate_for_rows(&row_ids)
+          .await;
+        group.wip_limit = self
+          .v_get_layout_settings(&DatabaseLayout::Board)
+          .await
+          .board
+          .and_then(|setting| setting.wip_limits.get(&group.group_id).copied());
+        Ok(group)
+      },
     }
   }
 
+  /// Returns an error when the board's WIP limit for the given group is
+  /// already reached, so adding one more card would exceed it. Groups without
+  /// a configured limit always pass.
+  pub async fn v_check_group_wip_limit(&self, group_id: &str) -> FlowyResult<()> {
+    let limit = match self
+      .v_get_layout_settings(&DatabaseLayout::Board)
+      .await
+      .board
+      .and_then(|setting| setting.wip_limits.get(group_id).copied())
+    {
+      Some(limit) if limit > 0 => limit,
+      _ => return Ok(()),
+    };
+
+    let count = self
+      .group_controller
+      .read()
+      .await
+      .as_ref()
+      .and_then(|controller| controller.get_group(group_id))
+      .map(|(_, group)| group.rows.len() as i64)
+      .unwrap_or(0);
+
+    if count >= limit {
+      return Err(
+        FlowyError::group_wip_limit_exceeded().with_context(format!(
+          "group {} contains {} of at most {} cards",
+          group_id, count, limit
+        )),
+      );
+    }
+    Ok(())
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn v_move_group(&self, from_group: &str, to_group: &str) -> FlowyResult<()> {
     self
@@ -891,7 +1041,24 @@ impl DatabaseViewEditor {
   pub async fn v_get_layout_settings(&self, layout_ty: &DatabaseLayout) -> LayoutSettingParams {
     let mut layout_setting = LayoutSettingParams::default();
     match layout_ty {
-      DatabaseLayout::Grid => {},
+      DatabaseLayout::Grid => {
+        // A timeline view is persisted as a grid, so its layout settings are
+        // stored under the grid key. See [DatabaseLayoutPB::Timeline].
+        if let Some(value) = self
+          .delegate
+          .get_layout_setting(&self.view_id, layout_ty)
+          .await
+        {
+          let timeline_setting = TimelineLayoutSetting::from(value.clone());
+          if !timeline_setting.start_field_id.is_empty() {
+            layout_setting.timeline = Some(timeline_setting);
+          }
+          let form_setting = FormLayoutSetting::from(value);
+          if !form_setting.fields.is_empty() {
+            layout_setting.form = Some(form_setting);
+          }
+        }
+      },
       DatabaseLayout::Board => {
         if let Some(value) = self
           .delegate
@@ -971,7 +1138,49 @@ impl DatabaseViewEditor {
           None
         }
       },
-      _ => None,
+      DatabaseLayout::Grid => {
+        if let Some(layout_setting) = params.timeline {
+          // Both bar boundaries must point at date fields.
+          for field_id in [&layout_setting.start_field_id, &layout_setting.end_field_id] {
+            if let Some(field) = self.delegate.get_field(field_id).await {
+              if FieldType::from(field.field_type) != FieldType::DateTime {
+                return Err(FlowyError::unexpect_calendar_field_type());
+              }
+            }
+          }
+
+          self
+            .delegate
+            .insert_layout_setting(
+              &self.view_id,
+              &params.layout_type,
+              layout_setting.clone().into(),
+            )
+            .await;
+
+          Some(DatabaseLayoutSettingPB::from_timeline(layout_setting))
+        } else {
+          let layout_setting = params.form.unwrap();
+
+          // Every field on the form must exist in the database.
+          for field_setting in layout_setting.fields.iter() {
+            if self.delegate.get_field(&field_setting.field_id).await.is_none() {
+              return Err(FlowyError::field_record_not_found());
+            }
+          }
+
+          self
+            .delegate
+            .insert_layout_setting(
+              &self.view_id,
+              &params.layout_type,
+              layout_setting.clone().into(),
+            )
+            .await;
+
+          Some(DatabaseLayoutSettingPB::from_form(layout_setting))
+        }
+      },
     };
 
     if let Some(payload) = layout_setting_pb {
@@ -984,6 +1193,7 @@ impl DatabaseViewEditor {
   }
 
   pub async fn v_did_delete_field(&self, deleted_field_id: &str) {
+    self.row_index.remove_field(deleted_field_id);
     let changeset = FilterChangeset::DeleteAllWithFieldId {
       field_id: deleted_field_id.to_string(),
     };
@@ -1012,6 +1222,9 @@ impl DatabaseViewEditor {
   }
 
   pub async fn v_did_update_field_type(&self, field_id: &str, new_field_type: FieldType) {
+    // The encoded values are stale once the field type changed; the index is
+    // rebuilt on the next use.
+    self.row_index.remove_field(field_id);
     self
       .sort_controller
       .read()
@@ -1040,6 +1253,7 @@ impl DatabaseViewEditor {
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn v_did_update_field_type_option(&self, old_field: &Field) -> FlowyResult<()> {
     let field_id = &old_field.id;
+    self.row_index.remove_field(field_id);
 
     if let Some(field) = self.delegate.get_field(field_id).await {
       self
@@ -1109,6 +1323,23 @@ impl DatabaseViewEditor {
     Ok(())
   }
 
+  /// The end timestamp of a row according to the end-date field configured
+  /// in the calendar setting, if any. Falls back to `None` when no end field
+  /// is configured or its cell is empty.
+  async fn end_timestamp_from_setting(
+    &self,
+    calendar_setting: &CalendarLayoutSetting,
+    row_id: &RowId,
+  ) -> Option<i64> {
+    if calendar_setting.end_field_id.is_empty() {
+      return None;
+    }
+    get_cell_for_row(self.delegate.clone(), &calendar_setting.end_field_id, row_id)
+      .await
+      .and_then(|cell| cell.into_date_field_cell_data())
+      .and_then(|cell_data| cell_data.timestamp)
+  }
+
   pub async fn v_get_calendar_event(&self, row_id: RowId) -> Option<CalendarEventPB> {
     let layout_ty = DatabaseLayout::Calendar;
     let calendar_setting = self.v_get_layout_settings(&layout_ty).await.calendar?;
@@ -1120,16 +1351,27 @@ impl DatabaseViewEditor {
     // Date
     let date_field = self.delegate.get_field(&calendar_setting.field_id).await?;
 
-    let date_cell = get_cell_for_row(self.delegate.clone(), &date_field.id, &row_id).await?;
+    let date_cell = self.delegate.get_cell_in_row(&date_field.id, &row_id).await;
     let title = text_cell
       .into_text_field_cell_data()
       .unwrap_or_default()
       .into();
 
-    let timestamp = date_cell
-      .into_date_field_cell_data()
-      .unwrap_or_default()
-      .timestamp;
+    let is_recurring = date_cell
+      .cell
+      .as_ref()
+      .and_then(get_recurring_rule)
+      .is_some();
+    let date_cell_data = date_cell
+      .cell
+      .as_ref()
+      .map(DateCellData::from)
+      .unwrap_or_default();
+    let timestamp = date_cell_data.timestamp;
+    let end_timestamp = self
+      .end_timestamp_from_setting(&calendar_setting, &row_id)
+      .await
+      .or(date_cell_data.end_timestamp);
 
     let (_, row_detail) = self.delegate.get_row_detail(&self.view_id, &row_id).await?;
 
@@ -1138,10 +1380,18 @@ impl DatabaseViewEditor {
       date_field_id: date_field.id.clone(),
       title,
       timestamp,
+      end_timestamp,
+      is_recurring,
     })
   }
 
-  pub async fn v_get_all_calendar_events(&self) -> Option<Vec<CalendarEventPB>> {
+  /// Returns one event per row, except for rows whose date cell carries a
+  /// recurring rule: those are expanded into one event per occurrence inside
+  /// `range` when it's provided.
+  pub async fn v_get_all_calendar_events(
+    &self,
+    range: Option<(i64, i64)>,
+  ) -> Option<Vec<CalendarEventPB>> {
     let layout_ty = DatabaseLayout::Calendar;
     let calendar_setting = match self.v_get_layout_settings(&layout_ty).await.calendar {
       None => {
@@ -1163,12 +1413,22 @@ impl DatabaseViewEditor {
 
     for row in rows {
       let primary_cell = get_cell_for_row(self.delegate.clone(), &primary_field.id, &row.id).await;
-      let timestamp_cell =
-        get_cell_for_row(self.delegate.clone(), &calendar_setting.field_id, &row.id).await;
+      let date_cell = self
+        .delegate
+        .get_cell_in_row(&calendar_setting.field_id, &row.id)
+        .await;
 
-      let timestamp = timestamp_cell
-        .and_then(|cell| cell.into_date_field_cell_data())
-        .and_then(|cell_data| cell_data.timestamp);
+      let recurring_rule = date_cell.cell.as_ref().and_then(get_recurring_rule);
+      let date_cell_data = date_cell.cell.as_ref().map(DateCellData::from);
+      let timestamp = date_cell_data.as_ref().and_then(|cell_data| cell_data.timestamp);
+      let end_timestamp = self
+        .end_timestamp_from_setting(&calendar_setting, &row.id)
+        .await
+        .or_else(|| {
+          date_cell_data
+            .as_ref()
+            .and_then(|cell_data| cell_data.end_timestamp)
+        });
 
       let title = primary_cell
         .and_then(|cell| cell.into_text_field_cell_data())
@@ -1181,9 +1441,80 @@ impl DatabaseViewEditor {
         date_field_id: calendar_setting.field_id.clone(),
         title,
         timestamp,
+        end_timestamp,
+        is_recurring: recurring_rule.is_some(),
       };
 
-      events.push(event);
+      match (recurring_rule, timestamp, range) {
+        (Some(rule), Some(base), Some((range_start, range_end))) => {
+          // Each occurrence keeps the duration of the base event.
+          let duration = end_timestamp.map(|end| end - base);
+          for occurrence in rule.occurrences_between(base, range_start, range_end) {
+            let mut occurrence_event = event.clone();
+            occurrence_event.timestamp = Some(occurrence);
+            occurrence_event.end_timestamp = duration.map(|duration| occurrence + duration);
+            events.push(occurrence_event);
+          }
+        },
+        _ => events.push(event),
+      }
+    }
+
+    Some(events)
+  }
+
+  pub async fn v_get_all_timeline_events(&self) -> Option<Vec<TimelineEventPB>> {
+    let timeline_setting = match self
+      .v_get_layout_settings(&DatabaseLayout::Grid)
+      .await
+      .timeline
+    {
+      None => {
+        tracing::warn!(
+          "Timeline layout setting not found in database view:{}",
+          self.view_id
+        );
+        return None;
+      },
+      Some(timeline_setting) => timeline_setting,
+    };
+
+    let primary_field = self.delegate.get_primary_field().await?;
+    let mut events: Vec<TimelineEventPB> = vec![];
+    let rows = self.v_get_all_rows().await;
+
+    for row in rows {
+      let primary_cell = get_cell_for_row(self.delegate.clone(), &primary_field.id, &row.id).await;
+      let start_cell =
+        get_cell_for_row(self.delegate.clone(), &timeline_setting.start_field_id, &row.id).await;
+      let end_cell =
+        get_cell_for_row(self.delegate.clone(), &timeline_setting.end_field_id, &row.id).await;
+
+      let start_cell_data = start_cell.and_then(|cell| cell.into_date_field_cell_data());
+      let start_timestamp = start_cell_data
+        .as_ref()
+        .and_then(|cell_data| cell_data.timestamp);
+      // A bar can also come from a single range cell: when the end cell is
+      // empty, fall back to the end timestamp of the start cell.
+      let end_timestamp = end_cell
+        .and_then(|cell| cell.into_date_field_cell_data())
+        .and_then(|cell_data| cell_data.timestamp)
+        .or_else(|| start_cell_data.and_then(|cell_data| cell_data.end_timestamp));
+
+      let title = primary_cell
+        .and_then(|cell| cell.into_text_field_cell_data())
+        .map(|cell_data| cell_data.into())
+        .unwrap_or_default();
+
+      let (_, row_detail) = self.delegate.get_row_detail(&self.view_id, &row.id).await?;
+      events.push(TimelineEventPB {
+        row_meta: RowMetaPB::from(row_detail.as_ref().clone()),
+        start_field_id: timeline_setting.start_field_id.clone(),
+        end_field_id: timeline_setting.end_field_id.clone(),
+        title,
+        start_timestamp,
+        end_timestamp,
+      });
     }
 
     Some(events)
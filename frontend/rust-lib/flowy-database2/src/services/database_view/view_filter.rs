@@ -3,8 +3,9 @@ use std::sync::Arc;
 
 use crate::services::cell::CellCache;
 use crate::services::database_view::{
-  DatabaseViewChangedNotifier, DatabaseViewOperation, gen_handler_id,
+  DatabaseViewChangedNotifier, DatabaseViewOperation, FieldRowIndex, gen_handler_id,
 };
+use crate::services::field::RelationCellResolver;
 use crate::services::filter::{Filter, FilterController, FilterDelegate, FilterTaskHandler};
 use collab_database::fields::Field;
 use collab_database::rows::{Row, RowDetail, RowId};
@@ -14,6 +15,7 @@ pub async fn make_filter_controller(
   delegate: Arc<dyn DatabaseViewOperation>,
   notifier: DatabaseViewChangedNotifier,
   cell_cache: CellCache,
+  row_index: Arc<FieldRowIndex>,
 ) -> Arc<FilterController> {
   let task_scheduler = delegate.get_task_scheduler();
   let filter_delegate = DatabaseViewFilterDelegateImpl(delegate.clone());
@@ -25,6 +27,7 @@ pub async fn make_filter_controller(
     filter_delegate,
     task_scheduler.clone(),
     cell_cache,
+    row_index,
     notifier,
   )
   .await;
@@ -67,4 +70,8 @@ impl FilterDelegate for DatabaseViewFilterDelegateImpl {
   async fn save_filters(&self, view_id: &str, filters: &[Filter]) {
     self.0.save_filters(view_id, filters).await
   }
+
+  fn get_relation_cell_resolver(&self) -> Option<Arc<RelationCellResolver>> {
+    self.0.get_relation_cell_resolver()
+  }
 }
@@ -0,0 +1,306 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Row, RowId};
+use dashmap::DashMap;
+
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell;
+
+/// A sortable encoding of a cell value. Numbers are encoded so that the
+/// unsigned comparison of the bit pattern matches the numeric order, which
+/// lets a [BTreeMap] keep numeric columns sorted without decoding the cells
+/// again.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IndexKey {
+  Number(u64),
+  Text(String),
+}
+
+impl IndexKey {
+  pub fn number(num: f64) -> Self {
+    let bits = num.to_bits();
+    // Flip the sign bit for positive numbers and all bits for negative ones
+    // so the unsigned order matches the numeric order.
+    let encoded = if num >= 0.0 { bits ^ (1 << 63) } else { !bits };
+    IndexKey::Number(encoded)
+  }
+
+  /// Text keys are lowercased, and nothing else, so that key equality
+  /// matches the case-insensitive comparison of the text filter.
+  pub fn text(content: &str) -> Self {
+    IndexKey::Text(content.to_lowercase())
+  }
+
+  fn is_empty(&self) -> bool {
+    matches!(self, IndexKey::Text(content) if content.is_empty())
+  }
+}
+
+/// Per-field indexes over the rows of a view: a sorted map from the encoded
+/// cell value to the row ids holding that value. The indexes are built lazily
+/// by the filter and sort controllers and maintained incrementally by the
+/// view editor on row changes, so filtering or sorting a large grid doesn't
+/// re-decode every cell on each change. Field types without a meaningful
+/// one-shot encoding (dates, checklists, relations, ...) are not indexed and
+/// fall back to the full scan.
+#[derive(Default)]
+pub struct FieldRowIndex {
+  indexes: DashMap<String, BTreeMap<IndexKey, HashSet<RowId>>>,
+}
+
+impl FieldRowIndex {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Whether the field's cell values can be indexed.
+  pub fn supports(field: &Field) -> bool {
+    matches!(
+      FieldType::from(field.field_type),
+      FieldType::RichText
+        | FieldType::URL
+        | FieldType::Number
+        | FieldType::Checkbox
+        | FieldType::SingleSelect
+    )
+  }
+
+  pub fn is_built(&self, field_id: &str) -> bool {
+    self.indexes.contains_key(field_id)
+  }
+
+  /// Builds the index of the field from scratch. `rows` must be the full
+  /// row set of the view: the index answers membership queries, so building
+  /// it from a subset would make every other row look like a non-match.
+  pub fn build(&self, field: &Field, rows: &[Arc<Row>]) {
+    if !Self::supports(field) {
+      return;
+    }
+    let mut index: BTreeMap<IndexKey, HashSet<RowId>> = BTreeMap::new();
+    for row in rows {
+      index
+        .entry(index_key(field, row))
+        .or_default()
+        .insert(row.id.clone());
+    }
+    self.indexes.insert(field.id.clone(), index);
+  }
+
+  /// Re-indexes the row for every built field index. Pass `None` as the old
+  /// row when the row was just created.
+  pub fn did_update_row(&self, fields: &[Field], old_row: Option<&Row>, row: &Row) {
+    for field in fields {
+      if let Some(mut index) = self.indexes.get_mut(&field.id) {
+        if let Some(old_row) = old_row {
+          remove_entry(&mut index, &index_key(field, old_row), &row.id);
+        }
+        index
+          .entry(index_key(field, row))
+          .or_default()
+          .insert(row.id.clone());
+      }
+    }
+  }
+
+  pub fn remove_row(&self, row_id: &RowId) {
+    for mut index in self.indexes.iter_mut() {
+      index.retain(|_, row_ids| {
+        row_ids.remove(row_id);
+        !row_ids.is_empty()
+      });
+    }
+  }
+
+  /// Drops the index of the field, e.g. after its type or type option
+  /// changed. It will be rebuilt on the next use.
+  pub fn remove_field(&self, field_id: &str) {
+    self.indexes.remove(field_id);
+  }
+
+  /// Returns the rank of every indexed row in value order: rows sharing a
+  /// value share a rank, so a stable sort by rank keeps ties in their
+  /// incoming order, like the comparison sort it replaces. Rows holding an
+  /// empty value always rank last regardless of `descending`. `None` when
+  /// the index isn't built.
+  pub fn row_ranks(&self, field_id: &str, descending: bool) -> Option<HashMap<RowId, usize>> {
+    let index = self.indexes.get(field_id)?;
+    let mut groups: Vec<&HashSet<RowId>> = index
+      .iter()
+      .filter(|(key, _)| !key.is_empty())
+      .map(|(_, row_ids)| row_ids)
+      .collect();
+    if descending {
+      groups.reverse();
+    }
+    let mut ranks = HashMap::new();
+    for (rank, row_ids) in groups.iter().enumerate() {
+      for row_id in row_ids.iter() {
+        ranks.insert(row_id.clone(), rank);
+      }
+    }
+    let empty_rank = groups.len();
+    for (key, row_ids) in index.iter() {
+      if key.is_empty() {
+        for row_id in row_ids.iter() {
+          ranks.insert(row_id.clone(), empty_rank);
+        }
+      }
+    }
+    Some(ranks)
+  }
+
+  /// Returns the rows whose encoded value equals the content, or `None` when
+  /// the index isn't built and the caller should fall back to a full scan.
+  pub fn rows_with_text(&self, field_id: &str, content: &str) -> Option<HashSet<RowId>> {
+    let index = self.indexes.get(field_id)?;
+    Some(
+      index
+        .get(&IndexKey::text(content))
+        .cloned()
+        .unwrap_or_default(),
+    )
+  }
+}
+
+fn remove_entry(index: &mut BTreeMap<IndexKey, HashSet<RowId>>, key: &IndexKey, row_id: &RowId) {
+  if let Some(row_ids) = index.get_mut(key) {
+    row_ids.remove(row_id);
+    if row_ids.is_empty() {
+      index.remove(key);
+    }
+  }
+}
+
+fn index_key(field: &Field, row: &Row) -> IndexKey {
+  let content = match row.cells.get(&field.id) {
+    Some(cell) => stringify_cell(cell, field),
+    None => String::new(),
+  };
+  if FieldType::from(field.field_type) == FieldType::Number {
+    if let Ok(num) = content.parse::<f64>() {
+      return IndexKey::number(num);
+    }
+  }
+  IndexKey::text(&content)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::entities::{TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::insert_text_cell;
+  use crate::services::field::FieldBuilder;
+
+  fn text_row(id: &str, field: &Field, content: &str) -> Arc<Row> {
+    let mut row = Row::empty(RowId::from(id.to_string()), "database_id");
+    row
+      .cells
+      .insert(field.id.clone(), insert_text_cell(content.to_string(), field));
+    Arc::new(row)
+  }
+
+  #[test]
+  fn number_keys_order_like_the_numbers_test() {
+    let values = [
+      f64::NEG_INFINITY,
+      -1024.5,
+      -1.0,
+      -0.001,
+      0.0,
+      0.001,
+      1.0,
+      1024.5,
+      f64::INFINITY,
+    ];
+    for pair in values.windows(2) {
+      assert!(
+        IndexKey::number(pair[0]) < IndexKey::number(pair[1]),
+        "expected {} < {}",
+        pair[0],
+        pair[1]
+      );
+    }
+    assert_eq!(IndexKey::number(1.5), IndexKey::number(1.5));
+  }
+
+  #[test]
+  fn text_keys_agree_with_text_filter_test() {
+    let field = FieldBuilder::from_field_type(FieldType::RichText).build();
+    let contents = ["AppFlowy", "appflowy", " appflowy", "appflowy ", "Other", ""];
+    let rows: Vec<Arc<Row>> = contents
+      .iter()
+      .enumerate()
+      .map(|(i, content)| text_row(&i.to_string(), &field, content))
+      .collect();
+
+    let index = FieldRowIndex::new();
+    index.build(&field, &rows);
+
+    // Empty filter content is excluded: `is_visible` treats it as
+    // match-everything, so the index path refuses to answer it.
+    for filter_content in contents.iter().filter(|content| !content.is_empty()) {
+      let filter = TextFilterPB {
+        condition: TextFilterConditionPB::TextIs,
+        content: filter_content.to_string(),
+      };
+      let from_index = index.rows_with_text(&field.id, filter_content).unwrap();
+      for (row, content) in rows.iter().zip(contents.iter()) {
+        assert_eq!(
+          from_index.contains(&row.id),
+          filter.is_visible(content),
+          "index and scan disagree on cell {:?} with filter {:?}",
+          content,
+          filter_content
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn incremental_row_maintenance_test() {
+    let field = FieldBuilder::from_field_type(FieldType::RichText).build();
+    let row_a = text_row("a", &field, "apple");
+    let row_b = text_row("b", &field, "banana");
+    let index = FieldRowIndex::new();
+    index.build(&field, &[row_a.clone(), row_b.clone()]);
+
+    let updated_a = text_row("a", &field, "banana");
+    index.did_update_row(
+      std::slice::from_ref(&field),
+      Some(row_a.as_ref()),
+      updated_a.as_ref(),
+    );
+    assert!(index.rows_with_text(&field.id, "apple").unwrap().is_empty());
+    let bananas = index.rows_with_text(&field.id, "banana").unwrap();
+    assert!(bananas.contains(&row_a.id) && bananas.contains(&row_b.id));
+
+    index.remove_row(&row_b.id);
+    let bananas = index.rows_with_text(&field.id, "banana").unwrap();
+    assert!(bananas.contains(&row_a.id) && !bananas.contains(&row_b.id));
+  }
+
+  #[test]
+  fn row_ranks_share_ties_and_keep_empty_last_test() {
+    let field = FieldBuilder::from_field_type(FieldType::RichText).build();
+    let rows = [
+      text_row("a", &field, "apple"),
+      text_row("b", &field, "Apple"),
+      text_row("c", &field, "banana"),
+      text_row("d", &field, ""),
+    ];
+    let index = FieldRowIndex::new();
+    index.build(&field, &rows);
+
+    let ascending = index.row_ranks(&field.id, false).unwrap();
+    assert_eq!(ascending[&rows[0].id], ascending[&rows[1].id]);
+    assert!(ascending[&rows[0].id] < ascending[&rows[2].id]);
+    assert!(ascending[&rows[2].id] < ascending[&rows[3].id]);
+
+    let descending = index.row_ranks(&field.id, true).unwrap();
+    assert_eq!(descending[&rows[0].id], descending[&rows[1].id]);
+    assert!(descending[&rows[2].id] < descending[&rows[0].id]);
+    assert!(descending[&rows[0].id] < descending[&rows[3].id]);
+  }
+}
@@ -14,7 +14,7 @@ use lib_infra::priority_task::TaskDispatcher;
 
 use crate::entities::{FieldSettingsChangesetPB, FieldType};
 use crate::services::calculations::Calculation;
-use crate::services::field::TypeOptionCellDataHandler;
+use crate::services::field::{RelationCellResolver, TypeOptionCellDataHandler};
 use crate::services::field_settings::FieldSettings;
 use crate::services::filter::Filter;
 use crate::services::group::GroupSetting;
@@ -127,6 +127,10 @@ pub trait DatabaseViewOperation: Send + Sync + 'static {
     field: &Field,
   ) -> Option<Box<dyn TypeOptionCellDataHandler>>;
 
+  /// Returns the resolver used to read cells of rows in related databases.
+  /// `None` until the manager installs one into the editor.
+  fn get_relation_cell_resolver(&self) -> Option<Arc<RelationCellResolver>>;
+
   async fn get_field_settings(
     &self,
     view_id: &str,
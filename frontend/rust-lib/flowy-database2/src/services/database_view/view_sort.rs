@@ -7,7 +7,7 @@ use tokio::sync::RwLock;
 
 use crate::services::cell::CellCache;
 use crate::services::database_view::{
-  DatabaseViewChangedNotifier, DatabaseViewOperation, gen_handler_id,
+  DatabaseViewChangedNotifier, DatabaseViewOperation, FieldRowIndex, gen_handler_id,
 };
 use crate::services::filter::FilterController;
 use crate::services::sort::{Sort, SortController, SortDelegate, SortTaskHandler};
@@ -18,6 +18,7 @@ pub(crate) async fn make_sort_controller(
   notifier: DatabaseViewChangedNotifier,
   filter_controller: Arc<FilterController>,
   cell_cache: CellCache,
+  row_index: Arc<FieldRowIndex>,
 ) -> Arc<RwLock<SortController>> {
   let handler_id = gen_handler_id();
   let sorts = delegate
@@ -38,6 +39,7 @@ pub(crate) async fn make_sort_controller(
     sort_delegate,
     task_scheduler.clone(),
     cell_cache,
+    row_index,
     notifier,
   )));
   task_scheduler
@@ -67,6 +69,12 @@ impl SortDelegate for DatabaseViewSortDelegateImpl {
     self.filter_controller.filter_rows(rows).await
   }
 
+  async fn get_all_rows(&self, view_id: &str) -> Vec<Arc<Row>> {
+    let view_id = view_id.to_string();
+    let row_orders = self.delegate.get_all_row_orders(&view_id).await;
+    self.delegate.get_all_rows(&view_id, row_orders).await
+  }
+
   async fn filter_row(&self, row: &Row) -> bool {
     let rows = vec![Arc::new(row.clone())];
     let rows = self.filter_controller.filter_rows(rows).await;
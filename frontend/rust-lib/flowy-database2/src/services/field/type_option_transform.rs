@@ -1,5 +1,5 @@
 use crate::entities::FieldType;
-use crate::services::field::TypeOptionTransform;
+use crate::services::field::{NumberTypeOptionExt, TimestampTypeOptionExt, TypeOptionTransform};
 use async_trait::async_trait;
 use collab_database::database::Database;
 use collab_database::fields::TypeOptionData;
@@ -7,12 +7,10 @@ use collab_database::fields::checkbox_type_option::CheckboxTypeOption;
 use collab_database::fields::checklist_type_option::ChecklistTypeOption;
 use collab_database::fields::date_type_option::{DateTypeOption, TimeTypeOption};
 use collab_database::fields::media_type_option::MediaTypeOption;
-use collab_database::fields::number_type_option::NumberTypeOption;
 use collab_database::fields::relation_type_option::RelationTypeOption;
 use collab_database::fields::select_type_option::{MultiSelectTypeOption, SingleSelectTypeOption};
 use collab_database::fields::summary_type_option::SummarizationTypeOption;
 use collab_database::fields::text_type_option::RichTextTypeOption;
-use collab_database::fields::timestamp_type_option::TimestampTypeOption;
 use collab_database::fields::translate_type_option::TranslateTypeOption;
 use collab_database::fields::url_type_option::URLTypeOption;
 
@@ -91,6 +89,56 @@ where
   }
 }
 
+/// Whether switching a field from `old` to `new` rewrites the stored cells.
+/// Pairs that are not listed keep the cells untouched and reinterpret them
+/// at read time.
+pub fn transform_rewrites_cells(old: FieldType, new: FieldType) -> bool {
+  if old == new {
+    return false;
+  }
+  match new {
+    // Cells are rewritten to the display value of the old field.
+    FieldType::RichText => matches!(
+      old,
+      FieldType::Number
+        | FieldType::DateTime
+        | FieldType::SingleSelect
+        | FieldType::MultiSelect
+        | FieldType::Checkbox
+        | FieldType::URL
+        | FieldType::Time
+    ),
+    // Cells are reformatted/rewritten from the text content.
+    FieldType::Number | FieldType::URL => old == FieldType::RichText,
+    // Cells are rewritten to ids of the options built from the old values.
+    FieldType::SingleSelect | FieldType::MultiSelect => {
+      matches!(old, FieldType::RichText | FieldType::Number)
+    },
+    _ => false,
+  }
+}
+
+/// Whether switching a field from `old` to `new` can drop information the
+/// new type cannot represent. Switches to [FieldType::RichText] keep the
+/// display value and are treated as lossless even though the old raw
+/// representation is dropped.
+pub fn transform_is_lossy(old: FieldType, new: FieldType) -> bool {
+  if old == new {
+    return false;
+  }
+  match (old, new) {
+    (_, FieldType::RichText) => false,
+    // Every distinct value becomes an option.
+    (FieldType::Number, FieldType::SingleSelect | FieldType::MultiSelect) => false,
+    (FieldType::Checkbox, FieldType::SingleSelect | FieldType::MultiSelect) => false,
+    (FieldType::SingleSelect, FieldType::MultiSelect) => false,
+    // Only one of the selected options survives.
+    (FieldType::MultiSelect, FieldType::SingleSelect) => true,
+    // Cells that don't parse as the new type are dropped.
+    _ => true,
+  }
+}
+
 fn get_type_option_transform_handler(
   type_option_data: TypeOptionData,
   field_type: FieldType,
@@ -100,13 +148,14 @@ fn get_type_option_transform_handler(
       Box::new(RichTextTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
     },
     FieldType::Number => {
-      Box::new(NumberTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+      // The Ext wrapper carries the formatting extras through the transform.
+      Box::new(NumberTypeOptionExt::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
     },
     FieldType::DateTime => {
       Box::new(DateTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
     },
     FieldType::LastEditedTime | FieldType::CreatedTime => {
-      Box::new(TimestampTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+      Box::new(TimestampTypeOptionExt::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
     },
     FieldType::SingleSelect => Box::new(SingleSelectTypeOption::from(type_option_data))
       as Box<dyn TypeOptionTransformHandler>,
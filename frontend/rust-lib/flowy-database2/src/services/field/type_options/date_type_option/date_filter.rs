@@ -192,6 +192,12 @@ pub struct DateCellChangeset {
   pub is_range: Option<bool>,
   pub clear_flag: Option<bool>,
   pub reminder_id: Option<String>,
+  /// Serialized [RecurringRule]. An empty string removes the rule from the
+  /// cell.
+  pub recurrence: Option<String>,
+  /// Seconds before the date at which the reminder fires. A negative value
+  /// removes the reminder offset from the cell.
+  pub reminder_offset: Option<i64>,
 }
 
 pub struct DateCellDataParser();
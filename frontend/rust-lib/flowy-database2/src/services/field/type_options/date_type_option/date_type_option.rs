@@ -13,6 +13,15 @@ use tracing::info;
 use crate::entities::{DateCellDataPB, DateFilterPB, FieldType};
 use crate::services::cell::{CellDataChangeset, CellDataDecoder};
 use crate::services::field::date_type_option::date_filter::DateCellChangeset;
+use crate::services::field::date_type_option::recurrence::{
+  RECURRING_RULE, insert_recurring_rule,
+};
+use crate::services::field::date_type_option::reminder::{
+  get_reminder_offset, insert_reminder_offset,
+};
+use crate::services::field::date_type_option::timezone::{
+  naive_to_utc_timestamp, parse_timezone, utc_to_naive_timestamp,
+};
 use crate::services::field::{
   CELL_DATA, CellDataProtobufEncoder, TypeOption, TypeOptionCellDataCompare,
   TypeOptionCellDataFilter, TypeOptionTransform, default_order,
@@ -34,9 +43,17 @@ impl CellDataProtobufEncoder for DateTypeOption {
     let include_time = cell_data.include_time;
     let is_range = cell_data.is_range;
 
-    let timestamp = cell_data.timestamp;
+    // The cell stores UTC. When the field has a timezone, hand the UI the
+    // wall-clock time in that timezone.
+    let timezone = parse_timezone(&self.timezone_id);
+    let to_naive = |timestamp: i64| match timezone {
+      Some(tz) => utc_to_naive_timestamp(timestamp, tz),
+      None => timestamp,
+    };
+
+    let timestamp = cell_data.timestamp.map(to_naive);
     let end_timestamp = if is_range {
-      cell_data.end_timestamp.or(timestamp)
+      cell_data.end_timestamp.map(to_naive).or(timestamp)
     } else {
       None
     };
@@ -100,6 +117,77 @@ impl TypeOptionTransform for DateTypeOption {
   }
 }
 
+/// One-time migration that runs when a timezone is first assigned to a
+/// DateTime field. Timestamps written before then were stored as naive
+/// wall-clock times, so they're reinterpreted in the new timezone to become
+/// real UTC timestamps.
+pub async fn migrate_date_cells_to_utc(
+  database: &mut Database,
+  type_option_data: &TypeOptionData,
+  old_field: &Field,
+) {
+  let field_type = FieldType::from(old_field.field_type);
+  if field_type != FieldType::DateTime {
+    return;
+  }
+  let old_timezone = old_field
+    .get_type_option::<DateTypeOption>(field_type)
+    .map(|type_option| type_option.timezone_id)
+    .unwrap_or_default();
+  if !old_timezone.is_empty() {
+    return;
+  }
+  let tz = match parse_timezone(&DateTypeOption::from(type_option_data.clone()).timezone_id) {
+    Some(tz) => tz,
+    None => return,
+  };
+  let view_id = match database.get_first_database_view_id() {
+    Some(view_id) => view_id,
+    None => return,
+  };
+
+  let rows = database
+    .get_cells_for_field(&view_id, &old_field.id)
+    .await
+    .into_iter()
+    .filter_map(|row| row.cell.map(|cell| (row.row_id, cell)))
+    .collect::<Vec<_>>();
+
+  info!(
+    "Assigning timezone {} to date field, migrating {} cells to UTC",
+    tz,
+    rows.len()
+  );
+  for (row_id, cell) in rows {
+    let mut cell_data = DateCellData::from(&cell);
+    if cell_data.timestamp.is_none() {
+      continue;
+    }
+    cell_data.timestamp = cell_data
+      .timestamp
+      .map(|timestamp| naive_to_utc_timestamp(timestamp, tz));
+    cell_data.end_timestamp = cell_data
+      .end_timestamp
+      .map(|timestamp| naive_to_utc_timestamp(timestamp, tz));
+
+    let mut new_cell = Cell::from(&cell_data);
+    if let Some(rule) = cell.get_as::<String>(RECURRING_RULE) {
+      insert_recurring_rule(&mut new_cell, &rule);
+    }
+    if let Some(offset) = get_reminder_offset(&cell) {
+      insert_reminder_offset(&mut new_cell, offset);
+    }
+
+    database
+      .update_row(row_id, |row| {
+        row.update_cells(|cells| {
+          cells.insert(&old_field.id, new_cell);
+        });
+      })
+      .await;
+  }
+}
+
 impl CellDataDecoder for DateTypeOption {
   fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
     let include_time = cell_data.include_time;
@@ -152,6 +240,28 @@ impl CellDataChangeset for DateTypeOption {
       return Ok((Cell::from(&cell_data), cell_data));
     }
 
+    // The recurrence rule and reminder offset are stored beside the date
+    // data and must survive the cell rebuild below, unless the changeset
+    // overwrites them.
+    let recurrence = changeset.recurrence.clone().or_else(|| {
+      cell
+        .as_ref()
+        .and_then(|cell| cell.get_as::<String>(RECURRING_RULE))
+    });
+    let reminder_offset = changeset
+      .reminder_offset
+      .or_else(|| cell.as_ref().and_then(get_reminder_offset));
+    let new_cell = |cell_data: &DateCellData| {
+      let mut cell = Cell::from(cell_data);
+      if let Some(rule) = &recurrence {
+        insert_recurring_rule(&mut cell, rule);
+      }
+      if let Some(offset) = reminder_offset {
+        insert_reminder_offset(&mut cell, offset);
+      }
+      cell
+    };
+
     // old date cell data
     let cell_data = match cell {
       Some(cell) => DateCellData::from(&cell),
@@ -166,7 +276,8 @@ impl CellDataChangeset for DateTypeOption {
     let missing_timestamp = is_range && has_timestamp != has_end_timestamp;
 
     if unexpected_end_changeset || missing_timestamp {
-      return Ok((Cell::from(&cell_data), cell_data));
+      let cell = new_cell(&cell_data);
+      return Ok((cell, cell_data));
     }
 
     let DateCellData {
@@ -181,9 +292,21 @@ impl CellDataChangeset for DateTypeOption {
     let include_time = changeset.include_time.unwrap_or(include_time);
     let reminder_id = changeset.reminder_id.unwrap_or(reminder_id);
 
-    let timestamp = changeset.timestamp.or(timestamp);
+    // Changeset timestamps are the wall-clock times the user picked. When
+    // the field has a timezone, store them as UTC.
+    let timezone = parse_timezone(&self.timezone_id);
+    let to_utc = |timestamp: i64| match timezone {
+      Some(tz) => naive_to_utc_timestamp(timestamp, tz),
+      None => timestamp,
+    };
+
+    let timestamp = changeset.timestamp.map(to_utc).or(timestamp);
     let end_timestamp = if is_range && timestamp.is_some() {
-      changeset.end_timestamp.or(end_timestamp).or(timestamp)
+      changeset
+        .end_timestamp
+        .map(to_utc)
+        .or(end_timestamp)
+        .or(timestamp)
     } else {
       None
     };
@@ -196,7 +319,8 @@ impl CellDataChangeset for DateTypeOption {
       reminder_id,
     };
 
-    Ok((Cell::from(&cell_data), cell_data))
+    let cell = new_cell(&cell_data);
+    Ok((cell, cell_data))
   }
 }
 
@@ -2,3 +2,6 @@
 pub mod date_filter;
 mod date_tests;
 pub mod date_type_option;
+pub mod recurrence;
+pub mod reminder;
+pub mod timezone;
@@ -0,0 +1,187 @@
+use chrono::{DateTime, Datelike, Duration, Months, TimeZone, Utc};
+use collab::util::AnyMapExt;
+use collab_database::rows::Cell;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// The key of the recurrence rule inside a date cell. The rule is stored
+/// beside the collab date cell data so it survives plain date edits, see
+/// [DateTypeOption::apply_changeset].
+pub const RECURRING_RULE: &str = "recurring_rule";
+
+/// Safety cap on the number of occurrences expanded for a single rule.
+const MAX_OCCURRENCES: usize = 1000;
+
+/// An RRULE-like recurrence attached to a date cell: every `interval`
+/// days/weeks/months/years starting from the cell's timestamp, optionally
+/// stopping at `until`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecurringRule {
+  pub frequency: RecurringFrequency,
+  #[serde(default = "default_interval")]
+  pub interval: u32,
+  /// The timestamp after which no more occurrences are generated.
+  #[serde(default)]
+  pub until: Option<i64>,
+  /// Occurrence start timestamps that were deleted or detached into a
+  /// standalone row ("edit this occurrence").
+  #[serde(default)]
+  pub exdates: Vec<i64>,
+}
+
+fn default_interval() -> u32 {
+  1
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum RecurringFrequency {
+  #[default]
+  Daily = 0,
+  Weekly = 1,
+  Monthly = 2,
+  Yearly = 3,
+}
+
+impl RecurringRule {
+  pub fn from_json(json: &str) -> Option<Self> {
+    serde_json::from_str(json).ok()
+  }
+
+  pub fn to_json(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+
+  /// Expands the rule into the occurrence start timestamps that fall inside
+  /// `[range_start, range_end]`. The base timestamp of the cell itself is
+  /// included when it's in range and not excluded.
+  pub fn occurrences_between(&self, base: i64, range_start: i64, range_end: i64) -> Vec<i64> {
+    let interval = self.interval.max(1);
+    let mut occurrences = vec![];
+    let mut current = match Utc.timestamp_opt(base, 0).single() {
+      Some(datetime) => datetime,
+      None => return occurrences,
+    };
+
+    for _ in 0..MAX_OCCURRENCES {
+      let timestamp = current.timestamp();
+      if timestamp > range_end {
+        break;
+      }
+      if let Some(until) = self.until {
+        if timestamp > until {
+          break;
+        }
+      }
+      if timestamp >= range_start && !self.exdates.contains(&timestamp) {
+        occurrences.push(timestamp);
+      }
+      current = match self.next_occurrence(current, interval) {
+        Some(next) => next,
+        None => break,
+      };
+    }
+    occurrences
+  }
+
+  fn next_occurrence(&self, current: DateTime<Utc>, interval: u32) -> Option<DateTime<Utc>> {
+    match self.frequency {
+      RecurringFrequency::Daily => Some(current + Duration::days(interval as i64)),
+      RecurringFrequency::Weekly => Some(current + Duration::weeks(interval as i64)),
+      RecurringFrequency::Monthly => current.checked_add_months(Months::new(interval)),
+      RecurringFrequency::Yearly => current.with_year(current.year() + interval as i32),
+    }
+  }
+}
+
+pub fn get_recurring_rule(cell: &Cell) -> Option<RecurringRule> {
+  let json: String = cell.get_as(RECURRING_RULE)?;
+  RecurringRule::from_json(&json)
+}
+
+/// Writes the serialized rule into the cell, or removes it when the rule is
+/// an empty string.
+pub fn insert_recurring_rule(cell: &mut Cell, rule_json: &str) {
+  if rule_json.is_empty() {
+    cell.remove(RECURRING_RULE);
+  } else {
+    cell.insert(RECURRING_RULE.into(), rule_json.to_string().into());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const DAY: i64 = 24 * 60 * 60;
+  // 2022-05-01 00:00:00 UTC
+  const BASE: i64 = 1651363200;
+
+  fn daily_rule() -> RecurringRule {
+    RecurringRule {
+      frequency: RecurringFrequency::Daily,
+      interval: 1,
+      until: None,
+      exdates: vec![],
+    }
+  }
+
+  #[test]
+  fn daily_occurrences_test() {
+    let rule = daily_rule();
+    let occurrences = rule.occurrences_between(BASE, BASE, BASE + 3 * DAY);
+    assert_eq!(
+      occurrences,
+      vec![BASE, BASE + DAY, BASE + 2 * DAY, BASE + 3 * DAY]
+    );
+  }
+
+  #[test]
+  fn weekly_interval_occurrences_test() {
+    let rule = RecurringRule {
+      frequency: RecurringFrequency::Weekly,
+      interval: 2,
+      ..daily_rule()
+    };
+    let occurrences = rule.occurrences_between(BASE, BASE, BASE + 28 * DAY);
+    assert_eq!(occurrences, vec![BASE, BASE + 14 * DAY, BASE + 28 * DAY]);
+  }
+
+  #[test]
+  fn until_stops_expansion_test() {
+    let rule = RecurringRule {
+      until: Some(BASE + DAY),
+      ..daily_rule()
+    };
+    let occurrences = rule.occurrences_between(BASE, BASE, BASE + 10 * DAY);
+    assert_eq!(occurrences, vec![BASE, BASE + DAY]);
+  }
+
+  #[test]
+  fn exdate_skips_occurrence_test() {
+    let rule = RecurringRule {
+      exdates: vec![BASE + DAY],
+      ..daily_rule()
+    };
+    let occurrences = rule.occurrences_between(BASE, BASE, BASE + 2 * DAY);
+    assert_eq!(occurrences, vec![BASE, BASE + 2 * DAY]);
+  }
+
+  #[test]
+  fn occurrences_before_range_are_skipped_test() {
+    let rule = daily_rule();
+    let occurrences = rule.occurrences_between(BASE, BASE + 2 * DAY, BASE + 3 * DAY);
+    assert_eq!(occurrences, vec![BASE + 2 * DAY, BASE + 3 * DAY]);
+  }
+
+  #[test]
+  fn rule_json_round_trip_test() {
+    let rule = RecurringRule {
+      frequency: RecurringFrequency::Monthly,
+      interval: 3,
+      until: Some(BASE),
+      exdates: vec![BASE + DAY],
+    };
+    assert_eq!(RecurringRule::from_json(&rule.to_json()), Some(rule));
+  }
+}
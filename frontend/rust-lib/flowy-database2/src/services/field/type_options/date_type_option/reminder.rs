@@ -0,0 +1,26 @@
+use collab::util::AnyMapExt;
+use collab_database::rows::Cell;
+
+/// The key of the reminder offset inside a date cell: how many seconds
+/// before the date the reminder fires. Stored beside the collab date cell
+/// data so it survives plain date edits, see [DateTypeOption::apply_changeset].
+pub const REMINDER_OFFSET: &str = "reminder_offset";
+
+pub fn get_reminder_offset(cell: &Cell) -> Option<i64> {
+  cell.get_as(REMINDER_OFFSET)
+}
+
+/// Writes the offset into the cell, or removes it when the offset is
+/// negative.
+pub fn insert_reminder_offset(cell: &mut Cell, offset: i64) {
+  if offset < 0 {
+    cell.remove(REMINDER_OFFSET);
+  } else {
+    cell.insert(REMINDER_OFFSET.into(), offset.into());
+  }
+}
+
+/// The timestamp at which a reminder with the given offset fires.
+pub fn reminder_fire_time(timestamp: i64, offset: i64) -> i64 {
+  timestamp - offset.max(0)
+}
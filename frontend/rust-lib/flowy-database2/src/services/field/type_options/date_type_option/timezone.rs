@@ -0,0 +1,72 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Parses the timezone id of a `DateTypeOption`. An empty or unknown id
+/// returns `None`, in which case timestamps pass through unchanged and the
+/// cell keeps its historical naive behavior.
+pub fn parse_timezone(timezone_id: &str) -> Option<Tz> {
+  timezone_id.parse().ok()
+}
+
+/// Interprets `naive_timestamp` as a wall-clock time in `tz` and returns the
+/// corresponding UTC timestamp. This is the write direction: the UI sends
+/// the time the user picked, the cell stores UTC.
+pub fn naive_to_utc_timestamp(naive_timestamp: i64, tz: Tz) -> i64 {
+  match DateTime::from_timestamp(naive_timestamp, 0) {
+    Some(datetime) => tz
+      .from_local_datetime(&datetime.naive_utc())
+      .earliest()
+      .map(|datetime| datetime.timestamp())
+      .unwrap_or(naive_timestamp),
+    None => naive_timestamp,
+  }
+}
+
+/// Converts a UTC timestamp into the wall-clock time in `tz`. This is the
+/// read direction: the cell stores UTC, the UI renders the time in the
+/// field's timezone.
+pub fn utc_to_naive_timestamp(utc_timestamp: i64, tz: Tz) -> i64 {
+  match Utc.timestamp_opt(utc_timestamp, 0).single() {
+    Some(datetime) => datetime
+      .with_timezone(&tz)
+      .naive_local()
+      .and_utc()
+      .timestamp(),
+    None => utc_timestamp,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // 2022-01-15 12:00:00, a date outside daylight saving time.
+  const WINTER_NOON: i64 = 1642248000;
+
+  #[test]
+  fn parse_timezone_test() {
+    assert_eq!(parse_timezone("America/New_York"), Some(Tz::America__New_York));
+    assert_eq!(parse_timezone(""), None);
+    assert_eq!(parse_timezone("Not/AZone"), None);
+  }
+
+  #[test]
+  fn naive_to_utc_test() {
+    // Noon in New York (UTC-5 in winter) is 17:00 UTC.
+    let utc = naive_to_utc_timestamp(WINTER_NOON, Tz::America__New_York);
+    assert_eq!(utc, WINTER_NOON + 5 * 3600);
+  }
+
+  #[test]
+  fn utc_to_naive_test() {
+    let naive = utc_to_naive_timestamp(WINTER_NOON + 5 * 3600, Tz::America__New_York);
+    assert_eq!(naive, WINTER_NOON);
+  }
+
+  #[test]
+  fn round_trip_test() {
+    let tz = Tz::Asia__Tokyo;
+    let utc = naive_to_utc_timestamp(WINTER_NOON, tz);
+    assert_eq!(utc_to_naive_timestamp(utc, tz), WINTER_NOON);
+  }
+}
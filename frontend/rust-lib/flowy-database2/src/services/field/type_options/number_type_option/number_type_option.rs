@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 
+use collab::preclude::Any;
 use collab_database::database::Database;
 use collab_database::fields::number_type_option::{
   NumberCellFormat, NumberFormat, NumberTypeOption,
@@ -9,13 +10,17 @@ use collab_database::rows::Cell;
 use fancy_regex::Regex;
 use flowy_error::FlowyResult;
 use lazy_static::lazy_static;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
 
 use collab_database::template::number_parse::NumberCellData;
 use std::cmp::Ordering;
+use std::str::FromStr;
 
 use tracing::info;
 
-use crate::entities::{FieldType, NumberFilterPB};
+use crate::entities::{FieldType, NumberFilterPB, NumberTypeOptionPB};
 use crate::services::cell::{CellDataChangeset, CellDataDecoder};
 use crate::services::field::type_options::util::ProtobufStr;
 use crate::services::field::{
@@ -24,6 +29,312 @@ use crate::services::field::{
 };
 use crate::services::sort::SortCondition;
 
+/// Key under which the formatting extras are stored in the number field's
+/// type option data. Kept outside [NumberTypeOption] whose layout is fixed
+/// by collab-database.
+pub const NUMBER_FORMAT_META: &str = "format_meta";
+
+/// Formatting extras of a number field: a unit prefix/suffix, custom
+/// thousands/decimal separators and significant-digit rounding. Empty
+/// strings and a zero digit count mean the extra is unset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NumberFormatMeta {
+  #[serde(default)]
+  pub prefix: String,
+
+  #[serde(default)]
+  pub suffix: String,
+
+  #[serde(default)]
+  pub thousands_separator: String,
+
+  #[serde(default)]
+  pub decimal_separator: String,
+
+  #[serde(default)]
+  pub significant_digits: u32,
+}
+
+impl NumberFormatMeta {
+  pub fn is_empty(&self) -> bool {
+    self.prefix.is_empty()
+      && self.suffix.is_empty()
+      && self.thousands_separator.is_empty()
+      && self.decimal_separator.is_empty()
+      && self.significant_digits == 0
+  }
+
+  /// Whether the number itself has to be re-rendered instead of reusing the
+  /// output of the built-in format.
+  fn overrides_rendering(&self) -> bool {
+    !self.thousands_separator.is_empty()
+      || !self.decimal_separator.is_empty()
+      || self.significant_digits > 0
+  }
+}
+
+pub fn number_format_meta_from_data(data: &TypeOptionData) -> NumberFormatMeta {
+  data
+    .get(NUMBER_FORMAT_META)
+    .and_then(|any| match any {
+      Any::String(json) => serde_json::from_str(json.as_ref()).ok(),
+      _ => None,
+    })
+    .unwrap_or_default()
+}
+
+/// Collects the formatting extras carried by the type option payload.
+pub fn number_format_meta_from_pb(pb: &NumberTypeOptionPB) -> NumberFormatMeta {
+  NumberFormatMeta {
+    prefix: pb.prefix.clone(),
+    suffix: pb.suffix.clone(),
+    thousands_separator: pb.thousands_separator.clone(),
+    decimal_separator: pb.decimal_separator.clone(),
+    significant_digits: pb.significant_digits,
+  }
+}
+
+/// Decorates the type option payload with the formatting extras stored in
+/// the type option data.
+pub fn apply_number_format_meta(pb: &mut NumberTypeOptionPB, meta: NumberFormatMeta) {
+  pb.prefix = meta.prefix;
+  pb.suffix = meta.suffix;
+  pb.thousands_separator = meta.thousands_separator;
+  pb.decimal_separator = meta.decimal_separator;
+  pb.significant_digits = meta.significant_digits;
+}
+
+pub fn insert_number_format_meta(data: &mut TypeOptionData, meta: NumberFormatMeta) {
+  if meta.is_empty() {
+    return;
+  }
+  if let Ok(json) = serde_json::to_string(&meta) {
+    data.insert(NUMBER_FORMAT_META.to_string(), Any::from(json));
+  }
+}
+
+/// [NumberTypeOption] together with the formatting extras stored next to it
+/// in the type option data. Cell handlers are built from this type so the
+/// formatter can see the extras; code that doesn't care about formatting
+/// keeps using [NumberTypeOption] directly.
+#[derive(Debug, Clone, Default)]
+pub struct NumberTypeOptionExt {
+  pub inner: NumberTypeOption,
+  pub meta: NumberFormatMeta,
+}
+
+impl From<TypeOptionData> for NumberTypeOptionExt {
+  fn from(data: TypeOptionData) -> Self {
+    let meta = number_format_meta_from_data(&data);
+    Self {
+      inner: NumberTypeOption::from(data),
+      meta,
+    }
+  }
+}
+
+impl From<NumberTypeOptionExt> for TypeOptionData {
+  fn from(type_option: NumberTypeOptionExt) -> Self {
+    let mut data: TypeOptionData = type_option.inner.into();
+    insert_number_format_meta(&mut data, type_option.meta);
+    data
+  }
+}
+
+impl NumberTypeOptionExt {
+  /// Renders the cell the way it is displayed: the built-in format first,
+  /// then the extras on top. When custom separators or significant-digit
+  /// rounding are set, the number is re-rendered from its raw value and the
+  /// prefix/suffix take over the unit display from the built-in symbol.
+  fn format_with_meta(&self, cell_data: &NumberCellData) -> String {
+    let formatter = match self.inner.format_cell_data(cell_data) {
+      Ok(formatter) => formatter,
+      Err(_) => return "".to_string(),
+    };
+    let mut body = formatter.to_string();
+    if body.is_empty() {
+      return body;
+    }
+
+    if self.meta.overrides_rendering() {
+      if let Ok(mut decimal) = Decimal::from_str(&formatter.to_unformatted_string()) {
+        if self.meta.significant_digits > 0 {
+          decimal = round_significant(decimal, self.meta.significant_digits);
+        }
+        let decimal_separator = if self.meta.decimal_separator.is_empty() {
+          "."
+        } else {
+          &self.meta.decimal_separator
+        };
+        body = render_decimal(decimal, &self.meta.thousands_separator, decimal_separator);
+      }
+    }
+
+    format!("{}{}{}", self.meta.prefix, body, self.meta.suffix)
+  }
+
+  /// Strips the extras from user input so both the display form and the raw
+  /// form of a number are accepted.
+  fn normalize_input(&self, input: String) -> String {
+    let mut input = input;
+    for affix in [&self.meta.prefix, &self.meta.suffix] {
+      if !affix.is_empty() {
+        input = input.replace(affix.as_str(), "");
+      }
+    }
+    if !self.meta.thousands_separator.is_empty() {
+      input = input.replace(&self.meta.thousands_separator, "");
+    }
+    if !self.meta.decimal_separator.is_empty() && self.meta.decimal_separator != "." {
+      input = input.replace(&self.meta.decimal_separator, ".");
+    }
+    input.trim().to_string()
+  }
+}
+
+/// Rounds a number to the given amount of significant digits.
+fn round_significant(decimal: Decimal, digits: u32) -> Decimal {
+  if decimal.is_zero() {
+    return decimal;
+  }
+  let Some(value) = decimal.to_f64() else {
+    return decimal;
+  };
+  let magnitude = value.abs().log10().floor() as i64;
+  let decimal_places = digits as i64 - 1 - magnitude;
+  if decimal_places >= 0 {
+    decimal.round_dp(decimal_places.min(u32::MAX as i64) as u32)
+  } else {
+    let factor = Decimal::from(10u64.pow((-decimal_places).min(18) as u32));
+    (decimal / factor).round() * factor
+  }
+}
+
+/// Renders a decimal with the given separators. Thousands grouping is only
+/// applied when a thousands separator is set.
+fn render_decimal(decimal: Decimal, thousands_separator: &str, decimal_separator: &str) -> String {
+  let raw = decimal.normalize().to_string();
+  let (int_part, frac_part) = match raw.split_once('.') {
+    Some((int_part, frac_part)) => (int_part, frac_part),
+    None => (raw.as_str(), ""),
+  };
+  let negative = int_part.starts_with('-');
+  let digits = int_part.trim_start_matches('-');
+
+  let mut rendered = String::new();
+  if negative {
+    rendered.push('-');
+  }
+  if thousands_separator.is_empty() {
+    rendered.push_str(digits);
+  } else {
+    for (index, char) in digits.chars().enumerate() {
+      if index > 0 && (digits.len() - index) % 3 == 0 {
+        rendered.push_str(thousands_separator);
+      }
+      rendered.push(char);
+    }
+  }
+  if !frac_part.is_empty() {
+    rendered.push_str(decimal_separator);
+    rendered.push_str(frac_part);
+  }
+  rendered
+}
+
+impl TypeOption for NumberTypeOptionExt {
+  type CellData = NumberCellData;
+  type CellChangeset = NumberCellChangeset;
+  type CellProtobufType = ProtobufStr;
+  type CellFilter = NumberFilterPB;
+}
+
+impl CellDataProtobufEncoder for NumberTypeOptionExt {
+  fn protobuf_encode(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    ProtobufStr::from(self.format_with_meta(&cell_data))
+  }
+}
+
+#[async_trait]
+impl TypeOptionTransform for NumberTypeOptionExt {
+  async fn transform_type_option(
+    &mut self,
+    view_id: &str,
+    field_id: &str,
+    old_type_option_field_type: FieldType,
+    old_type_option_data: TypeOptionData,
+    new_type_option_field_type: FieldType,
+    database: &mut Database,
+  ) {
+    self
+      .inner
+      .transform_type_option(
+        view_id,
+        field_id,
+        old_type_option_field_type,
+        old_type_option_data,
+        new_type_option_field_type,
+        database,
+      )
+      .await
+  }
+}
+
+impl CellDataDecoder for NumberTypeOptionExt {
+  fn decode_cell(&self, cell: &Cell) -> FlowyResult<<Self as TypeOption>::CellData> {
+    self.inner.decode_cell(cell)
+  }
+
+  fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    self.format_with_meta(&cell_data)
+  }
+
+  fn decode_cell_with_transform(
+    &self,
+    cell: &Cell,
+    from_field_type: FieldType,
+    field: &Field,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    self
+      .inner
+      .decode_cell_with_transform(cell, from_field_type, field)
+  }
+}
+
+impl CellDataChangeset for NumberTypeOptionExt {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    cell: Option<Cell>,
+  ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
+    self.inner.apply_changeset(self.normalize_input(changeset), cell)
+  }
+}
+
+impl TypeOptionCellDataFilter for NumberTypeOptionExt {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    self.inner.apply_filter(filter, cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for NumberTypeOptionExt {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+  ) -> Ordering {
+    self.inner.apply_cmp(cell_data, other_cell_data, sort_condition)
+  }
+}
+
 impl TypeOption for NumberTypeOption {
   type CellData = NumberCellData;
   type CellChangeset = NumberCellChangeset;
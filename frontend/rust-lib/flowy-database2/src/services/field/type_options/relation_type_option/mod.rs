@@ -1,4 +1,6 @@
 mod relation;
 mod relation_entities;
+mod relation_service;
 
 pub use relation_entities::*;
+pub use relation_service::*;
@@ -74,6 +74,10 @@ impl TypeOptionCellDataCompare for RelationTypeOption {
 }
 
 impl TypeOptionCellDataFilter for RelationTypeOption {
+  /// Relation filters with a remote condition are answered in the filter
+  /// controller, which pre-resolves the related rows' cells through the
+  /// relation cell resolver. Without a remote condition the filter has no
+  /// effect.
   fn apply_filter(&self, _filter: &RelationFilterPB, _cell_data: &RelationCellData) -> bool {
     true
   }
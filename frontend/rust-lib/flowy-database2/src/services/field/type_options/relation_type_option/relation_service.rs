@@ -0,0 +1,72 @@
+use std::sync::Weak;
+use std::time::{Duration, Instant};
+
+use collab_database::rows::RowId;
+use dashmap::DashMap;
+
+use crate::manager::DatabaseManager;
+use crate::services::cell::stringify_cell;
+
+/// How long a resolved remote cell stays valid before it is read from the
+/// related database again.
+const REMOTE_CELL_CACHE_TTL: Duration = Duration::from_secs(30);
+
+struct CachedRemoteCell {
+  text: String,
+  resolved_at: Instant,
+}
+
+/// Resolves cells of rows that live in another database, e.g. when a filter
+/// compares against a field of a related row. Resolved cells are cached for a
+/// short time because a single filter pass asks for the same remote rows over
+/// and over.
+pub struct RelationCellResolver {
+  manager: Weak<DatabaseManager>,
+  cache: DashMap<String, CachedRemoteCell>,
+}
+
+impl RelationCellResolver {
+  pub fn new(manager: Weak<DatabaseManager>) -> Self {
+    Self {
+      manager,
+      cache: DashMap::new(),
+    }
+  }
+
+  /// Returns the text representation of the cell of `field_id` in the given
+  /// row of the related database, or `None` when the database or the field
+  /// cannot be resolved. A row without a cell resolves to an empty string.
+  pub async fn resolve_remote_cell_text(
+    &self,
+    database_id: &str,
+    row_id: &str,
+    field_id: &str,
+  ) -> Option<String> {
+    let key = format!("{}:{}:{}", database_id, row_id, field_id);
+    if let Some(cached) = self.cache.get(&key) {
+      if cached.resolved_at.elapsed() < REMOTE_CELL_CACHE_TTL {
+        return Some(cached.text.clone());
+      }
+    }
+
+    let manager = self.manager.upgrade()?;
+    let editor = manager
+      .get_or_init_database_editor(database_id)
+      .await
+      .ok()?;
+    let field = editor.get_field(field_id).await?;
+    let row_id = RowId::from(row_id.to_string());
+    let text = match editor.get_cell(field_id, &row_id).await {
+      Some(cell) => stringify_cell(&cell, &field),
+      None => String::new(),
+    };
+    self.cache.insert(
+      key,
+      CachedRemoteCell {
+        text: text.clone(),
+        resolved_at: Instant::now(),
+      },
+    );
+    Some(text)
+  }
+}
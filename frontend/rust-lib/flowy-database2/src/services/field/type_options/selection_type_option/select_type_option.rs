@@ -1,4 +1,4 @@
-use crate::entities::{CheckboxCellDataPB, FieldType, SelectOptionCellDataPB};
+use crate::entities::{CheckboxCellDataPB, FieldType, SelectOptionCellDataPB, SelectOptionPB};
 use crate::services::cell::{CellDataDecoder, CellProtobufBlobParser};
 use crate::services::field::selection_type_option::type_option_transform::SelectOptionTypeOptionTransformHelper;
 use crate::services::field::{
@@ -6,6 +6,7 @@ use crate::services::field::{
 };
 use async_trait::async_trait;
 use bytes::Bytes;
+use collab::preclude::Any;
 use collab_database::database::Database;
 use collab_database::fields::select_type_option::{
   MultiSelectTypeOption, SELECTION_IDS_SEPARATOR, SelectOption, SelectOptionColor, SelectOptionIds,
@@ -15,6 +16,8 @@ use collab_database::fields::{Field, TypeOptionData};
 use collab_database::rows::Cell;
 use collab_database::template::util::ToCellString;
 use flowy_error::{ErrorCode, FlowyResult, internal_error};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::str::FromStr;
 
 /// Defines the shared actions used by SingleSelect or Multi-Select.
@@ -194,6 +197,128 @@ pub fn new_select_option_color(options: &[SelectOption]) -> SelectOptionColor {
   }
 }
 
+/// Key under which per-option extras are stored in the select field's type
+/// option data, next to the options themselves. Kept outside [SelectOption]
+/// so the fixed palette format stays readable by older clients.
+pub const SELECT_OPTION_META: &str = "option_meta";
+
+/// Extras of a single select option: an arbitrary hex color overriding the
+/// fixed palette and an optional label used to group options together.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SelectOptionMeta {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub hex_color: Option<String>,
+
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub group: Option<String>,
+}
+
+impl SelectOptionMeta {
+  pub fn is_empty(&self) -> bool {
+    self.hex_color.is_none() && self.group.is_none()
+  }
+}
+
+/// Returns true for colors like `#AABBCC` or `#ABC`.
+pub fn is_valid_hex_color(color: &str) -> bool {
+  let Some(digits) = color.strip_prefix('#') else {
+    return false;
+  };
+  (digits.len() == 3 || digits.len() == 6) && digits.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+pub fn select_option_meta_from_data(data: &TypeOptionData) -> HashMap<String, SelectOptionMeta> {
+  data
+    .get(SELECT_OPTION_META)
+    .and_then(|any| match any {
+      Any::String(json) => serde_json::from_str(json.as_ref()).ok(),
+      _ => None,
+    })
+    .unwrap_or_default()
+}
+
+pub fn select_option_meta_from_field(field: &Field) -> HashMap<String, SelectOptionMeta> {
+  let field_type = FieldType::from(field.field_type);
+  if !field_type.is_select_option() {
+    return HashMap::new();
+  }
+  field
+    .get_any_type_option(field_type)
+    .map(|data| select_option_meta_from_data(&data))
+    .unwrap_or_default()
+}
+
+/// Collects the extras carried by the given option payloads, dropping
+/// malformed hex colors.
+pub fn select_option_meta_from_pb_options(
+  options: &[SelectOptionPB],
+) -> HashMap<String, SelectOptionMeta> {
+  options
+    .iter()
+    .filter_map(|option| {
+      let meta = SelectOptionMeta {
+        hex_color: option
+          .hex_color
+          .clone()
+          .filter(|color| is_valid_hex_color(color)),
+        group: option.group.clone().filter(|group| !group.is_empty()),
+      };
+      if meta.is_empty() {
+        None
+      } else {
+        Some((option.id.clone(), meta))
+      }
+    })
+    .collect()
+}
+
+/// Decorates the option payloads with the extras stored in the type option
+/// data.
+pub fn apply_select_option_meta(
+  options: &mut [SelectOptionPB],
+  meta_by_option_id: &HashMap<String, SelectOptionMeta>,
+) {
+  for option in options {
+    if let Some(meta) = meta_by_option_id.get(&option.id) {
+      option.hex_color = meta.hex_color.clone();
+      option.group = meta.group.clone();
+    }
+  }
+}
+
+pub fn insert_select_option_meta(
+  data: &mut TypeOptionData,
+  meta_by_option_id: HashMap<String, SelectOptionMeta>,
+) {
+  let meta_by_option_id: HashMap<_, _> = meta_by_option_id
+    .into_iter()
+    .filter(|(_, meta)| !meta.is_empty())
+    .collect();
+  if meta_by_option_id.is_empty() {
+    return;
+  }
+  if let Ok(json) = serde_json::to_string(&meta_by_option_id) {
+    data.insert(SELECT_OPTION_META.to_string(), Any::from(json));
+  }
+}
+
+/// Builds the type option data of the select field, carrying over the
+/// per-option extras of the options that still exist.
+pub fn select_type_option_data_with_meta(
+  type_option: &dyn SelectTypeOptionSharedAction,
+  mut meta_by_option_id: HashMap<String, SelectOptionMeta>,
+) -> TypeOptionData {
+  meta_by_option_id.retain(|option_id, _| {
+    type_option
+      .options()
+      .iter()
+      .any(|option| &option.id == option_id)
+  });
+  let mut data = type_option.to_type_option_data();
+  insert_select_option_meta(&mut data, meta_by_option_id);
+  data
+}
+
 pub struct SelectOptionIdsParser();
 impl CellProtobufBlobParser for SelectOptionIdsParser {
   type Object = SelectOptionIds;
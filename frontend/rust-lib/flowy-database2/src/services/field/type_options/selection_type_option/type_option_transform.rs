@@ -1,6 +1,8 @@
 use crate::entities::FieldType;
 use crate::services::cell::CellDataDecoder;
-use crate::services::field::{CHECK, SelectTypeOptionSharedAction, TypeOption, UNCHECK};
+use crate::services::field::{
+  CHECK, NumberTypeOptionExt, SelectTypeOptionSharedAction, TypeOption, UNCHECK,
+};
 use collab_database::database::Database;
 use collab_database::fields::TypeOptionData;
 use collab_database::fields::select_type_option::{
@@ -95,6 +97,53 @@ impl SelectOptionTypeOptionTransformHelper {
           shared.mut_options().push(uncheck_option);
         }
       },
+      FieldType::Number => {
+        if !shared.options().is_empty() {
+          return;
+        }
+        // One option per distinct formatted value, so the numbers stay
+        // readable after the switch.
+        let number_type_option = NumberTypeOptionExt::from(old_type_option_data);
+        let rows = database
+          .get_cells_for_field(view_id, field_id)
+          .await
+          .into_iter()
+          .filter_map(|row| row.cell.map(|cell| (row.row_id, cell)))
+          .map(|(row_id, cell)| {
+            let text = number_type_option
+              .decode_cell(&cell)
+              .map(|cell_data| number_type_option.stringify_cell_data(cell_data))
+              .unwrap_or_default();
+            (row_id, text)
+          })
+          .collect::<Vec<_>>();
+
+        let options =
+          build_options_from_cells(&rows.iter().map(|row| row.1.clone()).collect::<Vec<_>>());
+        info!(
+          "Transforming Number to SelectOption, updating {} row's cell content",
+          rows.len()
+        );
+        for (row_id, text) in rows {
+          let transformed_ids = options
+            .iter()
+            .filter(|option| option.name == text)
+            .map(|option| option.id.clone())
+            .collect::<Vec<_>>();
+          database
+            .update_row(row_id, |row| {
+              row.update_cells(|cell| {
+                cell.insert(
+                  field_id,
+                  SelectOptionIds::from(transformed_ids).to_cell(new_field_type),
+                );
+              });
+            })
+            .await;
+        }
+
+        shared.mut_options().extend(options);
+      },
       FieldType::MultiSelect => {
         let options = SelectTypeOption::from(old_type_option_data).options;
         options.iter().for_each(|new_option| {
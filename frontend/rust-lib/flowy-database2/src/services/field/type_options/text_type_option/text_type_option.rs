@@ -1,18 +1,25 @@
+use async_trait::async_trait;
 use collab::util::AnyMapExt;
 use std::cmp::Ordering;
 
-use collab_database::fields::Field;
+use collab_database::database::Database;
+use collab_database::fields::checkbox_type_option::CheckboxTypeOption;
+use collab_database::fields::date_type_option::{DateTypeOption, TimeTypeOption};
+use collab_database::fields::select_type_option::{MultiSelectTypeOption, SingleSelectTypeOption};
 use collab_database::fields::text_type_option::RichTextTypeOption;
+use collab_database::fields::url_type_option::URLTypeOption;
+use collab_database::fields::{Field, TypeOptionData};
 use collab_database::rows::{Cell, new_cell_builder};
 use collab_database::template::util::ToCellString;
 use flowy_error::{FlowyError, FlowyResult};
+use tracing::info;
 
 use crate::entities::{FieldType, TextFilterPB};
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, stringify_cell};
 use crate::services::field::type_options::util::ProtobufStr;
 use crate::services::field::{
-  CELL_DATA, CellDataProtobufEncoder, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare,
-  TypeOptionCellDataFilter, TypeOptionTransform,
+  CELL_DATA, CellDataProtobufEncoder, NumberTypeOptionExt, TypeOption, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use crate::services::sort::SortCondition;
 
@@ -23,7 +30,67 @@ impl TypeOption for RichTextTypeOption {
   type CellFilter = TextFilterPB;
 }
 
-impl TypeOptionTransform for RichTextTypeOption {}
+/// Decodes the cell with the old field's type option and renders it the way
+/// the old field displayed it.
+fn stringify_as<T>(old_type_option_data: TypeOptionData, cell: &Cell) -> Option<String>
+where
+  T: From<TypeOptionData> + CellDataDecoder,
+{
+  let type_option = T::from(old_type_option_data);
+  let cell_data = type_option.decode_cell(cell).ok()?;
+  Some(type_option.stringify_cell_data(cell_data))
+}
+
+#[async_trait]
+impl TypeOptionTransform for RichTextTypeOption {
+  async fn transform_type_option(
+    &mut self,
+    view_id: &str,
+    field_id: &str,
+    old_type_option_field_type: FieldType,
+    old_type_option_data: TypeOptionData,
+    _new_type_option_field_type: FieldType,
+    database: &mut Database,
+  ) {
+    // Rewrite the cells to the display value of the old field so the text
+    // holds readable content (option names, formatted dates, …) instead of
+    // the old type's raw representation. Lossless for the display form; the
+    // raw representation is dropped.
+    let stringify: fn(TypeOptionData, &Cell) -> Option<String> = match old_type_option_field_type {
+      FieldType::Number => stringify_as::<NumberTypeOptionExt>,
+      FieldType::DateTime => stringify_as::<DateTypeOption>,
+      FieldType::SingleSelect => stringify_as::<SingleSelectTypeOption>,
+      FieldType::MultiSelect => stringify_as::<MultiSelectTypeOption>,
+      FieldType::Checkbox => stringify_as::<CheckboxTypeOption>,
+      FieldType::URL => stringify_as::<URLTypeOption>,
+      FieldType::Time => stringify_as::<TimeTypeOption>,
+      _ => return,
+    };
+
+    let rows = database
+      .get_cells_for_field(view_id, field_id)
+      .await
+      .into_iter()
+      .filter_map(|row| row.cell.map(|cell| (row.row_id, cell)))
+      .collect::<Vec<_>>();
+    info!(
+      "Transforming {} to RichText, updating {} row's cell content",
+      old_type_option_field_type,
+      rows.len()
+    );
+    for (row_id, cell) in rows {
+      if let Some(text) = stringify(old_type_option_data.clone(), &cell) {
+        database
+          .update_row(row_id, |row| {
+            row.update_cells(|cell_update| {
+              cell_update.insert(field_id, StringCellData(text.clone()));
+            });
+          })
+          .await;
+      }
+    }
+  }
+}
 
 impl CellDataProtobufEncoder for RichTextTypeOption {
   fn protobuf_encode(
@@ -1,4 +1,6 @@
 mod time;
 mod time_filter;
+mod time_tracking;
 
 pub use time::*;
+pub use time_tracking::*;
@@ -25,11 +25,9 @@ impl CellDataProtobufEncoder for TimeTypeOption {
     &self,
     cell_data: <Self as TypeOption>::CellData,
   ) -> <Self as TypeOption>::CellProtobufType {
-    if let Some(time) = cell_data.0 {
-      return TimeCellDataPB { time };
-    }
     TimeCellDataPB {
-      time: i64::default(),
+      time: cell_data.0.unwrap_or_default(),
+      ..Default::default()
     }
   }
 }
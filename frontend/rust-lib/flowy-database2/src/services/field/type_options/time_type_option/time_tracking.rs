@@ -0,0 +1,118 @@
+use collab::util::AnyMapExt;
+use collab_database::rows::Cell;
+use collab_database::template::time_parse::TimeCellData;
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The key of the tracked sessions inside a time cell. Sessions are stored
+/// beside the collab time cell data, which keeps holding the total duration so
+/// filters, sorts and sum/average calculations keep working.
+pub const TIME_TRACKING_SESSIONS: &str = "sessions";
+/// The key of the start timestamp of the currently running timer. Absent when
+/// no timer is running on the cell.
+pub const TIME_TRACKING_STARTED_AT: &str = "started_at";
+
+/// One finished tracking session, in unix seconds.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TimeSession {
+  pub start: i64,
+  pub end: i64,
+}
+
+impl TimeSession {
+  pub fn duration(&self) -> i64 {
+    (self.end - self.start).max(0)
+  }
+}
+
+pub fn get_time_sessions(cell: &Cell) -> Vec<TimeSession> {
+  cell
+    .get_as::<String>(TIME_TRACKING_SESSIONS)
+    .and_then(|json| serde_json::from_str(&json).ok())
+    .unwrap_or_default()
+}
+
+/// The start timestamp of the timer currently running on the cell, if any.
+pub fn get_time_tracking_started_at(cell: &Cell) -> Option<i64> {
+  cell.get_as(TIME_TRACKING_STARTED_AT)
+}
+
+/// Starts a timer on the cell at `now`. `cell` is the current time cell, or
+/// [None] when the row doesn't have one yet.
+pub fn start_time_session(cell: Option<&Cell>, now: i64) -> FlowyResult<Cell> {
+  let mut new_cell = cell
+    .cloned()
+    .unwrap_or_else(|| Cell::from(&TimeCellData(None)));
+  if get_time_tracking_started_at(&new_cell).is_some() {
+    return Err(FlowyError::invalid_data().with_context("A timer is already running on this cell"));
+  }
+  new_cell.insert(TIME_TRACKING_STARTED_AT.into(), now.into());
+  Ok(new_cell)
+}
+
+/// Stops the running timer at `now`, appending the finished session and adding
+/// its duration to the cell's total time.
+pub fn stop_time_session(cell: Option<&Cell>, now: i64) -> FlowyResult<Cell> {
+  let no_running_timer =
+    || FlowyError::invalid_data().with_context("No timer is running on this cell");
+  let cell = cell.ok_or_else(no_running_timer)?;
+  let started_at = get_time_tracking_started_at(cell).ok_or_else(no_running_timer)?;
+
+  let session = TimeSession {
+    start: started_at,
+    end: now.max(started_at),
+  };
+  let total = TimeCellData::from(cell).0.unwrap_or_default() + session.duration();
+  let mut sessions = get_time_sessions(cell);
+  sessions.push(session);
+
+  let mut new_cell = Cell::from(&TimeCellData(Some(total)));
+  new_cell.insert(
+    TIME_TRACKING_SESSIONS.into(),
+    serde_json::to_string(&sessions).unwrap_or_default().into(),
+  );
+  Ok(new_cell)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn start_then_stop_accumulates_total_test() {
+    let cell = start_time_session(None, 100).unwrap();
+    assert_eq!(get_time_tracking_started_at(&cell), Some(100));
+
+    let cell = stop_time_session(Some(&cell), 160).unwrap();
+    assert_eq!(get_time_tracking_started_at(&cell), None);
+    assert_eq!(TimeCellData::from(&cell).0, Some(60));
+    assert_eq!(get_time_sessions(&cell), vec![TimeSession {
+      start: 100,
+      end: 160
+    }]);
+  }
+
+  #[test]
+  fn second_session_adds_to_total_test() {
+    let cell = start_time_session(None, 0).unwrap();
+    let cell = stop_time_session(Some(&cell), 30).unwrap();
+    let cell = start_time_session(Some(&cell), 100).unwrap();
+    let cell = stop_time_session(Some(&cell), 170).unwrap();
+
+    assert_eq!(TimeCellData::from(&cell).0, Some(100));
+    assert_eq!(get_time_sessions(&cell).len(), 2);
+  }
+
+  #[test]
+  fn double_start_fails_test() {
+    let cell = start_time_session(None, 0).unwrap();
+    assert!(start_time_session(Some(&cell), 10).is_err());
+  }
+
+  #[test]
+  fn stop_without_start_fails_test() {
+    assert!(stop_time_session(None, 10).is_err());
+    let cell = Cell::from(&TimeCellData(Some(5)));
+    assert!(stop_time_session(Some(&cell), 10).is_err());
+  }
+}
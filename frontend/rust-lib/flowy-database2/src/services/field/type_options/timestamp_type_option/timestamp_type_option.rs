@@ -1,15 +1,235 @@
-use crate::entities::{DateFilterPB, TimestampCellDataPB};
+use crate::entities::{DateFilterPB, TimestampCellDataPB, TimestampTypeOptionPB};
 use crate::services::cell::{CellDataChangeset, CellDataDecoder};
 use crate::services::field::{
   CellDataProtobufEncoder, TypeOption, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
   TypeOptionTransform, default_order,
 };
 use crate::services::sort::SortCondition;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, Utc};
+use chrono_tz::Tz;
+use collab::preclude::Any;
+use collab_database::fields::TypeOptionData;
 use collab_database::fields::timestamp_type_option::TimestampTypeOption;
 use collab_database::rows::Cell;
 use collab_database::template::timestamp_parse::TimestampCellData;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::util::timestamp;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Key under which the display extras are stored in the timestamp field's
+/// type option data. Kept outside [TimestampTypeOption] whose layout is
+/// fixed by collab-database.
+pub const TIMESTAMP_DISPLAY_META: &str = "display_meta";
+
+/// Display extras of a created-at/last-edited field: a custom strftime
+/// format string overriding the fixed formats and a relative display mode
+/// ("3 hours ago"). Relative display wins over the custom format.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimestampDisplayMeta {
+  #[serde(default)]
+  pub custom_format: String,
+
+  #[serde(default)]
+  pub show_relative: bool,
+}
+
+impl TimestampDisplayMeta {
+  pub fn is_empty(&self) -> bool {
+    self.custom_format.is_empty() && !self.show_relative
+  }
+}
+
+/// Returns true when chrono can render the given strftime format string.
+pub fn is_valid_strftime_format(format: &str) -> bool {
+  !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+}
+
+pub fn timestamp_display_meta_from_data(data: &TypeOptionData) -> TimestampDisplayMeta {
+  data
+    .get(TIMESTAMP_DISPLAY_META)
+    .and_then(|any| match any {
+      Any::String(json) => serde_json::from_str(json.as_ref()).ok(),
+      _ => None,
+    })
+    .unwrap_or_default()
+}
+
+pub fn insert_timestamp_display_meta(data: &mut TypeOptionData, meta: TimestampDisplayMeta) {
+  if meta.is_empty() {
+    return;
+  }
+  if let Ok(json) = serde_json::to_string(&meta) {
+    data.insert(TIMESTAMP_DISPLAY_META.to_string(), Any::from(json));
+  }
+}
+
+/// Collects the display extras carried by the type option payload, dropping
+/// format strings chrono can't render.
+pub fn timestamp_display_meta_from_pb(pb: &TimestampTypeOptionPB) -> TimestampDisplayMeta {
+  TimestampDisplayMeta {
+    custom_format: Some(pb.custom_format.clone())
+      .filter(|format| is_valid_strftime_format(format))
+      .unwrap_or_default(),
+    show_relative: pb.show_relative,
+  }
+}
+
+/// Decorates the type option payload with the display extras stored in the
+/// type option data.
+pub fn apply_timestamp_display_meta(pb: &mut TimestampTypeOptionPB, meta: TimestampDisplayMeta) {
+  pb.custom_format = meta.custom_format;
+  pb.show_relative = meta.show_relative;
+}
+
+/// [TimestampTypeOption] together with the display extras stored next to it
+/// in the type option data. Cell handlers are built from this type so the
+/// stringifier can see the extras.
+#[derive(Debug, Clone, Default)]
+pub struct TimestampTypeOptionExt {
+  pub inner: TimestampTypeOption,
+  pub meta: TimestampDisplayMeta,
+}
+
+impl From<TypeOptionData> for TimestampTypeOptionExt {
+  fn from(data: TypeOptionData) -> Self {
+    let meta = timestamp_display_meta_from_data(&data);
+    Self {
+      inner: TimestampTypeOption::from(data),
+      meta,
+    }
+  }
+}
+
+impl From<TimestampTypeOptionExt> for TypeOptionData {
+  fn from(type_option: TimestampTypeOptionExt) -> Self {
+    let mut data: TypeOptionData = type_option.inner.into();
+    insert_timestamp_display_meta(&mut data, type_option.meta);
+    data
+  }
+}
+
+impl TimestampTypeOptionExt {
+  /// Renders the timestamp the way it is displayed: relative when the
+  /// relative mode is on, through the custom format string when one is set,
+  /// otherwise through the fixed formats of [TimestampTypeOption].
+  fn format_with_meta(&self, cell_data: &TimestampCellData) -> String {
+    let Some(cell_timestamp) = cell_data.timestamp else {
+      return "".to_string();
+    };
+    if self.meta.show_relative {
+      return relative_from_now(cell_timestamp, timestamp());
+    }
+    if !self.meta.custom_format.is_empty() && is_valid_strftime_format(&self.meta.custom_format) {
+      if let Some(date_time) = DateTime::<Utc>::from_timestamp(cell_timestamp, 0) {
+        let format = self.meta.custom_format.as_str();
+        return match self
+          .inner
+          .timezone
+          .as_deref()
+          .and_then(|timezone| Tz::from_str(timezone).ok())
+        {
+          Some(timezone) => date_time.with_timezone(&timezone).format(format).to_string(),
+          None => date_time.with_timezone(&Local).format(format).to_string(),
+        };
+      }
+    }
+    self.inner.stringify_cell_data(cell_data.clone())
+  }
+}
+
+/// Renders a timestamp relative to now, e.g. "3 hours ago" or "in 2 days".
+fn relative_from_now(cell_timestamp: i64, now: i64) -> String {
+  let diff = now - cell_timestamp;
+  let in_future = diff < 0;
+  let diff = diff.abs();
+  if diff < 60 {
+    return if in_future {
+      "in a moment".to_string()
+    } else {
+      "just now".to_string()
+    };
+  }
+  let (value, unit) = if diff < 3_600 {
+    (diff / 60, "minute")
+  } else if diff < 86_400 {
+    (diff / 3_600, "hour")
+  } else if diff < 7 * 86_400 {
+    (diff / 86_400, "day")
+  } else if diff < 30 * 86_400 {
+    (diff / (7 * 86_400), "week")
+  } else if diff < 365 * 86_400 {
+    (diff / (30 * 86_400), "month")
+  } else {
+    (diff / (365 * 86_400), "year")
+  };
+  let plural = if value == 1 { "" } else { "s" };
+  if in_future {
+    format!("in {} {}{}", value, unit, plural)
+  } else {
+    format!("{} {}{} ago", value, unit, plural)
+  }
+}
+
+impl TypeOption for TimestampTypeOptionExt {
+  type CellData = TimestampCellData;
+  type CellChangeset = String;
+  type CellProtobufType = TimestampCellDataPB;
+  type CellFilter = DateFilterPB;
+}
+
+impl CellDataProtobufEncoder for TimestampTypeOptionExt {
+  fn protobuf_encode(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    TimestampCellDataPB {
+      date_time: self.format_with_meta(&cell_data),
+      timestamp: cell_data.timestamp,
+    }
+  }
+}
+
+impl TypeOptionTransform for TimestampTypeOptionExt {}
+
+impl CellDataDecoder for TimestampTypeOptionExt {
+  fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    self.format_with_meta(&cell_data)
+  }
+}
+
+impl CellDataChangeset for TimestampTypeOptionExt {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    cell: Option<Cell>,
+  ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
+    self.inner.apply_changeset(changeset, cell)
+  }
+}
+
+impl TypeOptionCellDataFilter for TimestampTypeOptionExt {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    self.inner.apply_filter(filter, cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for TimestampTypeOptionExt {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+  ) -> Ordering {
+    self.inner.apply_cmp(cell_data, other_cell_data, sort_condition)
+  }
+}
 
 impl TypeOption for TimestampTypeOption {
   type CellData = TimestampCellData;
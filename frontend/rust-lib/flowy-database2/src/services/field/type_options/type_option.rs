@@ -5,6 +5,13 @@ use crate::entities::{
   TranslateTypeOptionPB, URLTypeOptionPB,
 };
 use crate::services::cell::CellDataDecoder;
+use crate::services::field::{
+  apply_number_format_meta, apply_select_option_meta, apply_timestamp_display_meta,
+  insert_number_format_meta, insert_select_option_meta, insert_timestamp_display_meta,
+  number_format_meta_from_data, number_format_meta_from_pb, select_option_meta_from_data,
+  select_option_meta_from_pb_options, timestamp_display_meta_from_data,
+  timestamp_display_meta_from_pb,
+};
 use crate::services::filter::{ParseFilterData, PreFillCellsWithFilter};
 use crate::services::sort::SortCondition;
 use async_trait::async_trait;
@@ -155,21 +162,35 @@ pub fn type_option_data_from_pb<T: Into<Bytes>>(
     FieldType::RichText => {
       RichTextTypeOptionPB::try_from(bytes).map(|pb| RichTextTypeOption::from(pb).into())
     },
-    FieldType::Number => {
-      NumberTypeOptionPB::try_from(bytes).map(|pb| NumberTypeOption::from(pb).into())
-    },
+    FieldType::Number => NumberTypeOptionPB::try_from(bytes).map(|pb| {
+      let meta = number_format_meta_from_pb(&pb);
+      let mut data: TypeOptionData = NumberTypeOption::from(pb).into();
+      insert_number_format_meta(&mut data, meta);
+      data
+    }),
     FieldType::DateTime => {
       DateTypeOptionPB::try_from(bytes).map(|pb| DateTypeOption::from(pb).into())
     },
     FieldType::LastEditedTime | FieldType::CreatedTime => {
-      TimestampTypeOptionPB::try_from(bytes).map(|pb| TimestampTypeOption::from(pb).into())
-    },
-    FieldType::SingleSelect => {
-      SingleSelectTypeOptionPB::try_from(bytes).map(|pb| SingleSelectTypeOption::from(pb).into())
-    },
-    FieldType::MultiSelect => {
-      MultiSelectTypeOptionPB::try_from(bytes).map(|pb| MultiSelectTypeOption::from(pb).into())
+      TimestampTypeOptionPB::try_from(bytes).map(|pb| {
+        let meta = timestamp_display_meta_from_pb(&pb);
+        let mut data: TypeOptionData = TimestampTypeOption::from(pb).into();
+        insert_timestamp_display_meta(&mut data, meta);
+        data
+      })
     },
+    FieldType::SingleSelect => SingleSelectTypeOptionPB::try_from(bytes).map(|pb| {
+      let meta = select_option_meta_from_pb_options(&pb.options);
+      let mut data: TypeOptionData = SingleSelectTypeOption::from(pb).into();
+      insert_select_option_meta(&mut data, meta);
+      data
+    }),
+    FieldType::MultiSelect => MultiSelectTypeOptionPB::try_from(bytes).map(|pb| {
+      let meta = select_option_meta_from_pb_options(&pb.options);
+      let mut data: TypeOptionData = MultiSelectTypeOption::from(pb).into();
+      insert_select_option_meta(&mut data, meta);
+      data
+    }),
     FieldType::Checkbox => {
       CheckboxTypeOptionPB::try_from(bytes).map(|pb| CheckboxTypeOption::from(pb).into())
     },
@@ -202,32 +223,36 @@ pub fn type_option_to_pb(type_option: TypeOptionData, field_type: &FieldType) ->
         .unwrap()
     },
     FieldType::Number => {
+      let meta = number_format_meta_from_data(&type_option);
       let number_type_option: NumberTypeOption = type_option.into();
-      NumberTypeOptionPB::from(number_type_option)
-        .try_into()
-        .unwrap()
+      let mut pb = NumberTypeOptionPB::from(number_type_option);
+      apply_number_format_meta(&mut pb, meta);
+      pb.try_into().unwrap()
     },
     FieldType::DateTime => {
       let date_type_option: DateTypeOption = type_option.into();
       DateTypeOptionPB::from(date_type_option).try_into().unwrap()
     },
     FieldType::LastEditedTime | FieldType::CreatedTime => {
+      let meta = timestamp_display_meta_from_data(&type_option);
       let timestamp_type_option: TimestampTypeOption = type_option.into();
-      TimestampTypeOptionPB::from(timestamp_type_option)
-        .try_into()
-        .unwrap()
+      let mut pb = TimestampTypeOptionPB::from(timestamp_type_option);
+      apply_timestamp_display_meta(&mut pb, meta);
+      pb.try_into().unwrap()
     },
     FieldType::SingleSelect => {
+      let meta = select_option_meta_from_data(&type_option);
       let single_select_type_option: SingleSelectTypeOption = type_option.into();
-      SingleSelectTypeOptionPB::from(single_select_type_option.0)
-        .try_into()
-        .unwrap()
+      let mut pb = SingleSelectTypeOptionPB::from(single_select_type_option.0);
+      apply_select_option_meta(&mut pb.options, &meta);
+      pb.try_into().unwrap()
     },
     FieldType::MultiSelect => {
+      let meta = select_option_meta_from_data(&type_option);
       let multi_select_type_option: MultiSelectTypeOption = type_option.into();
-      MultiSelectTypeOptionPB::from(multi_select_type_option.0)
-        .try_into()
-        .unwrap()
+      let mut pb = MultiSelectTypeOptionPB::from(multi_select_type_option.0);
+      apply_select_option_meta(&mut pb.options, &meta);
+      pb.try_into().unwrap()
     },
     FieldType::Checkbox => {
       let checkbox_type_option: CheckboxTypeOption = type_option.into();
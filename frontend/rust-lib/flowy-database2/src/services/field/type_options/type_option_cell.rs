@@ -1,8 +1,8 @@
 use crate::entities::FieldType;
 use crate::services::cell::{CellCache, CellDataChangeset, CellDataDecoder, CellProtobufBlob};
 use crate::services::field::{
-  CellDataProtobufEncoder, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare,
-  TypeOptionCellDataFilter, TypeOptionTransform,
+  CellDataProtobufEncoder, NumberTypeOptionExt, TimestampTypeOptionExt, TypeOption,
+  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use crate::services::sort::SortCondition;
 use collab::preclude::Any;
@@ -381,7 +381,7 @@ impl<'a> TypeOptionCellExt<'a> {
         }),
       FieldType::Number => self
         .field
-        .get_type_option::<NumberTypeOption>(field_type)
+        .get_type_option::<NumberTypeOptionExt>(field_type)
         .map(|type_option| {
           TypeOptionCellDataHandlerImpl::new_with_boxed(
             type_option,
@@ -401,7 +401,7 @@ impl<'a> TypeOptionCellExt<'a> {
         }),
       FieldType::LastEditedTime | FieldType::CreatedTime => self
         .field
-        .get_type_option::<TimestampTypeOption>(field_type)
+        .get_type_option::<TimestampTypeOptionExt>(field_type)
         .map(|type_option| {
           TypeOptionCellDataHandlerImpl::new_with_boxed(
             type_option,
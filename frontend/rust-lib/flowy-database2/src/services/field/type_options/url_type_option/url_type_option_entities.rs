@@ -9,7 +9,10 @@ use crate::services::cell::CellProtobufBlobParser;
 
 impl From<URLCellData> for URLCellDataPB {
   fn from(data: URLCellData) -> Self {
-    Self { content: data.data }
+    Self {
+      content: data.data,
+      preview: None,
+    }
   }
 }
 
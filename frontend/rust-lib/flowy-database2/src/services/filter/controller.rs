@@ -1,12 +1,14 @@
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::Arc;
 
 use collab::lock::RwLock;
 use collab_database::database::gen_database_filter_id;
 use collab_database::fields::Field;
+use collab_database::fields::relation_type_option::RelationTypeOption;
 use collab_database::rows::{Cell, Cells, Row, RowDetail, RowId};
+use collab_database::template::relation_parse::RelationCellData;
 use collab_database::template::timestamp_parse::TimestampCellData;
 use dashmap::DashMap;
 use flowy_error::FlowyResult;
@@ -20,8 +22,10 @@ use tracing::{error, trace};
 use crate::entities::filter_entities::*;
 use crate::entities::{FieldType, InsertedRowPB, RowMetaPB};
 use crate::services::cell::CellCache;
-use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
-use crate::services::field::TypeOptionCellExt;
+use crate::services::database_view::{
+  DatabaseViewChanged, DatabaseViewChangedNotifier, FieldRowIndex,
+};
+use crate::services::field::{CHECK, RelationCellResolver, TypeOptionCellExt, UNCHECK};
 use crate::services::filter::{Filter, FilterChangeset, FilterInner, FilterResultNotification};
 
 #[async_trait]
@@ -32,12 +36,18 @@ pub trait FilterDelegate: Send + Sync + 'static {
   async fn get_row(&self, view_id: &str, rows_id: &RowId) -> Option<(usize, Arc<RowDetail>)>;
   async fn get_all_filters(&self, view_id: &str) -> Vec<Filter>;
   async fn save_filters(&self, view_id: &str, filters: &[Filter]);
+  fn get_relation_cell_resolver(&self) -> Option<Arc<RelationCellResolver>>;
 }
 
 pub trait PreFillCellsWithFilter {
   fn get_compliant_cell(&self, field: &Field) -> Option<Cell>;
 }
 
+/// For each relation filter with a remote condition (keyed by filter id),
+/// whether each related row referenced by the rows being filtered satisfies
+/// that condition.
+type RelationFilterResults = HashMap<String, HashMap<String, bool>>;
+
 pub struct FilterController {
   view_id: String,
   handler_id: String,
@@ -46,6 +56,7 @@ pub struct FilterController {
   cell_cache: CellCache,
   filters: RwLock<Vec<Filter>>,
   task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
+  row_index: Arc<FieldRowIndex>,
   notifier: DatabaseViewChangedNotifier,
 }
 
@@ -62,6 +73,7 @@ impl FilterController {
     delegate: T,
     task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
     cell_cache: CellCache,
+    row_index: Arc<FieldRowIndex>,
     notifier: DatabaseViewChangedNotifier,
   ) -> Self
   where
@@ -108,6 +120,7 @@ impl FilterController {
       cell_cache,
       filters: RwLock::new(filters),
       task_scheduler,
+      row_index,
       notifier,
     }
   }
@@ -322,6 +335,10 @@ impl FilterController {
 
     if let Some((_, row_detail)) = self.delegate.get_row(&self.view_id, &row_id).await {
       let field_by_field_id = self.get_field_map().await;
+      let rows = [Arc::new(row_detail.row.clone())];
+      let relation_results = self
+        .resolve_relation_filters(&filters, &field_by_field_id, &rows)
+        .await;
       let mut notification = FilterResultNotification::new(self.view_id.clone());
       if filter_row(
         &row_detail.row,
@@ -329,6 +346,7 @@ impl FilterController {
         &field_by_field_id,
         &self.cell_cache,
         &filters,
+        &relation_results,
       ) {
         if let Some((index, _row)) = self.delegate.get_row(&self.view_id, &row_id).await {
           notification.visible_rows.push(
@@ -350,6 +368,9 @@ impl FilterController {
   pub async fn filter_rows_and_notify(&self, rows: &mut Vec<Arc<Row>>) -> FlowyResult<()> {
     let filters = self.filters.read().await;
     let field_by_field_id = self.get_field_map().await;
+    let relation_results = self
+      .resolve_relation_filters(&filters, &field_by_field_id, rows)
+      .await;
     let (visible_rows, invisible_rows): (Vec<_>, Vec<_>) =
       rows.par_iter().enumerate().partition_map(|(index, row)| {
         if filter_row(
@@ -358,6 +379,7 @@ impl FilterController {
           &field_by_field_id,
           &self.cell_cache,
           &filters,
+          &relation_results,
         ) {
           let row_meta = RowMetaPB::from(row.as_ref());
           // Visible rows go into the left partition
@@ -386,6 +408,26 @@ impl FilterController {
   pub async fn filter_rows(&self, mut rows: Vec<Arc<Row>>) -> Vec<Arc<Row>> {
     let filters = self.filters.read().await;
     let field_by_field_id = self.get_field_map().await;
+
+    // Answer simple exact-match filters from the per-field row index instead
+    // of re-decoding every cell.
+    if let Some(visible_rows) = self.index_candidates(&filters, &field_by_field_id).await {
+      let len = rows.len();
+      rows.retain(|row| {
+        let is_visible = visible_rows.contains(&row.id);
+        self.result_by_row_id.insert(row.id.clone(), is_visible);
+        is_visible
+      });
+      trace!(
+        "[Database]: filter out {} invisible rows using the row index",
+        len - rows.len()
+      );
+      return rows;
+    }
+
+    let relation_results = self
+      .resolve_relation_filters(&filters, &field_by_field_id, &rows)
+      .await;
     rows.par_iter().for_each(|row| {
       let _ = filter_row(
         row,
@@ -393,6 +435,7 @@ impl FilterController {
         &field_by_field_id,
         &self.cell_cache,
         &filters,
+        &relation_results,
       );
     });
 
@@ -408,6 +451,138 @@ impl FilterController {
     rows
   }
 
+  /// Tries to answer the filters from the per-field row index, building the
+  /// index on first use. Only a single exact-match filter on an indexed field
+  /// can be answered this way; any other filter tree returns `None` and the
+  /// caller falls back to the full scan.
+  async fn index_candidates(
+    &self,
+    filters: &[Filter],
+    field_by_field_id: &HashMap<String, Field>,
+  ) -> Option<HashSet<RowId>> {
+    if filters.len() != 1 {
+      return None;
+    }
+    let (field_id, field_type, condition_and_content) = match &filters[0].inner {
+      FilterInner::Data {
+        field_id,
+        field_type,
+        condition_and_content,
+      } => (field_id, field_type, condition_and_content),
+      _ => return None,
+    };
+    let field = field_by_field_id.get(field_id)?;
+
+    // Decide whether the filter can be answered at all before paying for the
+    // index build.
+    let query = match field_type {
+      FieldType::RichText | FieldType::URL => {
+        let filter = condition_and_content.cloned::<TextFilterPB>()?;
+        if filter.condition != TextFilterConditionPB::TextIs || filter.content.is_empty() {
+          return None;
+        }
+        IndexQuery::Text(filter.content)
+      },
+      FieldType::Checkbox => {
+        let filter = condition_and_content.cloned::<CheckboxFilterPB>()?;
+        match filter.condition {
+          CheckboxFilterConditionPB::IsChecked => IndexQuery::Checked,
+          CheckboxFilterConditionPB::IsUnChecked => IndexQuery::Unchecked,
+        }
+      },
+      _ => return None,
+    };
+
+    if !self.row_index.is_built(field_id) {
+      // `filter_rows` is also called with single rows and other subsets of
+      // the view, so the index has to be built from the full row set of the
+      // view rather than from the rows currently being filtered.
+      let all_rows = self.delegate.get_rows(&self.view_id).await;
+      self.row_index.build(field, &all_rows);
+    }
+
+    match query {
+      IndexQuery::Text(content) => self.row_index.rows_with_text(field_id, &content),
+      IndexQuery::Checked => self.row_index.rows_with_text(field_id, CHECK),
+      IndexQuery::Unchecked => {
+        // Unchecked covers both an explicit "No" and an empty cell.
+        let mut row_ids = self.row_index.rows_with_text(field_id, UNCHECK)?;
+        row_ids.extend(self.row_index.rows_with_text(field_id, "")?);
+        Some(row_ids)
+      },
+    }
+  }
+
+  /// Resolves the remote cells that relation filters with a remote condition
+  /// compare against. The evaluation itself happens in the synchronous filter
+  /// pass; this pre-pass reads (and caches) everything it needs from the
+  /// related databases.
+  async fn resolve_relation_filters(
+    &self,
+    filters: &[Filter],
+    field_by_field_id: &HashMap<String, Field>,
+    rows: &[Arc<Row>],
+  ) -> RelationFilterResults {
+    let mut results = RelationFilterResults::new();
+
+    let mut relation_filters = vec![];
+    for filter in filters.iter() {
+      collect_relation_filters(filter, &mut relation_filters);
+    }
+    if relation_filters.is_empty() {
+      return results;
+    }
+    let resolver = match self.delegate.get_relation_cell_resolver() {
+      Some(resolver) => resolver,
+      None => return results,
+    };
+
+    // Gather the related row ids referenced by the rows, per relation field.
+    let field_ids = relation_filters
+      .iter()
+      .map(|(_, field_id, _)| field_id.clone())
+      .collect::<HashSet<String>>();
+    let mut related_row_ids_by_field: HashMap<String, HashSet<String>> = HashMap::new();
+    for row in rows.iter() {
+      for field_id in field_ids.iter() {
+        if let Some(cell) = row.cells.get(field_id) {
+          let cell_data = RelationCellData::from(cell);
+          related_row_ids_by_field
+            .entry(field_id.clone())
+            .or_default()
+            .extend(cell_data.row_ids.iter().map(|row_id| row_id.to_string()));
+        }
+      }
+    }
+
+    for (filter_id, field_id, condition) in relation_filters {
+      let database_id = match field_by_field_id
+        .get(&field_id)
+        .and_then(|field| field.get_type_option::<RelationTypeOption>(FieldType::Relation))
+      {
+        Some(type_option) if !type_option.database_id.is_empty() => type_option.database_id,
+        _ => continue,
+      };
+
+      let remote_content = condition.remote_content.to_lowercase();
+      let mut row_results = HashMap::new();
+      if let Some(related_row_ids) = related_row_ids_by_field.get(&field_id) {
+        for related_row_id in related_row_ids.iter() {
+          let matches = match resolver
+            .resolve_remote_cell_text(&database_id, related_row_id, &condition.remote_field_id)
+            .await
+          {
+            Some(text) => text.to_lowercase().contains(&remote_content),
+            None => false,
+          };
+          row_results.insert(related_row_id.clone(), matches);
+        }
+      }
+      results.insert(filter_id, row_results);
+    }
+    results
+  }
+
   async fn get_field_map(&self) -> HashMap<String, Field> {
     self
       .delegate
@@ -443,6 +618,13 @@ impl FilterController {
   }
 }
 
+/// A filter that the per-field row index can answer.
+enum IndexQuery {
+  Text(String),
+  Checked,
+  Unchecked,
+}
+
 /// Returns `Some` if the visibility of the row changed after applying the filter and `None`
 /// otherwise
 #[tracing::instrument(level = "trace", skip_all)]
@@ -452,13 +634,20 @@ fn filter_row(
   field_by_field_id: &HashMap<String, Field>,
   cell_data_cache: &CellCache,
   filters: &Vec<Filter>,
+  relation_results: &RelationFilterResults,
 ) -> bool {
   // Create a filter result cache if it doesn't exist
   let mut filter_result = result_by_row_id.entry(row.id.clone()).or_insert(true);
   let mut new_is_visible = true;
 
   for filter in filters {
-    if let Some(is_visible) = apply_filter(row, field_by_field_id, cell_data_cache, filter) {
+    if let Some(is_visible) = apply_filter(
+      row,
+      field_by_field_id,
+      cell_data_cache,
+      filter,
+      relation_results,
+    ) {
       new_is_visible = new_is_visible && is_visible;
       // short-circuit as soon as one filter tree returns false
       if !new_is_visible {
@@ -477,6 +666,7 @@ fn apply_filter(
   field_by_field_id: &HashMap<String, Field>,
   cell_data_cache: &CellCache,
   filter: &Filter,
+  relation_results: &RelationFilterResults,
 ) -> Option<bool> {
   match &filter.inner {
     FilterInner::And { children } => {
@@ -484,7 +674,13 @@ fn apply_filter(
         return None;
       }
       for child_filter in children.iter() {
-        if let Some(false) = apply_filter(row, field_by_field_id, cell_data_cache, child_filter) {
+        if let Some(false) = apply_filter(
+          row,
+          field_by_field_id,
+          cell_data_cache,
+          child_filter,
+          relation_results,
+        ) {
           return Some(false);
         }
       }
@@ -495,7 +691,13 @@ fn apply_filter(
         return None;
       }
       for child_filter in children.iter() {
-        if let Some(true) = apply_filter(row, field_by_field_id, cell_data_cache, child_filter) {
+        if let Some(true) = apply_filter(
+          row,
+          field_by_field_id,
+          cell_data_cache,
+          child_filter,
+          relation_results,
+        ) {
           return Some(true);
         }
       }
@@ -530,6 +732,23 @@ fn apply_filter(
         _ => None,
       };
       let cell = timestamp_cell.or_else(|| row.cells.get(field_id).cloned());
+
+      // Relation filters with a remote condition are answered from the
+      // pre-resolved remote cells; the relation type option itself cannot
+      // reach into other databases.
+      if *field_type == FieldType::Relation {
+        if let Some(row_results) = relation_results.get(&filter.id) {
+          let is_visible = match cell.as_ref() {
+            Some(cell) => RelationCellData::from(cell)
+              .row_ids
+              .iter()
+              .any(|row_id| row_results.get(&row_id.to_string()).copied().unwrap_or(false)),
+            None => false,
+          };
+          return Some(is_visible);
+        }
+      }
+
       if let Some(handler) = TypeOptionCellExt::new(field, Some(cell_data_cache.clone()))
         .get_type_option_cell_data_handler()
       {
@@ -541,6 +760,34 @@ fn apply_filter(
   }
 }
 
+/// Recursively collects the id, field id and parsed condition of every
+/// relation filter in the tree that has a remote condition.
+fn collect_relation_filters(
+  filter: &Filter,
+  relation_filters: &mut Vec<(String, String, RelationFilterPB)>,
+) {
+  match &filter.inner {
+    FilterInner::And { children } | FilterInner::Or { children } => {
+      for child_filter in children.iter() {
+        collect_relation_filters(child_filter, relation_filters);
+      }
+    },
+    FilterInner::Data {
+      field_id,
+      field_type,
+      condition_and_content,
+    } => {
+      if *field_type == FieldType::Relation {
+        if let Some(condition) = condition_and_content.cloned::<RelationFilterPB>() {
+          if !condition.remote_field_id.is_empty() {
+            relation_filters.push((filter.id.clone(), field_id.clone(), condition));
+          }
+        }
+      }
+    },
+  }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 enum FilterEvent {
   FilterDidChanged,
@@ -16,8 +16,8 @@ use tracing::error;
 
 use crate::entities::{
   CheckboxFilterPB, ChecklistFilterPB, DateFilterContent, DateFilterPB, FieldType, FilterType,
-  InsertedRowPB, MediaFilterPB, NumberFilterPB, RelationFilterPB, SelectOptionFilterPB,
-  TextFilterPB, TimeFilterPB,
+  InsertedRowPB, MediaFilterPB, NumberFilterPB, RelationFilterContent, RelationFilterPB,
+  SelectOptionFilterPB, TextFilterPB, TimeFilterPB,
 };
 
 pub trait ParseFilterData {
@@ -383,7 +383,12 @@ impl<'a> From<&'a Filter> for FilterMap {
             },
             FieldType::Relation => {
               let filter = condition_and_content.cloned::<RelationFilterPB>()?;
-              (filter.condition as u8, "".to_string())
+              let content = RelationFilterContent {
+                remote_field_id: filter.remote_field_id,
+                remote_content: filter.remote_content,
+              }
+              .to_json_string();
+              (filter.condition as u8, content)
             },
             FieldType::Summary => {
               let filter = condition_and_content.cloned::<TextFilterPB>()?;
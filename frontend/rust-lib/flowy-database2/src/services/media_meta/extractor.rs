@@ -0,0 +1,106 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use collab_database::fields::media_type_option::{MediaFile, MediaFileType, MediaUploadType};
+use lib_infra::util::timestamp;
+use tracing::warn;
+
+use crate::services::media_meta::MediaFileMetaTable;
+
+/// The longest edge of a generated thumbnail, in pixels.
+const THUMBNAIL_MAX_EDGE: u32 = 256;
+
+/// The directory thumbnails are written into, next to the source file.
+const THUMBNAIL_DIR: &str = ".af_thumbnails";
+
+/// Extracts the metadata of a media file. Only local files are probed; for
+/// network and cloud files the mime type is guessed from the name and
+/// everything else is left at its default. Extraction is best effort: a file
+/// that cannot be read or decoded simply keeps zeroed fields.
+pub fn extract_media_file_meta(database_id: &str, file: &MediaFile) -> MediaFileMetaTable {
+  let mut meta = MediaFileMetaTable {
+    file_id: file.id.clone(),
+    database_id: database_id.to_string(),
+    width: 0,
+    height: 0,
+    duration: 0,
+    mime: mime_guess::from_path(&file.name)
+      .first_raw()
+      .unwrap_or_default()
+      .to_string(),
+    thumbnail_path: String::new(),
+    updated_at: timestamp(),
+  };
+
+  if !matches!(file.upload_type, MediaUploadType::Local) {
+    return meta;
+  }
+  let path = Path::new(&file.url);
+  if !path.exists() {
+    return meta;
+  }
+
+  match file.file_type {
+    MediaFileType::Image => match image::open(path) {
+      Ok(image) => {
+        meta.width = image.width() as i32;
+        meta.height = image.height() as i32;
+        match generate_thumbnail(&image, path, &file.id) {
+          Ok(thumbnail_path) => meta.thumbnail_path = thumbnail_path,
+          Err(err) => warn!(
+            "[MediaMeta]: failed to generate thumbnail for {}: {}",
+            file.id, err
+          ),
+        }
+      },
+      Err(err) => warn!("[MediaMeta]: failed to decode image {}: {}", file.id, err),
+    },
+    MediaFileType::Audio | MediaFileType::Video => {
+      // Only WAV exposes its duration in a trivially parseable header; other
+      // formats are left at zero.
+      if let Some(duration) = wav_duration_millis(path) {
+        meta.duration = duration;
+      }
+    },
+    _ => {},
+  }
+
+  meta
+}
+
+/// Writes a PNG thumbnail of the image into a [THUMBNAIL_DIR] directory next
+/// to the source file and returns its path. Images smaller than the
+/// thumbnail size still get one so a cover is always available.
+fn generate_thumbnail(
+  image: &image::DynamicImage,
+  source: &Path,
+  file_id: &str,
+) -> Result<String, anyhow::Error> {
+  let dir = source
+    .parent()
+    .map(|parent| parent.join(THUMBNAIL_DIR))
+    .ok_or_else(|| anyhow::anyhow!("media file has no parent directory"))?;
+  std::fs::create_dir_all(&dir)?;
+  let path = dir.join(format!("{}.png", file_id));
+  image
+    .thumbnail(THUMBNAIL_MAX_EDGE, THUMBNAIL_MAX_EDGE)
+    .save(&path)?;
+  Ok(path.to_string_lossy().to_string())
+}
+
+/// Reads the duration of a WAV file from its header, in milliseconds.
+fn wav_duration_millis(path: &Path) -> Option<i64> {
+  let mut header = [0u8; 44];
+  let mut file = File::open(path).ok()?;
+  file.read_exact(&mut header).ok()?;
+  if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+    return None;
+  }
+  let byte_rate = u32::from_le_bytes(header[28..32].try_into().ok()?) as u64;
+  if byte_rate == 0 {
+    return None;
+  }
+  let data_len = file.metadata().ok()?.len().saturating_sub(header.len() as u64);
+  Some((data_len * 1000 / byte_rate) as i64)
+}
@@ -0,0 +1,52 @@
+use flowy_error::FlowyResult;
+use flowy_sqlite::DBConnection;
+use flowy_sqlite::schema::media_file_meta_table;
+use flowy_sqlite::schema::media_file_meta_table::dsl;
+use flowy_sqlite::{ExpressionMethods, prelude::*};
+
+/// Locally extracted metadata of a media attachment: the dimensions of an
+/// image, the duration of an audio/video file (in milliseconds), the mime
+/// type and the path of the generated thumbnail. Zeroed/empty fields mean
+/// the value could not be extracted. See
+/// [crate::services::media_meta::extract_media_file_meta].
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = media_file_meta_table)]
+#[diesel(primary_key(file_id))]
+pub struct MediaFileMetaTable {
+  pub file_id: String,
+  pub database_id: String,
+  pub width: i32,
+  pub height: i32,
+  pub duration: i64,
+  pub mime: String,
+  pub thumbnail_path: String,
+  pub updated_at: i64,
+}
+
+pub fn upsert_media_file_meta(
+  conn: &mut DBConnection,
+  meta: &MediaFileMetaTable,
+) -> FlowyResult<()> {
+  diesel::replace_into(dsl::media_file_meta_table)
+    .values(meta.clone())
+    .execute(conn)?;
+  Ok(())
+}
+
+/// Selects the metadata of the given media files. Files whose metadata
+/// hasn't been extracted yet are simply absent from the result.
+pub fn select_media_file_metas(
+  conn: &mut DBConnection,
+  file_ids: &[String],
+) -> FlowyResult<Vec<MediaFileMetaTable>> {
+  let metas = dsl::media_file_meta_table
+    .filter(media_file_meta_table::file_id.eq_any(file_ids))
+    .load::<MediaFileMetaTable>(conn)?;
+  Ok(metas)
+}
+
+pub fn delete_media_file_metas(conn: &mut DBConnection, file_ids: &[String]) -> FlowyResult<()> {
+  diesel::delete(dsl::media_file_meta_table.filter(media_file_meta_table::file_id.eq_any(file_ids)))
+    .execute(conn)?;
+  Ok(())
+}
@@ -0,0 +1,5 @@
+mod extractor;
+mod media_meta_sql;
+
+pub use extractor::*;
+pub use media_meta_sql::*;
@@ -1,3 +1,4 @@
+pub mod automation;
 pub mod calculations;
 pub mod cell;
 pub mod database;
@@ -6,7 +7,15 @@ pub mod field;
 pub mod field_settings;
 pub mod filter;
 pub mod group;
+pub mod media_meta;
+pub mod my_tasks;
+pub mod personal_view;
+pub mod row_comment;
+pub mod row_history;
+pub mod row_template;
 pub mod setting;
 pub mod share;
 pub mod snapshot;
 pub mod sort;
+pub mod undo;
+pub mod url_preview;
@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::fields::date_type_option::DateCellData;
+use collab_database::rows::Row;
+
+use crate::entities::{CheckboxCellDataPB, FieldType};
+use crate::services::cell::stringify_cell;
+
+/// Lowercased field names that mark a text field as holding the person a row
+/// is assigned to.
+const PERSON_FIELD_NAMES: &[&str] = &["assignee", "assigned to", "person", "owner", "responsible"];
+/// Lowercased field names that mark a checkbox field as the row's done flag.
+const DONE_FIELD_NAMES: &[&str] = &["done", "complete", "completed", "finished"];
+/// Lowercased field names that mark a date field as the row's due date.
+const DUE_FIELD_NAMES: &[&str] = &["due", "due date", "deadline"];
+
+/// How rows across the workspace qualify as the current user's tasks.
+#[derive(Debug, Default, Clone)]
+pub struct MyTasksFilter {
+  /// Matches the person field against this name, case-insensitively. When
+  /// empty, only the unchecked-with-upcoming-date rule applies.
+  pub person_name: Option<String>,
+  /// Only keeps rows whose due date falls before this timestamp.
+  pub due_before: Option<i64>,
+  /// When true, rows whose done checkbox is already checked are kept too.
+  pub include_done: bool,
+}
+
+/// A row that qualified as a task, together with the view it came from so the
+/// home screen can open the row in place.
+#[derive(Debug, Clone)]
+pub struct MyTask {
+  pub database_id: String,
+  pub view_id: String,
+  pub row_id: String,
+  pub title: String,
+  pub due_timestamp: Option<i64>,
+  pub is_done: bool,
+}
+
+/// The fields of one database that the task scan looks at. A database without
+/// any of them contributes no tasks.
+struct TaskFields {
+  primary: Option<Field>,
+  person: Option<Field>,
+  done: Option<Field>,
+  due: Option<Field>,
+}
+
+impl TaskFields {
+  fn detect(fields: &[Field]) -> Self {
+    Self {
+      primary: fields.iter().find(|field| field.is_primary).cloned(),
+      person: find_field(fields, FieldType::RichText, PERSON_FIELD_NAMES, false),
+      done: find_field(fields, FieldType::Checkbox, DONE_FIELD_NAMES, true),
+      due: find_field(fields, FieldType::DateTime, DUE_FIELD_NAMES, true),
+    }
+  }
+}
+
+/// Whether the field is a text field holding the person a row is assigned
+/// to, matched by name.
+pub(crate) fn is_person_field(field: &Field) -> bool {
+  FieldType::from(field.field_type) == FieldType::RichText
+    && PERSON_FIELD_NAMES.contains(&field.name.trim().to_lowercase().as_str())
+}
+
+/// Finds a field of the given type by name. When `fallback_to_type` is true
+/// and no name matches, the first field of that type is used instead.
+fn find_field(
+  fields: &[Field],
+  field_type: FieldType,
+  names: &[&str],
+  fallback_to_type: bool,
+) -> Option<Field> {
+  let candidates = fields
+    .iter()
+    .filter(|field| FieldType::from(field.field_type) == field_type)
+    .collect::<Vec<_>>();
+  candidates
+    .iter()
+    .find(|field| names.contains(&field.name.trim().to_lowercase().as_str()))
+    .or_else(|| fallback_to_type.then(|| candidates.first()).flatten())
+    .map(|field| (*field).clone())
+}
+
+/// Collects the rows of one database that qualify as tasks. A row qualifies
+/// when its person field matches the filter's name, or when its done checkbox
+/// is unchecked and its due date hasn't passed yet.
+pub(crate) fn collect_tasks(
+  database_id: &str,
+  view_id: &str,
+  fields: &[Field],
+  rows: &[Arc<Row>],
+  filter: &MyTasksFilter,
+  now: i64,
+) -> Vec<MyTask> {
+  let task_fields = TaskFields::detect(fields);
+  if task_fields.person.is_none() && task_fields.done.is_none() {
+    return vec![];
+  }
+
+  let person_name = filter
+    .person_name
+    .as_ref()
+    .map(|name| name.trim().to_lowercase())
+    .filter(|name| !name.is_empty());
+
+  let mut tasks = vec![];
+  for row in rows {
+    let is_done = task_fields
+      .done
+      .as_ref()
+      .and_then(|field| row.cells.get(&field.id))
+      .is_some_and(|cell| CheckboxCellDataPB::from(cell).is_checked);
+    let due_timestamp = task_fields
+      .due
+      .as_ref()
+      .and_then(|field| row.cells.get(&field.id))
+      .and_then(|cell| DateCellData::from(cell).timestamp);
+
+    let person_matches = match (&person_name, &task_fields.person) {
+      (Some(name), Some(field)) => row
+        .cells
+        .get(&field.id)
+        .is_some_and(|cell| stringify_cell(cell, field).to_lowercase().contains(name)),
+      _ => false,
+    };
+    let upcoming = !is_done && due_timestamp.is_some_and(|timestamp| timestamp >= now);
+    if !person_matches && !upcoming {
+      continue;
+    }
+    if is_done && !filter.include_done {
+      continue;
+    }
+    if let Some(due_before) = filter.due_before {
+      if !due_timestamp.is_some_and(|timestamp| timestamp < due_before) {
+        continue;
+      }
+    }
+
+    let title = task_fields
+      .primary
+      .as_ref()
+      .and_then(|field| row.cells.get(&field.id).map(|cell| stringify_cell(cell, field)))
+      .unwrap_or_default();
+    tasks.push(MyTask {
+      database_id: database_id.to_string(),
+      view_id: view_id.to_string(),
+      row_id: row.id.to_string(),
+      title,
+      due_timestamp,
+      is_done,
+    });
+  }
+  tasks
+}
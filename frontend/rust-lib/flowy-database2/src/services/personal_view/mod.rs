@@ -0,0 +1,5 @@
+mod personal_view_sql;
+mod store;
+
+pub use personal_view_sql::*;
+pub use store::*;
@@ -0,0 +1,71 @@
+use flowy_error::FlowyResult;
+use flowy_sqlite::DBConnection;
+use flowy_sqlite::schema::database_personal_view_table;
+use flowy_sqlite::schema::database_personal_view_table::dsl;
+use flowy_sqlite::{ExpressionMethods, prelude::*};
+use lib_infra::util::timestamp;
+
+/// The per-user state of a personal view. The presence of a row marks the
+/// view as personal for that user; the filters, sorts and group settings are
+/// JSON-serialized collab setting maps that shadow the ones stored in the
+/// shared database collab.
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = database_personal_view_table)]
+#[diesel(primary_key(view_id, uid))]
+pub struct PersonalViewTable {
+  pub view_id: String,
+  pub uid: i64,
+  pub filters: String,
+  pub sorts: String,
+  pub group_settings: String,
+  pub updated_at: i64,
+}
+
+impl PersonalViewTable {
+  pub fn new(
+    view_id: String,
+    uid: i64,
+    filters: String,
+    sorts: String,
+    group_settings: String,
+  ) -> Self {
+    Self {
+      view_id,
+      uid,
+      filters,
+      sorts,
+      group_settings,
+      updated_at: timestamp(),
+    }
+  }
+}
+
+pub fn upsert_personal_view(conn: &mut DBConnection, view: &PersonalViewTable) -> FlowyResult<()> {
+  diesel::replace_into(dsl::database_personal_view_table)
+    .values(view.clone())
+    .execute(conn)?;
+  Ok(())
+}
+
+pub fn select_personal_view(
+  conn: &mut DBConnection,
+  view_id: &str,
+  uid: i64,
+) -> FlowyResult<Option<PersonalViewTable>> {
+  let view = dsl::database_personal_view_table
+    .filter(database_personal_view_table::view_id.eq(view_id))
+    .filter(database_personal_view_table::uid.eq(uid))
+    .first::<PersonalViewTable>(conn)
+    .optional()?;
+  Ok(view)
+}
+
+pub fn delete_personal_view(conn: &mut DBConnection, view_id: &str, uid: i64) -> FlowyResult<()> {
+  diesel::delete(
+    dsl::database_personal_view_table
+      .filter(database_personal_view_table::view_id.eq(view_id))
+      .filter(database_personal_view_table::uid.eq(uid)),
+  )
+  .execute(conn)?;
+  Ok(())
+}
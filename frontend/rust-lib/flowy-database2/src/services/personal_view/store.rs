@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use collab_database::views::{FilterMap, GroupSettingMap, SortMap};
+use tracing::warn;
+
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::DBConnection;
+use lib_infra::util::timestamp;
+
+use crate::manager::DatabaseUser;
+use crate::services::filter::Filter;
+use crate::services::group::GroupSetting;
+use crate::services::personal_view::{
+  PersonalViewTable, delete_personal_view, select_personal_view, upsert_personal_view,
+};
+use crate::services::sort::Sort;
+
+/// Per-user storage for the settings of personal views. A personal view keeps
+/// its filters, sorts and group settings in the user's sqlite database instead
+/// of the shared collab, so teammates working on the same database don't
+/// clobber each other's working views.
+///
+/// The settings are stored as JSON-serialized collab setting maps, the same
+/// representation the shared collab uses.
+pub struct PersonalViewStore {
+  user: Arc<dyn DatabaseUser>,
+}
+
+impl PersonalViewStore {
+  pub fn new(user: Arc<dyn DatabaseUser>) -> Self {
+    Self { user }
+  }
+
+  fn conn(&self) -> FlowyResult<(i64, DBConnection)> {
+    let uid = self.user.user_id()?;
+    let conn = self.user.sqlite_connection(uid)?;
+    Ok((uid, conn))
+  }
+
+  fn load(&self, view_id: &str) -> Option<PersonalViewTable> {
+    let (uid, mut conn) = self.conn().ok()?;
+    select_personal_view(&mut conn, view_id, uid).ok()?
+  }
+
+  pub fn is_personal(&self, view_id: &str) -> bool {
+    self.load(view_id).is_some()
+  }
+
+  /// Marks the view as personal, seeding the per-user settings from the
+  /// current shared ones so the user starts from the view as it is.
+  pub fn set_personal(
+    &self,
+    view_id: &str,
+    filters: &[Filter],
+    sorts: Vec<Sort>,
+    group_settings: Vec<GroupSetting>,
+  ) -> FlowyResult<()> {
+    let (uid, mut conn) = self.conn()?;
+    let view = PersonalViewTable::new(
+      view_id.to_string(),
+      uid,
+      serialize_filters(filters)?,
+      serialize_sorts(sorts)?,
+      serialize_group_settings(group_settings)?,
+    );
+    upsert_personal_view(&mut conn, &view)
+  }
+
+  /// Removes the personal state of the view; subsequent changes go to the
+  /// shared collab again.
+  pub fn remove_personal(&self, view_id: &str) -> FlowyResult<()> {
+    let (uid, mut conn) = self.conn()?;
+    delete_personal_view(&mut conn, view_id, uid)
+  }
+
+  /// Returns the per-user filters of the view, or `None` when the view isn't
+  /// personal and the shared collab should be used instead.
+  pub fn filters(&self, view_id: &str) -> Option<Vec<Filter>> {
+    let view = self.load(view_id)?;
+    let maps = serde_json::from_str::<Vec<FilterMap>>(&view.filters).ok()?;
+    Some(
+      maps
+        .into_iter()
+        .flat_map(|map| Filter::try_from(map).ok())
+        .collect(),
+    )
+  }
+
+  pub fn save_filters(&self, view_id: &str, filters: &[Filter]) {
+    if let Err(err) = self.update(view_id, |view| {
+      view.filters = serialize_filters(filters)?;
+      Ok(())
+    }) {
+      warn!("[PersonalView]: failed to save filters: {}", err);
+    }
+  }
+
+  pub fn sorts(&self, view_id: &str) -> Option<Vec<Sort>> {
+    let view = self.load(view_id)?;
+    let maps = serde_json::from_str::<Vec<SortMap>>(&view.sorts).ok()?;
+    Some(
+      maps
+        .into_iter()
+        .flat_map(|map| Sort::try_from(map).ok())
+        .collect(),
+    )
+  }
+
+  pub fn save_sorts(&self, view_id: &str, sorts: Vec<Sort>) {
+    if let Err(err) = self.update(view_id, |view| {
+      view.sorts = serialize_sorts(sorts)?;
+      Ok(())
+    }) {
+      warn!("[PersonalView]: failed to save sorts: {}", err);
+    }
+  }
+
+  pub fn group_settings(&self, view_id: &str) -> Option<Vec<GroupSetting>> {
+    let view = self.load(view_id)?;
+    let maps = serde_json::from_str::<Vec<GroupSettingMap>>(&view.group_settings).ok()?;
+    Some(
+      maps
+        .into_iter()
+        .flat_map(|map| GroupSetting::try_from(map).ok())
+        .collect(),
+    )
+  }
+
+  pub fn save_group_settings(&self, view_id: &str, group_settings: Vec<GroupSetting>) {
+    if let Err(err) = self.update(view_id, |view| {
+      view.group_settings = serialize_group_settings(group_settings)?;
+      Ok(())
+    }) {
+      warn!("[PersonalView]: failed to save group settings: {}", err);
+    }
+  }
+
+  fn update(
+    &self,
+    view_id: &str,
+    f: impl FnOnce(&mut PersonalViewTable) -> FlowyResult<()>,
+  ) -> FlowyResult<()> {
+    let (uid, mut conn) = self.conn()?;
+    let mut view = select_personal_view(&mut conn, view_id, uid)?.ok_or_else(|| {
+      FlowyError::record_not_found()
+        .with_context(format!("view:{} is not a personal view", view_id))
+    })?;
+    f(&mut view)?;
+    view.updated_at = timestamp();
+    upsert_personal_view(&mut conn, &view)
+  }
+}
+
+fn serialize_filters(filters: &[Filter]) -> FlowyResult<String> {
+  let maps: Vec<FilterMap> = filters.iter().map(FilterMap::from).collect();
+  serde_json::to_string(&maps).map_err(|err| FlowyError::serde().with_context(err))
+}
+
+fn serialize_sorts(sorts: Vec<Sort>) -> FlowyResult<String> {
+  let maps: Vec<SortMap> = sorts.into_iter().map(SortMap::from).collect();
+  serde_json::to_string(&maps).map_err(|err| FlowyError::serde().with_context(err))
+}
+
+fn serialize_group_settings(group_settings: Vec<GroupSetting>) -> FlowyResult<String> {
+  let maps: Vec<GroupSettingMap> = group_settings
+    .into_iter()
+    .map(GroupSettingMap::from)
+    .collect();
+  serde_json::to_string(&maps).map_err(|err| FlowyError::serde().with_context(err))
+}
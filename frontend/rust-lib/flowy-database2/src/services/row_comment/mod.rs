@@ -0,0 +1,3 @@
+mod row_comment_sql;
+
+pub use row_comment_sql::*;
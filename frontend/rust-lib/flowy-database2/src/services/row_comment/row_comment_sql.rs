@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::DBConnection;
+use flowy_sqlite::schema::row_comment_table;
+use flowy_sqlite::schema::row_comment_table::dsl;
+use flowy_sqlite::{ExpressionMethods, prelude::*};
+use lib_infra::util::timestamp;
+use uuid::Uuid;
+
+/// The reactions of a comment, keyed by emoji. The value is the list of user
+/// ids that reacted with that emoji. Persisted as JSON in the `reactions`
+/// column.
+pub type CommentReactions = HashMap<String, Vec<i64>>;
+
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = row_comment_table)]
+#[diesel(primary_key(comment_id))]
+pub struct RowCommentTable {
+  pub comment_id: String,
+  pub row_id: String,
+  pub reply_comment_id: Option<String>,
+  pub uid: i64,
+  pub content: String,
+  pub reactions: String,
+  pub created_at: i64,
+  pub updated_at: i64,
+}
+
+impl RowCommentTable {
+  pub fn new(row_id: String, uid: i64, content: String, reply_comment_id: Option<String>) -> Self {
+    let now = timestamp();
+    Self {
+      comment_id: Uuid::new_v4().to_string(),
+      row_id,
+      reply_comment_id,
+      uid,
+      content,
+      reactions: "".to_string(),
+      created_at: now,
+      updated_at: now,
+    }
+  }
+
+  pub fn parse_reactions(&self) -> CommentReactions {
+    serde_json::from_str(&self.reactions).unwrap_or_default()
+  }
+}
+
+pub fn insert_row_comment(conn: &mut DBConnection, comment: &RowCommentTable) -> FlowyResult<()> {
+  diesel::insert_into(dsl::row_comment_table)
+    .values(comment.clone())
+    .execute(conn)?;
+  Ok(())
+}
+
+/// Selects a single comment by id.
+pub fn select_row_comment(
+  conn: &mut DBConnection,
+  comment_id: &str,
+) -> FlowyResult<RowCommentTable> {
+  let comment = dsl::row_comment_table
+    .filter(row_comment_table::comment_id.eq(comment_id))
+    .first::<RowCommentTable>(conn)?;
+  Ok(comment)
+}
+
+/// Updates the content of a comment. Only the author is allowed to edit, so
+/// the update is filtered by `uid`.
+pub fn update_row_comment_content(
+  conn: &mut DBConnection,
+  comment_id: &str,
+  uid: i64,
+  content: &str,
+) -> FlowyResult<RowCommentTable> {
+  let affected = diesel::update(
+    dsl::row_comment_table
+      .filter(row_comment_table::comment_id.eq(comment_id))
+      .filter(row_comment_table::uid.eq(uid)),
+  )
+  .set((
+    row_comment_table::content.eq(content),
+    row_comment_table::updated_at.eq(timestamp()),
+  ))
+  .execute(conn)?;
+  if affected == 0 {
+    return Err(
+      FlowyError::record_not_found()
+        .with_context(format!("comment:{} is not editable by uid:{}", comment_id, uid)),
+    );
+  }
+  select_row_comment(conn, comment_id)
+}
+
+/// Deletes a comment together with its direct replies. Returns the ids of all
+/// the deleted comments.
+pub fn delete_row_comment(conn: &mut DBConnection, comment_id: &str) -> FlowyResult<Vec<String>> {
+  let reply_ids = dsl::row_comment_table
+    .filter(row_comment_table::reply_comment_id.eq(comment_id))
+    .select(row_comment_table::comment_id)
+    .load::<String>(conn)?;
+  diesel::delete(
+    dsl::row_comment_table.filter(
+      row_comment_table::comment_id
+        .eq(comment_id)
+        .or(row_comment_table::reply_comment_id.eq(comment_id)),
+    ),
+  )
+  .execute(conn)?;
+
+  let mut deleted_ids = vec![comment_id.to_string()];
+  deleted_ids.extend(reply_ids);
+  Ok(deleted_ids)
+}
+
+/// Toggles the reaction of `uid` for the given emoji on a comment.
+pub fn toggle_row_comment_reaction(
+  conn: &mut DBConnection,
+  comment_id: &str,
+  uid: i64,
+  emoji: &str,
+) -> FlowyResult<RowCommentTable> {
+  let comment = select_row_comment(conn, comment_id)?;
+  let mut reactions = comment.parse_reactions();
+  let uids = reactions.entry(emoji.to_string()).or_default();
+  match uids.iter().position(|id| *id == uid) {
+    Some(index) => {
+      uids.remove(index);
+    },
+    None => uids.push(uid),
+  }
+  reactions.retain(|_, uids| !uids.is_empty());
+
+  let json = serde_json::to_string(&reactions).unwrap_or_default();
+  diesel::update(dsl::row_comment_table.filter(row_comment_table::comment_id.eq(comment_id)))
+    .set(row_comment_table::reactions.eq(json))
+    .execute(conn)?;
+  select_row_comment(conn, comment_id)
+}
+
+/// Selects a page of comments for a row, newest first. When `before` is set,
+/// only the comments created strictly before that timestamp are returned.
+/// The second element of the returned tuple indicates whether older comments
+/// remain beyond this page.
+pub fn select_row_comments(
+  conn: &mut DBConnection,
+  row_id: &str,
+  limit: i64,
+  before: Option<i64>,
+) -> FlowyResult<(Vec<RowCommentTable>, bool)> {
+  let mut query = dsl::row_comment_table
+    .filter(row_comment_table::row_id.eq(row_id))
+    .order(row_comment_table::created_at.desc())
+    .into_boxed();
+  if let Some(before) = before {
+    query = query.filter(row_comment_table::created_at.lt(before));
+  }
+  // Fetch one extra record to detect whether another page exists.
+  let mut comments = query.limit(limit + 1).load::<RowCommentTable>(conn)?;
+  let has_more = comments.len() as i64 > limit;
+  if has_more {
+    comments.truncate(limit as usize);
+  }
+  Ok((comments, has_more))
+}
+
+/// Counts all the comments of a row, replies included.
+pub fn count_row_comments(conn: &mut DBConnection, row_id: &str) -> FlowyResult<i64> {
+  let count = dsl::row_comment_table
+    .filter(row_comment_table::row_id.eq(row_id))
+    .count()
+    .get_result::<i64>(conn)?;
+  Ok(count)
+}
@@ -0,0 +1,3 @@
+mod row_history_sql;
+
+pub use row_history_sql::*;
@@ -0,0 +1,87 @@
+use flowy_error::FlowyResult;
+use flowy_sqlite::DBConnection;
+use flowy_sqlite::schema::row_history_table;
+use flowy_sqlite::schema::row_history_table::dsl;
+use flowy_sqlite::{ExpressionMethods, prelude::*};
+use lib_infra::util::timestamp;
+
+/// A recorded cell-level change of a row. The auto-incremented `id` doubles
+/// as the version number of the row history.
+#[derive(Clone, Default, Queryable, Identifiable)]
+#[diesel(table_name = row_history_table)]
+pub struct RowHistoryTable {
+  pub id: i32,
+  pub row_id: String,
+  pub field_id: String,
+  pub old_value: String,
+  pub new_value: String,
+  pub uid: i64,
+  pub created_at: i64,
+}
+
+/// The insertable part of [RowHistoryTable]. The `id` is assigned by sqlite.
+#[derive(Clone, Default, Insertable)]
+#[diesel(table_name = row_history_table)]
+pub struct RowHistoryChange {
+  pub row_id: String,
+  pub field_id: String,
+  pub old_value: String,
+  pub new_value: String,
+  pub uid: i64,
+  pub created_at: i64,
+}
+
+impl RowHistoryChange {
+  pub fn new(
+    row_id: String,
+    field_id: String,
+    uid: i64,
+    old_value: String,
+    new_value: String,
+  ) -> Self {
+    Self {
+      row_id,
+      field_id,
+      old_value,
+      new_value,
+      uid,
+      created_at: timestamp(),
+    }
+  }
+}
+
+pub fn insert_row_history(conn: &mut DBConnection, change: &RowHistoryChange) -> FlowyResult<()> {
+  diesel::insert_into(dsl::row_history_table)
+    .values(change.clone())
+    .execute(conn)?;
+  Ok(())
+}
+
+/// Selects the most recent changes of a row, newest first.
+pub fn select_row_history(
+  conn: &mut DBConnection,
+  row_id: &str,
+  limit: i64,
+) -> FlowyResult<Vec<RowHistoryTable>> {
+  let entries = dsl::row_history_table
+    .filter(row_history_table::row_id.eq(row_id))
+    .order(row_history_table::id.desc())
+    .limit(limit)
+    .load::<RowHistoryTable>(conn)?;
+  Ok(entries)
+}
+
+/// Selects all the changes of a row recorded after the given version, oldest
+/// first.
+pub fn select_row_history_after(
+  conn: &mut DBConnection,
+  row_id: &str,
+  version: i64,
+) -> FlowyResult<Vec<RowHistoryTable>> {
+  let entries = dsl::row_history_table
+    .filter(row_history_table::row_id.eq(row_id))
+    .filter(row_history_table::id.gt(version as i32))
+    .order(row_history_table::id.asc())
+    .load::<RowHistoryTable>(conn)?;
+  Ok(entries)
+}
@@ -0,0 +1,3 @@
+mod row_template_sql;
+
+pub use row_template_sql::*;
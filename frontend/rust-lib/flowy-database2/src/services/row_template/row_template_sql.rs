@@ -0,0 +1,124 @@
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::DBConnection;
+use flowy_sqlite::schema::row_template_table;
+use flowy_sqlite::schema::row_template_table::dsl;
+use flowy_sqlite::{ExpressionMethods, prelude::*};
+use lib_infra::util::timestamp;
+use uuid::Uuid;
+
+/// A saved row template. The cells are stored as a JSON-serialized
+/// [collab_database::rows::Cells] map.
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = row_template_table)]
+#[diesel(primary_key(template_id))]
+pub struct RowTemplateTable {
+  pub template_id: String,
+  pub database_id: String,
+  pub name: String,
+  pub cells: String,
+  pub icon: Option<String>,
+  pub cover: Option<String>,
+  pub source_document_id: Option<String>,
+  pub is_default: bool,
+  pub created_at: i64,
+}
+
+impl RowTemplateTable {
+  pub fn new(
+    database_id: String,
+    name: String,
+    cells: String,
+    icon: Option<String>,
+    cover: Option<String>,
+    source_document_id: Option<String>,
+  ) -> Self {
+    Self {
+      template_id: Uuid::new_v4().to_string(),
+      database_id,
+      name,
+      cells,
+      icon,
+      cover,
+      source_document_id,
+      is_default: false,
+      created_at: timestamp(),
+    }
+  }
+}
+
+pub fn insert_row_template(
+  conn: &mut DBConnection,
+  template: &RowTemplateTable,
+) -> FlowyResult<()> {
+  diesel::insert_into(dsl::row_template_table)
+    .values(template.clone())
+    .execute(conn)?;
+  Ok(())
+}
+
+pub fn select_row_template(
+  conn: &mut DBConnection,
+  template_id: &str,
+) -> FlowyResult<RowTemplateTable> {
+  let template = dsl::row_template_table
+    .filter(row_template_table::template_id.eq(template_id))
+    .first::<RowTemplateTable>(conn)?;
+  Ok(template)
+}
+
+/// Selects all the templates of a database, oldest first.
+pub fn select_row_templates(
+  conn: &mut DBConnection,
+  database_id: &str,
+) -> FlowyResult<Vec<RowTemplateTable>> {
+  let templates = dsl::row_template_table
+    .filter(row_template_table::database_id.eq(database_id))
+    .order(row_template_table::created_at.asc())
+    .load::<RowTemplateTable>(conn)?;
+  Ok(templates)
+}
+
+/// Selects the default template of a database, if one is set.
+pub fn select_default_row_template(
+  conn: &mut DBConnection,
+  database_id: &str,
+) -> FlowyResult<Option<RowTemplateTable>> {
+  let template = dsl::row_template_table
+    .filter(row_template_table::database_id.eq(database_id))
+    .filter(row_template_table::is_default.eq(true))
+    .first::<RowTemplateTable>(conn)
+    .optional()?;
+  Ok(template)
+}
+
+pub fn delete_row_template(conn: &mut DBConnection, template_id: &str) -> FlowyResult<()> {
+  diesel::delete(dsl::row_template_table.filter(row_template_table::template_id.eq(template_id)))
+    .execute(conn)?;
+  Ok(())
+}
+
+/// Marks the given template as the default of its database, clearing the flag
+/// on every other template.
+pub fn set_default_row_template(
+  conn: &mut DBConnection,
+  database_id: &str,
+  template_id: &str,
+) -> FlowyResult<()> {
+  diesel::update(
+    dsl::row_template_table.filter(row_template_table::database_id.eq(database_id)),
+  )
+  .set(row_template_table::is_default.eq(false))
+  .execute(conn)?;
+  let affected = diesel::update(
+    dsl::row_template_table.filter(row_template_table::template_id.eq(template_id)),
+  )
+  .set(row_template_table::is_default.eq(true))
+  .execute(conn)?;
+  if affected == 0 {
+    return Err(
+      FlowyError::record_not_found()
+        .with_context(format!("template:{} is not found", template_id)),
+    );
+  }
+  Ok(())
+}
@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use collab::preclude::Any;
-use collab::preclude::encoding::serde::from_any;
+use collab::preclude::encoding::serde::{from_any, to_any};
 use collab_database::views::{LayoutSetting, LayoutSettingBuilder};
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
@@ -16,6 +18,11 @@ pub struct CalendarLayoutSetting {
   pub show_week_numbers: bool,
   #[serde(default)]
   pub field_id: String,
+  /// An optional second date field marking the end of each event. When set,
+  /// rows span from [Self::field_id] to this field and the calendar renders
+  /// them on every day they cover. Empty when unset.
+  #[serde(default)]
+  pub end_field_id: String,
 }
 
 impl From<LayoutSetting> for CalendarLayoutSetting {
@@ -38,6 +45,7 @@ impl From<CalendarLayoutSetting> for LayoutSetting {
       ),
       ("show_weekends".into(), Any::Bool(setting.show_weekends)),
       ("field_id".into(), setting.field_id.into()),
+      ("end_field_id".into(), setting.end_field_id.into()),
     ])
   }
 }
@@ -50,6 +58,7 @@ impl CalendarLayoutSetting {
       show_weekends: DEFAULT_SHOW_WEEKENDS,
       show_week_numbers: DEFAULT_SHOW_WEEK_NUMBERS,
       field_id,
+      end_field_id: String::new(),
     }
   }
 }
@@ -90,6 +99,10 @@ pub struct BoardLayoutSetting {
   pub hide_ungrouped_column: bool,
   #[serde(default)]
   pub collapse_hidden_groups: bool,
+  /// The WIP limit of each group, keyed by group id. A group without an
+  /// entry, or with a non-positive limit, is unlimited.
+  #[serde(default)]
+  pub wip_limits: HashMap<String, i64>,
 }
 
 impl BoardLayoutSetting {
@@ -97,10 +110,107 @@ impl BoardLayoutSetting {
     Self {
       hide_ungrouped_column: false,
       collapse_hidden_groups: true,
+      wip_limits: HashMap::new(),
+    }
+  }
+}
+
+/// The settings of a timeline view: which date fields provide the start and
+/// end of each bar, and how far the view is zoomed.
+///
+/// `collab-database` doesn't have a dedicated timeline layout yet, so these
+/// settings are persisted under the [DatabaseLayout::Grid] key of the view
+/// that renders the timeline.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TimelineLayoutSetting {
+  #[serde(default)]
+  pub start_field_id: String,
+  #[serde(default)]
+  pub end_field_id: String,
+  #[serde(default)]
+  pub zoom_level: TimelineZoomLevel,
+}
+
+impl TimelineLayoutSetting {
+  pub fn new(start_field_id: String, end_field_id: String) -> Self {
+    Self {
+      start_field_id,
+      end_field_id,
+      zoom_level: TimelineZoomLevel::default(),
     }
   }
 }
 
+impl From<LayoutSetting> for TimelineLayoutSetting {
+  fn from(setting: LayoutSetting) -> Self {
+    from_any(&Any::from(setting)).unwrap_or_default()
+  }
+}
+
+impl From<TimelineLayoutSetting> for LayoutSetting {
+  fn from(setting: TimelineLayoutSetting) -> Self {
+    LayoutSettingBuilder::from([
+      ("start_field_id".into(), setting.start_field_id.into()),
+      ("end_field_id".into(), setting.end_field_id.into()),
+      (
+        "zoom_level".into(),
+        Any::BigInt(setting.zoom_level.value() as i64),
+      ),
+    ])
+  }
+}
+
+/// The settings of a form view: which fields are shown to the submitter, in
+/// which order, and which of them are required.
+///
+/// Like [TimelineLayoutSetting], form settings are persisted under the
+/// [DatabaseLayout::Grid] key because `collab-database` doesn't have a
+/// dedicated form layout yet. A view is only ever one sublayout, so the two
+/// settings never collide.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormLayoutSetting {
+  #[serde(default)]
+  pub fields: Vec<FormFieldSetting>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormFieldSetting {
+  pub field_id: String,
+  #[serde(default)]
+  pub visible: bool,
+  #[serde(default)]
+  pub required: bool,
+}
+
+impl From<LayoutSetting> for FormLayoutSetting {
+  fn from(setting: LayoutSetting) -> Self {
+    from_any(&Any::from(setting)).unwrap_or_default()
+  }
+}
+
+impl From<FormLayoutSetting> for LayoutSetting {
+  fn from(setting: FormLayoutSetting) -> Self {
+    let fields = to_any(&setting.fields).unwrap_or_else(|_| Any::Array(std::sync::Arc::from([])));
+    LayoutSettingBuilder::from([("fields".into(), fields)])
+  }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum TimelineZoomLevel {
+  Day = 0,
+  #[default]
+  Week = 1,
+  Month = 2,
+  Year = 3,
+}
+
+impl TimelineZoomLevel {
+  pub fn value(&self) -> u8 {
+    *self as u8
+  }
+}
+
 impl From<LayoutSetting> for BoardLayoutSetting {
   fn from(setting: LayoutSetting) -> Self {
     from_any(&Any::from(setting)).unwrap()
@@ -109,6 +219,8 @@ impl From<LayoutSetting> for BoardLayoutSetting {
 
 impl From<BoardLayoutSetting> for LayoutSetting {
   fn from(setting: BoardLayoutSetting) -> Self {
+    let wip_limits = to_any(&setting.wip_limits)
+      .unwrap_or_else(|_| Any::Map(std::sync::Arc::new(Default::default())));
     LayoutSettingBuilder::from([
       (
         "hide_ungrouped_column".into(),
@@ -118,6 +230,7 @@ impl From<BoardLayoutSetting> for LayoutSetting {
         "collapse_hidden_groups".into(),
         setting.collapse_hidden_groups.into(),
       ),
+      ("wip_limits".into(), wip_limits),
     ])
   }
 }
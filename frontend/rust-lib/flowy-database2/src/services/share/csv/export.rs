@@ -1,6 +1,8 @@
+use std::sync::Arc;
+
 use collab_database::database::Database;
 use collab_database::fields::Field;
-use collab_database::rows::Cell;
+use collab_database::rows::{Cell, Row};
 use collab_database::template::timestamp_parse::TimestampCellData;
 use futures::StreamExt;
 use indexmap::IndexMap;
@@ -27,11 +29,30 @@ impl CSVExport {
     database: &Database,
     style: CSVFormat,
   ) -> FlowyResult<String> {
-    let mut wtr = csv::Writer::from_writer(vec![]);
     let view_id = database
       .get_first_database_view_id()
       .ok_or_else(|| FlowyError::internal().with_context("failed to get first database view"))?;
     let fields = database.get_fields_in_view(&view_id, None);
+    let rows = database
+      .get_rows_for_view(&view_id, 20, None)
+      .await
+      .filter_map(|result| async { result.ok() })
+      .map(Arc::new)
+      .collect::<Vec<_>>()
+      .await;
+
+    self.export_rows(fields, rows, style)
+  }
+
+  /// Exports the given rows with the given fields, in order. Used to export a
+  /// view with its filters, sorts and field visibility already applied.
+  pub fn export_rows(
+    &self,
+    fields: Vec<Field>,
+    rows: Vec<Arc<Row>>,
+    style: CSVFormat,
+  ) -> FlowyResult<String> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
 
     // Write fields
     let field_records = fields
@@ -50,12 +71,6 @@ impl CSVExport {
     fields.into_iter().for_each(|field| {
       field_by_field_id.insert(field.id.clone(), field);
     });
-    let rows = database
-      .get_rows_for_view(&view_id, 20, None)
-      .await
-      .filter_map(|result| async { result.ok() })
-      .collect::<Vec<_>>()
-      .await;
 
     let stringify = |cell: &Cell, field: &Field, style: CSVFormat| match style {
       CSVFormat::Original => stringify_cell(cell, field),
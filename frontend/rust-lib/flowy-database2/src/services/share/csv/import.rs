@@ -10,7 +10,10 @@ use std::{fs::File, io::prelude::*};
 use crate::entities::FieldType;
 use crate::services::field::{CELL_DATA, default_type_option_data_from_type};
 use crate::services::field_settings::default_field_settings_for_fields;
-use crate::services::share::csv::CSVFormat;
+use crate::services::share::csv::{
+  CSVFormat, CSVImportOptions, CSVRowImportError, INFER_SAMPLE_SIZE, infer_field_type, typed_cell,
+  typed_field,
+};
 
 #[derive(Default)]
 pub struct CSVImporter;
@@ -41,6 +44,105 @@ impl CSVImporter {
     Ok(database_data)
   }
 
+  /// Imports the content inferring a field type for every column by sampling
+  /// its values, honoring the explicit column overrides in `options`. Rows
+  /// whose cells don't parse as the column type are reported in the returned
+  /// error list instead of failing the whole file; the offending cells are
+  /// left empty.
+  pub fn import_csv_from_string_with_options(
+    &self,
+    view_id: &str,
+    content: String,
+    options: CSVImportOptions,
+  ) -> FlowyResult<(CreateDatabaseParams, Vec<CSVRowImportError>)> {
+    let FieldsRows { fields: headers, rows } = self.get_fields_and_rows(content)?;
+    let database_id = gen_database_id();
+
+    // One (field, option name → option id) pair per column.
+    let columns = headers
+      .into_iter()
+      .enumerate()
+      .map(|(index, header)| {
+        let mut distinct_values: Vec<String> = vec![];
+        for row in rows.iter() {
+          if let Some(value) = row.get(index) {
+            let value = value.trim();
+            if !value.is_empty() && !distinct_values.iter().any(|v| v == value) {
+              distinct_values.push(value.to_string());
+            }
+          }
+        }
+
+        // The primary field is always text.
+        let field_type = if index == 0 {
+          FieldType::RichText
+        } else if let Some(field_type) = options.column_types.get(&header) {
+          *field_type
+        } else if options.infer_field_types {
+          let samples = rows
+            .iter()
+            .filter_map(|row| row.get(index).map(|value| value.trim()))
+            .filter(|value| !value.is_empty())
+            .take(INFER_SAMPLE_SIZE)
+            .collect::<Vec<_>>();
+          infer_field_type(&samples)
+        } else {
+          FieldType::RichText
+        };
+        typed_field(header, field_type, index == 0, &distinct_values)
+      })
+      .collect::<Vec<_>>();
+
+    let mut row_errors = vec![];
+    let rows = rows
+      .iter()
+      .enumerate()
+      .map(|(row_index, cells)| {
+        let mut params = CreateRowParams::new(gen_row_id(), database_id.clone());
+        for (index, cell_content) in cells.iter().enumerate() {
+          if let Some((field, option_ids)) = columns.get(index) {
+            if cell_content.trim().is_empty() {
+              continue;
+            }
+            let field_type = FieldType::from(field.field_type);
+            match typed_cell(cell_content, field_type, option_ids) {
+              Ok(cell) => {
+                params.cells.insert(field.id.clone(), cell);
+              },
+              Err(error) => row_errors.push(CSVRowImportError {
+                row: row_index,
+                column: field.name.clone(),
+                error,
+              }),
+            }
+          }
+        }
+        params
+      })
+      .collect::<Vec<CreateRowParams>>();
+
+    let fields = columns.into_iter().map(|(field, _)| field).collect::<Vec<_>>();
+    let field_settings = default_field_settings_for_fields(&fields, DatabaseLayout::Grid);
+    let timestamp = timestamp();
+
+    let params = CreateDatabaseParams {
+      database_id: database_id.clone(),
+      rows,
+      fields,
+      views: vec![CreateViewParams {
+        database_id,
+        view_id: view_id.to_string(),
+        name: "".to_string(),
+        layout: DatabaseLayout::Grid,
+        field_settings,
+        created_at: timestamp,
+        modified_at: timestamp,
+        ..Default::default()
+      }],
+    };
+    Ok((params, row_errors))
+  }
+
   fn get_fields_and_rows(&self, content: String) -> Result<FieldsRows, FlowyError> {
     let mut fields: Vec<String> = vec![];
     if content.is_empty() {
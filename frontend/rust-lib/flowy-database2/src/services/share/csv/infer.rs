@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use collab_database::database::gen_field_id;
+use collab_database::fields::Field;
+use collab_database::fields::select_type_option::{
+  SELECTION_IDS_SEPARATOR, SelectOption, SelectTypeOption, SingleSelectTypeOption,
+};
+use collab_database::rows::{Cell, new_cell_builder};
+
+use crate::entities::FieldType;
+use crate::services::field::{
+  CELL_DATA, CHECK, UNCHECK, default_type_option_data_from_type, select_type_option_from_field,
+};
+
+/// The maximum number of non-empty values sampled per column when inferring
+/// its field type.
+pub(crate) const INFER_SAMPLE_SIZE: usize = 50;
+
+/// The maximum number of distinct values a column may hold to be inferred as
+/// a single select field.
+const SELECT_OPTION_LIMIT: usize = 10;
+
+/// Options of the typed CSV import pipeline.
+#[derive(Default)]
+pub struct CSVImportOptions {
+  /// When true, a field type is inferred for every column by sampling its
+  /// values. When false, every column is imported as text like the legacy
+  /// importer.
+  pub infer_field_types: bool,
+  /// Explicit column name → field type overrides, taking precedence over
+  /// inference.
+  pub column_types: HashMap<String, FieldType>,
+}
+
+/// A cell that failed to parse as the type of its column. The row is still
+/// imported, the offending cell is left empty.
+#[derive(Debug, Clone)]
+pub struct CSVRowImportError {
+  /// Zero-based index of the row in the file, header excluded.
+  pub row: usize,
+  pub column: String,
+  pub error: String,
+}
+
+/// Infers the field type of a column from a sample of its non-empty values.
+pub(crate) fn infer_field_type(samples: &[&str]) -> FieldType {
+  if samples.is_empty() {
+    return FieldType::RichText;
+  }
+  if samples.iter().all(|value| parse_checkbox(value).is_some()) {
+    return FieldType::Checkbox;
+  }
+  if samples.iter().all(|value| parse_number(value).is_some()) {
+    return FieldType::Number;
+  }
+  if samples.iter().all(|value| parse_date(value).is_some()) {
+    return FieldType::DateTime;
+  }
+
+  let mut distinct: Vec<&str> = vec![];
+  for value in samples {
+    if !distinct.contains(value) {
+      distinct.push(value);
+    }
+  }
+  // A column with few repeated values is likely an enumeration.
+  if distinct.len() <= SELECT_OPTION_LIMIT && distinct.len() < samples.len() {
+    return FieldType::SingleSelect;
+  }
+  FieldType::RichText
+}
+
+pub(crate) fn parse_checkbox(value: &str) -> Option<bool> {
+  match value.trim().to_lowercase().as_str() {
+    "1" | "true" | "yes" => Some(true),
+    "0" | "false" | "no" => Some(false),
+    _ => None,
+  }
+}
+
+pub(crate) fn parse_number(value: &str) -> Option<f64> {
+  value.trim().replace(',', "").parse::<f64>().ok()
+}
+
+/// Parses a date in one of the formats commonly found in CSV exports,
+/// returning a unix timestamp at midnight UTC.
+pub(crate) fn parse_date(value: &str) -> Option<i64> {
+  const FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y", "%B %d, %Y", "%b %d, %Y"];
+  let value = value.trim();
+  FORMATS
+    .iter()
+    .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+    .map(|date| date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp())
+}
+
+/// Creates a field of the given type for a CSV column. Single select fields
+/// get one option per distinct value of the column.
+pub(crate) fn typed_field(
+  name: String,
+  field_type: FieldType,
+  is_primary: bool,
+  distinct_values: &[String],
+) -> (Field, HashMap<String, String>) {
+  let field_id = gen_field_id();
+  match field_type {
+    FieldType::SingleSelect => {
+      let options = distinct_values
+        .iter()
+        .map(|value| SelectOption::new(value))
+        .collect::<Vec<_>>();
+      let option_ids = options
+        .iter()
+        .map(|option| (option.name.clone(), option.id.clone()))
+        .collect::<HashMap<_, _>>();
+      let type_option = SingleSelectTypeOption(SelectTypeOption {
+        options,
+        disable_color: false,
+      });
+      let field = Field::new(field_id, name, field_type.into(), is_primary)
+        .with_type_option_data(field_type, type_option.into());
+      (field, option_ids)
+    },
+    _ => {
+      let type_option_data = default_type_option_data_from_type(field_type);
+      let field = Field::new(field_id, name, field_type.into(), is_primary)
+        .with_type_option_data(field_type, type_option_data);
+      (field, HashMap::new())
+    },
+  }
+}
+
+/// Converts a CSV value into a cell of the given field type. Returns an error
+/// message when the value doesn't parse as that type.
+pub(crate) fn typed_cell(
+  content: &str,
+  field_type: FieldType,
+  option_ids: &HashMap<String, String>,
+) -> Result<Cell, String> {
+  let mut cell = new_cell_builder(field_type);
+  match field_type {
+    FieldType::Number => {
+      parse_number(content).ok_or_else(|| format!("'{}' is not a number", content))?;
+      cell.insert(CELL_DATA.into(), content.trim().into());
+    },
+    FieldType::Checkbox => {
+      let is_checked =
+        parse_checkbox(content).ok_or_else(|| format!("'{}' is not a checkbox value", content))?;
+      let value = if is_checked { CHECK } else { UNCHECK };
+      cell.insert(CELL_DATA.into(), value.into());
+    },
+    FieldType::DateTime => {
+      let timestamp =
+        parse_date(content).ok_or_else(|| format!("'{}' is not a date", content))?;
+      cell.insert(CELL_DATA.into(), timestamp.to_string().into());
+    },
+    FieldType::SingleSelect => {
+      let option_id = option_ids
+        .get(content.trim())
+        .ok_or_else(|| format!("'{}' is not a known option", content))?;
+      cell.insert(CELL_DATA.into(), option_id.as_str().into());
+    },
+    _ => {
+      cell.insert(CELL_DATA.into(), content.into());
+    },
+  }
+  Ok(cell)
+}
+
+/// Converts a CSV value into a cell for an existing field, matching select
+/// values against the field's options by name.
+pub(crate) fn typed_cell_for_field(content: &str, field: &Field) -> Result<Cell, String> {
+  let field_type = FieldType::from(field.field_type);
+  match field_type {
+    FieldType::SingleSelect | FieldType::MultiSelect => {
+      let type_option = select_type_option_from_field(field).map_err(|err| err.to_string())?;
+      let options = type_option.options();
+      let ids = content
+        .split(SELECTION_IDS_SEPARATOR)
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+          options
+            .iter()
+            .find(|option| option.name == name)
+            .map(|option| option.id.clone())
+            .ok_or_else(|| format!("'{}' is not a known option", name))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+      let mut cell = new_cell_builder(field_type);
+      cell.insert(CELL_DATA.into(), ids.join(SELECTION_IDS_SEPARATOR).into());
+      Ok(cell)
+    },
+    _ => typed_cell(content, field_type, &HashMap::new()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn infer_number_column_test() {
+    assert_eq!(
+      infer_field_type(&["1", "2.5", "1,000", " 42 "]),
+      FieldType::Number
+    );
+  }
+
+  #[test]
+  fn infer_checkbox_column_test() {
+    assert_eq!(
+      infer_field_type(&["Yes", "no", "true", "0"]),
+      FieldType::Checkbox
+    );
+  }
+
+  #[test]
+  fn infer_date_column_test() {
+    assert_eq!(
+      infer_field_type(&["2023-05-26", "May 22, 2023"]),
+      FieldType::DateTime
+    );
+  }
+
+  #[test]
+  fn infer_select_column_test() {
+    assert_eq!(
+      infer_field_type(&["todo", "doing", "done", "todo", "done"]),
+      FieldType::SingleSelect
+    );
+  }
+
+  #[test]
+  fn infer_text_column_test() {
+    assert_eq!(
+      infer_field_type(&["alpha", "beta", "gamma", "delta"]),
+      FieldType::RichText
+    );
+    assert_eq!(infer_field_type(&[]), FieldType::RichText);
+  }
+
+  #[test]
+  fn parse_date_formats_test() {
+    assert_eq!(parse_date("2023-05-26"), parse_date("May 26, 2023"));
+    assert!(parse_date("not a date").is_none());
+  }
+}
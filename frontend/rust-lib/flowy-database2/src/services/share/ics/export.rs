@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeZone, Utc};
+use collab_database::fields::Field;
+use collab_database::fields::date_type_option::DateCellData;
+use collab_database::rows::Row;
+
+use crate::services::cell::stringify_cell;
+use crate::services::field::recurrence::{RecurringFrequency, RecurringRule, get_recurring_rule};
+
+/// Renders calendar rows as an iCalendar (RFC 5545) document, one VEVENT per
+/// row with a date in the calendar's date field. Recurring rules are emitted
+/// as RRULE/EXDATE so subscribing calendar apps expand them on their own.
+pub struct ICSExport;
+
+impl ICSExport {
+  pub fn export_rows(
+    &self,
+    primary_field: &Field,
+    date_field: &Field,
+    rows: Vec<Arc<Row>>,
+  ) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//AppFlowy//Database Calendar//EN");
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+
+    let dtstamp = format_utc(Utc::now());
+    for row in rows {
+      let date_cell = match row.cells.get(&date_field.id) {
+        Some(cell) => cell,
+        None => continue,
+      };
+      let cell_data = DateCellData::from(date_cell);
+      let timestamp = match cell_data.timestamp {
+        Some(timestamp) => timestamp,
+        None => continue,
+      };
+
+      let summary = row
+        .cells
+        .get(&primary_field.id)
+        .map(|cell| stringify_cell(cell, primary_field))
+        .unwrap_or_default();
+
+      push_line(&mut out, "BEGIN:VEVENT");
+      push_line(&mut out, &format!("UID:{}@appflowy", row.id));
+      push_line(&mut out, &format!("DTSTAMP:{}", dtstamp));
+      push_line(&mut out, &format!("SUMMARY:{}", escape_text(&summary)));
+      push_date(&mut out, "DTSTART", timestamp, cell_data.include_time);
+      if let Some(end_timestamp) = cell_data.end_timestamp {
+        push_date(&mut out, "DTEND", end_timestamp, cell_data.include_time);
+      }
+      if let Some(rule) = get_recurring_rule(date_cell) {
+        push_rrule(&mut out, &rule, cell_data.include_time);
+      }
+      push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+  }
+}
+
+fn push_rrule(out: &mut String, rule: &RecurringRule, include_time: bool) {
+  let frequency = match rule.frequency {
+    RecurringFrequency::Daily => "DAILY",
+    RecurringFrequency::Weekly => "WEEKLY",
+    RecurringFrequency::Monthly => "MONTHLY",
+    RecurringFrequency::Yearly => "YEARLY",
+  };
+  let mut rrule = format!("RRULE:FREQ={}", frequency);
+  if rule.interval > 1 {
+    rrule.push_str(&format!(";INTERVAL={}", rule.interval));
+  }
+  if let Some(until) = rule.until {
+    if let Some(until) = Utc.timestamp_opt(until, 0).single() {
+      rrule.push_str(&format!(";UNTIL={}", format_utc(until)));
+    }
+  }
+  push_line(out, &rrule);
+
+  for exdate in &rule.exdates {
+    if let Some(exdate) = Utc.timestamp_opt(*exdate, 0).single() {
+      if include_time {
+        push_line(out, &format!("EXDATE:{}", format_utc(exdate)));
+      } else {
+        push_line(
+          out,
+          &format!("EXDATE;VALUE=DATE:{}", exdate.format("%Y%m%d")),
+        );
+      }
+    }
+  }
+}
+
+/// Dates without a time render as all-day DATE values, timed ones as UTC
+/// DATE-TIME values.
+fn push_date(out: &mut String, property: &str, timestamp: i64, include_time: bool) {
+  let datetime = match Utc.timestamp_opt(timestamp, 0).single() {
+    Some(datetime) => datetime,
+    None => return,
+  };
+  if include_time {
+    push_line(out, &format!("{}:{}", property, format_utc(datetime)));
+  } else {
+    push_line(
+      out,
+      &format!("{};VALUE=DATE:{}", property, datetime.format("%Y%m%d")),
+    );
+  }
+}
+
+fn format_utc(datetime: DateTime<Utc>) -> String {
+  datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// ICS lines end with CRLF.
+fn push_line(out: &mut String, line: &str) {
+  out.push_str(line);
+  out.push_str("\r\n");
+}
+
+/// Escapes TEXT values per RFC 5545: backslash, comma, semicolon and line
+/// breaks.
+fn escape_text(text: &str) -> String {
+  text
+    .replace('\\', "\\\\")
+    .replace(',', "\\,")
+    .replace(';', "\\;")
+    .replace("\r\n", "\\n")
+    .replace(['\n', '\r'], "\\n")
+}
@@ -0,0 +1,61 @@
+use collab_database::database::Database;
+use collab_database::fields::Field;
+use collab_database::rows::Cells;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use flowy_error::{FlowyError, FlowyResult};
+
+/// A database serialized as a schema (fields with their type options) plus
+/// rows. Field ids, select option ids and relation type options are kept
+/// as-is so an export can be re-imported without breaking references.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseJsonData {
+  pub database_id: String,
+  pub fields: Vec<Field>,
+  pub rows: Vec<DatabaseJsonRow>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DatabaseJsonRow {
+  pub id: String,
+  pub cells: Cells,
+  pub height: i32,
+  pub visibility: bool,
+  pub created_at: i64,
+  pub modified_at: i64,
+}
+
+pub struct DatabaseJsonExport;
+
+impl DatabaseJsonExport {
+  pub async fn export_database(&self, database: &Database) -> FlowyResult<String> {
+    let view_id = database
+      .get_first_database_view_id()
+      .ok_or_else(|| FlowyError::internal().with_context("failed to get first database view"))?;
+    let fields = database.get_fields_in_view(&view_id, None);
+    let rows = database
+      .get_rows_for_view(&view_id, 20, None)
+      .await
+      .filter_map(|result| async { result.ok() })
+      .collect::<Vec<_>>()
+      .await;
+
+    let data = DatabaseJsonData {
+      database_id: database.get_database_id(),
+      fields,
+      rows: rows
+        .into_iter()
+        .map(|row| DatabaseJsonRow {
+          id: row.id.into_inner(),
+          cells: row.cells,
+          height: row.height,
+          visibility: row.visibility,
+          created_at: row.created_at,
+          modified_at: row.modified_at,
+        })
+        .collect(),
+    };
+    serde_json::to_string(&data).map_err(|err| FlowyError::serde().with_context(err))
+  }
+}
@@ -0,0 +1,66 @@
+use collab_database::database::timestamp;
+use collab_database::entity::{CreateDatabaseParams, CreateViewParams};
+use collab_database::rows::{CreateRowParams, RowId};
+use collab_database::views::{DatabaseLayout, OrderObjectPosition};
+
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::services::field_settings::default_field_settings_for_fields;
+use crate::services::share::json::DatabaseJsonData;
+
+#[derive(Default)]
+pub struct DatabaseJsonImporter;
+
+impl DatabaseJsonImporter {
+  /// Recreates a database from an export produced by [DatabaseJsonExport],
+  /// keeping the original database id, field ids, select option ids and row
+  /// ids so relation cells and select cells keep resolving.
+  ///
+  /// [DatabaseJsonExport]: crate::services::share::json::DatabaseJsonExport
+  pub fn import_json_from_string(
+    &self,
+    view_id: &str,
+    content: String,
+  ) -> FlowyResult<CreateDatabaseParams> {
+    let data = serde_json::from_str::<DatabaseJsonData>(&content)
+      .map_err(|err| FlowyError::serde().with_context(err))?;
+    let DatabaseJsonData {
+      database_id,
+      fields,
+      rows,
+    } = data;
+
+    let rows = rows
+      .into_iter()
+      .map(|row| CreateRowParams {
+        id: RowId::from(row.id),
+        database_id: database_id.clone(),
+        cells: row.cells,
+        height: row.height,
+        visibility: row.visibility,
+        row_position: OrderObjectPosition::End,
+        created_at: row.created_at,
+        modified_at: row.modified_at,
+      })
+      .collect::<Vec<CreateRowParams>>();
+
+    let field_settings = default_field_settings_for_fields(&fields, DatabaseLayout::Grid);
+    let timestamp = timestamp();
+
+    Ok(CreateDatabaseParams {
+      database_id: database_id.clone(),
+      rows,
+      fields,
+      views: vec![CreateViewParams {
+        database_id,
+        view_id: view_id.to_string(),
+        name: "".to_string(),
+        layout: DatabaseLayout::Grid,
+        field_settings,
+        created_at: timestamp,
+        modified_at: timestamp,
+        ..Default::default()
+      }],
+    })
+  }
+}
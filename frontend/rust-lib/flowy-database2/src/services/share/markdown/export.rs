@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Row};
+use collab_database::template::timestamp_parse::TimestampCellData;
+
+use crate::entities::{CheckboxCellDataPB, FieldType};
+use crate::services::cell::stringify_cell;
+
+/// Renders rows as a GitHub-flavored markdown table, so a view can be pasted
+/// into documents or issues.
+pub struct MarkdownExport;
+
+impl MarkdownExport {
+  /// Exports the given rows with the given fields, in order. Used to export a
+  /// view with its filters, sorts and field visibility already applied.
+  pub fn export_rows(&self, fields: Vec<Field>, rows: Vec<Arc<Row>>) -> String {
+    if fields.is_empty() {
+      return String::new();
+    }
+
+    let mut out = String::new();
+    write_table_row(&mut out, fields.iter().map(|field| escape_cell(&field.name)));
+    write_table_row(&mut out, fields.iter().map(|_| "---".to_string()));
+
+    for row in rows {
+      let cells = fields.iter().map(|field| {
+        let field_type = FieldType::from(field.field_type);
+        match field_type {
+          FieldType::LastEditedTime | FieldType::CreatedTime => {
+            let cell_data = if field_type.is_created_time() {
+              TimestampCellData::new(row.created_at)
+            } else {
+              TimestampCellData::new(row.modified_at)
+            };
+            let cell = cell_data.to_cell(field.field_type);
+            escape_cell(&stringify_cell(&cell, field))
+          },
+          _ => match row.cells.get(&field.id) {
+            None => "".to_string(),
+            Some(cell) => stringify_markdown_cell(cell, field, field_type),
+          },
+        }
+      });
+      write_table_row(&mut out, cells);
+    }
+
+    out
+  }
+}
+
+fn stringify_markdown_cell(cell: &Cell, field: &Field, field_type: FieldType) -> String {
+  match field_type {
+    // Checkboxes render as task-list glyphs instead of the Yes/No strings
+    // used by the plain stringify.
+    FieldType::Checkbox => {
+      if CheckboxCellDataPB::from(cell).is_checked {
+        "[x]".to_string()
+      } else {
+        "[ ]".to_string()
+      }
+    },
+    _ => escape_cell(&stringify_cell(cell, field)),
+  }
+}
+
+fn write_table_row(out: &mut String, cells: impl Iterator<Item = String>) {
+  out.push('|');
+  for cell in cells {
+    out.push(' ');
+    out.push_str(&cell);
+    out.push_str(" |");
+  }
+  out.push('\n');
+}
+
+/// Escapes characters that would break the table layout: pipes are escaped
+/// and line breaks become `<br>` so a multi-line cell stays in one table row.
+fn escape_cell(text: &str) -> String {
+  text
+    .replace('|', "\\|")
+    .replace("\r\n", "<br>")
+    .replace(['\n', '\r'], "<br>")
+}
@@ -1 +1,5 @@
 pub mod csv;
+pub mod ics;
+pub mod json;
+pub mod markdown;
+pub mod xlsx;
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::Row;
+use collab_database::template::timestamp_parse::TimestampCellData;
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook};
+
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell;
+use crate::services::field::{CELL_DATA, CHECK};
+
+pub struct XLSXExport;
+
+impl XLSXExport {
+  /// Exports the given rows with the given fields to an .xlsx workbook.
+  /// Number, checkbox and date cells are written as typed excel cells instead
+  /// of text.
+  pub fn export_rows(&self, fields: Vec<Field>, rows: Vec<Arc<Row>>) -> FlowyResult<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let date_format = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+
+    // Write fields
+    for (index, field) in fields.iter().enumerate() {
+      worksheet
+        .write_string(0, index as u16, &field.name)
+        .map_err(xlsx_error)?;
+    }
+
+    // Write rows
+    for (index, row) in rows.iter().enumerate() {
+      let row_index = index as u32 + 1;
+      for (col, field) in fields.iter().enumerate() {
+        let col = col as u16;
+        let field_type = FieldType::from(field.field_type);
+        let cell = match field_type {
+          FieldType::LastEditedTime | FieldType::CreatedTime => {
+            let cell_data = if field_type.is_created_time() {
+              TimestampCellData::new(row.created_at)
+            } else {
+              TimestampCellData::new(row.modified_at)
+            };
+            Some(cell_data.to_cell(field.field_type))
+          },
+          _ => row.cells.get(&field.id).cloned(),
+        };
+        let cell = match cell {
+          Some(cell) => cell,
+          None => continue,
+        };
+
+        match field_type {
+          FieldType::Number => {
+            let content = cell.get_as::<String>(CELL_DATA).unwrap_or_default();
+            match content.trim().replace(',', "").parse::<f64>() {
+              Ok(number) => {
+                worksheet
+                  .write_number(row_index, col, number)
+                  .map_err(xlsx_error)?;
+              },
+              Err(_) => {
+                worksheet
+                  .write_string(row_index, col, stringify_cell(&cell, field))
+                  .map_err(xlsx_error)?;
+              },
+            }
+          },
+          FieldType::Checkbox => {
+            let is_checked = cell
+              .get_as::<String>(CELL_DATA)
+              .map(|data| data == CHECK)
+              .unwrap_or(false);
+            worksheet
+              .write_boolean(row_index, col, is_checked)
+              .map_err(xlsx_error)?;
+          },
+          FieldType::DateTime | FieldType::LastEditedTime | FieldType::CreatedTime => {
+            let datetime = cell
+              .get_as::<String>(CELL_DATA)
+              .and_then(|data| data.parse::<i64>().ok())
+              .and_then(|timestamp| ExcelDateTime::from_timestamp(timestamp).ok());
+            match datetime {
+              Some(datetime) => {
+                worksheet
+                  .write_datetime_with_format(row_index, col, &datetime, &date_format)
+                  .map_err(xlsx_error)?;
+              },
+              None => {
+                worksheet
+                  .write_string(row_index, col, stringify_cell(&cell, field))
+                  .map_err(xlsx_error)?;
+              },
+            }
+          },
+          _ => {
+            worksheet
+              .write_string(row_index, col, stringify_cell(&cell, field))
+              .map_err(xlsx_error)?;
+          },
+        }
+      }
+    }
+
+    workbook.save_to_buffer().map_err(xlsx_error)
+  }
+}
+
+fn xlsx_error(error: rust_xlsxwriter::XlsxError) -> FlowyError {
+  FlowyError::internal().with_context(error)
+}
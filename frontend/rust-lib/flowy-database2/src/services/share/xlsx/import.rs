@@ -0,0 +1,135 @@
+use std::io::Cursor;
+
+use calamine::{Data, Reader, Xlsx};
+use collab_database::database::{gen_database_id, gen_row_id, timestamp};
+use collab_database::entity::{CreateDatabaseParams, CreateViewParams};
+use collab_database::fields::Field;
+use collab_database::rows::{CreateRowParams, new_cell_builder};
+use collab_database::views::DatabaseLayout;
+
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::field::{CELL_DATA, CHECK, UNCHECK};
+use crate::services::field_settings::default_field_settings_for_fields;
+use crate::services::share::csv::typed_field;
+
+#[derive(Default)]
+pub struct XLSXImporter;
+
+impl XLSXImporter {
+  /// Imports the first sheet of an .xlsx workbook as a grid, keeping the
+  /// typed cells of the sheet: boolean columns become checkbox fields,
+  /// numeric columns number fields and date columns date fields.
+  pub fn import_xlsx_from_bytes(
+    &self,
+    view_id: &str,
+    bytes: Vec<u8>,
+  ) -> FlowyResult<CreateDatabaseParams> {
+    let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(bytes))
+      .map_err(|err| FlowyError::invalid_data().with_context(format!("Invalid xlsx: {}", err)))?;
+    let range = workbook
+      .worksheet_range_at(0)
+      .ok_or_else(|| FlowyError::invalid_data().with_context("The workbook has no sheet"))?
+      .map_err(|err| {
+        FlowyError::invalid_data().with_context(format!("Failed to read sheet: {}", err))
+      })?;
+
+    let mut sheet_rows = range.rows();
+    let headers = sheet_rows
+      .next()
+      .ok_or_else(|| FlowyError::invalid_data().with_context("The sheet is empty"))?;
+    let records = sheet_rows.collect::<Vec<_>>();
+
+    let database_id = gen_database_id();
+    let fields = headers
+      .iter()
+      .enumerate()
+      .map(|(index, header)| {
+        // The primary field is always text.
+        let field_type = if index == 0 {
+          FieldType::RichText
+        } else {
+          infer_column_type(&records, index)
+        };
+        let (field, _) = typed_field(header.to_string(), field_type, index == 0, &[]);
+        field
+      })
+      .collect::<Vec<Field>>();
+
+    let rows = records
+      .iter()
+      .map(|record| {
+        let mut params = CreateRowParams::new(gen_row_id(), database_id.clone());
+        for (index, data) in record.iter().enumerate() {
+          if let Some(field) = fields.get(index) {
+            if matches!(data, Data::Empty) {
+              continue;
+            }
+            let field_type = FieldType::from(field.field_type);
+            let value = match (field_type, data) {
+              (FieldType::Number, Data::Float(value)) => value.to_string(),
+              (FieldType::Number, Data::Int(value)) => value.to_string(),
+              (FieldType::Checkbox, Data::Bool(is_checked)) => {
+                if *is_checked { CHECK } else { UNCHECK }.to_string()
+              },
+              (FieldType::DateTime, Data::DateTime(value)) => match value.as_datetime() {
+                Some(datetime) => datetime.and_utc().timestamp().to_string(),
+                None => continue,
+              },
+              _ => data.to_string(),
+            };
+            let mut cell = new_cell_builder(field_type);
+            cell.insert(CELL_DATA.into(), value.into());
+            params.cells.insert(field.id.clone(), cell);
+          }
+        }
+        params
+      })
+      .collect::<Vec<CreateRowParams>>();
+
+    let field_settings = default_field_settings_for_fields(&fields, DatabaseLayout::Grid);
+    let timestamp = timestamp();
+
+    Ok(CreateDatabaseParams {
+      database_id: database_id.clone(),
+      rows,
+      fields,
+      views: vec![CreateViewParams {
+        database_id,
+        view_id: view_id.to_string(),
+        name: "".to_string(),
+        layout: DatabaseLayout::Grid,
+        field_settings,
+        created_at: timestamp,
+        modified_at: timestamp,
+        ..Default::default()
+      }],
+    })
+  }
+}
+
+/// Infers a field type for a column from the excel cell types of its values.
+fn infer_column_type(records: &[&[Data]], index: usize) -> FieldType {
+  let samples = records
+    .iter()
+    .filter_map(|record| record.get(index))
+    .filter(|data| !matches!(data, Data::Empty))
+    .collect::<Vec<_>>();
+  if samples.is_empty() {
+    return FieldType::RichText;
+  }
+  if samples.iter().all(|data| matches!(data, Data::Bool(_))) {
+    return FieldType::Checkbox;
+  }
+  if samples
+    .iter()
+    .all(|data| matches!(data, Data::Float(_) | Data::Int(_)))
+  {
+    return FieldType::Number;
+  }
+  if samples.iter().all(|data| matches!(data, Data::DateTime(_))) {
+    return FieldType::DateTime;
+  }
+  FieldType::RichText
+}
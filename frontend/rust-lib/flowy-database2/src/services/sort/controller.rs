@@ -17,7 +17,9 @@ use lib_infra::priority_task::{QualityOfService, Task, TaskContent, TaskDispatch
 use crate::entities::SortChangesetNotificationPB;
 use crate::entities::{FieldType, SortWithIndexPB};
 use crate::services::cell::CellCache;
-use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
+use crate::services::database_view::{
+  DatabaseViewChanged, DatabaseViewChangedNotifier, FieldRowIndex,
+};
 use crate::services::field::{TypeOptionCellExt, default_order};
 use crate::services::sort::{
   ReorderAllRowsResult, ReorderSingleRowResult, Sort, SortChangeset, SortCondition,
@@ -28,6 +30,8 @@ pub trait SortDelegate: Send + Sync {
   async fn get_sort(&self, view_id: &str, sort_id: &str) -> Option<Arc<Sort>>;
   /// Returns all the rows after applying grid's filter
   async fn get_rows(&self, view_id: &str) -> Vec<Arc<Row>>;
+  /// Returns all the rows of the view, without applying grid's filter
+  async fn get_all_rows(&self, view_id: &str) -> Vec<Arc<Row>>;
   async fn filter_row(&self, row_detail: &Row) -> bool;
   async fn get_field(&self, field_id: &str) -> Option<Field>;
   async fn get_fields(&self, view_id: &str, field_ids: Option<Vec<String>>) -> Vec<Field>;
@@ -40,6 +44,7 @@ pub struct SortController {
   task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
   sorts: Vec<Arc<Sort>>,
   cell_cache: CellCache,
+  row_index: Arc<FieldRowIndex>,
   row_index_cache: HashMap<RowId, usize>,
   notifier: DatabaseViewChangedNotifier,
 }
@@ -58,6 +63,7 @@ impl SortController {
     delegate: T,
     task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
     cell_cache: CellCache,
+    row_index: Arc<FieldRowIndex>,
     notifier: DatabaseViewChangedNotifier,
   ) -> Self
   where
@@ -70,6 +76,7 @@ impl SortController {
       task_scheduler,
       sorts,
       cell_cache,
+      row_index,
       row_index_cache: Default::default(),
       notifier,
     }
@@ -204,14 +211,46 @@ impl SortController {
 
   pub async fn sort_rows(&mut self, rows: &mut [Arc<Row>]) {
     let fields = self.delegate.get_fields(&self.view_id, None).await;
-    for sort in self.sorts.iter().rev() {
-      rows.par_sort_by(|left, right| cmp_row(left, right, sort, &fields, &self.cell_cache));
+    if !self.sort_rows_with_index(rows, &fields).await {
+      for sort in self.sorts.iter().rev() {
+        rows.par_sort_by(|left, right| cmp_row(left, right, sort, &fields, &self.cell_cache));
+      }
     }
     rows.iter().enumerate().for_each(|(index, row)| {
       self.row_index_cache.insert(row.id.clone(), index);
     });
   }
 
+  /// Sorts the rows using the per-field row index instead of comparing the
+  /// decoded cells. Only a single sort on an indexed field can be answered
+  /// this way; returns `false` when the caller should fall back to the
+  /// comparison sort.
+  async fn sort_rows_with_index(&self, rows: &mut [Arc<Row>], fields: &[Field]) -> bool {
+    let sort = match self.sorts.as_slice() {
+      [sort] => sort,
+      _ => return false,
+    };
+    let field = match fields.iter().find(|field| field.id == sort.field_id) {
+      Some(field) if FieldRowIndex::supports(field) => field,
+      _ => return false,
+    };
+    if !self.row_index.is_built(&field.id) {
+      // `rows` may be a filtered subset, so the index is built from the full
+      // row set of the view instead.
+      let all_rows = self.delegate.get_all_rows(&self.view_id).await;
+      self.row_index.build(field, &all_rows);
+    }
+    let descending = matches!(sort.condition, SortCondition::Descending);
+    let rank_by_row_id = match self.row_index.row_ranks(&field.id, descending) {
+      Some(ranks) => ranks,
+      None => return false,
+    };
+    // Rows sharing a value share a rank, so the stable sort keeps ties in
+    // their incoming order, like the comparison sort it replaces.
+    rows.sort_by_key(|row| rank_by_row_id.get(&row.id).copied().unwrap_or(usize::MAX));
+    true
+  }
+
   pub async fn delete_all_sorts(&mut self) {
     self.sorts.clear();
     self
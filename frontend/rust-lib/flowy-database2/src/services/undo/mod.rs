@@ -0,0 +1,67 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use collab_database::rows::{Cell, RowId};
+
+/// Maximum number of cell edits kept on either stack.
+const MAX_UNDO_DEPTH: usize = 100;
+
+/// A single cell edit of the current user, with enough context to invert it.
+#[derive(Debug, Clone)]
+pub struct CellEditRecord {
+  pub row_id: RowId,
+  pub field_id: String,
+  /// The cell before the edit. `None` when the edit created the cell.
+  pub before: Option<Cell>,
+  /// The cell after the edit, used to detect that someone else changed the
+  /// cell in the meantime.
+  pub after: Option<Cell>,
+}
+
+/// Per-database undo/redo stacks of the current user's cell edits. The
+/// stacks live in the editor, so they only ever contain edits made on this
+/// device; collaborators' changes never end up on them.
+#[derive(Default)]
+pub struct CellUndoStack {
+  undo: Mutex<VecDeque<CellEditRecord>>,
+  redo: Mutex<VecDeque<CellEditRecord>>,
+}
+
+impl CellUndoStack {
+  /// Records a fresh edit. A fresh edit invalidates the redo stack.
+  pub fn push(&self, record: CellEditRecord) {
+    let mut undo = self.undo.lock().unwrap();
+    undo.push_back(record);
+    if undo.len() > MAX_UNDO_DEPTH {
+      undo.pop_front();
+    }
+    self.redo.lock().unwrap().clear();
+  }
+
+  pub fn pop_undo(&self) -> Option<CellEditRecord> {
+    self.undo.lock().unwrap().pop_back()
+  }
+
+  pub fn pop_redo(&self) -> Option<CellEditRecord> {
+    self.redo.lock().unwrap().pop_back()
+  }
+
+  /// Moves an undone edit onto the redo stack.
+  pub fn push_redo(&self, record: CellEditRecord) {
+    let mut redo = self.redo.lock().unwrap();
+    redo.push_back(record);
+    if redo.len() > MAX_UNDO_DEPTH {
+      redo.pop_front();
+    }
+  }
+
+  /// Moves a redone edit back onto the undo stack, without invalidating the
+  /// remaining redo entries.
+  pub fn push_undo(&self, record: CellEditRecord) {
+    let mut undo = self.undo.lock().unwrap();
+    undo.push_back(record);
+    if undo.len() > MAX_UNDO_DEPTH {
+      undo.pop_front();
+    }
+  }
+}
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+use lib_infra::util::timestamp;
+use tracing::trace;
+use url::Url;
+
+use crate::services::url_preview::UrlPreviewTable;
+
+/// How long a preview fetch may take before it is abandoned.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How much of the page body is inspected. The tags the preview is built
+/// from all live in the head of the document.
+const MAX_BODY_BYTES: usize = 512 * 1024;
+
+lazy_static! {
+  static ref TITLE_REGEX: Regex = Regex::new(r"(?is)<title[^>]*>(.*?)</title>").unwrap();
+  static ref OG_IMAGE_REGEX: Regex = Regex::new(
+    r#"(?is)<meta[^>]*property\s*=\s*["']og:image["'][^>]*content\s*=\s*["']([^"']+)["']"#
+  )
+  .unwrap();
+  static ref OG_IMAGE_REVERSED_REGEX: Regex = Regex::new(
+    r#"(?is)<meta[^>]*content\s*=\s*["']([^"']+)["'][^>]*property\s*=\s*["']og:image["']"#
+  )
+  .unwrap();
+  static ref FAVICON_REGEX: Regex = Regex::new(
+    r#"(?is)<link[^>]*rel\s*=\s*["'][^"']*icon[^"']*["'][^>]*href\s*=\s*["']([^"']+)["']"#
+  )
+  .unwrap();
+  static ref FAVICON_REVERSED_REGEX: Regex = Regex::new(
+    r#"(?is)<link[^>]*href\s*=\s*["']([^"']+)["'][^>]*rel\s*=\s*["'][^"']*icon[^"']*["']"#
+  )
+  .unwrap();
+}
+
+/// Fetches the link preview of a URL: the page title, the favicon and the
+/// og:image. Returns `None` when the page couldn't be reached (offline, DNS
+/// failure, timeout) so the URL is retried on a later read instead of caching
+/// an empty preview. A page that is reached but yields nothing still returns
+/// a preview with empty fields, which gets cached so the page isn't fetched
+/// on every read.
+pub async fn fetch_url_preview(url: &str) -> Option<UrlPreviewTable> {
+  let mut preview = UrlPreviewTable {
+    url: url.to_string(),
+    title: String::new(),
+    favicon_url: String::new(),
+    image_url: String::new(),
+    fetched_at: timestamp(),
+  };
+
+  let parsed = match Url::parse(url) {
+    Ok(parsed) if parsed.scheme() == "http" || parsed.scheme() == "https" => parsed,
+    // Not something that can be fetched; cache the empty preview.
+    _ => return Some(preview),
+  };
+
+  let client = reqwest::Client::builder()
+    .timeout(FETCH_TIMEOUT)
+    .build()
+    .ok()?;
+  let response = match client.get(parsed.clone()).send().await {
+    Ok(response) => response,
+    Err(err) => {
+      trace!("[UrlPreview]: skip {}: {}", url, err);
+      return None;
+    },
+  };
+  let bytes = match response.bytes().await {
+    Ok(bytes) => bytes,
+    Err(err) => {
+      trace!("[UrlPreview]: skip {}: {}", url, err);
+      return None;
+    },
+  };
+  let body = String::from_utf8_lossy(&bytes[..bytes.len().min(MAX_BODY_BYTES)]);
+
+  if let Some(title) = capture(&TITLE_REGEX, &body) {
+    preview.title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+  }
+  if let Some(image) =
+    capture(&OG_IMAGE_REGEX, &body).or_else(|| capture(&OG_IMAGE_REVERSED_REGEX, &body))
+  {
+    preview.image_url = resolve_href(&parsed, &image);
+  }
+  preview.favicon_url = capture(&FAVICON_REGEX, &body)
+    .or_else(|| capture(&FAVICON_REVERSED_REGEX, &body))
+    .map(|href| resolve_href(&parsed, &href))
+    // Most sites serve a favicon at the well-known path even without
+    // declaring it.
+    .unwrap_or_else(|| resolve_href(&parsed, "/favicon.ico"));
+
+  Some(preview)
+}
+
+fn capture(regex: &Regex, body: &str) -> Option<String> {
+  let captures = regex.captures(body).ok()??;
+  Some(captures.get(1)?.as_str().trim().to_string())
+}
+
+/// Resolves a possibly relative href against the page URL.
+fn resolve_href(base: &Url, href: &str) -> String {
+  base
+    .join(href)
+    .map(String::from)
+    .unwrap_or_else(|_| href.to_string())
+}
@@ -0,0 +1,5 @@
+mod fetcher;
+mod url_preview_sql;
+
+pub use fetcher::*;
+pub use url_preview_sql::*;
@@ -0,0 +1,39 @@
+use flowy_error::FlowyResult;
+use flowy_sqlite::DBConnection;
+use flowy_sqlite::schema::url_preview_table;
+use flowy_sqlite::schema::url_preview_table::dsl;
+use flowy_sqlite::{ExpressionMethods, prelude::*};
+
+/// Cached link preview of a URL: the page title, the favicon and the
+/// og:image. Empty fields mean the value could not be extracted from the
+/// page. See [crate::services::url_preview::fetch_url_preview].
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = url_preview_table)]
+#[diesel(primary_key(url))]
+pub struct UrlPreviewTable {
+  pub url: String,
+  pub title: String,
+  pub favicon_url: String,
+  pub image_url: String,
+  pub fetched_at: i64,
+}
+
+pub fn upsert_url_preview(conn: &mut DBConnection, preview: &UrlPreviewTable) -> FlowyResult<()> {
+  diesel::replace_into(dsl::url_preview_table)
+    .values(preview.clone())
+    .execute(conn)?;
+  Ok(())
+}
+
+/// Selects the cached preview of a URL. `None` means the URL hasn't been
+/// fetched yet.
+pub fn select_url_preview(
+  conn: &mut DBConnection,
+  url: &str,
+) -> FlowyResult<Option<UrlPreviewTable>> {
+  let preview = dsl::url_preview_table
+    .filter(url_preview_table::url.eq(url))
+    .first::<UrlPreviewTable>(conn)
+    .optional()?;
+  Ok(preview)
+}
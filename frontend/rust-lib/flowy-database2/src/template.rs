@@ -7,11 +7,64 @@ use collab_database::rows::CreateRowParams;
 use collab_database::views::{DatabaseLayout, LayoutSettings};
 
 use crate::entities::FieldType;
-use crate::services::cell::{insert_select_option_cell, insert_text_cell};
+use crate::services::cell::{
+  insert_checkbox_cell, insert_date_cell, insert_select_option_cell, insert_text_cell,
+};
 use crate::services::field::FieldBuilder;
 use crate::services::field_settings::default_field_settings_for_fields;
 use crate::services::setting::{BoardLayoutSetting, CalendarLayoutSetting};
 
+pub const TASK_TRACKER_TEMPLATE_ID: &str = "task_tracker";
+pub const CRM_TEMPLATE_ID: &str = "crm";
+pub const CONTENT_CALENDAR_TEMPLATE_ID: &str = "content_calendar";
+
+/// A built-in database template: a full schema plus a handful of sample rows,
+/// embedded in the binary so it works offline.
+pub struct DatabaseTemplate {
+  pub id: &'static str,
+  pub name: &'static str,
+  pub description: &'static str,
+  pub layout: DatabaseLayout,
+}
+
+pub fn list_database_templates() -> Vec<DatabaseTemplate> {
+  vec![
+    DatabaseTemplate {
+      id: TASK_TRACKER_TEMPLATE_ID,
+      name: "Task Tracker",
+      description: "Track tasks with status, priority and due dates",
+      layout: DatabaseLayout::Grid,
+    },
+    DatabaseTemplate {
+      id: CRM_TEMPLATE_ID,
+      name: "CRM",
+      description: "Follow up on contacts through your sales pipeline",
+      layout: DatabaseLayout::Board,
+    },
+    DatabaseTemplate {
+      id: CONTENT_CALENDAR_TEMPLATE_ID,
+      name: "Content Calendar",
+      description: "Plan content by publish date and channel",
+      layout: DatabaseLayout::Calendar,
+    },
+  ]
+}
+
+/// Builds the [CreateDatabaseParams] for one of the built-in templates, or
+/// `None` when the template id is unknown.
+pub fn make_database_from_template(
+  template_id: &str,
+  view_id: &str,
+  name: &str,
+) -> Option<CreateDatabaseParams> {
+  match template_id {
+    TASK_TRACKER_TEMPLATE_ID => Some(make_task_tracker_database(view_id, name)),
+    CRM_TEMPLATE_ID => Some(make_crm_database(view_id, name)),
+    CONTENT_CALENDAR_TEMPLATE_ID => Some(make_content_calendar_database(view_id, name)),
+    _ => None,
+  }
+}
+
 pub fn make_default_grid(view_id: &str, name: &str) -> CreateDatabaseParams {
   let database_id = gen_database_id();
   let timestamp = timestamp();
@@ -175,3 +228,317 @@ pub fn make_default_calendar(view_id: &str, name: &str) -> CreateDatabaseParams
     fields,
   }
 }
+
+fn make_task_tracker_database(view_id: &str, name: &str) -> CreateDatabaseParams {
+  let database_id = gen_database_id();
+  let timestamp = timestamp();
+
+  let task_field = FieldBuilder::from_field_type(FieldType::RichText)
+    .name("Task")
+    .primary(true)
+    .build();
+  let task_field_id = task_field.id.clone();
+
+  let to_do_option = SelectOption::with_color("To Do", SelectOptionColor::Purple);
+  let in_progress_option = SelectOption::with_color("In Progress", SelectOptionColor::Orange);
+  let done_option = SelectOption::with_color("Done", SelectOptionColor::Green);
+  let mut status_type_option = SingleSelectTypeOption::default();
+  status_type_option.options.extend(vec![
+    to_do_option.clone(),
+    in_progress_option.clone(),
+    done_option.clone(),
+  ]);
+  let status_field = FieldBuilder::new(FieldType::SingleSelect, status_type_option)
+    .name("Status")
+    .build();
+  let status_field_id = status_field.id.clone();
+
+  let low_option = SelectOption::with_color("Low", SelectOptionColor::Aqua);
+  let medium_option = SelectOption::with_color("Medium", SelectOptionColor::Yellow);
+  let high_option = SelectOption::with_color("High", SelectOptionColor::Pink);
+  let mut priority_type_option = SingleSelectTypeOption::default();
+  priority_type_option.options.extend(vec![
+    low_option.clone(),
+    medium_option.clone(),
+    high_option.clone(),
+  ]);
+  let priority_field = FieldBuilder::new(FieldType::SingleSelect, priority_type_option)
+    .name("Priority")
+    .build();
+  let priority_field_id = priority_field.id.clone();
+
+  let due_date_field = FieldBuilder::from_field_type(FieldType::DateTime)
+    .name("Due Date")
+    .build();
+  let due_date_field_id = due_date_field.id.clone();
+
+  let done_field = FieldBuilder::from_field_type(FieldType::Checkbox)
+    .name("Done")
+    .build();
+  let done_field_id = done_field.id.clone();
+
+  const DAY: i64 = 24 * 60 * 60;
+  let samples = [
+    ("Plan the project", &to_do_option, &high_option, 1, false),
+    ("Draft the first milestone", &in_progress_option, &medium_option, 3, false),
+    ("Set up the workspace", &done_option, &low_option, -1, true),
+  ];
+
+  let mut rows = vec![];
+  for (task, status, priority, due_in_days, is_done) in samples {
+    let mut row = CreateRowParams::new(gen_row_id(), database_id.clone());
+    row
+      .cells
+      .insert(task_field_id.clone(), insert_text_cell(task.to_string(), &task_field));
+    row.cells.insert(
+      status_field_id.clone(),
+      insert_select_option_cell(vec![status.id.clone()], &status_field),
+    );
+    row.cells.insert(
+      priority_field_id.clone(),
+      insert_select_option_cell(vec![priority.id.clone()], &priority_field),
+    );
+    row.cells.insert(
+      due_date_field_id.clone(),
+      insert_date_cell(timestamp + due_in_days * DAY, None, Some(false), &due_date_field),
+    );
+    row
+      .cells
+      .insert(done_field_id.clone(), insert_checkbox_cell(is_done, &done_field));
+    rows.push(row);
+  }
+
+  let fields = vec![
+    task_field,
+    status_field,
+    priority_field,
+    due_date_field,
+    done_field,
+  ];
+  let field_settings = default_field_settings_for_fields(&fields, DatabaseLayout::Grid);
+
+  CreateDatabaseParams {
+    database_id: database_id.clone(),
+    views: vec![CreateViewParams {
+      database_id,
+      view_id: view_id.to_string(),
+      name: name.to_string(),
+      layout: DatabaseLayout::Grid,
+      layout_settings: Default::default(),
+      filters: vec![],
+      group_settings: vec![],
+      sorts: vec![],
+      field_settings,
+      created_at: timestamp,
+      modified_at: timestamp,
+      ..Default::default()
+    }],
+    rows,
+    fields,
+  }
+}
+
+fn make_crm_database(view_id: &str, name: &str) -> CreateDatabaseParams {
+  let database_id = gen_database_id();
+  let timestamp = timestamp();
+
+  let contact_field = FieldBuilder::from_field_type(FieldType::RichText)
+    .name("Contact")
+    .primary(true)
+    .build();
+  let contact_field_id = contact_field.id.clone();
+
+  let company_field = FieldBuilder::from_field_type(FieldType::RichText)
+    .name("Company")
+    .build();
+  let company_field_id = company_field.id.clone();
+
+  let lead_option = SelectOption::with_color("Lead", SelectOptionColor::Purple);
+  let contacted_option = SelectOption::with_color("Contacted", SelectOptionColor::Blue);
+  let negotiation_option = SelectOption::with_color("Negotiation", SelectOptionColor::Orange);
+  let won_option = SelectOption::with_color("Won", SelectOptionColor::Green);
+  let lost_option = SelectOption::with_color("Lost", SelectOptionColor::LightPink);
+  let mut stage_type_option = SingleSelectTypeOption::default();
+  stage_type_option.options.extend(vec![
+    lead_option.clone(),
+    contacted_option.clone(),
+    negotiation_option.clone(),
+    won_option,
+    lost_option,
+  ]);
+  let stage_field = FieldBuilder::new(FieldType::SingleSelect, stage_type_option)
+    .name("Stage")
+    .build();
+  let stage_field_id = stage_field.id.clone();
+
+  let email_field = FieldBuilder::from_field_type(FieldType::URL)
+    .name("Email")
+    .build();
+
+  let notes_field = FieldBuilder::from_field_type(FieldType::RichText)
+    .name("Notes")
+    .build();
+
+  let samples = [
+    ("Alex Chen", "Acme Inc", &lead_option),
+    ("Robin Patel", "Globex", &contacted_option),
+    ("Sam Torres", "Initech", &negotiation_option),
+  ];
+
+  let mut rows = vec![];
+  for (contact, company, stage) in samples {
+    let mut row = CreateRowParams::new(gen_row_id(), database_id.clone());
+    row.cells.insert(
+      contact_field_id.clone(),
+      insert_text_cell(contact.to_string(), &contact_field),
+    );
+    row.cells.insert(
+      company_field_id.clone(),
+      insert_text_cell(company.to_string(), &company_field),
+    );
+    row.cells.insert(
+      stage_field_id.clone(),
+      insert_select_option_cell(vec![stage.id.clone()], &stage_field),
+    );
+    rows.push(row);
+  }
+
+  let fields = vec![
+    contact_field,
+    company_field,
+    stage_field,
+    email_field,
+    notes_field,
+  ];
+  let field_settings = default_field_settings_for_fields(&fields, DatabaseLayout::Board);
+
+  let mut layout_settings = LayoutSettings::default();
+  layout_settings.insert(DatabaseLayout::Board, BoardLayoutSetting::new().into());
+
+  CreateDatabaseParams {
+    database_id: database_id.clone(),
+    views: vec![CreateViewParams {
+      database_id,
+      view_id: view_id.to_string(),
+      name: name.to_string(),
+      layout: DatabaseLayout::Board,
+      layout_settings,
+      filters: vec![],
+      group_settings: vec![],
+      sorts: vec![],
+      field_settings,
+      created_at: timestamp,
+      modified_at: timestamp,
+      ..Default::default()
+    }],
+    rows,
+    fields,
+  }
+}
+
+fn make_content_calendar_database(view_id: &str, name: &str) -> CreateDatabaseParams {
+  let database_id = gen_database_id();
+  let timestamp = timestamp();
+
+  let title_field = FieldBuilder::from_field_type(FieldType::RichText)
+    .name("Title")
+    .primary(true)
+    .build();
+  let title_field_id = title_field.id.clone();
+
+  let publish_date_field = FieldBuilder::from_field_type(FieldType::DateTime)
+    .name("Publish Date")
+    .build();
+  let publish_date_field_id = publish_date_field.id.clone();
+
+  let blog_option = SelectOption::with_color("Blog", SelectOptionColor::Purple);
+  let newsletter_option = SelectOption::with_color("Newsletter", SelectOptionColor::Orange);
+  let social_option = SelectOption::with_color("Social", SelectOptionColor::Blue);
+  let mut channel_type_option = SingleSelectTypeOption::default();
+  channel_type_option.options.extend(vec![
+    blog_option.clone(),
+    newsletter_option.clone(),
+    social_option.clone(),
+  ]);
+  let channel_field = FieldBuilder::new(FieldType::SingleSelect, channel_type_option)
+    .name("Channel")
+    .build();
+  let channel_field_id = channel_field.id.clone();
+
+  let draft_option = SelectOption::with_color("Draft", SelectOptionColor::Yellow);
+  let review_option = SelectOption::with_color("In Review", SelectOptionColor::Orange);
+  let published_option = SelectOption::with_color("Published", SelectOptionColor::Green);
+  let mut status_type_option = SingleSelectTypeOption::default();
+  status_type_option.options.extend(vec![
+    draft_option.clone(),
+    review_option.clone(),
+    published_option,
+  ]);
+  let status_field = FieldBuilder::new(FieldType::SingleSelect, status_type_option)
+    .name("Status")
+    .build();
+  let status_field_id = status_field.id.clone();
+
+  const DAY: i64 = 24 * 60 * 60;
+  let samples = [
+    ("Welcome post", &blog_option, &review_option, 1),
+    ("Monthly newsletter", &newsletter_option, &draft_option, 7),
+    ("Launch announcement", &social_option, &draft_option, 14),
+  ];
+
+  let mut rows = vec![];
+  for (title, channel, status, publish_in_days) in samples {
+    let mut row = CreateRowParams::new(gen_row_id(), database_id.clone());
+    row.cells.insert(
+      title_field_id.clone(),
+      insert_text_cell(title.to_string(), &title_field),
+    );
+    row.cells.insert(
+      publish_date_field_id.clone(),
+      insert_date_cell(
+        timestamp + publish_in_days * DAY,
+        None,
+        Some(false),
+        &publish_date_field,
+      ),
+    );
+    row.cells.insert(
+      channel_field_id.clone(),
+      insert_select_option_cell(vec![channel.id.clone()], &channel_field),
+    );
+    row.cells.insert(
+      status_field_id.clone(),
+      insert_select_option_cell(vec![status.id.clone()], &status_field),
+    );
+    rows.push(row);
+  }
+
+  let fields = vec![title_field, publish_date_field, channel_field, status_field];
+  let field_settings = default_field_settings_for_fields(&fields, DatabaseLayout::Calendar);
+
+  let mut layout_settings = LayoutSettings::default();
+  layout_settings.insert(
+    DatabaseLayout::Calendar,
+    CalendarLayoutSetting::new(publish_date_field_id).into(),
+  );
+
+  CreateDatabaseParams {
+    database_id: database_id.clone(),
+    views: vec![CreateViewParams {
+      database_id,
+      view_id: view_id.to_string(),
+      name: name.to_string(),
+      layout: DatabaseLayout::Calendar,
+      layout_settings,
+      filters: vec![],
+      group_settings: vec![],
+      sorts: vec![],
+      field_settings,
+      created_at: timestamp,
+      modified_at: timestamp,
+      ..Default::default()
+    }],
+    rows,
+    fields,
+  }
+}
@@ -85,6 +85,55 @@ async fn calculations_test() {
     .await;
 
   test.assert_calculation_float_value(expected_median).await;
+
+  // Insert Range calculation and assert its value
+  test
+    .insert_calculation(UpdateCalculationChangesetPB {
+      view_id: view_id.to_owned(),
+      field_id: field_id.to_owned(),
+      calculation_id: Some(calculation_id.clone()),
+      calculation_type: CalculationType::Range,
+    })
+    .await;
+
+  test
+    .assert_calculation_float_value(expected_max - expected_min)
+    .await;
+}
+
+#[tokio::test]
+async fn calculations_percent_checked_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+
+  let view_id = &test.view_id();
+  let checkbox_fields = test
+    .fields
+    .clone()
+    .into_iter()
+    .filter(|field| field.field_type == FieldType::Checkbox as i64)
+    .collect::<Vec<Arc<Field>>>();
+  let field_id = &checkbox_fields.first().unwrap().id;
+  let calculation_id = "calc_id".to_owned();
+
+  test
+    .insert_calculation(UpdateCalculationChangesetPB {
+      view_id: view_id.to_owned(),
+      field_id: field_id.to_owned(),
+      calculation_id: Some(calculation_id.clone()),
+      calculation_type: CalculationType::PercentChecked,
+    })
+    .await;
+  test.assert_calculation_value("50.0%").await;
+
+  test
+    .insert_calculation(UpdateCalculationChangesetPB {
+      view_id: view_id.to_owned(),
+      field_id: field_id.to_owned(),
+      calculation_id: Some(calculation_id.clone()),
+      calculation_type: CalculationType::PercentUnchecked,
+    })
+    .await;
+  test.assert_calculation_value("50.0%").await;
 }
 
 #[tokio::test]
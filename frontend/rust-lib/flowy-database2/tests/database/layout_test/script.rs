@@ -59,7 +59,7 @@ impl DatabaseLayoutTest {
     let events = self
       .database_test
       .editor
-      .get_all_calendar_events(&self.database_test.view_id)
+      .get_all_calendar_events(&self.database_test.view_id, None)
       .await;
     assert_eq!(events.len(), expected);
   }
@@ -100,6 +100,8 @@ impl DatabaseLayoutTest {
       layout_type: DatabaseLayout::Board,
       board: Some(new_setting),
       calendar: None,
+      timeline: None,
+      form: None,
     };
     self
       .database_test
@@ -113,7 +115,7 @@ impl DatabaseLayoutTest {
     let events = self
       .database_test
       .editor
-      .get_all_calendar_events(&self.database_test.view_id)
+      .get_all_calendar_events(&self.database_test.view_id, None)
       .await;
     assert_eq!(events.len(), 5);
 
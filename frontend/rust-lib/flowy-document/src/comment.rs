@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use flowy_error::FlowyResult;
+
+/// Where a comment thread is attached: a text range inside a block. The
+/// commented text is kept as a snippet so the anchor can be re-resolved
+/// after the surrounding text was edited.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentAnchor {
+  pub block_id: String,
+  /// Start of the range, in characters.
+  pub start: usize,
+  /// End of the range (exclusive), in characters.
+  pub end: usize,
+  /// The text the thread was anchored to when it was created.
+  pub snippet: String,
+}
+
+/// A single comment inside a thread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+  pub comment_id: String,
+  pub author_id: i64,
+  pub content: String,
+  pub created_at: i64,
+  pub updated_at: i64,
+}
+
+/// A threaded conversation anchored to a text range of a document.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentThread {
+  pub thread_id: String,
+  pub anchor: CommentAnchor,
+  pub resolved: bool,
+  pub comments: Vec<Comment>,
+}
+
+/// Persistence for comment threads, implemented by the integration layer,
+/// e.g. on top of the local key-value store or a cloud endpoint. Threads are
+/// stored per document.
+pub trait DocumentCommentStore: Send + Sync {
+  fn load_threads(&self, document_id: &str) -> FlowyResult<Vec<CommentThread>>;
+  fn save_threads(&self, document_id: &str, threads: &[CommentThread]) -> FlowyResult<()>;
+}
+
+/// Re-resolves the anchor against the block's current text. When the stored
+/// range no longer matches the snippet, the occurrence of the snippet closest
+/// to the stored position is used, so the thread follows its text through
+/// edits. An anchor whose snippet disappeared is left untouched, only clamped
+/// to the text length.
+pub(crate) fn resolve_anchor(anchor: &mut CommentAnchor, block_text: &str) {
+  let chars: Vec<char> = block_text.chars().collect();
+  let text_len = chars.len();
+  let current: String = chars
+    .get(anchor.start..anchor.end.min(text_len))
+    .map(|slice| slice.iter().collect())
+    .unwrap_or_default();
+  if current == anchor.snippet {
+    return;
+  }
+
+  let snippet_chars: Vec<char> = anchor.snippet.chars().collect();
+  let mut best: Option<usize> = None;
+  if !snippet_chars.is_empty() && snippet_chars.len() <= text_len {
+    for start in 0..=(text_len - snippet_chars.len()) {
+      if chars[start..start + snippet_chars.len()] == snippet_chars[..] {
+        let better = match best {
+          None => true,
+          Some(best_start) => start.abs_diff(anchor.start) < best_start.abs_diff(anchor.start),
+        };
+        if better {
+          best = Some(start);
+        }
+      }
+    }
+  }
+
+  match best {
+    Some(start) => {
+      anchor.start = start;
+      anchor.end = start + snippet_chars.len();
+    },
+    None => {
+      anchor.start = anchor.start.min(text_len);
+      anchor.end = anchor.end.min(text_len);
+    },
+  }
+}
@@ -14,7 +14,11 @@ use std::str::FromStr;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::comment::{Comment, CommentThread};
+use crate::equation::LatexParseError;
+use crate::mention::{MentionType, Mentionable};
 use crate::parse::{NotEmptyStr, NotEmptyVec};
+use crate::template::DocumentTemplate;
 
 #[derive(Default, ProtoBuf)]
 pub struct EncodedCollabPB {
@@ -557,6 +561,829 @@ pub struct DocumentSnapshotData {
   pub encoded_v1: Vec<u8>,
 }
 
+/// One automatically taken local version of a document.
+pub struct DocumentVersionMeta {
+  pub version_id: String,
+  pub created_at: i64,
+  pub summary: String,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedDocumentVersionPB {
+  #[pb(index = 1)]
+  pub items: Vec<DocumentVersionPB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentVersionPB {
+  #[pb(index = 1)]
+  pub version_id: String,
+
+  #[pb(index = 2)]
+  pub created_at: i64,
+
+  /// A short summary of how the text changed since the previous version,
+  /// e.g. "+12 words, +64 characters".
+  #[pb(index = 3)]
+  pub summary: String,
+}
+
+impl From<DocumentVersionMeta> for DocumentVersionPB {
+  fn from(meta: DocumentVersionMeta) -> Self {
+    Self {
+      version_id: meta.version_id,
+      created_at: meta.created_at,
+      summary: meta.summary,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RestoreDocumentVersionPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub version_id: String,
+}
+
+pub struct RestoreDocumentVersionParams {
+  pub document_id: Uuid,
+  pub version_id: String,
+}
+
+impl TryInto<RestoreDocumentVersionParams> for RestoreDocumentVersionPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<RestoreDocumentVersionParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let version_id = NotEmptyStr::parse(self.version_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(RestoreDocumentVersionParams {
+      document_id,
+      version_id: version_id.0,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentStatisticsPB {
+  #[pb(index = 1)]
+  pub word_count: i64,
+
+  #[pb(index = 2)]
+  pub character_count: i64,
+
+  /// Number of blocks per block type, e.g. "paragraph" or "todo_list".
+  #[pb(index = 3)]
+  pub block_counts: Vec<BlockTypeCountPB>,
+
+  /// Estimated reading time in minutes, at least 1 for non-empty documents.
+  #[pb(index = 4)]
+  pub reading_time_minutes: i64,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct BlockTypeCountPB {
+  #[pb(index = 1)]
+  pub ty: String,
+
+  #[pb(index = 2)]
+  pub count: i64,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentOutlinePB {
+  #[pb(index = 1)]
+  pub items: Vec<DocumentOutlineItemPB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentOutlineItemPB {
+  #[pb(index = 1)]
+  pub block_id: String,
+
+  #[pb(index = 2)]
+  pub text: String,
+
+  /// Heading level, starting at 1.
+  #[pb(index = 3)]
+  pub level: i64,
+}
+
+/// Sent with [DocumentNotification::DidUpdateDocumentOutline] when editing
+/// changes the heading hierarchy of a document whose outline was requested.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentOutlineDiffPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub inserted: Vec<DocumentOutlineItemPB>,
+
+  #[pb(index = 3)]
+  pub updated: Vec<DocumentOutlineItemPB>,
+
+  #[pb(index = 4)]
+  pub removed_block_ids: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct SearchMentionablePayloadPB {
+  #[pb(index = 1)]
+  pub query: String,
+}
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone, Default)]
+pub enum MentionTypePB {
+  #[default]
+  UserMention = 0,
+  PageMention = 1,
+  DateMention = 2,
+}
+
+impl From<MentionType> for MentionTypePB {
+  fn from(ty: MentionType) -> Self {
+    match ty {
+      MentionType::User => MentionTypePB::UserMention,
+      MentionType::Page => MentionTypePB::PageMention,
+      MentionType::Date => MentionTypePB::DateMention,
+    }
+  }
+}
+
+impl From<MentionTypePB> for MentionType {
+  fn from(ty: MentionTypePB) -> Self {
+    match ty {
+      MentionTypePB::UserMention => MentionType::User,
+      MentionTypePB::PageMention => MentionType::Page,
+      MentionTypePB::DateMention => MentionType::Date,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct MentionablePB {
+  /// Identifier of the mentioned entity: user email, view id or similar.
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub ty: MentionTypePB,
+
+  #[pb(index = 4, one_of)]
+  pub icon: Option<String>,
+}
+
+impl From<Mentionable> for MentionablePB {
+  fn from(mentionable: Mentionable) -> Self {
+    Self {
+      id: mentionable.id,
+      name: mentionable.name,
+      ty: mentionable.ty.into(),
+      icon: mentionable.icon,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedMentionablePB {
+  #[pb(index = 1)]
+  pub items: Vec<MentionablePB>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateMentionPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub block_id: String,
+
+  #[pb(index = 3)]
+  pub ty: MentionTypePB,
+
+  /// What is mentioned: user email, view id or date string, depending on `ty`.
+  #[pb(index = 4)]
+  pub value: String,
+}
+
+pub struct CreateMentionParams {
+  pub document_id: Uuid,
+  pub block_id: String,
+  pub ty: MentionType,
+  pub value: String,
+}
+
+impl TryInto<CreateMentionParams> for CreateMentionPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<CreateMentionParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let block_id = NotEmptyStr::parse(self.block_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let value = NotEmptyStr::parse(self.value).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(CreateMentionParams {
+      document_id,
+      block_id: block_id.0,
+      ty: self.ty.into(),
+      value: value.0,
+    })
+  }
+}
+
+/// The inline data of a created mention. The `mention_id` is stable, so the
+/// same mention keeps its identity across edits and copies of the document.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct MentionPB {
+  #[pb(index = 1)]
+  pub mention_id: String,
+
+  #[pb(index = 2)]
+  pub ty: MentionTypePB,
+
+  #[pb(index = 3)]
+  pub value: String,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateCommentThreadPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub block_id: String,
+
+  /// Start of the commented range, in characters.
+  #[pb(index = 3)]
+  pub start: i64,
+
+  /// End of the commented range (exclusive), in characters.
+  #[pb(index = 4)]
+  pub end: i64,
+
+  /// Content of the first comment of the thread.
+  #[pb(index = 5)]
+  pub content: String,
+}
+
+pub struct CreateCommentThreadParams {
+  pub document_id: Uuid,
+  pub block_id: String,
+  pub start: usize,
+  pub end: usize,
+  pub content: String,
+}
+
+impl TryInto<CreateCommentThreadParams> for CreateCommentThreadPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<CreateCommentThreadParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let block_id = NotEmptyStr::parse(self.block_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::InvalidParams)?;
+    if self.start < 0 || self.end <= self.start {
+      return Err(ErrorCode::InvalidParams);
+    }
+    Ok(CreateCommentThreadParams {
+      document_id,
+      block_id: block_id.0,
+      start: self.start as usize,
+      end: self.end as usize,
+      content: content.0,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct AddCommentPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub thread_id: String,
+
+  #[pb(index = 3)]
+  pub content: String,
+}
+
+pub struct AddCommentParams {
+  pub document_id: Uuid,
+  pub thread_id: String,
+  pub content: String,
+}
+
+impl TryInto<AddCommentParams> for AddCommentPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<AddCommentParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let thread_id = NotEmptyStr::parse(self.thread_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(AddCommentParams {
+      document_id,
+      thread_id: thread_id.0,
+      content: content.0,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateCommentPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub thread_id: String,
+
+  #[pb(index = 3)]
+  pub comment_id: String,
+
+  #[pb(index = 4)]
+  pub content: String,
+}
+
+pub struct UpdateCommentParams {
+  pub document_id: Uuid,
+  pub thread_id: String,
+  pub comment_id: String,
+  pub content: String,
+}
+
+impl TryInto<UpdateCommentParams> for UpdateCommentPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<UpdateCommentParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let thread_id = NotEmptyStr::parse(self.thread_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let comment_id = NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(UpdateCommentParams {
+      document_id,
+      thread_id: thread_id.0,
+      comment_id: comment_id.0,
+      content: content.0,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct DeleteCommentPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub thread_id: String,
+
+  #[pb(index = 3)]
+  pub comment_id: String,
+}
+
+pub struct DeleteCommentParams {
+  pub document_id: Uuid,
+  pub thread_id: String,
+  pub comment_id: String,
+}
+
+impl TryInto<DeleteCommentParams> for DeleteCommentPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<DeleteCommentParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let thread_id = NotEmptyStr::parse(self.thread_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let comment_id = NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(DeleteCommentParams {
+      document_id,
+      thread_id: thread_id.0,
+      comment_id: comment_id.0,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct SetCommentThreadResolvedPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub thread_id: String,
+
+  #[pb(index = 3)]
+  pub resolved: bool,
+}
+
+pub struct SetCommentThreadResolvedParams {
+  pub document_id: Uuid,
+  pub thread_id: String,
+  pub resolved: bool,
+}
+
+impl TryInto<SetCommentThreadResolvedParams> for SetCommentThreadResolvedPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<SetCommentThreadResolvedParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let thread_id = NotEmptyStr::parse(self.thread_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(SetCommentThreadResolvedParams {
+      document_id,
+      thread_id: thread_id.0,
+      resolved: self.resolved,
+    })
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CommentPB {
+  #[pb(index = 1)]
+  pub comment_id: String,
+
+  #[pb(index = 2)]
+  pub author_id: i64,
+
+  #[pb(index = 3)]
+  pub content: String,
+
+  #[pb(index = 4)]
+  pub created_at: i64,
+
+  #[pb(index = 5)]
+  pub updated_at: i64,
+}
+
+impl From<Comment> for CommentPB {
+  fn from(comment: Comment) -> Self {
+    Self {
+      comment_id: comment.comment_id,
+      author_id: comment.author_id,
+      content: comment.content,
+      created_at: comment.created_at,
+      updated_at: comment.updated_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CommentThreadPB {
+  #[pb(index = 1)]
+  pub thread_id: String,
+
+  #[pb(index = 2)]
+  pub block_id: String,
+
+  #[pb(index = 3)]
+  pub start: i64,
+
+  #[pb(index = 4)]
+  pub end: i64,
+
+  /// The text the thread is anchored to.
+  #[pb(index = 5)]
+  pub snippet: String,
+
+  #[pb(index = 6)]
+  pub resolved: bool,
+
+  #[pb(index = 7)]
+  pub comments: Vec<CommentPB>,
+}
+
+impl From<CommentThread> for CommentThreadPB {
+  fn from(thread: CommentThread) -> Self {
+    Self {
+      thread_id: thread.thread_id,
+      block_id: thread.anchor.block_id,
+      start: thread.anchor.start as i64,
+      end: thread.anchor.end as i64,
+      snippet: thread.anchor.snippet,
+      resolved: thread.resolved,
+      comments: thread.comments.into_iter().map(Into::into).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedCommentThreadPB {
+  #[pb(index = 1)]
+  pub items: Vec<CommentThreadPB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct UnresolvedCommentCountPB {
+  #[pb(index = 1)]
+  pub count: i64,
+}
+
+/// Sent with [DocumentNotification::DidUpdateDocumentComments] whenever the
+/// comment threads of a document changed.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentCommentsChangedPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub unresolved_count: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct DiffDocumentVersionsPayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub from_version_id: String,
+
+  #[pb(index = 3)]
+  pub to_version_id: String,
+}
+
+pub struct DiffDocumentVersionsParams {
+  pub document_id: Uuid,
+  pub from_version_id: String,
+  pub to_version_id: String,
+}
+
+impl TryInto<DiffDocumentVersionsParams> for DiffDocumentVersionsPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<DiffDocumentVersionsParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let from_version_id =
+      NotEmptyStr::parse(self.from_version_id).map_err(|_| ErrorCode::InvalidParams)?;
+    let to_version_id =
+      NotEmptyStr::parse(self.to_version_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(DiffDocumentVersionsParams {
+      document_id,
+      from_version_id: from_version_id.0,
+      to_version_id: to_version_id.0,
+    })
+  }
+}
+
+/// Structured diff between two versions of a document, in document order.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentVersionDiffPB {
+  #[pb(index = 1)]
+  pub added: Vec<BlockDiffPB>,
+
+  #[pb(index = 2)]
+  pub removed: Vec<BlockDiffPB>,
+
+  #[pb(index = 3)]
+  pub modified: Vec<ModifiedBlockPB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct BlockDiffPB {
+  #[pb(index = 1)]
+  pub block_id: String,
+
+  #[pb(index = 2)]
+  pub ty: String,
+
+  #[pb(index = 3)]
+  pub text: String,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct ModifiedBlockPB {
+  #[pb(index = 1)]
+  pub block_id: String,
+
+  #[pb(index = 2)]
+  pub ty: String,
+
+  #[pb(index = 3, one_of)]
+  pub text_diff: Option<TextDiffPB>,
+
+  /// Whether the block's type or attributes changed, in addition to its text.
+  #[pb(index = 4)]
+  pub data_changed: bool,
+}
+
+/// A single text replacement: at `start` (in characters), `deleted` was
+/// replaced by `inserted`.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct TextDiffPB {
+  #[pb(index = 1)]
+  pub start: i64,
+
+  #[pb(index = 2)]
+  pub deleted: String,
+
+  #[pb(index = 3)]
+  pub inserted: String,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct SaveAsTemplatePayloadPB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub description: String,
+
+  #[pb(index = 4, one_of)]
+  pub icon: Option<String>,
+}
+
+pub struct SaveAsTemplateParams {
+  pub document_id: Uuid,
+  pub name: String,
+  pub description: String,
+  pub icon: Option<String>,
+}
+
+impl TryInto<SaveAsTemplateParams> for SaveAsTemplatePayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<SaveAsTemplateParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let name = NotEmptyStr::parse(self.name).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(SaveAsTemplateParams {
+      document_id,
+      name: name.0,
+      description: self.description,
+      icon: self.icon,
+    })
+  }
+}
+
+/// Metadata of a stored document template; the template's content stays in
+/// the store until the template is instantiated.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DocumentTemplatePB {
+  #[pb(index = 1)]
+  pub template_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub description: String,
+
+  #[pb(index = 4, one_of)]
+  pub icon: Option<String>,
+
+  #[pb(index = 5)]
+  pub created_at: i64,
+}
+
+impl From<DocumentTemplate> for DocumentTemplatePB {
+  fn from(template: DocumentTemplate) -> Self {
+    Self {
+      template_id: template.template_id,
+      name: template.name,
+      description: template.description,
+      icon: template.icon,
+      created_at: template.created_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedDocumentTemplatePB {
+  #[pb(index = 1)]
+  pub items: Vec<DocumentTemplatePB>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct DeleteDocumentTemplatePayloadPB {
+  #[pb(index = 1)]
+  pub template_id: String,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct CreateDocumentFromTemplatePayloadPB {
+  /// Id of the document to create.
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub template_id: String,
+
+  /// Substituted for `{{author}}`; the variable is left untouched when absent.
+  #[pb(index = 3, one_of)]
+  pub author_name: Option<String>,
+}
+
+pub struct CreateDocumentFromTemplateParams {
+  pub document_id: Uuid,
+  pub template_id: String,
+  pub author_name: Option<String>,
+}
+
+impl TryInto<CreateDocumentFromTemplateParams> for CreateDocumentFromTemplatePayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<CreateDocumentFromTemplateParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    let template_id = NotEmptyStr::parse(self.template_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(CreateDocumentFromTemplateParams {
+      document_id,
+      template_id: template_id.0,
+      author_name: self.author_name,
+    })
+  }
+}
+
+/// Result of instantiating a template: where the cursor should be placed,
+/// taken from the `{{cursor}}` variable.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct TemplateInstantiatedPB {
+  #[pb(index = 1, one_of)]
+  pub cursor_block_id: Option<String>,
+
+  /// Offset inside the cursor block's text, in characters.
+  #[pb(index = 2)]
+  pub cursor_offset: i64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ConvertHtmlToBlocksPayloadPB {
+  /// The document the content is pasted into; used to store downloaded
+  /// images.
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  #[pb(index = 2)]
+  pub html: String,
+}
+
+pub struct ConvertHtmlToBlocksParams {
+  pub document_id: Uuid,
+  pub html: String,
+}
+
+impl TryInto<ConvertHtmlToBlocksParams> for ConvertHtmlToBlocksPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<ConvertHtmlToBlocksParams, Self::Error> {
+    let document_id =
+      NotEmptyStr::parse(self.document_id).map_err(|_| ErrorCode::DocumentIdIsEmpty)?;
+    let document_id = Uuid::from_str(&document_id.0).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(ConvertHtmlToBlocksParams {
+      document_id,
+      html: self.html,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct LatexPayloadPB {
+  #[pb(index = 1)]
+  pub latex: String,
+}
+
+/// Result of validating a LaTeX snippet. An empty error list means the
+/// snippet is structurally valid.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct LatexValidationPB {
+  #[pb(index = 1)]
+  pub is_valid: bool,
+
+  #[pb(index = 2)]
+  pub errors: Vec<LatexParseErrorPB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct LatexParseErrorPB {
+  /// Character position the error was found at.
+  #[pb(index = 1)]
+  pub position: i64,
+
+  #[pb(index = 2)]
+  pub message: String,
+}
+
+impl From<LatexParseError> for LatexParseErrorPB {
+  fn from(error: LatexParseError) -> Self {
+    Self {
+      position: error.position as i64,
+      message: error.message,
+    }
+  }
+}
+
+/// A pre-rendered equation, as a standalone SVG document.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct EquationSvgPB {
+  #[pb(index = 1)]
+  pub svg: String,
+}
+
 #[derive(ProtoBuf, Debug, Default)]
 pub struct DocumentAwarenessStatesPB {
   #[pb(index = 1)]
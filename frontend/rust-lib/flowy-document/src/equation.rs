@@ -0,0 +1,351 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A structural error found in a LaTeX snippet, with the character position
+/// it was found at so the editor can highlight it.
+#[derive(Debug, Clone)]
+pub(crate) struct LatexParseError {
+  pub position: usize,
+  pub message: String,
+}
+
+impl LatexParseError {
+  fn new(position: usize, message: impl ToString) -> Self {
+    Self {
+      position,
+      message: message.to_string(),
+    }
+  }
+}
+
+/// The hash the rendered SVG of an equation is cached under, so unchanged
+/// equations are not re-rendered on every export.
+pub(crate) fn latex_content_hash(latex: &str) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  latex.hash(&mut hasher);
+  hasher.finish()
+}
+
+/// Checks the structure of a LaTeX snippet: balanced braces, matching
+/// `\left`/`\right` pairs, matching `\begin`/`\end` environments, complete
+/// commands and non-empty script arguments. Returns every error found, in
+/// document order. An empty result means the snippet is structurally valid.
+pub(crate) fn validate_latex(latex: &str) -> Vec<LatexParseError> {
+  let chars: Vec<char> = latex.chars().collect();
+  let mut errors = vec![];
+  let mut open_braces: Vec<usize> = vec![];
+  let mut open_lefts: Vec<usize> = vec![];
+  let mut open_environments: Vec<(String, usize)> = vec![];
+  let mut index = 0;
+  while index < chars.len() {
+    match chars[index] {
+      '{' => {
+        open_braces.push(index);
+        index += 1;
+      },
+      '}' => {
+        if open_braces.pop().is_none() {
+          errors.push(LatexParseError::new(index, "Unmatched '}'"));
+        }
+        index += 1;
+      },
+      '^' | '_' => {
+        let script = chars[index];
+        let mut next = index + 1;
+        while next < chars.len() && chars[next].is_whitespace() {
+          next += 1;
+        }
+        if next >= chars.len() || matches!(chars[next], '^' | '_' | '}') {
+          errors.push(LatexParseError::new(
+            index,
+            format!("Missing argument after '{}'", script),
+          ));
+        }
+        index += 1;
+      },
+      '\\' => {
+        let start = index;
+        index += 1;
+        let mut name = String::new();
+        while index < chars.len() && chars[index].is_ascii_alphabetic() {
+          name.push(chars[index]);
+          index += 1;
+        }
+        if name.is_empty() {
+          if index >= chars.len() {
+            errors.push(LatexParseError::new(start, "Incomplete command"));
+          } else {
+            // An escaped symbol such as `\{` or `\%`.
+            index += 1;
+          }
+          continue;
+        }
+        match name.as_str() {
+          "left" => open_lefts.push(start),
+          "right" => {
+            if open_lefts.pop().is_none() {
+              errors.push(LatexParseError::new(start, "'\\right' without '\\left'"));
+            }
+          },
+          "begin" | "end" => match read_group(&chars, index) {
+            Some((environment, end)) => {
+              if name == "begin" {
+                open_environments.push((environment, start));
+              } else {
+                match open_environments.pop() {
+                  Some((open_name, _)) if open_name == environment => {},
+                  Some((open_name, _)) => errors.push(LatexParseError::new(
+                    start,
+                    format!(
+                      "'\\end{{{}}}' does not match '\\begin{{{}}}'",
+                      environment, open_name
+                    ),
+                  )),
+                  None => errors.push(LatexParseError::new(
+                    start,
+                    format!("'\\end{{{}}}' without '\\begin'", environment),
+                  )),
+                }
+              }
+              index = end;
+            },
+            None => errors.push(LatexParseError::new(
+              start,
+              format!("Missing environment name after '\\{}'", name),
+            )),
+          },
+          _ => {},
+        }
+      },
+      _ => index += 1,
+    }
+  }
+  for position in open_braces {
+    errors.push(LatexParseError::new(position, "Unclosed '{'"));
+  }
+  for position in open_lefts {
+    errors.push(LatexParseError::new(position, "'\\left' without '\\right'"));
+  }
+  for (environment, position) in open_environments {
+    errors.push(LatexParseError::new(
+      position,
+      format!("Unclosed environment '{}'", environment),
+    ));
+  }
+  errors.sort_by_key(|error| error.position);
+  errors
+}
+
+/// Reads a `{...}` group starting at `index` and returns its content and the
+/// position after the closing brace.
+fn read_group(chars: &[char], index: usize) -> Option<(String, usize)> {
+  if chars.get(index) != Some(&'{') {
+    return None;
+  }
+  let mut content = String::new();
+  let mut current = index + 1;
+  while current < chars.len() {
+    if chars[current] == '}' {
+      return Some((content, current + 1));
+    }
+    content.push(chars[current]);
+    current += 1;
+  }
+  None
+}
+
+/// A run of rendered equation text at a script level.
+enum Segment {
+  Text(String),
+  Superscript(String),
+  Subscript(String),
+}
+
+/// Renders the LaTeX snippet to a standalone SVG, substituting the common
+/// symbol commands and laying out superscripts and subscripts. This is a
+/// lightweight renderer for export and publish paths, not a full TeX engine:
+/// commands it does not know are rendered as their name.
+pub(crate) fn render_latex_to_svg(latex: &str) -> String {
+  let segments = parse_segments(latex);
+  let mut body = String::new();
+  let mut width = 0.0;
+  for segment in &segments {
+    match segment {
+      Segment::Text(text) => {
+        width += text.chars().count() as f32 * 10.0;
+        body.push_str(&escape_xml(text));
+      },
+      Segment::Superscript(text) => {
+        width += text.chars().count() as f32 * 6.5;
+        body.push_str(&format!(
+          "<tspan dy=\"-7\" font-size=\"11\">{}</tspan><tspan dy=\"7\"> </tspan>",
+          escape_xml(text)
+        ));
+      },
+      Segment::Subscript(text) => {
+        width += text.chars().count() as f32 * 6.5;
+        body.push_str(&format!(
+          "<tspan dy=\"5\" font-size=\"11\">{}</tspan><tspan dy=\"-5\"> </tspan>",
+          escape_xml(text)
+        ));
+      },
+    }
+  }
+  let width = (width + 16.0).ceil() as u32;
+  format!(
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"36\">\
+     <text x=\"8\" y=\"24\" font-family=\"STIX Two Math, serif\" font-style=\"italic\" \
+     font-size=\"18\">{}</text></svg>",
+    width, body
+  )
+}
+
+/// Splits the snippet into text, superscript and subscript runs with the
+/// symbol commands substituted and grouping braces dropped.
+fn parse_segments(latex: &str) -> Vec<Segment> {
+  let chars: Vec<char> = latex.chars().collect();
+  let mut segments = vec![];
+  let mut text = String::new();
+  let mut index = 0;
+  while index < chars.len() {
+    match chars[index] {
+      '{' | '}' => index += 1,
+      '^' | '_' => {
+        let script = chars[index];
+        let (argument, end) = read_script_argument(&chars, index + 1);
+        if !argument.is_empty() {
+          if !text.is_empty() {
+            segments.push(Segment::Text(std::mem::take(&mut text)));
+          }
+          if script == '^' {
+            segments.push(Segment::Superscript(argument));
+          } else {
+            segments.push(Segment::Subscript(argument));
+          }
+        }
+        index = end;
+      },
+      '\\' => {
+        let (symbol, end) = read_command(&chars, index + 1);
+        text.push_str(&symbol);
+        index = end;
+      },
+      ch => {
+        text.push(ch);
+        index += 1;
+      },
+    }
+  }
+  if !text.is_empty() {
+    segments.push(Segment::Text(text));
+  }
+  segments
+}
+
+/// Reads the argument of a `^` or `_`: either a braced group, a command, or
+/// a single character. Returns the flattened argument text and the position
+/// after it.
+fn read_script_argument(chars: &[char], index: usize) -> (String, usize) {
+  match chars.get(index) {
+    Some('{') => match read_group(chars, index) {
+      Some((content, end)) => (flatten_latex(&content), end),
+      None => (String::new(), chars.len()),
+    },
+    Some('\\') => read_command(chars, index + 1),
+    Some(ch) => (ch.to_string(), index + 1),
+    None => (String::new(), index),
+  }
+}
+
+/// Reads the command name starting after a backslash and returns its
+/// substitution and the position after the name. Unknown commands render as
+/// their name.
+fn read_command(chars: &[char], index: usize) -> (String, usize) {
+  let mut name = String::new();
+  let mut current = index;
+  while current < chars.len() && chars[current].is_ascii_alphabetic() {
+    name.push(chars[current]);
+    current += 1;
+  }
+  if name.is_empty() {
+    // An escaped symbol such as `\{` renders as the symbol itself.
+    return match chars.get(current) {
+      Some(ch) => (ch.to_string(), current + 1),
+      None => (String::new(), current),
+    };
+  }
+  if name == "frac" {
+    if let Some((numerator, after_numerator)) = read_group(chars, current) {
+      if let Some((denominator, end)) = read_group(chars, after_numerator) {
+        let fraction = format!("{}/{}", flatten_latex(&numerator), flatten_latex(&denominator));
+        return (fraction, end);
+      }
+    }
+  }
+  match symbol_for_command(&name) {
+    Some(symbol) => (symbol.to_string(), current),
+    None => (name, current),
+  }
+}
+
+/// Flattens nested LaTeX to plain rendered text, dropping script levels.
+fn flatten_latex(latex: &str) -> String {
+  parse_segments(latex)
+    .into_iter()
+    .map(|segment| match segment {
+      Segment::Text(text) | Segment::Superscript(text) | Segment::Subscript(text) => text,
+    })
+    .collect()
+}
+
+/// The substitutions for the common symbol commands.
+fn symbol_for_command(name: &str) -> Option<&'static str> {
+  let symbol = match name {
+    "alpha" => "α",
+    "beta" => "β",
+    "gamma" => "γ",
+    "delta" => "δ",
+    "epsilon" => "ε",
+    "theta" => "θ",
+    "lambda" => "λ",
+    "mu" => "μ",
+    "pi" => "π",
+    "sigma" => "σ",
+    "phi" => "φ",
+    "omega" => "ω",
+    "Delta" => "Δ",
+    "Sigma" => "Σ",
+    "Omega" => "Ω",
+    "pm" => "±",
+    "times" => "×",
+    "div" => "÷",
+    "cdot" => "⋅",
+    "le" | "leq" => "≤",
+    "ge" | "geq" => "≥",
+    "ne" | "neq" => "≠",
+    "approx" => "≈",
+    "infty" => "∞",
+    "sum" => "∑",
+    "prod" => "∏",
+    "int" => "∫",
+    "sqrt" => "√",
+    "partial" => "∂",
+    "nabla" => "∇",
+    "rightarrow" | "to" => "→",
+    "leftarrow" => "←",
+    "in" => "∈",
+    "subset" => "⊂",
+    "cup" => "∪",
+    "cap" => "∩",
+    "left" | "right" | "begin" | "end" => "",
+    _ => return None,
+  };
+  Some(symbol)
+}
+
+fn escape_xml(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+}
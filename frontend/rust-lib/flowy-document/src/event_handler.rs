@@ -12,11 +12,12 @@ use collab_document::blocks::{
 };
 
 use crate::entities::*;
+use crate::equation::validate_latex;
 use crate::parser::document_data_parser::DocumentDataParser;
 use crate::parser::external::parser::ExternalDataToNestedJSONParser;
 use crate::parser::parser_entities::{
   ConvertDataToJsonParams, ConvertDataToJsonPayloadPB, ConvertDataToJsonResponsePB,
-  ConvertDocumentParams, ConvertDocumentPayloadPB, ConvertDocumentResponsePB,
+  ConvertDocumentParams, ConvertDocumentPayloadPB, ConvertDocumentResponsePB, InputType,
 };
 use crate::{manager::DocumentManager, parser::json::parser::JsonToDocumentParser};
 use flowy_error::{FlowyError, FlowyResult};
@@ -127,6 +128,7 @@ pub(crate) async fn apply_action_handler(
   let actions = params.actions;
   sync_trace!("{} applying action: {:?}", doc_id, actions);
   document.write().await.apply_action(actions)?;
+  manager.record_document_edit(&doc_id).await;
   Ok(())
 }
 
@@ -142,6 +144,8 @@ pub(crate) async fn create_text_handler(
   let mut document = document.write().await;
   sync_trace!("{} creating text: {:?}", doc_id, params.delta);
   document.apply_text_delta(&params.text_id, params.delta);
+  drop(document);
+  manager.record_document_edit(&doc_id).await;
   Ok(())
 }
 
@@ -159,6 +163,8 @@ pub(crate) async fn apply_text_delta_handler(
   let mut document = document.write().await;
   sync_trace!("{} applying delta: {:?}", doc_id, delta);
   document.apply_text_delta(&text_id, delta);
+  drop(document);
+  manager.record_document_edit(&doc_id).await;
   Ok(())
 }
 
@@ -262,6 +268,198 @@ pub(crate) async fn get_snapshot_data_handler(
   data_result_ok(snapshot)
 }
 
+pub(crate) async fn get_document_statistics_handler(
+  data: AFPluginData<OpenDocumentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<DocumentStatisticsPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: OpenDocumentParams = data.into_inner().try_into()?;
+  let doc_id = params.document_id;
+  let statistics = manager.get_document_statistics(&doc_id).await?;
+  data_result_ok(statistics)
+}
+
+pub(crate) async fn get_document_outline_handler(
+  data: AFPluginData<OpenDocumentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<DocumentOutlinePB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: OpenDocumentParams = data.into_inner().try_into()?;
+  let doc_id = params.document_id;
+  let outline = manager.get_document_outline(&doc_id).await?;
+  data_result_ok(outline)
+}
+
+pub(crate) async fn search_mentionable_handler(
+  data: AFPluginData<SearchMentionablePayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<RepeatedMentionablePB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let query = data.into_inner().query;
+  let items = manager.search_mentionable(&query).await?;
+  data_result_ok(RepeatedMentionablePB {
+    items: items.into_iter().map(Into::into).collect(),
+  })
+}
+
+pub(crate) async fn create_mention_handler(
+  data: AFPluginData<CreateMentionPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<MentionPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: CreateMentionParams = data.into_inner().try_into()?;
+  data_result_ok(manager.create_mention(params))
+}
+
+pub(crate) async fn create_comment_thread_handler(
+  data: AFPluginData<CreateCommentThreadPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<CommentThreadPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: CreateCommentThreadParams = data.into_inner().try_into()?;
+  let thread = manager.create_comment_thread(params).await?;
+  data_result_ok(thread)
+}
+
+pub(crate) async fn add_comment_handler(
+  data: AFPluginData<AddCommentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<CommentPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: AddCommentParams = data.into_inner().try_into()?;
+  let comment = manager.add_comment(params).await?;
+  data_result_ok(comment)
+}
+
+pub(crate) async fn update_comment_handler(
+  data: AFPluginData<UpdateCommentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_document(manager)?;
+  let params: UpdateCommentParams = data.into_inner().try_into()?;
+  manager.update_comment(params).await
+}
+
+pub(crate) async fn delete_comment_handler(
+  data: AFPluginData<DeleteCommentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_document(manager)?;
+  let params: DeleteCommentParams = data.into_inner().try_into()?;
+  manager.delete_comment(params).await
+}
+
+pub(crate) async fn set_comment_thread_resolved_handler(
+  data: AFPluginData<SetCommentThreadResolvedPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_document(manager)?;
+  let params: SetCommentThreadResolvedParams = data.into_inner().try_into()?;
+  manager.set_comment_thread_resolved(params).await
+}
+
+pub(crate) async fn list_comment_threads_handler(
+  data: AFPluginData<OpenDocumentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<RepeatedCommentThreadPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: OpenDocumentParams = data.into_inner().try_into()?;
+  let doc_id = params.document_id;
+  let items = manager.list_comment_threads(&doc_id).await?;
+  data_result_ok(RepeatedCommentThreadPB { items })
+}
+
+pub(crate) async fn save_as_template_handler(
+  data: AFPluginData<SaveAsTemplatePayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<DocumentTemplatePB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: SaveAsTemplateParams = data.into_inner().try_into()?;
+  let template = manager.save_document_as_template(params).await?;
+  data_result_ok(template)
+}
+
+pub(crate) async fn list_document_templates_handler(
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<RepeatedDocumentTemplatePB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let items = manager.list_document_templates()?;
+  data_result_ok(RepeatedDocumentTemplatePB { items })
+}
+
+pub(crate) async fn delete_document_template_handler(
+  data: AFPluginData<DeleteDocumentTemplatePayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_document(manager)?;
+  let template_id = data.into_inner().template_id;
+  manager.delete_document_template(&template_id)
+}
+
+pub(crate) async fn create_document_from_template_handler(
+  data: AFPluginData<CreateDocumentFromTemplatePayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<TemplateInstantiatedPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: CreateDocumentFromTemplateParams = data.into_inner().try_into()?;
+  let uid = manager.user_service.user_id()?;
+  let (_, cursor) = manager
+    .create_document_from_template(
+      uid,
+      &params.document_id,
+      &params.template_id,
+      params.author_name.as_deref(),
+    )
+    .await?;
+  data_result_ok(TemplateInstantiatedPB {
+    cursor_block_id: cursor.block_id,
+    cursor_offset: cursor.offset as i64,
+  })
+}
+
+pub(crate) async fn diff_document_versions_handler(
+  data: AFPluginData<DiffDocumentVersionsPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<DocumentVersionDiffPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: DiffDocumentVersionsParams = data.into_inner().try_into()?;
+  let diff = manager.diff_document_versions(params).await?;
+  data_result_ok(diff)
+}
+
+pub(crate) async fn get_unresolved_comment_count_handler(
+  data: AFPluginData<OpenDocumentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<UnresolvedCommentCountPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: OpenDocumentParams = data.into_inner().try_into()?;
+  let doc_id = params.document_id;
+  let count = manager.unresolved_comment_count(&doc_id).await?;
+  data_result_ok(UnresolvedCommentCountPB { count })
+}
+
+pub(crate) async fn list_document_versions_handler(
+  data: AFPluginData<OpenDocumentPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<RepeatedDocumentVersionPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: OpenDocumentParams = data.into_inner().try_into()?;
+  let doc_id = params.document_id;
+  let items = manager.list_document_versions(&doc_id).await?;
+  data_result_ok(RepeatedDocumentVersionPB { items })
+}
+
+pub(crate) async fn restore_document_version_handler(
+  data: AFPluginData<RestoreDocumentVersionPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_document(manager)?;
+  let params: RestoreDocumentVersionParams = data.into_inner().try_into()?;
+  manager
+    .restore_document_version(&params.document_id, &params.version_id)
+    .await
+}
+
 impl From<BlockActionPB> for BlockAction {
   fn from(pb: BlockActionPB) -> Self {
     Self {
@@ -437,6 +635,57 @@ pub(crate) async fn convert_data_to_json_handler(
   data_result_ok(ConvertDataToJsonResponsePB { json: result })
 }
 
+/// Handler for converting pasted HTML into document blocks. Unlike
+/// [convert_data_to_json_handler], remote images referenced by the HTML are
+/// downloaded into the document's file storage before the blocks are
+/// returned.
+pub(crate) async fn convert_html_to_blocks_handler(
+  data: AFPluginData<ConvertHtmlToBlocksPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<ConvertDataToJsonResponsePB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let params: ConvertHtmlToBlocksParams = data.into_inner().try_into()?;
+  let parser = ExternalDataToNestedJSONParser::new(params.html, InputType::Html);
+
+  let result = match parser.to_nested_block() {
+    Some(mut block) => {
+      manager
+        .localize_remote_images(&params.document_id, &mut block)
+        .await;
+      serde_json::to_string(&block)?
+    },
+    None => "".to_string(),
+  };
+
+  data_result_ok(ConvertDataToJsonResponsePB { json: result })
+}
+
+/// Handler for validating a LaTeX snippet. Returns every structural error
+/// with its character position so the editor can highlight it.
+pub(crate) async fn validate_latex_handler(
+  data: AFPluginData<LatexPayloadPB>,
+) -> DataResult<LatexValidationPB, FlowyError> {
+  let errors: Vec<LatexParseErrorPB> = validate_latex(&data.into_inner().latex)
+    .into_iter()
+    .map(Into::into)
+    .collect();
+  data_result_ok(LatexValidationPB {
+    is_valid: errors.is_empty(),
+    errors,
+  })
+}
+
+/// Handler for pre-rendering an equation to SVG for export and publish
+/// paths. Rendered output is cached by content hash.
+pub(crate) async fn render_equation_handler(
+  data: AFPluginData<LatexPayloadPB>,
+  manager: AFPluginState<Weak<DocumentManager>>,
+) -> DataResult<EquationSvgPB, FlowyError> {
+  let manager = upgrade_document(manager)?;
+  let svg = manager.render_equation(&data.into_inner().latex);
+  data_result_ok(EquationSvgPB { svg })
+}
+
 // Handler for uploading a file
 // `workspace_id` and `file_name` determines file identity
 pub(crate) async fn upload_file_handler(
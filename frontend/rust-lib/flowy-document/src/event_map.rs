@@ -52,6 +52,66 @@ pub fn init(document_manager: Weak<DocumentManager>) -> AFPlugin {
       DocumentEvent::SetAwarenessState,
       set_awareness_local_state_handler,
     )
+    .event(
+      DocumentEvent::ListDocumentVersions,
+      list_document_versions_handler,
+    )
+    .event(
+      DocumentEvent::RestoreDocumentVersion,
+      restore_document_version_handler,
+    )
+    .event(
+      DocumentEvent::GetDocumentStatistics,
+      get_document_statistics_handler,
+    )
+    .event(
+      DocumentEvent::GetDocumentOutline,
+      get_document_outline_handler,
+    )
+    .event(DocumentEvent::SearchMentionable, search_mentionable_handler)
+    .event(DocumentEvent::CreateMention, create_mention_handler)
+    .event(
+      DocumentEvent::CreateCommentThread,
+      create_comment_thread_handler,
+    )
+    .event(DocumentEvent::AddComment, add_comment_handler)
+    .event(DocumentEvent::UpdateComment, update_comment_handler)
+    .event(DocumentEvent::DeleteComment, delete_comment_handler)
+    .event(
+      DocumentEvent::SetCommentThreadResolved,
+      set_comment_thread_resolved_handler,
+    )
+    .event(
+      DocumentEvent::ListCommentThreads,
+      list_comment_threads_handler,
+    )
+    .event(
+      DocumentEvent::GetUnresolvedCommentCount,
+      get_unresolved_comment_count_handler,
+    )
+    .event(
+      DocumentEvent::DiffDocumentVersions,
+      diff_document_versions_handler,
+    )
+    .event(DocumentEvent::SaveAsTemplate, save_as_template_handler)
+    .event(
+      DocumentEvent::ListDocumentTemplates,
+      list_document_templates_handler,
+    )
+    .event(
+      DocumentEvent::DeleteDocumentTemplate,
+      delete_document_template_handler,
+    )
+    .event(
+      DocumentEvent::CreateDocumentFromTemplate,
+      create_document_from_template_handler,
+    )
+    .event(
+      DocumentEvent::ConvertHtmlToBlocks,
+      convert_html_to_blocks_handler,
+    )
+    .event(DocumentEvent::ValidateLatex, validate_latex_handler)
+    .event(DocumentEvent::RenderEquation, render_equation_handler)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Display, ProtoBuf_Enum, Flowy_Event)]
@@ -137,4 +197,108 @@ pub enum DocumentEvent {
 
   #[event(input = "OpenDocumentPayloadPB", output = "DocumentTextPB")]
   GetDocumentText = 20,
+
+  /// Returns the automatically taken local versions of the document, newest
+  /// first.
+  #[event(
+    input = "OpenDocumentPayloadPB",
+    output = "RepeatedDocumentVersionPB"
+  )]
+  ListDocumentVersions = 21,
+
+  /// Replaces the document's local content with a stored version. The current
+  /// state is snapshotted first so the restore itself can be reverted.
+  #[event(input = "RestoreDocumentVersionPayloadPB")]
+  RestoreDocumentVersion = 22,
+
+  /// Returns word/character counts, per-type block counts and the estimated
+  /// reading time of the document.
+  #[event(input = "OpenDocumentPayloadPB", output = "DocumentStatisticsPB")]
+  GetDocumentStatistics = 23,
+
+  /// Returns the heading hierarchy of the document. Outline diffs are sent
+  /// through [DocumentNotification::DidUpdateDocumentOutline] afterwards.
+  #[event(input = "OpenDocumentPayloadPB", output = "DocumentOutlinePB")]
+  GetDocumentOutline = 24,
+
+  /// Returns autocomplete candidates (workspace members and pages) for the
+  /// mention popover.
+  #[event(input = "SearchMentionablePayloadPB", output = "RepeatedMentionablePB")]
+  SearchMentionable = 25,
+
+  /// Creates the inline data for a new mention with a stable id and records
+  /// user mentions for later notification.
+  #[event(input = "CreateMentionPayloadPB", output = "MentionPB")]
+  CreateMention = 26,
+
+  /// Creates a comment thread anchored to a text range of a block.
+  #[event(input = "CreateCommentThreadPayloadPB", output = "CommentThreadPB")]
+  CreateCommentThread = 27,
+
+  /// Appends a reply to an existing comment thread.
+  #[event(input = "AddCommentPayloadPB", output = "CommentPB")]
+  AddComment = 28,
+
+  #[event(input = "UpdateCommentPayloadPB")]
+  UpdateComment = 29,
+
+  #[event(input = "DeleteCommentPayloadPB")]
+  DeleteComment = 30,
+
+  /// Marks a comment thread as resolved, or reopens it.
+  #[event(input = "SetCommentThreadResolvedPayloadPB")]
+  SetCommentThreadResolved = 31,
+
+  /// Returns the comment threads of the document with their anchors
+  /// re-resolved against the current text.
+  #[event(input = "OpenDocumentPayloadPB", output = "RepeatedCommentThreadPB")]
+  ListCommentThreads = 32,
+
+  /// Returns the number of unresolved comment threads of the document.
+  #[event(input = "OpenDocumentPayloadPB", output = "UnresolvedCommentCountPB")]
+  GetUnresolvedCommentCount = 33,
+
+  /// Produces a structured diff between two stored versions of the document:
+  /// blocks added, removed and modified, with text-level diffs.
+  #[event(
+    input = "DiffDocumentVersionsPayloadPB",
+    output = "DocumentVersionDiffPB"
+  )]
+  DiffDocumentVersions = 34,
+
+  /// Stores the document's current content as a local template.
+  #[event(input = "SaveAsTemplatePayloadPB", output = "DocumentTemplatePB")]
+  SaveAsTemplate = 35,
+
+  #[event(output = "RepeatedDocumentTemplatePB")]
+  ListDocumentTemplates = 36,
+
+  #[event(input = "DeleteDocumentTemplatePayloadPB")]
+  DeleteDocumentTemplate = 37,
+
+  /// Creates a document from a template, substituting `{{date}}`,
+  /// `{{author}}` and `{{cursor}}`, and returns the initial cursor position.
+  #[event(
+    input = "CreateDocumentFromTemplatePayloadPB",
+    output = "TemplateInstantiatedPB"
+  )]
+  CreateDocumentFromTemplate = 38,
+
+  /// Converts pasted HTML into document blocks, keeping tables, nested
+  /// lists, code blocks and inline styles. Remote images are downloaded
+  /// into the document's file storage.
+  #[event(
+    input = "ConvertHtmlToBlocksPayloadPB",
+    output = "ConvertDataToJsonResponsePB"
+  )]
+  ConvertHtmlToBlocks = 39,
+
+  /// Validates a LaTeX snippet and reports parse errors with positions.
+  #[event(input = "LatexPayloadPB", output = "LatexValidationPB")]
+  ValidateLatex = 40,
+
+  /// Pre-renders an equation to SVG for export and publish paths. Rendered
+  /// output is cached by content hash.
+  #[event(input = "LatexPayloadPB", output = "EquationSvgPB")]
+  RenderEquation = 41,
 }
@@ -0,0 +1,216 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use collab_document::blocks::DocumentData;
+use flowy_error::FlowyResult;
+use serde_json::Value;
+
+use crate::equation::{latex_content_hash, render_latex_to_svg};
+use crate::parser::constant::{FILE, FORMULA, IMAGE, MATH_EQUATION, URL};
+use crate::parser::document_data_parser::DocumentDataParser;
+use crate::parser::parser_entities::{ConvertBlockToHtmlParams, NestedBlock};
+
+/// The directory inside the export directory that holds the copied local
+/// images and attachments.
+const ASSETS_DIR: &str = "assets";
+
+/// The stylesheet embedded in every exported page so callouts and toggles
+/// render reasonably without any external resources.
+const PAGE_STYLE: &str = "body { font-family: sans-serif; max-width: 50rem; margin: 2rem auto; padding: 0 1rem; } \
+aside[role=\"note\"] { background: #f2f2f2; border-radius: 4px; padding: 0.5rem 1rem; } \
+details > summary { cursor: pointer; } \
+nav.subpages { border-top: 1px solid #ddd; margin-top: 2rem; padding-top: 1rem; }";
+
+/// A link to another exported page, rendered in the "Subpages" section of the
+/// parent page.
+pub struct HtmlChildLink {
+  /// The file name of the child page, relative to the export directory.
+  pub file_name: String,
+  /// The display name of the child view.
+  pub name: String,
+}
+
+/// Exports the document as a standalone HTML file in `output_dir` and returns
+/// the path of the written file.
+///
+/// Images and attachments that reference local files are copied into an
+/// `assets` folder next to the page and the markup points at the copies, so
+/// the export directory is self-contained. `child_links` are rendered as a
+/// "Subpages" section so a whole view subtree can be exported into linked
+/// files. If a file with the same name already exists in the directory, a
+/// numeric suffix is appended.
+pub(crate) fn export_to_html(
+  document_data: DocumentData,
+  name: &str,
+  child_links: &[HtmlChildLink],
+  output_dir: &Path,
+) -> FlowyResult<PathBuf> {
+  std::fs::create_dir_all(output_dir)?;
+  let parser = DocumentDataParser::new(Arc::new(document_data), None);
+  let mut root = parser.to_json();
+  if let Some(root) = root.as_mut() {
+    copy_local_assets(root, output_dir)?;
+    render_equations_to_assets(root, output_dir)?;
+  }
+  let body = root
+    .map(|root| {
+      root.convert_to_html(ConvertBlockToHtmlParams {
+        prev_block_ty: None,
+        next_block_ty: None,
+      })
+    })
+    .unwrap_or_default();
+
+  let path = unique_path(output_dir, &html_file_name(name));
+  std::fs::write(&path, render_page(name, &body, child_links))?;
+  Ok(path)
+}
+
+/// Returns a file-system safe `<name>.html` file name for the page.
+pub(crate) fn html_file_name(name: &str) -> String {
+  let sanitized: String = name
+    .chars()
+    .map(|c| match c {
+      'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | ' ' | '.' => c,
+      _ => '_',
+    })
+    .collect();
+  let sanitized = sanitized.trim().trim_matches('.');
+  if sanitized.is_empty() {
+    "Untitled.html".to_string()
+  } else {
+    format!("{}.html", sanitized)
+  }
+}
+
+/// Copies every image and file attachment that points at a local file into
+/// the assets folder and rewrites the block's url to the relative copy.
+/// Remote urls are left untouched.
+fn copy_local_assets(block: &mut NestedBlock, output_dir: &Path) -> FlowyResult<()> {
+  if block.ty == IMAGE || block.ty == FILE {
+    if let Some(source) = block
+      .data
+      .get(URL)
+      .and_then(|value| value.as_str())
+      .and_then(local_asset_path)
+    {
+      let assets_dir = output_dir.join(ASSETS_DIR);
+      std::fs::create_dir_all(&assets_dir)?;
+      let file_name = source
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("asset")
+        .to_string();
+      let dest = unique_path(&assets_dir, &file_name);
+      std::fs::copy(&source, &dest)?;
+      let relative = format!(
+        "{}/{}",
+        ASSETS_DIR,
+        dest.file_name().and_then(|name| name.to_str()).unwrap()
+      );
+      block.data.insert(URL.to_string(), Value::String(relative));
+    }
+  }
+  for child in &mut block.children {
+    copy_local_assets(child, output_dir)?;
+  }
+  Ok(())
+}
+
+/// Pre-renders every equation block into an SVG in the assets folder and
+/// turns the block into an image pointing at it, so the exported page shows
+/// equations without a TeX renderer. The file name is derived from the
+/// content hash, so identical equations share one asset and unchanged
+/// equations are not re-rendered on a re-export.
+fn render_equations_to_assets(block: &mut NestedBlock, output_dir: &Path) -> FlowyResult<()> {
+  if block.ty == MATH_EQUATION {
+    let formula = block
+      .data
+      .get(FORMULA)
+      .and_then(|value| value.as_str())
+      .unwrap_or_default()
+      .to_string();
+    if !formula.is_empty() {
+      let assets_dir = output_dir.join(ASSETS_DIR);
+      std::fs::create_dir_all(&assets_dir)?;
+      let file_name = format!("equation-{:x}.svg", latex_content_hash(&formula));
+      let path = assets_dir.join(&file_name);
+      if !path.exists() {
+        std::fs::write(&path, render_latex_to_svg(&formula))?;
+      }
+      block.ty = IMAGE.to_string();
+      block.data.insert(
+        URL.to_string(),
+        Value::String(format!("{}/{}", ASSETS_DIR, file_name)),
+      );
+    }
+  }
+  for child in &mut block.children {
+    render_equations_to_assets(child, output_dir)?;
+  }
+  Ok(())
+}
+
+/// Returns the local path the url points at, if it references an existing
+/// file on disk.
+fn local_asset_path(url: &str) -> Option<PathBuf> {
+  let path = Path::new(url.strip_prefix("file://").unwrap_or(url));
+  if path.is_absolute() && path.is_file() {
+    Some(path.to_path_buf())
+  } else {
+    None
+  }
+}
+
+/// Returns `dir/file_name`, appending a numeric suffix when the file already
+/// exists so sibling pages with the same name don't overwrite each other.
+fn unique_path(dir: &Path, file_name: &str) -> PathBuf {
+  let path = dir.join(file_name);
+  if !path.exists() {
+    return path;
+  }
+  let (stem, ext) = match file_name.rsplit_once('.') {
+    Some((stem, ext)) => (stem.to_string(), format!(".{}", ext)),
+    None => (file_name.to_string(), String::new()),
+  };
+  let mut index = 1;
+  loop {
+    let path = dir.join(format!("{}-{}{}", stem, index, ext));
+    if !path.exists() {
+      return path;
+    }
+    index += 1;
+  }
+}
+
+fn render_page(name: &str, body: &str, child_links: &[HtmlChildLink]) -> String {
+  let title = escape_html(name);
+  let mut html = String::new();
+  html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"UTF-8\">\n");
+  html.push_str(&format!("<title>{}</title>\n", title));
+  html.push_str(&format!("<style>{}</style>\n", PAGE_STYLE));
+  html.push_str("</head>\n<body>\n");
+  html.push_str(&format!("<h1>{}</h1>\n", title));
+  html.push_str(body);
+  if !child_links.is_empty() {
+    html.push_str("\n<nav class=\"subpages\">\n<h2>Subpages</h2>\n<ul>\n");
+    for link in child_links {
+      html.push_str(&format!(
+        "<li><a href=\"{}\">{}</a></li>\n",
+        escape_html(&link.file_name),
+        escape_html(&link.name)
+      ));
+    }
+    html.push_str("</ul>\n</nav>");
+  }
+  html.push_str("\n</body>\n</html>\n");
+  html
+}
+
+fn escape_html(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
@@ -7,8 +7,17 @@ pub mod manager;
 pub mod parser;
 pub mod protobuf;
 
+pub mod comment;
 pub mod deps;
+pub mod html_export;
+pub mod mention;
 pub mod notification;
+mod equation;
 mod parse;
 pub mod reminder;
+pub mod template;
+mod outline;
+mod statistics;
+mod version_diff;
+mod version_history;
 pub use collab_document::document::DocumentIndexContent;
@@ -1,3 +1,4 @@
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::Weak;
 
@@ -7,7 +8,7 @@ use collab::core::origin::CollabOrigin;
 use collab::entity::EncodedCollab;
 use collab::lock::RwLock;
 use collab::preclude::Collab;
-use collab_document::blocks::DocumentData;
+use collab_document::blocks::{BlockAction, BlockActionPayload, BlockActionType, DocumentData};
 use collab_document::document::Document;
 use collab_document::document_awareness::DocumentAwarenessState;
 use collab_document::document_awareness::DocumentAwarenessUser;
@@ -27,14 +28,36 @@ use flowy_error::{ErrorCode, FlowyError, FlowyResult, internal_error};
 use flowy_storage_pub::storage::{CreatedUpload, StorageService};
 use lib_infra::util::timestamp;
 use tracing::{event, instrument};
-use tracing::{info, trace};
+use tracing::{info, trace, warn};
 use uuid::Uuid;
 
 use crate::entities::UpdateDocumentAwarenessStatePB;
 use crate::entities::{
+  AddCommentParams, CommentPB, CommentThreadPB, CreateCommentThreadParams, CreateMentionParams,
+  DeleteCommentParams, DiffDocumentVersionsParams, DocumentCommentsChangedPB, DocumentOutlinePB,
   DocumentSnapshotData, DocumentSnapshotMeta, DocumentSnapshotMetaPB, DocumentSnapshotPB,
+  DocumentStatisticsPB, DocumentTemplatePB, DocumentVersionDiffPB, DocumentVersionMeta,
+  DocumentVersionPB, MentionPB, SaveAsTemplateParams, SetCommentThreadResolvedParams,
+  UpdateCommentParams,
 };
+use crate::comment::{Comment, CommentAnchor, CommentThread, DocumentCommentStore, resolve_anchor};
+use crate::equation::{latex_content_hash, render_latex_to_svg};
+use crate::html_export::{HtmlChildLink, export_to_html};
+use crate::mention::{MentionType, Mentionable, MentionableProvider, RecordedUserMention};
+use crate::notification::{DocumentNotification, document_notification_builder};
+use crate::outline::{
+  OutlineItem, compute_outline, diff_outline, outline_items_to_value, outline_to_pb,
+};
+use crate::parser::constant::{IMAGE, OUTLINE, OUTLINE_ITEMS, URL};
+use crate::parser::parser_entities::NestedBlock;
+use crate::parser::utils::{delta_to_text, get_delta_for_block};
 use crate::reminder::DocumentReminderAction;
+use crate::statistics::{DocumentStatistics, compute_statistics};
+use crate::template::{
+  DocumentTemplate, DocumentTemplateStore, TemplateCursor, substitute_variables,
+};
+use crate::version_diff::diff_document_data;
+use crate::version_history::{VersionHistoryRecorder, text_stats};
 
 pub trait DocumentUserService: Send + Sync {
   fn user_id(&self) -> Result<i64, FlowyError>;
@@ -49,6 +72,16 @@ pub trait DocumentSnapshotService: Send + Sync {
     document_id: &str,
   ) -> FlowyResult<Vec<DocumentSnapshotMeta>>;
   fn get_document_snapshot(&self, snapshot_id: &str) -> FlowyResult<DocumentSnapshotData>;
+  /// Stores a local version snapshot of the document and returns its id.
+  /// Old versions are rotated out by the storage.
+  fn create_document_snapshot(
+    &self,
+    document_id: &str,
+    data: Vec<u8>,
+    summary: String,
+  ) -> FlowyResult<String>;
+  /// Returns the locally stored versions of the document, newest first.
+  fn get_document_versions(&self, document_id: &str) -> FlowyResult<Vec<DocumentVersionMeta>>;
 }
 
 pub struct DocumentManager {
@@ -59,6 +92,25 @@ pub struct DocumentManager {
   cloud_service: Arc<dyn DocumentCloudService>,
   storage_service: Weak<dyn StorageService>,
   snapshot_service: Arc<dyn DocumentSnapshotService>,
+  version_recorder: VersionHistoryRecorder,
+  /// Cached statistics per document, invalidated on edit.
+  statistics_cache: DashMap<Uuid, DocumentStatistics>,
+  /// Last outline sent per document, used to emit outline diffs on edit.
+  outline_cache: DashMap<Uuid, Vec<OutlineItem>>,
+  /// Autocomplete providers for the mention popover, registered by the
+  /// integration layer.
+  mention_providers: std::sync::RwLock<Vec<Arc<dyn MentionableProvider>>>,
+  /// User mentions created in documents, drained by the notification pipeline.
+  recorded_user_mentions: std::sync::Mutex<Vec<RecordedUserMention>>,
+  /// Persistence for comment threads, set by the integration layer. Without a
+  /// store, comments only live in the in-memory cache below.
+  comment_store: std::sync::RwLock<Option<Arc<dyn DocumentCommentStore>>>,
+  comment_cache: DashMap<Uuid, Vec<CommentThread>>,
+  /// Persistence for document templates, set by the integration layer.
+  template_store: std::sync::RwLock<Option<Arc<dyn DocumentTemplateStore>>>,
+  /// Rendered equation SVGs keyed by content hash, so unchanged equations
+  /// are not re-rendered on every export or publish.
+  equation_svg_cache: DashMap<u64, String>,
 }
 
 impl Drop for DocumentManager {
@@ -83,6 +135,15 @@ impl DocumentManager {
       cloud_service,
       storage_service,
       snapshot_service,
+      version_recorder: VersionHistoryRecorder::default(),
+      statistics_cache: DashMap::new(),
+      outline_cache: DashMap::new(),
+      mention_providers: std::sync::RwLock::new(vec![]),
+      recorded_user_mentions: std::sync::Mutex::new(vec![]),
+      comment_store: std::sync::RwLock::new(None),
+      comment_cache: DashMap::new(),
+      template_store: std::sync::RwLock::new(None),
+      equation_svg_cache: DashMap::new(),
     }
   }
 
@@ -432,6 +493,708 @@ impl DocumentManager {
     Ok(snapshot)
   }
 
+  /// Records one applied edit on the document and takes an automatic local
+  /// version snapshot when enough editing activity has accumulated.
+  pub async fn record_document_edit(&self, doc_id: &Uuid) {
+    self.statistics_cache.remove(doc_id);
+    self.refresh_outline(doc_id).await;
+    if self.version_recorder.record_edit(doc_id, timestamp()) {
+      if let Err(err) = self.snapshot_document_version(doc_id).await {
+        warn!("failed to snapshot version of document {}: {}", doc_id, err);
+      }
+    }
+  }
+
+  /// Stores the document's current state as a local version.
+  async fn snapshot_document_version(&self, doc_id: &Uuid) -> FlowyResult<String> {
+    let document = self.editable_document(doc_id).await?;
+    let (encoded_collab, text) = {
+      let lock = document.read().await;
+      let encoded_collab = lock.encode_collab()?;
+      let text = lock.paragraphs().join("\n");
+      (encoded_collab, text)
+    };
+    let (words, chars) = text_stats(&text);
+    let summary = self.version_recorder.take_summary(doc_id, words, chars);
+
+    let snapshot_service = self.snapshot_service.clone();
+    let document_id = doc_id.to_string();
+    let version_id = tokio::task::spawn_blocking(move || {
+      snapshot_service.create_document_snapshot(
+        &document_id,
+        encoded_collab.doc_state.to_vec(),
+        summary,
+      )
+    })
+    .await??;
+    trace!("took version snapshot {} of document {}", version_id, doc_id);
+    Ok(version_id)
+  }
+
+  /// Returns the locally stored versions of the document, newest first.
+  pub async fn list_document_versions(&self, doc_id: &Uuid) -> FlowyResult<Vec<DocumentVersionPB>> {
+    let metas = self
+      .snapshot_service
+      .get_document_versions(doc_id.to_string().as_str())?;
+    Ok(metas.into_iter().map(Into::into).collect())
+  }
+
+  /// Replaces the document's local content with the given version. The
+  /// current state is snapshotted first, so the restore itself shows up in
+  /// the history and can be reverted.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn restore_document_version(&self, doc_id: &Uuid, version_id: &str) -> FlowyResult<()> {
+    let versions = self
+      .snapshot_service
+      .get_document_versions(doc_id.to_string().as_str())?;
+    if !versions.iter().any(|version| version.version_id == version_id) {
+      return Err(
+        FlowyError::invalid_data().with_context("The version doesn't belong to this document"),
+      );
+    }
+    let snapshot = self.snapshot_service.get_document_snapshot(version_id)?;
+
+    // Validate the stored doc state before touching the current document.
+    let doc_id_str = doc_id.to_string();
+    let encoded_collab = tokio::task::spawn_blocking(move || {
+      let collab = Collab::new_with_source(
+        CollabOrigin::Empty,
+        &doc_id_str,
+        DataSource::DocStateV1(snapshot.encoded_v1),
+        vec![],
+        false,
+      )
+      .map_err(internal_error)?;
+      let document = Document::open(collab).map_err(internal_error)?;
+      document.encode_collab().map_err(internal_error)
+    })
+    .await??;
+
+    // Keep the pre-restore state in the history so the restore can be undone.
+    // The document might not be open; in that case there is nothing to keep.
+    let _ = self.snapshot_document_version(doc_id).await;
+
+    // Swap the local collab with the restored state. The document is dropped
+    // from the cache so the next open loads the restored content.
+    self.documents.remove(doc_id);
+    self.removing_documents.remove(doc_id);
+    let uid = self.user_service.user_id()?;
+    let workspace_id = self.user_service.workspace_id()?;
+    if let Some(db) = self.user_service.collab_db(uid)?.upgrade() {
+      db.delete_doc(uid, &workspace_id.to_string(), &doc_id.to_string())
+        .await?;
+    }
+    self
+      .persistence()?
+      .save_collab_to_disk(doc_id.to_string().as_str(), encoded_collab)
+      .map_err(internal_error)?;
+    Ok(())
+  }
+
+  /// Produces a structured diff between two stored versions of the document:
+  /// blocks added, removed and modified, with text-level diffs. Used by the
+  /// history UI and to show what a sync just changed.
+  pub async fn diff_document_versions(
+    &self,
+    params: DiffDocumentVersionsParams,
+  ) -> FlowyResult<DocumentVersionDiffPB> {
+    let doc_id = params.document_id;
+    let versions = self
+      .snapshot_service
+      .get_document_versions(doc_id.to_string().as_str())?;
+    for version_id in [&params.from_version_id, &params.to_version_id] {
+      if !versions
+        .iter()
+        .any(|version| &version.version_id == version_id)
+      {
+        return Err(
+          FlowyError::invalid_data().with_context("The version doesn't belong to this document"),
+        );
+      }
+    }
+    let from = self
+      .snapshot_service
+      .get_document_snapshot(&params.from_version_id)?;
+    let to = self
+      .snapshot_service
+      .get_document_snapshot(&params.to_version_id)?;
+
+    let doc_id_str = doc_id.to_string();
+    let diff = tokio::task::spawn_blocking(move || {
+      let old = decode_document_data(&doc_id_str, from.encoded_v1)?;
+      let new = decode_document_data(&doc_id_str, to.encoded_v1)?;
+      Ok::<_, FlowyError>(diff_document_data(&old, &new))
+    })
+    .await??;
+    Ok(diff)
+  }
+
+  /// Returns the word/character counts, per-type block counts and estimated
+  /// reading time of the document. The result is cached and only recomputed
+  /// after the document was edited, so polling from an open info panel
+  /// doesn't block the editor.
+  pub async fn get_document_statistics(&self, doc_id: &Uuid) -> FlowyResult<DocumentStatisticsPB> {
+    if let Some(stats) = self.statistics_cache.get(doc_id) {
+      return Ok(stats.clone().into());
+    }
+    let text = self.get_document_text(doc_id).await?;
+    let document_data = self.get_document_data(doc_id).await?;
+    let stats =
+      tokio::task::spawn_blocking(move || compute_statistics(&text, &document_data)).await?;
+    self.statistics_cache.insert(*doc_id, stats.clone());
+    Ok(stats.into())
+  }
+
+  /// Returns the heading hierarchy of the document, in document order.
+  /// Requesting the outline subscribes the document to
+  /// [DocumentNotification::DidUpdateDocumentOutline] diffs on edit.
+  pub async fn get_document_outline(&self, doc_id: &Uuid) -> FlowyResult<DocumentOutlinePB> {
+    let document_data = self.get_document_data(doc_id).await?;
+    let outline = compute_outline(&document_data);
+    self.outline_cache.insert(*doc_id, outline.clone());
+    Ok(outline_to_pb(outline))
+  }
+
+  /// Registers an autocomplete provider for the mention popover. Called by
+  /// the integration layer once the surrounding managers exist.
+  pub fn register_mention_provider(&self, provider: Arc<dyn MentionableProvider>) {
+    if let Ok(mut providers) = self.mention_providers.write() {
+      providers.push(provider);
+    }
+  }
+
+  /// Collects autocomplete candidates for the mention popover from all
+  /// registered providers. A failing provider is skipped so e.g. an offline
+  /// member lookup doesn't hide page results.
+  pub async fn search_mentionable(&self, query: &str) -> FlowyResult<Vec<Mentionable>> {
+    let providers = self
+      .mention_providers
+      .read()
+      .map(|providers| providers.clone())
+      .unwrap_or_default();
+    let mut results = vec![];
+    for provider in providers {
+      match provider.search_mentionable(query).await {
+        Ok(items) => results.extend(items),
+        Err(err) => warn!("mention provider search failed: {}", err),
+      }
+    }
+    Ok(results)
+  }
+
+  /// Creates the inline data for a new mention with a stable id. User
+  /// mentions are recorded so notifications can be generated later.
+  pub fn create_mention(&self, params: CreateMentionParams) -> MentionPB {
+    let mention_id = Uuid::new_v4().to_string();
+    if params.ty == MentionType::User {
+      if let Ok(mut mentions) = self.recorded_user_mentions.lock() {
+        mentions.push(RecordedUserMention {
+          mention_id: mention_id.clone(),
+          document_id: params.document_id,
+          block_id: params.block_id,
+          mentioned_user_id: params.value.clone(),
+          created_at: timestamp(),
+        });
+      }
+    }
+    MentionPB {
+      mention_id,
+      ty: params.ty.into(),
+      value: params.value,
+    }
+  }
+
+  /// Drains the user mentions recorded since the last call.
+  pub fn take_recorded_user_mentions(&self) -> Vec<RecordedUserMention> {
+    self
+      .recorded_user_mentions
+      .lock()
+      .map(|mut mentions| std::mem::take(&mut *mentions))
+      .unwrap_or_default()
+  }
+
+  /// Sets the persistence for comment threads. Called by the integration
+  /// layer once the surrounding services exist.
+  pub fn set_comment_store(&self, store: Arc<dyn DocumentCommentStore>) {
+    if let Ok(mut comment_store) = self.comment_store.write() {
+      *comment_store = Some(store);
+    }
+  }
+
+  fn comment_store(&self) -> Option<Arc<dyn DocumentCommentStore>> {
+    self
+      .comment_store
+      .read()
+      .ok()
+      .and_then(|store| store.clone())
+  }
+
+  /// Returns the comment threads of the document with their anchors
+  /// re-resolved against the current text.
+  pub async fn list_comment_threads(&self, doc_id: &Uuid) -> FlowyResult<Vec<CommentThreadPB>> {
+    let threads = self.comment_threads(doc_id).await?;
+    Ok(threads.into_iter().map(Into::into).collect())
+  }
+
+  /// Returns the number of unresolved comment threads of the document.
+  pub async fn unresolved_comment_count(&self, doc_id: &Uuid) -> FlowyResult<i64> {
+    let threads = self.comment_threads(doc_id).await?;
+    Ok(threads.iter().filter(|thread| !thread.resolved).count() as i64)
+  }
+
+  /// Creates a comment thread anchored to `start..end` of the block's text,
+  /// with the given content as its first comment.
+  pub async fn create_comment_thread(
+    &self,
+    params: CreateCommentThreadParams,
+  ) -> FlowyResult<CommentThreadPB> {
+    let doc_id = params.document_id;
+    let block_text = self.block_text(&doc_id, &params.block_id).await?;
+    let chars: Vec<char> = block_text.chars().collect();
+    if params.end > chars.len() {
+      return Err(FlowyError::out_of_bounds().with_context("The commented range is out of bounds"));
+    }
+    let snippet: String = chars[params.start..params.end].iter().collect();
+    let now = timestamp();
+    let thread = CommentThread {
+      thread_id: Uuid::new_v4().to_string(),
+      anchor: CommentAnchor {
+        block_id: params.block_id,
+        start: params.start,
+        end: params.end,
+        snippet,
+      },
+      resolved: false,
+      comments: vec![Comment {
+        comment_id: Uuid::new_v4().to_string(),
+        author_id: self.user_service.user_id()?,
+        content: params.content,
+        created_at: now,
+        updated_at: now,
+      }],
+    };
+    let mut threads = self.comment_threads(&doc_id).await?;
+    threads.push(thread.clone());
+    self.persist_comment_threads(&doc_id, threads);
+    Ok(thread.into())
+  }
+
+  /// Appends a reply to an existing comment thread.
+  pub async fn add_comment(&self, params: AddCommentParams) -> FlowyResult<CommentPB> {
+    let doc_id = params.document_id;
+    let mut threads = self.comment_threads(&doc_id).await?;
+    let thread = threads
+      .iter_mut()
+      .find(|thread| thread.thread_id == params.thread_id)
+      .ok_or_else(FlowyError::record_not_found)?;
+    let now = timestamp();
+    let comment = Comment {
+      comment_id: Uuid::new_v4().to_string(),
+      author_id: self.user_service.user_id()?,
+      content: params.content,
+      created_at: now,
+      updated_at: now,
+    };
+    thread.comments.push(comment.clone());
+    self.persist_comment_threads(&doc_id, threads);
+    Ok(comment.into())
+  }
+
+  /// Updates the content of a comment. Only the author can edit it.
+  pub async fn update_comment(&self, params: UpdateCommentParams) -> FlowyResult<()> {
+    let doc_id = params.document_id;
+    let uid = self.user_service.user_id()?;
+    let mut threads = self.comment_threads(&doc_id).await?;
+    let comment = threads
+      .iter_mut()
+      .find(|thread| thread.thread_id == params.thread_id)
+      .and_then(|thread| {
+        thread
+          .comments
+          .iter_mut()
+          .find(|comment| comment.comment_id == params.comment_id)
+      })
+      .ok_or_else(FlowyError::record_not_found)?;
+    if comment.author_id != uid {
+      return Err(FlowyError::invalid_data().with_context("Only the author can edit a comment"));
+    }
+    comment.content = params.content;
+    comment.updated_at = timestamp();
+    self.persist_comment_threads(&doc_id, threads);
+    Ok(())
+  }
+
+  /// Deletes a comment. Deleting the last comment of a thread removes the
+  /// whole thread.
+  pub async fn delete_comment(&self, params: DeleteCommentParams) -> FlowyResult<()> {
+    let doc_id = params.document_id;
+    let uid = self.user_service.user_id()?;
+    let mut threads = self.comment_threads(&doc_id).await?;
+    let thread = threads
+      .iter_mut()
+      .find(|thread| thread.thread_id == params.thread_id)
+      .ok_or_else(FlowyError::record_not_found)?;
+    let index = thread
+      .comments
+      .iter()
+      .position(|comment| comment.comment_id == params.comment_id)
+      .ok_or_else(FlowyError::record_not_found)?;
+    if thread.comments[index].author_id != uid {
+      return Err(FlowyError::invalid_data().with_context("Only the author can delete a comment"));
+    }
+    thread.comments.remove(index);
+    threads.retain(|thread| !thread.comments.is_empty());
+    self.persist_comment_threads(&doc_id, threads);
+    Ok(())
+  }
+
+  /// Marks a comment thread as resolved, or reopens it.
+  pub async fn set_comment_thread_resolved(
+    &self,
+    params: SetCommentThreadResolvedParams,
+  ) -> FlowyResult<()> {
+    let doc_id = params.document_id;
+    let mut threads = self.comment_threads(&doc_id).await?;
+    let thread = threads
+      .iter_mut()
+      .find(|thread| thread.thread_id == params.thread_id)
+      .ok_or_else(FlowyError::record_not_found)?;
+    thread.resolved = params.resolved;
+    self.persist_comment_threads(&doc_id, threads);
+    Ok(())
+  }
+
+  /// Loads the threads from the cache or the store and re-resolves their
+  /// anchors against the document's current text, so threads follow the text
+  /// they were attached to through edits.
+  async fn comment_threads(&self, doc_id: &Uuid) -> FlowyResult<Vec<CommentThread>> {
+    let mut threads = match self.comment_cache.get(doc_id) {
+      Some(entry) => entry.value().clone(),
+      None => match self.comment_store() {
+        Some(store) => store.load_threads(doc_id.to_string().as_str())?,
+        None => vec![],
+      },
+    };
+    if !threads.is_empty() {
+      if let Ok(document_data) = self.get_document_data(doc_id).await {
+        for thread in threads.iter_mut() {
+          let block_text = get_delta_for_block(&thread.anchor.block_id, &document_data)
+            .map(|delta| delta_to_text(&delta))
+            .unwrap_or_default();
+          resolve_anchor(&mut thread.anchor, &block_text);
+        }
+      }
+      self.comment_cache.insert(*doc_id, threads.clone());
+    }
+    Ok(threads)
+  }
+
+  /// Stores the updated threads and notifies subscribers.
+  fn persist_comment_threads(&self, doc_id: &Uuid, threads: Vec<CommentThread>) {
+    let document_id = doc_id.to_string();
+    if let Some(store) = self.comment_store() {
+      if let Err(err) = store.save_threads(&document_id, &threads) {
+        warn!(
+          "failed to save comment threads of document {}: {}",
+          doc_id, err
+        );
+      }
+    }
+    let unresolved_count = threads.iter().filter(|thread| !thread.resolved).count() as i64;
+    self.comment_cache.insert(*doc_id, threads);
+    document_notification_builder(&document_id, DocumentNotification::DidUpdateDocumentComments)
+      .payload(DocumentCommentsChangedPB {
+        document_id: document_id.clone(),
+        unresolved_count,
+      })
+      .send();
+  }
+
+  /// A short excerpt of the text of a block, e.g. for notifications that
+  /// point at a mention inside the block.
+  pub async fn get_block_snippet(
+    &self,
+    doc_id: &Uuid,
+    block_id: &str,
+    max_len: usize,
+  ) -> FlowyResult<String> {
+    let text = self.block_text(doc_id, block_id).await?;
+    if text.chars().count() <= max_len {
+      return Ok(text);
+    }
+    Ok(format!(
+      "{}…",
+      text.chars().take(max_len).collect::<String>().trim_end()
+    ))
+  }
+
+  async fn block_text(&self, doc_id: &Uuid, block_id: &str) -> FlowyResult<String> {
+    let document_data = self.get_document_data(doc_id).await?;
+    if !document_data.blocks.contains_key(block_id) {
+      return Err(FlowyError::record_not_found().with_context("The block doesn't exist"));
+    }
+    Ok(
+      get_delta_for_block(block_id, &document_data)
+        .map(|delta| delta_to_text(&delta))
+        .unwrap_or_default(),
+    )
+  }
+
+  /// Sets the persistence for document templates. Called by the integration
+  /// layer once the surrounding services exist.
+  pub fn set_template_store(&self, store: Arc<dyn DocumentTemplateStore>) {
+    if let Ok(mut template_store) = self.template_store.write() {
+      *template_store = Some(store);
+    }
+  }
+
+  fn template_store(&self) -> FlowyResult<Arc<dyn DocumentTemplateStore>> {
+    self
+      .template_store
+      .read()
+      .ok()
+      .and_then(|store| store.clone())
+      .ok_or_else(|| FlowyError::internal().with_context("The template store is not set"))
+  }
+
+  /// Stores the document's current content as a template and returns its
+  /// metadata.
+  pub async fn save_document_as_template(
+    &self,
+    params: SaveAsTemplateParams,
+  ) -> FlowyResult<DocumentTemplatePB> {
+    let store = self.template_store()?;
+    let data = self.get_document_data(&params.document_id).await?;
+    let template = DocumentTemplate {
+      template_id: Uuid::new_v4().to_string(),
+      name: params.name,
+      description: params.description,
+      icon: params.icon,
+      data,
+      created_at: timestamp(),
+    };
+    let mut templates = store.load_templates()?;
+    templates.push(template.clone());
+    store.save_templates(&templates)?;
+    Ok(template.into())
+  }
+
+  /// Returns the metadata of all stored templates.
+  pub fn list_document_templates(&self) -> FlowyResult<Vec<DocumentTemplatePB>> {
+    let templates = self.template_store()?.load_templates()?;
+    Ok(templates.into_iter().map(Into::into).collect())
+  }
+
+  pub fn delete_document_template(&self, template_id: &str) -> FlowyResult<()> {
+    let store = self.template_store()?;
+    let mut templates = store.load_templates()?;
+    let len = templates.len();
+    templates.retain(|template| template.template_id != template_id);
+    if templates.len() == len {
+      return Err(FlowyError::record_not_found());
+    }
+    store.save_templates(&templates)
+  }
+
+  /// Creates a new document from a template, substituting the placeholder
+  /// variables, and returns its encoded collab together with the initial
+  /// cursor position taken from `{{cursor}}`.
+  pub async fn create_document_from_template(
+    &self,
+    uid: i64,
+    doc_id: &Uuid,
+    template_id: &str,
+    author: Option<&str>,
+  ) -> FlowyResult<(EncodedCollab, TemplateCursor)> {
+    let template = self
+      .template_store()?
+      .load_templates()?
+      .into_iter()
+      .find(|template| template.template_id == template_id)
+      .ok_or_else(|| {
+        FlowyError::record_not_found().with_context(format!("Template {} not found", template_id))
+      })?;
+    let mut data = template.data;
+    let cursor = substitute_variables(&mut data, author);
+    let encoded_collab = self.create_document(uid, doc_id, Some(data)).await?;
+    Ok((encoded_collab, cursor))
+  }
+
+  /// Downloads the remote images referenced by pasted content into the
+  /// file storage and rewrites their URLs in place, so pasted pages keep
+  /// their images when the source goes away. Images that can't be fetched
+  /// keep their original URL.
+  pub async fn localize_remote_images(&self, doc_id: &Uuid, block: &mut NestedBlock) {
+    let Ok(workspace_id) = self.user_service.workspace_id() else {
+      return;
+    };
+    let mut stack = vec![block];
+    while let Some(current) = stack.pop() {
+      if current.ty == IMAGE {
+        if let Some(url) = current
+          .data
+          .get(URL)
+          .and_then(|url| url.as_str())
+          .map(ToString::to_string)
+        {
+          if url.starts_with("http://") || url.starts_with("https://") {
+            match self.download_remote_image(&workspace_id, doc_id, &url).await {
+              Ok(local_url) => {
+                current
+                  .data
+                  .insert(URL.to_string(), serde_json::Value::String(local_url));
+              },
+              Err(err) => warn!("failed to localize pasted image {}: {}", url, err),
+            }
+          }
+        }
+      }
+      stack.extend(current.children.iter_mut());
+    }
+  }
+
+  async fn download_remote_image(
+    &self,
+    workspace_id: &Uuid,
+    doc_id: &Uuid,
+    url: &str,
+  ) -> FlowyResult<String> {
+    let response = reqwest::get(url)
+      .await
+      .map_err(|err| FlowyError::http().with_context(err))?;
+    let bytes = response
+      .bytes()
+      .await
+      .map_err(|err| FlowyError::http().with_context(err))?;
+    let file_name = url
+      .rsplit('/')
+      .next()
+      .and_then(|name| name.split('?').next())
+      .filter(|name| !name.is_empty())
+      .unwrap_or("image");
+    let path = std::env::temp_dir().join(format!("{}-{}", Uuid::new_v4(), file_name));
+    tokio::fs::write(&path, &bytes).await?;
+    let path = path
+      .to_str()
+      .ok_or_else(|| FlowyError::internal().with_context("Invalid temporary file path"))?
+      .to_string();
+    let upload = self
+      .upload_file(workspace_id.to_string(), &doc_id.to_string(), &path)
+      .await?;
+    Ok(upload.url)
+  }
+
+  /// Recomputes the outline after an edit: mirrors it into the document's
+  /// table-of-contents blocks and emits a diff notification when the heading
+  /// hierarchy of a document whose outline was requested before changed.
+  async fn refresh_outline(&self, doc_id: &Uuid) {
+    let old_outline = self
+      .outline_cache
+      .get(doc_id)
+      .map(|entry| entry.value().clone());
+    let Ok(document_data) = self.get_document_data(doc_id).await else {
+      return;
+    };
+    let has_toc_block = document_data.blocks.values().any(|block| block.ty == OUTLINE);
+    if old_outline.is_none() && !has_toc_block {
+      return;
+    }
+    let new_outline = compute_outline(&document_data);
+    if has_toc_block {
+      if let Err(err) = self
+        .sync_outline_blocks(doc_id, &document_data, &new_outline)
+        .await
+      {
+        warn!(
+          "failed to refresh outline blocks of document {}: {}",
+          doc_id, err
+        );
+      }
+    }
+    if let Some(old_outline) = old_outline {
+      let document_id = doc_id.to_string();
+      if let Some(diff) = diff_outline(&document_id, &old_outline, &new_outline) {
+        self.outline_cache.insert(*doc_id, new_outline);
+        document_notification_builder(&document_id, DocumentNotification::DidUpdateDocumentOutline)
+          .payload(diff)
+          .send();
+      }
+    }
+  }
+
+  /// Writes the computed outline into the data of every table-of-contents
+  /// block whose stored items are stale. Because the items live in the
+  /// document data, publish and export paths render the block without
+  /// needing the live editor.
+  async fn sync_outline_blocks(
+    &self,
+    doc_id: &Uuid,
+    document_data: &DocumentData,
+    outline: &[OutlineItem],
+  ) -> FlowyResult<()> {
+    let items = outline_items_to_value(outline);
+    let actions = document_data
+      .blocks
+      .values()
+      .filter(|block| block.ty == OUTLINE && block.data.get(OUTLINE_ITEMS) != Some(&items))
+      .map(|block| {
+        let mut block = block.clone();
+        block.data.insert(OUTLINE_ITEMS.to_string(), items.clone());
+        BlockAction {
+          action: BlockActionType::Update,
+          payload: BlockActionPayload {
+            parent_id: Some(block.parent.clone()),
+            block: Some(block),
+            prev_id: None,
+            text_id: None,
+            delta: None,
+          },
+        }
+      })
+      .collect::<Vec<_>>();
+    if actions.is_empty() {
+      return Ok(());
+    }
+    let document = self.editable_document(doc_id).await?;
+    document.write().await.apply_action(actions)?;
+    Ok(())
+  }
+
+  /// Renders a LaTeX equation to a standalone SVG, serving unchanged
+  /// equations from the content-hash cache so repeated exports and publishes
+  /// stay responsive.
+  pub fn render_equation(&self, latex: &str) -> String {
+    let hash = latex_content_hash(latex);
+    if let Some(svg) = self.equation_svg_cache.get(&hash) {
+      return svg.clone();
+    }
+    let svg = render_latex_to_svg(latex);
+    self.equation_svg_cache.insert(hash, svg.clone());
+    svg
+  }
+
+  /// Exports the document as a standalone HTML file in `output_dir` and
+  /// returns the path of the written file. Referenced local images and
+  /// attachments are copied into an assets folder next to the page, and
+  /// `child_links` are rendered as a "Subpages" section so a whole view
+  /// subtree can be exported into linked files.
+  pub async fn export_document_as_html(
+    &self,
+    doc_id: &Uuid,
+    name: &str,
+    child_links: Vec<HtmlChildLink>,
+    output_dir: &Path,
+  ) -> FlowyResult<PathBuf> {
+    let document_data = self.get_document_data(doc_id).await?;
+    let name = name.to_string();
+    let output_dir = output_dir.to_path_buf();
+    let path = tokio::task::spawn_blocking(move || {
+      export_to_html(document_data, &name, &child_links, &output_dir)
+    })
+    .await??;
+    Ok(path)
+  }
+
   #[instrument(level = "debug", skip_all, err)]
   pub async fn upload_file(
     &self,
@@ -523,3 +1286,17 @@ async fn doc_state_from_document_data(
   .await??;
   Ok(encoded_collab)
 }
+
+/// Decodes a stored doc state into the document's data.
+fn decode_document_data(doc_id: &str, doc_state: Vec<u8>) -> FlowyResult<DocumentData> {
+  let collab = Collab::new_with_source(
+    CollabOrigin::Empty,
+    doc_id,
+    DataSource::DocStateV1(doc_state),
+    vec![],
+    false,
+  )
+  .map_err(internal_error)?;
+  let document = Document::open(collab).map_err(internal_error)?;
+  document.get_document_data().map_err(internal_error)
+}
@@ -0,0 +1,45 @@
+use lib_infra::async_trait::async_trait;
+
+use flowy_error::FlowyResult;
+use uuid::Uuid;
+
+/// What a mention points at. `User` and `Page` results come from the
+/// registered [MentionableProvider]s; `Date` mentions are created directly
+/// from a date picker and are never searched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MentionType {
+  User,
+  Page,
+  Date,
+}
+
+/// A single autocomplete candidate returned by [MentionableProvider::search_mentionable].
+#[derive(Debug, Clone)]
+pub struct Mentionable {
+  /// Identifier of the mentioned entity: user email, view id or similar.
+  pub id: String,
+  pub name: String,
+  pub ty: MentionType,
+  pub icon: Option<String>,
+}
+
+/// Supplies autocomplete candidates for the mention popover. Providers are
+/// registered on the [DocumentManager](crate::manager::DocumentManager) by the
+/// integration layer, e.g. one for workspace members and one for pages.
+#[async_trait]
+pub trait MentionableProvider: Send + Sync {
+  async fn search_mentionable(&self, query: &str) -> FlowyResult<Vec<Mentionable>>;
+}
+
+/// A user mention that was inserted into a document, kept so notifications
+/// for the mentioned user can be generated later.
+#[derive(Debug, Clone)]
+pub struct RecordedUserMention {
+  /// Stable id of the mention, shared with the inline data in the document.
+  pub mention_id: String,
+  pub document_id: Uuid,
+  pub block_id: String,
+  /// Identifier of the mentioned user, as returned by the provider.
+  pub mentioned_user_id: String,
+  pub created_at: i64,
+}
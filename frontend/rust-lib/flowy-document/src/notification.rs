@@ -12,6 +12,8 @@ pub enum DocumentNotification {
   DidUpdateDocumentSnapshotState = 2,
   DidUpdateDocumentSyncState = 3,
   DidUpdateDocumentAwarenessState = 4,
+  DidUpdateDocumentOutline = 5,
+  DidUpdateDocumentComments = 6,
 }
 
 impl std::convert::From<DocumentNotification> for i32 {
@@ -26,6 +28,8 @@ impl std::convert::From<i32> for DocumentNotification {
       2 => DocumentNotification::DidUpdateDocumentSnapshotState,
       3 => DocumentNotification::DidUpdateDocumentSyncState,
       4 => DocumentNotification::DidUpdateDocumentAwarenessState,
+      5 => DocumentNotification::DidUpdateDocumentOutline,
+      6 => DocumentNotification::DidUpdateDocumentComments,
       _ => DocumentNotification::Unknown,
     }
   }
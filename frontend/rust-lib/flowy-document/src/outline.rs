@@ -0,0 +1,122 @@
+use collab_document::blocks::DocumentData;
+use serde_json::{Value, json};
+
+use crate::entities::{DocumentOutlineDiffPB, DocumentOutlineItemPB, DocumentOutlinePB};
+use crate::parser::constant::{HEADING, LEVEL, TEXT};
+use crate::parser::utils::{delta_to_text, get_delta_for_block};
+
+/// A single heading of a document, in document order. Cached by the manager so
+/// edits can be turned into outline diffs for subscribers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OutlineItem {
+  pub block_id: String,
+  pub text: String,
+  pub level: usize,
+}
+
+impl From<OutlineItem> for DocumentOutlineItemPB {
+  fn from(item: OutlineItem) -> Self {
+    Self {
+      block_id: item.block_id,
+      text: item.text,
+      level: item.level as i64,
+    }
+  }
+}
+
+/// Extracts the heading hierarchy from the document data, in document order.
+pub(crate) fn compute_outline(document_data: &DocumentData) -> Vec<OutlineItem> {
+  let mut items = vec![];
+  collect_outline_items(document_data, &document_data.page_id, &mut items);
+  items
+}
+
+fn collect_outline_items(
+  document_data: &DocumentData,
+  block_id: &str,
+  items: &mut Vec<OutlineItem>,
+) {
+  let Some(block) = document_data.blocks.get(block_id) else {
+    return;
+  };
+  if block.ty == HEADING {
+    let level = block
+      .data
+      .get(LEVEL)
+      .and_then(|level| level.as_u64())
+      .unwrap_or(1) as usize;
+    let text = get_delta_for_block(block_id, document_data)
+      .map(|delta| delta_to_text(&delta))
+      .unwrap_or_default();
+    items.push(OutlineItem {
+      block_id: block_id.to_string(),
+      text,
+      level,
+    });
+  }
+  if let Some(children) = document_data.meta.children_map.get(&block.children) {
+    for child_id in children {
+      collect_outline_items(document_data, child_id, items);
+    }
+  }
+}
+
+/// Serializes the outline into the value stored in a table-of-contents
+/// block's data, so publish and export paths can render the block straight
+/// from the document data without recomputing the outline.
+pub(crate) fn outline_items_to_value(items: &[OutlineItem]) -> Value {
+  Value::Array(
+    items
+      .iter()
+      .map(|item| {
+        json!({
+          "block_id": item.block_id,
+          (TEXT): item.text,
+          (LEVEL): item.level,
+        })
+      })
+      .collect(),
+  )
+}
+
+pub(crate) fn outline_to_pb(items: Vec<OutlineItem>) -> DocumentOutlinePB {
+  DocumentOutlinePB {
+    items: items.into_iter().map(Into::into).collect(),
+  }
+}
+
+/// Compares two outlines and returns the diff, or `None` when nothing changed.
+pub(crate) fn diff_outline(
+  document_id: &str,
+  old: &[OutlineItem],
+  new: &[OutlineItem],
+) -> Option<DocumentOutlineDiffPB> {
+  if old == new {
+    return None;
+  }
+  let mut inserted = vec![];
+  let mut updated = vec![];
+  for item in new {
+    match old.iter().find(|old_item| old_item.block_id == item.block_id) {
+      None => inserted.push(item.clone().into()),
+      Some(old_item) if old_item != item => updated.push(item.clone().into()),
+      Some(_) => {},
+    }
+  }
+  let removed_block_ids = old
+    .iter()
+    .filter(|item| !new.iter().any(|new_item| new_item.block_id == item.block_id))
+    .map(|item| item.block_id.clone())
+    .collect::<Vec<_>>();
+  if inserted.is_empty() && updated.is_empty() && removed_block_ids.is_empty() {
+    // Same headings in a different order; resend them all so the client can
+    // rebuild the sidebar.
+    updated = new.iter().cloned().map(Into::into).collect();
+  }
+  Some(DocumentOutlineDiffPB {
+    document_id: document_id.to_string(),
+    inserted,
+    updated,
+    removed_block_ids,
+  })
+}
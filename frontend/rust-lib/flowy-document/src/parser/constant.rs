@@ -23,8 +23,19 @@ pub const TOGGLE_LIST: &str = "toggle_list";
 pub const QUOTE: &str = "quote";
 pub const CALLOUT: &str = "callout";
 pub const IMAGE: &str = "image";
+pub const FILE: &str = "file";
+pub const NAME: &str = "name";
 pub const DIVIDER: &str = "divider";
 pub const MATH_EQUATION: &str = "math_equation";
+pub const OUTLINE: &str = "outline";
+pub const OUTLINE_ITEMS: &str = "items";
+pub const TEXT: &str = "text";
+pub const TABLE: &str = "table";
+pub const TABLE_CELL: &str = "table/cell";
+pub const ROW_POSITION: &str = "rowPosition";
+pub const COL_POSITION: &str = "colPosition";
+pub const ROWS_LEN: &str = "rowsLen";
+pub const COLS_LEN: &str = "colsLen";
 pub const BOLD: &str = "bold";
 pub const ITALIC: &str = "italic";
 pub const STRIKETHROUGH: &str = "strikethrough";
@@ -100,6 +111,13 @@ pub const MENU_TAG_NAME: &str = "menu";
 
 pub const MARK_TAG_NAME: &str = "mark";
 
+pub const NAV_TAG_NAME: &str = "nav";
+
+pub const TABLE_TAG_NAME: &str = "table";
+pub const TR_TAG_NAME: &str = "tr";
+pub const TD_TAG_NAME: &str = "td";
+pub const TH_TAG_NAME: &str = "th";
+
 pub const FONT_WEIGHT: &str = "font-weight";
 pub const FONT_STYLE: &str = "font-style";
 pub const TEXT_DECORATION: &str = "text-decoration";
@@ -119,6 +137,8 @@ pub const FONT_FAMILY_FANTASY: &str = "font-family: fantasy;";
 pub const SRC: &str = "src";
 pub const HREF: &str = "href";
 pub const ROLE: &str = "role";
+pub const NOTE: &str = "note";
+pub const OPEN: &str = "open";
 pub const CHECKBOX: &str = "checkbox";
 pub const ARIA_CHECKED: &str = "aria-checked";
 pub const CLASS: &str = "class";
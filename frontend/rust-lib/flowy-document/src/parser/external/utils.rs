@@ -152,6 +152,7 @@ fn flatten_element_to_json(
     },
     PRE_TAG_NAME => process_code_element(node),
     IMG_TAG_NAME => process_image_element(node),
+    TABLE_TAG_NAME => process_table_element(node),
     B_TAG_NAME => {
       // Compatible with Google Docs, <b id=xxx> is the document top level tag, so we need to process it's children
       let id = find_attribute_value(node.to_owned(), "id");
@@ -231,6 +232,58 @@ fn process_code_element(node: ElementRef) -> Option<JSONResult> {
 }
 
 // process "ul" | "ol" | "dl" | "menu" element
+// process the table element, Example: <table><tr><td>1</td><td>2</td></tr></table>
+// the rows can be wrapped in <thead> or <tbody>, and the cells can be <td> or <th>
+fn process_table_element(node: ElementRef) -> Option<JSONResult> {
+  let mut cells = vec![];
+  let mut rows_len = 0;
+  let mut cols_len = 0;
+  let rows = node
+    .descendants()
+    .filter_map(ElementRef::wrap)
+    .filter(|element| get_tag_name(*element) == TR_TAG_NAME);
+  for (row_position, row) in rows.enumerate() {
+    let mut col_position = 0;
+    for cell in row.children().filter_map(ElementRef::wrap) {
+      let tag_name = get_tag_name(cell);
+      if tag_name != TD_TAG_NAME && tag_name != TH_TAG_NAME {
+        continue;
+      }
+      let (delta, children) = process_node_children(cell, &None, None);
+      let mut paragraph_data = HashMap::new();
+      if !delta.is_empty() {
+        paragraph_data.insert(DELTA.to_string(), delta_to_json(&delta));
+      }
+      let mut cell_data = HashMap::new();
+      cell_data.insert(ROW_POSITION.to_string(), Value::from(row_position));
+      cell_data.insert(COL_POSITION.to_string(), Value::from(col_position));
+      cells.push(NestedBlock {
+        ty: TABLE_CELL.to_string(),
+        data: cell_data,
+        children: vec![NestedBlock {
+          ty: PARAGRAPH.to_string(),
+          data: paragraph_data,
+          children,
+        }],
+      });
+      col_position += 1;
+    }
+    cols_len = cols_len.max(col_position);
+    rows_len = row_position + 1;
+  }
+  if cells.is_empty() {
+    return None;
+  }
+  let mut data = HashMap::new();
+  data.insert(ROWS_LEN.to_string(), Value::from(rows_len));
+  data.insert(COLS_LEN.to_string(), Value::from(cols_len));
+  Some(JSONResult::Block(NestedBlock {
+    ty: TABLE.to_string(),
+    data,
+    children: cells,
+  }))
+}
+
 fn process_nested_element(node: ElementRef) -> Option<JSONResult> {
   let tag_name = get_tag_name(node.to_owned());
 
@@ -315,20 +315,27 @@ impl NestedBlock {
           self.to_owned(),
         )));
       },
-      // <aside>😁Hello</aside>
+      // <aside role="note"><span>😁</span>Hello</aside>
       CALLOUT => {
         html.push_str(&format!(
-          "<{}>{}{}</{}>",
+          "<{} {}=\"{}\"><{}>{}</{}>{}",
           ASIDE_TAG_NAME,
+          ROLE,
+          NOTE,
+          SPAN_TAG_NAME,
           self
             .data
             .get(ICON)
             .unwrap_or(&Value::Null)
             .to_string()
             .trim_matches('\"'),
+          SPAN_TAG_NAME,
           text_html,
-          ASIDE_TAG_NAME
         ));
+        html.push_str(&convert_nested_block_children_to_html(Arc::new(
+          self.to_owned(),
+        )));
+        html.push_str(&format!("</{}>", ASIDE_TAG_NAME));
       },
       // <img src="https://www.google.com/images/branding/googlelogo/2x/googlelogo_color_272x92dp.png" alt="Google Logo" />
       IMAGE => {
@@ -339,10 +346,44 @@ impl NestedBlock {
           "AppFlowy-Image"
         ));
       },
+      // <p><a href="assets/report.pdf">report.pdf</a></p>
+      FILE => {
+        let url = self
+          .data
+          .get(URL)
+          .unwrap_or(&Value::Null)
+          .to_string()
+          .trim_matches('\"')
+          .to_string();
+        let name = self
+          .data
+          .get(NAME)
+          .and_then(|name| name.as_str())
+          .unwrap_or(url.as_str())
+          .to_string();
+        html.push_str(&format!(
+          "<{}><{} {}=\"{}\">{}</{}></{}>",
+          P_TAG_NAME, A_TAG_NAME, HREF, url, name, A_TAG_NAME, P_TAG_NAME
+        ));
+      },
       // <hr />
       DIVIDER => {
         html.push_str(&format!("<{} />", HR_TAG_NAME));
       },
+      // <nav class="toc"><ul><li>Hello</li></ul></nav>
+      OUTLINE => {
+        html.push_str(&format!(
+          "<{} {}=\"toc\"><{}>",
+          NAV_TAG_NAME, CLASS, UL_TAG_NAME
+        ));
+        if let Some(items) = self.data.get(OUTLINE_ITEMS).and_then(|items| items.as_array()) {
+          for item in items {
+            let item_text = item.get(TEXT).and_then(|text| text.as_str()).unwrap_or("");
+            html.push_str(&format!("<{}>{}</{}>", LI_TAG_NAME, item_text, LI_TAG_NAME));
+          }
+        }
+        html.push_str(&format!("</{}></{}>", UL_TAG_NAME, NAV_TAG_NAME));
+      },
       // <p>$$x = {-b \pm \sqrt{b^2-4ac} \over 2a}.$$</p>
       MATH_EQUATION => {
         let formula = self.data.get(FORMULA).unwrap_or(&Value::Null);
@@ -368,9 +409,18 @@ impl NestedBlock {
           PRE_TAG_NAME
         ));
       },
-      // <details><summary>Hello</summary><p>World!</p></details>
+      // <details open><summary>Hello</summary><p>World!</p></details>
       TOGGLE_LIST => {
-        html.push_str(&format!("<{}>", DETAILS_TAG_NAME));
+        let collapsed = self
+          .data
+          .get(COLLAPSED)
+          .and_then(|v| v.as_bool())
+          .unwrap_or_default();
+        if collapsed {
+          html.push_str(&format!("<{}>", DETAILS_TAG_NAME));
+        } else {
+          html.push_str(&format!("<{} {}>", DETAILS_TAG_NAME, OPEN));
+        }
         html.push_str(&format!(
           "<{}>{}</{}>",
           SUMMARY_TAG_NAME, text_html, SUMMARY_TAG_NAME
@@ -476,6 +526,15 @@ impl NestedBlock {
         let formula = self.data.get(FORMULA).unwrap_or(&Value::Null);
         text.push_str(&format!("{}\n", formula.to_string().trim_matches('\"')));
       },
+      OUTLINE => {
+        if let Some(items) = self.data.get(OUTLINE_ITEMS).and_then(|items| items.as_array()) {
+          for item in items {
+            if let Some(item_text) = item.get(TEXT).and_then(|text| text.as_str()) {
+              text.push_str(&format!("{}\n", item_text));
+            }
+          }
+        }
+      },
       PAGE => {
         if !delta_text.is_empty() {
           text.push_str(&format!("{}\n", delta_text));
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use collab_document::blocks::DocumentData;
+
+use crate::entities::{BlockTypeCountPB, DocumentStatisticsPB};
+
+/// Average reading speed used for the estimated reading time.
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Statistics of a document, cached by the manager and invalidated on edit so
+/// an open info panel doesn't recompute them on every poll.
+#[derive(Debug, Clone)]
+pub(crate) struct DocumentStatistics {
+  pub word_count: usize,
+  pub character_count: usize,
+  /// Number of blocks per block type, e.g. "paragraph" or "todo_list".
+  pub block_counts: HashMap<String, usize>,
+  /// Estimated reading time in minutes, at least 1 for non-empty documents.
+  pub reading_time_minutes: usize,
+}
+
+impl From<DocumentStatistics> for DocumentStatisticsPB {
+  fn from(stats: DocumentStatistics) -> Self {
+    let mut block_counts = stats
+      .block_counts
+      .into_iter()
+      .map(|(ty, count)| BlockTypeCountPB {
+        ty,
+        count: count as i64,
+      })
+      .collect::<Vec<_>>();
+    block_counts.sort_by(|a, b| a.ty.cmp(&b.ty));
+    Self {
+      word_count: stats.word_count as i64,
+      character_count: stats.character_count as i64,
+      block_counts,
+      reading_time_minutes: stats.reading_time_minutes as i64,
+    }
+  }
+}
+
+/// Computes the statistics from the document's plain text and its block data.
+pub(crate) fn compute_statistics(text: &str, document_data: &DocumentData) -> DocumentStatistics {
+  let word_count = text.split_whitespace().count();
+  let character_count = text.chars().count();
+  let mut block_counts: HashMap<String, usize> = HashMap::new();
+  for block in document_data.blocks.values() {
+    *block_counts.entry(block.ty.clone()).or_default() += 1;
+  }
+  let reading_time_minutes = if word_count == 0 {
+    0
+  } else {
+    word_count.div_ceil(WORDS_PER_MINUTE)
+  };
+  DocumentStatistics {
+    word_count,
+    character_count,
+    block_counts,
+    reading_time_minutes,
+  }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use collab_document::blocks::DocumentData;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use flowy_error::FlowyResult;
+
+/// Replaced by the current date when a template is instantiated.
+pub const DATE_VARIABLE: &str = "{{date}}";
+/// Replaced by the author's name when a template is instantiated.
+pub const AUTHOR_VARIABLE: &str = "{{author}}";
+/// Removed on instantiation; its position becomes the initial cursor.
+pub const CURSOR_VARIABLE: &str = "{{cursor}}";
+
+/// Key in the create-view meta carrying the template to instantiate.
+pub const DOCUMENT_TEMPLATE_ID_META_KEY: &str = "template_id";
+/// Key in the create-view meta carrying the author name for `{{author}}`.
+pub const DOCUMENT_TEMPLATE_AUTHOR_META_KEY: &str = "template_author";
+
+/// A locally stored document template. The document data is kept as-is and
+/// the placeholder variables are substituted on instantiation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentTemplate {
+  pub template_id: String,
+  pub name: String,
+  pub description: String,
+  pub icon: Option<String>,
+  pub data: DocumentData,
+  pub created_at: i64,
+}
+
+/// Persistence for document templates, implemented by the integration layer
+/// on top of the local key-value store.
+pub trait DocumentTemplateStore: Send + Sync {
+  fn load_templates(&self) -> FlowyResult<Vec<DocumentTemplate>>;
+  fn save_templates(&self, templates: &[DocumentTemplate]) -> FlowyResult<()>;
+}
+
+/// Where the cursor should be placed after instantiating a template,
+/// determined by the `{{cursor}}` variable.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateCursor {
+  pub block_id: Option<String>,
+  /// Offset inside the block's text, in characters.
+  pub offset: usize,
+}
+
+/// Substitutes the placeholder variables in the template data. `{{date}}` is
+/// replaced by the current date, `{{author}}` by the given author (left
+/// untouched when unknown) and the first `{{cursor}}` is removed, with its
+/// position returned as the initial cursor.
+pub(crate) fn substitute_variables(
+  data: &mut DocumentData,
+  author: Option<&str>,
+) -> TemplateCursor {
+  let date = chrono::Local::now().format("%Y-%m-%d").to_string();
+  let mut cursor = TemplateCursor::default();
+
+  let block_by_text_id: HashMap<String, String> = data
+    .blocks
+    .iter()
+    .filter_map(|(block_id, block)| {
+      block
+        .external_id
+        .as_ref()
+        .map(|text_id| (text_id.clone(), block_id.clone()))
+    })
+    .collect();
+
+  let Some(text_map) = data.meta.text_map.as_mut() else {
+    return cursor;
+  };
+  for (text_id, delta_json) in text_map.iter_mut() {
+    let Ok(Value::Array(mut ops)) = serde_json::from_str::<Value>(delta_json) else {
+      continue;
+    };
+    let mut changed = false;
+    let mut chars_before = 0;
+    for op in ops.iter_mut() {
+      let Some(insert) = op.get("insert").and_then(|insert| insert.as_str()) else {
+        continue;
+      };
+      let mut text = insert.replace(DATE_VARIABLE, &date);
+      if let Some(author) = author {
+        text = text.replace(AUTHOR_VARIABLE, author);
+      }
+      if cursor.block_id.is_none() {
+        if let Some(index) = text.find(CURSOR_VARIABLE) {
+          let offset = text[..index].chars().count();
+          text = text.replacen(CURSOR_VARIABLE, "", 1);
+          cursor = TemplateCursor {
+            block_id: block_by_text_id.get(text_id).cloned(),
+            offset: chars_before + offset,
+          };
+        }
+      }
+      if text != insert {
+        changed = true;
+        op["insert"] = Value::String(text.clone());
+      }
+      chars_before += text.chars().count();
+    }
+    if changed {
+      if let Ok(serialized) = serde_json::to_string(&ops) {
+        *delta_json = serialized;
+      }
+    }
+  }
+  cursor
+}
@@ -0,0 +1,114 @@
+use collab_document::blocks::DocumentData;
+
+use crate::entities::{BlockDiffPB, DocumentVersionDiffPB, ModifiedBlockPB, TextDiffPB};
+use crate::parser::utils::{delta_to_text, get_delta_for_block};
+
+/// Computes a structured diff between two states of a document: blocks that
+/// were added, removed or modified, with a text-level diff for modified
+/// text. Blocks are listed in document order.
+pub(crate) fn diff_document_data(old: &DocumentData, new: &DocumentData) -> DocumentVersionDiffPB {
+  let old_order = block_order(old);
+  let new_order = block_order(new);
+
+  let mut added = vec![];
+  let mut modified = vec![];
+  for block_id in &new_order {
+    let Some(new_block) = new.blocks.get(block_id) else {
+      continue;
+    };
+    let new_text = block_text(block_id, new);
+    match old.blocks.get(block_id) {
+      None => added.push(BlockDiffPB {
+        block_id: block_id.clone(),
+        ty: new_block.ty.clone(),
+        text: new_text,
+      }),
+      Some(old_block) => {
+        let old_text = block_text(block_id, old);
+        let data_changed = old_block.ty != new_block.ty || old_block.data != new_block.data;
+        let text_diff = diff_text(&old_text, &new_text);
+        if data_changed || text_diff.is_some() {
+          modified.push(ModifiedBlockPB {
+            block_id: block_id.clone(),
+            ty: new_block.ty.clone(),
+            text_diff,
+            data_changed,
+          });
+        }
+      },
+    }
+  }
+
+  let removed = old_order
+    .iter()
+    .filter(|block_id| !new.blocks.contains_key(*block_id))
+    .filter_map(|block_id| {
+      old.blocks.get(block_id).map(|block| BlockDiffPB {
+        block_id: block_id.clone(),
+        ty: block.ty.clone(),
+        text: block_text(block_id, old),
+      })
+    })
+    .collect();
+
+  DocumentVersionDiffPB {
+    added,
+    removed,
+    modified,
+  }
+}
+
+/// Diffs two texts into a single replacement: the changed middle after
+/// stripping the common prefix and suffix. Returns `None` when the texts are
+/// equal.
+fn diff_text(old: &str, new: &str) -> Option<TextDiffPB> {
+  if old == new {
+    return None;
+  }
+  let old_chars: Vec<char> = old.chars().collect();
+  let new_chars: Vec<char> = new.chars().collect();
+  let mut prefix = 0;
+  while prefix < old_chars.len()
+    && prefix < new_chars.len()
+    && old_chars[prefix] == new_chars[prefix]
+  {
+    prefix += 1;
+  }
+  let mut suffix = 0;
+  while suffix < old_chars.len() - prefix
+    && suffix < new_chars.len() - prefix
+    && old_chars[old_chars.len() - 1 - suffix] == new_chars[new_chars.len() - 1 - suffix]
+  {
+    suffix += 1;
+  }
+  Some(TextDiffPB {
+    start: prefix as i64,
+    deleted: old_chars[prefix..old_chars.len() - suffix].iter().collect(),
+    inserted: new_chars[prefix..new_chars.len() - suffix].iter().collect(),
+  })
+}
+
+/// Returns the block ids of the document in document order.
+fn block_order(document_data: &DocumentData) -> Vec<String> {
+  let mut order = vec![];
+  collect_block_order(document_data, &document_data.page_id, &mut order);
+  order
+}
+
+fn collect_block_order(document_data: &DocumentData, block_id: &str, order: &mut Vec<String>) {
+  let Some(block) = document_data.blocks.get(block_id) else {
+    return;
+  };
+  order.push(block_id.to_string());
+  if let Some(children) = document_data.meta.children_map.get(&block.children) {
+    for child_id in children {
+      collect_block_order(document_data, child_id, order);
+    }
+  }
+}
+
+fn block_text(block_id: &str, document_data: &DocumentData) -> String {
+  get_delta_for_block(block_id, document_data)
+    .map(|delta| delta_to_text(&delta))
+    .unwrap_or_default()
+}
@@ -0,0 +1,66 @@
+use dashmap::DashMap;
+use uuid::Uuid;
+
+/// A version snapshot is taken after this much active editing time, even when
+/// the operation threshold hasn't been reached yet.
+const VERSION_SNAPSHOT_INTERVAL_SECS: i64 = 5 * 60;
+/// A version snapshot is taken after this many applied operations.
+const VERSION_SNAPSHOT_OPS_THRESHOLD: usize = 50;
+
+#[derive(Default)]
+struct EditActivity {
+  ops_since_snapshot: usize,
+  /// When the first edit after the last snapshot was applied.
+  first_edit_at: Option<i64>,
+  /// (word count, character count) of the text at the last snapshot, used for
+  /// the next version's diff summary.
+  last_stats: Option<(usize, usize)>,
+}
+
+/// Tracks edit activity per document and decides when an automatic local
+/// version snapshot is due.
+#[derive(Default)]
+pub(crate) struct VersionHistoryRecorder {
+  activity: DashMap<Uuid, EditActivity>,
+}
+
+impl VersionHistoryRecorder {
+  /// Records one applied edit and returns true when a version snapshot is due.
+  pub fn record_edit(&self, doc_id: &Uuid, now: i64) -> bool {
+    let mut entry = self.activity.entry(*doc_id).or_default();
+    entry.ops_since_snapshot += 1;
+    let first_edit_at = *entry.first_edit_at.get_or_insert(now);
+    entry.ops_since_snapshot >= VERSION_SNAPSHOT_OPS_THRESHOLD
+      || now - first_edit_at >= VERSION_SNAPSHOT_INTERVAL_SECS
+  }
+
+  /// Marks a snapshot as taken and returns the diff summary against the text
+  /// of the previous snapshot.
+  pub fn take_summary(&self, doc_id: &Uuid, words: usize, chars: usize) -> String {
+    let mut entry = self.activity.entry(*doc_id).or_default();
+    let summary = match entry.last_stats {
+      Some((prev_words, prev_chars)) => diff_summary(prev_words, prev_chars, words, chars),
+      None => format!("{} words, {} characters", words, chars),
+    };
+    entry.ops_since_snapshot = 0;
+    entry.first_edit_at = None;
+    entry.last_stats = Some((words, chars));
+    summary
+  }
+}
+
+pub(crate) fn text_stats(text: &str) -> (usize, usize) {
+  (text.split_whitespace().count(), text.chars().count())
+}
+
+fn diff_summary(prev_words: usize, prev_chars: usize, words: usize, chars: usize) -> String {
+  if words == prev_words && chars == prev_chars {
+    "No text changes".to_string()
+  } else {
+    format!(
+      "{:+} words, {:+} characters",
+      words as i64 - prev_words as i64,
+      chars as i64 - prev_chars as i64
+    )
+  }
+}
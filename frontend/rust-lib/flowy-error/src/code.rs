@@ -410,6 +410,9 @@ pub enum ErrorCode {
 
   #[error("Invalid guest")]
   InvalidGuest = 140,
+
+  #[error("Group WIP limit exceeded")]
+  GroupWipLimitExceeded = 141,
 }
 
 impl ErrorCode {
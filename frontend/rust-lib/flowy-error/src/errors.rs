@@ -138,6 +138,7 @@ impl FlowyError {
   static_flowy_error!(out_of_bounds, ErrorCode::OutOfBounds);
   static_flowy_error!(serde, ErrorCode::Serde);
   static_flowy_error!(field_record_not_found, ErrorCode::FieldRecordNotFound);
+  static_flowy_error!(group_wip_limit_exceeded, ErrorCode::GroupWipLimitExceeded);
   static_flowy_error!(payload_none, ErrorCode::UnexpectedEmpty);
   static_flowy_error!(http, ErrorCode::NetworkError);
   static_flowy_error!(
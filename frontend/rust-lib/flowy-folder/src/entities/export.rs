@@ -0,0 +1,45 @@
+use crate::entities::parser::view::ViewIdentify;
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+use std::path::PathBuf;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, ProtoBuf, Default)]
+pub struct ExportViewToHtmlPayloadPB {
+  /// The root view of the subtree to export.
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The directory the HTML files and the assets folder are written into.
+  #[pb(index = 2)]
+  pub output_dir: String,
+}
+
+pub struct ExportViewToHtmlParams {
+  pub view_id: Uuid,
+  pub output_dir: PathBuf,
+}
+
+impl TryInto<ExportViewToHtmlParams> for ExportViewToHtmlPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ExportViewToHtmlParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)
+      .and_then(|id| Uuid::from_str(&id.0).map_err(|_| ErrorCode::InvalidParams))?;
+    if self.output_dir.is_empty() {
+      return Err(ErrorCode::InvalidParams);
+    }
+    Ok(ExportViewToHtmlParams {
+      view_id,
+      output_dir: PathBuf::from(self.output_dir),
+    })
+  }
+}
+
+#[derive(Clone, Debug, ProtoBuf, Default)]
+pub struct ExportViewHtmlFilePB {
+  /// The path of the root HTML page.
+  #[pb(index = 1)]
+  pub file_path: String,
+}
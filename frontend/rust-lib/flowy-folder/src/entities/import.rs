@@ -15,6 +15,8 @@ pub enum ImportTypePB {
   Markdown = 2,
   AFDatabase = 3,
   CSV = 4,
+  XLSX = 5,
+  DatabaseJSON = 6,
 }
 
 impl From<ImportTypePB> for ImportType {
@@ -25,6 +27,8 @@ impl From<ImportTypePB> for ImportType {
       ImportTypePB::Markdown => ImportType::Markdown,
       ImportTypePB::AFDatabase => ImportType::AFDatabase,
       ImportTypePB::CSV => ImportType::CSV,
+      ImportTypePB::XLSX => ImportType::XLSX,
+      ImportTypePB::DatabaseJSON => ImportType::DatabaseJSON,
     }
   }
 }
@@ -58,6 +62,11 @@ pub struct ImportItemPayloadPB {
   // the type of the import page
   #[pb(index = 5)]
   pub import_type: ImportTypePB,
+
+  // when set, markdown imports are split on headings of this level (1-6) and
+  // the sections become child views of the imported page
+  #[pb(index = 6, one_of)]
+  pub split_heading_level: Option<i32>,
 }
 
 #[derive(Clone, Debug, Validate, ProtoBuf, Default)]
@@ -101,11 +110,23 @@ impl TryInto<ImportParams> for ImportPayloadPB {
           },
         };
 
+        let split_heading_level = match item.split_heading_level {
+          None => None,
+          Some(level) if (1..=6).contains(&level) => Some(level as u8),
+          Some(level) => {
+            return Err(
+              FlowyError::invalid_data()
+                .with_context(format!("Invalid split heading level: {}", level)),
+            );
+          },
+        };
+
         Ok(ImportItem {
           name,
           data,
           view_layout: item.view_layout.into(),
           import_type: item.import_type.into(),
+          split_heading_level,
         })
       })
       .collect::<Result<Vec<_>, _>>()?;
@@ -192,13 +192,14 @@ pub enum ViewLayoutPB {
   Board = 2,
   Calendar = 3,
   Chat = 4,
+  Timeline = 5,
 }
 
 impl ViewLayoutPB {
   pub fn is_database(&self) -> bool {
     matches!(
       self,
-      ViewLayoutPB::Grid | ViewLayoutPB::Board | ViewLayoutPB::Calendar
+      ViewLayoutPB::Grid | ViewLayoutPB::Board | ViewLayoutPB::Calendar | ViewLayoutPB::Timeline
     )
   }
 }
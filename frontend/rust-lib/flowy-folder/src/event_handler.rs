@@ -600,3 +600,16 @@ pub(crate) async fn get_shared_view_section_handler(
   let section = folder.get_shared_view_section(&view_id).await?;
   data_result_ok(GetSharedViewSectionResponsePB { section })
 }
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn export_view_to_html_handler(
+  data: AFPluginData<ExportViewToHtmlPayloadPB>,
+  folder: AFPluginState<Weak<FolderManager>>,
+) -> DataResult<ExportViewHtmlFilePB, FlowyError> {
+  let folder = upgrade_folder(folder)?;
+  let params: ExportViewToHtmlParams = data.into_inner().try_into()?;
+  let file_path = folder
+    .export_view_as_html(&params.view_id, &params.output_dir)
+    .await?;
+  data_result_ok(ExportViewHtmlFilePB { file_path })
+}
@@ -59,6 +59,7 @@ pub fn init(folder: Weak<FolderManager>) -> AFPlugin {
     .event(FolderEvent::GetSharedUsers, get_shared_users_handler)
     .event(FolderEvent::GetSharedViews, get_shared_views_handler)
     .event(FolderEvent::GetSharedViewSection, get_shared_view_section_handler)
+    .event(FolderEvent::ExportViewToHtml, export_view_to_html_handler)
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
@@ -246,4 +247,8 @@ pub enum FolderEvent {
 
   #[event(input = "ViewIdPB", output = "GetSharedViewSectionResponsePB")]
   GetSharedViewSection = 60,
+
+  /// Export the view and its subtree as linked standalone HTML files.
+  #[event(input = "ExportViewToHtmlPayloadPB", output = "ExportViewHtmlFilePB")]
+  ExportViewToHtml = 61,
 }
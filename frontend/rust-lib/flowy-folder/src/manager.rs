@@ -16,7 +16,8 @@ use crate::publish_util::{generate_publish_name, view_pb_to_publish_view};
 use crate::share::{ImportData, ImportItem, ImportParams};
 use crate::util::{folder_not_init_error, workspace_data_not_sync_error};
 use crate::view_operation::{
-  FolderOperationHandler, FolderOperationHandlers, GatherEncodedCollab, ViewData, create_view,
+  ExportedHtmlPage, ExtensionOperationHandlers, FolderOperationHandler, FolderOperationHandlers,
+  GatherEncodedCollab, ViewData, create_view, layout_tag_from_extra,
 };
 use arc_swap::ArcSwapOption;
 use client_api::entity::guest_dto::{
@@ -56,6 +57,7 @@ use flowy_user_pub::entities::{Role, UserWorkspace};
 use futures::future;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::path::Path;
 use std::str::FromStr;
 use std::sync::{Arc, Weak};
 use tokio::sync::RwLockWriteGuard;
@@ -76,6 +78,7 @@ pub struct FolderManager {
   pub(crate) collab_builder: Arc<AppFlowyCollabBuilder>,
   pub(crate) user: Arc<dyn FolderUser>,
   pub(crate) operation_handlers: FolderOperationHandlers,
+  pub(crate) extension_handlers: ExtensionOperationHandlers,
   pub cloud_service: Weak<dyn FolderCloudService>,
   pub(crate) store_preferences: Arc<KVStorePreferences>,
   pub(crate) folder_ready_notifier: tokio::sync::watch::Sender<bool>,
@@ -100,6 +103,7 @@ impl FolderManager {
       mutex_folder: Default::default(),
       collab_builder,
       operation_handlers: Default::default(),
+      extension_handlers: Default::default(),
       cloud_service,
       store_preferences,
       folder_ready_notifier,
@@ -127,6 +131,27 @@ impl FolderManager {
     self.operation_handlers.insert(layout, handler);
   }
 
+  /// Registers an operation handler for an extension layout tag at runtime.
+  /// Views whose `extra` data carries the tag are routed to this handler,
+  /// letting experimental view types plug in without a [ViewLayout] variant.
+  pub fn register_extension_handler(
+    &self,
+    layout_tag: &str,
+    handler: Arc<dyn FolderOperationHandler + Send + Sync>,
+  ) {
+    info!("register extension handler: {}", layout_tag);
+    self
+      .extension_handlers
+      .insert(layout_tag.to_string(), handler);
+  }
+
+  /// Removes a runtime registered extension handler. Views carrying the tag
+  /// fall back to the handler of their [ViewLayout]. Returns whether a
+  /// handler was registered for the tag.
+  pub fn unregister_extension_handler(&self, layout_tag: &str) -> bool {
+    self.extension_handlers.remove(layout_tag).is_some()
+  }
+
   #[instrument(level = "debug", skip(self), err)]
   pub async fn get_current_workspace(&self) -> FlowyResult<WorkspacePB> {
     let workspace_id = self.user.workspace_id()?;
@@ -169,7 +194,12 @@ impl FolderManager {
     view_id: &Uuid,
     layout: &ViewLayout,
   ) -> FlowyResult<GatherEncodedCollab> {
-    let handler = self.get_handler(layout)?;
+    let extra = self
+      .get_view_pb(&view_id.to_string())
+      .await
+      .ok()
+      .and_then(|view| view.extra);
+    let handler = self.get_handler_with_extra(layout, extra.as_deref())?;
     let encoded_collab = handler
       .gather_publish_encode_collab(&self.user, view_id)
       .await?;
@@ -570,7 +600,7 @@ impl FolderManager {
   ) -> FlowyResult<(View, Option<EncodedCollab>)> {
     let workspace_id = self.user.workspace_id()?;
     let view_layout: ViewLayout = params.layout.clone().into();
-    let handler = self.get_handler(&view_layout)?;
+    let handler = self.get_handler_with_extra(&view_layout, params.extra.as_deref())?;
     let user_id = self.user.user_id()?;
     let mut encoded_collab: Option<EncodedCollab> = None;
 
@@ -624,7 +654,7 @@ impl FolderManager {
   ) -> FlowyResult<View> {
     let view_layout: ViewLayout = params.layout.clone().into();
     // TODO(nathan): remove orphan view. Just use for create document in row
-    let handler = self.get_handler(&view_layout)?;
+    let handler = self.get_handler_with_extra(&view_layout, params.extra.as_deref())?;
     let user_id = self.user.user_id()?;
     handler
       .create_default_view(
@@ -653,7 +683,7 @@ impl FolderManager {
         drop(folder);
 
         let view_id = Uuid::from_str(view_id)?;
-        let handler = self.get_handler(&view.layout)?;
+        let handler = self.get_handler_for_view(&view)?;
         handler.close_view(&view_id).await?;
       }
     }
@@ -1177,7 +1207,7 @@ impl FolderManager {
             .with_context(format!("Can't duplicate the view({})", view_id))
         })?;
 
-      let handler = self.get_handler(&view.layout)?;
+      let handler = self.get_handler_for_view(&view)?;
       info!(
         "{} duplicate view{}, name:{}, layout:{:?}",
         handler.name(),
@@ -1312,7 +1342,7 @@ impl FolderManager {
     let view = self.get_current_view().await;
     if let Some(view) = &view {
       let view_layout: ViewLayout = view.layout.clone().into();
-      if let Some(handle) = self.operation_handlers.get(&view_layout) {
+      if let Ok(handle) = self.get_handler_with_extra(&view_layout, view.extra.as_deref()) {
         info!("Open view: {}-{}", view.name, view.id);
         let view_id = Uuid::from_str(&view.id)?;
         if let Err(err) = handle.open_view(&view_id).await {
@@ -1853,14 +1883,14 @@ impl FolderManager {
     publish_name: Option<String>,
     layout: ViewLayout,
   ) -> FlowyResult<PublishPayload> {
-    let handler = self.get_handler(&layout)?;
+    let view_str_id = view_id.to_string();
+    let view = self.get_view_pb(&view_str_id).await?;
+
+    let handler = self.get_handler_with_extra(&layout, view.extra.as_deref())?;
     let encoded_collab_wrapper: GatherEncodedCollab = handler
       .gather_publish_encode_collab(&self.user, view_id)
       .await?;
 
-    let view_str_id = view_id.to_string();
-    let view = self.get_view_pb(&view_str_id).await?;
-
     let publish_name = publish_name.unwrap_or_else(|| generate_publish_name(&view.id, &view.name));
 
     let child_views = self
@@ -2035,7 +2065,7 @@ impl FolderManager {
 
       if let Some(view) = view {
         let view_id = Uuid::from_str(view_id)?;
-        if let Ok(handler) = self.get_handler(&view.layout) {
+        if let Ok(handler) = self.get_handler_for_view(&view) {
           handler.delete_view(&view_id).await?;
         }
       }
@@ -2054,27 +2084,34 @@ impl FolderManager {
     let handler = self.get_handler(&import_data.view_layout)?;
     let view_id = gen_view_id();
     let uid = self.user.user_id()?;
-    let mut encoded_collab = vec![];
 
     info!("import single file from:{}", import_data.data);
-    match import_data.data {
+    let (encoded_collab, child_views) = match import_data.data {
       ImportData::FilePath { file_path } => {
         handler
-          .import_from_file_path(&view_id.to_string(), &import_data.name, file_path)
-          .await?;
+          .import_from_file_path_with_children(
+            uid,
+            &view_id,
+            &import_data.name,
+            import_data.import_type,
+            file_path,
+            import_data.split_heading_level,
+          )
+          .await?
       },
       ImportData::Bytes { bytes } => {
-        encoded_collab = handler
-          .import_from_bytes(
+        handler
+          .import_from_bytes_with_children(
             uid,
             &view_id,
             &import_data.name,
             import_data.import_type,
             bytes,
+            import_data.split_heading_level,
           )
-          .await?;
+          .await?
       },
-    }
+    };
 
     let params = CreateViewParams {
       parent_view_id,
@@ -2090,12 +2127,30 @@ impl FolderManager {
       icon: None,
     };
 
-    let view = create_view(self.user.user_id()?, params, import_data.view_layout);
+    let view = create_view(self.user.user_id()?, params, import_data.view_layout.clone());
 
-    // Insert the new view into the folder
+    // Insert the new view and the child views the handler created into the
+    // folder
     if let Some(lock) = self.mutex_folder.load_full() {
       let mut folder = lock.write().await;
       folder.insert_view(view.clone(), None);
+      for child in child_views {
+        let child_params = CreateViewParams {
+          parent_view_id: view_id,
+          name: child.name,
+          layout: import_data.view_layout.clone().into(),
+          initial_data: ViewData::Empty,
+          view_id: child.view_id,
+          meta: Default::default(),
+          set_as_current: false,
+          index: None,
+          section: None,
+          extra: None,
+          icon: None,
+        };
+        let child_view = create_view(uid, child_params, import_data.view_layout.clone());
+        folder.insert_view(child_view, None);
+      }
     }
 
     Ok((view, encoded_collab))
@@ -2172,7 +2227,7 @@ impl FolderManager {
     };
 
     if let Some((Some(old_view), Some(new_view))) = value {
-      if let Ok(handler) = self.get_handler(&old_view.layout) {
+      if let Ok(handler) = self.get_handler_for_view(&old_view) {
         handler.did_update_view(&old_view, &new_view).await?;
       }
     }
@@ -2191,6 +2246,91 @@ impl FolderManager {
     Ok(())
   }
 
+  /// Exports the view and its subtree as linked standalone HTML files in
+  /// `output_dir` and returns the path of the root page. Children are
+  /// exported before their parent so every page can link to its subpages.
+  /// Views whose layout has no HTML representation are skipped, but their
+  /// children are still exported.
+  pub async fn export_view_as_html(
+    &self,
+    view_id: &Uuid,
+    output_dir: &Path,
+  ) -> FlowyResult<String> {
+    let root = {
+      let lock = self
+        .mutex_folder
+        .load_full()
+        .ok_or_else(folder_not_init_error)?;
+      let folder = lock.read().await;
+      Self::build_html_export_node(&folder, &view_id.to_string()).ok_or_else(|| {
+        FlowyError::record_not_found().with_context(format!("Can't find the view: {}", view_id))
+      })?
+    };
+
+    // Flatten the tree into post-order so children are exported before their
+    // parent.
+    let mut order = vec![];
+    let mut stack = vec![&root];
+    while let Some(node) = stack.pop() {
+      order.push(node);
+      stack.extend(node.children.iter());
+    }
+
+    let mut exported: HashMap<Uuid, ExportedHtmlPage> = HashMap::new();
+    for node in order.into_iter().rev() {
+      let child_pages = node
+        .children
+        .iter()
+        .filter_map(|child| exported.get(&child.view_id).cloned())
+        .collect::<Vec<_>>();
+      let result = match self.get_handler(&node.layout) {
+        Ok(handler) => {
+          handler
+            .export_html(&node.view_id, &node.name, child_pages, output_dir)
+            .await
+        },
+        Err(err) => Err(err),
+      };
+      if node.view_id == *view_id {
+        let path = result?;
+        return Ok(path.to_string_lossy().into_owned());
+      }
+      match result {
+        Ok(path) => {
+          if let Some(file_name) = path.file_name().and_then(|name| name.to_str()) {
+            exported.insert(
+              node.view_id,
+              ExportedHtmlPage {
+                file_name: file_name.to_string(),
+                name: node.name.clone(),
+              },
+            );
+          }
+        },
+        Err(err) => {
+          tracing::debug!("export view {} as HTML skipped: {}", node.view_id, err);
+        },
+      }
+    }
+    Err(FlowyError::not_support().with_context("The view can't be exported as HTML"))
+  }
+
+  /// Builds the subtree of views rooted at `view_id` for the HTML export.
+  fn build_html_export_node(folder: &Folder, view_id: &str) -> Option<HtmlExportNode> {
+    let view = folder.get_view(view_id)?;
+    let children = folder
+      .get_views_belong_to(view_id)
+      .into_iter()
+      .filter_map(|child| Self::build_html_export_node(folder, &child.id))
+      .collect();
+    Some(HtmlExportNode {
+      view_id: Uuid::from_str(&view.id).ok()?,
+      name: view.name.clone(),
+      layout: view.layout.clone(),
+      children,
+    })
+  }
+
   /// Returns a handler that implements the [FolderOperationHandler] trait
   fn get_handler(&self, view_layout: &ViewLayout) -> FlowyResult<Arc<dyn FolderOperationHandler>> {
     match self.operation_handlers.get(view_layout) {
@@ -2202,6 +2342,25 @@ impl FolderManager {
     }
   }
 
+  /// Like [Self::get_handler], but prefers the extension handler named by
+  /// the layout tag in the view's `extra` data when one is registered.
+  fn get_handler_with_extra(
+    &self,
+    view_layout: &ViewLayout,
+    extra: Option<&str>,
+  ) -> FlowyResult<Arc<dyn FolderOperationHandler>> {
+    if let Some(tag) = layout_tag_from_extra(extra) {
+      if let Some(handler) = self.extension_handlers.get(&tag) {
+        return Ok(handler.clone());
+      }
+    }
+    self.get_handler(view_layout)
+  }
+
+  fn get_handler_for_view(&self, view: &View) -> FlowyResult<Arc<dyn FolderOperationHandler>> {
+    self.get_handler_with_extra(&view.layout, view.extra.as_deref())
+  }
+
   fn get_folder_collab_params(
     &self,
     object_id: Uuid,
@@ -2618,6 +2777,14 @@ pub(crate) fn get_workspace_private_view_pbs(workspace_id: &Uuid, folder: &Folde
     .collect()
 }
 
+/// A view in the subtree collected for the HTML export.
+struct HtmlExportNode {
+  view_id: Uuid,
+  name: String,
+  layout: ViewLayout,
+  children: Vec<HtmlExportNode>,
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 pub enum FolderInitDataSource {
@@ -9,6 +9,8 @@ pub enum ImportType {
   Markdown = 2,
   AFDatabase = 3,
   CSV = 4,
+  XLSX = 5,
+  DatabaseJSON = 6,
 }
 
 #[derive(Clone, Debug)]
@@ -17,6 +19,9 @@ pub struct ImportItem {
   pub data: ImportData,
   pub view_layout: ViewLayout,
   pub import_type: ImportType,
+  /// When set, markdown imports are split on headings of this level and the
+  /// sections become child views of the imported page.
+  pub split_heading_level: Option<u8>,
 }
 
 #[derive(Clone, Debug)]
@@ -8,6 +8,7 @@ use collab_folder::hierarchy_builder::NestedViewBuilder;
 use dashmap::DashMap;
 use flowy_error::FlowyError;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
@@ -35,6 +36,25 @@ pub struct DatabaseEncodedCollab {
 
 pub type ImportedData = (String, CollabType, EncodedCollab);
 
+/// A child view created while importing a single file, e.g. a markdown
+/// section that was split into its own page. The folder inserts these views
+/// under the imported view.
+#[derive(Debug, Clone)]
+pub struct ImportedChildView {
+  pub view_id: Uuid,
+  pub name: String,
+}
+
+/// A page written by [FolderOperationHandler::export_html], used to link a
+/// parent page to its exported children.
+#[derive(Debug, Clone)]
+pub struct ExportedHtmlPage {
+  /// The file name of the page, relative to the export directory.
+  pub file_name: String,
+  /// The display name of the view.
+  pub name: String,
+}
+
 /// The handler will be used to handler the folder operation for a specific
 /// view layout. Each [ViewLayout] will have a handler. So when creating a new
 /// view, the [ViewLayout] will be used to get the handler.
@@ -128,15 +148,85 @@ pub trait FolderOperationHandler: Send + Sync {
     path: String,
   ) -> Result<(), FlowyError>;
 
+  /// Like [Self::import_from_bytes], but additionally reports the child views
+  /// the handler created, e.g. markdown sections split into their own pages
+  /// when `split_heading_level` is set. The default implementation creates no
+  /// children.
+  async fn import_from_bytes_with_children(
+    &self,
+    uid: i64,
+    view_id: &Uuid,
+    name: &str,
+    import_type: ImportType,
+    bytes: Vec<u8>,
+    _split_heading_level: Option<u8>,
+  ) -> Result<(Vec<ImportedData>, Vec<ImportedChildView>), FlowyError> {
+    let data = self
+      .import_from_bytes(uid, view_id, name, import_type, bytes)
+      .await?;
+    Ok((data, vec![]))
+  }
+
+  /// Like [Self::import_from_file_path], but additionally reports the child
+  /// views the handler created. The default implementation creates no
+  /// children.
+  async fn import_from_file_path_with_children(
+    &self,
+    _uid: i64,
+    view_id: &Uuid,
+    name: &str,
+    _import_type: ImportType,
+    path: String,
+    _split_heading_level: Option<u8>,
+  ) -> Result<(Vec<ImportedData>, Vec<ImportedChildView>), FlowyError> {
+    self
+      .import_from_file_path(&view_id.to_string(), name, path)
+      .await?;
+    Ok((vec![], vec![]))
+  }
+
   /// Called when the view is updated. The handler is the `old` registered handler.
   async fn did_update_view(&self, _old: &View, _new: &View) -> Result<(), FlowyError> {
     Ok(())
   }
+
+  /// Exports the view as a standalone HTML file in `output_dir` and returns
+  /// the path of the written file. `child_pages` are the already exported
+  /// children of the view, rendered as links on the page. Layouts without an
+  /// HTML representation return [FlowyError::not_support].
+  async fn export_html(
+    &self,
+    _view_id: &Uuid,
+    _name: &str,
+    _child_pages: Vec<ExportedHtmlPage>,
+    _output_dir: &Path,
+  ) -> Result<PathBuf, FlowyError> {
+    Err(FlowyError::not_support())
+  }
 }
 
 pub type FolderOperationHandlers =
   Arc<DashMap<ViewLayout, Arc<dyn FolderOperationHandler + Send + Sync>>>;
 
+/// Key in a view's `extra` JSON naming the extension layout the view is
+/// rendered with. Views carrying this tag are routed to the handler
+/// registered for the tag instead of the handler of their [ViewLayout].
+pub const VIEW_EXTRA_LAYOUT_TAG: &str = "layout_tag";
+
+/// Operation handlers registered at runtime for experimental view types,
+/// keyed by the layout tag carried in the view's `extra` data.
+pub type ExtensionOperationHandlers =
+  Arc<DashMap<String, Arc<dyn FolderOperationHandler + Send + Sync>>>;
+
+/// Reads the extension layout tag from a view's `extra` JSON, if any.
+pub fn layout_tag_from_extra(extra: Option<&str>) -> Option<String> {
+  let value = serde_json::from_str::<serde_json::Value>(extra?).ok()?;
+  value
+    .get(VIEW_EXTRA_LAYOUT_TAG)?
+    .as_str()
+    .map(|tag| tag.to_string())
+}
+
 impl From<ViewLayoutPB> for ViewLayout {
   fn from(pb: ViewLayoutPB) -> Self {
     match pb {
@@ -145,6 +235,9 @@ impl From<ViewLayoutPB> for ViewLayout {
       ViewLayoutPB::Board => ViewLayout::Board,
       ViewLayoutPB::Calendar => ViewLayout::Calendar,
       ViewLayoutPB::Chat => ViewLayout::Chat,
+      // collab-folder doesn't have a dedicated timeline layout yet, so
+      // timeline views are persisted as grids.
+      ViewLayoutPB::Timeline => ViewLayout::Grid,
     }
   }
 }
@@ -30,16 +30,91 @@ pub trait FolderViewObserver: Send + Sync {
   async fn set_observer_rx(&self, rx: IndexContentReceiver);
 }
 
+/// Folder attributes stored alongside an indexed document so searches can
+/// filter on them at query time. `None` keeps the value already stored in
+/// the index for that document.
+#[derive(Default, Debug, Clone)]
+pub struct TanvityDocumentAttributes {
+  /// `ViewLayout` discriminant of the view, e.g. document or board.
+  pub view_layout: Option<i64>,
+  pub parent_view_id: Option<String>,
+  /// Uid of the user that created the view.
+  pub created_by: Option<i64>,
+  /// Unix timestamp of the last edit.
+  pub last_edited_at: Option<i64>,
+  /// Whether the view currently sits in the trash. Trashed views stay in the
+  /// index so they remain findable, see [TanvitySearchFilter::include_trash].
+  pub trashed: Option<bool>,
+}
+
+/// Query-time restrictions for [crate::tantivy_state::DocumentTantivyState::search].
+/// Empty collections and `None` values mean no restriction.
+#[derive(Default, Debug, Clone)]
+pub struct TanvitySearchFilter {
+  pub object_ids: Option<Vec<String>>,
+  /// `LocalSearchTantivySchema`'s `TYPE_*` values.
+  pub object_types: Option<Vec<String>>,
+  /// `ViewLayout` discriminants. Results outside these layouts are dropped,
+  /// but still counted in [TanvitySearchResults::facets].
+  pub view_layouts: Vec<i64>,
+  pub parent_view_id: Option<String>,
+  pub created_by: Option<i64>,
+  /// Keep results last edited at or after this unix timestamp.
+  pub modified_after: Option<i64>,
+  /// Keep results last edited at or before this unix timestamp.
+  pub modified_before: Option<i64>,
+  /// Also return views that sit in the trash, flagged via
+  /// [TanvitySearchResponseItem::trashed].
+  pub include_trash: bool,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct TanvitySearchResults {
+  pub items: Vec<TanvitySearchResponseItem>,
+  /// Number of matches per view layout, counted before the
+  /// [TanvitySearchFilter::view_layouts] restriction is applied.
+  pub facets: Vec<TanvityFacetCount>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct TanvityFacetCount {
+  pub view_layout: i64,
+  pub count: u64,
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct TanvitySearchResponseItem {
   pub id: String,
+  /// One of `LocalSearchTantivySchema`'s `TYPE_*` values.
+  pub object_type: String,
   pub display_name: String,
   pub icon: Option<ResultIcon>,
   pub workspace_id: String,
   pub content: String,
+  /// Plain-text snippet around the matched terms, empty when no snippet
+  /// could be generated. [Self::highlights] holds the matched ranges.
+  pub snippet: String,
+  /// Byte ranges of the matched terms within [Self::snippet], so the client
+  /// can render the context with the matches bolded.
+  pub highlights: Vec<HighlightRange>,
+  /// The schema field the snippet was taken from, e.g.
+  /// `LocalSearchTantivySchema::CONTENT` when the body matched or
+  /// `LocalSearchTantivySchema::NAME` when only the title matched. For
+  /// database rows the cell text is indexed as content.
+  pub matched_field: String,
+  /// Whether the view sits in the trash, for UI badging. Only `true` when
+  /// the search ran with [TanvitySearchFilter::include_trash].
+  pub trashed: bool,
   pub score: f32,
 }
 
+/// A byte range into a snippet marking one matched term.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct HighlightRange {
+  pub start: usize,
+  pub end: usize,
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct ResultIcon {
   pub ty: u8,
@@ -0,0 +1,188 @@
+use collab_folder::ViewIcon;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::{RwLock, mpsc};
+use uuid::Uuid;
+
+use crate::entities::TanvityDocumentAttributes;
+use crate::tantivy_state::DocumentTantivyState;
+use flowy_error::{FlowyError, FlowyResult};
+use tracing::{error, info};
+
+/// Upper bound on queued index work. Producers block once the queue is full,
+/// which keeps a busy editor from piling up unbounded indexing work.
+const INDEX_QUEUE_CAPACITY: usize = 512;
+/// Delay inserted before each queued task while the editor is active.
+const EDITOR_ACTIVE_THROTTLE: Duration = Duration::from_millis(300);
+
+#[derive(Debug)]
+pub enum IndexCommand {
+  AddDocument {
+    id: String,
+    object_type: String,
+    content: Option<String>,
+    name: Option<String>,
+    icon: Option<ViewIcon>,
+    attrs: TanvityDocumentAttributes,
+  },
+  DeleteDocuments(Vec<String>),
+  /// Drops every indexed document of the workspace. The full index provider
+  /// repopulates the index afterwards, so this doubles as the recovery path
+  /// for corrupted indexes.
+  Rebuild,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct IndexStatus {
+  /// Documents indexed since the coordinator started (or since the last
+  /// rebuild).
+  pub indexed: u64,
+  /// Queued tasks that have not been applied to the index yet.
+  pub pending: u64,
+  pub last_error: Option<String>,
+}
+
+/// Serializes index writes for one workspace through a bounded queue, so
+/// change notifications never block the caller on tantivy commits and can be
+/// throttled while the user is typing.
+pub struct DocumentIndexCoordinator {
+  sender: mpsc::Sender<IndexCommand>,
+  indexed: Arc<AtomicU64>,
+  pending: Arc<AtomicU64>,
+  last_error: Arc<Mutex<Option<String>>>,
+  editor_active: Arc<AtomicBool>,
+}
+
+impl DocumentIndexCoordinator {
+  pub fn new(state: Weak<RwLock<DocumentTantivyState>>) -> Self {
+    let (sender, mut receiver) = mpsc::channel::<IndexCommand>(INDEX_QUEUE_CAPACITY);
+    let indexed = Arc::new(AtomicU64::new(0));
+    let pending = Arc::new(AtomicU64::new(0));
+    let last_error = Arc::new(Mutex::new(None));
+    let editor_active = Arc::new(AtomicBool::new(false));
+
+    let worker_indexed = indexed.clone();
+    let worker_pending = pending.clone();
+    let worker_last_error = last_error.clone();
+    let worker_editor_active = editor_active.clone();
+    tokio::spawn(async move {
+      while let Some(command) = receiver.recv().await {
+        // Back off while the editor is active so indexing never competes
+        // with typing for the collab locks.
+        if worker_editor_active.load(Ordering::Relaxed) {
+          tokio::time::sleep(EDITOR_ACTIVE_THROTTLE).await;
+        }
+
+        let state = match state.upgrade() {
+          Some(state) => state,
+          None => break,
+        };
+
+        let result = match command {
+          IndexCommand::AddDocument {
+            id,
+            object_type,
+            content,
+            name,
+            icon,
+            attrs,
+          } => {
+            let result = state
+              .write()
+              .await
+              .add_document(&id, &object_type, content, name, icon, attrs);
+            if result.is_ok() {
+              worker_indexed.fetch_add(1, Ordering::Relaxed);
+            }
+            result
+          },
+          IndexCommand::DeleteDocuments(ids) => state.write().await.delete_documents(&ids),
+          IndexCommand::Rebuild => {
+            let workspace_id = state.read().await.workspace_id;
+            info!("[Indexing] rebuilding index for workspace: {}", workspace_id);
+            worker_indexed.store(0, Ordering::Relaxed);
+            *worker_last_error.lock().unwrap() = None;
+            state.write().await.delete_workspace(&workspace_id)
+          },
+        };
+        worker_pending.fetch_sub(1, Ordering::Relaxed);
+
+        if let Err(err) = result {
+          error!("[Indexing] index task failed: {}", err);
+          *worker_last_error.lock().unwrap() = Some(err.to_string());
+        }
+      }
+      info!("[Indexing] index coordinator worker stopped");
+    });
+
+    Self {
+      sender,
+      indexed,
+      pending,
+      last_error,
+      editor_active,
+    }
+  }
+
+  /// Flag that the user is actively editing, slowing down queued index work
+  /// until the flag is cleared again.
+  pub fn set_editor_active(&self, active: bool) {
+    self.editor_active.store(active, Ordering::Relaxed);
+  }
+
+  pub async fn enqueue(&self, command: IndexCommand) -> FlowyResult<()> {
+    self.pending.fetch_add(1, Ordering::Relaxed);
+    if self.sender.send(command).await.is_err() {
+      self.pending.fetch_sub(1, Ordering::Relaxed);
+      return Err(FlowyError::internal().with_context("Index coordinator worker stopped"));
+    }
+    Ok(())
+  }
+
+  pub fn status(&self) -> IndexStatus {
+    IndexStatus {
+      indexed: self.indexed.load(Ordering::Relaxed),
+      pending: self.pending.load(Ordering::Relaxed),
+      last_error: self.last_error.lock().unwrap().clone(),
+    }
+  }
+}
+
+/// Global map: workspace_id → its index coordinator, mirroring the global
+/// tantivy state map in [crate::tantivy_state_init].
+static INDEX_COORDINATORS: Lazy<DashMap<Uuid, Arc<DocumentIndexCoordinator>>> =
+  Lazy::new(DashMap::new);
+
+pub fn get_or_init_index_coordinator(
+  workspace_id: Uuid,
+  state: Weak<RwLock<DocumentTantivyState>>,
+) -> Arc<DocumentIndexCoordinator> {
+  INDEX_COORDINATORS
+    .entry(workspace_id)
+    .or_insert_with(|| {
+      info!(
+        "[Indexing] Creating index coordinator for workspace: {}",
+        workspace_id
+      );
+      Arc::new(DocumentIndexCoordinator::new(state))
+    })
+    .clone()
+}
+
+pub fn get_index_coordinator(workspace_id: &Uuid) -> Option<Arc<DocumentIndexCoordinator>> {
+  INDEX_COORDINATORS
+    .get(workspace_id)
+    .map(|entry| entry.value().clone())
+}
+
+pub fn close_index_coordinator(workspace_id: &Uuid) {
+  if INDEX_COORDINATORS.remove(workspace_id).is_some() {
+    info!(
+      "[Indexing] close index coordinator for workspace: {}",
+      workspace_id
+    );
+  }
+}
@@ -1,5 +1,6 @@
 pub mod cloud;
 pub mod entities;
+pub mod index_coordinator;
 pub mod tantivy_state;
 pub mod tantivy_state_init;
 
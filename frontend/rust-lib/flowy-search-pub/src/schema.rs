@@ -1,4 +1,4 @@
-use tantivy::schema::{STORED, STRING, Schema, TEXT};
+use tantivy::schema::{FAST, INDEXED, STORED, STRING, Schema, TEXT};
 
 pub struct LocalSearchTantivySchema(pub Schema);
 
@@ -11,19 +11,36 @@ impl Default for LocalSearchTantivySchema {
 impl LocalSearchTantivySchema {
   pub const WORKSPACE_ID: &'static str = "workspace_id";
   pub const OBJECT_ID: &'static str = "object_id";
+  pub const OBJECT_TYPE: &'static str = "object_ty";
   pub const CONTENT: &'static str = "content";
   pub const NAME: &'static str = "name";
   pub const ICON: &'static str = "icon";
   pub const ICON_TYPE: &'static str = "icon_ty";
+  pub const VIEW_LAYOUT: &'static str = "view_layout";
+  pub const PARENT_VIEW_ID: &'static str = "parent_view_id";
+  pub const CREATED_BY: &'static str = "created_by";
+  pub const LAST_EDITED_AT: &'static str = "last_edited_at";
+  pub const TRASHED: &'static str = "trashed";
+
+  /// [Self::OBJECT_TYPE] value for document views.
+  pub const TYPE_DOCUMENT: &'static str = "document";
+  /// [Self::OBJECT_TYPE] value for database rows.
+  pub const TYPE_DATABASE_ROW: &'static str = "database_row";
 
   pub fn new() -> Self {
     let mut builder = Schema::builder();
     builder.add_text_field(Self::WORKSPACE_ID, STRING | STORED);
     builder.add_text_field(Self::OBJECT_ID, STRING | STORED);
+    builder.add_text_field(Self::OBJECT_TYPE, STRING | STORED);
     builder.add_text_field(Self::CONTENT, TEXT | STORED);
     builder.add_text_field(Self::NAME, TEXT | STORED);
     builder.add_text_field(Self::ICON, TEXT | STORED);
     builder.add_text_field(Self::ICON_TYPE, STRING | STORED);
+    builder.add_i64_field(Self::VIEW_LAYOUT, INDEXED | STORED | FAST);
+    builder.add_text_field(Self::PARENT_VIEW_ID, STRING | STORED);
+    builder.add_i64_field(Self::CREATED_BY, INDEXED | STORED);
+    builder.add_i64_field(Self::LAST_EDITED_AT, INDEXED | STORED | FAST);
+    builder.add_i64_field(Self::TRASHED, INDEXED | STORED);
     LocalSearchTantivySchema(builder.build())
   }
 }
@@ -7,7 +7,10 @@ use tantivy::{Index, IndexReader, IndexWriter, TantivyDocument, Term};
 use tracing::{error, trace, warn};
 use uuid::Uuid;
 
-use crate::entities::{ResultIcon, TanvitySearchResponseItem};
+use crate::entities::{
+  HighlightRange, ResultIcon, TanvityDocumentAttributes, TanvityFacetCount, TanvitySearchFilter,
+  TanvitySearchResponseItem, TanvitySearchResults,
+};
 use crate::schema::LocalSearchTantivySchema;
 use flowy_error::{FlowyError, FlowyResult};
 
@@ -22,10 +25,16 @@ pub struct DocumentTantivyState {
   // Cached fields for better performance
   field_workspace_id: tantivy::schema::Field,
   field_object_id: tantivy::schema::Field,
+  field_object_type: tantivy::schema::Field,
   field_content: tantivy::schema::Field,
   field_name: tantivy::schema::Field,
   field_icon: tantivy::schema::Field,
   field_icon_type: tantivy::schema::Field,
+  field_view_layout: tantivy::schema::Field,
+  field_parent_view_id: tantivy::schema::Field,
+  field_created_by: tantivy::schema::Field,
+  field_last_edited_at: tantivy::schema::Field,
+  field_trashed: tantivy::schema::Field,
 }
 
 impl DocumentTantivyState {
@@ -40,7 +49,19 @@ impl DocumentTantivyState {
 
     let schema = LocalSearchTantivySchema::new();
     let dir = MmapDirectory::open(&index_path)?;
-    let index = Index::open_or_create(dir, schema.0.clone())?;
+    let index = match Index::open_or_create(dir, schema.0.clone()) {
+      Ok(index) => index,
+      Err(err) => {
+        // The schema of an existing index no longer matches, e.g. after an
+        // upgrade added fields. Rebuild from scratch; the full index provider
+        // repopulates it.
+        warn!("[Tantivy] schema mismatch, recreating index: {:?}", err);
+        fs::remove_dir_all(&index_path)?;
+        fs::create_dir_all(&index_path)?;
+        let dir = MmapDirectory::open(&index_path)?;
+        Index::open_or_create(dir, schema.0.clone())?
+      },
+    };
     let writer = index.writer(15_000_000)?; // 15 MB buffer
     let reader = index.reader()?;
 
@@ -53,6 +74,10 @@ impl DocumentTantivyState {
       .0
       .get_field(LocalSearchTantivySchema::OBJECT_ID)
       .map_err(|_| FlowyError::internal().with_context("object_id field missing"))?;
+    let field_object_type = schema
+      .0
+      .get_field(LocalSearchTantivySchema::OBJECT_TYPE)
+      .map_err(|_| FlowyError::internal().with_context("object_ty field missing"))?;
     let field_content = schema
       .0
       .get_field(LocalSearchTantivySchema::CONTENT)
@@ -69,6 +94,26 @@ impl DocumentTantivyState {
       .0
       .get_field(LocalSearchTantivySchema::ICON_TYPE)
       .map_err(|_| FlowyError::internal().with_context("icon_type field missing"))?;
+    let field_view_layout = schema
+      .0
+      .get_field(LocalSearchTantivySchema::VIEW_LAYOUT)
+      .map_err(|_| FlowyError::internal().with_context("view_layout field missing"))?;
+    let field_parent_view_id = schema
+      .0
+      .get_field(LocalSearchTantivySchema::PARENT_VIEW_ID)
+      .map_err(|_| FlowyError::internal().with_context("parent_view_id field missing"))?;
+    let field_created_by = schema
+      .0
+      .get_field(LocalSearchTantivySchema::CREATED_BY)
+      .map_err(|_| FlowyError::internal().with_context("created_by field missing"))?;
+    let field_last_edited_at = schema
+      .0
+      .get_field(LocalSearchTantivySchema::LAST_EDITED_AT)
+      .map_err(|_| FlowyError::internal().with_context("last_edited_at field missing"))?;
+    let field_trashed = schema
+      .0
+      .get_field(LocalSearchTantivySchema::TRASHED)
+      .map_err(|_| FlowyError::internal().with_context("trashed field missing"))?;
 
     Ok(Self {
       path,
@@ -79,26 +124,34 @@ impl DocumentTantivyState {
       workspace_id: *workspace_id,
       field_workspace_id,
       field_object_id,
+      field_object_type,
       field_content,
       field_name,
       field_icon,
       field_icon_type,
+      field_view_layout,
+      field_parent_view_id,
+      field_created_by,
+      field_last_edited_at,
+      field_trashed,
     })
   }
 
   pub fn add_document(
     &mut self,
     id: &str,
+    object_type: &str,
     content: Option<String>,
     name: Option<String>,
     icon: Option<ViewIcon>,
+    attrs: TanvityDocumentAttributes,
   ) -> FlowyResult<()> {
     match content {
       None => {
         self.add_document_metadata(id, name, icon)?;
       },
       Some(content) => {
-        self.add_document_content(id, content, name, icon)?;
+        self.add_document_content(id, object_type, content, name, icon, attrs)?;
       },
     }
     Ok(())
@@ -107,9 +160,11 @@ impl DocumentTantivyState {
   fn add_document_content(
     &mut self,
     id: &str,
+    object_type: &str,
     content: String,
     name: Option<String>,
     icon: Option<ViewIcon>,
+    attrs: TanvityDocumentAttributes,
   ) -> FlowyResult<()> {
     trace!("[Tantivy] Adding document with id:{}, name:{:?}", id, name);
     let term = Term::from_field_text(self.field_object_id, id);
@@ -118,51 +173,49 @@ impl DocumentTantivyState {
       tantivy::query::TermQuery::new(term.clone(), tantivy::schema::IndexRecordOption::Basic);
     let top_docs = searcher.search(&query, &tantivy::collector::TopDocs::with_limit(1))?;
 
-    let (existing_name, existing_icon) = if let Some((_score, doc_address)) = top_docs.first() {
-      let retrieved: TantivyDocument = searcher.doc(*doc_address)?;
+    let existing: Option<TantivyDocument> = match top_docs.first() {
+      Some((_score, doc_address)) => Some(searcher.doc(*doc_address)?),
+      None => None,
+    };
 
-      // Get existing name if needed
-      let existing_name = if name.is_none() {
-        retrieved
+    // Get existing name if needed
+    let existing_name = if name.is_none() {
+      existing.as_ref().and_then(|doc| {
+        doc
           .get_first(self.field_name)
           .and_then(|v| v.as_str())
           .map(|s| s.to_string())
-      } else {
-        None
-      };
+      })
+    } else {
+      None
+    };
 
-      // Get existing icon if needed
-      let existing_icon = if icon.is_none() {
-        let icon_value = retrieved
+    // Get existing icon if needed
+    let existing_icon = if icon.is_none() {
+      existing.as_ref().and_then(|doc| {
+        let icon_value = doc
           .get_first(self.field_icon)
           .and_then(|v| v.as_str())
-          .map(|s| s.to_string());
-
-        if let Some(icon_value) = icon_value {
-          let icon_type_str = retrieved
-            .get_first(self.field_icon_type)
-            .and_then(|v| v.as_str())
-            .unwrap_or_default();
-
-          let icon_type = icon_type_str.parse::<u8>().unwrap_or_default();
-
-          // Recreate the ViewIcon from stored values
-          Some(ViewIcon {
-            value: icon_value,
-            ty: icon_type.into(),
-          })
-        } else {
-          None
-        }
-      } else {
-        None
-      };
-
-      (existing_name, existing_icon)
+          .map(|s| s.to_string())?;
+        let icon_type = doc
+          .get_first(self.field_icon_type)
+          .and_then(|v| v.as_str())
+          .unwrap_or_default()
+          .parse::<u8>()
+          .unwrap_or_default();
+
+        // Recreate the ViewIcon from stored values
+        Some(ViewIcon {
+          value: icon_value,
+          ty: icon_type.into(),
+        })
+      })
     } else {
-      (None, None)
+      None
     };
 
+    let attrs = self.merge_existing_attributes(attrs, existing.as_ref());
+
     // Delete existing document with same ID
     self.writer.delete_term(term);
 
@@ -177,6 +230,7 @@ impl DocumentTantivyState {
     let mut doc_builder = tantivy::doc!(
         self.field_workspace_id => self.workspace_id.to_string(),
         self.field_object_id => id,
+        self.field_object_type => object_type,
         self.field_content => content,
         self.field_name => document_name
     );
@@ -187,12 +241,75 @@ impl DocumentTantivyState {
       doc_builder.add_text(self.field_icon_type, (view_icon.ty as u8).to_string());
     }
 
+    self.add_attribute_fields(&mut doc_builder, &attrs);
+
     self.writer.add_document(doc_builder)?;
     self.writer.commit()?;
 
     Ok(())
   }
 
+  /// Keeps the attributes already stored for the document where the caller
+  /// did not provide a new value.
+  fn merge_existing_attributes(
+    &self,
+    attrs: TanvityDocumentAttributes,
+    existing: Option<&TantivyDocument>,
+  ) -> TanvityDocumentAttributes {
+    let existing = match existing {
+      Some(doc) => doc,
+      None => return attrs,
+    };
+
+    TanvityDocumentAttributes {
+      view_layout: attrs
+        .view_layout
+        .or_else(|| existing.get_first(self.field_view_layout).and_then(|v| v.as_i64())),
+      parent_view_id: attrs.parent_view_id.or_else(|| {
+        existing
+          .get_first(self.field_parent_view_id)
+          .and_then(|v| v.as_str())
+          .map(|s| s.to_string())
+      }),
+      created_by: attrs
+        .created_by
+        .or_else(|| existing.get_first(self.field_created_by).and_then(|v| v.as_i64())),
+      last_edited_at: attrs.last_edited_at.or_else(|| {
+        existing
+          .get_first(self.field_last_edited_at)
+          .and_then(|v| v.as_i64())
+      }),
+      trashed: attrs.trashed.or_else(|| {
+        existing
+          .get_first(self.field_trashed)
+          .and_then(|v| v.as_i64())
+          .map(|v| v != 0)
+      }),
+    }
+  }
+
+  fn add_attribute_fields(
+    &self,
+    doc_builder: &mut TantivyDocument,
+    attrs: &TanvityDocumentAttributes,
+  ) {
+    if let Some(view_layout) = attrs.view_layout {
+      doc_builder.add_i64(self.field_view_layout, view_layout);
+    }
+    if let Some(parent_view_id) = &attrs.parent_view_id {
+      doc_builder.add_text(self.field_parent_view_id, parent_view_id);
+    }
+    if let Some(created_by) = attrs.created_by {
+      doc_builder.add_i64(self.field_created_by, created_by);
+    }
+    if let Some(last_edited_at) = attrs.last_edited_at {
+      doc_builder.add_i64(self.field_last_edited_at, last_edited_at);
+    }
+    if let Some(trashed) = attrs.trashed {
+      doc_builder.add_i64(self.field_trashed, trashed as i64);
+    }
+  }
+
   pub fn add_document_metadata(
     &mut self,
     id: &str,
@@ -207,57 +324,77 @@ impl DocumentTantivyState {
     // Search for the document
     let top_docs = searcher.search(&query, &tantivy::collector::TopDocs::with_limit(1))?;
 
-    let (existing_content, existing_name, existing_icon) =
-      if let Some((_score, doc_address)) = top_docs.first() {
-        let retrieved: TantivyDocument = searcher.doc(*doc_address)?;
+    let existing: Option<TantivyDocument> = match top_docs.first() {
+      Some((_score, doc_address)) => Some(searcher.doc(*doc_address)?),
+      None => None,
+    };
 
-        // Get existing content
-        let content = retrieved
+    // Get existing content
+    let existing_content = existing
+      .as_ref()
+      .and_then(|doc| {
+        doc
           .get_first(self.field_content)
           .and_then(|v| v.as_str())
-          .unwrap_or_default()
-          .to_string();
+          .map(|s| s.to_string())
+      })
+      .unwrap_or_default();
+
+    // Keep the object type of the indexed document
+    let existing_type = existing
+      .as_ref()
+      .and_then(|doc| {
+        doc
+          .get_first(self.field_object_type)
+          .and_then(|v| v.as_str())
+          .map(|s| s.to_string())
+      })
+      .unwrap_or_else(|| LocalSearchTantivySchema::TYPE_DOCUMENT.to_string());
 
-        // Get existing name if needed
-        let existing_name = if name.is_none() {
-          retrieved
-            .get_first(self.field_name)
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-        } else {
-          None
-        };
+    // Get existing name if needed
+    let existing_name = if name.is_none() {
+      existing.as_ref().and_then(|doc| {
+        doc
+          .get_first(self.field_name)
+          .and_then(|v| v.as_str())
+          .map(|s| s.to_string())
+      })
+    } else {
+      None
+    };
 
-        // Get existing icon if needed
-        let existing_icon = if icon.is_none() {
-          let icon_value = retrieved
-            .get_first(self.field_icon)
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
-
-          if let Some(icon_value) = icon_value {
-            let icon_type_str = retrieved
-              .get_first(self.field_icon_type)
-              .and_then(|v| v.as_str())
-              .unwrap_or_default();
-
-            let icon_type = icon_type_str.parse::<u8>().unwrap_or_default();
-
-            Some(ViewIcon {
-              value: icon_value,
-              ty: icon_type.into(),
-            })
-          } else {
-            None
-          }
-        } else {
-          None
-        };
+    // Get existing icon if needed
+    let existing_icon = if icon.is_none() {
+      existing.as_ref().and_then(|doc| {
+        let icon_value = doc
+          .get_first(self.field_icon)
+          .and_then(|v| v.as_str())
+          .map(|s| s.to_string())?;
+        let icon_type = doc
+          .get_first(self.field_icon_type)
+          .and_then(|v| v.as_str())
+          .unwrap_or_default()
+          .parse::<u8>()
+          .unwrap_or_default();
+
+        Some(ViewIcon {
+          value: icon_value,
+          ty: icon_type.into(),
+        })
+      })
+    } else {
+      None
+    };
 
-        (content, existing_name, existing_icon)
-      } else {
-        (String::new(), None, None)
-      };
+    // Metadata updates only arrive for live folder views, so they also clear
+    // the trashed flag of restored views.
+    let attrs = self.merge_existing_attributes(
+      TanvityDocumentAttributes {
+        trashed: Some(false),
+        ..Default::default()
+      },
+      existing.as_ref(),
+    );
 
     // Use existing values if new ones not provided
     let final_name = name.or(existing_name);
@@ -273,6 +410,7 @@ impl DocumentTantivyState {
     let mut doc_builder = tantivy::doc!(
         self.field_workspace_id => self.workspace_id.to_string(),
         self.field_object_id => id,
+        self.field_object_type => existing_type,
         self.field_content => existing_content,
         self.field_name => document_name
     );
@@ -283,12 +421,85 @@ impl DocumentTantivyState {
       doc_builder.add_text(self.field_icon_type, (view_icon.ty as u8).to_string());
     }
 
+    self.add_attribute_fields(&mut doc_builder, &attrs);
+
     self.writer.add_document(doc_builder)?;
     self.writer.commit()?;
 
     Ok(())
   }
 
+  /// Flags documents as trashed, or restores them, without removing them
+  /// from the index so accidentally deleted pages stay findable through
+  /// [crate::entities::TanvitySearchFilter::include_trash].
+  pub fn set_documents_trashed(&mut self, ids: &[String], trashed: bool) -> FlowyResult<()> {
+    trace!(
+      "[Tantivy] set documents trashed:{} with ids: {:?}",
+      trashed, ids
+    );
+    let searcher = self.reader.searcher();
+    for id in ids {
+      let term = Term::from_field_text(self.field_object_id, id);
+      let query =
+        tantivy::query::TermQuery::new(term.clone(), tantivy::schema::IndexRecordOption::Basic);
+      let top_docs = searcher.search(&query, &tantivy::collector::TopDocs::with_limit(1))?;
+      let existing: TantivyDocument = match top_docs.first() {
+        Some((_score, doc_address)) => searcher.doc(*doc_address)?,
+        None => continue,
+      };
+
+      let attrs = self.merge_existing_attributes(
+        TanvityDocumentAttributes {
+          trashed: Some(trashed),
+          ..Default::default()
+        },
+        Some(&existing),
+      );
+      let object_type = existing
+        .get_first(self.field_object_type)
+        .and_then(|v| v.as_str())
+        .unwrap_or(LocalSearchTantivySchema::TYPE_DOCUMENT)
+        .to_string();
+      let content = existing
+        .get_first(self.field_content)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+      let name = existing
+        .get_first(self.field_name)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+      let icon = existing
+        .get_first(self.field_icon)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+      let icon_type = existing
+        .get_first(self.field_icon_type)
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+      self.writer.delete_term(term);
+
+      let mut doc_builder = tantivy::doc!(
+          self.field_workspace_id => self.workspace_id.to_string(),
+          self.field_object_id => id.as_str(),
+          self.field_object_type => object_type,
+          self.field_content => content,
+          self.field_name => name
+      );
+      if let Some(icon) = icon {
+        doc_builder.add_text(self.field_icon, icon);
+        doc_builder.add_text(self.field_icon_type, icon_type.unwrap_or_default());
+      }
+      self.add_attribute_fields(&mut doc_builder, &attrs);
+      self.writer.add_document(doc_builder)?;
+    }
+    self.writer.commit()?;
+
+    Ok(())
+  }
+
   pub fn delete_workspace(&mut self, workspace_id: &Uuid) -> FlowyResult<()> {
     let term = Term::from_field_text(self.field_workspace_id, &workspace_id.to_string());
     self.writer.delete_term(term);
@@ -322,10 +533,10 @@ impl DocumentTantivyState {
     &self,
     workspace_id: &Uuid,
     query: &str,
-    object_ids: Option<Vec<String>>,
+    filter: TanvitySearchFilter,
     limit: usize,
     score_threshold: f32,
-  ) -> FlowyResult<Vec<TanvitySearchResponseItem>> {
+  ) -> FlowyResult<TanvitySearchResults> {
     let workspace_id = workspace_id.to_string();
     let reader = self.reader.clone();
     let searcher = reader.searcher();
@@ -337,15 +548,98 @@ impl DocumentTantivyState {
     );
     // Enable fuzzy matching for name field (better user experience for typos)
     qp.set_field_fuzzy(self.field_name, true, 2, true);
+    let text_query = qp.parse_query(query)?;
+
+    // Structured restrictions become part of the index query itself, so
+    // tantivy evaluates them instead of the caller post-filtering results.
+    let mut clauses: Vec<(tantivy::query::Occur, Box<dyn tantivy::query::Query>)> = vec![
+      (
+        tantivy::query::Occur::Must,
+        Box::new(tantivy::query::TermQuery::new(
+          Term::from_field_text(self.field_workspace_id, &workspace_id),
+          tantivy::schema::IndexRecordOption::Basic,
+        )),
+      ),
+      (tantivy::query::Occur::Must, text_query),
+    ];
+    if let Some(parent_view_id) = &filter.parent_view_id {
+      clauses.push((
+        tantivy::query::Occur::Must,
+        Box::new(tantivy::query::TermQuery::new(
+          Term::from_field_text(self.field_parent_view_id, parent_view_id),
+          tantivy::schema::IndexRecordOption::Basic,
+        )),
+      ));
+    }
+    if let Some(created_by) = filter.created_by {
+      clauses.push((
+        tantivy::query::Occur::Must,
+        Box::new(tantivy::query::TermQuery::new(
+          Term::from_field_i64(self.field_created_by, created_by),
+          tantivy::schema::IndexRecordOption::Basic,
+        )),
+      ));
+    }
+    if !filter.include_trash {
+      clauses.push((
+        tantivy::query::Occur::MustNot,
+        Box::new(tantivy::query::TermQuery::new(
+          Term::from_field_i64(self.field_trashed, 1),
+          tantivy::schema::IndexRecordOption::Basic,
+        )),
+      ));
+    }
+    if filter.modified_after.is_some() || filter.modified_before.is_some() {
+      let lower = filter
+        .modified_after
+        .map(|v| std::ops::Bound::Included(Term::from_field_i64(self.field_last_edited_at, v)))
+        .unwrap_or(std::ops::Bound::Unbounded);
+      let upper = filter
+        .modified_before
+        .map(|v| std::ops::Bound::Included(Term::from_field_i64(self.field_last_edited_at, v)))
+        .unwrap_or(std::ops::Bound::Unbounded);
+      clauses.push((
+        tantivy::query::Occur::Must,
+        Box::new(tantivy::query::RangeQuery::new(lower, upper)),
+      ));
+    }
+    let query = tantivy::query::BooleanQuery::new(clauses);
 
-    let query = qp.parse_query(query)?;
-    let top_docs = searcher.search(&query, &tantivy::collector::TopDocs::with_limit(limit))?;
+    // The view layout restriction is applied after collecting matches so the
+    // facet counts still cover every layout. Fetch more than the requested
+    // limit in that case to compensate for the dropped results.
+    let fetch_limit = if filter.view_layouts.is_empty() {
+      limit
+    } else {
+      limit.max(100)
+    };
+    let top_docs =
+      searcher.search(&query, &tantivy::collector::TopDocs::with_limit(fetch_limit))?;
+
+    // Highlighted content snippets for the result list. Failing to build the
+    // generator only drops the highlights, never the search itself.
+    let snippet_generator =
+      tantivy::snippet::SnippetGenerator::create(&searcher, &query, self.field_content)
+        .map(|mut generator| {
+          generator.set_max_num_chars(150);
+          generator
+        })
+        .ok();
+    // Fallback generator for results that only matched on the title.
+    let name_snippet_generator =
+      tantivy::snippet::SnippetGenerator::create(&searcher, &query, self.field_name)
+        .map(|mut generator| {
+          generator.set_max_num_chars(150);
+          generator
+        })
+        .ok();
 
     let mut results = Vec::with_capacity(top_docs.len());
     let mut seen_ids = std::collections::HashSet::new();
+    let mut facet_counts = std::collections::BTreeMap::<i64, u64>::new();
 
     // If object_ids is provided and not empty, create a lookup set for faster filtering
-    let object_ids_filter = object_ids.and_then(|ids| {
+    let object_ids_filter = filter.object_ids.and_then(|ids| {
       if ids.is_empty() {
         None
       } else {
@@ -353,6 +647,15 @@ impl DocumentTantivyState {
       }
     });
 
+    // Same for object types, e.g. restricting a search to documents only
+    let object_types_filter = filter.object_types.and_then(|types| {
+      if types.is_empty() {
+        None
+      } else {
+        Some(types.into_iter().collect::<std::collections::HashSet<_>>())
+      }
+    });
+
     for (score, doc_address) in top_docs {
       // Skip results that don't meet the score threshold
       if score < score_threshold {
@@ -393,11 +696,43 @@ impl DocumentTantivyState {
         }
       }
 
+      let object_type = retrieved
+        .get_first(self.field_object_type)
+        .and_then(|v| v.as_str())
+        .unwrap_or(LocalSearchTantivySchema::TYPE_DOCUMENT)
+        .to_string();
+
+      // Apply object_types filter if present
+      if let Some(ref filter) = object_types_filter {
+        if !filter.contains(&object_type) {
+          continue;
+        }
+      }
+
       // Skip duplicate records based on object_id
       if !seen_ids.insert(object_id.clone()) {
         continue;
       }
 
+      // Count layout facets before the layout restriction so the UI can show
+      // e.g. "12 documents, 3 boards" even when only documents are requested.
+      let view_layout = retrieved
+        .get_first(self.field_view_layout)
+        .and_then(|v| v.as_i64());
+      if let Some(view_layout) = view_layout {
+        *facet_counts.entry(view_layout).or_insert(0) += 1;
+      }
+
+      if !filter.view_layouts.is_empty()
+        && !view_layout.is_some_and(|layout| filter.view_layouts.contains(&layout))
+      {
+        continue;
+      }
+
+      if results.len() >= limit {
+        continue;
+      }
+
       let name = retrieved
         .get_first(self.field_name)
         .and_then(|v| v.as_str())
@@ -435,16 +770,74 @@ impl DocumentTantivyState {
         .unwrap_or_default()
         .to_string();
 
+      // Prefer a content snippet; when only the title matched, fall back to
+      // a snippet of the name so the client still knows what to highlight.
+      let content_snippet = snippet_generator
+        .as_ref()
+        .map(|generator| generator.snippet_from_doc(&retrieved));
+      let (snippet, highlights, matched_field) = match content_snippet {
+        Some(snippet) if !snippet.highlighted().is_empty() => (
+          snippet.fragment().to_string(),
+          highlight_ranges(&snippet),
+          LocalSearchTantivySchema::CONTENT.to_string(),
+        ),
+        _ => match name_snippet_generator
+          .as_ref()
+          .map(|generator| generator.snippet_from_doc(&retrieved))
+        {
+          Some(snippet) if !snippet.highlighted().is_empty() => (
+            snippet.fragment().to_string(),
+            highlight_ranges(&snippet),
+            LocalSearchTantivySchema::NAME.to_string(),
+          ),
+          _ => (
+            content_snippet
+              .map(|snippet| snippet.fragment().to_string())
+              .unwrap_or_default(),
+            Vec::new(),
+            LocalSearchTantivySchema::CONTENT.to_string(),
+          ),
+        },
+      };
+
+      let trashed = retrieved
+        .get_first(self.field_trashed)
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0)
+        != 0;
+
       results.push(TanvitySearchResponseItem {
         id: object_id,
+        object_type,
         display_name: name,
         icon,
         workspace_id: workspace_id_str,
         content,
+        snippet,
+        highlights,
+        matched_field,
+        trashed,
         score,
       });
     }
 
-    Ok(results)
+    Ok(TanvitySearchResults {
+      items: results,
+      facets: facet_counts
+        .into_iter()
+        .map(|(view_layout, count)| TanvityFacetCount { view_layout, count })
+        .collect(),
+    })
   }
 }
+
+fn highlight_ranges(snippet: &tantivy::snippet::Snippet) -> Vec<HighlightRange> {
+  snippet
+    .highlighted()
+    .iter()
+    .map(|range| HighlightRange {
+      start: range.start,
+      end: range.end,
+    })
+    .collect()
+}
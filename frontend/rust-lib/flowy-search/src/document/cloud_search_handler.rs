@@ -1,5 +1,5 @@
 use crate::entities::{
-  CreateSearchResultPBArgs, RepeatedSearchResponseItemPB, RepeatedSearchSummaryPB,
+  CreateSearchResultPBArgs, RepeatedSearchResponseItemPB, RepeatedSearchSummaryPB, SearchFilterPB,
   SearchResponsePB, SearchSourcePB, SearchSummaryPB,
 };
 use crate::{
@@ -44,6 +44,7 @@ impl SearchHandler for DocumentCloudSearchHandler {
   async fn perform_search(
     &self,
     query: String,
+    _filter: Option<SearchFilterPB>,
     workspace_id: &Uuid,
   ) -> Pin<Box<dyn Stream<Item = FlowyResult<SearchResponsePB>> + Send + 'static>> {
     let cloud_service = self.cloud_service.clone();
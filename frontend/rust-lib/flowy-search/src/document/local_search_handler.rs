@@ -8,11 +8,12 @@ use uuid::Uuid;
 
 use crate::entities::{
   CreateSearchResultPBArgs, LocalSearchResponseItemPB, RepeatedLocalSearchResponseItemPB,
-  ResultIconPB, ResultIconTypePB, SearchResponsePB,
+  ResultIconPB, ResultIconTypePB, SearchFacetCountPB, SearchFilterPB, SearchHighlightRangePB,
+  SearchResponsePB,
 };
 use crate::services::manager::{SearchHandler, SearchType};
 use flowy_error::FlowyResult;
-use flowy_search_pub::entities::TanvitySearchResponseItem;
+use flowy_search_pub::entities::{TanvitySearchFilter, TanvitySearchResponseItem};
 use flowy_search_pub::tantivy_state::DocumentTantivyState;
 use lib_infra::async_trait::async_trait;
 
@@ -35,10 +36,12 @@ impl SearchHandler for DocumentLocalSearchHandler {
   async fn perform_search(
     &self,
     query: String,
+    filter: Option<SearchFilterPB>,
     workspace_id: &Uuid,
   ) -> Pin<Box<dyn Stream<Item = FlowyResult<SearchResponsePB>> + Send + 'static>> {
     let workspace_id = *workspace_id;
     let state = self.state.clone();
+    let filter = filter.map(tanvity_filter_from_pb).unwrap_or_default();
     Box::pin(stream! {
       match state.upgrade() {
         None => {
@@ -50,10 +53,10 @@ impl SearchHandler for DocumentLocalSearchHandler {
           );
         },
         Some(state) => {
-          match state.read().await.search(&workspace_id, &query, None, 10, 0.4) {
-            Ok(items) => {
-              trace!("[Tanvity] local document search result: {:?}", items);
-              if items.is_empty() {
+          match state.read().await.search(&workspace_id, &query, filter, 10, 0.4) {
+            Ok(results) => {
+              trace!("[Tanvity] local document search result: {:?}", results);
+              if results.items.is_empty() {
                 yield Ok(
                   CreateSearchResultPBArgs::default()
                     .local_search_result(None)
@@ -61,8 +64,16 @@ impl SearchHandler for DocumentLocalSearchHandler {
                     .unwrap(),
                 );
               } else {
-                let items = items.into_iter().map(tanvity_item_to_local_search_item).collect::<Vec<_>>();
-                let search_result = RepeatedLocalSearchResponseItemPB { items };
+                let items = results.items.into_iter().map(tanvity_item_to_local_search_item).collect::<Vec<_>>();
+                let facets = results
+                  .facets
+                  .into_iter()
+                  .map(|facet| SearchFacetCountPB {
+                    view_layout: facet.view_layout,
+                    count: facet.count,
+                  })
+                  .collect::<Vec<_>>();
+                let search_result = RepeatedLocalSearchResponseItemPB { items, facets };
                 yield Ok(
                   CreateSearchResultPBArgs::default()
                     .local_search_result(Some(search_result))
@@ -79,6 +90,19 @@ impl SearchHandler for DocumentLocalSearchHandler {
   }
 }
 
+fn tanvity_filter_from_pb(filter: SearchFilterPB) -> TanvitySearchFilter {
+  TanvitySearchFilter {
+    object_ids: None,
+    object_types: Some(filter.object_types).filter(|types| !types.is_empty()),
+    view_layouts: filter.view_layouts,
+    parent_view_id: Some(filter.parent_view_id).filter(|id| !id.is_empty()),
+    created_by: filter.created_by,
+    modified_after: filter.modified_after,
+    modified_before: filter.modified_before,
+    include_trash: filter.include_trash,
+  }
+}
+
 fn tanvity_item_to_local_search_item(item: TanvitySearchResponseItem) -> LocalSearchResponseItemPB {
   LocalSearchResponseItemPB {
     id: item.id,
@@ -88,5 +112,17 @@ fn tanvity_item_to_local_search_item(item: TanvitySearchResponseItem) -> LocalSe
       value: icon.value,
     }),
     workspace_id: item.workspace_id,
+    object_type: item.object_type,
+    snippet: item.snippet,
+    highlights: item
+      .highlights
+      .into_iter()
+      .map(|range| SearchHighlightRangePB {
+        start: range.start as u64,
+        end: range.end as u64,
+      })
+      .collect(),
+    matched_field: item.matched_field,
+    trashed: item.trashed,
   }
 }
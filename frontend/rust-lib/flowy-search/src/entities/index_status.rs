@@ -0,0 +1,17 @@
+use flowy_derive::ProtoBuf;
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct IndexStatusPB {
+  /// Documents indexed since the index coordinator started, or since the
+  /// last rebuild.
+  #[pb(index = 1)]
+  pub indexed: u64,
+
+  /// Queued index tasks that have not been applied yet.
+  #[pb(index = 2)]
+  pub pending: u64,
+
+  /// The most recent indexing error, empty when indexing is healthy.
+  #[pb(index = 3)]
+  pub last_error: String,
+}
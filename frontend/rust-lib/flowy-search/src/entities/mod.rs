@@ -1,8 +1,10 @@
+mod index_status;
 mod notification;
 mod query;
 mod result;
 mod search_filter;
 
+pub use index_status::*;
 pub use notification::*;
 pub use query::*;
 pub use result::*;
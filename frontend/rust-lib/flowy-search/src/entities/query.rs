@@ -1,5 +1,7 @@
 use flowy_derive::ProtoBuf;
 
+use crate::entities::SearchFilterPB;
+
 #[derive(Eq, PartialEq, ProtoBuf, Default, Debug, Clone)]
 pub struct SearchQueryPB {
   #[pb(index = 1)]
@@ -13,4 +15,7 @@ pub struct SearchQueryPB {
 
   #[pb(index = 4)]
   pub stream_port: i64,
+
+  #[pb(index = 5, one_of)]
+  pub filter: Option<SearchFilterPB>,
 }
@@ -86,6 +86,22 @@ pub struct SearchResponseItemPB {
 pub struct RepeatedLocalSearchResponseItemPB {
   #[pb(index = 1)]
   pub items: Vec<LocalSearchResponseItemPB>,
+
+  /// Number of matches per view layout, counted before the
+  /// `SearchFilterPB` view layout restriction is applied so the UI can show
+  /// e.g. "12 documents, 3 boards".
+  #[pb(index = 2)]
+  pub facets: Vec<SearchFacetCountPB>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SearchFacetCountPB {
+  /// A `ViewLayoutPB` value.
+  #[pb(index = 1)]
+  pub view_layout: i64,
+
+  #[pb(index = 2)]
+  pub count: u64,
 }
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
@@ -101,6 +117,40 @@ pub struct LocalSearchResponseItemPB {
 
   #[pb(index = 4)]
   pub workspace_id: String,
+
+  /// One of `LocalSearchTantivySchema`'s `TYPE_*` values, e.g. `document`
+  /// or `database_row`.
+  #[pb(index = 5)]
+  pub object_type: String,
+
+  /// Plain-text snippet around the matched terms, empty when no snippet
+  /// could be generated.
+  #[pb(index = 6)]
+  pub snippet: String,
+
+  /// Byte ranges of the matched terms within `snippet`, so the client can
+  /// render the context with the matches bolded.
+  #[pb(index = 7)]
+  pub highlights: Vec<SearchHighlightRangePB>,
+
+  /// The index field the snippet was taken from: `content` when the body (or
+  /// a database row cell) matched, `name` when only the title matched.
+  #[pb(index = 8)]
+  pub matched_field: String,
+
+  /// Whether the view sits in the trash. Only `true` when the search ran
+  /// with `SearchFilterPB.include_trash`.
+  #[pb(index = 9)]
+  pub trashed: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct SearchHighlightRangePB {
+  #[pb(index = 1)]
+  pub start: u64,
+
+  #[pb(index = 2)]
+  pub end: u64,
 }
 
 #[derive(ProtoBuf_Enum, Clone, Debug, PartialEq, Eq, Default)]
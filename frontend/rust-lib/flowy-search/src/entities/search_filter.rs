@@ -4,4 +4,36 @@ use flowy_derive::ProtoBuf;
 pub struct SearchFilterPB {
   #[pb(index = 1)]
   pub workspace_id: String,
+
+  /// Restrict results to the given object types, matching
+  /// `LocalSearchTantivySchema`'s `TYPE_*` values. Empty means no
+  /// restriction.
+  #[pb(index = 2)]
+  pub object_types: Vec<String>,
+
+  /// Restrict results to the given view layouts, matching `ViewLayoutPB`
+  /// values. Empty means no restriction.
+  #[pb(index = 3)]
+  pub view_layouts: Vec<i64>,
+
+  /// Restrict results to views inside this space or parent view. Empty means
+  /// no restriction.
+  #[pb(index = 4)]
+  pub parent_view_id: String,
+
+  /// Restrict results to views created by this user.
+  #[pb(index = 5, one_of)]
+  pub created_by: Option<i64>,
+
+  /// Keep results last edited at or after this unix timestamp.
+  #[pb(index = 6, one_of)]
+  pub modified_after: Option<i64>,
+
+  /// Keep results last edited at or before this unix timestamp.
+  #[pb(index = 7, one_of)]
+  pub modified_before: Option<i64>,
+
+  /// Also return views that sit in the trash, flagged for UI badging.
+  #[pb(index = 8)]
+  pub include_trash: bool,
 }
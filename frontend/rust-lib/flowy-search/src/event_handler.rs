@@ -1,9 +1,14 @@
 use std::sync::{Arc, Weak};
 
-use crate::{entities::SearchQueryPB, services::manager::SearchManager};
+use crate::{
+  entities::{IndexStatusPB, SearchQueryPB},
+  services::manager::SearchManager,
+};
 use flowy_error::{FlowyError, FlowyResult};
-use lib_dispatch::prelude::{AFPluginData, AFPluginState};
+use flowy_search_pub::index_coordinator::{IndexCommand, get_index_coordinator};
+use lib_dispatch::prelude::{AFPluginData, AFPluginState, DataResult, data_result_ok};
 use lib_infra::util::timestamp;
+use uuid::Uuid;
 
 fn upgrade_manager(
   search_manager: AFPluginState<Weak<SearchManager>>,
@@ -29,8 +34,41 @@ pub(crate) async fn stream_search_handler(
   }
 
   manager
-    .perform_search(query.search, query.stream_port, search_id)
+    .perform_search(query.search, query.filter, query.stream_port, search_id)
     .await;
 
   Ok(())
 }
+
+fn current_workspace_id(manager: &SearchManager) -> FlowyResult<Uuid> {
+  manager
+    .current_workspace_id()
+    .ok_or_else(|| FlowyError::internal().with_context("No workspace opened"))
+}
+
+#[tracing::instrument(level = "debug", skip(manager), err)]
+pub(crate) async fn get_index_status_handler(
+  manager: AFPluginState<Weak<SearchManager>>,
+) -> DataResult<IndexStatusPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let workspace_id = current_workspace_id(&manager)?;
+  let status = get_index_coordinator(&workspace_id)
+    .map(|coordinator| coordinator.status())
+    .unwrap_or_default();
+  data_result_ok(IndexStatusPB {
+    indexed: status.indexed,
+    pending: status.pending,
+    last_error: status.last_error.unwrap_or_default(),
+  })
+}
+
+#[tracing::instrument(level = "info", skip(manager), err)]
+pub(crate) async fn rebuild_search_index_handler(
+  manager: AFPluginState<Weak<SearchManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let workspace_id = current_workspace_id(&manager)?;
+  let coordinator = get_index_coordinator(&workspace_id)
+    .ok_or_else(|| FlowyError::internal().with_context("Index coordinator not initialized"))?;
+  coordinator.enqueue(IndexCommand::Rebuild).await
+}
@@ -4,13 +4,18 @@ use strum_macros::Display;
 use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
 use lib_dispatch::prelude::*;
 
-use crate::{event_handler::stream_search_handler, services::manager::SearchManager};
+use crate::{
+  event_handler::{get_index_status_handler, rebuild_search_index_handler, stream_search_handler},
+  services::manager::SearchManager,
+};
 
 pub fn init(search_manager: Weak<SearchManager>) -> AFPlugin {
   AFPlugin::new()
     .state(search_manager)
     .name(env!("CARGO_PKG_NAME"))
     .event(SearchEvent::StreamSearch, stream_search_handler)
+    .event(SearchEvent::GetIndexStatus, get_index_status_handler)
+    .event(SearchEvent::RebuildSearchIndex, rebuild_search_index_handler)
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
@@ -18,4 +23,13 @@ pub fn init(search_manager: Weak<SearchManager>) -> AFPlugin {
 pub enum SearchEvent {
   #[event(input = "SearchQueryPB")]
   StreamSearch = 0,
+
+  /// Returns how much index work has been done and whether indexing hit an
+  /// error.
+  #[event(output = "IndexStatusPB")]
+  GetIndexStatus = 1,
+
+  /// Drops and repopulates the search index of the current workspace, e.g.
+  /// after corruption.
+  RebuildSearchIndex = 2,
 }
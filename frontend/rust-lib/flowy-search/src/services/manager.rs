@@ -1,5 +1,5 @@
 use crate::document::local_search_handler::DocumentLocalSearchHandler;
-use crate::entities::{SearchResponsePB, SearchStatePB};
+use crate::entities::{SearchFilterPB, SearchResponsePB, SearchStatePB};
 use allo_isolate::Isolate;
 use arc_swap::ArcSwapOption;
 use dashmap::DashMap;
@@ -31,6 +31,7 @@ pub trait SearchHandler: Send + Sync + 'static {
   async fn perform_search(
     &self,
     query: String,
+    filter: Option<SearchFilterPB>,
     workspace_id: &Uuid,
   ) -> Pin<Box<dyn Stream<Item = FlowyResult<SearchResponsePB>> + Send + 'static>>;
 }
@@ -63,6 +64,10 @@ impl SearchManager {
     self.handlers.get(&search_type).map(|h| h.value().clone())
   }
 
+  pub fn current_workspace_id(&self) -> Option<Uuid> {
+    self.workspace_id.load_full().map(|id| *id)
+  }
+
   fn create_local_document_search(&self, state: Option<Weak<RwLock<DocumentTantivyState>>>) {
     if let Some(state) = state {
       let handler = DocumentLocalSearchHandler::new(state);
@@ -111,13 +116,26 @@ impl SearchManager {
     self.create_local_document_search(state);
   }
 
-  pub async fn perform_search(&self, query: String, stream_port: i64, search_id: i64) {
+  pub async fn perform_search(
+    &self,
+    query: String,
+    filter: Option<SearchFilterPB>,
+    stream_port: i64,
+    search_id: i64,
+  ) {
     let sink = IsolateSink::new(Isolate::new(stream_port));
-    self.perform_search_with_sink(query, sink, search_id).await;
+    self
+      .perform_search_with_sink(query, filter, sink, search_id)
+      .await;
   }
 
-  pub async fn perform_search_with_sink<S>(&self, query: String, mut sink: S, search_id: i64)
-  where
+  pub async fn perform_search_with_sink<S>(
+    &self,
+    query: String,
+    filter: Option<SearchFilterPB>,
+    mut sink: S,
+    search_id: i64,
+  ) where
     S: Sink<Vec<u8>> + Clone + Send + Unpin + 'static,
     S::Error: std::fmt::Display,
   {
@@ -159,6 +177,7 @@ impl SearchManager {
     for handler in handlers.iter().map(|entry| entry.value().clone()) {
       let mut sink_clone = sink.clone();
       let query_clone = query.clone();
+      let filter_clone = filter.clone();
       let current_search_clone = current_search.clone();
       let workspace_id_clone = workspace_id.clone();
 
@@ -170,7 +189,7 @@ impl SearchManager {
         }
 
         let mut stream = handler
-          .perform_search(query_clone.clone(), &workspace_id_clone)
+          .perform_search(query_clone.clone(), filter_clone, &workspace_id_clone)
           .await;
 
         while let Some(Ok(search_result)) = stream.next().await {
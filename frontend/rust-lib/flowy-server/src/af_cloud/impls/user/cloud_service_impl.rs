@@ -332,6 +332,20 @@ where
     Ok(())
   }
 
+  async fn transfer_workspace_ownership(
+    &self,
+    new_owner_email: String,
+    workspace_id: Uuid,
+  ) -> Result<(), FlowyError> {
+    let try_get_client = self.server.try_get_client();
+    let changeset =
+      WorkspaceMemberChangeset::new(new_owner_email).with_role(to_af_role(Role::Owner));
+    try_get_client?
+      .update_workspace_member(&workspace_id, changeset)
+      .await?;
+    Ok(())
+  }
+
   async fn get_workspace_members(
     &self,
     workspace_id: Uuid,
@@ -257,7 +257,7 @@ impl ChatCloudService for LocalChatServiceImpl {
     let rag_ids = deserialize_rag_ids(&row.rag_ids);
     let metadata = deserialize_chat_metadata::<Value>(&row.metadata);
     let setting = ChatSettings {
-      name: "".to_string(),
+      name: row.name,
       rag_ids,
       metadata,
     };
@@ -279,6 +279,7 @@ impl ChatCloudService for LocalChatServiceImpl {
       rag_ids: s.rag_ids.map(|s| serialize_rag_ids(&s)),
       is_sync: None,
       summary: None,
+      name: s.name,
     };
 
     update_chat(db, changeset)?;
@@ -1,5 +1,5 @@
 use flowy_ai_pub::cloud::search_dto::{SearchContentType, SearchDocumentResponseItem};
-use flowy_search_pub::entities::TanvitySearchResponseItem;
+use flowy_search_pub::entities::{TanvitySearchFilter, TanvitySearchResponseItem};
 use flowy_search_pub::tantivy_state::DocumentTantivyState;
 use serde::{Deserialize, Deserializer};
 use std::sync::Weak;
@@ -33,12 +33,17 @@ pub async fn tanvity_local_search(
       None
     },
     Some(state) => {
+      let filter = TanvitySearchFilter {
+        object_ids,
+        ..Default::default()
+      };
       let results = state
         .read()
         .await
-        .search(workspace_id, query, object_ids, limit, score_threshold)
+        .search(workspace_id, query, filter, limit, score_threshold)
         .ok()?;
       let items = results
+        .items
         .into_iter()
         .flat_map(|v| tanvity_document_to_search_document(*workspace_id, v))
         .collect::<Vec<_>>();
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS reminder_schedule_table (
+  reminder_id TEXT NOT NULL PRIMARY KEY,
+  object_id TEXT NOT NULL,
+  scheduled_at BIGINT NOT NULL,
+  is_fired BOOLEAN NOT NULL DEFAULT FALSE
+);
@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS row_comment_table (
+  comment_id TEXT NOT NULL PRIMARY KEY,
+  row_id TEXT NOT NULL,
+  reply_comment_id TEXT,
+  uid BIGINT NOT NULL,
+  content TEXT NOT NULL,
+  reactions TEXT NOT NULL DEFAULT '',
+  created_at BIGINT NOT NULL,
+  updated_at BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_row_comment_row_id ON row_comment_table (row_id);
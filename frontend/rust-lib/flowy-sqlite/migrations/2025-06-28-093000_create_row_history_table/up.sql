@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS row_history_table (
+  id INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+  row_id TEXT NOT NULL,
+  field_id TEXT NOT NULL,
+  old_value TEXT NOT NULL DEFAULT '',
+  new_value TEXT NOT NULL DEFAULT '',
+  uid BIGINT NOT NULL,
+  created_at BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_row_history_row_id ON row_history_table (row_id);
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS row_template_table (
+  template_id TEXT NOT NULL PRIMARY KEY,
+  database_id TEXT NOT NULL,
+  name TEXT NOT NULL DEFAULT '',
+  cells TEXT NOT NULL DEFAULT '',
+  icon TEXT,
+  cover TEXT,
+  source_document_id TEXT,
+  is_default BOOLEAN NOT NULL DEFAULT FALSE,
+  created_at BIGINT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_row_template_database_id ON row_template_table (database_id);
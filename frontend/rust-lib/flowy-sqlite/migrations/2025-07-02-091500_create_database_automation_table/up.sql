@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE database_automation_table (
+  trigger_id TEXT NOT NULL PRIMARY KEY,
+  database_id TEXT NOT NULL DEFAULT '',
+  trigger_type INTEGER NOT NULL DEFAULT 0,
+  trigger_config TEXT NOT NULL DEFAULT '',
+  action_type INTEGER NOT NULL DEFAULT 0,
+  action_config TEXT NOT NULL DEFAULT '',
+  enabled BOOLEAN NOT NULL DEFAULT TRUE,
+  created_at BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX idx_database_automation_database_id ON database_automation_table (database_id);
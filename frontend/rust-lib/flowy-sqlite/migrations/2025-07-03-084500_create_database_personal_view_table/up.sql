@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE database_personal_view_table (
+  view_id TEXT NOT NULL,
+  uid BIGINT NOT NULL,
+  filters TEXT NOT NULL DEFAULT '[]',
+  sorts TEXT NOT NULL DEFAULT '[]',
+  group_settings TEXT NOT NULL DEFAULT '[]',
+  updated_at BIGINT NOT NULL DEFAULT 0,
+  PRIMARY KEY (view_id, uid)
+);
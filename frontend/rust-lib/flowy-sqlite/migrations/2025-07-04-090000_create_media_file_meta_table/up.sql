@@ -0,0 +1,12 @@
+-- Your SQL goes here
+CREATE TABLE media_file_meta_table (
+  file_id TEXT NOT NULL PRIMARY KEY,
+  database_id TEXT NOT NULL DEFAULT '',
+  width INTEGER NOT NULL DEFAULT 0,
+  height INTEGER NOT NULL DEFAULT 0,
+  duration BIGINT NOT NULL DEFAULT 0,
+  mime TEXT NOT NULL DEFAULT '',
+  thumbnail_path TEXT NOT NULL DEFAULT '',
+  updated_at BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX idx_media_file_meta_database_id ON media_file_meta_table (database_id);
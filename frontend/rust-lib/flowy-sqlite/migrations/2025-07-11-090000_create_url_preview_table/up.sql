@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE url_preview_table (
+  url TEXT NOT NULL PRIMARY KEY,
+  title TEXT NOT NULL DEFAULT '',
+  favicon_url TEXT NOT NULL DEFAULT '',
+  image_url TEXT NOT NULL DEFAULT '',
+  fetched_at BIGINT NOT NULL DEFAULT 0
+);
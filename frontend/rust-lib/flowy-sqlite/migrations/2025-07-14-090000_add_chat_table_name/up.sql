@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE chat_table ADD COLUMN name TEXT NOT NULL DEFAULT '';
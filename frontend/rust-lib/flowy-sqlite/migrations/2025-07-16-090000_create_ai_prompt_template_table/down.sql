@@ -0,0 +1 @@
+DROP TABLE ai_prompt_template_table;
@@ -0,0 +1,10 @@
+CREATE TABLE ai_prompt_template_table (
+  id TEXT PRIMARY KEY NOT NULL,
+  name TEXT NOT NULL,
+  description TEXT NOT NULL DEFAULT '',
+  template TEXT NOT NULL,
+  preferred_model TEXT NOT NULL DEFAULT '',
+  temperature DOUBLE,
+  created_at BIGINT NOT NULL,
+  updated_at BIGINT NOT NULL
+);
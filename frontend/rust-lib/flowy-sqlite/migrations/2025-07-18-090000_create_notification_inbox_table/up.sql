@@ -0,0 +1,12 @@
+CREATE TABLE notification_inbox_table (
+  id TEXT PRIMARY KEY NOT NULL,
+  kind INTEGER NOT NULL,
+  title TEXT NOT NULL,
+  body TEXT NOT NULL DEFAULT '',
+  object_id TEXT NOT NULL DEFAULT '',
+  sender TEXT NOT NULL DEFAULT '',
+  workspace_id TEXT NOT NULL DEFAULT '',
+  created_at BIGINT NOT NULL,
+  is_read BOOLEAN NOT NULL DEFAULT FALSE,
+  is_archived BOOLEAN NOT NULL DEFAULT FALSE
+);
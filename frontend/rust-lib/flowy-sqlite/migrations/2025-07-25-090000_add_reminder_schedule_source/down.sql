@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE reminder_schedule_table DROP COLUMN source;
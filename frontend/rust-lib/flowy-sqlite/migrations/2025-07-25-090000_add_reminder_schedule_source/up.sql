@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE reminder_schedule_table ADD COLUMN source INTEGER NOT NULL DEFAULT 0;
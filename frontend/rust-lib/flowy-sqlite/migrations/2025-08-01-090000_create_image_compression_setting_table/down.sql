@@ -0,0 +1 @@
+DROP TABLE image_compression_setting_table;
@@ -0,0 +1,8 @@
+CREATE TABLE image_compression_setting_table (
+  workspace_id TEXT PRIMARY KEY NOT NULL,
+  enabled BOOLEAN NOT NULL DEFAULT FALSE,
+  max_dimension INTEGER NOT NULL DEFAULT 2048,
+  quality INTEGER NOT NULL DEFAULT 80,
+  convert_format INTEGER NOT NULL DEFAULT 0,
+  keep_original BOOLEAN NOT NULL DEFAULT TRUE
+);
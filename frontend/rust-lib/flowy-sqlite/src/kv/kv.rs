@@ -20,11 +20,27 @@ pub struct KVStorePreferences {
 impl KVStorePreferences {
   #[tracing::instrument(level = "trace", err)]
   pub fn new(root: &str) -> Result<Self, anyhow::Error> {
+    Self::new_with_key(root, None)
+  }
+
+  /// Same as [`new`](Self::new), but keys the database with the given
+  /// SQLCipher key. An existing unencrypted database is migrated in place the
+  /// first time a key is provided. Requires the `sqlcipher` feature.
+  pub fn new_with_key(root: &str, encryption_key: Option<&str>) -> Result<Self, anyhow::Error> {
     if !Path::new(root).exists() {
       return Err(anyhow!("Init StorePreferences failed. {} not exists", root));
     }
 
-    let pool_config = PoolConfig::default();
+    let mut pool_config = PoolConfig::default();
+    if let Some(encryption_key) = encryption_key {
+      if !cfg!(feature = "sqlcipher") {
+        return Err(anyhow!(
+          "flowy-sqlite was built without the sqlcipher feature"
+        ));
+      }
+      crate::sqlite_impl::encrypt_plain_database(root, DB_NAME, encryption_key)?;
+      pool_config = pool_config.encryption_key(encryption_key.to_string());
+    }
     let database = Database::new(root, DB_NAME, pool_config).unwrap();
     let mut conn = database.get_connection().unwrap();
     sql_query(KV_SQL).execute(&mut conn).unwrap();
@@ -11,7 +11,10 @@ pub use diesel::*;
 pub use diesel_derives::*;
 use diesel_migrations::{EmbeddedMigrations, MigrationHarness};
 
-pub use crate::sqlite_impl::{ConnectionPool, DBConnection, Database, PoolConfig};
+pub use crate::sqlite_impl::{
+  ConnectionPool, DBConnection, Database, DatabaseStats, IntegrityReport, MaintenanceExtension,
+  PoolConfig, SQLiteAutoVacuum, TableRowCount,
+};
 
 pub mod kv;
 mod sqlite_impl;
@@ -30,11 +33,32 @@ pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("../flowy-sqlite/mi
 pub const DB_NAME: &str = "flowy-database.db";
 
 pub fn init<P: AsRef<Path>>(storage_path: P) -> Result<Database, io::Error> {
+  init_with_key(storage_path, None)
+}
+
+/// Same as [`init`], but keys the database with the given SQLCipher key. An
+/// existing unencrypted database is migrated in place the first time a key is
+/// provided. Requires the `sqlcipher` feature; without it, opening with a key
+/// is refused rather than silently storing the data in plain text.
+pub fn init_with_key<P: AsRef<Path>>(
+  storage_path: P,
+  encryption_key: Option<&str>,
+) -> Result<Database, io::Error> {
   let storage_path = storage_path.as_ref().to_str().unwrap();
   if !Path::new(storage_path).exists() {
     std::fs::create_dir_all(storage_path)?;
   }
-  let pool_config = PoolConfig::default();
+  let mut pool_config = PoolConfig::default();
+  if let Some(encryption_key) = encryption_key {
+    if !cfg!(feature = "sqlcipher") {
+      return Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "flowy-sqlite was built without the sqlcipher feature",
+      ));
+    }
+    crate::sqlite_impl::encrypt_plain_database(storage_path, DB_NAME, encryption_key)?;
+    pool_config = pool_config.encryption_key(encryption_key.to_string());
+  }
   let database = Database::new(storage_path, DB_NAME, pool_config).map_err(as_io_error)?;
   let mut conn = database.get_connection().map_err(as_io_error)?;
   (*conn)
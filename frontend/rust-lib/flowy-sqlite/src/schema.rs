@@ -9,6 +9,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    ai_prompt_template_table (id) {
+        id -> Text,
+        name -> Text,
+        description -> Text,
+        template -> Text,
+        preferred_model -> Text,
+        temperature -> Nullable<Double>,
+        created_at -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
 diesel::table! {
     chat_local_setting_table (chat_id) {
         chat_id -> Text,
@@ -39,6 +52,7 @@ diesel::table! {
         rag_ids -> Nullable<Text>,
         is_sync -> Bool,
         summary -> Text,
+        name -> Text,
     }
 }
 
@@ -54,6 +68,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    database_automation_table (trigger_id) {
+        trigger_id -> Text,
+        database_id -> Text,
+        trigger_type -> Integer,
+        trigger_config -> Text,
+        action_type -> Integer,
+        action_config -> Text,
+        enabled -> Bool,
+        created_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    database_personal_view_table (view_id, uid) {
+        view_id -> Text,
+        uid -> BigInt,
+        filters -> Text,
+        sorts -> Text,
+        group_settings -> Text,
+        updated_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    image_compression_setting_table (workspace_id) {
+        workspace_id -> Text,
+        enabled -> Bool,
+        max_dimension -> Integer,
+        quality -> Integer,
+        convert_format -> Integer,
+        keep_original -> Bool,
+    }
+}
+
 diesel::table! {
     index_collab_record_table (oid) {
         oid -> Text,
@@ -69,6 +118,83 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    media_file_meta_table (file_id) {
+        file_id -> Text,
+        database_id -> Text,
+        width -> Integer,
+        height -> Integer,
+        duration -> BigInt,
+        mime -> Text,
+        thumbnail_path -> Text,
+        updated_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    notification_inbox_table (id) {
+        id -> Text,
+        kind -> Integer,
+        title -> Text,
+        body -> Text,
+        object_id -> Text,
+        sender -> Text,
+        workspace_id -> Text,
+        created_at -> BigInt,
+        is_read -> Bool,
+        is_archived -> Bool,
+    }
+}
+
+diesel::table! {
+    reminder_schedule_table (reminder_id) {
+        reminder_id -> Text,
+        object_id -> Text,
+        scheduled_at -> BigInt,
+        is_fired -> Bool,
+        source -> Integer,
+    }
+}
+
+diesel::table! {
+    row_comment_table (comment_id) {
+        comment_id -> Text,
+        row_id -> Text,
+        reply_comment_id -> Nullable<Text>,
+        uid -> BigInt,
+        content -> Text,
+        reactions -> Text,
+        created_at -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    row_history_table (id) {
+        id -> Integer,
+        row_id -> Text,
+        field_id -> Text,
+        old_value -> Text,
+        new_value -> Text,
+        uid -> BigInt,
+        created_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    row_template_table (template_id) {
+        template_id -> Text,
+        database_id -> Text,
+        name -> Text,
+        cells -> Text,
+        icon -> Nullable<Text>,
+        cover -> Nullable<Text>,
+        source_document_id -> Nullable<Text>,
+        is_default -> Bool,
+        created_at -> BigInt,
+    }
+}
+
 diesel::table! {
     upload_file_part (upload_id, e_tag) {
         upload_id -> Text,
@@ -92,6 +218,16 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    url_preview_table (url) {
+        url -> Text,
+        title -> Text,
+        favicon_url -> Text,
+        image_url -> Text,
+        fetched_at -> BigInt,
+    }
+}
+
 diesel::table! {
     user_data_migration_records (id) {
         id -> Integer,
@@ -172,14 +308,25 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
   af_collab_metadata,
+  ai_prompt_template_table,
   chat_local_setting_table,
   chat_message_table,
   chat_table,
   collab_snapshot,
+  database_automation_table,
+  database_personal_view_table,
+  image_compression_setting_table,
   index_collab_record_table,
   local_ai_model_table,
+  media_file_meta_table,
+  notification_inbox_table,
+  reminder_schedule_table,
+  row_comment_table,
+  row_history_table,
+  row_template_table,
   upload_file_part,
   upload_file_table,
+  url_preview_table,
   user_data_migration_records,
   user_table,
   user_workspace_table,
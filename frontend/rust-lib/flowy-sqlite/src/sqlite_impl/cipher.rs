@@ -0,0 +1,65 @@
+use std::io::{self, Read};
+use std::path::Path;
+
+use diesel::{Connection, SqliteConnection};
+
+use crate::sqlite_impl::conn_ext::ConnectionExtension;
+
+const PLAIN_SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+/// One-time migration of an existing plain database into a SQLCipher
+/// encrypted copy. The plain database is exported into a sibling file with
+/// `sqlcipher_export`, which then atomically replaces the original. Does
+/// nothing when the database is missing or already encrypted, so it is safe
+/// to call on every open.
+pub(crate) fn encrypt_plain_database(dir: &str, name: &str, key: &str) -> io::Result<()> {
+  let db_path = Path::new(dir).join(name);
+  if !db_path.exists() {
+    return Ok(());
+  }
+
+  // An encrypted database never starts with the plain-text SQLite header.
+  let mut header = [0u8; 16];
+  let mut file = std::fs::File::open(&db_path)?;
+  if file.read_exact(&mut header).is_err() || &header != PLAIN_SQLITE_HEADER {
+    return Ok(());
+  }
+  drop(file);
+
+  tracing::info!("Encrypting existing database at {:?}", db_path);
+  let encrypted_path = db_path.with_extension("encrypting");
+  if encrypted_path.exists() {
+    std::fs::remove_file(&encrypted_path)?;
+  }
+
+  let db_uri = db_path
+    .to_str()
+    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid database path"))?;
+  let encrypted_uri = encrypted_path
+    .to_str()
+    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid database path"))?;
+
+  let mut conn = SqliteConnection::establish(db_uri).map_err(as_io_error)?;
+  conn
+    .exec(format!(
+      "ATTACH DATABASE '{}' AS encrypted KEY '{}'",
+      encrypted_uri.replace('\'', "''"),
+      key.replace('\'', "''")
+    ))
+    .map_err(as_io_error)?;
+  conn
+    .exec("SELECT sqlcipher_export('encrypted')")
+    .map_err(as_io_error)?;
+  conn
+    .exec("DETACH DATABASE encrypted")
+    .map_err(as_io_error)?;
+  drop(conn);
+
+  std::fs::rename(&encrypted_path, &db_path)?;
+  tracing::info!("Encrypted database at {:?}", db_path);
+  Ok(())
+}
+
+fn as_io_error<E: std::fmt::Debug>(e: E) -> io::Error {
+  io::Error::new(io::ErrorKind::Other, format!("{:?}", e))
+}
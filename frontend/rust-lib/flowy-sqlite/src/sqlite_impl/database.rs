@@ -4,6 +4,7 @@ use r2d2::PooledConnection;
 
 use crate::sqlite_impl::{
   errors::*,
+  maintenance::{DatabaseStats, IntegrityReport, MaintenanceExtension},
   pool::{ConnectionManager, ConnectionPool, PoolConfig},
 };
 
@@ -42,6 +43,28 @@ impl Database {
   pub fn get_pool(&self) -> Arc<ConnectionPool> {
     self.pool.clone()
   }
+
+  /// Collects file size, page usage and per-table row counts.
+  pub fn stats(&self) -> Result<DatabaseStats> {
+    let mut conn = self.get_connection()?;
+    let mut stats = (*conn).database_stats()?;
+    stats.file_size = std::fs::metadata(&self.uri).map(|m| m.len()).unwrap_or(0);
+    Ok(stats)
+  }
+
+  /// Rebuilds the database file to reclaim free pages and refreshes the
+  /// query planner statistics.
+  pub fn vacuum(&self) -> Result<()> {
+    let mut conn = self.get_connection()?;
+    (*conn).vacuum()?;
+    (*conn).analyze()?;
+    Ok(())
+  }
+
+  pub fn integrity_check(&self) -> Result<IntegrityReport> {
+    let mut conn = self.get_connection()?;
+    (*conn).integrity_check()
+  }
 }
 
 pub fn db_file_uri(dir: &str, name: &str) -> String {
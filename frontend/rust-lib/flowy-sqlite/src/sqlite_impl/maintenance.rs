@@ -0,0 +1,188 @@
+#![allow(clippy::upper_case_acronyms)]
+
+use anyhow::anyhow;
+use std::{
+  convert::{TryFrom, TryInto},
+  fmt,
+};
+
+use diesel::{
+  dsl::sql,
+  expression::SqlLiteral,
+  query_dsl::load_dsl::LoadQuery,
+  sql_types::{BigInt, SingleValue, Text},
+  RunQueryDsl, SqliteConnection,
+};
+
+use crate::sqlite_impl::conn_ext::ConnectionExtension;
+use crate::sqlite_impl::errors::{Error, Result};
+use crate::sqlite_impl::pragma::PragmaExtension;
+
+/// Maintenance operations for long-lived databases. Deleted rows leave free
+/// pages behind, so the file keeps growing until it is vacuumed; this trait
+/// exposes the reclaim and inspection commands in one place.
+pub trait MaintenanceExtension: PragmaExtension {
+  fn query_all<'query, ST, T>(&mut self, query: &str) -> Result<Vec<T>>
+  where
+    SqlLiteral<ST>: LoadQuery<'query, SqliteConnection, T>,
+    ST: SingleValue;
+
+  /// Rebuilds the database file, reclaiming free pages. Requires exclusive
+  /// access and temporarily doubles the disk usage of the database.
+  fn vacuum(&mut self) -> Result<()> {
+    tracing::trace!("SQLITE VACUUM");
+    self.exec("VACUUM")?;
+    Ok(())
+  }
+
+  /// Refreshes the statistics the query planner uses to pick indexes.
+  fn analyze(&mut self) -> Result<()> {
+    tracing::trace!("SQLITE ANALYZE");
+    self.exec("ANALYZE")?;
+    Ok(())
+  }
+
+  /// Runs `PRAGMA integrity_check` and collects the reported problems.
+  fn integrity_check(&mut self) -> Result<IntegrityReport> {
+    let messages = self.query_all::<Text, String>("PRAGMA integrity_check")?;
+    let ok = messages.len() == 1 && messages[0] == "ok";
+    Ok(IntegrityReport {
+      ok,
+      messages: if ok { vec![] } else { messages },
+    })
+  }
+
+  /// Counts the rows of every user table, largest first.
+  fn table_row_counts(&mut self) -> Result<Vec<TableRowCount>> {
+    let names = self.query_all::<Text, String>(
+      "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    )?;
+    let mut tables = Vec::with_capacity(names.len());
+    for name in names {
+      let row_count = self.query::<BigInt, i64>(&format!(
+        "SELECT COUNT(*) FROM \"{}\"",
+        name.replace('"', "\"\"")
+      ))?;
+      tables.push(TableRowCount { name, row_count });
+    }
+    tables.sort_by(|a, b| b.row_count.cmp(&a.row_count));
+    Ok(tables)
+  }
+
+  /// Collects page usage and per-table row counts. The file size is filled
+  /// in by the caller, which knows the database path.
+  fn database_stats(&mut self) -> Result<DatabaseStats> {
+    let page_size = self.pragma_get::<BigInt, i64>("page_size", None)?;
+    let page_count = self.pragma_get::<BigInt, i64>("page_count", None)?;
+    let freelist_count = self.pragma_get::<BigInt, i64>("freelist_count", None)?;
+    let tables = self.table_row_counts()?;
+    Ok(DatabaseStats {
+      file_size: 0,
+      page_size,
+      page_count,
+      freelist_count,
+      tables,
+    })
+  }
+
+  /// Changes the auto-vacuum mode. For an existing database the new mode
+  /// only takes effect after the next full [`vacuum`](Self::vacuum).
+  fn pragma_set_auto_vacuum(&mut self, mode: SQLiteAutoVacuum) -> Result<()> {
+    self.pragma("auto_vacuum", mode as u8, None)
+  }
+
+  fn pragma_get_auto_vacuum(&mut self) -> Result<SQLiteAutoVacuum> {
+    self
+      .pragma_get::<BigInt, i64>("auto_vacuum", None)?
+      .try_into()
+  }
+
+  /// Releases free pages back to the filesystem without rebuilding the whole
+  /// file. Only effective when auto-vacuum is `INCREMENTAL`; `pages` limits
+  /// how many pages are freed per call, `None` frees all of them.
+  fn incremental_vacuum(&mut self, pages: Option<u32>) -> Result<()> {
+    let query = match pages {
+      Some(pages) => format!("PRAGMA incremental_vacuum({})", pages),
+      None => "PRAGMA incremental_vacuum".to_string(),
+    };
+    tracing::trace!("SQLITE {}", query);
+    self.exec(&query)?;
+    Ok(())
+  }
+}
+
+impl MaintenanceExtension for SqliteConnection {
+  fn query_all<'query, ST, T>(&mut self, query: &str) -> Result<Vec<T>>
+  where
+    SqlLiteral<ST>: LoadQuery<'query, SqliteConnection, T>,
+    ST: SingleValue,
+  {
+    Ok(sql::<ST>(query).load(self)?)
+  }
+}
+
+/// Outcome of `PRAGMA integrity_check`. When `ok` is false, `messages`
+/// holds the problems SQLite reported, one per row.
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityReport {
+  pub ok: bool,
+  pub messages: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableRowCount {
+  pub name: String,
+  pub row_count: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseStats {
+  pub file_size: u64,
+  pub page_size: i64,
+  pub page_count: i64,
+  /// Pages that hold no data but still take up space in the file. A large
+  /// freelist is the signal that a vacuum is worthwhile.
+  pub freelist_count: i64,
+  pub tables: Vec<TableRowCount>,
+}
+
+impl DatabaseStats {
+  /// Bytes a full vacuum would reclaim, estimated from the freelist.
+  pub fn reclaimable_bytes(&self) -> u64 {
+    self.freelist_count.max(0) as u64 * self.page_size.max(0) as u64
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SQLiteAutoVacuum {
+  NONE = 0,
+  FULL = 1,
+  INCREMENTAL = 2,
+}
+
+impl fmt::Display for SQLiteAutoVacuum {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::NONE => "NONE",
+        Self::FULL => "FULL",
+        Self::INCREMENTAL => "INCREMENTAL",
+      }
+    )
+  }
+}
+
+impl TryFrom<i64> for SQLiteAutoVacuum {
+  type Error = Error;
+
+  fn try_from(v: i64) -> Result<Self> {
+    match v {
+      0 => Ok(Self::NONE),
+      1 => Ok(Self::FULL),
+      2 => Ok(Self::INCREMENTAL),
+      _ => Err(anyhow!("Unknown value {} for AutoVacuum", v).into()),
+    }
+  }
+}
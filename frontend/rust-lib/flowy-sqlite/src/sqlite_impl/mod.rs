@@ -1,11 +1,15 @@
+mod cipher;
 mod conn_ext;
 mod database;
 #[allow(deprecated, clippy::large_enum_variant)]
 mod errors;
+mod maintenance;
 mod pool;
 mod pragma;
 
+pub(crate) use cipher::encrypt_plain_database;
 pub use database::*;
+pub use maintenance::*;
 pub use pool::*;
 
 pub use errors::Error;
@@ -4,7 +4,7 @@ use diesel::{connection::Connection, r2d2::R2D2Connection, SqliteConnection};
 use r2d2::{CustomizeConnection, ManageConnection, Pool};
 use scheduled_thread_pool::ScheduledThreadPool;
 
-use crate::sqlite_impl::{errors::*, pragma::*};
+use crate::sqlite_impl::{errors::*, maintenance::*, pragma::*};
 
 pub struct ConnectionPool {
   pub(crate) inner: Pool<ConnectionManager>,
@@ -31,7 +31,11 @@ impl ConnectionPool {
         .build(),
     );
     let config = Arc::new(config);
-    let customizer_config = DatabaseCustomizerConfig::default();
+    let customizer_config = DatabaseCustomizerConfig {
+      auto_vacuum: config.auto_vacuum,
+      encryption_key: config.encryption_key.clone(),
+      ..Default::default()
+    };
 
     let pool = r2d2::Pool::builder()
       .thread_pool(thread_pool)
@@ -54,6 +58,8 @@ pub struct PoolConfig {
   max_size: u32,
   connection_timeout: Duration,
   idle_timeout: Duration,
+  auto_vacuum: Option<SQLiteAutoVacuum>,
+  encryption_key: Option<String>,
 }
 
 impl Default for PoolConfig {
@@ -63,6 +69,8 @@ impl Default for PoolConfig {
       max_size: 10,
       connection_timeout: Duration::from_secs(10),
       idle_timeout: Duration::from_secs(5 * 60),
+      auto_vacuum: None,
+      encryption_key: None,
     }
   }
 }
@@ -79,6 +87,21 @@ impl PoolConfig {
     self.max_size = max_size;
     self
   }
+
+  /// Applies the auto-vacuum mode on every connection. For an existing
+  /// database the mode only takes effect after a full vacuum.
+  #[allow(dead_code)]
+  pub fn auto_vacuum(mut self, auto_vacuum: SQLiteAutoVacuum) -> Self {
+    self.auto_vacuum = Some(auto_vacuum);
+    self
+  }
+
+  /// Keys every connection with the given SQLCipher key. Only effective when
+  /// flowy-sqlite is built with the `sqlcipher` feature.
+  pub fn encryption_key(mut self, encryption_key: String) -> Self {
+    self.encryption_key = Some(encryption_key);
+    self
+  }
 }
 
 pub struct ConnectionManager {
@@ -115,6 +138,8 @@ pub struct DatabaseCustomizerConfig {
   pub(crate) busy_timeout: i32,
   #[allow(dead_code)]
   pub(crate) secure_delete: bool,
+  pub(crate) auto_vacuum: Option<SQLiteAutoVacuum>,
+  pub(crate) encryption_key: Option<String>,
 }
 
 impl Default for DatabaseCustomizerConfig {
@@ -124,6 +149,8 @@ impl Default for DatabaseCustomizerConfig {
       synchronous: SQLiteSynchronous::NORMAL,
       busy_timeout: 5000,
       secure_delete: true,
+      auto_vacuum: None,
+      encryption_key: None,
     }
   }
 }
@@ -144,11 +171,18 @@ impl DatabaseCustomizer {
 
 impl CustomizeConnection<SqliteConnection, crate::sqlite_impl::Error> for DatabaseCustomizer {
   fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<()> {
+    // The cipher key has to reach the connection before any other statement.
+    if let Some(encryption_key) = &self.config.encryption_key {
+      conn.pragma_set_cipher_key(encryption_key)?;
+    }
     conn.pragma_set_busy_timeout(self.config.busy_timeout)?;
     if self.config.journal_mode != SQLiteJournalMode::WAL {
       conn.pragma_set_journal_mode(self.config.journal_mode, None)?;
     }
     conn.pragma_set_synchronous(self.config.synchronous, None)?;
+    if let Some(auto_vacuum) = self.config.auto_vacuum {
+      conn.pragma_set_auto_vacuum(auto_vacuum)?;
+    }
 
     Ok(())
   }
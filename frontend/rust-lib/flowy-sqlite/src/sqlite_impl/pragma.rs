@@ -65,6 +65,14 @@ pub trait PragmaExtension: ConnectionExtension {
     self.query::<ST, T>(&query)
   }
 
+  /// Keys the connection of a SQLCipher database. Must be the first statement
+  /// that touches the database. Deliberately not routed through
+  /// [`pragma`](Self::pragma), so the key never reaches the logs.
+  fn pragma_set_cipher_key(&mut self, key: &str) -> Result<()> {
+    self.exec(format!("PRAGMA key = '{}'", key.replace('\'', "''")))?;
+    Ok(())
+  }
+
   #[allow(dead_code)]
   fn pragma_set_busy_timeout(&mut self, timeout_ms: i32) -> Result<i32> {
     self.pragma_ret::<Integer, i32, i32>("busy_timeout", timeout_ms, None)
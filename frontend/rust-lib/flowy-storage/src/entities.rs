@@ -1,4 +1,5 @@
-use flowy_derive::ProtoBuf;
+use crate::sqlite_sql::ImageCompressionSettingTable;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 
 #[derive(Default, ProtoBuf, Clone, Debug)]
 pub struct RegisterStreamPB {
@@ -20,3 +21,154 @@ pub struct FileStatePB {
   #[pb(index = 2)]
   pub is_finish: bool,
 }
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct UploadConcurrencyPB {
+  /// How many uploads may run at the same time, clamped to 1..=8.
+  #[pb(index = 1)]
+  pub limit: u64,
+}
+
+/// An unfinished upload together with how far it got, derived from the
+/// chunks persisted in sqlite.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct PendingUploadPB {
+  #[pb(index = 1)]
+  pub file_id: String,
+
+  #[pb(index = 2)]
+  pub parent_dir: String,
+
+  #[pb(index = 3)]
+  pub local_file_path: String,
+
+  #[pb(index = 4)]
+  pub created_at: i64,
+
+  #[pb(index = 5)]
+  pub num_chunk: i64,
+
+  #[pb(index = 6)]
+  pub completed_chunks: i64,
+
+  /// Fraction of the chunks that already reached the server, 0.0..=1.0.
+  #[pb(index = 7)]
+  pub progress: f64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct RepeatedPendingUploadPB {
+  #[pb(index = 1)]
+  pub items: Vec<PendingUploadPB>,
+}
+
+#[derive(Default, ProtoBuf, Clone)]
+pub struct StoreClipboardImagePB {
+  #[pb(index = 1)]
+  pub data: Vec<u8>,
+
+  /// Mime type of the pasted data, e.g. "image/png".
+  #[pb(index = 2)]
+  pub mime: String,
+
+  /// The id of the document the image is pasted into; it becomes the parent
+  /// directory of the uploaded object.
+  #[pb(index = 3)]
+  pub parent_dir: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ClipboardImagePB {
+  /// The object url to embed; it resolves once the upload completes.
+  #[pb(index = 1)]
+  pub url: String,
+
+  /// Serves the image until the upload completes. Derived from the content
+  /// hash, so pasting the same bytes again yields the same path.
+  #[pb(index = 2)]
+  pub local_file_path: String,
+
+  #[pb(index = 3)]
+  pub file_id: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct FileCacheBudgetPB {
+  /// How many bytes the attachment cache may hold. Zero keeps the current
+  /// budget.
+  #[pb(index = 1)]
+  pub budget_bytes: u64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct FileCacheStatsPB {
+  #[pb(index = 1)]
+  pub file_count: u64,
+
+  #[pb(index = 2)]
+  pub total_bytes: u64,
+
+  #[pb(index = 3)]
+  pub budget_bytes: u64,
+}
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Eq, PartialEq, Default)]
+pub enum ImageConvertFormatPB {
+  #[default]
+  Jpeg = 0,
+  Webp = 1,
+}
+
+/// The per-workspace settings for compressing images before they are
+/// uploaded. The workspace the setting belongs to is derived from the
+/// session, not carried in the payload.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ImageCompressionSettingPB {
+  #[pb(index = 1)]
+  pub enabled: bool,
+
+  /// Images whose longest edge exceeds this are downscaled, in pixels.
+  #[pb(index = 2)]
+  pub max_dimension: i32,
+
+  /// JPEG encoding quality, 1..=100. Ignored for WebP which is lossless.
+  #[pb(index = 3)]
+  pub quality: i32,
+
+  #[pb(index = 4)]
+  pub convert_format: ImageConvertFormatPB,
+
+  /// When false, the picked file is replaced with the compressed copy.
+  #[pb(index = 5)]
+  pub keep_original: bool,
+}
+
+impl From<ImageCompressionSettingTable> for ImageCompressionSettingPB {
+  fn from(setting: ImageCompressionSettingTable) -> Self {
+    Self {
+      enabled: setting.enabled,
+      max_dimension: setting.max_dimension,
+      quality: setting.quality,
+      convert_format: if setting.convert_format == 1 {
+        ImageConvertFormatPB::Webp
+      } else {
+        ImageConvertFormatPB::Jpeg
+      },
+      keep_original: setting.keep_original,
+    }
+  }
+}
+
+impl From<ImageCompressionSettingPB> for ImageCompressionSettingTable {
+  fn from(pb: ImageCompressionSettingPB) -> Self {
+    Self {
+      // Filled in with the current workspace when the setting is persisted.
+      workspace_id: String::new(),
+      enabled: pb.enabled,
+      max_dimension: pb.max_dimension.clamp(256, 8192),
+      quality: pb.quality.clamp(1, 100),
+      convert_format: pb.convert_format as i32,
+      keep_original: pb.keep_original,
+    }
+  }
+}
@@ -1,4 +1,8 @@
-use crate::entities::{FileStatePB, QueryFilePB, RegisterStreamPB};
+use crate::entities::{
+  ClipboardImagePB, FileCacheBudgetPB, FileCacheStatsPB, FileStatePB, ImageCompressionSettingPB,
+  QueryFilePB, RegisterStreamPB, RepeatedPendingUploadPB, StoreClipboardImagePB,
+  UploadConcurrencyPB,
+};
 use crate::manager::StorageManager;
 use flowy_error::{FlowyError, FlowyResult};
 use lib_dispatch::prelude::{AFPluginData, AFPluginState, DataResult, data_result_ok};
@@ -36,3 +40,83 @@ pub(crate) async fn query_file_handler(
   })?;
   data_result_ok(pb)
 }
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn set_upload_concurrency_handler(
+  data: AFPluginData<UploadConcurrencyPB>,
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  let data = data.into_inner();
+  manager.set_max_concurrent_uploads(data.limit.min(u8::MAX as u64) as u8);
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_pending_uploads_handler(
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> DataResult<RepeatedPendingUploadPB, FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  let items = manager.get_pending_uploads().await?;
+  data_result_ok(RepeatedPendingUploadPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_image_compression_setting_handler(
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> DataResult<ImageCompressionSettingPB, FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  let setting = manager.get_image_compression_setting().await?;
+  data_result_ok(ImageCompressionSettingPB::from(setting))
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn update_image_compression_setting_handler(
+  data: AFPluginData<ImageCompressionSettingPB>,
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  let setting = data.into_inner().into();
+  manager.update_image_compression_setting(setting).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn set_file_cache_budget_handler(
+  data: AFPluginData<FileCacheBudgetPB>,
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  manager.set_file_cache_budget(data.into_inner().budget_bytes);
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_file_cache_stats_handler(
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> DataResult<FileCacheStatsPB, FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  let stats = manager.get_file_cache_stats().await?;
+  data_result_ok(stats)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn clear_file_cache_handler(
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> DataResult<FileCacheStatsPB, FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  let stats = manager.clear_file_cache().await?;
+  data_result_ok(stats)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn store_clipboard_image_handler(
+  data: AFPluginData<StoreClipboardImagePB>,
+  storage_manager: AFPluginState<Weak<StorageManager>>,
+) -> DataResult<ClipboardImagePB, FlowyError> {
+  let manager = upgrade_storage_manager(storage_manager)?;
+  let data = data.into_inner();
+  let image = manager
+    .store_clipboard_image(&data.parent_dir, data.data, &data.mime)
+    .await?;
+  data_result_ok(image)
+}
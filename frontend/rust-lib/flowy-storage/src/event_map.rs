@@ -1,4 +1,9 @@
-use crate::event_handler::{query_file_handler, register_stream_handler};
+use crate::event_handler::{
+  clear_file_cache_handler, get_file_cache_stats_handler, get_image_compression_setting_handler,
+  get_pending_uploads_handler, query_file_handler, register_stream_handler,
+  set_file_cache_budget_handler, set_upload_concurrency_handler, store_clipboard_image_handler,
+  update_image_compression_setting_handler,
+};
 use crate::manager::StorageManager;
 use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
 use lib_dispatch::prelude::*;
@@ -11,6 +16,32 @@ pub fn init(manager: Weak<StorageManager>) -> AFPlugin {
     .state(manager)
     .event(FileStorageEvent::RegisterStream, register_stream_handler)
     .event(FileStorageEvent::QueryFile, query_file_handler)
+    .event(
+      FileStorageEvent::SetUploadConcurrency,
+      set_upload_concurrency_handler,
+    )
+    .event(FileStorageEvent::GetPendingUploads, get_pending_uploads_handler)
+    .event(
+      FileStorageEvent::GetImageCompressionSetting,
+      get_image_compression_setting_handler,
+    )
+    .event(
+      FileStorageEvent::UpdateImageCompressionSetting,
+      update_image_compression_setting_handler,
+    )
+    .event(
+      FileStorageEvent::SetFileCacheBudget,
+      set_file_cache_budget_handler,
+    )
+    .event(
+      FileStorageEvent::GetFileCacheStats,
+      get_file_cache_stats_handler,
+    )
+    .event(FileStorageEvent::ClearFileCache, clear_file_cache_handler)
+    .event(
+      FileStorageEvent::StoreClipboardImage,
+      store_clipboard_image_handler,
+    )
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
@@ -22,4 +53,37 @@ pub enum FileStorageEvent {
 
   #[event(input = "QueryFilePB", output = "FileStatePB")]
   QueryFile = 1,
+
+  /// Cap how many uploads run at the same time
+  #[event(input = "UploadConcurrencyPB")]
+  SetUploadConcurrency = 2,
+
+  /// List the unfinished uploads together with their chunk progress
+  #[event(output = "RepeatedPendingUploadPB")]
+  GetPendingUploads = 3,
+
+  /// The image compression setting of the current workspace
+  #[event(output = "ImageCompressionSettingPB")]
+  GetImageCompressionSetting = 4,
+
+  /// Configure how images are compressed before they are uploaded
+  #[event(input = "ImageCompressionSettingPB")]
+  UpdateImageCompressionSetting = 5,
+
+  /// Cap how many bytes the attachment cache may hold
+  #[event(input = "FileCacheBudgetPB")]
+  SetFileCacheBudget = 6,
+
+  /// The size, file count and budget of the attachment cache
+  #[event(output = "FileCacheStatsPB")]
+  GetFileCacheStats = 7,
+
+  /// Remove cached files that are not part of a pending upload
+  #[event(output = "FileCacheStatsPB")]
+  ClearFileCache = 8,
+
+  /// Store pasted image bytes, deduplicated by content hash, and schedule
+  /// their upload
+  #[event(input = "StoreClipboardImagePB", output = "ClipboardImagePB")]
+  StoreClipboardImage = 9,
 }
@@ -1,7 +1,10 @@
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
 use tokio::fs::{self, File};
 use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
-use tracing::error;
+use tracing::{error, info, trace};
 
 /// [FileTempStorage] is used to store the temporary files for uploading. After the file is uploaded,
 /// the file will be deleted.
@@ -87,3 +90,132 @@ impl FileTempStorage {
     Ok(())
   }
 }
+
+/// The default cache budget, 1 GiB.
+const DEFAULT_CACHE_BUDGET_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Byte count and file count of the attachment cache.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileCacheStats {
+  pub file_count: u64,
+  pub total_bytes: u64,
+}
+
+/// Keeps the attachment cache directory within a size budget by evicting the
+/// least recently used files. Files that belong to a pending upload are never
+/// evicted since their bytes have not reached the server yet. The budget is
+/// held in memory; the frontend re-applies its persisted preference on
+/// startup.
+pub struct FileCacheManager {
+  cache_dir: PathBuf,
+  budget_bytes: AtomicU64,
+}
+
+impl FileCacheManager {
+  pub fn new(cache_dir: PathBuf) -> Self {
+    Self {
+      cache_dir,
+      budget_bytes: AtomicU64::new(DEFAULT_CACHE_BUDGET_BYTES),
+    }
+  }
+
+  pub fn budget_bytes(&self) -> u64 {
+    self.budget_bytes.load(Ordering::SeqCst)
+  }
+
+  /// A budget of zero keeps the default; eviction always spares pending
+  /// uploads no matter how small the budget is.
+  pub fn set_budget_bytes(&self, budget_bytes: u64) {
+    if budget_bytes == 0 {
+      return;
+    }
+    self.budget_bytes.store(budget_bytes, Ordering::SeqCst);
+    info!("[File] cache budget set to {} bytes", budget_bytes);
+  }
+
+  /// Scans the cache directory and returns its current size and file count.
+  pub fn stats(&self) -> FileCacheStats {
+    let mut stats = FileCacheStats::default();
+    for (_, _, len) in self.cached_files() {
+      stats.file_count += 1;
+      stats.total_bytes += len;
+    }
+    stats
+  }
+
+  /// Evicts the least recently used files until the cache fits the budget.
+  /// Returns the number of bytes that were freed.
+  pub fn evict_to_budget(&self, protected: &HashSet<PathBuf>) -> u64 {
+    let budget = self.budget_bytes();
+    let mut files = self.cached_files();
+    let mut total_bytes: u64 = files.iter().map(|(_, _, len)| *len).sum();
+    if total_bytes <= budget {
+      return 0;
+    }
+
+    // Oldest first. The access time is not reliably maintained on every
+    // platform, so it falls back to the modification time as recency signal.
+    files.sort_by_key(|(_, recency, _)| *recency);
+    let mut freed: u64 = 0;
+    for (path, _, len) in files {
+      if total_bytes <= budget {
+        break;
+      }
+      if protected.contains(&path) {
+        trace!("[File] skip evicting pending upload: {:?}", path);
+        continue;
+      }
+      match std::fs::remove_file(&path) {
+        Ok(_) => {
+          total_bytes -= len;
+          freed += len;
+          trace!("[File] evicted cached file: {:?}", path);
+        },
+        Err(err) => error!("[File] evict cached file {:?} failed: {}", path, err),
+      }
+    }
+    if freed > 0 {
+      info!("[File] cache eviction freed {} bytes", freed);
+    }
+    freed
+  }
+
+  /// Removes every cached file that is not part of a pending upload. Returns
+  /// the number of bytes that were freed.
+  pub fn clear(&self, protected: &HashSet<PathBuf>) -> u64 {
+    let mut freed: u64 = 0;
+    for (path, _, len) in self.cached_files() {
+      if protected.contains(&path) {
+        continue;
+      }
+      match std::fs::remove_file(&path) {
+        Ok(_) => freed += len,
+        Err(err) => error!("[File] remove cached file {:?} failed: {}", path, err),
+      }
+    }
+    info!("[File] cleared file cache, freed {} bytes", freed);
+    freed
+  }
+
+  /// Every regular file in the cache directory with its recency and size.
+  fn cached_files(&self) -> Vec<(PathBuf, SystemTime, u64)> {
+    let entries = match std::fs::read_dir(&self.cache_dir) {
+      Ok(entries) => entries,
+      Err(_) => return Vec::new(),
+    };
+    entries
+      .flatten()
+      .filter_map(|entry| {
+        let metadata = entry.metadata().ok()?;
+        if !metadata.is_file() {
+          return None;
+        }
+        let recency = metadata
+          .accessed()
+          .or_else(|_| metadata.modified())
+          .unwrap_or(SystemTime::UNIX_EPOCH);
+        Some((entry.path(), recency, metadata.len()))
+      })
+      .collect()
+  }
+}
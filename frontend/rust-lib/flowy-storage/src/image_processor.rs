@@ -0,0 +1,128 @@
+use crate::sqlite_sql::ImageCompressionSettingTable;
+use flowy_error::{FlowyError, FlowyResult};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use tracing::{info, trace};
+
+/// The target format images are converted to before upload. JPEG honors the
+/// configured quality; WebP is always encoded losslessly because the encoder
+/// shipped with the `image` crate has no lossy mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageConvertFormat {
+  Jpeg = 0,
+  Webp = 1,
+}
+
+impl From<i32> for ImageConvertFormat {
+  fn from(value: i32) -> Self {
+    match value {
+      1 => ImageConvertFormat::Webp,
+      _ => ImageConvertFormat::Jpeg,
+    }
+  }
+}
+
+/// Compresses the image at `path` in place according to the workspace
+/// setting: downscales it when either edge exceeds the configured max
+/// dimension and re-encodes it in the configured format. Returns the path of
+/// the processed file, which differs from the input when the conversion
+/// changed the extension, or `None` when the file was left untouched.
+///
+/// Only formats the bundled decoder understands (PNG, JPEG, WebP) are
+/// processed; everything else, including HEIC, is uploaded as is. Images with
+/// an alpha channel are never converted to JPEG since that would discard
+/// transparency; they are re-encoded as PNG instead. The rewrite is kept only
+/// when it actually shrank the file or the image was downscaled.
+pub(crate) fn compress_image_for_upload(
+  path: &Path,
+  setting: &ImageCompressionSettingTable,
+) -> FlowyResult<Option<PathBuf>> {
+  let source_format = match ImageFormat::from_path(path) {
+    Ok(format @ (ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP)) => format,
+    _ => {
+      trace!("[File] skip image compression, unsupported format: {:?}", path);
+      return Ok(None);
+    },
+  };
+  let original_size = path.metadata()?.len();
+  let image = image::open(path)
+    .map_err(|err| FlowyError::internal().with_context(format!("decode image failed: {}", err)))?;
+
+  let max_dimension = setting.max_dimension.max(1) as u32;
+  let resized = image.width() > max_dimension || image.height() > max_dimension;
+  let image = if resized {
+    image.resize(max_dimension, max_dimension, FilterType::Lanczos3)
+  } else {
+    image
+  };
+
+  let target_format = match ImageConvertFormat::from(setting.convert_format) {
+    // JPEG can not represent transparency; fall back to PNG for such images.
+    ImageConvertFormat::Jpeg if image.color().has_alpha() => ImageFormat::Png,
+    ImageConvertFormat::Jpeg => ImageFormat::Jpeg,
+    ImageConvertFormat::Webp => ImageFormat::WebP,
+  };
+
+  let output_path = path.with_extension(
+    target_format
+      .extensions_str()
+      .first()
+      .copied()
+      .unwrap_or("jpg"),
+  );
+  encode_image(&image, &output_path, target_format, setting.quality)?;
+
+  let output_size = output_path.metadata()?.len();
+  if !resized && output_size >= original_size {
+    trace!(
+      "[File] discard compressed image, {} bytes -> {} bytes: {:?}",
+      original_size, output_size, path
+    );
+    if output_path != path {
+      let _ = std::fs::remove_file(&output_path);
+    } else {
+      // The rewrite replaced the source; restore it from the decoded image.
+      encode_image(&image, &output_path, source_format, 100)?;
+    }
+    return Ok(None);
+  }
+
+  if output_path != path {
+    let _ = std::fs::remove_file(path);
+  }
+  info!(
+    "[File] compressed image for upload, {} bytes -> {} bytes: {:?}",
+    original_size, output_size, output_path
+  );
+  Ok(Some(output_path))
+}
+
+fn encode_image(
+  image: &DynamicImage,
+  path: &Path,
+  format: ImageFormat,
+  quality: i32,
+) -> FlowyResult<()> {
+  let file = File::create(path)?;
+  let mut writer = BufWriter::new(file);
+  let result = match format {
+    ImageFormat::Jpeg => {
+      let quality = quality.clamp(1, 100) as u8;
+      DynamicImage::ImageRgb8(image.to_rgb8())
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut writer, quality))
+    },
+    ImageFormat::WebP => DynamicImage::ImageRgba8(image.to_rgba8())
+      .write_with_encoder(WebPEncoder::new_lossless(&mut writer)),
+    _ => image.write_with_encoder(PngEncoder::new(&mut writer)),
+  };
+  result.map_err(|err| {
+    let _ = std::fs::remove_file(path);
+    FlowyError::internal().with_context(format!("encode image failed: {}", err))
+  })
+}
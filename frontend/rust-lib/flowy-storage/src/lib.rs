@@ -2,6 +2,7 @@ mod entities;
 mod event_handler;
 pub mod event_map;
 mod file_cache;
+mod image_processor;
 pub mod manager;
 mod notification;
 mod protobuf;
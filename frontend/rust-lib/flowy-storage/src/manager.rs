@@ -1,11 +1,13 @@
-use crate::entities::FileStatePB;
-use crate::file_cache::FileTempStorage;
+use crate::entities::{ClipboardImagePB, FileCacheStatsPB, FileStatePB, PendingUploadPB};
+use crate::file_cache::{FileCacheManager, FileTempStorage};
+use crate::image_processor::compress_image_for_upload;
 use crate::notification::{StorageNotification, make_notification};
 use crate::sqlite_sql::{
-  UploadFilePartTable, UploadFileTable, batch_select_upload_file, delete_all_upload_parts,
-  delete_upload_file, delete_upload_file_by_file_id, insert_upload_file, insert_upload_part,
-  is_upload_completed, is_upload_exist, select_upload_file, select_upload_parts,
-  update_upload_file_completed, update_upload_file_upload_id,
+  ImageCompressionSettingTable, UploadFilePartTable, UploadFileTable, batch_select_upload_file,
+  delete_all_upload_parts, delete_upload_file, delete_upload_file_by_file_id, insert_upload_file,
+  insert_upload_part, is_upload_completed, is_upload_exist, select_all_unfinished_upload_files,
+  select_image_compression_setting, select_upload_file, select_upload_parts,
+  update_upload_file_completed, update_upload_file_upload_id, upsert_image_compression_setting,
 };
 use crate::uploader::{FileUploader, FileUploaderRunner, Signal, UploadTask, UploadTaskQueue};
 use allo_isolate::Isolate;
@@ -22,11 +24,13 @@ use flowy_storage_pub::storage::{
 };
 use lib_infra::box_any::BoxAny;
 use lib_infra::isolate_stream::{IsolateSink, SinkExt};
-use lib_infra::util::timestamp;
+use lib_infra::util::{md5, timestamp};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
+use std::time::Duration;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::{broadcast, watch};
 use tracing::{debug, error, info, instrument, trace};
@@ -45,6 +49,7 @@ pub struct StorageManager {
   cloud_service: Arc<dyn StorageCloudService>,
   user_service: Arc<dyn StorageUserService>,
   uploader: Arc<FileUploader>,
+  cache_manager: Arc<FileCacheManager>,
   progress_notifiers: Arc<DashMap<String, ProgressNotifier>>,
   global_notifier: GlobalNotifier,
 }
@@ -66,7 +71,8 @@ impl StorageManager {
       user_service.get_application_root_dir()
     ));
     let (global_notifier, _) = broadcast::channel(2000);
-    let temp_storage = Arc::new(FileTempStorage::new(temp_storage_path));
+    let temp_storage = Arc::new(FileTempStorage::new(temp_storage_path.clone()));
+    let cache_manager = Arc::new(FileCacheManager::new(temp_storage_path));
     let (notifier, notifier_rx) = watch::channel(Signal::Proceed);
     let task_queue = Arc::new(UploadTaskQueue::new(notifier));
     let progress_notifiers = Arc::new(DashMap::new());
@@ -125,11 +131,14 @@ impl StorageManager {
       }
     });
 
+    spawn_cache_eviction(Arc::downgrade(&cache_manager), user_service.clone());
+
     Self {
       storage_service,
       cloud_service,
       user_service,
       uploader,
+      cache_manager,
       progress_notifiers,
       global_notifier,
     }
@@ -231,6 +240,204 @@ impl StorageManager {
     let tasks = self.uploader.all_tasks().await;
     Ok(tasks)
   }
+
+  /// Caps how many uploads run at the same time.
+  pub fn set_max_concurrent_uploads(&self, limit: u8) {
+    self.uploader.set_max_uploads(limit);
+  }
+
+  /// The unfinished uploads of the current workspace, newest first, together
+  /// with how far each one got.
+  pub async fn get_pending_uploads(&self) -> FlowyResult<Vec<PendingUploadPB>> {
+    let uid = self.user_service.user_id()?;
+    let workspace_id = self.user_service.workspace_id()?;
+    let conn = self.user_service.sqlite_connection(uid)?;
+    let upload_files = batch_select_upload_file(conn, &workspace_id.to_string(), 100, false)?;
+
+    let mut conn = self.user_service.sqlite_connection(uid)?;
+    let mut items = Vec::with_capacity(upload_files.len());
+    for upload_file in upload_files {
+      // Uploads that haven't reached the server yet have no upload id and
+      // therefore no completed chunks.
+      let completed_chunks = if upload_file.upload_id.is_empty() {
+        0
+      } else {
+        select_upload_parts(&mut conn, &upload_file.upload_id)?.len() as i64
+      };
+      let num_chunk = i64::from(upload_file.num_chunk.max(1));
+      items.push(PendingUploadPB {
+        file_id: upload_file.file_id,
+        parent_dir: upload_file.parent_dir,
+        local_file_path: upload_file.local_file_path,
+        created_at: upload_file.created_at,
+        num_chunk,
+        completed_chunks,
+        progress: (completed_chunks as f64 / num_chunk as f64).clamp(0.0, 1.0),
+      });
+    }
+    Ok(items)
+  }
+
+  /// The image compression setting of the current workspace, falling back to
+  /// the defaults when the workspace never configured it.
+  pub async fn get_image_compression_setting(&self) -> FlowyResult<ImageCompressionSettingTable> {
+    let uid = self.user_service.user_id()?;
+    let workspace_id = self.user_service.workspace_id()?.to_string();
+    let mut conn = self.user_service.sqlite_connection(uid)?;
+    let setting = select_image_compression_setting(&mut conn, &workspace_id)?
+      .unwrap_or_else(|| ImageCompressionSettingTable::default_for_workspace(workspace_id));
+    Ok(setting)
+  }
+
+  /// Persists the image compression setting for the current workspace. It
+  /// applies to uploads created after the update; queued uploads keep the
+  /// bytes they were created with.
+  pub async fn update_image_compression_setting(
+    &self,
+    mut setting: ImageCompressionSettingTable,
+  ) -> FlowyResult<()> {
+    setting.workspace_id = self.user_service.workspace_id()?.to_string();
+    let uid = self.user_service.user_id()?;
+    let mut conn = self.user_service.sqlite_connection(uid)?;
+    upsert_image_compression_setting(&mut conn, &setting)?;
+    info!("[File] image compression setting updated: {:?}", setting);
+    Ok(())
+  }
+
+  /// Writes pasted image bytes to disk and schedules their upload. The file
+  /// is named after the content hash, so pasting the same image twice reuses
+  /// one file and one upload. Returns the object url the editor can embed
+  /// immediately together with the local path that serves the image until
+  /// the upload completes.
+  pub async fn store_clipboard_image(
+    &self,
+    parent_dir: &str,
+    data: Vec<u8>,
+    mime: &str,
+  ) -> FlowyResult<ClipboardImagePB> {
+    if data.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("clipboard image data is empty"));
+    }
+    if !mime.starts_with("image/") {
+      return Err(
+        FlowyError::invalid_data().with_context(format!("not an image mime type: {}", mime)),
+      );
+    }
+
+    let extension = mime_guess::get_mime_extensions_str(mime)
+      .and_then(|extensions| extensions.first())
+      .copied()
+      .unwrap_or("png");
+    let file_name = format!("clipboard_{}.{}", md5(&data), extension);
+    // Clipboard images live outside the upload temp dir so the local path
+    // stays valid after the temp copy is removed on upload completion.
+    let dir = PathBuf::from(format!(
+      "{}/clipboard_images",
+      self.user_service.get_application_root_dir()
+    ));
+    tokio::fs::create_dir_all(&dir).await?;
+    let file_path = dir.join(file_name);
+    if !file_path.exists() {
+      tokio::fs::write(&file_path, &data).await?;
+    }
+    let local_file_path = file_path.to_string_lossy().to_string();
+
+    let workspace_id = self.user_service.workspace_id()?.to_string();
+    let (upload, _) = self
+      .storage_service
+      .create_upload(&workspace_id, parent_dir, &local_file_path)
+      .await?;
+    Ok(ClipboardImagePB {
+      url: upload.url,
+      local_file_path,
+      file_id: upload.file_id,
+    })
+  }
+
+  /// Caps how many bytes the attachment cache may hold before the least
+  /// recently used files are evicted. Takes effect immediately.
+  pub fn set_file_cache_budget(&self, budget_bytes: u64) {
+    self.cache_manager.set_budget_bytes(budget_bytes);
+    let cache_manager = self.cache_manager.clone();
+    let user_service = self.user_service.clone();
+    tokio::task::spawn_blocking(move || {
+      let protected = pending_upload_paths(&user_service);
+      cache_manager.evict_to_budget(&protected);
+    });
+  }
+
+  pub async fn get_file_cache_stats(&self) -> FlowyResult<FileCacheStatsPB> {
+    let cache_manager = self.cache_manager.clone();
+    let stats = tokio::task::spawn_blocking(move || cache_manager.stats())
+      .await
+      .map_err(|err| FlowyError::internal().with_context(err))?;
+    Ok(FileCacheStatsPB {
+      file_count: stats.file_count,
+      total_bytes: stats.total_bytes,
+      budget_bytes: self.cache_manager.budget_bytes(),
+    })
+  }
+
+  /// Removes every cached file that is not part of a pending upload and
+  /// returns the stats of what is left.
+  pub async fn clear_file_cache(&self) -> FlowyResult<FileCacheStatsPB> {
+    let cache_manager = self.cache_manager.clone();
+    let user_service = self.user_service.clone();
+    let stats = tokio::task::spawn_blocking(move || {
+      let protected = pending_upload_paths(&user_service);
+      cache_manager.clear(&protected);
+      cache_manager.stats()
+    })
+    .await
+    .map_err(|err| FlowyError::internal().with_context(err))?;
+    Ok(FileCacheStatsPB {
+      file_count: stats.file_count,
+      total_bytes: stats.total_bytes,
+      budget_bytes: self.cache_manager.budget_bytes(),
+    })
+  }
+}
+
+/// The local files of unfinished uploads; those must survive cache eviction.
+fn pending_upload_paths(user_service: &Arc<dyn StorageUserService>) -> HashSet<PathBuf> {
+  let files = user_service
+    .user_id()
+    .and_then(|uid| user_service.sqlite_connection(uid))
+    .and_then(|mut conn| select_all_unfinished_upload_files(&mut conn));
+  match files {
+    Ok(files) => files
+      .into_iter()
+      .map(|file| PathBuf::from(file.local_file_path))
+      .collect(),
+    Err(err) => {
+      error!("[File] read pending uploads for cache eviction failed: {}", err);
+      HashSet::new()
+    },
+  }
+}
+
+/// Periodically trims the attachment cache back to its budget. The task ends
+/// once the cache manager is dropped.
+fn spawn_cache_eviction(
+  cache_manager: std::sync::Weak<FileCacheManager>,
+  user_service: Arc<dyn StorageUserService>,
+) {
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(Duration::from_secs(30 * 60));
+    loop {
+      interval.tick().await;
+      let cache_manager = match cache_manager.upgrade() {
+        Some(cache_manager) => cache_manager,
+        None => break,
+      };
+      let user_service = user_service.clone();
+      let _ = tokio::task::spawn_blocking(move || {
+        let protected = pending_upload_paths(&user_service);
+        cache_manager.evict_to_budget(&protected);
+      })
+      .await;
+    }
+  });
 }
 
 async fn prepare_upload_task(
@@ -267,6 +474,78 @@ pub struct StorageServiceImpl {
   global_notifier: GlobalNotifier,
 }
 
+impl StorageServiceImpl {
+  /// Runs the workspace's image compression setting against the temp copy of
+  /// the file about to be uploaded and returns the path to upload. Compression
+  /// is best effort: when it is disabled, the file is not a supported image or
+  /// the rewrite fails, the original temp copy is uploaded unchanged.
+  async fn apply_image_compression(
+    &self,
+    workspace_id: &str,
+    source_file_path: &str,
+    temp_file_path: String,
+  ) -> String {
+    let setting = self
+      .user_service
+      .user_id()
+      .and_then(|uid| self.user_service.sqlite_connection(uid))
+      .and_then(|mut conn| select_image_compression_setting(&mut conn, workspace_id));
+    let setting = match setting {
+      Ok(Some(setting)) if setting.enabled => setting,
+      Ok(_) => return temp_file_path,
+      Err(err) => {
+        error!("[File] read image compression setting failed: {}", err);
+        return temp_file_path;
+      },
+    };
+
+    let path = PathBuf::from(&temp_file_path);
+    let compress_setting = setting.clone();
+    let result = tokio::task::spawn_blocking(move || {
+      compress_image_for_upload(&path, &compress_setting)
+    })
+    .await;
+    match result {
+      Ok(Ok(Some(compressed_path))) => {
+        if !setting.keep_original {
+          replace_original_with_compressed(source_file_path, &compressed_path).await;
+        }
+        compressed_path.to_string_lossy().to_string()
+      },
+      Ok(Ok(None)) => temp_file_path,
+      Ok(Err(err)) => {
+        error!("[File] compress image failed: {}", err);
+        temp_file_path
+      },
+      Err(err) => {
+        error!("[File] compress image task failed: {}", err);
+        temp_file_path
+      },
+    }
+  }
+}
+
+/// Replaces the original file the user picked with the compressed copy,
+/// adjusting the extension when the conversion changed the format. Used when
+/// the workspace opted out of keeping originals.
+async fn replace_original_with_compressed(source_file_path: &str, compressed_path: &Path) {
+  let source = Path::new(source_file_path);
+  let extension = compressed_path
+    .extension()
+    .map(|ext| ext.to_os_string())
+    .unwrap_or_default();
+  let target = source.with_extension(extension);
+  if let Err(err) = tokio::fs::copy(compressed_path, &target).await {
+    error!("[File] replace original with compressed copy failed: {}", err);
+    return;
+  }
+  if target != source {
+    if let Err(err) = tokio::fs::remove_file(source).await {
+      error!("[File] remove original after compression failed: {}", err);
+    }
+  }
+}
+
 #[async_trait]
 impl StorageService for StorageServiceImpl {
   async fn delete_object(&self, url: String) -> FlowyResult<()> {
@@ -389,6 +668,11 @@ impl StorageService for StorageServiceImpl {
           .with_context(format!("create temp file for upload file failed: {}", err))
       })?;
 
+    // Compress images before they are chunked when the workspace opted in.
+    let local_file_path = self
+      .apply_image_compression(&workspace_id, &file_path, local_file_path)
+      .await;
+
     // 1. create a file record and chunk the file
     let record = create_upload_record(workspace_id, parent_dir, local_file_path.clone()).await?;
     // 2. save the record to sqlite
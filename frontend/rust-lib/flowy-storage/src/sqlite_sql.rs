@@ -1,7 +1,7 @@
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_sqlite::result::DatabaseErrorKind;
 use flowy_sqlite::result::Error::DatabaseError;
-use flowy_sqlite::schema::{upload_file_part, upload_file_table};
+use flowy_sqlite::schema::{image_compression_setting_table, upload_file_part, upload_file_table};
 use flowy_sqlite::{
   AsChangeset, BoolExpressionMethods, DBConnection, ExpressionMethods, Identifiable, Insertable,
   OptionalExtension, QueryDsl, Queryable, RunQueryDsl, SqliteConnection, diesel,
@@ -33,6 +33,53 @@ pub struct UploadFilePartTable {
   pub part_num: i32,
 }
 
+/// Per-workspace settings for compressing images before they are uploaded.
+#[derive(Queryable, Insertable, AsChangeset, Identifiable, Debug, Clone)]
+#[diesel(table_name = image_compression_setting_table)]
+#[diesel(primary_key(workspace_id))]
+pub struct ImageCompressionSettingTable {
+  pub workspace_id: String,
+  pub enabled: bool,
+  pub max_dimension: i32,
+  pub quality: i32,
+  pub convert_format: i32,
+  pub keep_original: bool,
+}
+
+impl ImageCompressionSettingTable {
+  pub fn default_for_workspace(workspace_id: String) -> Self {
+    Self {
+      workspace_id,
+      enabled: false,
+      max_dimension: 2048,
+      quality: 80,
+      convert_format: 0,
+      keep_original: true,
+    }
+  }
+}
+
+pub fn select_image_compression_setting(
+  conn: &mut SqliteConnection,
+  workspace_id: &str,
+) -> FlowyResult<Option<ImageCompressionSettingTable>> {
+  let result = image_compression_setting_table::dsl::image_compression_setting_table
+    .filter(image_compression_setting_table::workspace_id.eq(workspace_id))
+    .first::<ImageCompressionSettingTable>(conn)
+    .optional()?;
+  Ok(result)
+}
+
+pub fn upsert_image_compression_setting(
+  conn: &mut SqliteConnection,
+  setting: &ImageCompressionSettingTable,
+) -> FlowyResult<()> {
+  diesel::replace_into(image_compression_setting_table::table)
+    .values(setting)
+    .execute(conn)?;
+  Ok(())
+}
+
 pub fn is_upload_file_exist(
   conn: &mut SqliteConnection,
   workspace_id: &str,
@@ -239,6 +286,17 @@ pub fn batch_select_upload_file(
   Ok(results)
 }
 
+/// All unfinished uploads across workspaces. Used to protect their local
+/// files from cache eviction.
+pub fn select_all_unfinished_upload_files(
+  conn: &mut SqliteConnection,
+) -> FlowyResult<Vec<UploadFileTable>> {
+  let results = upload_file_table::dsl::upload_file_table
+    .filter(upload_file_table::is_finish.eq(false))
+    .load::<UploadFileTable>(conn)?;
+  Ok(results)
+}
+
 pub fn select_upload_file(
   conn: &mut SqliteConnection,
   workspace_id: &str,
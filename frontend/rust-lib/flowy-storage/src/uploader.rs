@@ -58,7 +58,7 @@ impl UploadTaskQueue {
 pub struct FileUploader {
   storage_service: Arc<dyn StorageService>,
   queue: Arc<UploadTaskQueue>,
-  max_uploads: u8,
+  max_uploads: AtomicU8,
   current_uploads: AtomicU8,
   pause_sync: AtomicBool,
   disable_upload: Arc<AtomicBool>,
@@ -79,13 +79,25 @@ impl FileUploader {
     Self {
       storage_service,
       queue,
-      max_uploads: 3,
+      max_uploads: AtomicU8::new(3),
       current_uploads: Default::default(),
       pause_sync: Default::default(),
       disable_upload: is_exceed_limit,
     }
   }
 
+  /// Caps how many uploads run at the same time. Clamped to 1..=8 and takes
+  /// effect when the next task is picked from the queue; running uploads are
+  /// not interrupted.
+  pub fn set_max_uploads(&self, max_uploads: u8) {
+    let max_uploads = max_uploads.clamp(1, 8);
+    self
+      .max_uploads
+      .store(max_uploads, std::sync::atomic::Ordering::SeqCst);
+    info!("[File] max concurrent uploads set to {}", max_uploads);
+    let _ = self.queue.notifier.send(Signal::Proceed);
+  }
+
   pub async fn all_tasks(&self) -> Vec<UploadTask> {
     let tasks = self.queue.tasks.read().await;
     tasks.iter().cloned().collect()
@@ -145,7 +157,7 @@ impl FileUploader {
     if self
       .current_uploads
       .load(std::sync::atomic::Ordering::SeqCst)
-      >= self.max_uploads
+      >= self.max_uploads.load(std::sync::atomic::Ordering::SeqCst)
     {
       // If the current uploads count is greater than or equal to the max uploads, do not proceed.
       let _ = self.queue.notifier.send(Signal::ProceedAfterSecs(10));
@@ -232,6 +232,15 @@ pub trait UserCloudService: Send + Sync + 'static {
     Ok(())
   }
 
+  /// Transfers the workspace ownership to the member with the given email.
+  async fn transfer_workspace_ownership(
+    &self,
+    new_owner_email: String,
+    workspace_id: Uuid,
+  ) -> Result<(), FlowyError> {
+    Err(FlowyError::not_support())
+  }
+
   async fn get_workspace_members(
     &self,
     workspace_id: Uuid,
@@ -344,6 +353,35 @@ pub trait UserCloudService: Send + Sync + 'static {
     workspace_id: &Uuid,
     workspace_settings: AFWorkspaceSettingsChange,
   ) -> Result<AFWorkspaceSettings, FlowyError>;
+
+  /// Delivers a mention notification to the mentioned user's devices through
+  /// the cloud. The default does nothing, so servers without a notification
+  /// endpoint fall back to local-only notifications.
+  async fn send_mention_notification(
+    &self,
+    params: MentionNotificationParams,
+  ) -> Result<(), FlowyError> {
+    Ok(())
+  }
+}
+
+/// A mention to deliver to another user, with enough context for the
+/// receiving client to navigate to the mention on tap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MentionNotificationParams {
+  /// Email of the mentioned user, as stored in the workspace member list.
+  pub mentioned_user_email: String,
+  pub workspace_id: Uuid,
+  /// The view holding the mention.
+  pub view_id: String,
+  /// The document block the mention sits in, when mentioned in a document.
+  pub block_id: Option<String>,
+  /// The database row, when assigned through a person cell.
+  pub row_id: Option<String>,
+  /// A short excerpt of the content surrounding the mention.
+  pub snippet: String,
+  /// Display name of the user who created the mention.
+  pub sender_name: String,
 }
 
 pub type UserUpdateReceiver = tokio::sync::mpsc::Receiver<UserUpdate>;
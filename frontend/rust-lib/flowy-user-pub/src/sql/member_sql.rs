@@ -1,6 +1,6 @@
 use crate::entities::{Role, WorkspaceMember};
 use diesel::{RunQueryDsl, insert_into};
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 use flowy_sqlite::schema::workspace_members_table;
 use flowy_sqlite::schema::workspace_members_table::dsl;
 use flowy_sqlite::{DBConnection, ExpressionMethods, prelude::*};
@@ -62,3 +62,50 @@ pub fn select_workspace_member(
 
   Ok(member)
 }
+
+pub fn select_workspace_members(
+  conn: &mut SqliteConnection,
+  workspace_id: &str,
+) -> FlowyResult<Vec<WorkspaceMemberTable>> {
+  let members = dsl::workspace_members_table
+    .filter(workspace_members_table::workspace_id.eq(workspace_id))
+    .order(workspace_members_table::name.asc())
+    .load::<WorkspaceMemberTable>(conn)?;
+
+  Ok(members)
+}
+
+/// Replaces the cached member list of the workspace with the given members.
+/// Rows inserted without a uid inherit it from the previous cache entry for
+/// the same email, so targeted lookups by uid keep working.
+pub fn replace_workspace_members(
+  conn: &mut SqliteConnection,
+  workspace_id: &str,
+  mut members: Vec<WorkspaceMemberTable>,
+) -> FlowyResult<()> {
+  conn.immediate_transaction(|conn| {
+    let existing = dsl::workspace_members_table
+      .filter(workspace_members_table::workspace_id.eq(workspace_id))
+      .load::<WorkspaceMemberTable>(conn)?;
+    for member in members.iter_mut() {
+      if member.uid == 0 {
+        if let Some(row) = existing.iter().find(|row| row.email == member.email) {
+          member.uid = row.uid;
+        }
+      }
+    }
+
+    diesel::delete(
+      dsl::workspace_members_table.filter(workspace_members_table::workspace_id.eq(workspace_id)),
+    )
+    .execute(conn)?;
+    for member in members {
+      insert_into(workspace_members_table::table)
+        .values(&member)
+        .execute(conn)?;
+    }
+    Ok::<_, FlowyError>(())
+  })?;
+
+  Ok(())
+}
@@ -0,0 +1,53 @@
+use flowy_derive::ProtoBuf;
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AppLockSettingsPB {
+  /// Whether a passcode has been set.
+  #[pb(index = 1)]
+  pub is_enabled: bool,
+
+  #[pb(index = 2)]
+  pub is_locked: bool,
+
+  #[pb(index = 3, one_of)]
+  pub auto_lock_timeout_secs: Option<u64>,
+
+  #[pb(index = 4)]
+  pub biometric_enabled: bool,
+}
+
+#[derive(ProtoBuf, Default, Clone, Validate)]
+pub struct SetAppLockPasscodePB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub passcode: String,
+
+  /// Lock the app after this many seconds without activity. Unset disables
+  /// auto locking.
+  #[pb(index = 2, one_of)]
+  pub auto_lock_timeout_secs: Option<u64>,
+
+  /// Allow a successful platform biometric check to unlock the app.
+  #[pb(index = 3)]
+  pub biometric_enabled: bool,
+}
+
+#[derive(ProtoBuf, Default, Clone, Validate)]
+pub struct RemoveAppLockPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub passcode: String,
+}
+
+#[derive(ProtoBuf, Default, Clone)]
+pub struct UnlockAppPB {
+  #[pb(index = 1, one_of)]
+  pub passcode: Option<String>,
+
+  /// Set by the client after the platform biometric prompt succeeded. Only
+  /// honored when biometric unlock is enabled in the settings.
+  #[pb(index = 2)]
+  pub biometric_verified: bool,
+}
@@ -257,3 +257,54 @@ impl Default for AuthStatePB {
     Self::AuthStateUnknown
   }
 }
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UserAccountPB {
+  #[pb(index = 1)]
+  pub uid: i64,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub email: String,
+
+  #[pb(index = 4)]
+  pub is_current: bool,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedUserAccountPB {
+  #[pb(index = 1)]
+  pub items: Vec<UserAccountPB>,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SwitchAccountPB {
+  #[pb(index = 1)]
+  pub uid: i64,
+}
+
+#[derive(ProtoBuf, Default, Clone)]
+pub struct DeleteAccountPB {
+  /// Must match the account email, or the user name for local accounts.
+  #[pb(index = 1)]
+  pub confirmation: String,
+
+  /// Set by the client after a data export was offered to the user. Account
+  /// deletion refuses to proceed until an export has been offered.
+  #[pb(index = 2)]
+  pub export_offered: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DeleteAccountProgressPB {
+  #[pb(index = 1)]
+  pub current: u64,
+
+  #[pb(index = 2)]
+  pub total: u64,
+
+  #[pb(index = 3)]
+  pub description: String,
+}
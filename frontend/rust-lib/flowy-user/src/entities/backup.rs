@@ -0,0 +1,222 @@
+use validator::Validate;
+
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use lib_infra::validator_fn::required_not_empty_str;
+
+use crate::services::backup::{BackupConfig, BackupEntry, BackupKind};
+use crate::user_manager::manager_local_backup::LocalBackupConfig;
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Eq, PartialEq, Default)]
+pub enum BackupKindPB {
+  #[default]
+  WebDav = 0,
+  S3 = 1,
+}
+
+impl From<BackupKind> for BackupKindPB {
+  fn from(kind: BackupKind) -> Self {
+    match kind {
+      BackupKind::WebDav => BackupKindPB::WebDav,
+      BackupKind::S3 => BackupKindPB::S3,
+    }
+  }
+}
+
+impl From<BackupKindPB> for BackupKind {
+  fn from(kind: BackupKindPB) -> Self {
+    match kind {
+      BackupKindPB::WebDav => BackupKind::WebDav,
+      BackupKindPB::S3 => BackupKind::S3,
+    }
+  }
+}
+
+/// The self-hosted backup target. The credentials are stored locally and
+/// never leave the device.
+#[derive(Default, ProtoBuf)]
+pub struct BackupConfigPB {
+  #[pb(index = 1)]
+  pub kind: BackupKindPB,
+
+  #[pb(index = 2)]
+  pub endpoint: String,
+
+  #[pb(index = 3)]
+  pub username: String,
+
+  #[pb(index = 4)]
+  pub password: String,
+
+  #[pb(index = 5)]
+  pub access_key: String,
+
+  #[pb(index = 6)]
+  pub secret_key: String,
+
+  #[pb(index = 7)]
+  pub bucket: String,
+
+  #[pb(index = 8)]
+  pub region: String,
+
+  /// Hours between two automatic backups, zero disables them.
+  #[pb(index = 9)]
+  pub interval_hours: i64,
+
+  /// How many archives to keep on the target.
+  #[pb(index = 10)]
+  pub retention_count: u64,
+}
+
+impl From<BackupConfig> for BackupConfigPB {
+  fn from(config: BackupConfig) -> Self {
+    Self {
+      kind: config.kind.into(),
+      endpoint: config.endpoint,
+      username: config.username,
+      password: config.password,
+      access_key: config.access_key,
+      secret_key: config.secret_key,
+      bucket: config.bucket,
+      region: config.region,
+      interval_hours: config.interval_hours,
+      retention_count: config.retention_count as u64,
+    }
+  }
+}
+
+impl From<BackupConfigPB> for BackupConfig {
+  fn from(pb: BackupConfigPB) -> Self {
+    Self {
+      kind: pb.kind.into(),
+      endpoint: pb.endpoint,
+      username: pb.username,
+      password: pb.password,
+      access_key: pb.access_key,
+      secret_key: pb.secret_key,
+      bucket: pb.bucket,
+      region: pb.region,
+      interval_hours: pb.interval_hours,
+      retention_count: pb.retention_count as usize,
+    }
+  }
+}
+
+/// One archive on the backup target.
+#[derive(Default, ProtoBuf)]
+pub struct BackupInfoPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  /// Milliseconds since the epoch.
+  #[pb(index = 2)]
+  pub created_at: i64,
+
+  /// Size of the encrypted archive in bytes.
+  #[pb(index = 3)]
+  pub size: u64,
+
+  #[pb(index = 4)]
+  pub sha256: String,
+}
+
+impl From<BackupEntry> for BackupInfoPB {
+  fn from(entry: BackupEntry) -> Self {
+    Self {
+      name: entry.name,
+      created_at: entry.created_at,
+      size: entry.size,
+      sha256: entry.sha256,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RepeatedBackupInfoPB {
+  #[pb(index = 1)]
+  pub items: Vec<BackupInfoPB>,
+}
+
+#[derive(Default, ProtoBuf, Validate)]
+pub struct RestoreBackupPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub name: String,
+}
+
+/// Where the decrypted archive was written, ready for the import flow.
+#[derive(Default, ProtoBuf)]
+pub struct RestoredBackupPB {
+  #[pb(index = 1)]
+  pub archive_path: String,
+}
+
+/// Configuration of the automatic local backups, plain zips in a folder the
+/// user picked.
+#[derive(Default, ProtoBuf)]
+pub struct LocalBackupConfigPB {
+  #[pb(index = 1)]
+  pub folder: String,
+
+  /// Hours between two automatic backups, zero disables them.
+  #[pb(index = 2)]
+  pub interval_hours: i64,
+
+  /// How many days to keep the newest archive of.
+  #[pb(index = 3)]
+  pub daily_count: u64,
+
+  /// How many weeks to keep the newest archive of, on top of the dailies.
+  #[pb(index = 4)]
+  pub weekly_count: u64,
+}
+
+impl From<LocalBackupConfig> for LocalBackupConfigPB {
+  fn from(config: LocalBackupConfig) -> Self {
+    Self {
+      folder: config.folder,
+      interval_hours: config.interval_hours,
+      daily_count: config.daily_count as u64,
+      weekly_count: config.weekly_count as u64,
+    }
+  }
+}
+
+impl From<LocalBackupConfigPB> for LocalBackupConfig {
+  fn from(pb: LocalBackupConfigPB) -> Self {
+    Self {
+      folder: pb.folder,
+      interval_hours: pb.interval_hours,
+      daily_count: pb.daily_count as usize,
+      weekly_count: pb.weekly_count as usize,
+    }
+  }
+}
+
+/// One archive in the local backup folder.
+#[derive(Default, ProtoBuf, Clone)]
+pub struct LocalBackupInfoPB {
+  #[pb(index = 1)]
+  pub path: String,
+
+  /// Milliseconds since the epoch.
+  #[pb(index = 2)]
+  pub created_at: i64,
+
+  #[pb(index = 3)]
+  pub size: u64,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RepeatedLocalBackupInfoPB {
+  #[pb(index = 1)]
+  pub items: Vec<LocalBackupInfoPB>,
+}
+
+#[derive(Default, ProtoBuf, Validate)]
+pub struct RestoreLocalBackupPB {
+  /// Full path of the archive to restore.
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub path: String,
+}
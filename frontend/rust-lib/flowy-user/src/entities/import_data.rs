@@ -14,3 +14,18 @@ pub struct ImportAppFlowyDataPB {
   #[pb(index = 3, one_of)]
   pub parent_view_id: Option<String>,
 }
+
+#[derive(ProtoBuf, Validate, Default)]
+pub struct ExportUserDataPB {
+  /// Directory the export archive will be written into.
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub dest_path: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct ExportedUserDataPB {
+  /// Path of the generated archive.
+  #[pb(index = 1)]
+  pub archive_path: String,
+}
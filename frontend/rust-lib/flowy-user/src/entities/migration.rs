@@ -0,0 +1,61 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(ProtoBuf_Enum, Debug, Default, Clone, Eq, PartialEq)]
+pub enum DataMigrationDirectionPB {
+  /// Copy the anonymous/local user's data into the signed-in cloud account.
+  #[default]
+  LocalToCloud = 0,
+  /// Copy the cloud account's local data into the anonymous/local user.
+  CloudToLocal = 1,
+}
+
+#[derive(ProtoBuf, Default, Clone)]
+pub struct MigrateAnonDataPB {
+  #[pb(index = 1)]
+  pub direction: DataMigrationDirectionPB,
+
+  /// When true, only report what would be migrated without moving anything.
+  #[pb(index = 2)]
+  pub dry_run: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DataMigrationPlanPB {
+  /// The top level views (spaces) that will be copied.
+  #[pb(index = 1)]
+  pub items: Vec<DataMigrationItemPB>,
+
+  /// Name of the container view the data will be copied into. A numeric
+  /// suffix is appended when the target workspace already has a top level
+  /// view with the same name.
+  #[pb(index = 2)]
+  pub container_name: String,
+
+  /// False for a dry run, true when the migration was executed.
+  #[pb(index = 3)]
+  pub executed: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DataMigrationItemPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub child_count: u64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct MigrationProgressPB {
+  #[pb(index = 1)]
+  pub current: u64,
+
+  #[pb(index = 2)]
+  pub total: u64,
+
+  #[pb(index = 3)]
+  pub description: String,
+}
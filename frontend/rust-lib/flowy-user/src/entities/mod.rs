@@ -1,17 +1,27 @@
+pub use app_lock::*;
 pub use auth::*;
+pub use backup::*;
 pub use import_data::*;
+pub use migration::*;
+pub use notification_inbox::*;
 pub use realtime::*;
 pub use reminder::*;
+pub use sync_status::*;
 pub use user_profile::*;
 pub use user_setting::*;
 pub use workspace::*;
 
+mod app_lock;
 pub mod auth;
+mod backup;
 pub mod date_time;
 mod import_data;
+mod migration;
+mod notification_inbox;
 pub mod parser;
 pub mod realtime;
 mod reminder;
+mod sync_status;
 mod user_profile;
 mod user_setting;
 mod workspace;
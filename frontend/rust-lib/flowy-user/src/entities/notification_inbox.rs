@@ -0,0 +1,123 @@
+use validator::Validate;
+
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use lib_infra::validator_fn::required_not_empty_str;
+
+use crate::services::notification_inbox::NotificationInboxTable;
+
+#[derive(ProtoBuf_Enum, Debug, Clone, Eq, PartialEq, Default)]
+pub enum InboxNotificationKindPB {
+  #[default]
+  Mention = 0,
+  Comment = 1,
+  Reminder = 2,
+  SharedWithYou = 3,
+  Publish = 4,
+}
+
+impl From<i32> for InboxNotificationKindPB {
+  fn from(kind: i32) -> Self {
+    match kind {
+      0 => InboxNotificationKindPB::Mention,
+      1 => InboxNotificationKindPB::Comment,
+      2 => InboxNotificationKindPB::Reminder,
+      3 => InboxNotificationKindPB::SharedWithYou,
+      _ => InboxNotificationKindPB::Publish,
+    }
+  }
+}
+
+/// One record of the notification inbox.
+#[derive(Default, ProtoBuf)]
+pub struct InboxNotificationPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub kind: InboxNotificationKindPB,
+
+  #[pb(index = 3)]
+  pub title: String,
+
+  #[pb(index = 4)]
+  pub body: String,
+
+  /// The view, document or row the notification points at.
+  #[pb(index = 5)]
+  pub object_id: String,
+
+  /// Who triggered the notification, empty for system events.
+  #[pb(index = 6)]
+  pub sender: String,
+
+  #[pb(index = 7)]
+  pub workspace_id: String,
+
+  /// Milliseconds since the epoch.
+  #[pb(index = 8)]
+  pub created_at: i64,
+
+  #[pb(index = 9)]
+  pub is_read: bool,
+
+  #[pb(index = 10)]
+  pub is_archived: bool,
+}
+
+impl From<NotificationInboxTable> for InboxNotificationPB {
+  fn from(record: NotificationInboxTable) -> Self {
+    Self {
+      id: record.id,
+      kind: record.kind.into(),
+      title: record.title,
+      body: record.body,
+      object_id: record.object_id,
+      sender: record.sender,
+      workspace_id: record.workspace_id,
+      created_at: record.created_at,
+      is_read: record.is_read,
+      is_archived: record.is_archived,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct RepeatedInboxNotificationPB {
+  #[pb(index = 1)]
+  pub items: Vec<InboxNotificationPB>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ListInboxNotificationsPB {
+  #[pb(index = 1)]
+  pub offset: u64,
+
+  /// Page size, capped by the handler.
+  #[pb(index = 2)]
+  pub limit: u64,
+
+  #[pb(index = 3)]
+  pub include_archived: bool,
+}
+
+/// Marks the given records as read, or everything when `ids` is empty.
+#[derive(Default, ProtoBuf)]
+pub struct MarkInboxNotificationsReadPB {
+  #[pb(index = 1)]
+  pub ids: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Validate)]
+pub struct ArchiveInboxNotificationPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub id: String,
+}
+
+/// Payload of the unread-count push notification and of the
+/// GetInboxUnreadCount event.
+#[derive(Default, ProtoBuf)]
+pub struct InboxUnreadCountPB {
+  #[pb(index = 1)]
+  pub count: u64,
+}
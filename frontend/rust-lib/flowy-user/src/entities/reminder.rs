@@ -77,3 +77,13 @@ pub struct ReminderIdentifierPB {
   #[pb(index = 1)]
   pub id: String,
 }
+
+#[derive(ProtoBuf, Default, Clone)]
+pub struct SnoozeReminderPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  /// The new time the reminder fires at, same epoch as `scheduled_at`.
+  #[pb(index = 2)]
+  pub snooze_until: i64,
+}
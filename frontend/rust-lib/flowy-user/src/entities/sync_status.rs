@@ -0,0 +1,140 @@
+use collab_integrate::sync_status::{ObjectSyncStatus, PendingSyncInfo};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use lib_infra::validator_fn::required_not_empty_str;
+use validator::Validate;
+
+#[derive(ProtoBuf, Default, Clone, Validate)]
+pub struct ObjectSyncStatusQueryPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub object_id: String,
+}
+
+/// The sync status of one collab object, driving the "saved / syncing /
+/// offline" indicator of the UI.
+#[derive(ProtoBuf, Default, Clone)]
+pub struct ObjectSyncStatusPB {
+  #[pb(index = 1)]
+  pub object_id: String,
+
+  #[pb(index = 2)]
+  pub status: SyncStatusTypePB,
+
+  /// Number of local changes waiting to be synced. Only meaningful while the
+  /// status is [SyncStatusTypePB::Pending].
+  #[pb(index = 3)]
+  pub pending_changes: u32,
+
+  /// Why the last sync attempt failed. Only set while the status is
+  /// [SyncStatusTypePB::SyncError].
+  #[pb(index = 4, one_of)]
+  pub error_reason: Option<String>,
+}
+
+#[derive(ProtoBuf_Enum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SyncStatusTypePB {
+  #[default]
+  Synced = 0,
+  Pending = 1,
+  Syncing = 2,
+  SyncError = 3,
+}
+
+impl ObjectSyncStatusPB {
+  pub fn new(object_id: String, status: ObjectSyncStatus) -> Self {
+    let mut pb = ObjectSyncStatusPB {
+      object_id,
+      ..Default::default()
+    };
+    match status {
+      ObjectSyncStatus::Synced => pb.status = SyncStatusTypePB::Synced,
+      ObjectSyncStatus::Pending { local_changes } => {
+        pb.status = SyncStatusTypePB::Pending;
+        pb.pending_changes = local_changes;
+      },
+      ObjectSyncStatus::Syncing => pb.status = SyncStatusTypePB::Syncing,
+      ObjectSyncStatus::Error { reason } => {
+        pb.status = SyncStatusTypePB::SyncError;
+        pb.error_reason = Some(reason);
+      },
+    }
+    pb
+  }
+}
+
+/// One collab object with local updates waiting to be synced.
+#[derive(ProtoBuf, Default, Clone)]
+pub struct PendingSyncObjectPB {
+  #[pb(index = 1)]
+  pub object_id: String,
+
+  #[pb(index = 2)]
+  pub pending_updates: u32,
+
+  #[pb(index = 3)]
+  pub pending_bytes: u64,
+
+  /// When the oldest pending update was made, in milliseconds since the
+  /// epoch.
+  #[pb(index = 4)]
+  pub oldest_timestamp: i64,
+}
+
+impl From<PendingSyncInfo> for PendingSyncObjectPB {
+  fn from(info: PendingSyncInfo) -> Self {
+    PendingSyncObjectPB {
+      object_id: info.object_id,
+      pending_updates: info.pending_updates,
+      pending_bytes: info.pending_bytes,
+      oldest_timestamp: info.oldest_timestamp,
+    }
+  }
+}
+
+#[derive(ProtoBuf, Default, Clone)]
+pub struct PendingSyncQueuePB {
+  #[pb(index = 1)]
+  pub items: Vec<PendingSyncObjectPB>,
+}
+
+#[derive(ProtoBuf, Default, Clone, Validate)]
+pub struct DiscardPendingSyncPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub object_id: String,
+}
+
+#[derive(ProtoBuf, Default, Clone, Validate)]
+pub struct CompactCollabPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub workspace_id: String,
+
+  /// Compact only this object when set, otherwise the whole workspace.
+  #[pb(index = 2, one_of)]
+  pub object_id: Option<String>,
+
+  /// Objects with update or sync activity within this window are left
+  /// untouched, their history may still be needed by devices catching up.
+  #[pb(index = 3)]
+  pub retention_secs: u64,
+}
+
+#[derive(ProtoBuf, Default, Clone)]
+pub struct CollabCompactionResultPB {
+  #[pb(index = 1)]
+  pub compacted_objects: u64,
+
+  /// Objects skipped because they were recently active or not fully synced.
+  #[pb(index = 2)]
+  pub skipped_objects: u64,
+
+  /// History updates that were merged into the compacted doc states.
+  #[pb(index = 3)]
+  pub pruned_updates: u64,
+
+  /// Best-effort estimate, the storage engine reclaims the space lazily
+  /// during its own compaction.
+  #[pb(index = 4)]
+  pub reclaimed_bytes: u64,
+}
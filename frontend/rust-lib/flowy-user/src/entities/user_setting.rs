@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use lib_infra::validator_fn::required_not_empty_str;
 
 use crate::entities::EncryptionTypePB;
 
@@ -194,6 +196,16 @@ pub struct UserSecretPB {
   pub encryption_sign: String,
 }
 
+/// The recovery phrase of the secret that wraps the workspace encryption
+/// keys. Exported so the user can store it safely, imported on a new device
+/// to regain access to end-to-end encrypted workspaces.
+#[derive(Default, ProtoBuf, Validate)]
+pub struct RecoveryPhrasePB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub phrase: String,
+}
+
 #[derive(Default, ProtoBuf)]
 pub struct UserEncryptionConfigurationPB {
   #[pb(index = 1)]
@@ -220,6 +232,12 @@ impl NetworkTypePB {
       },
     }
   }
+
+  /// Whether the connection is likely billed by usage, background work
+  /// should be deferred on it.
+  pub fn is_metered(&self) -> bool {
+    matches!(self, NetworkTypePB::Cell)
+  }
 }
 
 #[derive(ProtoBuf, Debug, Default, Clone)]
@@ -276,3 +294,11 @@ pub struct AppFlowyCloudSettingPB {
   #[pb(index = 3)]
   pub gotrue_url: String,
 }
+
+/// Payload of the `DidUpdateSyncedSettings` notification, listing the
+/// preference keys that were updated from another device.
+#[derive(Default, ProtoBuf)]
+pub struct SyncedSettingsChangedPB {
+  #[pb(index = 1)]
+  pub keys: Vec<String>,
+}
@@ -151,6 +151,17 @@ pub struct UpdateWorkspaceMemberPB {
   pub role: AFRolePB,
 }
 
+#[derive(ProtoBuf, Default, Clone, Validate)]
+pub struct TransferWorkspaceOwnershipPB {
+  #[pb(index = 1)]
+  #[validate(custom(function = "required_not_empty_str"))]
+  pub workspace_id: String,
+
+  #[pb(index = 2)]
+  #[validate(email)]
+  pub email: String,
+}
+
 // Workspace Role
 #[derive(Debug, ProtoBuf_Enum, Clone, Default, Eq, PartialEq)]
 pub enum AFRolePB {
@@ -471,6 +482,101 @@ impl From<WorkspaceUsageAndLimit> for WorkspaceUsagePB {
   }
 }
 
+/// Usage statistics of one workspace for the settings dashboard. The counts
+/// are computed from the local data, the cloud fields are only set when the
+/// quota could be fetched from the cloud service.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct WorkspaceUsageStatsPB {
+  #[pb(index = 1)]
+  pub view_counts: Vec<ViewLayoutCountPB>,
+
+  #[pb(index = 2)]
+  pub document_count: u64,
+
+  #[pb(index = 3)]
+  pub database_row_count: u64,
+
+  #[pb(index = 4)]
+  pub member_count: u64,
+
+  /// Bytes of the user's local data directory, including attachments.
+  #[pb(index = 5)]
+  pub local_storage_bytes: u64,
+
+  #[pb(index = 6, one_of)]
+  pub cloud_storage_bytes: Option<u64>,
+
+  #[pb(index = 7, one_of)]
+  pub cloud_storage_bytes_limit: Option<u64>,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct ViewLayoutCountPB {
+  /// Name of the view layout, e.g. "Document" or "Grid".
+  #[pb(index = 1)]
+  pub layout: String,
+
+  #[pb(index = 2)]
+  pub count: u64,
+}
+
+/// Breakdown of the local disk usage by category, with the collab bytes
+/// attributed per workspace and the biggest collab objects attributed to
+/// their views.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct StorageBreakdownPB {
+  #[pb(index = 1)]
+  pub total_bytes: u64,
+
+  #[pb(index = 2)]
+  pub categories: Vec<StorageCategoryPB>,
+
+  /// Collab bytes per workspace, measured as the encoded size of the docs
+  /// rather than the shared on-disk store.
+  #[pb(index = 3)]
+  pub workspace_collab_usage: Vec<WorkspaceCollabUsagePB>,
+
+  /// The biggest collab objects of the requested workspace, largest first.
+  #[pb(index = 4)]
+  pub largest_collabs: Vec<CollabDiskUsagePB>,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct StorageCategoryPB {
+  /// Name of the category, e.g. "Collab database" or "Search index".
+  #[pb(index = 1)]
+  pub name: String,
+
+  #[pb(index = 2)]
+  pub bytes: u64,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct WorkspaceCollabUsagePB {
+  #[pb(index = 1)]
+  pub workspace_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub bytes: u64,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CollabDiskUsagePB {
+  #[pb(index = 1)]
+  pub object_id: String,
+
+  /// Name of the view the object belongs to, empty when the object is not a
+  /// view, e.g. a database row.
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub bytes: u64,
+}
+
 #[derive(Debug, ProtoBuf, Default, Clone)]
 pub struct BillingPortalPB {
   #[pb(index = 1)]
@@ -135,12 +135,16 @@ pub async fn sign_out_handler(manager: AFPluginState<Weak<UserManager>>) -> Resu
   Ok(())
 }
 
-#[tracing::instrument(level = "debug", skip(manager))]
+#[tracing::instrument(level = "info", skip_all, err)]
 pub async fn delete_account_handler(
+  payload: AFPluginData<DeleteAccountPB>,
   manager: AFPluginState<Weak<UserManager>>,
 ) -> Result<(), FlowyError> {
+  let payload = payload.into_inner();
   let manager = upgrade_manager(manager)?;
-  manager.delete_account().await?;
+  manager
+    .delete_account(&payload.confirmation, payload.export_offered)
+    .await?;
   Ok(())
 }
 
@@ -155,11 +159,12 @@ pub async fn update_user_profile_handler(
   Ok(())
 }
 
-const APPEARANCE_SETTING_CACHE_KEY: &str = "appearance_settings";
+pub(crate) const APPEARANCE_SETTING_CACHE_KEY: &str = "appearance_settings";
 
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub async fn set_appearance_setting(
   store_preferences: AFPluginState<Weak<KVStorePreferences>>,
+  manager: AFPluginState<Weak<UserManager>>,
   data: AFPluginData<AppearanceSettingsPB>,
 ) -> Result<(), FlowyError> {
   let store_preferences = upgrade_store_preferences(store_preferences)?;
@@ -168,6 +173,11 @@ pub async fn set_appearance_setting(
     setting.theme = APPEARANCE_DEFAULT_THEME.to_string();
   }
   store_preferences.set_object(APPEARANCE_SETTING_CACHE_KEY, &setting)?;
+  if let Ok(manager) = upgrade_manager(manager) {
+    let _ = manager
+      .push_synced_setting(APPEARANCE_SETTING_CACHE_KEY)
+      .await;
+  }
   Ok(())
 }
 
@@ -191,11 +201,12 @@ pub async fn get_appearance_setting(
   }
 }
 
-const DATE_TIME_SETTINGS_CACHE_KEY: &str = "date_time_settings";
+pub(crate) const DATE_TIME_SETTINGS_CACHE_KEY: &str = "date_time_settings";
 
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub async fn set_date_time_settings(
   store_preferences: AFPluginState<Weak<KVStorePreferences>>,
+  manager: AFPluginState<Weak<UserManager>>,
   data: AFPluginData<DateTimeSettingsPB>,
 ) -> Result<(), FlowyError> {
   let store_preferences = upgrade_store_preferences(store_preferences)?;
@@ -205,6 +216,11 @@ pub async fn set_date_time_settings(
   }
 
   store_preferences.set_object(DATE_TIME_SETTINGS_CACHE_KEY, &setting)?;
+  if let Ok(manager) = upgrade_manager(manager) {
+    let _ = manager
+      .push_synced_setting(DATE_TIME_SETTINGS_CACHE_KEY)
+      .await;
+  }
   Ok(())
 }
 
@@ -475,8 +491,10 @@ pub async fn update_network_state_handler(
   manager: AFPluginState<Weak<UserManager>>,
 ) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
-  let reachable = data.into_inner().ty.is_reachable();
+  let network_type = data.into_inner().ty;
+  let reachable = network_type.is_reachable();
   manager.cloud_service()?.set_network_reachable(reachable);
+  manager.update_network_type(network_type);
   manager
     .app_life_cycle
     .read()
@@ -503,6 +521,110 @@ pub async fn open_anon_user_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn list_accounts_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<RepeatedUserAccountPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let items = manager.list_accounts().await?;
+  data_result_ok(RepeatedUserAccountPB { items })
+}
+
+#[tracing::instrument(level = "info", skip_all, err)]
+pub async fn switch_account_handler(
+  payload: AFPluginData<SwitchAccountPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.switch_account(payload.into_inner().uid).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_app_lock_settings_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<AppLockSettingsPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  data_result_ok(manager.get_app_lock_settings())
+}
+
+#[tracing::instrument(level = "info", skip_all, err)]
+pub async fn set_app_lock_passcode_handler(
+  payload: AFPluginData<SetAppLockPasscodePB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let payload = payload.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  manager.set_app_lock_passcode(
+    &payload.passcode,
+    payload.auto_lock_timeout_secs,
+    payload.biometric_enabled,
+  )?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "info", skip_all, err)]
+pub async fn remove_app_lock_handler(
+  payload: AFPluginData<RemoveAppLockPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let payload = payload.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  manager.remove_app_lock(&payload.passcode)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "info", skip_all)]
+pub async fn lock_app_handler(manager: AFPluginState<Weak<UserManager>>) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.lock_app();
+  Ok(())
+}
+
+#[tracing::instrument(level = "info", skip_all, err)]
+pub async fn unlock_app_handler(
+  payload: AFPluginData<UnlockAppPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let payload = payload.into_inner();
+  let manager = upgrade_manager(manager)?;
+  manager.unlock_app(payload.passcode, payload.biometric_verified)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "info", skip_all, err)]
+pub async fn migrate_anon_data_handler(
+  payload: AFPluginData<MigrateAnonDataPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<DataMigrationPlanPB, FlowyError> {
+  let payload = payload.into_inner();
+  let manager = upgrade_manager(manager)?;
+  let plan = manager
+    .migrate_anon_data(payload.direction, payload.dry_run)
+    .await?;
+  data_result_ok(plan)
+}
+
+#[tracing::instrument(level = "info", skip_all, err)]
+pub async fn export_user_data_handler(
+  payload: AFPluginData<ExportUserDataPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<ExportedUserDataPB, FlowyError> {
+  let payload = payload.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  let archive_path = manager.export_user_data(&payload.dest_path).await?;
+  data_result_ok(ExportedUserDataPB { archive_path })
+}
+
+#[tracing::instrument(level = "trace", skip_all)]
+pub async fn notify_app_activity_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.record_app_activity();
+  Ok(())
+}
+
 pub async fn push_realtime_event_handler(
   payload: AFPluginData<RealtimePayloadPB>,
   manager: AFPluginState<Weak<UserManager>>,
@@ -570,6 +692,19 @@ pub async fn update_reminder_event_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn snooze_reminder_event_handler(
+  data: AFPluginData<SnoozeReminderPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.into_inner();
+  manager
+    .snooze_reminder(params.id.as_str(), params.snooze_until)
+    .await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub async fn delete_workspace_member_handler(
   data: AFPluginData<RemoveWorkspaceMemberPB>,
@@ -615,6 +750,20 @@ pub async fn update_workspace_member_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn transfer_workspace_ownership_handler(
+  data: AFPluginData<TransferWorkspaceOwnershipPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let data = data.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  let workspace_id = Uuid::from_str(&data.workspace_id)?;
+  manager
+    .transfer_workspace_ownership(data.email, workspace_id)
+    .await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub async fn create_workspace_handler(
   data: AFPluginData<CreateWorkspacePB>,
@@ -776,6 +925,259 @@ pub async fn get_workspace_usage_handler(
   data_result_ok(WorkspaceUsagePB::from(workspace_usage))
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_sync_status_handler(
+  param: AFPluginData<ObjectSyncStatusQueryPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<ObjectSyncStatusPB, FlowyError> {
+  let params = param.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  let status = manager.get_sync_status(&params.object_id).await?;
+  data_result_ok(status)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_pending_sync_queue_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<PendingSyncQueuePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let queue = manager.get_pending_sync_queue().await?;
+  data_result_ok(queue)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn retry_sync_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.retry_sync().await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn discard_pending_sync_handler(
+  param: AFPluginData<DiscardPendingSyncPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let params = param.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  manager.discard_pending_sync(&params.object_id).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_workspace_usage_stats_handler(
+  param: AFPluginData<UserWorkspaceIdPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<WorkspaceUsageStatsPB, FlowyError> {
+  let workspace_id = Uuid::from_str(&param.into_inner().workspace_id)?;
+  let manager = upgrade_manager(manager)?;
+  let stats = manager.get_workspace_usage_stats(&workspace_id).await?;
+  data_result_ok(stats)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_storage_breakdown_handler(
+  param: AFPluginData<UserWorkspaceIdPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<StorageBreakdownPB, FlowyError> {
+  let workspace_id = Uuid::from_str(&param.into_inner().workspace_id)?;
+  let manager = upgrade_manager(manager)?;
+  let breakdown = manager.get_storage_breakdown(&workspace_id).await?;
+  data_result_ok(breakdown)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn enable_workspace_encryption_handler(
+  param: AFPluginData<UserWorkspaceIdPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let workspace_id = Uuid::from_str(&param.into_inner().workspace_id)?;
+  let manager = upgrade_manager(manager)?;
+  manager.enable_workspace_encryption(&workspace_id).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn rotate_workspace_encryption_key_handler(
+  param: AFPluginData<UserWorkspaceIdPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let workspace_id = Uuid::from_str(&param.into_inner().workspace_id)?;
+  let manager = upgrade_manager(manager)?;
+  manager.rotate_workspace_encryption_key(&workspace_id).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn export_recovery_phrase_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<RecoveryPhrasePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let phrase = manager.export_recovery_phrase()?;
+  data_result_ok(RecoveryPhrasePB { phrase })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn import_recovery_phrase_handler(
+  param: AFPluginData<RecoveryPhrasePB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let params = param.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  manager.import_recovery_phrase(&params.phrase).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn set_backup_config_handler(
+  param: AFPluginData<BackupConfigPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.set_backup_config(param.into_inner().into())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_backup_config_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<BackupConfigPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let config = manager.get_backup_config()?;
+  data_result_ok(config.into())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn backup_now_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<BackupInfoPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let entry = manager.backup_now().await?;
+  data_result_ok(entry.into())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_backup_list_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<RepeatedBackupInfoPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let items = manager
+    .list_backups()
+    .await?
+    .into_iter()
+    .map(Into::into)
+    .collect();
+  data_result_ok(RepeatedBackupInfoPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn restore_from_backup_handler(
+  param: AFPluginData<RestoreBackupPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<RestoredBackupPB, FlowyError> {
+  let params = param.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  let archive_path = manager.restore_from_backup(&params.name).await?;
+  data_result_ok(RestoredBackupPB { archive_path })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn compact_collabs_handler(
+  param: AFPluginData<CompactCollabPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<CollabCompactionResultPB, FlowyError> {
+  let params = param.try_into_inner()?;
+  let workspace_id = Uuid::from_str(&params.workspace_id)?;
+  let manager = upgrade_manager(manager)?;
+  let result = manager
+    .compact_collabs(&workspace_id, params.object_id, params.retention_secs)
+    .await?;
+  data_result_ok(result)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn set_local_backup_config_handler(
+  param: AFPluginData<LocalBackupConfigPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  manager.set_local_backup_config(param.into_inner().into())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_local_backup_config_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<LocalBackupConfigPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let config = manager.get_local_backup_config()?;
+  data_result_ok(config.into())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn local_backup_now_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<LocalBackupInfoPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let info = manager.local_backup_now().await?;
+  data_result_ok(info)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_local_backup_list_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<RepeatedLocalBackupInfoPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let items = manager.list_local_backups().await?;
+  data_result_ok(RepeatedLocalBackupInfoPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn restore_local_backup_handler(
+  param: AFPluginData<RestoreLocalBackupPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let params = param.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  manager.restore_local_backup(&params.path).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn list_inbox_notifications_handler(
+  param: AFPluginData<ListInboxNotificationsPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<RepeatedInboxNotificationPB, FlowyError> {
+  let params = param.into_inner();
+  let manager = upgrade_manager(manager)?;
+  let notifications = manager
+    .list_inbox_notifications(params.offset, params.limit, params.include_archived)
+    .await?;
+  data_result_ok(notifications)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn mark_inbox_notifications_read_handler(
+  param: AFPluginData<MarkInboxNotificationsReadPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let params = param.into_inner();
+  let manager = upgrade_manager(manager)?;
+  manager.mark_inbox_notifications_read(&params.ids).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn archive_inbox_notification_handler(
+  param: AFPluginData<ArchiveInboxNotificationPB>,
+  manager: AFPluginState<Weak<UserManager>>,
+) -> Result<(), FlowyError> {
+  let params = param.try_into_inner()?;
+  let manager = upgrade_manager(manager)?;
+  manager.archive_inbox_notification(&params.id).await
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub async fn get_inbox_unread_count_handler(
+  manager: AFPluginState<Weak<UserManager>>,
+) -> DataResult<InboxUnreadCountPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let count = manager.inbox_unread_count().await?;
+  data_result_ok(count)
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub async fn get_billing_portal_handler(
   manager: AFPluginState<Weak<UserManager>>,
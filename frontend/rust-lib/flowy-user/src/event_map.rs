@@ -49,6 +49,7 @@ pub fn init(user_manager: Weak<UserManager>) -> AFPlugin {
     .event(UserEvent::GetAllReminders, get_all_reminder_event_handler)
     .event(UserEvent::RemoveReminder, remove_reminder_event_handler)
     .event(UserEvent::UpdateReminder, update_reminder_event_handler)
+    .event(UserEvent::SnoozeReminder, snooze_reminder_event_handler)
     .event(UserEvent::SetDateTimeSettings, set_date_time_settings)
     .event(UserEvent::GetDateTimeSettings, get_date_time_settings)
     .event(UserEvent::SetNotificationSettings, set_notification_settings)
@@ -58,6 +59,7 @@ pub fn init(user_manager: Weak<UserManager>) -> AFPlugin {
     .event(UserEvent::RemoveWorkspaceMember, delete_workspace_member_handler)
     .event(UserEvent::GetWorkspaceMembers, get_workspace_members_handler)
     .event(UserEvent::UpdateWorkspaceMember, update_workspace_member_handler)
+    .event(UserEvent::TransferWorkspaceOwnership, transfer_workspace_ownership_handler)
       // Workspace
     .event(UserEvent::GetAllWorkspace, get_all_workspace_handler)
     .event(UserEvent::CreateWorkspace, create_workspace_handler)
@@ -81,6 +83,79 @@ pub fn init(user_manager: Weak<UserManager>) -> AFPlugin {
     .event(UserEvent::GetWorkspaceSetting, get_workspace_setting_handler)
     .event(UserEvent::NotifyDidSwitchPlan, notify_did_switch_plan_handler)
     .event(UserEvent::PasscodeSignIn, sign_in_with_passcode_handler)
+    // Accounts
+    .event(UserEvent::ListAccounts, list_accounts_handler)
+    .event(UserEvent::SwitchAccount, switch_account_handler)
+    // App lock
+    .event(UserEvent::GetAppLockSettings, get_app_lock_settings_handler)
+    .event(UserEvent::SetAppLockPasscode, set_app_lock_passcode_handler)
+    .event(UserEvent::RemoveAppLock, remove_app_lock_handler)
+    .event(UserEvent::LockApp, lock_app_handler)
+    .event(UserEvent::UnlockApp, unlock_app_handler)
+    .event(UserEvent::NotifyAppActivity, notify_app_activity_handler)
+    .event(UserEvent::MigrateAnonData, migrate_anon_data_handler)
+    .event(UserEvent::ExportUserData, export_user_data_handler)
+    .event(
+      UserEvent::GetWorkspaceUsageStats,
+      get_workspace_usage_stats_handler,
+    )
+    .event(UserEvent::GetSyncStatus, get_sync_status_handler)
+    .event(
+      UserEvent::GetPendingSyncQueue,
+      get_pending_sync_queue_handler,
+    )
+    .event(UserEvent::RetrySync, retry_sync_handler)
+    .event(UserEvent::DiscardPendingSync, discard_pending_sync_handler)
+    .event(
+      UserEvent::EnableWorkspaceEncryption,
+      enable_workspace_encryption_handler,
+    )
+    .event(
+      UserEvent::RotateWorkspaceEncryptionKey,
+      rotate_workspace_encryption_key_handler,
+    )
+    .event(
+      UserEvent::ExportRecoveryPhrase,
+      export_recovery_phrase_handler,
+    )
+    .event(
+      UserEvent::ImportRecoveryPhrase,
+      import_recovery_phrase_handler,
+    )
+    .event(UserEvent::SetBackupConfig, set_backup_config_handler)
+    .event(UserEvent::GetBackupConfig, get_backup_config_handler)
+    .event(UserEvent::BackupNow, backup_now_handler)
+    .event(UserEvent::GetBackupList, get_backup_list_handler)
+    .event(UserEvent::RestoreFromBackup, restore_from_backup_handler)
+    .event(UserEvent::CompactCollabs, compact_collabs_handler)
+    .event(UserEvent::GetStorageBreakdown, get_storage_breakdown_handler)
+    .event(
+      UserEvent::SetLocalBackupConfig,
+      set_local_backup_config_handler,
+    )
+    .event(
+      UserEvent::GetLocalBackupConfig,
+      get_local_backup_config_handler,
+    )
+    .event(UserEvent::LocalBackupNow, local_backup_now_handler)
+    .event(UserEvent::GetLocalBackupList, get_local_backup_list_handler)
+    .event(UserEvent::RestoreLocalBackup, restore_local_backup_handler)
+    .event(
+      UserEvent::ListInboxNotifications,
+      list_inbox_notifications_handler,
+    )
+    .event(
+      UserEvent::MarkInboxNotificationsRead,
+      mark_inbox_notifications_read_handler,
+    )
+    .event(
+      UserEvent::ArchiveInboxNotification,
+      archive_inbox_notification_handler,
+    )
+    .event(
+      UserEvent::GetInboxUnreadCount,
+      get_inbox_unread_count_handler,
+    )
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
@@ -269,11 +344,181 @@ pub enum UserEvent {
   #[event(input = "SuccessWorkspaceSubscriptionPB")]
   NotifyDidSwitchPlan = 63,
 
-  #[event()]
+  /// Deletes the account in the cloud and wipes its local data. Requires a
+  /// typed confirmation and a prior data export offer
+  #[event(input = "DeleteAccountPB")]
   DeleteAccount = 64,
 
   #[event(input = "PasscodeSignInPB", output = "GotrueTokenResponsePB")]
   PasscodeSignIn = 65,
+
+  /// Lists every account signed in on this device
+  #[event(output = "RepeatedUserAccountPB")]
+  ListAccounts = 66,
+
+  /// Switches the active account without restarting the app
+  #[event(input = "SwitchAccountPB")]
+  SwitchAccount = 67,
+
+  /// Transfers the workspace ownership to another member
+  #[event(input = "TransferWorkspaceOwnershipPB")]
+  TransferWorkspaceOwnership = 68,
+
+  #[event(output = "AppLockSettingsPB")]
+  GetAppLockSettings = 69,
+
+  /// Enables the app lock with a passcode, or updates the passcode, the
+  /// inactivity timeout and the biometric unlock setting
+  #[event(input = "SetAppLockPasscodePB")]
+  SetAppLockPasscode = 70,
+
+  /// Disables the app lock. Requires the current passcode
+  #[event(input = "RemoveAppLockPB")]
+  RemoveAppLock = 71,
+
+  /// Locks the app immediately
+  #[event()]
+  LockApp = 72,
+
+  /// Unlocks the app with the passcode or a platform biometric result
+  #[event(input = "UnlockAppPB")]
+  UnlockApp = 73,
+
+  /// Resets the inactivity timer that auto locks the app
+  #[event()]
+  NotifyAppActivity = 74,
+
+  /// Copies the local user's data into the signed-in cloud account, or the
+  /// reverse. Supports a dry run that only reports what would move
+  #[event(input = "MigrateAnonDataPB", output = "DataMigrationPlanPB")]
+  MigrateAnonData = 75,
+
+  /// Exports all workspaces' raw collab data plus markdown/CSV renditions,
+  /// settings and a manifest into a zip archive, without the cloud service
+  #[event(input = "ExportUserDataPB", output = "ExportedUserDataPB")]
+  ExportUserData = 76,
+
+  /// Usage statistics of a workspace for the usage dashboard: counts of views
+  /// by layout, documents, database rows and members plus the local storage
+  /// footprint, merged with the cloud storage quota when available
+  #[event(input = "UserWorkspaceIdPB", output = "WorkspaceUsageStatsPB")]
+  GetWorkspaceUsageStats = 77,
+
+  /// The sync status of one collab object, for a "saved / syncing / offline"
+  /// indicator. Transitions are pushed via the `DidUpdateSyncStatus`
+  /// notification keyed by the object id
+  #[event(input = "ObjectSyncStatusQueryPB", output = "ObjectSyncStatusPB")]
+  GetSyncStatus = 78,
+
+  /// The collab objects with local updates that have not been synced yet,
+  /// with per-object update count, size and oldest timestamp
+  #[event(output = "PendingSyncQueuePB")]
+  GetPendingSyncQueue = 79,
+
+  /// Forces the sync plugins to reconnect and push the pending queue
+  #[event()]
+  RetrySync = 80,
+
+  /// Discards the pending local updates of one object after the user
+  /// confirmed that they are lost, the object is reloaded from the server
+  #[event(input = "DiscardPendingSyncPB")]
+  DiscardPendingSync = 81,
+
+  /// Enables end-to-end encryption for the workspace: collab updates are
+  /// encrypted client-side with a workspace key before they are sent to the
+  /// cloud services. The key is wrapped with the user secret and shared to
+  /// the user's other devices
+  #[event(input = "UserWorkspaceIdPB")]
+  EnableWorkspaceEncryption = 82,
+
+  /// Rotates the encryption key of a workspace, the previous key is retired
+  /// but kept so earlier data stays readable
+  #[event(input = "UserWorkspaceIdPB")]
+  RotateWorkspaceEncryptionKey = 83,
+
+  /// The recovery phrase of the user secret wrapping the workspace keys, for
+  /// the user to store safely
+  #[event(output = "RecoveryPhrasePB")]
+  ExportRecoveryPhrase = 84,
+
+  /// Restores the user secret from a recovery phrase on a new device and
+  /// unwraps the shared workspace keys
+  #[event(input = "RecoveryPhrasePB")]
+  ImportRecoveryPhrase = 85,
+
+  /// Configures the self-hosted WebDAV or S3 backup target. The credentials
+  /// are stored locally and never leave the device
+  #[event(input = "BackupConfigPB")]
+  SetBackupConfig = 86,
+
+  #[event(output = "BackupConfigPB")]
+  GetBackupConfig = 87,
+
+  /// Uploads an encrypted archive of all local user data to the configured
+  /// backup target, verifies it and prunes archives beyond the retention
+  /// count
+  #[event(output = "BackupInfoPB")]
+  BackupNow = 88,
+
+  /// The archives available on the configured backup target, oldest first
+  #[event(output = "RepeatedBackupInfoPB")]
+  GetBackupList = 89,
+
+  /// Downloads, verifies and decrypts an archive, returning the path of the
+  /// plain zip for the import flow
+  #[event(input = "RestoreBackupPB", output = "RestoredBackupPB")]
+  RestoreFromBackup = 90,
+
+  /// Compacts collab docs by merging their stored update history into a
+  /// single doc state, per object or workspace-wide. Recently active or
+  /// unsynced objects keep their history
+  #[event(input = "CompactCollabPB", output = "CollabCompactionResultPB")]
+  CompactCollabs = 91,
+
+  /// Reports the local disk usage by category, with the biggest collab
+  /// objects of the given workspace attributed to their views
+  #[event(input = "UserWorkspaceIdPB", output = "StorageBreakdownPB")]
+  GetStorageBreakdown = 92,
+
+  /// Configures the automatic local backups: target folder, interval and
+  /// daily/weekly retention
+  #[event(input = "LocalBackupConfigPB")]
+  SetLocalBackupConfig = 93,
+
+  #[event(output = "LocalBackupConfigPB")]
+  GetLocalBackupConfig = 94,
+
+  /// Zips the whole data directory into a timestamped archive in the
+  /// configured folder and rotates old archives
+  #[event(output = "LocalBackupInfoPB")]
+  LocalBackupNow = 95,
+
+  #[event(output = "RepeatedLocalBackupInfoPB")]
+  GetLocalBackupList = 96,
+
+  /// Validates the archive and swaps the data directory for its contents.
+  /// The app has to be restarted afterwards
+  #[event(input = "RestoreLocalBackupPB")]
+  RestoreLocalBackup = 97,
+
+  /// One page of the notification inbox, newest first
+  #[event(input = "ListInboxNotificationsPB", output = "RepeatedInboxNotificationPB")]
+  ListInboxNotifications = 98,
+
+  /// Marks the given notifications as read, or the whole inbox when the
+  /// list of ids is empty
+  #[event(input = "MarkInboxNotificationsReadPB")]
+  MarkInboxNotificationsRead = 99,
+
+  #[event(input = "ArchiveInboxNotificationPB")]
+  ArchiveInboxNotification = 100,
+
+  #[event(output = "InboxUnreadCountPB")]
+  GetInboxUnreadCount = 101,
+
+  /// Pushes a fired reminder forward so it fires again at the new time
+  #[event(input = "SnoozeReminderPB")]
+  SnoozeReminder = 102,
 }
 
 #[async_trait]
@@ -346,6 +591,14 @@ pub trait AppLifeCycle: Send + Sync + 'static {
     Ok(())
   }
   fn on_network_status_changed(&self, _reachable: bool) {}
+
+  /// Fires when the background sync scheduler performs a full reconcile, so
+  /// the modules can refresh data that is not covered by collab sync, e.g.
+  /// the shared views.
+  async fn on_full_sync_reconcile(&self, _user_id: i64, _workspace_id: &Uuid) -> FlowyResult<()> {
+    Ok(())
+  }
+
   fn on_subscription_plans_updated(&self, _plans: Vec<SubscriptionPlan>) {}
   fn on_storage_permission_updated(&self, _can_write: bool) {}
 
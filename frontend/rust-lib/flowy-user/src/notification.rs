@@ -21,6 +21,26 @@ pub(crate) enum UserNotification {
   // TODO: implement reminder observer
   DidUpdateReminder = 8,
   DidOpenWorkspace = 9,
+  /// A scheduled reminder became due. The notification id is the object id
+  /// of the reminder.
+  DidFireReminder = 10,
+  /// The app was locked, either explicitly or by the inactivity timeout.
+  DidLockApp = 11,
+  /// Progress of a data migration between the local and a cloud account.
+  DidUpdateMigrationProgress = 12,
+  /// Progress of an account deletion and the local data wipe.
+  DidUpdateDeleteAccountProgress = 13,
+  /// Synced settings from another device were applied to the local
+  /// preferences. The payload lists the affected preference keys.
+  DidUpdateSyncedSettings = 14,
+  /// The sync status of a collab object changed. The notification id is the
+  /// object id.
+  DidUpdateSyncStatus = 15,
+  /// The pending sync queue drained, all offline edits reached the server.
+  DidDrainSyncQueue = 16,
+  /// The notification inbox changed. The payload carries the new unread
+  /// count.
+  DidUpdateNotificationInbox = 17,
 }
 
 #[tracing::instrument(level = "trace", skip_all)]
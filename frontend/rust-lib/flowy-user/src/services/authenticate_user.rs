@@ -38,7 +38,10 @@ impl Drop for AuthenticateUser {
 impl AuthenticateUser {
   pub fn new(user_config: UserConfig, store_preferences: Arc<KVStorePreferences>) -> Self {
     let user_paths = UserPaths::new(user_config.storage_path.clone());
-    let database = Arc::new(UserDB::new(user_paths.clone()));
+    let database = Arc::new(
+      UserDB::new(user_paths.clone())
+        .with_encryption_key(user_config.local_encryption_key.clone()),
+    );
     let session = migrate_session(&user_config.session_cache_key, &store_preferences).map(Arc::new);
     Self {
       user_config,
@@ -0,0 +1,123 @@
+pub(crate) mod s3;
+pub(crate) mod webdav;
+
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::async_trait::async_trait;
+
+use crate::services::backup::s3::S3Transport;
+use crate::services::backup::webdav::WebDavTransport;
+
+/// Name of the manifest object kept next to the archives on the backup
+/// target. Listing is done through it instead of WebDAV PROPFIND or S3 LIST,
+/// which keeps the transports to plain GET/PUT/DELETE.
+pub(crate) const BACKUP_MANIFEST_NAME: &str = "appflowy_backup_manifest.json";
+
+/// Which protocol the backup target speaks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BackupKind {
+  #[default]
+  WebDav,
+  S3,
+}
+
+/// The user-configured backup target, stored locally in the key value store.
+/// The credentials never leave the device.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BackupConfig {
+  pub kind: BackupKind,
+  /// Base url of the WebDAV collection or the S3-compatible endpoint.
+  pub endpoint: String,
+  /// WebDAV basic auth credentials.
+  pub username: String,
+  pub password: String,
+  /// S3 credentials and location.
+  pub access_key: String,
+  pub secret_key: String,
+  pub bucket: String,
+  pub region: String,
+  /// Hours between two automatic backups, zero disables them.
+  pub interval_hours: i64,
+  /// How many archives to keep on the target, older ones are pruned.
+  pub retention_count: usize,
+}
+
+/// One uploaded archive as recorded in the manifest.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BackupEntry {
+  pub name: String,
+  /// Milliseconds since the epoch.
+  pub created_at: i64,
+  /// Size of the encrypted archive in bytes.
+  pub size: u64,
+  /// Hex encoded sha256 of the encrypted archive, checked after upload and
+  /// before restore.
+  pub sha256: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BackupManifest {
+  pub archives: Vec<BackupEntry>,
+}
+
+/// The verbs a backup target must support. Both transports operate on plain
+/// object names relative to their configured location.
+#[async_trait]
+pub trait BackupTransport: Send + Sync {
+  async fn upload(&self, name: &str, data: Vec<u8>) -> FlowyResult<()>;
+  async fn download(&self, name: &str) -> FlowyResult<Vec<u8>>;
+  async fn delete(&self, name: &str) -> FlowyResult<()>;
+}
+
+/// Builds the transport matching the configured backup target.
+pub(crate) fn make_transport(config: &BackupConfig) -> FlowyResult<Arc<dyn BackupTransport>> {
+  if config.endpoint.is_empty() {
+    return Err(FlowyError::new(
+      ErrorCode::InvalidParams,
+      "The backup endpoint is not configured",
+    ));
+  }
+  match config.kind {
+    BackupKind::WebDav => Ok(Arc::new(WebDavTransport::new(
+      &config.endpoint,
+      &config.username,
+      &config.password,
+    ))),
+    BackupKind::S3 => {
+      if config.bucket.is_empty() {
+        return Err(FlowyError::new(
+          ErrorCode::InvalidParams,
+          "The backup bucket is not configured",
+        ));
+      }
+      Ok(Arc::new(S3Transport::new(
+        &config.endpoint,
+        &config.bucket,
+        &config.region,
+        &config.access_key,
+        &config.secret_key,
+      )))
+    },
+  }
+}
+
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+  hex_encode(&Sha256::digest(data))
+}
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+  let mut out = String::with_capacity(bytes.len() * 2);
+  for byte in bytes {
+    let _ = write!(out, "{:02x}", byte);
+  }
+  out
+}
+
+pub(crate) fn transport_error(action: &str, err: impl std::fmt::Display) -> FlowyError {
+  FlowyError::new(ErrorCode::NetworkError, format!("{}: {}", action, err))
+}
@@ -0,0 +1,161 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::async_trait::async_trait;
+
+use crate::services::backup::{BackupTransport, hex_encode, sha256_hex, transport_error};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Pushes archives to an S3-compatible endpoint with AWS signature v4,
+/// path-style addressing. Only GET/PUT/DELETE on plain object names are
+/// needed, so the implementation signs exactly those: no query parameters
+/// and names that require no uri encoding.
+pub(crate) struct S3Transport {
+  client: reqwest::Client,
+  endpoint: String,
+  bucket: String,
+  region: String,
+  access_key: String,
+  secret_key: String,
+}
+
+impl S3Transport {
+  pub(crate) fn new(
+    endpoint: &str,
+    bucket: &str,
+    region: &str,
+    access_key: &str,
+    secret_key: &str,
+  ) -> Self {
+    let region = if region.is_empty() {
+      "us-east-1".to_string()
+    } else {
+      region.to_string()
+    };
+    Self {
+      client: reqwest::Client::new(),
+      endpoint: endpoint.trim_end_matches('/').to_string(),
+      bucket: bucket.to_string(),
+      region,
+      access_key: access_key.to_string(),
+      secret_key: secret_key.to_string(),
+    }
+  }
+
+  async fn request(&self, method: &str, name: &str, body: Vec<u8>) -> FlowyResult<reqwest::Response> {
+    let uri = format!("/{}/{}", self.bucket, name);
+    let url = format!("{}{}", self.endpoint, uri);
+    let parsed = reqwest::Url::parse(&url)
+      .map_err(|err| FlowyError::new(ErrorCode::InvalidParams, format!("Invalid endpoint: {}", err)))?;
+    let host = match parsed.port() {
+      Some(port) => format!("{}:{}", parsed.host_str().unwrap_or_default(), port),
+      None => parsed.host_str().unwrap_or_default().to_string(),
+    };
+
+    let now = Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+    let payload_hash = sha256_hex(&body);
+
+    let canonical_headers = format!(
+      "host:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n",
+      host, payload_hash, amz_date
+    );
+    let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+    let canonical_request = format!(
+      "{}\n{}\n\n{}\n{}\n{}",
+      method, uri, canonical_headers, signed_headers, payload_hash
+    );
+
+    let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+    let string_to_sign = format!(
+      "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+      amz_date,
+      scope,
+      sha256_hex(canonical_request.as_bytes())
+    );
+
+    let mut key = hmac_sha256(
+      format!("AWS4{}", self.secret_key).as_bytes(),
+      date.as_bytes(),
+    );
+    for part in [self.region.as_bytes(), b"s3", b"aws4_request"] {
+      key = hmac_sha256(&key, part);
+    }
+    let signature = hex_encode(&hmac_sha256(&key, string_to_sign.as_bytes()));
+
+    let authorization = format!(
+      "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+      self.access_key, scope, signed_headers, signature
+    );
+
+    let request = match method {
+      "PUT" => self.client.put(url).body(body),
+      "DELETE" => self.client.delete(url),
+      _ => self.client.get(url),
+    };
+    request
+      .header("host", host)
+      .header("x-amz-content-sha256", payload_hash)
+      .header("x-amz-date", amz_date)
+      .header("authorization", authorization)
+      .send()
+      .await
+      .map_err(|err| transport_error("Backup target request failed", err))
+  }
+}
+
+#[async_trait]
+impl BackupTransport for S3Transport {
+  async fn upload(&self, name: &str, data: Vec<u8>) -> FlowyResult<()> {
+    let response = self.request("PUT", name, data).await?;
+    if !response.status().is_success() {
+      return Err(transport_error(
+        "Upload backup archive failed",
+        response.status(),
+      ));
+    }
+    Ok(())
+  }
+
+  async fn download(&self, name: &str) -> FlowyResult<Vec<u8>> {
+    let response = self.request("GET", name, Vec::new()).await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+      return Err(FlowyError::new(
+        ErrorCode::RecordNotFound,
+        format!("Backup archive {} not found", name),
+      ));
+    }
+    if !response.status().is_success() {
+      return Err(transport_error(
+        "Download backup archive failed",
+        response.status(),
+      ));
+    }
+    let bytes = response
+      .bytes()
+      .await
+      .map_err(|err| transport_error("Download backup archive failed", err))?;
+    Ok(bytes.to_vec())
+  }
+
+  async fn delete(&self, name: &str) -> FlowyResult<()> {
+    let response = self.request("DELETE", name, Vec::new()).await?;
+    if !response.status().is_success() && response.status() != reqwest::StatusCode::NOT_FOUND {
+      return Err(transport_error(
+        "Delete backup archive failed",
+        response.status(),
+      ));
+    }
+    Ok(())
+  }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+  let mut mac = HmacSha256::new_from_slice(key).expect("hmac accepts keys of any size");
+  mac.update(data);
+  mac.finalize().into_bytes().to_vec()
+}
@@ -0,0 +1,94 @@
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::async_trait::async_trait;
+
+use crate::services::backup::{BackupTransport, transport_error};
+
+/// Pushes archives to a WebDAV collection with basic auth. The collection
+/// must already exist, the transport only issues GET/PUT/DELETE on objects
+/// inside it.
+pub(crate) struct WebDavTransport {
+  client: reqwest::Client,
+  endpoint: String,
+  username: String,
+  password: String,
+}
+
+impl WebDavTransport {
+  pub(crate) fn new(endpoint: &str, username: &str, password: &str) -> Self {
+    Self {
+      client: reqwest::Client::new(),
+      endpoint: endpoint.trim_end_matches('/').to_string(),
+      username: username.to_string(),
+      password: password.to_string(),
+    }
+  }
+
+  fn object_url(&self, name: &str) -> String {
+    format!("{}/{}", self.endpoint, name)
+  }
+}
+
+#[async_trait]
+impl BackupTransport for WebDavTransport {
+  async fn upload(&self, name: &str, data: Vec<u8>) -> FlowyResult<()> {
+    let response = self
+      .client
+      .put(self.object_url(name))
+      .basic_auth(&self.username, Some(&self.password))
+      .body(data)
+      .send()
+      .await
+      .map_err(|err| transport_error("Upload backup archive failed", err))?;
+    if !response.status().is_success() {
+      return Err(transport_error(
+        "Upload backup archive failed",
+        response.status(),
+      ));
+    }
+    Ok(())
+  }
+
+  async fn download(&self, name: &str) -> FlowyResult<Vec<u8>> {
+    let response = self
+      .client
+      .get(self.object_url(name))
+      .basic_auth(&self.username, Some(&self.password))
+      .send()
+      .await
+      .map_err(|err| transport_error("Download backup archive failed", err))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+      return Err(FlowyError::new(
+        ErrorCode::RecordNotFound,
+        format!("Backup archive {} not found", name),
+      ));
+    }
+    if !response.status().is_success() {
+      return Err(transport_error(
+        "Download backup archive failed",
+        response.status(),
+      ));
+    }
+    let bytes = response
+      .bytes()
+      .await
+      .map_err(|err| transport_error("Download backup archive failed", err))?;
+    Ok(bytes.to_vec())
+  }
+
+  async fn delete(&self, name: &str) -> FlowyResult<()> {
+    let response = self
+      .client
+      .delete(self.object_url(name))
+      .basic_auth(&self.username, Some(&self.password))
+      .send()
+      .await
+      .map_err(|err| transport_error("Delete backup archive failed", err))?;
+    if !response.status().is_success() && response.status() != reqwest::StatusCode::NOT_FOUND {
+      return Err(transport_error(
+        "Delete backup archive failed",
+        response.status(),
+      ));
+    }
+    Ok(())
+  }
+}
@@ -24,6 +24,8 @@ pub struct UserDB {
   paths: Box<dyn UserDBPath>,
   sqlite_map: DashMap<i64, Database>,
   collab_db_map: DashMap<i64, Arc<CollabKVDB>>,
+  /// Opt-in SQLCipher key for the per-user sqlite databases.
+  encryption_key: Option<String>,
 }
 
 impl UserDB {
@@ -32,9 +34,15 @@ impl UserDB {
       paths: Box::new(paths),
       sqlite_map: Default::default(),
       collab_db_map: Default::default(),
+      encryption_key: None,
     }
   }
 
+  pub fn with_encryption_key(mut self, encryption_key: Option<String>) -> Self {
+    self.encryption_key = encryption_key;
+    self
+  }
+
   /// Performs a conditional backup or restoration of the collaboration database (CollabDB) for a specific user.
   #[instrument(level = "debug", skip_all)]
   pub fn backup(&self, uid: i64, workspace_id: &str) {
@@ -111,9 +119,10 @@ impl UserDB {
       Entry::Occupied(e) => Ok(e.get().get_pool()),
       Entry::Vacant(e) => {
         tracing::debug!("open sqlite db {} at path: {:?}", user_id, db_path.as_ref());
-        let db = flowy_sqlite::init(&db_path).map_err(|e| {
-          FlowyError::internal().with_context(format!("open user db failed, {:?}", e))
-        })?;
+        let db =
+          flowy_sqlite::init_with_key(&db_path, self.encryption_key.as_deref()).map_err(|e| {
+            FlowyError::internal().with_context(format!("open user db failed, {:?}", e))
+          })?;
         let pool = db.get_pool();
         e.insert(db);
         Ok(pool)
@@ -147,6 +156,8 @@ impl UserDB {
           uid,
           collab_db_path.as_ref()
         );
+        // The rocksdb backed collab db does not expose a keyed open yet, so the
+        // local encryption key only covers the sqlite databases for now.
         let db = match CollabKVDB::open(&collab_db_path) {
           Ok(db) => Ok(db),
           Err(err) => {
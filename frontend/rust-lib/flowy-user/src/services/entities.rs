@@ -21,6 +21,9 @@ pub struct UserConfig {
   /// Used as the key of `Session` when saving session information to KV.
   pub(crate) session_cache_key: String,
   pub app_version: Version,
+  /// Opt-in SQLCipher key for the local databases, provided by the host
+  /// application from the OS keychain.
+  pub local_encryption_key: Option<String>,
 }
 
 impl UserConfig {
@@ -40,9 +43,15 @@ impl UserConfig {
       session_cache_key,
       device_id: device_id.to_owned(),
       app_version,
+      local_encryption_key: None,
     }
   }
 
+  pub fn with_local_encryption_key(mut self, local_encryption_key: Option<String>) -> Self {
+    self.local_encryption_key = local_encryption_key;
+    self
+  }
+
   /// Returns bool whether the user choose a custom path for the user data.
   pub fn is_custom_storage_path(&self) -> bool {
     !self.storage_path.contains(&self.application_path)
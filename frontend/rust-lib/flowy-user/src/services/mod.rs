@@ -1,7 +1,10 @@
 pub mod authenticate_user;
+pub mod backup;
 pub(crate) mod billing_check;
 pub mod cloud_config;
 pub mod collab_interact;
 pub mod data_import;
 pub mod db;
 pub mod entities;
+pub mod notification_inbox;
+pub mod reminder_scheduler;
@@ -0,0 +1,145 @@
+use chrono::Utc;
+use flowy_error::FlowyError;
+use flowy_sqlite::schema::notification_inbox_table;
+use flowy_sqlite::schema::notification_inbox_table::dsl;
+use flowy_sqlite::{DBConnection, ExpressionMethods, prelude::*};
+
+use crate::entities::InboxUnreadCountPB;
+use crate::notification::{UserNotification, send_notification};
+
+const NOTIFICATION_INBOX_OBSERVER_ID: &str = "notification_inbox";
+
+/// What produced an inbox notification. Stored as an integer, so new kinds
+/// must only be appended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InboxNotificationKind {
+  Mention = 0,
+  Comment = 1,
+  Reminder = 2,
+  SharedWithYou = 3,
+  Publish = 4,
+}
+
+/// One record of the notification inbox. The id doubles as the dedup key:
+/// producers derive it from their source, e.g. `shared:{view_id}`, so
+/// re-observing the same event does not create a second record.
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = notification_inbox_table)]
+pub struct NotificationInboxTable {
+  pub id: String,
+  pub kind: i32,
+  pub title: String,
+  pub body: String,
+  pub object_id: String,
+  pub sender: String,
+  pub workspace_id: String,
+  pub created_at: i64,
+  pub is_read: bool,
+  pub is_archived: bool,
+}
+
+impl NotificationInboxTable {
+  pub fn new(id: String, kind: InboxNotificationKind, title: String) -> Self {
+    Self {
+      id,
+      kind: kind as i32,
+      title,
+      created_at: Utc::now().timestamp_millis(),
+      ..Default::default()
+    }
+  }
+}
+
+/// Inserts an inbox record, returns false when a record with the same id
+/// already exists.
+pub fn insert_inbox_record(
+  conn: &mut DBConnection,
+  record: NotificationInboxTable,
+) -> Result<bool, FlowyError> {
+  let affected = diesel::insert_or_ignore_into(dsl::notification_inbox_table)
+    .values(record)
+    .execute(conn)?;
+  Ok(affected > 0)
+}
+
+/// One page of the inbox, newest first. Archived records are only included
+/// on request.
+pub fn select_inbox_records(
+  conn: &mut DBConnection,
+  offset: i64,
+  limit: i64,
+  include_archived: bool,
+) -> Result<Vec<NotificationInboxTable>, FlowyError> {
+  let mut query = dsl::notification_inbox_table.into_boxed();
+  if !include_archived {
+    query = query.filter(notification_inbox_table::is_archived.eq(false));
+  }
+  let records = query
+    .order(notification_inbox_table::created_at.desc())
+    .offset(offset)
+    .limit(limit)
+    .load::<NotificationInboxTable>(conn)?;
+  Ok(records)
+}
+
+/// Marks the given records as read, or every unread record when `ids` is
+/// empty. Returns the number of records that changed.
+pub fn mark_inbox_records_read(
+  conn: &mut DBConnection,
+  ids: &[String],
+) -> Result<usize, FlowyError> {
+  let affected = if ids.is_empty() {
+    diesel::update(
+      dsl::notification_inbox_table.filter(notification_inbox_table::is_read.eq(false)),
+    )
+    .set(notification_inbox_table::is_read.eq(true))
+    .execute(conn)?
+  } else {
+    diesel::update(
+      dsl::notification_inbox_table
+        .filter(notification_inbox_table::id.eq_any(ids))
+        .filter(notification_inbox_table::is_read.eq(false)),
+    )
+    .set(notification_inbox_table::is_read.eq(true))
+    .execute(conn)?
+  };
+  Ok(affected)
+}
+
+/// Archives a record. Archived records disappear from the default listing
+/// and from the unread count, but stay queryable.
+pub fn archive_inbox_record(conn: &mut DBConnection, id: &str) -> Result<usize, FlowyError> {
+  let affected = diesel::update(
+    dsl::notification_inbox_table.filter(notification_inbox_table::id.eq(id)),
+  )
+  .set((
+    notification_inbox_table::is_archived.eq(true),
+    notification_inbox_table::is_read.eq(true),
+  ))
+  .execute(conn)?;
+  Ok(affected)
+}
+
+pub fn count_unread_inbox_records(conn: &mut DBConnection) -> Result<i64, FlowyError> {
+  let count = dsl::notification_inbox_table
+    .filter(notification_inbox_table::is_read.eq(false))
+    .filter(notification_inbox_table::is_archived.eq(false))
+    .count()
+    .get_result::<i64>(conn)?;
+  Ok(count)
+}
+
+/// Pushes the current unread count to the frontend. Call after anything that
+/// changes the inbox.
+pub(crate) fn notify_inbox_changed(conn: &mut DBConnection) -> Result<(), FlowyError> {
+  let count = count_unread_inbox_records(conn)?;
+  send_notification(
+    NOTIFICATION_INBOX_OBSERVER_ID,
+    UserNotification::DidUpdateNotificationInbox,
+  )
+  .payload(InboxUnreadCountPB {
+    count: count as u64,
+  })
+  .send();
+  Ok(())
+}
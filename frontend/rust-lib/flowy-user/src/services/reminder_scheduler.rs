@@ -0,0 +1,219 @@
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use collab_entity::reminder::Reminder;
+use flowy_error::FlowyError;
+use flowy_sqlite::schema::reminder_schedule_table;
+use flowy_sqlite::schema::reminder_schedule_table::dsl;
+use flowy_sqlite::{ConnectionPool, DBConnection, ExpressionMethods, prelude::*};
+use lib_infra::util::timestamp;
+use tokio::task::JoinHandle;
+use tracing::{error, info, trace};
+
+use crate::entities::ReminderPB;
+use crate::notification::{UserNotification, send_notification};
+use crate::services::notification_inbox::{
+  InboxNotificationKind, NotificationInboxTable, insert_inbox_record, notify_inbox_changed,
+};
+
+/// How often the scheduler looks for due reminders.
+const SCHEDULER_TICK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Reminder meta key the document editor sets on reminders created from a
+/// reminder block.
+const REMINDER_META_BLOCK_ID: &str = "block_id";
+/// Reminder meta key the database editor sets on reminders created from a
+/// date cell.
+const REMINDER_META_ROW_ID: &str = "row_id";
+
+/// Where a reminder originated. All sources funnel into the same schedule
+/// table, so they fire, snooze and catch up the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderSource {
+  /// A manual "remind me about this page" request.
+  Page = 0,
+  DatabaseDateCell = 1,
+  DocumentBlock = 2,
+}
+
+impl From<&Reminder> for ReminderSource {
+  fn from(reminder: &Reminder) -> Self {
+    let meta = reminder.meta.clone().into_inner();
+    if meta.contains_key(REMINDER_META_BLOCK_ID) {
+      ReminderSource::DocumentBlock
+    } else if meta.contains_key(REMINDER_META_ROW_ID) {
+      ReminderSource::DatabaseDateCell
+    } else {
+      ReminderSource::Page
+    }
+  }
+}
+
+#[derive(Clone, Default, Queryable, Identifiable, Insertable)]
+#[diesel(table_name = reminder_schedule_table)]
+#[diesel(primary_key(reminder_id))]
+pub struct ReminderScheduleTable {
+  pub reminder_id: String,
+  pub object_id: String,
+  pub scheduled_at: i64,
+  pub is_fired: bool,
+  pub source: i32,
+}
+
+impl From<&Reminder> for ReminderScheduleTable {
+  fn from(reminder: &Reminder) -> Self {
+    Self {
+      reminder_id: reminder.id.clone(),
+      object_id: reminder.object_id.clone(),
+      scheduled_at: reminder.scheduled_at,
+      is_fired: false,
+      source: ReminderSource::from(reminder) as i32,
+    }
+  }
+}
+
+/// Upserts the schedule of a reminder. Rescheduling an existing reminder
+/// resets its fired flag so it fires again at the new time.
+pub fn upsert_reminder_schedule(
+  conn: &mut DBConnection,
+  schedule: ReminderScheduleTable,
+) -> Result<(), FlowyError> {
+  diesel::insert_into(dsl::reminder_schedule_table)
+    .values(schedule.clone())
+    .on_conflict(reminder_schedule_table::reminder_id)
+    .do_update()
+    .set((
+      reminder_schedule_table::object_id.eq(schedule.object_id),
+      reminder_schedule_table::scheduled_at.eq(schedule.scheduled_at),
+      reminder_schedule_table::is_fired.eq(false),
+      reminder_schedule_table::source.eq(schedule.source),
+    ))
+    .execute(conn)?;
+  Ok(())
+}
+
+/// Pushes the schedule of a reminder forward and arms it again. Returns
+/// false when no schedule with the given id exists.
+pub fn snooze_reminder_schedule(
+  conn: &mut DBConnection,
+  reminder_id: &str,
+  snooze_until: i64,
+) -> Result<bool, FlowyError> {
+  let affected = diesel::update(
+    dsl::reminder_schedule_table.filter(reminder_schedule_table::reminder_id.eq(reminder_id)),
+  )
+  .set((
+    reminder_schedule_table::scheduled_at.eq(snooze_until),
+    reminder_schedule_table::is_fired.eq(false),
+  ))
+  .execute(conn)?;
+  Ok(affected > 0)
+}
+
+pub fn delete_reminder_schedule(
+  conn: &mut DBConnection,
+  reminder_id: &str,
+) -> Result<(), FlowyError> {
+  diesel::delete(
+    dsl::reminder_schedule_table.filter(reminder_schedule_table::reminder_id.eq(reminder_id)),
+  )
+  .execute(conn)?;
+  Ok(())
+}
+
+/// Selects the reminders that are due but haven't fired yet.
+pub fn select_due_reminder_schedules(
+  conn: &mut DBConnection,
+  now: i64,
+) -> Result<Vec<ReminderScheduleTable>, FlowyError> {
+  let schedules = dsl::reminder_schedule_table
+    .filter(reminder_schedule_table::scheduled_at.le(now))
+    .filter(reminder_schedule_table::is_fired.eq(false))
+    .load::<ReminderScheduleTable>(conn)?;
+  Ok(schedules)
+}
+
+fn mark_reminder_schedule_fired(
+  conn: &mut DBConnection,
+  reminder_id: &str,
+) -> Result<(), FlowyError> {
+  diesel::update(
+    dsl::reminder_schedule_table.filter(reminder_schedule_table::reminder_id.eq(reminder_id)),
+  )
+  .set(reminder_schedule_table::is_fired.eq(true))
+  .execute(conn)?;
+  Ok(())
+}
+
+/// Periodically checks the persisted reminder schedules and fires a
+/// [UserNotification::DidFireReminder] notification for each one that is due.
+/// The schedules live in sqlite, so reminders created before a restart still
+/// fire; the first tick runs immediately on start, which catches up on
+/// reminders that became due while the app was closed.
+pub struct ReminderScheduler {
+  handle: JoinHandle<()>,
+}
+
+impl ReminderScheduler {
+  pub fn start(sqlite_pool: Weak<ConnectionPool>) -> Self {
+    let handle = tokio::spawn(async move {
+      let mut interval = tokio::time::interval(SCHEDULER_TICK_INTERVAL);
+      loop {
+        interval.tick().await;
+        let pool = match sqlite_pool.upgrade() {
+          Some(pool) => pool,
+          None => break,
+        };
+        if let Err(err) = fire_due_reminders(&pool) {
+          error!("Failed to fire due reminders: {:?}", err);
+        }
+      }
+    });
+    Self { handle }
+  }
+}
+
+impl Drop for ReminderScheduler {
+  fn drop(&mut self) {
+    self.handle.abort();
+  }
+}
+
+fn fire_due_reminders(pool: &Arc<ConnectionPool>) -> Result<(), FlowyError> {
+  let mut conn = pool.get()?;
+  let now = timestamp();
+  let due_schedules = select_due_reminder_schedules(&mut conn, now)?;
+  let mut inbox_changed = false;
+  for schedule in due_schedules {
+    if now - schedule.scheduled_at > SCHEDULER_TICK_INTERVAL.as_secs() as i64 {
+      info!(
+        "Catching up on reminder {} that became due while the app was closed",
+        schedule.reminder_id
+      );
+    }
+    trace!(
+      "Firing reminder: {} for object: {}",
+      schedule.reminder_id, schedule.object_id
+    );
+    mark_reminder_schedule_fired(&mut conn, &schedule.reminder_id)?;
+    let mut record = NotificationInboxTable::new(
+      format!("reminder:{}", schedule.reminder_id),
+      InboxNotificationKind::Reminder,
+      "Reminder".to_string(),
+    );
+    record.object_id = schedule.object_id.clone();
+    inbox_changed |= insert_inbox_record(&mut conn, record)?;
+    send_notification(&schedule.object_id, UserNotification::DidFireReminder)
+      .payload(ReminderPB {
+        id: schedule.reminder_id,
+        object_id: schedule.object_id,
+        scheduled_at: schedule.scheduled_at,
+        ..Default::default()
+      })
+      .send();
+  }
+  if inbox_changed {
+    notify_inbox_changed(&mut conn)?;
+  }
+  Ok(())
+}
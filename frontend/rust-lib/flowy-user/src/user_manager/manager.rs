@@ -24,7 +24,9 @@ use tokio_stream::StreamExt;
 use tracing::{debug, error, event, info, instrument, warn};
 use uuid::Uuid;
 
-use crate::entities::{AuthStateChangedPB, AuthStatePB, UserProfilePB, UserSettingPB};
+use crate::entities::{
+  AuthStateChangedPB, AuthStatePB, DeleteAccountProgressPB, UserProfilePB, UserSettingPB,
+};
 use crate::event_map::{AppLifeCycle, DefaultUserStatusCallback};
 use crate::migrations::document_empty_content::HistoricalEmptyDocumentMigration;
 use crate::migrations::migration::{
@@ -35,6 +37,10 @@ use crate::migrations::workspace_trash_v1::WorkspaceTrashMapToSectionMigration;
 use crate::services::authenticate_user::AuthenticateUser;
 use crate::services::cloud_config::get_cloud_config;
 use crate::services::collab_interact::{DefaultCollabInteract, UserReminder};
+use crate::services::reminder_scheduler::ReminderScheduler;
+use crate::user_manager::manager_app_lock::AppLockState;
+use crate::user_manager::manager_export::data_export_offered_key;
+use crate::user_manager::manager_sync_scheduler::SyncSchedulerState;
 
 use crate::migrations::anon_user_workspace::AnonUserWorkspaceTableMigration;
 use crate::migrations::doc_key_with_workspace::CollabDocKeyWithWorkspaceIdMigration;
@@ -53,6 +59,9 @@ pub struct UserManager {
   pub(crate) authenticate_user: Arc<AuthenticateUser>,
   refresh_user_profile_since: AtomicI64,
   pub(crate) is_loading_awareness: Arc<DashMap<Uuid, bool>>,
+  pub(crate) reminder_scheduler: RwLock<Option<ReminderScheduler>>,
+  pub(crate) app_lock_state: Arc<AppLockState>,
+  pub(crate) sync_scheduler_state: SyncSchedulerState,
 }
 
 impl Drop for UserManager {
@@ -84,7 +93,13 @@ impl UserManager {
       refresh_user_profile_since,
       user_workspace_service,
       is_loading_awareness: Arc::new(Default::default()),
+      reminder_scheduler: RwLock::new(None),
+      app_lock_state: Arc::new(Default::default()),
+      sync_scheduler_state: Default::default(),
     });
+    Self::start_sync_scheduler(Arc::downgrade(&user_manager));
+    Self::start_backup_scheduler(Arc::downgrade(&user_manager));
+    Self::start_local_backup_scheduler(Arc::downgrade(&user_manager));
 
     let weak_user_manager = Arc::downgrade(&user_manager);
     if let Ok(user_service) = user_manager
@@ -142,6 +157,10 @@ impl UserManager {
     let app_life_cycle = Arc::new(user_status_callback);
     *self.app_life_cycle.write().await = app_life_cycle.clone();
     *self.collab_interact.write().await = Arc::new(collab_interact);
+    self.init_app_lock();
+    if let Err(err) = self.start_sync_status_forwarder() {
+      error!("Start sync status forwarder failed: {}", err);
+    }
     let cloud_service = self.cloud_service()?;
 
     if let Ok(session) = self.get_session() {
@@ -149,6 +168,9 @@ impl UserManager {
         "Init user session: {}, workspace: {}",
         session.user_id, session.workspace_id
       );
+      // Make sure the active session shows up in the signed-in accounts
+      // registry for installs that predate it.
+      self.record_account(&session);
       let workspace_uuid = Uuid::parse_str(&session.workspace_id)?;
       let mut conn = self.db_connection(session.user_id)?;
       let workspace_type =
@@ -496,6 +518,7 @@ impl UserManager {
   #[tracing::instrument(level = "info", skip(self))]
   pub async fn sign_out(&self) -> Result<(), FlowyError> {
     if let Ok(session) = self.get_session() {
+      self.remove_account(session.user_id);
       sign_out(
         &self.cloud_service()?,
         &session,
@@ -507,13 +530,86 @@ impl UserManager {
     Ok(())
   }
 
-  #[tracing::instrument(level = "info", skip(self))]
-  pub async fn delete_account(&self) -> Result<(), FlowyError> {
-    self
-      .cloud_service()?
-      .get_user_service()?
-      .delete_account()
+  /// Deletes the account in the cloud, then wipes every local trace of the
+  /// user: the sqlite database, collab KV stores, cached files and the stored
+  /// preferences for that uid.
+  ///
+  /// `confirmation` must match the account email (or the user name for local
+  /// accounts without one). A data export has to be offered first, either by
+  /// running a data export or by setting `export_offered` after showing the
+  /// offer to the user.
+  #[tracing::instrument(level = "info", skip(self, confirmation), err)]
+  pub async fn delete_account(
+    &self,
+    confirmation: &str,
+    export_offered: bool,
+  ) -> Result<(), FlowyError> {
+    let session = self.get_session()?;
+    let uid = session.user_id;
+    let profile = self
+      .get_user_profile_from_disk(uid, &session.workspace_id)
       .await?;
+
+    let expected = if profile.auth_type.is_local() || profile.email.is_empty() {
+      profile.name.clone()
+    } else {
+      profile.email.clone()
+    };
+    if confirmation != expected {
+      return Err(FlowyError::new(
+        flowy_error::ErrorCode::InvalidParams,
+        "The confirmation doesn't match the account",
+      ));
+    }
+
+    if export_offered {
+      let _ = self
+        .store_preferences
+        .set_bool(&data_export_offered_key(uid), true);
+    }
+    if !self
+      .store_preferences
+      .get_bool_or_default(&data_export_offered_key(uid))
+    {
+      return Err(FlowyError::new(
+        flowy_error::ErrorCode::InvalidParams,
+        "Offer the user a data export before deleting the account",
+      ));
+    }
+
+    let total = 3u64;
+    send_delete_account_progress(0, total, "Deleting the account in the cloud");
+    if !profile.auth_type.is_local() {
+      self
+        .cloud_service()?
+        .get_user_service()?
+        .delete_account()
+        .await?;
+    }
+
+    send_delete_account_progress(1, total, "Signing out");
+    self.remove_account(uid);
+    if self.get_anon_user_id().ok() == Some(uid) {
+      self.remove_anon_user();
+    }
+    let _ = remove_user_token(uid, self.db_connection(uid)?);
+    self.authenticate_user.database.close(uid)?;
+    self.authenticate_user.set_session(None)?;
+
+    send_delete_account_progress(2, total, "Wiping local data");
+    let user_data_dir = self.authenticate_user.user_paths.user_data_dir(uid);
+    if let Err(err) = std::fs::remove_dir_all(&user_data_dir) {
+      error!("Wipe user data dir {} failed: {:?}", user_data_dir, err);
+    }
+    self
+      .store_preferences
+      .remove(&data_export_offered_key(uid));
+
+    send_delete_account_progress(total, total, "Account deleted");
+    send_auth_state_notification(AuthStateChangedPB {
+      state: AuthStatePB::AuthStateSignOut,
+      message: "Account deleted".to_string(),
+    });
     Ok(())
   }
 
@@ -789,6 +885,7 @@ impl UserManager {
     self
       .authenticate_user
       .set_session(Some(session.clone().into()))?;
+    self.record_account(session);
     self
       .save_user(uid, (user_profile, auth_type).into())
       .await?;
@@ -843,6 +940,19 @@ fn save_user_token(
   upsert_user_profile_change(uid, workspace_id, conn, changeset)
 }
 
+fn send_delete_account_progress(current: u64, total: u64, description: &str) {
+  send_notification(
+    "delete_account",
+    UserNotification::DidUpdateDeleteAccountProgress,
+  )
+  .payload(DeleteAccountProgressPB {
+    current,
+    total,
+    description: description.to_string(),
+  })
+  .send();
+}
+
 #[instrument(level = "info", skip_all, err)]
 fn remove_user_token(uid: i64, mut conn: DBConnection) -> FlowyResult<()> {
   diesel::update(user_table::dsl::user_table.filter(user_table::id.eq(&uid.to_string())))
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use tracing::{error, info, instrument, warn};
+use uuid::Uuid;
+
+use crate::entities::{AuthStateChangedPB, AuthStatePB, UserAccountPB, UserProfilePB};
+use crate::notification::{send_auth_state_notification, send_notification, UserNotification};
+use crate::user_manager::UserManager;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use flowy_user_pub::entities::{AuthType, WorkspaceType};
+use flowy_user_pub::session::Session;
+use flowy_user_pub::sql::select_user_workspace_type;
+
+/// Sessions of every account signed in on this device, keyed by user id. Each
+/// account keeps its own sqlite/collab databases under its uid, so switching
+/// only swaps the active session and reopens the target account's data.
+pub const USER_ACCOUNTS: &str = "user_accounts";
+
+impl UserManager {
+  pub(crate) fn load_account_sessions(&self) -> Vec<Session> {
+    self
+      .store_preferences
+      .get_object::<Vec<Session>>(USER_ACCOUNTS)
+      .unwrap_or_default()
+  }
+
+  /// Records the session in the signed-in accounts registry, replacing any
+  /// previous session for the same user id.
+  pub(crate) fn record_account(&self, session: &Session) {
+    let mut sessions = self.load_account_sessions();
+    sessions.retain(|s| s.user_id != session.user_id);
+    sessions.push(session.clone());
+    if let Err(err) = self.store_preferences.set_object(USER_ACCOUNTS, &sessions) {
+      error!("Save account registry failed: {:?}", err);
+    }
+  }
+
+  pub(crate) fn remove_account(&self, uid: i64) {
+    let mut sessions = self.load_account_sessions();
+    sessions.retain(|s| s.user_id != uid);
+    if let Err(err) = self.store_preferences.set_object(USER_ACCOUNTS, &sessions) {
+      error!("Save account registry failed: {:?}", err);
+    }
+  }
+
+  /// Lists every account signed in on this device, reading each profile from
+  /// that account's own database.
+  pub async fn list_accounts(&self) -> FlowyResult<Vec<UserAccountPB>> {
+    let current_uid = self.get_session().ok().map(|session| session.user_id);
+    let mut accounts = Vec::new();
+    for session in self.load_account_sessions() {
+      match self
+        .get_user_profile_from_disk(session.user_id, &session.workspace_id)
+        .await
+      {
+        Ok(mut profile) => {
+          // Local accounts use a placeholder email that should not be exposed
+          // to the client.
+          if profile.auth_type == AuthType::Local {
+            profile.email = "".to_string();
+          }
+          accounts.push(UserAccountPB {
+            uid: profile.uid,
+            name: profile.name,
+            email: profile.email,
+            is_current: current_uid == Some(session.user_id),
+          });
+        },
+        Err(err) => {
+          warn!(
+            "Skip account {} without a local profile: {:?}",
+            session.user_id, err
+          );
+        },
+      }
+    }
+    Ok(accounts)
+  }
+
+  /// Switches the active account to the given user id without restarting the
+  /// app. The current account's workspace is closed and its databases are
+  /// released, then the target account's session is activated and the folder,
+  /// database and document managers are reinitialized through the
+  /// [crate::event_map::AppLifeCycle] callbacks. Notifications stay routed per
+  /// account because they are keyed by uid.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn switch_account(&self, uid: i64) -> FlowyResult<()> {
+    if let Ok(current) = self.get_session() {
+      if current.user_id == uid {
+        return Ok(());
+      }
+    }
+
+    let target = self
+      .load_account_sessions()
+      .into_iter()
+      .find(|session| session.user_id == uid)
+      .ok_or_else(|| {
+        FlowyError::new(
+          ErrorCode::RecordNotFound,
+          format!("No signed in account for user: {}", uid),
+        )
+      })?;
+
+    if let Ok(current) = self.get_session() {
+      if let Ok(workspace_id) = Uuid::parse_str(&current.workspace_id) {
+        if let Err(err) = self
+          .app_life_cycle
+          .read()
+          .await
+          .on_workspace_closed(&workspace_id)
+          .await
+        {
+          error!("Close workspace failed when switching account: {:?}", err);
+        }
+      }
+      if let Err(err) = self.authenticate_user.database.close(current.user_id) {
+        error!("Close user db failed when switching account: {:?}", err);
+      }
+    }
+
+    let target = Arc::new(target);
+    self.authenticate_user.set_session(Some(target.clone()))?;
+    self.prepare_user(&target).await;
+
+    let workspace_id = Uuid::parse_str(&target.workspace_id)?;
+    let mut conn = self.db_connection(uid)?;
+    let workspace_type =
+      select_user_workspace_type(&target.workspace_id, &mut conn).or_else(|err| {
+        // Anonymous workspaces may not be recorded in user_workspace_table.
+        // See init_with_callback for the same workaround.
+        if self
+          .get_anon_user_id()
+          .ok()
+          .filter(|&anon_id| anon_id == uid)
+          .is_some()
+        {
+          Ok(WorkspaceType::Local)
+        } else {
+          Err(err)
+        }
+      })?;
+
+    let auth_type = AuthType::from(workspace_type);
+    let token = self.token_from_auth_type(&auth_type)?;
+    let cloud_service = self.cloud_service()?;
+    cloud_service.set_server_auth_type(&auth_type, token)?;
+
+    let profile = self
+      .get_user_profile_from_disk(uid, &target.workspace_id)
+      .await?;
+    if let Err(err) = cloud_service.set_token(&profile.token) {
+      error!("Set token failed: {}", err);
+    }
+
+    info!(
+      "switch account: {}, workspace: {}, auth type: {:?}",
+      uid, target.workspace_id, auth_type
+    );
+
+    let _ = self
+      .initial_user_awareness(uid, &target.user_uuid, &workspace_id, &workspace_type)
+      .await;
+    self
+      .app_life_cycle
+      .read()
+      .await
+      .on_sign_in(
+        uid,
+        &workspace_id,
+        &self.authenticate_user.user_config,
+        &self.authenticate_user.user_paths,
+        &workspace_type,
+      )
+      .await?;
+
+    send_auth_state_notification(AuthStateChangedPB {
+      state: AuthStatePB::AuthStateSignIn,
+      message: "Switch account success".to_string(),
+    });
+    send_notification(uid, UserNotification::DidOpenWorkspace)
+      .payload(UserProfilePB::from(profile))
+      .send();
+    Ok(())
+  }
+}
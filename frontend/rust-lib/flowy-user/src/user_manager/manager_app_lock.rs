@@ -0,0 +1,209 @@
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::SaltString;
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, instrument};
+
+use crate::entities::AppLockSettingsPB;
+use crate::notification::{send_notification, UserNotification};
+use crate::user_manager::UserManager;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+/// Device level app lock settings, stored in [crate::user_manager::UserManager]'s
+/// key value preferences.
+pub const APP_LOCK_SETTINGS: &str = "app_lock_settings";
+
+/// The notification id for [UserNotification::DidLockApp]. The lock applies to
+/// the whole device, so it is not keyed by uid.
+const APP_LOCK_NOTIFICATION_ID: &str = "app_lock";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppLockSettings {
+  /// Argon2 PHC string of the passcode. The plain passcode is never stored.
+  pub passcode_hash: String,
+  /// Lock the app after this many seconds without activity. None disables
+  /// auto locking.
+  pub auto_lock_timeout_secs: Option<u64>,
+  /// When true, a successful platform biometric check reported by the client
+  /// unlocks the app without the passcode.
+  pub biometric_enabled: bool,
+}
+
+/// Runtime lock state. The settings persist across restarts, the lock flag and
+/// activity timestamp do not: the app starts locked whenever a passcode is set.
+#[derive(Default)]
+pub struct AppLockState {
+  locked: AtomicBool,
+  last_activity_ms: AtomicI64,
+  /// Bumped every time the auto lock timer is (re)configured so stale timer
+  /// tasks exit.
+  timer_generation: AtomicU64,
+}
+
+impl UserManager {
+  fn app_lock_settings(&self) -> Option<AppLockSettings> {
+    self
+      .store_preferences
+      .get_object::<AppLockSettings>(APP_LOCK_SETTINGS)
+  }
+
+  pub fn get_app_lock_settings(&self) -> AppLockSettingsPB {
+    match self.app_lock_settings() {
+      Some(settings) => AppLockSettingsPB {
+        is_enabled: true,
+        is_locked: self.app_lock_state.locked.load(Ordering::SeqCst),
+        auto_lock_timeout_secs: settings.auto_lock_timeout_secs,
+        biometric_enabled: settings.biometric_enabled,
+      },
+      None => AppLockSettingsPB::default(),
+    }
+  }
+
+  /// Enables the app lock with the given passcode, or updates the passcode
+  /// and timeout of an existing lock.
+  #[instrument(level = "info", skip(self, passcode), err)]
+  pub fn set_app_lock_passcode(
+    &self,
+    passcode: &str,
+    auto_lock_timeout_secs: Option<u64>,
+    biometric_enabled: bool,
+  ) -> FlowyResult<()> {
+    if passcode.is_empty() {
+      return Err(FlowyError::new(
+        ErrorCode::InvalidParams,
+        "Passcode should not be empty",
+      ));
+    }
+
+    let salt = SaltString::generate(&mut OsRng);
+    let passcode_hash = Argon2::default()
+      .hash_password(passcode.as_bytes(), &salt)
+      .map_err(|err| FlowyError::internal().with_context(err))?
+      .to_string();
+    let settings = AppLockSettings {
+      passcode_hash,
+      auto_lock_timeout_secs,
+      biometric_enabled,
+    };
+    self
+      .store_preferences
+      .set_object(APP_LOCK_SETTINGS, &settings)?;
+
+    self.record_app_activity();
+    self.restart_auto_lock_timer(auto_lock_timeout_secs);
+    Ok(())
+  }
+
+  /// Disables the app lock. The current passcode is required.
+  #[instrument(level = "info", skip(self, passcode), err)]
+  pub fn remove_app_lock(&self, passcode: &str) -> FlowyResult<()> {
+    let settings = self
+      .app_lock_settings()
+      .ok_or_else(|| FlowyError::new(ErrorCode::RecordNotFound, "App lock is not enabled"))?;
+    verify_passcode(passcode, &settings.passcode_hash)?;
+
+    self.store_preferences.remove(APP_LOCK_SETTINGS);
+    self.app_lock_state.locked.store(false, Ordering::SeqCst);
+    // Stop any running auto lock timer.
+    self
+      .app_lock_state
+      .timer_generation
+      .fetch_add(1, Ordering::SeqCst);
+    Ok(())
+  }
+
+  /// Locks the app immediately. No-op when the app lock is not enabled.
+  pub fn lock_app(&self) {
+    if self.app_lock_settings().is_none() {
+      return;
+    }
+    if !self.app_lock_state.locked.swap(true, Ordering::SeqCst) {
+      info!("App locked");
+      send_notification(APP_LOCK_NOTIFICATION_ID, UserNotification::DidLockApp).send();
+    }
+  }
+
+  /// Unlocks the app with the passcode, or with a successful platform
+  /// biometric check when biometric unlock is enabled. The biometric
+  /// verification itself happens on the platform side; the client only
+  /// reports its result here.
+  #[instrument(level = "info", skip(self, passcode), err)]
+  pub fn unlock_app(&self, passcode: Option<String>, biometric_verified: bool) -> FlowyResult<()> {
+    let settings = self
+      .app_lock_settings()
+      .ok_or_else(|| FlowyError::new(ErrorCode::RecordNotFound, "App lock is not enabled"))?;
+    if !self.app_lock_state.locked.load(Ordering::SeqCst) {
+      return Ok(());
+    }
+
+    if !(biometric_verified && settings.biometric_enabled) {
+      let passcode = passcode.unwrap_or_default();
+      verify_passcode(&passcode, &settings.passcode_hash)?;
+    }
+
+    self.app_lock_state.locked.store(false, Ordering::SeqCst);
+    self.record_app_activity();
+    info!("App unlocked");
+    Ok(())
+  }
+
+  /// Resets the inactivity timer. The client calls this on user interaction.
+  pub fn record_app_activity(&self) {
+    self
+      .app_lock_state
+      .last_activity_ms
+      .store(Utc::now().timestamp_millis(), Ordering::SeqCst);
+  }
+
+  /// Locks the app and starts the auto lock timer when a passcode is set.
+  /// Called once during initialization.
+  pub(crate) fn init_app_lock(&self) {
+    if let Some(settings) = self.app_lock_settings() {
+      self.app_lock_state.locked.store(true, Ordering::SeqCst);
+      self.record_app_activity();
+      self.restart_auto_lock_timer(settings.auto_lock_timeout_secs);
+    }
+  }
+
+  fn restart_auto_lock_timer(&self, auto_lock_timeout_secs: Option<u64>) {
+    let state = self.app_lock_state.clone();
+    let generation = state.timer_generation.fetch_add(1, Ordering::SeqCst) + 1;
+    let timeout_secs = match auto_lock_timeout_secs {
+      Some(secs) if secs > 0 => secs,
+      _ => return,
+    };
+
+    tokio::spawn(async move {
+      loop {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        if state.timer_generation.load(Ordering::SeqCst) != generation {
+          return;
+        }
+        if state.locked.load(Ordering::SeqCst) {
+          continue;
+        }
+
+        let idle_ms = Utc::now().timestamp_millis() - state.last_activity_ms.load(Ordering::SeqCst);
+        if idle_ms >= timeout_secs as i64 * 1000 && !state.locked.swap(true, Ordering::SeqCst) {
+          info!("App locked after {}s of inactivity", timeout_secs);
+          send_notification(APP_LOCK_NOTIFICATION_ID, UserNotification::DidLockApp).send();
+        }
+      }
+    });
+  }
+}
+
+fn verify_passcode(passcode: &str, passcode_hash: &str) -> FlowyResult<()> {
+  let parsed_hash = PasswordHash::new(passcode_hash).map_err(|err| {
+    error!("Parse passcode hash failed: {:?}", err);
+    FlowyError::internal().with_context("Invalid passcode hash")
+  })?;
+  Argon2::default()
+    .verify_password(passcode.as_bytes(), &parsed_hash)
+    .map_err(|_| FlowyError::new(ErrorCode::UserUnauthorized, "Incorrect passcode"))
+}
@@ -0,0 +1,205 @@
+use std::sync::Weak;
+use std::time::Duration;
+
+use chrono::Utc;
+use tracing::{error, info, instrument, trace, warn};
+
+use crate::services::backup::{
+  BACKUP_MANIFEST_NAME, BackupConfig, BackupEntry, BackupManifest, BackupTransport, make_transport,
+  sha256_hex,
+};
+use crate::services::cloud_config::get_or_create_cloud_config;
+use crate::user_manager::UserManager;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::encryption::{decrypt_data, encrypt_data};
+
+/// How often the backup scheduler re-checks whether an automatic backup is
+/// due. The interval itself comes from the configuration.
+const BACKUP_POLL_INTERVAL: Duration = Duration::from_secs(15 * 60);
+/// When the last automatic backup finished, milliseconds since the epoch.
+fn last_backup_at_key(uid: i64) -> String {
+  format!("last_backup_at:{}", uid)
+}
+
+fn backup_config_key(uid: i64) -> String {
+  format!("backup_config:{}", uid)
+}
+
+impl UserManager {
+  /// The backup target configuration, empty defaults when none was set. The
+  /// credentials are stored locally and never leave the device.
+  pub fn get_backup_config(&self) -> FlowyResult<BackupConfig> {
+    let uid = self.user_id()?;
+    Ok(
+      self
+        .store_preferences
+        .get_object::<BackupConfig>(&backup_config_key(uid))
+        .unwrap_or_default(),
+    )
+  }
+
+  pub fn set_backup_config(&self, config: BackupConfig) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    self
+      .store_preferences
+      .set_object(&backup_config_key(uid), &config)?;
+    Ok(())
+  }
+
+  /// Creates an encrypted archive of all local user data, uploads it to the
+  /// configured target, verifies the upload and prunes archives beyond the
+  /// retention count. Returns the manifest entry of the new archive.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn backup_now(&self) -> FlowyResult<BackupEntry> {
+    let uid = self.user_id()?;
+    let config = self.get_backup_config()?;
+    let transport = make_transport(&config)?;
+
+    // The full export already covers the collab kv store and the sqlite
+    // data of every workspace.
+    let staging_dir = std::env::temp_dir().join(format!("appflowy_backup_{}", uid));
+    let archive_path = self
+      .export_user_data(&staging_dir.to_string_lossy())
+      .await?;
+    let plain = tokio::fs::read(&archive_path).await?;
+    let _ = tokio::fs::remove_file(&archive_path).await;
+
+    let secret = get_or_create_cloud_config(uid, &self.store_preferences).encrypt_secret;
+    let encrypted = encrypt_data(&plain, &secret)?;
+    let name = format!(
+      "appflowy_backup_{}_{}.enc",
+      uid,
+      Utc::now().format("%Y%m%d%H%M%S")
+    );
+    let entry = BackupEntry {
+      name: name.clone(),
+      created_at: Utc::now().timestamp_millis(),
+      size: encrypted.len() as u64,
+      sha256: sha256_hex(&encrypted),
+    };
+
+    transport.upload(&name, encrypted).await?;
+    // Verify the upload by reading the archive back and comparing digests,
+    // a corrupted backup is worse than a failed one.
+    let echoed = transport.download(&name).await?;
+    if sha256_hex(&echoed) != entry.sha256 {
+      let _ = transport.delete(&name).await;
+      return Err(FlowyError::new(
+        ErrorCode::NetworkError,
+        "The uploaded backup archive failed the integrity check",
+      ));
+    }
+
+    let mut manifest = self.fetch_manifest(&*transport).await;
+    manifest.archives.push(entry.clone());
+    manifest.archives.sort_by_key(|archive| archive.created_at);
+
+    // Prune the oldest archives beyond the retention count.
+    let retention = config.retention_count.max(1);
+    while manifest.archives.len() > retention {
+      let stale = manifest.archives.remove(0);
+      if let Err(err) = transport.delete(&stale.name).await {
+        warn!("Prune backup archive {} failed: {}", stale.name, err);
+      }
+    }
+
+    let manifest_json = serde_json::to_vec(&manifest)?;
+    transport
+      .upload(BACKUP_MANIFEST_NAME, manifest_json)
+      .await?;
+
+    self
+      .store_preferences
+      .set_i64(&last_backup_at_key(uid), entry.created_at)?;
+    info!("Uploaded backup archive {}", entry.name);
+    Ok(entry)
+  }
+
+  /// The archives available on the configured target, oldest first.
+  pub async fn list_backups(&self) -> FlowyResult<Vec<BackupEntry>> {
+    let config = self.get_backup_config()?;
+    let transport = make_transport(&config)?;
+    Ok(self.fetch_manifest(&*transport).await.archives)
+  }
+
+  /// Downloads the archive, verifies its digest against the manifest,
+  /// decrypts it and writes the plain zip next to the user data. The
+  /// returned path feeds the existing import flow; nothing is overwritten
+  /// automatically.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn restore_from_backup(&self, name: &str) -> FlowyResult<String> {
+    let uid = self.user_id()?;
+    let config = self.get_backup_config()?;
+    let transport = make_transport(&config)?;
+
+    let encrypted = transport.download(name).await?;
+    let manifest = self.fetch_manifest(&*transport).await;
+    if let Some(entry) = manifest.archives.iter().find(|entry| entry.name == name) {
+      if sha256_hex(&encrypted) != entry.sha256 {
+        return Err(FlowyError::new(
+          ErrorCode::NetworkError,
+          "The downloaded backup archive failed the integrity check",
+        ));
+      }
+    } else {
+      warn!("Backup archive {} is not listed in the manifest", name);
+    }
+
+    let secret = get_or_create_cloud_config(uid, &self.store_preferences).encrypt_secret;
+    let plain = decrypt_data(&encrypted, &secret)?;
+
+    let restore_dir = self
+      .authenticate_user
+      .get_user_data_dir()?
+      .join("restored_backups");
+    tokio::fs::create_dir_all(&restore_dir).await?;
+    let restored_path = restore_dir.join(format!("{}.zip", name.trim_end_matches(".enc")));
+    tokio::fs::write(&restored_path, plain).await?;
+    info!("Restored backup archive to {:?}", restored_path);
+    Ok(restored_path.to_string_lossy().to_string())
+  }
+
+  /// Spawns the automatic backup loop: when an interval is configured, a new
+  /// archive is uploaded whenever the last one is older than the interval.
+  pub(crate) fn start_backup_scheduler(weak_manager: Weak<UserManager>) {
+    tokio::spawn(async move {
+      loop {
+        tokio::time::sleep(BACKUP_POLL_INTERVAL).await;
+        let manager = match weak_manager.upgrade() {
+          Some(manager) => manager,
+          None => return,
+        };
+        let uid = match manager.user_id() {
+          Ok(uid) => uid,
+          Err(_) => continue,
+        };
+        let config = match manager.get_backup_config() {
+          Ok(config) => config,
+          Err(_) => continue,
+        };
+        if config.interval_hours <= 0 || config.endpoint.is_empty() {
+          continue;
+        }
+        let last_backup_at = manager
+          .store_preferences
+          .get_i64(&last_backup_at_key(uid))
+          .unwrap_or(0);
+        let due_at = last_backup_at + config.interval_hours * 60 * 60 * 1000;
+        if Utc::now().timestamp_millis() < due_at {
+          continue;
+        }
+        trace!("Automatic backup is due");
+        if let Err(err) = manager.backup_now().await {
+          error!("Automatic backup failed: {}", err);
+        }
+      }
+    });
+  }
+
+  async fn fetch_manifest(&self, transport: &dyn BackupTransport) -> BackupManifest {
+    match transport.download(BACKUP_MANIFEST_NAME).await {
+      Ok(data) => serde_json::from_slice(&data).unwrap_or_default(),
+      Err(_) => BackupManifest::default(),
+    }
+  }
+}
@@ -0,0 +1,127 @@
+use chrono::Utc;
+use collab::preclude::{Collab, StateVector};
+use collab_integrate::CollabKVAction;
+use collab_integrate::sync_status::ObjectSyncStatus;
+use collab_plugins::local_storage::kv::KVTransactionDB;
+use tracing::{info, instrument, trace};
+use uuid::Uuid;
+
+use crate::entities::CollabCompactionResultPB;
+use crate::user_manager::UserManager;
+use crate::user_manager::manager_user_workspace::dir_size;
+use flowy_error::{FlowyError, FlowyResult};
+
+impl UserManager {
+  /// Compacts collab docs by merging their stored update history into a
+  /// single doc state, per object or workspace-wide. Objects that are not
+  /// fully synced or saw activity within the retention window are skipped,
+  /// so compaction never throws away history another device may still need
+  /// to catch up on.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn compact_collabs(
+    &self,
+    workspace_id: &Uuid,
+    object_id: Option<String>,
+    retention_secs: u64,
+  ) -> FlowyResult<CollabCompactionResultPB> {
+    let uid = self.user_id()?;
+    let workspace_id_str = workspace_id.to_string();
+    let collab_db = self
+      .get_collab_db(uid)?
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab db not found"))?;
+    let registry = self
+      .upgrade_collab_builder()?
+      .sync_status_registry()
+      .clone();
+    let collab_db_dir = self.authenticate_user.get_user_data_dir()?.join("collab_db");
+
+    let result = tokio::task::spawn_blocking(move || {
+      let mut result = CollabCompactionResultPB::default();
+      let bytes_before = dir_size(&collab_db_dir);
+      let now = Utc::now().timestamp_millis();
+      let retention_ms = retention_secs as i64 * 1000;
+
+      let object_ids = match object_id {
+        Some(object_id) => vec![object_id],
+        None => {
+          let read_txn = collab_db.read_txn();
+          read_txn
+            .get_all_object_ids(uid, &workspace_id_str)
+            .map(|iter| iter.collect::<Vec<String>>())
+            .unwrap_or_default()
+        },
+      };
+
+      // First pass: read and merge the history of every eligible object.
+      let mut compacted = Vec::new();
+      {
+        let read_txn = collab_db.read_txn();
+        for object_id in object_ids {
+          // Coordinate with the cloud: objects with unsynced changes or an
+          // active sync keep their history.
+          if registry.get_status(&object_id) != ObjectSyncStatus::Synced {
+            result.skipped_objects += 1;
+            continue;
+          }
+          if let Some(last_activity) = registry.last_activity(&object_id) {
+            if now - last_activity < retention_ms {
+              trace!("skip compaction of recently active {}", object_id);
+              result.skipped_objects += 1;
+              continue;
+            }
+          }
+
+          let mut collab = Collab::new(uid, &object_id, "phantom", vec![], false);
+          let mut txn = collab.transact_mut();
+          let update_count =
+            match read_txn.load_doc_with_txn(uid, &workspace_id_str, &object_id, &mut txn) {
+              Ok(update_count) => update_count,
+              Err(_) => {
+                result.skipped_objects += 1;
+                continue;
+              },
+            };
+          drop(txn);
+          if update_count <= 1 {
+            // Already a single doc state, nothing to merge.
+            continue;
+          }
+
+          let txn = collab.transact();
+          let state_vector = txn.state_vector();
+          let doc_state = txn.encode_state_as_update_v1(&StateVector::default());
+          drop(txn);
+          result.pruned_updates += update_count as u64 - 1;
+          compacted.push((object_id, state_vector.encode_v1(), doc_state));
+        }
+      }
+
+      // Second pass: replace the update history with the merged doc states.
+      if !compacted.is_empty() {
+        let write_txn = collab_db.write_txn();
+        for (object_id, state_vector, doc_state) in compacted {
+          write_txn
+            .flush_doc(uid, &workspace_id_str, &object_id, state_vector, doc_state)
+            .map_err(|err| {
+              FlowyError::internal().with_context(format!("Flush doc failed: {}", err))
+            })?;
+          result.compacted_objects += 1;
+        }
+        write_txn.commit_transaction().map_err(|err| {
+          FlowyError::internal().with_context(format!("Commit compaction failed: {}", err))
+        })?;
+      }
+
+      result.reclaimed_bytes = bytes_before.saturating_sub(dir_size(&collab_db_dir));
+      Ok::<_, FlowyError>(result)
+    })
+    .await??;
+
+    info!(
+      "Compacted {} collab objects, pruned {} updates, skipped {}",
+      result.compacted_objects, result.pruned_updates, result.skipped_objects
+    );
+    Ok(result)
+  }
+}
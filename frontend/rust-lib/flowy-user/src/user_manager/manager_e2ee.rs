@@ -0,0 +1,242 @@
+use std::borrow::{Borrow, BorrowMut};
+
+use chrono::Utc;
+use collab::preclude::{Any, Collab, Map, Out, ReadTxn};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument, trace, warn};
+use uuid::Uuid;
+
+use crate::services::cloud_config::{get_or_create_cloud_config, save_cloud_config};
+use crate::user_manager::UserManager;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::encryption::{decrypt_text, encrypt_text, generate_encryption_secret};
+
+/// Root map in the user awareness collab holding the wrapped workspace keys,
+/// keyed by workspace id. The keys are wrapped (encrypted) with the user
+/// secret, so sharing them through the cloud is safe: a device can only
+/// unwrap them after the user imported the recovery phrase.
+const WRAPPED_KEYS_MAP: &str = "wrapped_workspace_keys";
+
+/// The encryption keys of one workspace, each wrapped with the user secret.
+/// Retired keys are kept so data encrypted before a rotation stays readable.
+#[derive(Serialize, Deserialize, Clone)]
+struct WrappedKeyRing {
+  active: String,
+  retired: Vec<String>,
+  rotated_at: i64,
+}
+
+impl UserManager {
+  /// Enables end-to-end encryption for the workspace: generates a workspace
+  /// key, wraps it with the user secret and shares the wrapped key to the
+  /// user's other devices through the awareness collab. The key is handed to
+  /// the cloud services, which encrypt every collab update before it leaves
+  /// the device.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn enable_workspace_encryption(&self, workspace_id: &Uuid) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let workspace_id_str = workspace_id.to_string();
+    if self.load_key_ring(uid, &workspace_id_str).is_some() {
+      trace!("Encryption is already enabled for {}", workspace_id);
+      return Ok(());
+    }
+
+    let secret = self.user_encrypt_secret(uid);
+    let workspace_key = generate_encryption_secret();
+    let ring = WrappedKeyRing {
+      active: encrypt_text(&workspace_key, &secret)?,
+      retired: Vec::new(),
+      rotated_at: Utc::now().timestamp_millis(),
+    };
+    self.store_key_ring(uid, &workspace_id_str, &ring)?;
+    self.publish_key_ring(&workspace_id_str, &ring).await;
+
+    let mut config = get_or_create_cloud_config(uid, &self.store_preferences);
+    config.enable_encrypt = true;
+    save_cloud_config(uid, &self.store_preferences, &config)?;
+
+    self.cloud_service()?.set_encrypt_secret(workspace_key);
+    info!("Enabled end-to-end encryption for {}", workspace_id);
+    Ok(())
+  }
+
+  /// Rotates the encryption key of the workspace. The previous key is
+  /// retired but kept, so data encrypted with it stays readable. New updates
+  /// are encrypted with the new key.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn rotate_workspace_encryption_key(&self, workspace_id: &Uuid) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let workspace_id_str = workspace_id.to_string();
+    let mut ring = self.load_key_ring(uid, &workspace_id_str).ok_or_else(|| {
+      FlowyError::new(
+        ErrorCode::Internal,
+        "Encryption is not enabled for this workspace",
+      )
+    })?;
+
+    let secret = self.user_encrypt_secret(uid);
+    let workspace_key = generate_encryption_secret();
+    ring.retired.insert(0, ring.active.clone());
+    ring.active = encrypt_text(&workspace_key, &secret)?;
+    ring.rotated_at = Utc::now().timestamp_millis();
+    self.store_key_ring(uid, &workspace_id_str, &ring)?;
+    self.publish_key_ring(&workspace_id_str, &ring).await;
+
+    self.cloud_service()?.set_encrypt_secret(workspace_key);
+    info!("Rotated encryption key of {}", workspace_id);
+    Ok(())
+  }
+
+  /// The recovery phrase of the user secret that wraps the workspace keys.
+  /// Without it the encrypted data cannot be recovered on a new device, the
+  /// UI must prompt the user to store it safely.
+  pub fn export_recovery_phrase(&self) -> FlowyResult<String> {
+    let uid = self.user_id()?;
+    let secret = self.user_encrypt_secret(uid);
+    // Group the secret into chunks of four characters, which is easier to
+    // write down and read back than one long string.
+    let phrase = secret
+      .chars()
+      .collect::<Vec<_>>()
+      .chunks(4)
+      .map(|chunk| chunk.iter().collect::<String>())
+      .collect::<Vec<_>>()
+      .join(" ");
+    Ok(phrase)
+  }
+
+  /// Restores the user secret from a recovery phrase on a new device and
+  /// unwraps the workspace keys shared through the awareness collab.
+  #[instrument(level = "info", skip_all, err)]
+  pub async fn import_recovery_phrase(&self, phrase: &str) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let secret: String = phrase.split_whitespace().collect();
+    if secret.is_empty() || !secret.contains('$') {
+      return Err(FlowyError::new(
+        ErrorCode::InvalidEncryptSecret,
+        "The recovery phrase is not valid",
+      ));
+    }
+
+    let mut config = get_or_create_cloud_config(uid, &self.store_preferences);
+    config.encrypt_secret = secret.clone();
+    config.enable_encrypt = true;
+    save_cloud_config(uid, &self.store_preferences, &config)?;
+
+    self.pull_wrapped_key_rings(uid).await?;
+
+    // Apply the key of the current workspace, if one was shared.
+    let workspace_id = self.workspace_id()?;
+    match self.active_workspace_encryption_key(uid, &workspace_id.to_string()) {
+      Some(workspace_key) => {
+        self.cloud_service()?.set_encrypt_secret(workspace_key);
+        Ok(())
+      },
+      None => Err(FlowyError::new(
+        ErrorCode::InvalidEncryptSecret,
+        "No workspace key could be unwrapped with this recovery phrase",
+      )),
+    }
+  }
+
+  /// The unwrapped active encryption key of the workspace, or `None` when
+  /// encryption is not enabled or the local user secret cannot unwrap it.
+  pub(crate) fn active_workspace_encryption_key(
+    &self,
+    uid: i64,
+    workspace_id: &str,
+  ) -> Option<String> {
+    let ring = self.load_key_ring(uid, workspace_id)?;
+    let secret = self.user_encrypt_secret(uid);
+    match decrypt_text(&ring.active, &secret) {
+      Ok(workspace_key) => Some(workspace_key),
+      Err(err) => {
+        warn!("Unwrap workspace key of {} failed: {}", workspace_id, err);
+        None
+      },
+    }
+  }
+
+  /// The per-user secret wrapping the workspace keys, generated on first
+  /// use. It never leaves the device except as recovery phrase.
+  fn user_encrypt_secret(&self, uid: i64) -> String {
+    get_or_create_cloud_config(uid, &self.store_preferences).encrypt_secret
+  }
+
+  fn load_key_ring(&self, uid: i64, workspace_id: &str) -> Option<WrappedKeyRing> {
+    self
+      .store_preferences
+      .get_object::<WrappedKeyRing>(&key_ring_key(uid, workspace_id))
+  }
+
+  fn store_key_ring(&self, uid: i64, workspace_id: &str, ring: &WrappedKeyRing) -> FlowyResult<()> {
+    self
+      .store_preferences
+      .set_object(&key_ring_key(uid, workspace_id), ring)?;
+    Ok(())
+  }
+
+  /// Shares the wrapped key ring to the user's other devices through the
+  /// awareness collab. Best effort, enabling encryption must not fail when
+  /// the awareness collab is not loaded yet.
+  async fn publish_key_ring(&self, workspace_id: &str, ring: &WrappedKeyRing) {
+    let entry = match serde_json::to_string(ring) {
+      Ok(entry) => entry,
+      Err(err) => {
+        warn!("Serialize key ring of {} failed: {}", workspace_id, err);
+        return;
+      },
+    };
+    let awareness = match self.workspace_id().ok() {
+      Some(current_workspace) => match self.get_awareness(&current_workspace).await {
+        Ok(awareness) => awareness,
+        Err(err) => {
+          warn!("Skip publishing key ring of {}: {}", workspace_id, err);
+          return;
+        },
+      },
+      None => return,
+    };
+    let mut guard = awareness.write().await;
+    let collab: &mut Collab = (*guard).borrow_mut();
+    let map = collab.doc().get_or_insert_map(WRAPPED_KEYS_MAP);
+    let mut txn = collab.transact_mut();
+    map.insert(&mut txn, workspace_id, entry);
+  }
+
+  /// Merges the wrapped key rings shared through the awareness collab into
+  /// the local store, keeping the newer ring per workspace.
+  async fn pull_wrapped_key_rings(&self, uid: i64) -> FlowyResult<()> {
+    let workspace_id = self.workspace_id()?;
+    let awareness = self.get_awareness(&workspace_id).await?;
+    let guard = awareness.read().await;
+    let collab: &Collab = (*guard).borrow();
+    let txn = collab.transact();
+    let map = match txn.get_map(WRAPPED_KEYS_MAP) {
+      Some(map) => map,
+      None => return Ok(()),
+    };
+    for (shared_workspace_id, value) in map.iter(&txn) {
+      let remote = match value {
+        Out::Any(Any::String(entry)) => {
+          serde_json::from_str::<WrappedKeyRing>(entry.as_ref()).ok()
+        },
+        _ => None,
+      };
+      if let Some(remote) = remote {
+        let keep_remote = self
+          .load_key_ring(uid, shared_workspace_id)
+          .map(|local| remote.rotated_at > local.rotated_at)
+          .unwrap_or(true);
+        if keep_remote {
+          self.store_key_ring(uid, shared_workspace_id, &remote)?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+fn key_ring_key(uid: i64, workspace_id: &str) -> String {
+  format!("e2ee_key_ring:{}:{}", uid, workspace_id)
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::Utc;
+use collab::preclude::{Collab, ReadTxn, StateVector, Transact};
+use collab_document::document::DocumentBody;
+use collab_folder::ViewLayout;
+use collab_integrate::{CollabKVAction, CollabKVDB};
+use collab_plugins::local_storage::kv::KVTransactionDB;
+use serde_json::json;
+use tracing::{info, instrument, warn};
+
+use crate::event_handler::{APPEARANCE_SETTING_CACHE_KEY, DATE_TIME_SETTINGS_CACHE_KEY};
+use crate::services::data_import::load_collab_by_object_id;
+use crate::user_manager::manager_migration::read_folder_data;
+use crate::user_manager::UserManager;
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_user_pub::entities::UserWorkspace;
+use flowy_user_pub::sql::select_all_user_workspace;
+use lib_infra::file_util::zip_folder;
+
+impl UserManager {
+  /// Exports all of the current user's data into a zip archive in `dest_path`:
+  /// the raw collab data of every workspace, markdown renditions of documents,
+  /// a CSV rendition of database row cells, the locally stored settings and a
+  /// manifest. Everything is read from disk, so no cloud service is required.
+  ///
+  /// Returns the path of the generated archive.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn export_user_data(&self, dest_path: &str) -> FlowyResult<String> {
+    let session = self.get_session()?;
+    let uid = session.user_id;
+    let profile = self
+      .get_user_profile_from_disk(uid, &session.workspace_id)
+      .await?;
+    let mut conn = self.db_connection(uid)?;
+    let workspaces = select_all_user_workspace(uid, &mut conn)?;
+    let collab_db = self
+      .get_collab_db(uid)?
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab db not found"))?;
+
+    let dest_dir = PathBuf::from(dest_path);
+    fs::create_dir_all(&dest_dir)?;
+    let timestamp = Utc::now().format("%Y%m%d%H%M%S");
+    let staging = std::env::temp_dir().join(format!("appflowy_export_{}_{}", uid, timestamp));
+    let archive_path = dest_dir.join(format!("appflowy_export_{}_{}.zip", uid, timestamp));
+
+    // The locally stored settings, exported as-is.
+    let mut settings = serde_json::Map::new();
+    for key in [APPEARANCE_SETTING_CACHE_KEY, DATE_TIME_SETTINGS_CACHE_KEY] {
+      if let Some(value) = self.store_preferences.get_object::<serde_json::Value>(key) {
+        settings.insert(key.to_string(), value);
+      }
+    }
+
+    let app_version = self.authenticate_user.user_config.app_version.to_string();
+    let user_name = profile.name.clone();
+    let cloned_staging = staging.clone();
+    let cloned_archive_path = archive_path.clone();
+    let workspace_manifests = tokio::task::spawn_blocking(move || {
+      fs::create_dir_all(&cloned_staging)?;
+      let mut workspace_manifests = Vec::new();
+      for workspace in &workspaces {
+        workspace_manifests.push(export_workspace(
+          uid,
+          workspace,
+          &collab_db,
+          &cloned_staging,
+        )?);
+      }
+
+      let manifest = json!({
+        "exported_at": Utc::now().to_rfc3339(),
+        "app_version": app_version,
+        "uid": uid,
+        "user_name": user_name,
+        "workspaces": workspace_manifests,
+      });
+      fs::write(
+        cloned_staging.join("manifest.json"),
+        serde_json::to_vec_pretty(&manifest)?,
+      )?;
+      fs::write(
+        cloned_staging.join("settings.json"),
+        serde_json::to_vec_pretty(&serde_json::Value::Object(settings))?,
+      )?;
+
+      zip_folder(&cloned_staging, &cloned_archive_path)?;
+      let _ = fs::remove_dir_all(&cloned_staging);
+      Ok::<_, FlowyError>(manifest["workspaces"].clone())
+    })
+    .await
+    .map_err(|err| FlowyError::internal().with_context(err))??;
+
+    info!(
+      "Exported {} workspaces to {:?}",
+      workspace_manifests.as_array().map(Vec::len).unwrap_or(0),
+      archive_path
+    );
+    // A completed export also counts as the export offer required before
+    // account deletion.
+    let _ = self
+      .store_preferences
+      .set_bool(&data_export_offered_key(uid), true);
+    Ok(archive_path.to_string_lossy().to_string())
+  }
+}
+
+/// Writes one workspace into `staging/<workspace_id>/`: every collab object as
+/// a raw update file, documents as markdown named after their view, and the
+/// database row cells as a flat CSV. Returns the manifest entry for the
+/// workspace.
+fn export_workspace(
+  uid: i64,
+  workspace: &UserWorkspace,
+  collab_db: &Arc<CollabKVDB>,
+  staging: &Path,
+) -> FlowyResult<serde_json::Value> {
+  let workspace_dir = staging.join(&workspace.id);
+  let collabs_dir = workspace_dir.join("collabs");
+  let markdown_dir = workspace_dir.join("markdown");
+  fs::create_dir_all(&collabs_dir)?;
+  fs::create_dir_all(&markdown_dir)?;
+
+  // View names give the markdown files human-readable names. Exporting the
+  // raw data still works when the folder can't be read.
+  let view_names: HashMap<String, String> = match read_folder_data(uid, &workspace.id, collab_db) {
+    Ok(folder_data) => folder_data
+      .views
+      .iter()
+      .filter(|view| view.layout == ViewLayout::Document)
+      .map(|view| (view.id.clone(), view.name.clone()))
+      .collect(),
+    Err(err) => {
+      warn!("Read folder data of {} failed: {:?}", workspace.id, err);
+      HashMap::new()
+    },
+  };
+
+  let read_txn = collab_db.read_txn();
+  let object_ids = read_txn
+    .get_all_object_ids(uid, &workspace.id)
+    .map(|iter| iter.collect::<Vec<String>>())
+    .unwrap_or_default();
+
+  let mut document_count = 0;
+  let mut row_count = 0;
+  let mut csv_rows = String::from("row_id,field_id,data\n");
+  for object_id in &object_ids {
+    let collab = match load_collab_by_object_id(uid, &read_txn, &workspace.id, object_id) {
+      Ok(collab) => collab,
+      Err(err) => {
+        warn!("Load collab {} failed: {:?}", object_id, err);
+        continue;
+      },
+    };
+
+    let doc_state = collab
+      .transact()
+      .encode_state_as_update_v1(&StateVector::default());
+    fs::write(collabs_dir.join(format!("{}.collab", object_id)), doc_state)?;
+
+    if let Some(body) = DocumentBody::from_collab(&collab) {
+      if let Ok(txn) = collab.doc().try_transact() {
+        let paragraphs = body.paragraphs(txn);
+        if !paragraphs.is_empty() {
+          let name = view_names
+            .get(object_id)
+            .cloned()
+            .unwrap_or_else(|| object_id.clone());
+          fs::write(
+            markdown_dir.join(format!("{}.md", sanitize_file_name(&name))),
+            format!("# {}\n\n{}\n", name, paragraphs.join("\n\n")),
+          )?;
+          document_count += 1;
+        }
+      }
+    } else {
+      let cells = database_row_cells(&collab.to_json_value());
+      if !cells.is_empty() {
+        for (field_id, data) in cells {
+          csv_rows.push_str(&format!(
+            "{},{},{}\n",
+            escape_csv(object_id),
+            escape_csv(&field_id),
+            escape_csv(&data)
+          ));
+        }
+        row_count += 1;
+      }
+    }
+  }
+  if row_count > 0 {
+    fs::write(workspace_dir.join("database_rows.csv"), csv_rows)?;
+  }
+
+  Ok(json!({
+    "id": workspace.id,
+    "name": workspace.name,
+    "collab_count": object_ids.len(),
+    "document_count": document_count,
+    "database_row_count": row_count,
+  }))
+}
+
+/// Collects the `(field_id, data)` pairs of a database row collab's string
+/// cells.
+pub(crate) fn database_row_cells(value: &serde_json::Value) -> Vec<(String, String)> {
+  let mut cells_out = Vec::new();
+  collect_row_cells(value, &mut cells_out);
+  cells_out
+}
+
+fn collect_row_cells(value: &serde_json::Value, out: &mut Vec<(String, String)>) {
+  match value {
+    serde_json::Value::Object(map) => {
+      if let Some(serde_json::Value::Object(cells)) = map.get("cells") {
+        for (field_id, cell) in cells {
+          if let Some(serde_json::Value::String(data)) = cell.get("data") {
+            if !data.trim().is_empty() {
+              out.push((field_id.clone(), data.clone()));
+            }
+          }
+        }
+      }
+      for nested in map.values() {
+        collect_row_cells(nested, out);
+      }
+    },
+    serde_json::Value::Array(items) => {
+      for item in items {
+        collect_row_cells(item, out);
+      }
+    },
+    _ => {},
+  }
+}
+
+/// The preferences key marking that a data export was offered to (or made by)
+/// the user, required before their account can be deleted.
+pub(crate) fn data_export_offered_key(uid: i64) -> String {
+  format!("data_export_offered:{}", uid)
+}
+
+fn sanitize_file_name(name: &str) -> String {
+  name
+    .chars()
+    .map(|c| if matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') {
+      '_'
+    } else {
+      c
+    })
+    .collect()
+}
+
+fn escape_csv(value: &str) -> String {
+  if value.contains([',', '"', '\n']) {
+    format!("\"{}\"", value.replace('"', "\"\""))
+  } else {
+    value.to_string()
+  }
+}
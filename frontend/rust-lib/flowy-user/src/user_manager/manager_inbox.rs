@@ -0,0 +1,93 @@
+use flowy_error::FlowyResult;
+use flowy_user_pub::cloud::MentionNotificationParams;
+use tracing::instrument;
+
+use crate::entities::{InboxNotificationPB, InboxUnreadCountPB, RepeatedInboxNotificationPB};
+use crate::services::notification_inbox::{
+  NotificationInboxTable, archive_inbox_record, count_unread_inbox_records, insert_inbox_record,
+  mark_inbox_records_read, notify_inbox_changed, select_inbox_records,
+};
+use crate::user_manager::UserManager;
+
+/// Upper bound for one inbox page, applied when the caller passes 0 or an
+/// oversized limit.
+const MAX_INBOX_PAGE_SIZE: i64 = 100;
+
+impl UserManager {
+  /// Inserts a record into the notification inbox. The record id doubles as
+  /// the dedup key, so producers can call this every time they observe the
+  /// same event; only the first call creates a record and bumps the unread
+  /// count.
+  #[instrument(level = "debug", skip(self, record), fields(id = %record.id), err)]
+  pub async fn add_inbox_notification(&self, record: NotificationInboxTable) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    if insert_inbox_record(&mut conn, record)? {
+      notify_inbox_changed(&mut conn)?;
+    }
+    Ok(())
+  }
+
+  /// One page of the inbox, newest first.
+  pub async fn list_inbox_notifications(
+    &self,
+    offset: u64,
+    limit: u64,
+    include_archived: bool,
+  ) -> FlowyResult<RepeatedInboxNotificationPB> {
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    let limit = match limit as i64 {
+      0 => MAX_INBOX_PAGE_SIZE,
+      n => n.min(MAX_INBOX_PAGE_SIZE),
+    };
+    let items = select_inbox_records(&mut conn, offset as i64, limit, include_archived)?
+      .into_iter()
+      .map(InboxNotificationPB::from)
+      .collect();
+    Ok(RepeatedInboxNotificationPB { items })
+  }
+
+  /// Marks the given records as read, or the whole inbox when `ids` is
+  /// empty.
+  pub async fn mark_inbox_notifications_read(&self, ids: &[String]) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    if mark_inbox_records_read(&mut conn, ids)? > 0 {
+      notify_inbox_changed(&mut conn)?;
+    }
+    Ok(())
+  }
+
+  pub async fn archive_inbox_notification(&self, id: &str) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    if archive_inbox_record(&mut conn, id)? > 0 {
+      notify_inbox_changed(&mut conn)?;
+    }
+    Ok(())
+  }
+
+  pub async fn inbox_unread_count(&self) -> FlowyResult<InboxUnreadCountPB> {
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    let count = count_unread_inbox_records(&mut conn)?;
+    Ok(InboxUnreadCountPB {
+      count: count as u64,
+    })
+  }
+
+  /// Delivers a mention to another workspace member through the cloud so
+  /// their devices can show it. Mentions of the current user go through
+  /// [Self::add_inbox_notification] instead.
+  pub async fn send_mention_notification(
+    &self,
+    params: MentionNotificationParams,
+  ) -> FlowyResult<()> {
+    self
+      .cloud_service()?
+      .get_user_service()?
+      .send_mention_notification(params)
+      .await
+  }
+}
@@ -0,0 +1,284 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Weak;
+use std::time::Duration;
+
+use chrono::{Datelike, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, instrument, trace, warn};
+
+use crate::entities::LocalBackupInfoPB;
+use crate::user_manager::UserManager;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use lib_infra::file_util::{unzip_and_replace, validate_zip, zip_folder};
+
+/// How often the local backup scheduler re-checks whether a backup is due.
+const LOCAL_BACKUP_POLL_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const LOCAL_BACKUP_PREFIX: &str = "appflowy_local_backup_";
+const LOCAL_BACKUP_TIME_FORMAT: &str = "%Y%m%d%H%M%S";
+
+fn local_backup_config_key(uid: i64) -> String {
+  format!("local_backup_config:{}", uid)
+}
+
+/// When the last automatic local backup finished, milliseconds since the epoch.
+fn last_local_backup_at_key(uid: i64) -> String {
+  format!("last_local_backup_at:{}", uid)
+}
+
+/// Configuration of the automatic local backups. Unlike the cloud backup
+/// target, the archives are plain zips in a folder the user picked, e.g. an
+/// external drive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocalBackupConfig {
+  /// The folder the archives are written to. Must live outside the data
+  /// directory, otherwise every backup would back up its predecessors.
+  pub folder: String,
+  /// Hours between two automatic backups, zero disables them.
+  pub interval_hours: i64,
+  /// How many days to keep the newest archive of.
+  pub daily_count: usize,
+  /// How many weeks to keep the newest archive of, on top of the dailies.
+  pub weekly_count: usize,
+}
+
+impl UserManager {
+  pub fn get_local_backup_config(&self) -> FlowyResult<LocalBackupConfig> {
+    let uid = self.user_id()?;
+    Ok(
+      self
+        .store_preferences
+        .get_object::<LocalBackupConfig>(&local_backup_config_key(uid))
+        .unwrap_or_default(),
+    )
+  }
+
+  pub fn set_local_backup_config(&self, config: LocalBackupConfig) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let root = self.authenticate_user.user_paths.root();
+    if !config.folder.is_empty() && Path::new(&config.folder).starts_with(root) {
+      return Err(FlowyError::new(
+        ErrorCode::InvalidParams,
+        "The backup folder must live outside the data directory",
+      ));
+    }
+    self
+      .store_preferences
+      .set_object(&local_backup_config_key(uid), &config)?;
+    Ok(())
+  }
+
+  /// Zips the whole data directory - collab kv store, sqlite databases and
+  /// preferences - into a timestamped archive in the configured folder, then
+  /// rotates old archives by the daily/weekly retention.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn local_backup_now(&self) -> FlowyResult<LocalBackupInfoPB> {
+    let uid = self.user_id()?;
+    let config = self.get_local_backup_config()?;
+    if config.folder.is_empty() {
+      return Err(FlowyError::new(
+        ErrorCode::InvalidParams,
+        "No local backup folder is configured",
+      ));
+    }
+
+    // Flush the collab db so the archive holds the latest updates.
+    if let Ok(collab_db) = self.get_collab_db(uid) {
+      if let Some(collab_db) = collab_db.upgrade() {
+        let _ = collab_db.flush();
+      }
+    }
+
+    let root = PathBuf::from(self.authenticate_user.user_paths.root());
+    let folder = PathBuf::from(&config.folder);
+    let info = tokio::task::spawn_blocking(move || {
+      std::fs::create_dir_all(&folder)?;
+      let created_at = Utc::now();
+      let archive_path = folder.join(format!(
+        "{}{}.zip",
+        LOCAL_BACKUP_PREFIX,
+        created_at.format(LOCAL_BACKUP_TIME_FORMAT)
+      ));
+      zip_folder(&root, &archive_path)?;
+      let size = std::fs::metadata(&archive_path).map(|m| m.len()).unwrap_or(0);
+
+      rotate_local_backups(&folder, config.daily_count.max(1), config.weekly_count)?;
+
+      Ok::<_, FlowyError>(LocalBackupInfoPB {
+        path: archive_path.to_string_lossy().to_string(),
+        created_at: created_at.timestamp_millis(),
+        size,
+      })
+    })
+    .await??;
+
+    self
+      .store_preferences
+      .set_i64(&last_local_backup_at_key(uid), info.created_at)?;
+    info!("Created local backup archive {}", info.path);
+    Ok(info)
+  }
+
+  /// The archives in the configured folder, newest first.
+  pub async fn list_local_backups(&self) -> FlowyResult<Vec<LocalBackupInfoPB>> {
+    let config = self.get_local_backup_config()?;
+    if config.folder.is_empty() {
+      return Ok(vec![]);
+    }
+    let folder = PathBuf::from(&config.folder);
+    let mut archives = tokio::task::spawn_blocking(move || list_archives(&folder)).await?;
+    archives.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(archives)
+  }
+
+  /// Validates the archive and swaps the data directory for its contents.
+  /// The databases are closed first and the previous data is kept as a
+  /// sibling archive, so a bad restore can be undone. The app has to be
+  /// restarted afterwards.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn restore_local_backup(&self, archive_path: &str) -> FlowyResult<()> {
+    let root = PathBuf::from(self.authenticate_user.user_paths.root());
+    let archive_path = PathBuf::from(archive_path);
+
+    // Close every open database before touching the files underneath them.
+    self.close_db();
+
+    tokio::task::spawn_blocking(move || {
+      let entry_names = validate_zip(&archive_path)
+        .map_err(|err| FlowyError::new(ErrorCode::InvalidParams, err.to_string()))?;
+      // The preferences database sits at the top of every data directory
+      // archive; its absence means this zip is something else entirely.
+      if !entry_names.iter().any(|name| name.starts_with("cache.db")) {
+        return Err(FlowyError::new(
+          ErrorCode::InvalidParams,
+          "The archive is not an AppFlowy data backup",
+        ));
+      }
+
+      // Keep the current data next to the archive before replacing it.
+      let safety_path = archive_path.with_file_name(format!(
+        "appflowy_pre_restore_{}.zip",
+        Utc::now().format(LOCAL_BACKUP_TIME_FORMAT)
+      ));
+      zip_folder(&root, &safety_path)?;
+      info!("Saved the current data to {:?} before restoring", safety_path);
+
+      unzip_and_replace(&archive_path, &root)?;
+      Ok::<_, FlowyError>(())
+    })
+    .await??;
+
+    info!("Restored local backup, a restart is required");
+    Ok(())
+  }
+
+  /// Spawns the automatic local backup loop: when an interval is configured,
+  /// a new archive is written whenever the last one is older than the
+  /// interval.
+  pub(crate) fn start_local_backup_scheduler(weak_manager: Weak<UserManager>) {
+    tokio::spawn(async move {
+      loop {
+        tokio::time::sleep(LOCAL_BACKUP_POLL_INTERVAL).await;
+        let manager = match weak_manager.upgrade() {
+          Some(manager) => manager,
+          None => return,
+        };
+        let uid = match manager.user_id() {
+          Ok(uid) => uid,
+          Err(_) => continue,
+        };
+        let config = match manager.get_local_backup_config() {
+          Ok(config) => config,
+          Err(_) => continue,
+        };
+        if config.interval_hours <= 0 || config.folder.is_empty() {
+          continue;
+        }
+        let last_backup_at = manager
+          .store_preferences
+          .get_i64(&last_local_backup_at_key(uid))
+          .unwrap_or(0);
+        let due_at = last_backup_at + config.interval_hours * 60 * 60 * 1000;
+        if Utc::now().timestamp_millis() < due_at {
+          continue;
+        }
+        trace!("Automatic local backup is due");
+        if let Err(err) = manager.local_backup_now().await {
+          error!("Automatic local backup failed: {}", err);
+        }
+      }
+    });
+  }
+}
+
+fn list_archives(folder: &Path) -> Vec<LocalBackupInfoPB> {
+  let mut archives = Vec::new();
+  if let Ok(entries) = std::fs::read_dir(folder) {
+    for entry in entries.flatten() {
+      if let Some((path, timestamp)) = parse_archive_entry(&entry.path()) {
+        let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+        archives.push(LocalBackupInfoPB {
+          path: path.to_string_lossy().to_string(),
+          created_at: timestamp.and_utc().timestamp_millis(),
+          size,
+        });
+      }
+    }
+  }
+  archives
+}
+
+fn parse_archive_entry(path: &Path) -> Option<(PathBuf, NaiveDateTime)> {
+  let name = path.file_name()?.to_str()?;
+  let timestamp = name
+    .strip_prefix(LOCAL_BACKUP_PREFIX)?
+    .strip_suffix(".zip")?;
+  let timestamp = NaiveDateTime::parse_from_str(timestamp, LOCAL_BACKUP_TIME_FORMAT).ok()?;
+  Some((path.to_path_buf(), timestamp))
+}
+
+/// Keeps the newest archive of each of the most recent `daily_count` days and
+/// of each of the most recent `weekly_count` ISO weeks, deletes the rest.
+fn rotate_local_backups(
+  folder: &Path,
+  daily_count: usize,
+  weekly_count: usize,
+) -> FlowyResult<()> {
+  let mut archives = Vec::new();
+  if let Ok(entries) = std::fs::read_dir(folder) {
+    for entry in entries.flatten() {
+      if let Some(archive) = parse_archive_entry(&entry.path()) {
+        archives.push(archive);
+      }
+    }
+  }
+  // Newest first, so the first archive of a day or week is its latest one.
+  archives.sort_by(|a, b| b.1.cmp(&a.1));
+
+  let mut keep = HashSet::new();
+  let mut days = Vec::new();
+  let mut weeks = Vec::new();
+  for (path, timestamp) in &archives {
+    let day = timestamp.format("%Y%m%d").to_string();
+    if !days.contains(&day) && days.len() < daily_count {
+      days.push(day);
+      keep.insert(path.clone());
+    }
+    let iso_week = timestamp.iso_week();
+    let week = format!("{}{:02}", iso_week.year(), iso_week.week());
+    if !weeks.contains(&week) && weeks.len() < weekly_count {
+      weeks.push(week);
+      keep.insert(path.clone());
+    }
+  }
+
+  for (path, _) in archives {
+    if !keep.contains(&path) {
+      trace!("Rotate out local backup archive {:?}", path);
+      if let Err(err) = std::fs::remove_file(&path) {
+        warn!("Remove stale backup archive {:?} failed: {}", path, err);
+      }
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,214 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use collab::preclude::Collab;
+use collab_folder::{Folder, FolderData, UserId};
+use collab_integrate::{CollabKVAction, CollabKVDB};
+use collab_plugins::local_storage::kv::KVTransactionDB;
+use tracing::{info, instrument};
+
+use crate::entities::{
+  DataMigrationDirectionPB, DataMigrationItemPB, DataMigrationPlanPB, MigrationProgressPB,
+};
+use crate::notification::{send_notification, UserNotification};
+use crate::services::data_import::{ImportedFolder, ImportedSource};
+use crate::user_manager::manager_history_user::ANON_USER;
+use crate::user_manager::UserManager;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use flowy_user_pub::session::Session;
+use flowy_user_pub::sql::select_user_workspace;
+
+/// The notification id for [UserNotification::DidUpdateMigrationProgress].
+const MIGRATION_NOTIFICATION_ID: &str = "data_migration";
+
+impl UserManager {
+  /// Copies all collabs (folder, documents, databases) between the anonymous
+  /// local user and a signed-in cloud account, in the given direction. The
+  /// copied views end up under a new container view whose name is suffixed
+  /// when the target workspace already has a space with the same name.
+  ///
+  /// With `dry_run` the returned plan lists what would move without touching
+  /// any data.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn migrate_anon_data(
+    &self,
+    direction: DataMigrationDirectionPB,
+    dry_run: bool,
+  ) -> FlowyResult<DataMigrationPlanPB> {
+    let current_session = self.get_session()?;
+    let current_profile = self
+      .get_user_profile_from_disk(current_session.user_id, &current_session.workspace_id)
+      .await?;
+
+    let source_session = match direction {
+      DataMigrationDirectionPB::LocalToCloud => {
+        if current_profile.auth_type.is_local() {
+          return Err(FlowyError::new(
+            ErrorCode::InvalidParams,
+            "Sign in to a cloud account before migrating local data",
+          ));
+        }
+        self
+          .store_preferences
+          .get_object::<Session>(ANON_USER)
+          .ok_or(FlowyError::new(
+            ErrorCode::RecordNotFound,
+            "Anon user not found",
+          ))?
+      },
+      DataMigrationDirectionPB::CloudToLocal => {
+        if !current_profile.auth_type.is_local() {
+          return Err(FlowyError::new(
+            ErrorCode::InvalidParams,
+            "Open the local account before migrating cloud data",
+          ));
+        }
+        // The most recently signed-in cloud account on this device.
+        self
+          .load_account_sessions()
+          .into_iter()
+          .rev()
+          .find(|session| session.user_id != current_session.user_id)
+          .ok_or(FlowyError::new(
+            ErrorCode::RecordNotFound,
+            "No cloud account data found on this device",
+          ))?
+      },
+    };
+    if source_session.user_id == current_session.user_id {
+      return Err(FlowyError::new(
+        ErrorCode::InvalidParams,
+        "The source and target account are the same",
+      ));
+    }
+
+    let source_collab_db = self
+      .get_collab_db(source_session.user_id)?
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab db not found"))?;
+    let source_folder_data = read_folder_data(
+      source_session.user_id,
+      &source_session.workspace_id,
+      &source_collab_db,
+    )?;
+    let items = source_folder_data
+      .views
+      .iter()
+      .filter(|view| view.parent_view_id == source_session.workspace_id)
+      .map(|view| DataMigrationItemPB {
+        view_id: view.id.clone(),
+        name: view.name.clone(),
+        child_count: view.children.len() as u64,
+      })
+      .collect::<Vec<_>>();
+
+    // Resolve a container name that doesn't collide with a same-named space
+    // in the target workspace.
+    let current_collab_db = self
+      .get_collab_db(current_session.user_id)?
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab db not found"))?;
+    let existing_names = read_folder_data(
+      current_session.user_id,
+      &current_session.workspace_id,
+      &current_collab_db,
+    )
+      .map(|data| {
+        data
+          .views
+          .iter()
+          .filter(|view| view.parent_view_id == current_session.workspace_id)
+          .map(|view| view.name.clone())
+          .collect::<HashSet<_>>()
+      })
+      .unwrap_or_default();
+    let base_name = if source_folder_data.workspace.name.is_empty() {
+      "Migrated data".to_string()
+    } else {
+      source_folder_data.workspace.name.clone()
+    };
+    let mut container_name = base_name.clone();
+    let mut suffix = 1;
+    while existing_names.contains(&container_name) {
+      suffix += 1;
+      container_name = format!("{} ({})", base_name, suffix);
+    }
+
+    let plan = DataMigrationPlanPB {
+      items,
+      container_name: container_name.clone(),
+      executed: !dry_run,
+    };
+    if dry_run {
+      info!(
+        "Data migration dry run: {} views would move into {:?}",
+        plan.items.len(),
+        container_name
+      );
+      return Ok(plan);
+    }
+
+    let total = plan.items.len() as u64 + 1;
+    send_migration_progress(
+      0,
+      total,
+      format!("Copying {} views into {}", plan.items.len(), container_name),
+    );
+
+    let mut conn = self.db_connection(source_session.user_id)?;
+    let workspace_database_id =
+      select_user_workspace(&source_session.workspace_id, &mut conn)?.database_storage_id;
+    let imported_folder = ImportedFolder {
+      imported_session: source_session,
+      imported_collab_db: source_collab_db,
+      container_name: Some(container_name),
+      parent_view_id: None,
+      source: ImportedSource::ExternalFolder,
+      workspace_database_id,
+    };
+    self.perform_import(imported_folder).await?;
+
+    send_migration_progress(total, total, "Migration finished".to_string());
+    Ok(plan)
+  }
+}
+
+pub(crate) fn read_folder_data(
+  uid: i64,
+  workspace_id: &str,
+  collab_db: &Arc<CollabKVDB>,
+) -> FlowyResult<FolderData> {
+  let mut folder_collab = Collab::new(uid, workspace_id, "data_migration", vec![], false);
+  collab_db
+    .read_txn()
+    .load_doc_with_txn(
+      uid,
+      workspace_id,
+      workspace_id,
+      &mut folder_collab.transact_mut(),
+    )
+    .map_err(|err| {
+      FlowyError::internal().with_context(format!(
+        "Can't load the user:{} folder:{}. {}",
+        uid, workspace_id, err
+      ))
+    })?;
+  let folder = Folder::open(UserId::from(uid), folder_collab, None)
+    .map_err(|err| FlowyError::internal().with_context(format!("Can't open folder: {}", err)))?;
+  folder
+    .get_folder_data(workspace_id)
+    .ok_or_else(|| FlowyError::internal().with_context("Can't read the folder data"))
+}
+
+fn send_migration_progress(current: u64, total: u64, description: String) {
+  send_notification(
+    MIGRATION_NOTIFICATION_ID,
+    UserNotification::DidUpdateMigrationProgress,
+  )
+  .payload(MigrationProgressPB {
+    current,
+    total,
+    description,
+  })
+  .send();
+}
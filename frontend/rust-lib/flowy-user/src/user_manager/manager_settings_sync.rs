@@ -0,0 +1,158 @@
+use std::borrow::BorrowMut;
+use std::sync::Arc;
+
+use chrono::Utc;
+use collab::lock::RwLock;
+use collab::preclude::{Any, Collab, Map, Out, ReadTxn};
+use collab_user::core::UserAwareness;
+use flowy_sqlite::kv::KVStorePreferences;
+use serde::{Deserialize, Serialize};
+use tracing::{instrument, trace, warn};
+
+use crate::entities::SyncedSettingsChangedPB;
+use crate::event_handler::{APPEARANCE_SETTING_CACHE_KEY, DATE_TIME_SETTINGS_CACHE_KEY};
+use crate::notification::{send_notification, UserNotification};
+use crate::user_manager::UserManager;
+use flowy_error::FlowyResult;
+
+/// The preferences that sync across devices through the user awareness
+/// collab. Only whitelisted keys are synced; secrets such as API keys or
+/// tokens must never be added here.
+const SYNCED_PREFERENCE_KEYS: &[&str] = &[
+  APPEARANCE_SETTING_CACHE_KEY,
+  DATE_TIME_SETTINGS_CACHE_KEY,
+  // The selected AI model of flowy-ai, see `GLOBAL_ACTIVE_MODEL_KEY`. The
+  // model name is not a secret, the provider API keys live elsewhere.
+  "ai_models_global_active_model",
+];
+
+/// Root map in the user awareness collab holding one entry per synced
+/// preference key.
+const SETTINGS_SYNC_MAP: &str = "synced_settings";
+
+/// One synced preference: the raw JSON value as stored in
+/// [KVStorePreferences] plus the timestamp of its last change, used for the
+/// last-write-wins merge.
+#[derive(Serialize, Deserialize)]
+struct SyncedSetting {
+  value: serde_json::Value,
+  updated_at: i64,
+}
+
+impl UserManager {
+  /// Pushes the current local value of the given preference key into the
+  /// user awareness collab, so other devices pick it up. Does nothing for
+  /// keys that are not whitelisted. When the awareness collab is not loaded
+  /// yet the value is pushed by the next [merge_synced_settings] instead.
+  #[instrument(level = "trace", skip(self))]
+  pub(crate) async fn push_synced_setting(&self, key: &str) -> FlowyResult<()> {
+    if !SYNCED_PREFERENCE_KEYS.contains(&key) {
+      return Ok(());
+    }
+    let value = match self.store_preferences.get_object::<serde_json::Value>(key) {
+      Some(value) => value,
+      None => return Ok(()),
+    };
+    let updated_at = Utc::now().timestamp_millis();
+    // Record the timestamp first so an offline change still wins the merge
+    // once the awareness collab becomes available.
+    let _ = self
+      .store_preferences
+      .set_i64(&synced_setting_ts_key(key), updated_at);
+
+    let workspace_id = self.workspace_id()?;
+    let awareness = match self.get_awareness(&workspace_id).await {
+      Ok(awareness) => awareness,
+      Err(err) => {
+        trace!("Skip pushing setting {}: {}", key, err);
+        return Ok(());
+      },
+    };
+    let entry = serde_json::to_string(&SyncedSetting { value, updated_at })?;
+    let mut guard = awareness.write().await;
+    let collab: &mut Collab = (*guard).borrow_mut();
+    let map = collab.doc().get_or_insert_map(SETTINGS_SYNC_MAP);
+    let mut txn = collab.transact_mut();
+    map.insert(&mut txn, key, entry);
+    drop(txn);
+    trace!("Pushed setting {} to awareness collab", key);
+    Ok(())
+  }
+}
+
+/// Merges the synced settings of the awareness collab with the local
+/// preferences, last-write-wins per key: newer remote values are applied
+/// locally, newer local values are pushed into the collab. Notifies the UI
+/// with the applied keys so it can reload the affected settings. Called
+/// whenever the awareness collab has been (re)loaded.
+pub(crate) async fn merge_synced_settings(
+  store_preferences: &Arc<KVStorePreferences>,
+  uid: i64,
+  awareness: &Arc<RwLock<UserAwareness>>,
+) {
+  let mut changed_keys = Vec::new();
+  let mut to_push: Vec<(&str, String)> = Vec::new();
+
+  let mut guard = awareness.write().await;
+  let collab: &mut Collab = (*guard).borrow_mut();
+  {
+    let txn = collab.transact();
+    let map = txn.get_map(SETTINGS_SYNC_MAP);
+    for &key in SYNCED_PREFERENCE_KEYS {
+      let remote = map.as_ref().and_then(|map| match map.get(&txn, key) {
+        Some(Out::Any(Any::String(entry))) => {
+          serde_json::from_str::<SyncedSetting>(entry.as_ref()).ok()
+        },
+        _ => None,
+      });
+      let local_updated_at = store_preferences
+        .get_i64(&synced_setting_ts_key(key))
+        .unwrap_or(0);
+      let local_value = store_preferences.get_object::<serde_json::Value>(key);
+
+      match remote {
+        Some(remote) if remote.updated_at > local_updated_at => {
+          if let Err(err) = store_preferences.set_object(key, &remote.value) {
+            warn!("Apply synced setting {} failed: {}", key, err);
+            continue;
+          }
+          let _ = store_preferences.set_i64(&synced_setting_ts_key(key), remote.updated_at);
+          changed_keys.push(key.to_string());
+        },
+        remote => {
+          let remote_updated_at = remote.map(|remote| remote.updated_at).unwrap_or(0);
+          if local_updated_at > remote_updated_at {
+            if let Some(value) = local_value {
+              if let Ok(entry) = serde_json::to_string(&SyncedSetting {
+                value,
+                updated_at: local_updated_at,
+              }) {
+                to_push.push((key, entry));
+              }
+            }
+          }
+        },
+      }
+    }
+  }
+
+  if !to_push.is_empty() {
+    let map = collab.doc().get_or_insert_map(SETTINGS_SYNC_MAP);
+    let mut txn = collab.transact_mut();
+    for (key, entry) in to_push {
+      map.insert(&mut txn, key, entry);
+    }
+  }
+  drop(guard);
+
+  if !changed_keys.is_empty() {
+    trace!("Applied synced settings: {:?}", changed_keys);
+    send_notification(uid, UserNotification::DidUpdateSyncedSettings)
+      .payload(SyncedSettingsChangedPB { keys: changed_keys })
+      .send();
+  }
+}
+
+fn synced_setting_ts_key(key: &str) -> String {
+  format!("settings_sync_ts:{}", key)
+}
@@ -0,0 +1,125 @@
+use std::sync::Weak;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tracing::{error, trace};
+
+use crate::entities::NetworkTypePB;
+use crate::user_manager::UserManager;
+use flowy_error::FlowyResult;
+
+/// Seconds between two full reconciles, configurable through the key value
+/// store. Zero or a negative value disables the scheduler.
+const BACKGROUND_SYNC_INTERVAL_KEY: &str = "background_sync_interval_secs";
+const DEFAULT_BACKGROUND_SYNC_INTERVAL_SECS: i64 = 300;
+/// Whether full reconciles may run on metered connections, off by default.
+const BACKGROUND_SYNC_ON_METERED_KEY: &str = "background_sync_on_metered";
+/// How long the scheduler sleeps while it is disabled before re-reading the
+/// configuration.
+const DISABLED_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The network type last reported by the frontend, shared between the
+/// network handler and the scheduler loop.
+pub(crate) struct SyncSchedulerState {
+  network_tx: watch::Sender<NetworkTypePB>,
+}
+
+impl Default for SyncSchedulerState {
+  fn default() -> Self {
+    let (network_tx, _) = watch::channel(NetworkTypePB::default());
+    Self { network_tx }
+  }
+}
+
+impl UserManager {
+  /// Records the network type reported by the frontend, waking the scheduler
+  /// when the connection came back.
+  pub(crate) fn update_network_type(&self, network_type: NetworkTypePB) {
+    self
+      .sync_scheduler_state
+      .network_tx
+      .send_replace(network_type);
+  }
+
+  /// Spawns the background sync scheduler: a full reconcile runs on the
+  /// configured interval and when the network comes back, instead of relying
+  /// solely on open-view triggers. Reconciles are deferred on metered
+  /// connections unless the user opted in.
+  pub(crate) fn start_sync_scheduler(weak_manager: Weak<UserManager>) {
+    let mut network_rx = match weak_manager.upgrade() {
+      Some(manager) => manager.sync_scheduler_state.network_tx.subscribe(),
+      None => return,
+    };
+    tokio::spawn(async move {
+      loop {
+        let interval_secs = match weak_manager.upgrade() {
+          Some(manager) => manager
+            .store_preferences
+            .get_i64(BACKGROUND_SYNC_INTERVAL_KEY)
+            .unwrap_or(DEFAULT_BACKGROUND_SYNC_INTERVAL_SECS),
+          None => return,
+        };
+        if interval_secs <= 0 {
+          tokio::time::sleep(DISABLED_POLL_INTERVAL).await;
+          continue;
+        }
+
+        let due = tokio::select! {
+          _ = tokio::time::sleep(Duration::from_secs(interval_secs as u64)) => true,
+          changed = network_rx.changed() => match changed {
+            // Reconcile right away when the connection came back, other
+            // network changes just go around the loop.
+            Ok(_) => network_rx.borrow().is_reachable(),
+            Err(_) => return,
+          },
+        };
+        if !due {
+          continue;
+        }
+
+        let manager = match weak_manager.upgrade() {
+          Some(manager) => manager,
+          None => return,
+        };
+        let network_type = network_rx.borrow().clone();
+        // While the frontend has not reported a network type yet, run the
+        // reconcile optimistically.
+        let unknown = matches!(network_type, NetworkTypePB::NetworkUnknown);
+        if !unknown && !network_type.is_reachable() {
+          continue;
+        }
+        if network_type.is_metered()
+          && !manager
+            .store_preferences
+            .get_bool_or_default(BACKGROUND_SYNC_ON_METERED_KEY)
+        {
+          trace!("Defer background sync on a metered connection");
+          continue;
+        }
+        if let Err(err) = manager.full_sync_reconcile().await {
+          error!("Background sync reconcile failed: {}", err);
+        }
+      }
+    });
+  }
+
+  /// Pulls missing updates and pushes queued local changes by bouncing the
+  /// sync plugins, then lets the modules refresh data that is not covered by
+  /// collab sync, e.g. the shared views.
+  pub(crate) async fn full_sync_reconcile(&self) -> FlowyResult<()> {
+    // Nothing to reconcile while signed out.
+    let uid = match self.user_id() {
+      Ok(uid) => uid,
+      Err(_) => return Ok(()),
+    };
+    let workspace_id = self.workspace_id()?;
+    trace!("Background sync reconcile of workspace {}", workspace_id);
+    self.retry_sync().await?;
+    self
+      .app_life_cycle
+      .read()
+      .await
+      .on_full_sync_reconcile(uid, &workspace_id)
+      .await
+  }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use collab_integrate::collab_builder::AppFlowyCollabBuilder;
+use tracing::{info, instrument, trace};
+
+use crate::entities::{ObjectSyncStatusPB, PendingSyncQueuePB};
+use crate::notification::{send_notification, UserNotification};
+use crate::user_manager::UserManager;
+use flowy_error::{FlowyError, FlowyResult};
+
+/// Notification id of the pending sync queue, it is not tied to a single
+/// object.
+const SYNC_QUEUE: &str = "sync_queue";
+
+impl UserManager {
+  /// Returns the sync status of the given collab object, for the
+  /// "saved / syncing / offline" indicator.
+  pub async fn get_sync_status(&self, object_id: &str) -> FlowyResult<ObjectSyncStatusPB> {
+    let status = self
+      .upgrade_collab_builder()?
+      .sync_status_registry()
+      .get_status(object_id);
+    Ok(ObjectSyncStatusPB::new(object_id.to_string(), status))
+  }
+
+  /// The collab objects with local updates that have not reached the server
+  /// yet, oldest first.
+  pub async fn get_pending_sync_queue(&self) -> FlowyResult<PendingSyncQueuePB> {
+    let items = self
+      .upgrade_collab_builder()?
+      .sync_status_registry()
+      .pending_queue()
+      .into_iter()
+      .map(Into::into)
+      .collect();
+    Ok(PendingSyncQueuePB { items })
+  }
+
+  /// Forces the sync plugins to reconnect and push the pending queue, e.g.
+  /// after the user pressed a retry button while the indicator shows an
+  /// error.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn retry_sync(&self) -> FlowyResult<()> {
+    let collab_builder = self.upgrade_collab_builder()?;
+    // The sync plugins watch the network reachability, bouncing it makes
+    // them tear down and re-establish their connections.
+    collab_builder.update_network(false);
+    collab_builder.update_network(true);
+    Ok(())
+  }
+
+  /// Discards the pending local updates of the object by removing its local
+  /// doc, the object is reloaded from the server the next time it is opened.
+  /// The caller must have confirmed this with the user, the discarded
+  /// changes are lost.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn discard_pending_sync(&self, object_id: &str) -> FlowyResult<()> {
+    let uid = self.user_id()?;
+    let workspace_id = self.workspace_id()?;
+    let collab_db = self
+      .get_collab_db(uid)?
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab db not found"))?;
+    collab_db
+      .delete_doc(uid, &workspace_id.to_string(), object_id)
+      .await?;
+    self
+      .upgrade_collab_builder()?
+      .sync_status_registry()
+      .clear_pending(object_id);
+    info!("Discarded pending updates of {}", object_id);
+    Ok(())
+  }
+
+  /// Forwards sync status transitions of all collab objects as notifications
+  /// keyed by the object id, so the UI can update per-object indicators.
+  /// Also notifies when the pending queue drained after a reconnect. Called
+  /// once during initialization.
+  pub(crate) fn start_sync_status_forwarder(&self) -> FlowyResult<()> {
+    let registry = self.upgrade_collab_builder()?.sync_status_registry().clone();
+    let mut receiver = registry.subscribe();
+    tokio::spawn(async move {
+      while let Ok(update) = receiver.recv().await {
+        trace!(
+          "notify sync status of {}: {:?}",
+          update.object_id, update.status
+        );
+        send_notification(&update.object_id, UserNotification::DidUpdateSyncStatus)
+          .payload(ObjectSyncStatusPB::new(update.object_id, update.status))
+          .send();
+      }
+    });
+
+    let mut drain_receiver = registry.subscribe_drain();
+    tokio::spawn(async move {
+      while drain_receiver.recv().await.is_ok() {
+        send_notification(SYNC_QUEUE, UserNotification::DidDrainSyncQueue).send();
+      }
+    });
+    Ok(())
+  }
+
+  pub(crate) fn upgrade_collab_builder(&self) -> FlowyResult<Arc<AppFlowyCollabBuilder>> {
+    self
+      .collab_builder
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab builder is not available"))
+  }
+}
@@ -18,6 +18,11 @@ use uuid::Uuid;
 
 use crate::entities::ReminderPB;
 use crate::notification::{send_notification, UserNotification};
+use crate::services::reminder_scheduler::{
+  delete_reminder_schedule, snooze_reminder_schedule, upsert_reminder_schedule,
+  ReminderScheduleTable, ReminderScheduler,
+};
+use crate::user_manager::manager_settings_sync::merge_synced_settings;
 use crate::user_manager::UserManager;
 
 impl UserManager {
@@ -40,6 +45,10 @@ impl UserManager {
     let awareness = self.get_awareness(&workspace_id).await?;
     awareness.write().await.add_reminder(reminder.clone());
 
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    upsert_reminder_schedule(&mut conn, ReminderScheduleTable::from(&reminder))?;
+
     self
       .collab_interact
       .read()
@@ -60,6 +69,10 @@ impl UserManager {
       .await
       .remove_reminder(reminder_id);
 
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    delete_reminder_schedule(&mut conn, reminder_id)?;
+
     self
       .collab_interact
       .read()
@@ -91,6 +104,12 @@ impl UserManager {
           .set_meta(reminder.meta.clone().into_inner());
       });
 
+    // Rescheduling resets the fired flag so the reminder fires again at the
+    // new time.
+    let uid = self.user_id()?;
+    let mut conn = self.db_connection(uid)?;
+    upsert_reminder_schedule(&mut conn, ReminderScheduleTable::from(&reminder))?;
+
     self
       .collab_interact
       .read()
@@ -101,6 +120,40 @@ impl UserManager {
     Ok(())
   }
 
+  /// Pushes a fired reminder forward to `snooze_until` so it fires again at
+  /// the new time. Works for every reminder source, whether it came from a
+  /// database date cell, a document reminder block or a manual page
+  /// reminder.
+  pub async fn snooze_reminder(&self, reminder_id: &str, snooze_until: i64) -> FlowyResult<()> {
+    let reminder = self
+      .get_all_reminders()
+      .await?
+      .into_iter()
+      .find(|reminder| reminder.id == reminder_id);
+    match reminder {
+      Some(mut reminder) => {
+        reminder.scheduled_at = snooze_until;
+        reminder.is_ack = false;
+        reminder.is_read = false;
+        self.update_reminder(ReminderPB::from(reminder)).await?;
+      },
+      None => {
+        // The reminder is not (or no longer) in the user awareness, but its
+        // schedule may still exist; rearming just the schedule keeps snooze
+        // working for it.
+        let uid = self.user_id()?;
+        let mut conn = self.db_connection(uid)?;
+        if !snooze_reminder_schedule(&mut conn, reminder_id, snooze_until)? {
+          return Err(
+            FlowyError::record_not_found()
+              .with_context(format!("Can't find reminder with id: {}", reminder_id)),
+          );
+        }
+      },
+    }
+    Ok(())
+  }
+
   /// Retrieves all reminders for the user.
   ///
   /// This function fetches all reminders associated with the current user. It leverages the
@@ -184,10 +237,11 @@ impl UserManager {
         info!("User awareness initialized successfully");
         self
           .user_awareness_by_workspace
-          .insert(*workspace_id, awareness);
+          .insert(*workspace_id, awareness.clone());
         if let Some(mut is_loading) = self.is_loading_awareness.get_mut(&object_id) {
           *is_loading = false;
         }
+        merge_synced_settings(&self.store_preferences, uid, &awareness).await;
       } else {
         info!(
           "Initializing new user awareness from server:{}, {:?}",
@@ -205,6 +259,18 @@ impl UserManager {
       ));
     }
 
+    self.start_reminder_scheduler(uid).await?;
+
+    Ok(())
+  }
+
+  /// Starts (or restarts) the scheduler that fires persisted reminders.
+  /// Called whenever a workspace is opened, so reminders created before an
+  /// app restart still fire.
+  pub(crate) async fn start_reminder_scheduler(&self, uid: i64) -> FlowyResult<()> {
+    let pool = self.db_pool(uid)?;
+    let scheduler = ReminderScheduler::start(Arc::downgrade(&pool));
+    *self.reminder_scheduler.write().await = Some(scheduler);
     Ok(())
   }
 
@@ -224,6 +290,7 @@ impl UserManager {
     let user_awareness = self.user_awareness_by_workspace.clone();
     let cloud_services = self.cloud_service()?;
     let is_loading_awareness = self.is_loading_awareness.clone();
+    let store_preferences = self.store_preferences.clone();
     let workspace_id = *workspace_id;
 
     // Spawn an async task to fetch or create user awareness
@@ -291,7 +358,8 @@ impl UserManager {
 
       match create_awareness {
         Ok(new_user_awareness) => {
-          user_awareness.insert(workspace_id, new_user_awareness);
+          user_awareness.insert(workspace_id, new_user_awareness.clone());
+          merge_synced_settings(&store_preferences, uid, &new_user_awareness).await;
           send_notification(workspace_id, UserNotification::DidLoadUserAwareness);
 
           set_is_loading_false();
@@ -340,7 +408,10 @@ impl UserManager {
     Ok(collab)
   }
 
-  async fn get_awareness(&self, workspace_id: &Uuid) -> FlowyResult<Arc<RwLock<UserAwareness>>> {
+  pub(crate) async fn get_awareness(
+    &self,
+    workspace_id: &Uuid,
+  ) -> FlowyResult<Arc<RwLock<UserAwareness>>> {
     let awareness = self
       .user_awareness_by_workspace
       .get(workspace_id)
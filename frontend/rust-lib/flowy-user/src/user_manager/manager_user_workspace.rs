@@ -1,21 +1,31 @@
 use chrono::{Duration, NaiveDateTime, Utc};
 use client_api::entity::billing_dto::{RecurringInterval, SubscriptionPlanDetail};
 use client_api::entity::billing_dto::{SubscriptionPlan, WorkspaceUsageAndLimit};
-
+use collab::preclude::StateVector;
+use collab_document::document::DocumentBody;
+use collab_folder::ViewLayout;
+use collab_integrate::CollabKVAction;
+use collab_plugins::local_storage::kv::KVTransactionDB;
+
+use std::collections::HashMap;
+use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::entities::{
-  RepeatedUserWorkspacePB, SubscribeWorkspacePB, SuccessWorkspaceSubscriptionPB,
-  UpdateUserWorkspaceSettingPB, UserProfilePB, UserWorkspacePB, WorkspaceSettingsPB,
-  WorkspaceSubscriptionInfoPB, WorkspaceTypePB,
+  CollabDiskUsagePB, RepeatedUserWorkspacePB, StorageBreakdownPB, StorageCategoryPB,
+  SubscribeWorkspacePB, SuccessWorkspaceSubscriptionPB, UpdateUserWorkspaceSettingPB,
+  UserProfilePB, UserWorkspacePB, ViewLayoutCountPB, WorkspaceCollabUsagePB, WorkspaceSettingsPB,
+  WorkspaceSubscriptionInfoPB, WorkspaceTypePB, WorkspaceUsageStatsPB,
 };
 use crate::notification::{send_notification, UserNotification};
 use crate::services::billing_check::PeriodicallyCheckBillingState;
 use crate::services::data_import::{
-  generate_import_data, upload_collab_objects_data, ImportedFolder,
+  generate_import_data, load_collab_by_object_id, upload_collab_objects_data, ImportedFolder,
 };
 
+use crate::user_manager::manager_export::database_row_cells;
+use crate::user_manager::manager_migration::read_folder_data;
 use crate::user_manager::UserManager;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use flowy_folder_pub::entities::{ImportFrom, ImportedCollabData, ImportedFolderData};
@@ -27,7 +37,7 @@ use flowy_user_pub::entities::{
 };
 use flowy_user_pub::session::Session;
 use flowy_user_pub::sql::*;
-use tracing::{error, info, instrument, trace};
+use tracing::{error, info, instrument, trace, warn};
 use uuid::Uuid;
 
 impl UserManager {
@@ -180,6 +190,12 @@ impl UserManager {
       error!("Set token failed: {}", err);
     }
 
+    // Apply the workspace's end-to-end encryption key, if encryption was
+    // enabled for it.
+    if let Some(workspace_key) = self.active_workspace_encryption_key(uid, &workspace_id_str) {
+      cloud_service.set_encrypt_secret(workspace_key);
+    }
+
     let mut conn = self.db_connection(self.user_id()?)?;
     let user_workspace = match select_user_workspace(&workspace_id_str, &mut conn) {
       Err(err) => {
@@ -397,12 +413,50 @@ impl UserManager {
     &self,
     workspace_id: Uuid,
   ) -> FlowyResult<Vec<WorkspaceMember>> {
-    let members = self
+    let uid = self.user_id()?;
+    match self
       .cloud_service()?
       .get_user_service()?
       .get_workspace_members(workspace_id)
-      .await?;
-    Ok(members)
+      .await
+    {
+      Ok(members) => {
+        // Refresh the local cache so the members panel can open offline.
+        let records = members
+          .iter()
+          .map(|member| WorkspaceMemberTable {
+            email: member.email.clone(),
+            role: member.role.into(),
+            name: member.name.clone(),
+            avatar_url: member.avatar_url.clone(),
+            uid: 0,
+            workspace_id: workspace_id.to_string(),
+            updated_at: Utc::now().naive_utc(),
+            joined_at: member.joined_at,
+          })
+          .collect::<Vec<_>>();
+        let mut conn = self.db_connection(uid)?;
+        if let Err(err) = replace_workspace_members(&mut conn, &workspace_id.to_string(), records) {
+          error!("Cache workspace members failed: {:?}", err);
+        }
+        Ok(members)
+      },
+      Err(err) => {
+        // Fall back to the cached member list when the cloud service is
+        // unreachable.
+        let mut conn = self.db_connection(uid)?;
+        let cached = select_workspace_members(&mut conn, &workspace_id.to_string())?;
+        if cached.is_empty() {
+          return Err(err);
+        }
+        warn!(
+          "Get workspace members from remote failed: {:?}, fallback to {} cached members",
+          err,
+          cached.len()
+        );
+        Ok(cached.into_iter().map(WorkspaceMember::from).collect())
+      },
+    }
   }
 
   pub async fn get_workspace_member(
@@ -432,6 +486,19 @@ impl UserManager {
     Ok(())
   }
 
+  pub async fn transfer_workspace_ownership(
+    &self,
+    new_owner_email: String,
+    workspace_id: Uuid,
+  ) -> FlowyResult<()> {
+    self
+      .cloud_service()?
+      .get_user_service()?
+      .transfer_workspace_ownership(new_owner_email, workspace_id)
+      .await?;
+    Ok(())
+  }
+
   pub fn get_user_workspace_from_db(
     &self,
     uid: i64,
@@ -607,6 +674,220 @@ impl UserManager {
     Ok(workspace_usage)
   }
 
+  /// Computes usage statistics of a workspace for the usage dashboard in the
+  /// settings: view counts per layout, document and database row counts, the
+  /// local storage footprint and the member count. The counts are computed
+  /// from the local data, so they are also available offline; the cloud
+  /// storage quota is merged in on a best effort basis.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn get_workspace_usage_stats(
+    &self,
+    workspace_id: &Uuid,
+  ) -> FlowyResult<WorkspaceUsageStatsPB> {
+    let uid = self.user_id()?;
+    let collab_db = self
+      .get_collab_db(uid)?
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab db not found"))?;
+    let user_data_dir = self.authenticate_user.user_paths.user_data_dir(uid);
+    let workspace_id_str = workspace_id.to_string();
+
+    let mut stats = tokio::task::spawn_blocking(move || {
+      let folder_data = read_folder_data(uid, &workspace_id_str, &collab_db)?;
+      let mut layout_counts: HashMap<&'static str, u64> = HashMap::new();
+      for view in &folder_data.views {
+        *layout_counts.entry(layout_name(&view.layout)).or_default() += 1;
+      }
+
+      let read_txn = collab_db.read_txn();
+      let object_ids = read_txn
+        .get_all_object_ids(uid, &workspace_id_str)
+        .map(|iter| iter.collect::<Vec<String>>())
+        .unwrap_or_default();
+      let mut document_count = 0;
+      let mut database_row_count = 0;
+      for object_id in &object_ids {
+        let collab = match load_collab_by_object_id(uid, &read_txn, &workspace_id_str, object_id) {
+          Ok(collab) => collab,
+          Err(err) => {
+            trace!("Load collab {} failed: {:?}", object_id, err);
+            continue;
+          },
+        };
+        if DocumentBody::from_collab(&collab).is_some() {
+          document_count += 1;
+        } else if !database_row_cells(&collab.to_json_value()).is_empty() {
+          database_row_count += 1;
+        }
+      }
+
+      let mut view_counts = layout_counts
+        .into_iter()
+        .map(|(layout, count)| ViewLayoutCountPB {
+          layout: layout.to_string(),
+          count,
+        })
+        .collect::<Vec<_>>();
+      view_counts.sort_by(|a, b| a.layout.cmp(&b.layout));
+
+      Ok::<_, FlowyError>(WorkspaceUsageStatsPB {
+        view_counts,
+        document_count,
+        database_row_count,
+        member_count: 0,
+        local_storage_bytes: dir_size(Path::new(&user_data_dir)),
+        cloud_storage_bytes: None,
+        cloud_storage_bytes_limit: None,
+      })
+    })
+    .await
+    .map_err(|err| FlowyError::internal().with_context(err))??;
+
+    // The member list falls back to its local cache when offline. A workspace
+    // always has at least its owner.
+    stats.member_count = match self.get_workspace_members(*workspace_id).await {
+      Ok(members) => members.len().max(1) as u64,
+      Err(_) => 1,
+    };
+
+    if let Ok(usage) = self.get_workspace_usage(workspace_id).await {
+      stats.cloud_storage_bytes = Some(usage.storage_bytes as u64);
+      if !usage.storage_bytes_unlimited {
+        stats.cloud_storage_bytes_limit = Some(usage.storage_bytes_limit as u64);
+      }
+    }
+
+    Ok(stats)
+  }
+
+  /// Reports where the local disk space goes: per-category sizes of the data
+  /// directory, collab bytes per workspace and the biggest collab objects of
+  /// the given workspace attributed to their views, so users can see what is
+  /// eating space when the data folder grows large.
+  #[instrument(level = "info", skip(self), err)]
+  pub async fn get_storage_breakdown(
+    &self,
+    workspace_id: &Uuid,
+  ) -> FlowyResult<StorageBreakdownPB> {
+    let uid = self.user_id()?;
+    let collab_db = self
+      .get_collab_db(uid)?
+      .upgrade()
+      .ok_or_else(|| FlowyError::internal().with_context("Collab db not found"))?;
+    let user_data_dir = self.authenticate_user.user_paths.user_data_dir(uid);
+    let root_dir = self.authenticate_user.user_paths.root().to_string();
+    let workspace_id_str = workspace_id.to_string();
+    let workspaces = {
+      let mut conn = self.db_connection(uid)?;
+      select_all_user_workspace(uid, &mut conn)?
+    };
+
+    let breakdown = tokio::task::spawn_blocking(move || {
+      let user_data_path = Path::new(&user_data_dir);
+      let collab_db_bytes = dir_size(&user_data_path.join("collab_db"));
+      let collab_history_bytes = dir_size(&user_data_path.join("collab_db_history"));
+      let search_index_bytes = dir_size(&user_data_path.join("indexes"));
+      let restored_backup_bytes = dir_size(&user_data_path.join("restored_backups"));
+      let sqlite_bytes = file_sizes_with_prefix(user_data_path, flowy_sqlite::DB_NAME)
+        + file_sizes_with_prefix(Path::new(&root_dir), "cache.db");
+      let attachment_bytes = dir_size(&Path::new(&root_dir).join("cache_files"));
+      let temp_bytes = restored_backup_bytes + appflowy_temp_size();
+
+      // Whatever else lives in the user data directory, e.g. logs.
+      let other_bytes = dir_size(user_data_path).saturating_sub(
+        collab_db_bytes
+          + collab_history_bytes
+          + search_index_bytes
+          + restored_backup_bytes
+          + file_sizes_with_prefix(user_data_path, flowy_sqlite::DB_NAME),
+      );
+
+      let categories = vec![
+        ("Collab database", collab_db_bytes),
+        ("Collab backups", collab_history_bytes),
+        ("SQLite databases", sqlite_bytes),
+        ("Search index", search_index_bytes),
+        ("File attachments", attachment_bytes),
+        ("Temp files", temp_bytes),
+        ("Other", other_bytes),
+      ]
+      .into_iter()
+      .map(|(name, bytes)| StorageCategoryPB {
+        name: name.to_string(),
+        bytes,
+      })
+      .collect::<Vec<_>>();
+      let total_bytes = categories.iter().map(|category| category.bytes).sum();
+
+      // The rocksdb store is shared between workspaces, so the per-workspace
+      // attribution measures the encoded size of each workspace's docs.
+      let read_txn = collab_db.read_txn();
+      let mut workspace_collab_usage = Vec::with_capacity(workspaces.len());
+      let mut largest_collabs = Vec::new();
+      for workspace in &workspaces {
+        let object_ids = read_txn
+          .get_all_object_ids(uid, &workspace.id)
+          .map(|iter| iter.collect::<Vec<String>>())
+          .unwrap_or_default();
+        let is_requested_workspace = workspace.id == workspace_id_str;
+        let mut bytes = 0;
+        for object_id in object_ids {
+          let collab = match load_collab_by_object_id(uid, &read_txn, &workspace.id, &object_id) {
+            Ok(collab) => collab,
+            Err(err) => {
+              trace!("Load collab {} failed: {:?}", object_id, err);
+              continue;
+            },
+          };
+          let object_bytes = collab
+            .transact()
+            .encode_state_as_update_v1(&StateVector::default())
+            .len() as u64;
+          bytes += object_bytes;
+          if is_requested_workspace {
+            largest_collabs.push(CollabDiskUsagePB {
+              object_id,
+              name: String::new(),
+              bytes: object_bytes,
+            });
+          }
+        }
+        workspace_collab_usage.push(WorkspaceCollabUsagePB {
+          workspace_id: workspace.id.clone(),
+          name: workspace.name.clone(),
+          bytes,
+        });
+      }
+
+      largest_collabs.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+      largest_collabs.truncate(10);
+      // Attribute the biggest objects to their views where possible.
+      if let Ok(folder_data) = read_folder_data(uid, &workspace_id_str, &collab_db) {
+        let view_names = folder_data
+          .views
+          .iter()
+          .map(|view| (view.id.clone(), view.name.clone()))
+          .collect::<HashMap<String, String>>();
+        for usage in &mut largest_collabs {
+          if let Some(name) = view_names.get(&usage.object_id) {
+            usage.name = name.clone();
+          }
+        }
+      }
+
+      StorageBreakdownPB {
+        total_bytes,
+        categories,
+        workspace_collab_usage,
+        largest_collabs,
+      }
+    })
+    .await
+    .map_err(|err| FlowyError::internal().with_context(err))?;
+
+    Ok(breakdown)
+  }
+
   #[instrument(level = "info", skip(self), err)]
   pub async fn get_billing_portal_url(&self) -> FlowyResult<String> {
     let url = self
@@ -819,3 +1100,77 @@ async fn sync_workspace(
   }
   Ok(user_workspace)
 }
+
+fn layout_name(layout: &ViewLayout) -> &'static str {
+  match layout {
+    ViewLayout::Document => "Document",
+    ViewLayout::Grid => "Grid",
+    ViewLayout::Board => "Board",
+    ViewLayout::Calendar => "Calendar",
+    ViewLayout::Chat => "Chat",
+  }
+}
+
+/// Sums the sizes of the files in `dir` whose name starts with `prefix`,
+/// which catches sqlite sidecar files like `-wal` and `-shm` alongside the
+/// database itself.
+fn file_sizes_with_prefix(dir: &Path, prefix: &str) -> u64 {
+  let mut size = 0;
+  if let Ok(entries) = std::fs::read_dir(dir) {
+    for entry in entries.flatten() {
+      let matches = entry
+        .file_name()
+        .to_str()
+        .map(|name| name.starts_with(prefix))
+        .unwrap_or(false);
+      if matches {
+        if let Ok(metadata) = entry.metadata() {
+          if metadata.is_file() {
+            size += metadata.len();
+          }
+        }
+      }
+    }
+  }
+  size
+}
+
+/// Size of the staging files this app leaves in the system temp directory,
+/// e.g. backup archives.
+fn appflowy_temp_size() -> u64 {
+  let mut size = 0;
+  if let Ok(entries) = std::fs::read_dir(std::env::temp_dir()) {
+    for entry in entries.flatten() {
+      let matches = entry
+        .file_name()
+        .to_str()
+        .map(|name| name.starts_with("appflowy_"))
+        .unwrap_or(false);
+      if !matches {
+        continue;
+      }
+      let entry_path = entry.path();
+      if entry_path.is_dir() {
+        size += dir_size(&entry_path);
+      } else if let Ok(metadata) = entry.metadata() {
+        size += metadata.len();
+      }
+    }
+  }
+  size
+}
+
+pub(crate) fn dir_size(path: &Path) -> u64 {
+  let mut size = 0;
+  if let Ok(entries) = std::fs::read_dir(path) {
+    for entry in entries.flatten() {
+      let entry_path = entry.path();
+      if entry_path.is_dir() {
+        size += dir_size(&entry_path);
+      } else if let Ok(metadata) = entry.metadata() {
+        size += metadata.len();
+      }
+    }
+  }
+  size
+}
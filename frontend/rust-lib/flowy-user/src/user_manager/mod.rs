@@ -1,5 +1,17 @@
 mod manager;
+pub(crate) mod manager_accounts;
+pub(crate) mod manager_app_lock;
+pub(crate) mod manager_backup;
+pub(crate) mod manager_compaction;
+pub(crate) mod manager_e2ee;
+pub(crate) mod manager_export;
 pub(crate) mod manager_history_user;
+pub(crate) mod manager_inbox;
+pub(crate) mod manager_local_backup;
+pub(crate) mod manager_migration;
+pub(crate) mod manager_settings_sync;
+pub(crate) mod manager_sync_scheduler;
+pub(crate) mod manager_sync_status;
 pub(crate) mod manager_user_awareness;
 pub(crate) mod manager_user_encryption;
 pub(crate) mod manager_user_workspace;
@@ -129,6 +129,23 @@ pub fn zip_folder(src_path: impl AsRef<Path>, dest_path: &Path) -> io::Result<()
   zip.finish()?;
   Ok(())
 }
+/// Checks that the archive can be opened and every entry fully read, without
+/// extracting anything. Returns the entry names so the caller can verify the
+/// archive contains what it expects.
+pub fn validate_zip(zip_path: impl AsRef<Path>) -> Result<Vec<String>, anyhow::Error> {
+  let file = File::open(zip_path.as_ref())
+    .with_context(|| format!("Can't find the zip file: {:?}", zip_path.as_ref()))?;
+  let mut archive = ZipArchive::new(file).context("Unzip file fail")?;
+  let mut names = Vec::with_capacity(archive.len());
+  for i in 0..archive.len() {
+    let mut entry = archive.by_index(i)?;
+    io::copy(&mut entry, &mut io::sink())
+      .with_context(|| format!("Corrupted zip entry: {}", entry.name()))?;
+    names.push(entry.name().to_string());
+  }
+  Ok(names)
+}
+
 pub fn unzip_and_replace(
   zip_path: impl AsRef<Path>,
   target_folder: &Path,